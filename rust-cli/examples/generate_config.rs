@@ -1,4 +1,4 @@
-//! Generate config schema and example config files.
+//! Generate the config schema, example config, and config reference.
 //!
 //! Run with: cargo run --example `generate_config`
 //! Or use: just generate-config
@@ -19,6 +19,7 @@ fn main() -> anyhow::Result<()> {
     println!("Generated:");
     println!("  - {}/config.schema.json", output_dir.display());
     println!("  - {}/config.toml", output_dir.display());
+    println!("  - {}/config.md", output_dir.display());
 
     Ok(())
 }
@@ -1,8 +1,12 @@
 //! rust-cli library for config management and schema generation.
 //!
-//! This module provides functions to generate JSON schemas and example TOML
-//! configurations from the config struct definitions.
+//! This module provides functions to generate JSON schemas, example TOML
+//! configurations, and a Markdown config reference from the config struct
+//! definitions, and to describe how committed copies differ from them: the
+//! schema by JSON pointer, the config by dotted key.
 
+use std::collections::BTreeMap;
+use std::ffi::OsStr;
 use std::fmt::Write as _;
 use std::fs;
 use std::path::Path;
@@ -12,7 +16,7 @@ use schemars::JsonSchema;
 use schemars::Schema;
 use schemars::generate::SchemaSettings;
 use serde::{Deserialize, Serialize};
-use serde_json::json;
+use serde_json::{Value, json};
 
 /// Generated schema filename.
 pub const SCHEMA_FILENAME: &str = "config.schema.json";
@@ -20,6 +24,9 @@ pub const SCHEMA_FILENAME: &str = "config.schema.json";
 /// Generated config filename.
 pub const CONFIG_FILENAME: &str = "config.toml";
 
+/// Generated config reference filename.
+pub const CONFIG_DOCS_FILENAME: &str = "config.md";
+
 /// Application configuration.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default)]
@@ -156,18 +163,184 @@ pub fn generate_example_config(project_name: &str) -> Result<String> {
     let mut output = String::new();
     let _ = write!(
         output,
-        r#"#:schema {schema_url}"
+        r"#:schema {schema_url}
 
 # Configuration for {project_name}.
 # Copy this file to $XDG_CONFIG_HOME/{project_name}/config.toml and adjust as needed.
 
-"#
+"
     );
     output.push_str(&toml_body);
 
     Ok(output)
 }
 
+// rust-workspace generates its reference in `rust_core::config_docs`, on
+// top of its settings UI spec; this crate has neither, so it walks its
+// schema here with the same table layout.
+
+/// Generate the Markdown config reference from the `AppConfig` schema: a
+/// table per section with each setting's dotted key, type, default, and
+/// description.
+///
+/// # Errors
+///
+/// Returns an error if the schema has an unexpected shape.
+pub fn generate_config_docs(project_name: &str) -> Result<String> {
+    let schema = SchemaSettings::draft07()
+        .into_generator()
+        .into_root_schema_for::<AppConfig>();
+    config_docs(schema.as_value(), project_name)
+}
+
+/// Render the reference for a draft-07 `schema` of the config.
+fn config_docs(schema: &Value, project_name: &str) -> Result<String> {
+    let properties = schema
+        .get("properties")
+        .and_then(Value::as_object)
+        .context("config schema has no properties")?;
+    let mut general = Vec::new();
+    let mut sections = Vec::new();
+    for (key, property) in properties {
+        if key.starts_with('$') {
+            continue;
+        }
+        let node = resolve(schema, property)?;
+        if node.get("properties").is_some() {
+            let mut rows = Vec::new();
+            collect_rows(schema, key, node, &mut rows)?;
+            sections.push((format!("`{key}`"), description(property, node), rows));
+        } else {
+            general.push(row(key, property, node));
+        }
+    }
+    if !general.is_empty() {
+        sections.insert(0, ("General".to_string(), None, general));
+    }
+
+    let mut out = format!(
+        "# {project_name} configuration reference\n\n\
+         <!-- Generated from the config schema by `just generate-config`; do not edit by hand. -->\n\n\
+         Every setting of the config file, by section. Keys are dotted paths into the file.\n"
+    );
+    for (heading, description, rows) in sections.iter().filter(|(_, _, rows)| !rows.is_empty()) {
+        let _ = write!(out, "\n## {heading}\n\n");
+        if let Some(description) = description {
+            let _ = write!(out, "{description}\n\n");
+        }
+        out.push_str("| Key | Type | Default | Description |\n|---|---|---|---|\n");
+        for row in rows {
+            let _ = writeln!(out, "{row}");
+        }
+    }
+    Ok(out)
+}
+
+/// Add a table row for each setting of `section`, flattening nested
+/// sections under dotted keys.
+fn collect_rows(
+    schema: &Value,
+    prefix: &str,
+    section: &Value,
+    out: &mut Vec<String>,
+) -> Result<()> {
+    let Some(properties) = section.get("properties").and_then(Value::as_object) else {
+        return Ok(());
+    };
+    for (name, property) in properties {
+        let key = format!("{prefix}.{name}");
+        let node = resolve(schema, property)?;
+        if node.get("properties").is_some() {
+            collect_rows(schema, &key, node, out)?;
+        } else {
+            out.push(row(&key, property, node));
+        }
+    }
+    Ok(())
+}
+
+/// The definition `property` refers to, directly or through the one-item
+/// `allOf` schemars wraps a described reference in, else `property` itself.
+fn resolve<'a>(schema: &'a Value, property: &'a Value) -> Result<&'a Value> {
+    let reference = property.get("$ref").or_else(|| {
+        property
+            .get("allOf")
+            .and_then(Value::as_array)
+            .filter(|all_of| all_of.len() == 1)
+            .and_then(|all_of| all_of[0].get("$ref"))
+    });
+    let Some(reference) = reference.and_then(Value::as_str) else {
+        return Ok(property);
+    };
+    reference
+        .strip_prefix('#')
+        .and_then(|pointer| schema.pointer(pointer))
+        .with_context(|| format!("config schema has no definition {reference}"))
+}
+
+fn description(property: &Value, node: &Value) -> Option<String> {
+    property
+        .get("description")
+        .or_else(|| node.get("description"))
+        .and_then(Value::as_str)
+        .map(|text| text.replace('\n', " "))
+}
+
+/// One table row: key, type with its choices or bounds, default, and
+/// description.
+fn row(key: &str, property: &Value, node: &Value) -> String {
+    let types: Vec<&str> = match node.get("type") {
+        Some(Value::String(kind)) => vec![kind.as_str()],
+        Some(Value::Array(kinds)) => kinds.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    };
+    let nullable = types.contains(&"null");
+    let mut kind = types
+        .iter()
+        .filter(|kind| **kind != "null")
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" or ");
+    if let Some(choices) = node.get("enum").and_then(Value::as_array) {
+        let choices: Vec<String> = choices.iter().map(|choice| format!("`{choice}`")).collect();
+        let _ = write!(
+            kind,
+            "{}one of {}",
+            if kind.is_empty() { "" } else { ", " },
+            choices.join(", ")
+        );
+    }
+    match (node.get("minimum"), node.get("maximum")) {
+        (Some(min), Some(max)) => {
+            let _ = write!(kind, ", {min} to {max}");
+        }
+        (Some(min), None) => {
+            let _ = write!(kind, ", at least {min}");
+        }
+        (None, Some(max)) => {
+            let _ = write!(kind, ", at most {max}");
+        }
+        (None, None) => {}
+    }
+    let default = match property.get("default").or_else(|| node.get("default")) {
+        Some(Value::Null) => "unset".to_string(),
+        Some(default) => format!("`{default}`"),
+        None if nullable => "unset".to_string(),
+        None => "none".to_string(),
+    };
+    format!(
+        "| `{key}` | {} | {} | {} |",
+        cell(&kind),
+        cell(&default),
+        cell(&description(property, node).unwrap_or_default())
+    )
+}
+
+/// `text` safe inside a table cell.
+fn cell(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
 /// Write generated files to a directory.
 ///
 /// # Errors
@@ -187,6 +360,11 @@ pub fn write_generated_files(output_dir: &Path, project_name: &str, repo_url: &s
     fs::write(&config_path, &config)
         .with_context(|| format!("writing config to {}", config_path.display()))?;
 
+    let docs = generate_config_docs(project_name)?;
+    let docs_path = output_dir.join(CONFIG_DOCS_FILENAME);
+    fs::write(&docs_path, &docs)
+        .with_context(|| format!("writing config reference to {}", docs_path.display()))?;
+
     Ok(())
 }
 
@@ -200,46 +378,25 @@ pub fn validate_against_examples(
     project_name: &str,
     repo_url: &str,
 ) -> Result<()> {
-    let schema = generate_schema(project_name, repo_url)?;
-    let config = generate_example_config(project_name)?;
-
-    let schema_path = examples_dir.join(SCHEMA_FILENAME);
-    let config_path = examples_dir.join(CONFIG_FILENAME);
-
     let mut errors = Vec::new();
-
-    // Check schema
-    if schema_path.exists() {
-        let existing = fs::read_to_string(&schema_path)
-            .with_context(|| format!("reading {}", schema_path.display()))?;
-        if existing != schema {
-            errors.push(format!(
-                "{} is out of date. Run 'just generate-config' to update.",
-                schema_path.display()
-            ));
-        }
-    } else {
-        errors.push(format!(
-            "{} does not exist. Run 'just generate-config' to create.",
-            schema_path.display()
-        ));
-    }
-
-    // Check config
-    if config_path.exists() {
-        let existing = fs::read_to_string(&config_path)
-            .with_context(|| format!("reading {}", config_path.display()))?;
-        if existing != config {
+    for (file, generated) in [
+        (SCHEMA_FILENAME, generate_schema(project_name, repo_url)?),
+        (CONFIG_FILENAME, generate_example_config(project_name)?),
+        (CONFIG_DOCS_FILENAME, generate_config_docs(project_name)?),
+    ] {
+        let path = examples_dir.join(file);
+        if path.exists() {
+            let existing =
+                fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+            if existing != generated {
+                errors.push(out_of_date(&path, &existing, &generated));
+            }
+        } else {
             errors.push(format!(
-                "{} is out of date. Run 'just generate-config' to update.",
-                config_path.display()
+                "{} does not exist. Run 'just generate-config' to create.",
+                path.display()
             ));
         }
-    } else {
-        errors.push(format!(
-            "{} does not exist. Run 'just generate-config' to create.",
-            config_path.display()
-        ));
     }
 
     if errors.is_empty() {
@@ -252,6 +409,159 @@ pub fn validate_against_examples(
     }
 }
 
+/// The error line for a stale file, listing what differs.
+fn out_of_date(path: &Path, existing: &str, generated: &str) -> String {
+    let mut message = format!(
+        "{} is out of date. Run 'just generate-config' to update.",
+        path.display()
+    );
+    for change in changes(path, existing, generated) {
+        let _ = write!(message, "\n      {change}");
+    }
+    message
+}
+
+// `changes`, `json_changes`, and `toml_changes` are copies of the ones in
+// rust-workspace's `rust_core::artifacts`, kept on purpose: this template is
+// a standalone crate that must build without the workspace. A fix to one
+// copy belongs in the other too.
+
+/// What differs between the `committed` and `generated` copies of `path`.
+///
+/// JSON is compared by JSON pointer and TOML by dotted key; a TOML file
+/// whose values all match has changed comments or layout, reported at the
+/// first differing line, as is any other file.
+#[must_use]
+pub fn changes(path: &Path, committed: &str, generated: &str) -> Vec<String> {
+    let by_value = match path.extension().and_then(OsStr::to_str) {
+        Some("json") => match (
+            serde_json::from_str(committed),
+            serde_json::from_str(generated),
+        ) {
+            (Ok(committed), Ok(generated)) => json_changes(&committed, &generated),
+            (Err(err), _) => vec![format!("committed copy is not valid JSON: {err}")],
+            (_, Err(err)) => vec![format!("generated copy is not valid JSON: {err}")],
+        },
+        Some("toml") => toml_changes(committed, generated),
+        _ => Vec::new(),
+    };
+    if !by_value.is_empty() {
+        return by_value;
+    }
+    let line = committed
+        .lines()
+        .zip(generated.lines())
+        .position(|(committed, generated)| committed != generated)
+        .unwrap_or_else(|| committed.lines().count().min(generated.lines().count()));
+    vec![format!("text differs from line {}", line + 1)]
+}
+
+/// Differences between two JSON values, one per JSON pointer (RFC 6901):
+/// `<pointer>: <committed> -> <generated>`, or `added`/`removed` for keys
+/// and array items present on one side only.
+#[must_use]
+pub fn json_changes(committed: &Value, generated: &Value) -> Vec<String> {
+    let mut out = Vec::new();
+    diff_json("", committed, generated, &mut out);
+    out
+}
+
+fn diff_json(pointer: &str, committed: &Value, generated: &Value, out: &mut Vec<String>) {
+    match (committed, generated) {
+        (Value::Object(old), Value::Object(new)) => {
+            for (key, old_value) in old {
+                let child = format!("{pointer}/{}", escape_pointer(key));
+                match new.get(key) {
+                    Some(new_value) => diff_json(&child, old_value, new_value, out),
+                    None => out.push(format!("{child}: removed")),
+                }
+            }
+            for (key, new_value) in new {
+                if !old.contains_key(key) {
+                    let child = format!("{pointer}/{}", escape_pointer(key));
+                    out.push(format!("{child}: added {new_value}"));
+                }
+            }
+        }
+        (Value::Array(old), Value::Array(new)) => {
+            for (index, pair) in old.iter().zip(new).enumerate() {
+                diff_json(&format!("{pointer}/{index}"), pair.0, pair.1, out);
+            }
+            for (index, value) in new.iter().enumerate().skip(old.len()) {
+                out.push(format!("{pointer}/{index}: added {value}"));
+            }
+            for index in new.len()..old.len() {
+                out.push(format!("{pointer}/{index}: removed"));
+            }
+        }
+        _ if committed != generated => {
+            let pointer = if pointer.is_empty() { "/" } else { pointer };
+            out.push(format!("{pointer}: {committed} -> {generated}"));
+        }
+        _ => {}
+    }
+}
+
+/// `key` as a JSON pointer segment.
+fn escape_pointer(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+/// Differences between two TOML documents, one per dotted key:
+/// `<key>: <committed> -> <generated>`, or `added`/`removed`. Tables are
+/// compared key by key; arrays and inline values as a whole.
+#[must_use]
+pub fn toml_changes(committed: &str, generated: &str) -> Vec<String> {
+    let parse = |text: &str| text.parse::<toml::Table>();
+    let (old, new) = match (parse(committed), parse(generated)) {
+        (Ok(old), Ok(new)) => (flatten_toml(old), flatten_toml(new)),
+        (Err(err), _) => return vec![format!("committed copy is not valid TOML: {err}")],
+        (_, Err(err)) => return vec![format!("generated copy is not valid TOML: {err}")],
+    };
+    let mut out = Vec::new();
+    for (key, old_value) in &old {
+        match new.get(key) {
+            Some(new_value) if new_value != old_value => {
+                out.push(format!("{key}: {old_value} -> {new_value}"));
+            }
+            Some(_) => {}
+            None => out.push(format!("{key}: removed")),
+        }
+    }
+    for (key, new_value) in &new {
+        if !old.contains_key(key) {
+            out.push(format!("{key}: added {new_value}"));
+        }
+    }
+    out
+}
+
+/// Leaf values of `table` by dotted key; an empty table is a leaf, so
+/// adding or removing one is reported.
+fn flatten_toml(table: toml::Table) -> BTreeMap<String, String> {
+    fn walk(prefix: &str, table: toml::Table, out: &mut BTreeMap<String, String>) {
+        if table.is_empty() && !prefix.is_empty() {
+            out.insert(prefix.to_string(), "{}".to_string());
+        }
+        for (key, value) in table {
+            let path = if prefix.is_empty() {
+                key
+            } else {
+                format!("{prefix}.{key}")
+            };
+            match value {
+                toml::Value::Table(table) => walk(&path, table, out),
+                value => {
+                    out.insert(path, value.to_string());
+                }
+            }
+        }
+    }
+    let mut out = BTreeMap::new();
+    walk("", table, &mut out);
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -267,7 +577,7 @@ mod tests {
             schema.contains("rust-cli configuration"),
             "schema description is missing"
         );
-        anyhow::ensure!(schema.contains("#:schema"), "schema metadata is missing");
+        anyhow::ensure!(schema.contains("$schema"), "schema metadata is missing");
         Ok(())
     }
 
@@ -276,22 +586,100 @@ mod tests {
         let config = generate_example_config(APP_NAME)?;
         anyhow::ensure!(config.contains("[logging]"), "logging section is missing");
         anyhow::ensure!(config.contains("[runtime]"), "runtime section is missing");
-        anyhow::ensure!(config.contains("$schema"), "schema reference is missing");
+        anyhow::ensure!(config.contains("#:schema"), "schema reference is missing");
         Ok(())
     }
 
+    /// The committed schema, embedded so the check needs no filesystem.
+    const GOLDEN_SCHEMA: &str = include_str!("../examples/config.schema.json");
+
+    /// The committed example config.
+    const GOLDEN_CONFIG: &str = include_str!("../examples/config.toml");
+
+    /// The committed config reference.
+    const GOLDEN_CONFIG_DOCS: &str = include_str!("../examples/config.md");
+
+    /// Fail with what differs unless `generated` matches the `golden` copy
+    /// of `examples/<file>`.
+    fn ensure_golden(file: &str, golden: &str, generated: &str) -> Result<()> {
+        if golden == generated {
+            return Ok(());
+        }
+        anyhow::bail!(
+            "examples/{file} is out of date. Run 'just generate-config' to update:\n  - {}",
+            changes(Path::new(file), golden, generated).join("\n  - ")
+        )
+    }
+
+    #[test]
+    fn generated_schema_matches_golden() -> Result<()> {
+        ensure_golden(
+            SCHEMA_FILENAME,
+            GOLDEN_SCHEMA,
+            &generate_schema(APP_NAME, REPO_URL)?,
+        )
+    }
+
     #[test]
-    fn validate_examples_are_up_to_date() -> Result<()> {
-        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")?;
-        let crate_root = Path::new(&manifest_dir);
-        let examples_dir = crate_root.join("examples");
+    fn generated_config_matches_golden() -> Result<()> {
+        ensure_golden(
+            CONFIG_FILENAME,
+            GOLDEN_CONFIG,
+            &generate_example_config(APP_NAME)?,
+        )
+    }
 
+    #[test]
+    fn generated_config_docs_match_golden() -> Result<()> {
+        ensure_golden(
+            CONFIG_DOCS_FILENAME,
+            GOLDEN_CONFIG_DOCS,
+            &generate_config_docs(APP_NAME)?,
+        )
+    }
+
+    #[test]
+    fn config_docs_have_a_table_per_section() -> Result<()> {
+        let docs = generate_config_docs(APP_NAME)?;
+        for line in [
+            "## General",
+            "| `profile` | string | `\"default\"` | Active configuration profile name. |",
+            "## `logging`\n\nLogging configuration.\n",
+            "| `logging.file` | string | unset | Optional file path to write logs to. |",
+            "| `runtime.timeout` | integer, at least 0 | `60` | Default timeout in seconds for operations. |",
+            "| `runtime.fail_fast` | boolean | `true` | Stop on first error instead of continuing. |",
+            "## `paths`",
+        ] {
+            anyhow::ensure!(docs.contains(line), "missing {line:?} in\n{docs}");
+        }
+        anyhow::ensure!(!docs.contains("$schema"), "{docs}");
+        Ok(())
+    }
+
+    #[test]
+    fn changes_name_json_pointers_and_toml_keys() -> Result<()> {
+        let json = changes(
+            Path::new("x.json"),
+            r#"{"a": {"b/c": 1, "gone": true}, "list": [1, 2]}"#,
+            r#"{"a": {"b/c": 2}, "list": [1, 2, 3]}"#,
+        );
         anyhow::ensure!(
-            examples_dir.exists(),
-            "examples/ directory not found at {}. Create it and run 'just generate-config'.",
-            examples_dir.display()
+            json == ["/a/b~1c: 1 -> 2", "/a/gone: removed", "/list/2: added 3"],
+            "{json:?}"
         );
-
-        validate_against_examples(&examples_dir, APP_NAME, REPO_URL)
+        let toml = changes(
+            Path::new("x.toml"),
+            "[runtime]\ntimeout = 60\n[paths]\n",
+            "[runtime]\ntimeout = 30\nfail_fast = true\n",
+        );
+        anyhow::ensure!(
+            toml == [
+                "paths: removed",
+                "runtime.timeout: 60 -> 30",
+                "runtime.fail_fast: added true",
+            ],
+            "{toml:?}"
+        );
+        Ok(())
     }
 }
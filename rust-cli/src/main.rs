@@ -162,6 +162,8 @@ enum ConfigCommand {
     Paths,
     /// Print the JSON schema for the config file
     Schema,
+    /// Print the Markdown reference for the config file
+    Docs,
     /// Regenerate the default configuration file
     Reset,
 }
@@ -451,6 +453,10 @@ fn handle_config(ctx: &RuntimeContext, command: ConfigCommand) -> Result<()> {
             println!("{schema}");
             Ok(())
         }
+        ConfigCommand::Docs => {
+            print!("{}", rust_cli::generate_config_docs(APP_NAME)?);
+            Ok(())
+        }
         ConfigCommand::Reset => {
             if ctx.common.dry_run {
                 info!(
@@ -1,33 +1,65 @@
 //! HTTP API server for rust-workspace.
+//!
+//! With `[daemon.http] enabled = true` it also serves a status page for
+//! local clients (see [`status`]).
+
+mod status;
 
 use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::Result;
 use axum::{Json, Router, extract::State, http::StatusCode, routing::get};
 use clap::{Args, Parser};
-use log::info;
+use log::{debug, info, warn};
 use serde::Serialize;
 use tower_http::cors::{Any, CorsLayer};
 use tower_http::trace::TraceLayer;
 
-use rust_core::{AppConfig, AppPaths};
-
-fn main() -> anyhow::Result<()> {
-    try_main()
+use rust_core::{AppConfig, AppPaths, Ctx, ErrorCode, ExitCode, PathMode};
+use rust_core::{activation, notify};
+
+fn main() -> std::process::ExitCode {
+    let cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        Err(err) => {
+            let _ = err.print();
+            let code = if err.use_stderr() {
+                ExitCode::Usage
+            } else {
+                ExitCode::Success
+            };
+            return code.into();
+        }
+    };
+    match try_main(&cli) {
+        Ok(()) => ExitCode::Success.into(),
+        Err(err) => {
+            match ErrorCode::from_error(&err) {
+                Some(code) => eprintln!("Error[{code}]: {err:?}"),
+                None => eprintln!("Error: {err:?}"),
+            }
+            ExitCode::from_error(&err).into()
+        }
+    }
 }
 
 #[tokio::main]
-async fn try_main() -> Result<()> {
+async fn try_main(cli: &Cli) -> Result<()> {
     env_logger::init();
 
-    let cli = Cli::parse();
-    let paths = AppPaths::discover(cli.common.config.as_deref())?;
-    let config = AppConfig::load(&paths, false)?;
+    let (paths, config) = AppPaths::load(
+        PathMode::detect(),
+        cli.common.config.as_deref(),
+        &Ctx::default(),
+        &[],
+    )?;
 
+    let config = Arc::new(config);
     let state = AppState {
-        config: Arc::new(config),
+        config: Arc::clone(&config),
     };
 
     let cors = CorsLayer::new()
@@ -35,21 +67,109 @@ async fn try_main() -> Result<()> {
         .allow_methods(Any)
         .allow_headers(Any);
 
-    let app = Router::new()
+    let mut app = Router::new()
         .route("/", get(root))
         .route("/health", get(health))
         .route("/config", get(get_config))
-        .layer(cors)
-        .layer(TraceLayer::new_for_http())
         .with_state(state);
+    if config.daemon.http.enabled {
+        let token = status::token(&config, &paths.state_dir)?;
+        app = app.merge(status::routes(
+            Arc::clone(&config),
+            &paths.config_file,
+            &paths.state_dir,
+            token,
+        ));
+    }
+    let app = app.layer(cors).layer(TraceLayer::new_for_http());
+
+    let listener = if let Some(activated) = activation::tcp_listener()? {
+        activated.set_nonblocking(true)?;
+        let listener = tokio::net::TcpListener::from_std(activated)?;
+        info!(
+            "Starting API server on {} (socket from systemd)",
+            listener.local_addr()?
+        );
+        listener
+    } else {
+        let addr = SocketAddr::from(([127, 0, 0, 1], cli.common.port));
+        info!("Starting API server on {addr}");
+        tokio::net::TcpListener::bind(addr).await?
+    };
+    if config.daemon.http.enabled {
+        let token = if config.daemon.http.token.is_some() {
+            "daemon.http.token".to_string()
+        } else {
+            paths
+                .state_dir
+                .join(status::TOKEN_FILENAME)
+                .display()
+                .to_string()
+        };
+        info!(
+            "status page at http://{}/status?token=<token from {token}>",
+            listener.local_addr()?
+        );
+    }
+    if notify::ready()? {
+        debug!("notified systemd: ready");
+    }
+    if let Some(interval) = notify::watchdog_interval() {
+        info!("pinging the systemd watchdog every {interval:?}");
+        // On the server's own runtime, so a wedged runtime stops the pings.
+        tokio::spawn(ping_watchdog(interval));
+    }
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(shutdown_signal())
+    .await?;
 
-    let addr = SocketAddr::from(([127, 0, 0, 1], cli.common.port));
-    info!("Starting API server on {addr}");
+    Ok(())
+}
 
-    let listener = tokio::net::TcpListener::bind(addr).await?;
-    axum::serve(listener, app).await?;
+async fn ping_watchdog(interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        if let Err(err) = notify::watchdog() {
+            warn!("systemd watchdog ping failed: {err}");
+        }
+    }
+}
 
-    Ok(())
+/// Resolve on SIGINT or SIGTERM, telling systemd that shutdown began.
+async fn shutdown_signal() {
+    let interrupt = async {
+        if let Err(err) = tokio::signal::ctrl_c().await {
+            warn!("cannot listen for SIGINT: {err}");
+            std::future::pending::<()>().await;
+        }
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(err) => {
+                warn!("cannot listen for SIGTERM: {err}");
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        () = interrupt => {}
+        () = terminate => {}
+    }
+    info!("shutting down; finishing in-flight requests");
+    if let Err(err) = notify::stopping() {
+        warn!("cannot notify systemd of shutdown: {err}");
+    }
 }
 
 #[derive(Debug, Parser)]
@@ -65,7 +185,7 @@ struct CommonOpts {
     #[arg(long, value_name = "PATH")]
     config: Option<PathBuf>,
 
-    /// Port to listen on
+    /// Port to listen on (ignored when systemd passes a socket)
     #[arg(short, long, default_value = "3000")]
     port: u16,
 }
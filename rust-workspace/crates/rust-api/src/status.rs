@@ -0,0 +1,379 @@
+//! The status page served at `/status` when `[daemon.http] enabled = true`.
+//!
+//! One self-contained HTML page shows a summary of the config (secrets
+//! masked), the most recent runs from the run history, and the tail of
+//! `logging.file`, which it keeps following through server-sent events from
+//! `/status/log`. Both routes answer only clients on this machine that
+//! present the token, as `?token=` (what the page itself uses) or an
+//! `Authorization: Bearer` header; the token is `daemon.http.token`, or one
+//! made up at start and written to `status.token` in the state directory.
+
+use std::collections::{HashMap, VecDeque};
+use std::convert::Infallible;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use axum::extract::{ConnectInfo, Query, State};
+use axum::http::{HeaderMap, StatusCode, header};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{Html, IntoResponse, Response};
+use axum::{Router, routing::get};
+use futures_util::stream::{self, Stream};
+use tokio::io::{AsyncReadExt as _, AsyncSeekExt as _};
+
+use rust_core::config::{self, AppConfig};
+use rust_core::paths::expand_str_path;
+use rust_core::{RunRecord, flat, history};
+
+/// File in the state directory holding a generated token.
+pub const TOKEN_FILENAME: &str = "status.token";
+
+/// How often the log stream checks the file for new lines.
+const LOG_POLL: Duration = Duration::from_millis(500);
+
+/// Lines of the log shown when the page opens.
+const LOG_BACKLOG: usize = 50;
+
+/// Bytes read from the end of the log for the first lines.
+const LOG_BACKLOG_BYTES: u64 = 64 * 1024;
+
+/// What the status routes need.
+#[derive(Clone)]
+struct Status {
+    config: Arc<AppConfig>,
+    config_file: Arc<Path>,
+    state_dir: Arc<Path>,
+    log_file: Option<Arc<Path>>,
+    token: Arc<str>,
+}
+
+/// The `/status` routes, guarded by `token`.
+pub fn routes(
+    config: Arc<AppConfig>,
+    config_file: &Path,
+    state_dir: &Path,
+    token: String,
+) -> Router {
+    let log_file = config
+        .logging
+        .file
+        .as_deref()
+        .and_then(|file| expand_str_path(file).ok())
+        .map(Arc::from);
+    let status = Status {
+        config,
+        config_file: Arc::from(config_file),
+        state_dir: Arc::from(state_dir),
+        log_file,
+        token: Arc::from(token),
+    };
+    Router::new()
+        .route("/status", get(page))
+        .route("/status/log", get(log))
+        .with_state(status)
+}
+
+/// `daemon.http.token`, or a new random token written to
+/// [`TOKEN_FILENAME`] in `state_dir` for the user to read.
+///
+/// # Errors
+///
+/// Returns an error if no random bytes are available or the file cannot
+/// be written.
+pub fn token(config: &AppConfig, state_dir: &Path) -> Result<String> {
+    if let Some(token) = &config.daemon.http.token {
+        return Ok(token.clone());
+    }
+    let mut bytes = [0_u8; 16];
+    getrandom::fill(&mut bytes).map_err(|err| anyhow::anyhow!("generating a token: {err}"))?;
+    let token: String = bytes.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{byte:02x}");
+        hex
+    });
+    let path = state_dir.join(TOKEN_FILENAME);
+    fs::create_dir_all(state_dir).with_context(|| format!("creating {}", state_dir.display()))?;
+    write_private(&path, &token).with_context(|| format!("writing {}", path.display()))?;
+    Ok(token)
+}
+
+/// Write `text` to `path`, readable by this user only.
+#[cfg(unix)]
+fn write_private(path: &Path, text: &str) -> io::Result<()> {
+    use std::io::Write as _;
+    use std::os::unix::fs::OpenOptionsExt as _;
+    // Replaced rather than truncated, so a file someone else could read
+    // does not keep its permissions.
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+        _ => {}
+    }
+    fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(path)?
+        .write_all(text.as_bytes())
+}
+
+/// Write `text` to `path`; the state directory is already private to the
+/// user on Windows.
+#[cfg(not(unix))]
+fn write_private(path: &Path, text: &str) -> io::Result<()> {
+    fs::write(path, text)
+}
+
+/// Whether the request comes from this machine and carries the token.
+fn authorized(
+    status: &Status,
+    peer: SocketAddr,
+    headers: &HeaderMap,
+    query: &HashMap<String, String>,
+) -> bool {
+    let bearer = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    let presented = bearer.or_else(|| query.get("token").map(String::as_str));
+    peer.ip().is_loopback()
+        && presented.is_some_and(|token| config::token_matches(token, &status.token))
+}
+
+fn refuse() -> Response {
+    (
+        StatusCode::UNAUTHORIZED,
+        "status page: pass the token from daemon.http.token or status.token as ?token=\n",
+    )
+        .into_response()
+}
+
+async fn page(
+    State(status): State<Status>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    Query(query): Query<HashMap<String, String>>,
+) -> Response {
+    if !authorized(&status, peer, &headers, &query) {
+        return refuse();
+    }
+    // History and config rendering read files; keep them off the runtime.
+    match tokio::task::spawn_blocking(move || render_page(&status)).await {
+        Ok(html) => Html(html).into_response(),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+}
+
+/// The whole page.
+fn render_page(status: &Status) -> String {
+    let config = &status.config;
+    let mut html = String::from(concat!(
+        "<!doctype html>\n<html><head><meta charset=\"utf-8\">",
+        "<title>",
+        env!("CARGO_PKG_NAME"),
+        " status</title>\n<style>",
+        "body{font-family:sans-serif;margin:2em;max-width:70em}",
+        "table{border-collapse:collapse}td,th{padding:.2em .8em;text-align:left}",
+        "pre{background:#f4f4f4;padding:.8em;overflow:auto;max-height:30em}",
+        ".failed{color:#b00}",
+        "</style></head><body>\n<h1>",
+        env!("CARGO_PKG_NAME"),
+        " ",
+        env!("CARGO_PKG_VERSION"),
+        "</h1>\n"
+    ));
+
+    html.push_str("<h2>Config</h2>\n<table>\n");
+    let log_file = status
+        .log_file
+        .as_deref()
+        .map_or_else(|| "not set".to_string(), |file| file.display().to_string());
+    for (name, value) in [
+        ("file", status.config_file.display().to_string()),
+        ("profile", config.profile.clone()),
+        ("log level", config.logging.level.to_string()),
+        ("log file", log_file),
+        ("tasks", config.tasks.len().to_string()),
+    ] {
+        let _ = writeln!(html, "<tr><th>{name}</th><td>{}</td></tr>", escape(&value));
+    }
+    html.push_str("</table>\n");
+    let settings = config::redacted(config)
+        .and_then(|settings| flat::render(&settings))
+        .unwrap_or_else(|err| format!("cannot show the settings: {err}\n"));
+    let _ = writeln!(
+        html,
+        "<details><summary>All settings</summary><pre>{}</pre></details>",
+        escape(&settings)
+    );
+
+    html.push_str("<h2>Recent runs</h2>\n");
+    match history::list(&status.state_dir) {
+        Ok(runs) if runs.is_empty() => html.push_str("<p>No runs recorded yet.</p>\n"),
+        Ok(runs) => {
+            html.push_str(
+                "<table>\n<tr><th>#</th><th>task</th><th>started</th><th>took</th><th>exit</th></tr>\n",
+            );
+            for run in runs.iter().rev().take(config.daemon.http.recent_runs) {
+                html.push_str(&run_row(run));
+            }
+            html.push_str("</table>\n");
+        }
+        Err(err) => {
+            let _ = writeln!(html, "<p class=\"failed\">{}</p>", escape(&err.to_string()));
+        }
+    }
+
+    html.push_str("<h2>Log</h2>\n");
+    if status.log_file.is_some() {
+        // The token goes into a script: as a JSON string, with `<` escaped
+        // so it cannot close the element.
+        let token = serde_json::to_string(&*status.token)
+            .unwrap_or_default()
+            .replace('<', "\\u003c");
+        let _ = write!(
+            html,
+            concat!(
+                "<pre id=\"log\"></pre>\n<script>\n",
+                "const log = document.getElementById(\"log\");\n",
+                "const events = new EventSource(\"/status/log?token=\" + encodeURIComponent({token}));\n",
+                "events.onmessage = (event) => {{\n",
+                "  log.textContent += event.data + \"\\n\";\n",
+                "  log.scrollTop = log.scrollHeight;\n",
+                "}};\n</script>\n"
+            ),
+            token = token
+        );
+    } else {
+        html.push_str("<p>Set <code>logging.file</code> to follow the log here.</p>\n");
+    }
+    html.push_str("</body></html>\n");
+    html
+}
+
+/// One row of the recent runs table.
+fn run_row(run: &RunRecord) -> String {
+    let started =
+        humantime::format_rfc3339_seconds(UNIX_EPOCH + Duration::from_secs(run.started_at));
+    let took = run.finished_at.saturating_sub(run.started_at);
+    let class = if run.exit_code == 0 {
+        ""
+    } else {
+        " class=\"failed\""
+    };
+    format!(
+        "<tr{class}><td>{}</td><td>{}</td><td>{started}</td><td>{took}s</td><td>{}</td></tr>\n",
+        run.id,
+        escape(&run.task),
+        run.exit_code
+    )
+}
+
+/// `text` safe to put in HTML content.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            other => escaped.push(other),
+        }
+    }
+    escaped
+}
+
+async fn log(
+    State(status): State<Status>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    Query(query): Query<HashMap<String, String>>,
+) -> Response {
+    if !authorized(&status, peer, &headers, &query) {
+        return refuse();
+    }
+    let Some(path) = status.log_file.as_deref().map(Path::to_path_buf) else {
+        return (StatusCode::NOT_FOUND, "logging.file is not set\n").into_response();
+    };
+    Sse::new(follow(path))
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+/// Where [`follow`] is in the file, and lines read but not yet sent.
+struct Tail {
+    path: PathBuf,
+    offset: Option<u64>,
+    lines: VecDeque<String>,
+}
+
+/// The last lines of the log at `path`, then each line appended to it, as
+/// events. A file that is truncated or replaced is read again from the
+/// start.
+fn follow(path: PathBuf) -> impl Stream<Item = Result<Event, Infallible>> {
+    let tail = Tail {
+        path,
+        offset: None,
+        lines: VecDeque::new(),
+    };
+    stream::unfold(tail, |mut tail| async move {
+        loop {
+            if let Some(line) = tail.lines.pop_front() {
+                return Some((Ok(Event::default().data(line)), tail));
+            }
+            if tail.offset.is_some() {
+                tokio::time::sleep(LOG_POLL).await;
+            }
+            if let Err(err) = tail.read().await {
+                log::debug!("status page: reading {}: {err}", tail.path.display());
+            }
+        }
+    })
+}
+
+impl Tail {
+    /// Queue the complete lines written since the last read; the first read
+    /// queues the last [`LOG_BACKLOG`] lines.
+    async fn read(&mut self) -> io::Result<()> {
+        let mut file = match tokio::fs::File::open(&self.path).await {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                self.offset = Some(0);
+                return Ok(());
+            }
+            Err(err) => return Err(err),
+        };
+        let len = file.metadata().await?.len();
+        let first = self.offset.is_none();
+        let start = match self.offset {
+            Some(offset) if offset <= len => offset,
+            Some(_) => 0,
+            None => len.saturating_sub(LOG_BACKLOG_BYTES),
+        };
+        file.seek(io::SeekFrom::Start(start)).await?;
+        let mut bytes = Vec::new();
+        file.take(len - start).read_to_end(&mut bytes).await?;
+        // Only whole lines; the rest is read again once it is finished.
+        let end = bytes
+            .iter()
+            .rposition(|&byte| byte == b'\n')
+            .map_or(0, |at| at + 1);
+        self.offset = Some(start + end as u64);
+        let text = String::from_utf8_lossy(&bytes[..end]);
+        let mut lines: Vec<&str> = text.lines().collect();
+        if first {
+            if start > 0 && !lines.is_empty() {
+                // Begun mid-line.
+                lines.remove(0);
+            }
+            lines.drain(..lines.len().saturating_sub(LOG_BACKLOG));
+        }
+        self.lines.extend(lines.into_iter().map(str::to_string));
+        Ok(())
+    }
+}
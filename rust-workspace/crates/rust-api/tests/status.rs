@@ -0,0 +1,115 @@
+//! The `/status` page: it answers only requests carrying the token, and
+//! shows the runs recorded in the state directory the config points at.
+
+use std::io::{BufRead as _, BufReader};
+use std::process::{Child, Command, Stdio};
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context as _, Result, ensure};
+use rust_core::{HistoryConfig, RunRecord, TempScope, history};
+
+/// The API server on a port of its own; dropping it kills the server.
+struct ApiServer {
+    child: Child,
+    url: String,
+}
+
+impl ApiServer {
+    /// Start the server with the config file `config` and wait until it
+    /// listens.
+    fn start(dir: &TempScope, config: &str) -> Result<Self> {
+        let config_file = dir.path().join("config.toml");
+        std::fs::write(&config_file, config)?;
+        let mut child = Command::new(env!("CARGO_BIN_EXE_rust-api"))
+            .arg("--config")
+            .arg(&config_file)
+            .args(["--port", "0"])
+            .env("RUST_WORKSPACE_DATA_DIR", dir.path().join("data"))
+            .env("RUST_WORKSPACE_CACHE_DIR", dir.path().join("cache"))
+            .env("RUST_LOG", "rust_api=info")
+            .stderr(Stdio::piped())
+            .spawn()
+            .context("spawning rust-api")?;
+        let stderr = child.stderr.take().context("rust-api has no stderr")?;
+        let mut lines = BufReader::new(stderr).lines().map_while(Result::ok);
+        let url = lines
+            .find_map(|line| {
+                let (_, rest) = line.split_once("status page at ")?;
+                Some(rest.split_once('?')?.0.to_string())
+            })
+            .context("rust-api did not say where the status page is")?;
+        // Keep reading, so logging never blocks on or fails at a full pipe.
+        thread::spawn(move || lines.for_each(drop));
+        Ok(Self { child, url })
+    }
+
+    /// GET the status page with `token` as `?token=`, and as a bearer
+    /// header when `bearer` is set. Returns the status and the body.
+    fn get(&self, token: Option<&str>, bearer: bool) -> Result<(u16, String)> {
+        let agent: ureq::Agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .build()
+            .into();
+        let mut request = agent.get(&self.url);
+        if let Some(token) = token {
+            request = if bearer {
+                request.header("Authorization", format!("Bearer {token}"))
+            } else {
+                request.query("token", token)
+            };
+        }
+        let mut response = request.call()?;
+        let status = response.status().as_u16();
+        Ok((status, response.body_mut().read_to_string()?))
+    }
+}
+
+impl Drop for ApiServer {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+fn the_status_page_needs_the_token() -> Result<()> {
+    let dir = TempScope::new_in(&std::env::temp_dir().join("rust-api-tests"))?;
+    // Not the default state directory: the page must read this one.
+    let state_dir = dir.path().join("configured-state");
+    let finished_at = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let run = RunRecord {
+        id: 0,
+        correlation_id: String::new(),
+        task: "nightly-build".to_string(),
+        profile: "default".to_string(),
+        args: vec!["run".to_string(), "nightly-build".to_string()],
+        started_at: finished_at - 3,
+        finished_at,
+        exit_code: 0,
+        tasks: Vec::new(),
+    };
+    history::record(&state_dir, run, &HistoryConfig::default())?;
+    let server = ApiServer::start(
+        &dir,
+        &format!(
+            "[paths]\nstate_dir = {:?}\n\n[daemon.http]\nenabled = true\ntoken = \"status-token\"\n",
+            state_dir.to_str().context("scratch path is not UTF-8")?
+        ),
+    )?;
+
+    for (token, bearer) in [
+        (None, false),
+        (Some("wrong-token"), false),
+        (Some("status-token-but-longer"), true),
+    ] {
+        let (status, _) = server.get(token, bearer)?;
+        ensure!(status == 401, "{token:?}: {status}");
+    }
+    for bearer in [false, true] {
+        let (status, page) = server.get(Some("status-token"), bearer)?;
+        ensure!(status == 200, "bearer {bearer}: {status}");
+        ensure!(page.contains("nightly-build"), "{page}");
+    }
+    Ok(())
+}
@@ -0,0 +1,187 @@
+//! Command aliases from the `[aliases]` config table.
+//!
+//! [`expand`] runs before clap parses the command line: when the first
+//! positional argument is not a built-in command but names an alias, it is
+//! replaced by the alias's arguments (see [`rust_core::alias`]). Global flags
+//! in front of it are kept and decide which config file is read, so
+//! `--config other.toml st` uses `other.toml`'s aliases. Built-in commands
+//! never read the config here.
+
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::fmt::Write as _;
+
+use anyhow::Result;
+use clap::{Arg, CommandFactory as _, Parser, Subcommand};
+use serde::Serialize;
+
+use rust_core::{AppConfig, AppConfigExt as _, AppPaths, alias};
+
+use crate::output::{Output, Report};
+use crate::{APP_NAME, Cli, CommonOpts, RuntimeContext};
+
+#[derive(Debug, Clone, Copy, Subcommand)]
+pub enum AliasCommand {
+    /// List the aliases defined in the config and what they expand to
+    List,
+}
+
+/// The global flags before the command, parsed on their own.
+#[derive(Parser)]
+#[command(name = APP_NAME)]
+struct Globals {
+    #[command(flatten)]
+    common: CommonOpts,
+}
+
+/// `args` (starting with the program name) with an alias in command
+/// position expanded. Anything that is not an alias is returned unchanged
+/// for clap to accept or reject.
+///
+/// # Errors
+///
+/// Returns an error if the config holding the aliases cannot be loaded, or
+/// an alias is malformed or part of a cycle.
+pub fn expand(args: Vec<OsString>) -> Result<Vec<OsString>> {
+    let mut cmd = Cli::command();
+    cmd.build();
+    let Some(index) = command_position(&cmd, &args) else {
+        return Ok(args);
+    };
+    let Some(name) = args.get(index).and_then(|name| name.to_str()) else {
+        return Ok(args);
+    };
+    let is_builtin = |name: &str| cmd.find_subcommand(name).is_some();
+    if is_builtin(name) {
+        return Ok(args);
+    }
+    let Some(common) = leading_globals(&args, index) else {
+        return Ok(args);
+    };
+    let aliases = read_aliases(&common)?;
+    let Some(words) = alias::resolve(&aliases, name, is_builtin)? else {
+        return Ok(args);
+    };
+    let mut expanded: Vec<OsString> = args.iter().take(index).cloned().collect();
+    expanded.extend(words.into_iter().map(OsString::from));
+    expanded.extend(args.iter().skip(index + 1).cloned());
+    Ok(expanded)
+}
+
+/// The global flags in front of the command at `index`, or `None` if clap
+/// would reject them anyway.
+pub fn leading_globals(args: &[OsString], index: usize) -> Option<CommonOpts> {
+    Globals::try_parse_from(args.iter().take(index))
+        .ok()
+        .map(|globals| globals.common)
+}
+
+/// Index of the first positional argument, skipping global flags and their
+/// values; `None` if there is none before `--`. `cmd` must be built.
+pub fn command_position(cmd: &clap::Command, args: &[OsString]) -> Option<usize> {
+    let takes_value = |matches: &dyn Fn(&Arg) -> bool| {
+        cmd.get_arguments()
+            .any(|arg| matches(arg) && arg.get_action().takes_values())
+    };
+    let mut index = 1;
+    while let Some(arg) = args.get(index) {
+        let text = arg.to_str()?;
+        if text == "--" {
+            return None;
+        }
+        let skip = if let Some(long) = text.strip_prefix("--") {
+            let separate_value = !long.contains('=')
+                && takes_value(&|arg: &Arg| {
+                    arg.get_long() == Some(long)
+                        || arg
+                            .get_all_aliases()
+                            .is_some_and(|aliases| aliases.contains(&long))
+                });
+            if separate_value { 2 } else { 1 }
+        } else if let Some(shorts) = text.strip_prefix('-').filter(|shorts| !shorts.is_empty()) {
+            // A short flag that takes a value swallows the rest of the
+            // cluster, or the next argument when it comes last.
+            let position = shorts
+                .char_indices()
+                .find(|&(_, short)| takes_value(&|arg: &Arg| arg.get_short() == Some(short)));
+            match position {
+                Some((at, short)) if at + short.len_utf8() == shorts.len() => 2,
+                _ => 1,
+            }
+        } else {
+            return Some(index);
+        };
+        index += skip;
+    }
+    None
+}
+
+/// The `[aliases]` table of the config the global flags select, read
+/// without creating a default file.
+fn read_aliases(common: &CommonOpts) -> Result<BTreeMap<String, String>> {
+    let config = if common.skips_config_file() {
+        AppConfig::from_env()?
+    } else {
+        let paths = AppPaths::discover_in(common.path_mode(), common.config.as_deref())?;
+        AppConfig::load_from_path(&paths.config_file)?
+    };
+    Ok(config.aliases)
+}
+
+pub fn handle_alias(ctx: &RuntimeContext, command: AliasCommand) -> Result<Output> {
+    match command {
+        AliasCommand::List => {
+            let mut cmd = Cli::command();
+            cmd.build();
+            Output::new(&AliasList(
+                ctx.config
+                    .aliases
+                    .iter()
+                    .map(|(name, expansion)| AliasEntry {
+                        name: name.clone(),
+                        expansion: expansion.clone(),
+                        shadowed: cmd.find_subcommand(name).is_some(),
+                    })
+                    .collect(),
+            ))
+        }
+    }
+}
+
+/// One alias in `alias list`.
+#[derive(Debug, Serialize)]
+struct AliasEntry {
+    name: String,
+    expansion: String,
+    /// A built-in command has the same name, so the alias is never used.
+    shadowed: bool,
+}
+
+/// Result of `alias list`.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+struct AliasList(Vec<AliasEntry>);
+
+impl Report for AliasList {
+    fn text(&self) -> String {
+        if self.0.is_empty() {
+            return "no aliases defined; add them under [aliases] in the config file\n".into();
+        }
+        let width = self
+            .0
+            .iter()
+            .map(|entry| entry.name.len())
+            .max()
+            .unwrap_or_default();
+        let mut out = String::new();
+        for entry in &self.0 {
+            let note = if entry.shadowed {
+                "  (shadowed by the built-in command)"
+            } else {
+                ""
+            };
+            let _ = writeln!(out, "{:<width$}  {}{note}", entry.name, entry.expansion);
+        }
+        out
+    }
+}
@@ -0,0 +1,155 @@
+//! `backup` subcommands: archive, verify, and restore the app directories.
+
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use anyhow::Result;
+use clap::{Args, Subcommand};
+use serde::Serialize;
+
+use rust_core::backup::{self, BackupOptions};
+use rust_core::{Action, BackupManifest, RestoreReport};
+
+use crate::output::{Output, Report};
+use crate::{APP_NAME, RuntimeContext, format_bytes};
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum BackupCommand {
+    /// Archive the config and state (and optionally data) directories
+    Create(CreateCommand),
+    /// Verify an archive and restore its files into place
+    Restore(ArchiveArg),
+    /// Check every file in an archive against its manifest
+    Verify(ArchiveArg),
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct CreateCommand {
+    /// Archive to write (default: ./<app>-backup-<timestamp>.tar.zst)
+    #[arg(short, long, value_name = "FILE")]
+    file: Option<PathBuf>,
+    /// Include the data directory
+    #[arg(long = "include-data")]
+    include_data: bool,
+    /// Leave out archive paths matching GLOB (e.g. 'state/*.log'); repeatable,
+    /// added to `backup.exclude`
+    #[arg(long, value_name = "GLOB")]
+    exclude: Vec<String>,
+    /// zstd compression level (1-22; overrides `backup.compression_level`)
+    #[arg(long, value_name = "LEVEL", value_parser = clap::value_parser!(i32).range(1..=22))]
+    level: Option<i32>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct ArchiveArg {
+    /// Backup archive (.tar.zst)
+    #[arg(value_name = "FILE")]
+    file: PathBuf,
+}
+
+pub fn handle_backup(ctx: &RuntimeContext, command: BackupCommand) -> Result<Output> {
+    match command {
+        BackupCommand::Create(cmd) => {
+            let file = cmd.file.unwrap_or_else(default_archive_name);
+            let mut exclude = ctx.config.backup.exclude.clone();
+            exclude.extend(cmd.exclude);
+            let options = BackupOptions {
+                include_data: cmd.include_data,
+                level: cmd.level.unwrap_or(ctx.config.backup.compression_level),
+                exclude,
+            };
+            if ctx.op.dry_run() {
+                ctx.op.plan(Action::WriteFile { path: file });
+                return Output::new(&ArchiveReport {
+                    manifest: None,
+                    summary: String::new(),
+                });
+            }
+            let manifest = backup::create(&ctx.paths, &file, &options, &ctx.op)?;
+            Output::new(&ArchiveReport {
+                manifest: Some(manifest),
+                summary: format!("backed up to {}", file.display()),
+            })
+        }
+        BackupCommand::Verify(arg) => Output::new(&ArchiveReport {
+            manifest: Some(backup::verify(&arg.file, &ctx.op)?),
+            summary: format!("{} is intact", arg.file.display()),
+        }),
+        BackupCommand::Restore(arg) => Output::new(&RestoreResult {
+            report: backup::restore(&ctx.paths, &arg.file, ctx.common.assume_yes, &ctx.op)?,
+            dry_run: ctx.op.dry_run(),
+        }),
+    }
+}
+
+/// `<app>-backup-20260102T030405Z.tar.zst` in the current directory.
+fn default_archive_name() -> PathBuf {
+    let stamp: String = humantime::format_rfc3339_seconds(SystemTime::now())
+        .to_string()
+        .chars()
+        .filter(|c| !matches!(c, '-' | ':'))
+        .collect();
+    PathBuf::from(format!("{APP_NAME}-backup-{stamp}.tar.zst"))
+}
+
+/// Result of `backup create` and `backup verify`: the archive's manifest
+/// (none under `--dry-run`).
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+struct ArchiveReport {
+    manifest: Option<BackupManifest>,
+    #[serde(skip)]
+    summary: String,
+}
+
+impl Report for ArchiveReport {
+    fn text(&self) -> String {
+        let Some(manifest) = &self.manifest else {
+            return String::new();
+        };
+        let dirs: Vec<&str> = manifest.dirs.iter().map(|kind| kind.name()).collect();
+        format!(
+            "{}: {} files, {} ({})\n",
+            self.summary,
+            manifest.files.len(),
+            format_bytes(manifest.total_bytes()),
+            dirs.join(", ")
+        )
+    }
+}
+
+/// Result of `backup restore`.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+struct RestoreResult {
+    report: RestoreReport,
+    #[serde(skip)]
+    dry_run: bool,
+}
+
+impl Report for RestoreResult {
+    fn text(&self) -> String {
+        let verb = if self.dry_run {
+            "would restore"
+        } else {
+            "restored"
+        };
+        let mut out = String::new();
+        for path in &self.report.restored {
+            let note = if self.report.overwritten.contains(path) {
+                " (replaced)"
+            } else {
+                ""
+            };
+            let _ = writeln!(out, "{verb} {}{note}", path.display());
+        }
+        let _ = writeln!(
+            out,
+            "{verb} {} files, {} replaced",
+            self.report.restored.len(),
+            self.report.overwritten.len()
+        );
+        out
+    }
+}
@@ -0,0 +1,173 @@
+//! `cache` subcommands: inspect, clear, and repair the content cache.
+
+use std::fmt::Write as _;
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+use clap::{Args, Subcommand};
+use serde::Serialize;
+
+use rust_core::{CacheEntry, CacheReport, CacheStats, ClearFilter};
+
+use crate::RuntimeContext;
+use crate::output::{Output, Report};
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum CacheCommand {
+    /// Show size, entry counts, and hit/miss counters per namespace
+    Stats,
+    /// Remove entries selected by namespace, age, and key pattern
+    Clear(ClearCommand),
+    /// Check the cache index and every entry's checksum
+    Verify,
+    /// Drop corrupt index records, damaged entries, and orphaned files
+    Repair,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct ClearCommand {
+    /// Only clear entries in this namespace (the key prefix before `/`)
+    #[arg(long, value_name = "NAME")]
+    namespace: Option<String>,
+    /// Only clear entries stored longer ago than AGE (e.g. 30m, 12h, 7d)
+    #[arg(long = "older-than", value_name = "AGE", value_parser = humantime::parse_duration)]
+    older_than: Option<Duration>,
+    /// Only clear keys matching GLOB (`*` and `?` wildcards)
+    #[arg(long, value_name = "GLOB")]
+    pattern: Option<String>,
+}
+
+pub fn handle_cache(ctx: &RuntimeContext, command: CacheCommand) -> Result<Output> {
+    let cache = ctx.paths.cache();
+    match command {
+        CacheCommand::Stats => Output::new(&StatsReport(cache.stats()?)),
+        CacheCommand::Clear(cmd) => {
+            let filter = ClearFilter {
+                namespace: cmd.namespace,
+                older_than: cmd.older_than,
+                pattern: cmd.pattern,
+            };
+            let cleared = cache.clear(&filter, &ctx.op)?;
+            Output::new(&ClearReport {
+                bytes: cleared.iter().map(|entry| entry.size).sum(),
+                cleared,
+                dry_run: ctx.op.dry_run(),
+            })
+        }
+        CacheCommand::Verify => {
+            let report = cache.verify(&ctx.op)?;
+            let clean = report.is_clean();
+            let output = Output::new(&CheckReport {
+                report,
+                verb: "found",
+            })?;
+            if clean {
+                Ok(output)
+            } else {
+                Ok(output.then_fail(anyhow!(
+                    "cache at {} has problems; run `cache repair` to fix them",
+                    cache.root().display()
+                )))
+            }
+        }
+        CacheCommand::Repair => Output::new(&CheckReport {
+            report: cache.repair(&ctx.op)?,
+            verb: if ctx.op.dry_run() {
+                "would fix"
+            } else {
+                "fixed"
+            },
+        }),
+    }
+}
+
+/// Result of `cache stats`.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+struct StatsReport(CacheStats);
+
+impl Report for StatsReport {
+    fn text(&self) -> String {
+        let stats = &self.0;
+        let mut out = format!(
+            "entries: {}  payload: {} bytes  index: {} bytes  hits: {}  misses: {}\n",
+            stats.entries,
+            stats.payload_bytes,
+            stats.index_bytes,
+            stats.lookups.hits,
+            stats.lookups.misses
+        );
+        for (name, namespace) in &stats.namespaces {
+            let _ = writeln!(
+                out,
+                "  {name}: {} entries, {} bytes, {} hits, {} misses",
+                namespace.entries,
+                namespace.bytes,
+                namespace.lookups.hits,
+                namespace.lookups.misses
+            );
+        }
+        out
+    }
+}
+
+/// Result of `cache clear`.
+#[derive(Debug, Serialize)]
+struct ClearReport {
+    cleared: Vec<CacheEntry>,
+    bytes: u64,
+    dry_run: bool,
+}
+
+impl Report for ClearReport {
+    fn text(&self) -> String {
+        let verb = if self.dry_run {
+            "would clear"
+        } else {
+            "cleared"
+        };
+        let mut out = String::new();
+        for entry in &self.cleared {
+            let _ = writeln!(out, "{verb} {}", entry.key);
+        }
+        let _ = writeln!(out, "{verb} {} entries", self.cleared.len());
+        out
+    }
+}
+
+/// Result of `cache verify` and `cache repair`.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+struct CheckReport {
+    report: CacheReport,
+    #[serde(skip)]
+    verb: &'static str,
+}
+
+impl Report for CheckReport {
+    fn text(&self) -> String {
+        let (report, verb) = (&self.report, self.verb);
+        let mut out = format!("entries: {}\n", report.entries);
+        if report.is_clean() {
+            out.push_str("cache is healthy\n");
+            return out;
+        }
+        if report.corrupt_records > 0 {
+            let _ = writeln!(
+                out,
+                "{verb} {} corrupt index records",
+                report.corrupt_records
+            );
+        }
+        for key in &report.missing {
+            let _ = writeln!(out, "{verb} missing entry: {key}");
+        }
+        for key in &report.damaged {
+            let _ = writeln!(out, "{verb} damaged entry: {key}");
+        }
+        for path in &report.orphaned {
+            let _ = writeln!(out, "{verb} orphaned file: {}", path.display());
+        }
+        out
+    }
+}
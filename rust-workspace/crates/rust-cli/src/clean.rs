@@ -0,0 +1,174 @@
+//! `clean`: remove the cache, log file, run history, leftovers of
+//! interrupted operations, or the rest of the state, by category (see
+//! [`rust_core::clean`]).
+//!
+//! The size of each selected category is shown before anything is removed,
+//! and removal needs a `y` at the prompt or `--yes`; without a terminal (or
+//! with `--no-input`) it fails instead of asking. Paths this user may not
+//! remove are reported as skipped rather than failing the command.
+
+use std::fmt::Write as _;
+use std::io::{self, BufRead as _, IsTerminal as _, Write as _};
+
+use anyhow::{Context, Result, bail};
+use clap::{ArgGroup, Args};
+use log::info;
+use serde::Serialize;
+
+use rust_core::clean::{self, Category, Target};
+
+use crate::output::{Output, Report};
+use crate::{RuntimeContext, format_bytes};
+
+#[derive(Debug, Clone, Args)]
+#[command(group(ArgGroup::new("category").required(true).multiple(true)))]
+pub struct CleanCommand {
+    /// Remove every category below
+    #[arg(long, group = "category")]
+    all: bool,
+    /// Cache entries, index, and counters
+    #[arg(long, group = "category")]
+    cache: bool,
+    /// The log file named by `logging.file`
+    #[arg(long, group = "category")]
+    logs: bool,
+    /// Run history and the last outcome of each task
+    #[arg(long, group = "category")]
+    runs: bool,
+    /// Temporary directories and partial files left by interrupted operations
+    #[arg(long, group = "category")]
+    artifacts: bool,
+    /// Everything else in the state directory, such as the journal
+    #[arg(long, group = "category")]
+    state: bool,
+}
+
+impl CleanCommand {
+    fn categories(&self) -> Vec<Category> {
+        Category::ALL
+            .into_iter()
+            .filter(|category| {
+                self.all
+                    || match category {
+                        Category::Cache => self.cache,
+                        Category::Logs => self.logs,
+                        Category::Runs => self.runs,
+                        Category::Artifacts => self.artifacts,
+                        Category::State => self.state,
+                    }
+            })
+            .collect()
+    }
+}
+
+pub fn handle_clean(ctx: &RuntimeContext, cmd: &CleanCommand) -> Result<Output> {
+    let log_file = ctx.config.logging.file.as_deref().map(std::path::Path::new);
+    let mut targets = clean::survey(&ctx.paths, log_file, &cmd.categories())?;
+    let pending = targets.iter().any(|target| !target.is_empty());
+    let removed = if !pending || ctx.op.dry_run() {
+        clean::clean(&mut targets, &ctx.op)?;
+        false
+    } else if confirm(ctx, &targets)? {
+        clean::clean(&mut targets, &ctx.op)?;
+        true
+    } else {
+        false
+    };
+    Output::new(&CleanReport {
+        removed,
+        dry_run: ctx.op.dry_run(),
+        categories: targets,
+    })
+}
+
+/// Show what would be removed and ask; `--yes` answers for the user.
+fn confirm(ctx: &RuntimeContext, targets: &[Target]) -> Result<bool> {
+    let total: u64 = targets.iter().map(|target| target.bytes).sum();
+    if ctx.common.assume_yes {
+        for target in targets.iter().filter(|target| !target.is_empty()) {
+            info!(
+                "removing {}: {}",
+                target.category,
+                format_bytes(target.bytes)
+            );
+        }
+        return Ok(true);
+    }
+    if ctx.common.no_input || !io::stdin().is_terminal() {
+        bail!(
+            "clean would remove {}; pass --yes to remove it without asking",
+            format_bytes(total)
+        );
+    }
+    let mut stderr = io::stderr().lock();
+    write!(
+        stderr,
+        "{}Remove {}? [y/N] ",
+        summary(targets),
+        format_bytes(total)
+    )
+    .and_then(|()| stderr.flush())
+    .context("writing to stderr")?;
+    let mut answer = String::new();
+    io::stdin()
+        .lock()
+        .read_line(&mut answer)
+        .context("reading the answer")?;
+    Ok(matches!(answer.trim(), "y" | "Y" | "yes" | "Yes"))
+}
+
+/// One line per category: size and number of paths.
+fn summary(targets: &[Target]) -> String {
+    let mut out = String::new();
+    for target in targets {
+        let _ = write!(
+            out,
+            "  {:<10} {:>10}  {} paths",
+            target.category,
+            format_bytes(target.bytes),
+            target.paths.len()
+        );
+        if !target.skipped.is_empty() {
+            let _ = write!(out, ", {} skipped", target.skipped.len());
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Result of `clean`.
+#[derive(Debug, Serialize)]
+struct CleanReport {
+    /// Whether the paths were removed (false on a dry run or when declined).
+    removed: bool,
+    dry_run: bool,
+    /// What each category held, and so was (or would be) removed.
+    categories: Vec<Target>,
+}
+
+impl Report for CleanReport {
+    fn text(&self) -> String {
+        let total: u64 = self.categories.iter().map(|target| target.bytes).sum();
+        let mut out = summary(&self.categories);
+        let _ = if self.dry_run {
+            writeln!(out, "would remove {}", format_bytes(total))
+        } else if self.removed {
+            writeln!(out, "removed {}", format_bytes(total))
+        } else if self.categories.iter().all(Target::is_empty) {
+            writeln!(out, "nothing to remove")
+        } else {
+            writeln!(out, "nothing removed")
+        };
+        for target in &self.categories {
+            for skipped in &target.skipped {
+                let _ = writeln!(
+                    out,
+                    "skipped {}: {}",
+                    skipped.path.display(),
+                    skipped.reason
+                );
+            }
+        }
+        out
+    }
+}
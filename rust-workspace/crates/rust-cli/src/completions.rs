@@ -0,0 +1,248 @@
+//! Shell completion scripts, with values that are only known at run time.
+//!
+//! `clap_complete` renders static scripts: subcommands, flags, and fixed value
+//! lists. Task and profile names depend on the configuration, so the bash,
+//! zsh, and fish scripts are extended to ask the binary while completing:
+//! run with `<PREFIX>_COMPLETE=tasks` (or `profiles`) in its environment, it
+//! prints the candidates one per line and exits before parsing arguments,
+//! like `clap_complete`'s own `COMPLETE=` engine. Elvish and `PowerShell`
+//! scripts stay static.
+
+use std::fmt::Write as _;
+use std::io::{self, Write as _};
+
+use anyhow::{Context, Result};
+use clap::{ArgAction, ValueEnum};
+use clap_complete::Shell;
+
+use rust_core::{AppConfig, AppConfigExt as _, AppPaths, ExitCode, PathMode, env_prefix};
+
+use crate::APP_NAME;
+
+/// Values completed at run time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Candidates {
+    /// Task names for `run`
+    Tasks,
+    /// Profile names for `--profile`
+    Profiles,
+}
+
+/// An argument whose values are completed at run time.
+struct Dynamic {
+    /// Subcommand the argument belongs to.
+    command: &'static str,
+    /// Long flag, or `None` for the positional argument.
+    long: Option<&'static str>,
+    /// Argument id, as the zsh script names positionals.
+    id: &'static str,
+    candidates: Candidates,
+}
+
+const DYNAMIC: &[Dynamic] = &[
+    Dynamic {
+        command: "run",
+        long: None,
+        id: "task",
+        candidates: Candidates::Tasks,
+    },
+    Dynamic {
+        command: "run",
+        long: Some("profile"),
+        id: "profile",
+        candidates: Candidates::Profiles,
+    },
+];
+
+impl Candidates {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Tasks => "tasks",
+            Self::Profiles => "profiles",
+        }
+    }
+}
+
+/// The completion script for `shell`; `cmd` must be built.
+pub fn script(shell: Shell, cmd: &clap::Command) -> Result<String> {
+    let mut script = Vec::new();
+    clap_complete::generate(shell, &mut cmd.clone(), APP_NAME, &mut script);
+    let script = String::from_utf8(script).context("completion script is not UTF-8")?;
+    Ok(match shell {
+        Shell::Bash => script + &bash_hook(cmd),
+        Shell::Zsh => zsh_actions(&script),
+        Shell::Fish => script + &fish_lines(),
+        _ => script,
+    })
+}
+
+/// Variable that asks for candidates instead of running a command.
+fn complete_var() -> String {
+    format!("{}_COMPLETE", env_prefix())
+}
+
+/// If the completion variable is set, print its candidates one per line.
+/// Never fails, creates no files, and takes no lock, since it runs on every
+/// tab press; unknown kinds print nothing.
+pub fn complete_from_env() -> Option<ExitCode> {
+    let kind = std::env::var(complete_var()).ok()?;
+    let Ok(candidates) = Candidates::from_str(&kind, true) else {
+        return Some(ExitCode::Success);
+    };
+    let values: Vec<String> = match candidates {
+        Candidates::Tasks => read_config()
+            .map(|config| config.tasks.into_keys().collect())
+            .unwrap_or_default(),
+        Candidates::Profiles => {
+            let mut profiles = vec!["default".to_string()];
+            if let Some(config) = read_config() {
+                profiles.push(config.profile);
+            }
+            profiles.sort();
+            profiles.dedup();
+            profiles
+        }
+    };
+    let mut stdout = io::stdout().lock();
+    for value in values {
+        if writeln!(stdout, "{value}").is_err() {
+            break;
+        }
+    }
+    Some(ExitCode::Success)
+}
+
+/// The configuration as it stands, without creating a default file.
+fn read_config() -> Option<AppConfig> {
+    let paths = AppPaths::discover_in(PathMode::detect(), None).ok()?;
+    if paths.config_file.exists() {
+        AppConfig::load_from_path(&paths.config_file).ok()
+    } else {
+        AppConfig::from_env().ok()
+    }
+}
+
+/// Wrap the generated bash function: after it runs, replace its replies for
+/// dynamic arguments.
+fn bash_hook(cmd: &clap::Command) -> String {
+    let function = format!("_{APP_NAME}");
+    let var = complete_var();
+    let mut out = format!(
+        "\n# Values known only at run time come from `{var}=<KIND> {APP_NAME}`.\n\
+         {function}__dynamic() {{\n    \
+         {function} \"$@\"\n    \
+         local cur=\"${{COMP_WORDS[COMP_CWORD]}}\" prev=\"${{COMP_WORDS[COMP_CWORD-1]}}\" kind=\"\"\n    \
+         local seen=\" ${{COMP_WORDS[*]:1:COMP_CWORD-1}} \"\n"
+    );
+    let mut commands: Vec<&str> = DYNAMIC.iter().map(|dynamic| dynamic.command).collect();
+    commands.dedup();
+    for command in commands {
+        let _ = write!(
+            out,
+            "    if [[ \"${{seen}}\" == *\" {command} \"* ]]; then\n        \
+             case \"${{prev}}\" in\n"
+        );
+        let mut positional = None;
+        for dynamic in DYNAMIC.iter().filter(|dynamic| dynamic.command == command) {
+            match dynamic.long {
+                Some(long) => {
+                    let _ = writeln!(
+                        out,
+                        "            --{long}) kind={} ;;",
+                        dynamic.candidates.as_str()
+                    );
+                }
+                None => positional = Some(dynamic.candidates),
+            }
+        }
+        let value_options = value_options(cmd, command);
+        if !value_options.is_empty() {
+            let _ = writeln!(out, "            {}) ;;", value_options.join("|"));
+        }
+        if let Some(candidates) = positional {
+            let _ = writeln!(
+                out,
+                "            *) [[ \"${{cur}}\" == -* ]] || kind={} ;;",
+                candidates.as_str()
+            );
+        }
+        out.push_str("        esac\n    fi\n");
+    }
+    let _ = write!(
+        out,
+        "    if [[ -n \"${{kind}}\" ]]; then\n        \
+         COMPREPLY=( $(compgen -W \"$({var}=\"${{kind}}\" \"${{COMP_WORDS[0]}}\" 2>/dev/null)\" -- \"${{cur}}\") )\n    \
+         fi\n\
+         }}\n\
+         if [[ \"${{BASH_VERSINFO[0]}}\" -eq 4 && \"${{BASH_VERSINFO[1]}}\" -ge 4 || \"${{BASH_VERSINFO[0]}}\" -gt 4 ]]; then\n    \
+         complete -F {function}__dynamic -o nosort -o bashdefault -o default {APP_NAME}\n\
+         else\n    \
+         complete -F {function}__dynamic -o bashdefault -o default {APP_NAME}\n\
+         fi\n"
+    );
+    out
+}
+
+/// Options of `command` (including global ones) that take a value, whose
+/// value must not be completed as a positional.
+fn value_options(cmd: &clap::Command, command: &str) -> Vec<String> {
+    let Some(sub) = cmd.find_subcommand(command) else {
+        return Vec::new();
+    };
+    sub.get_arguments()
+        .filter(|arg| matches!(arg.get_action(), ArgAction::Set | ArgAction::Append))
+        .filter(|arg| !arg.is_positional())
+        .filter(|arg| !DYNAMIC.iter().any(|dynamic| dynamic.long == arg.get_long()))
+        .flat_map(|arg| {
+            let long = arg.get_long().map(|long| format!("--{long}"));
+            let short = arg.get_short().map(|short| format!("-{short}"));
+            long.into_iter().chain(short)
+        })
+        .collect()
+}
+
+/// Point the `_arguments` actions of dynamic arguments at the binary.
+fn zsh_actions(script: &str) -> String {
+    let mut out = String::with_capacity(script.len());
+    for line in script.split_inclusive('\n') {
+        let dynamic = DYNAMIC.iter().find(|dynamic| {
+            let spec = dynamic.long.map_or_else(
+                || format!("'::{} -- ", dynamic.id),
+                |long| format!("'--{long}=["),
+            );
+            line.starts_with(&spec)
+        });
+        match dynamic {
+            Some(dynamic) if line.contains(":_default'") => {
+                let action = format!(
+                    ":{{compadd -- ${{(f)\"$({}={} $words[1] 2>/dev/null)\"}}}}'",
+                    complete_var(),
+                    dynamic.candidates.as_str()
+                );
+                out.push_str(&line.replacen(":_default'", &action, 1));
+            }
+            _ => out.push_str(line),
+        }
+    }
+    out
+}
+
+/// Extra `complete` rules; fish merges them with the generated ones.
+fn fish_lines() -> String {
+    let helper = format!("__fish_{}_using_subcommand", APP_NAME.replace('-', "_"));
+    let var = complete_var();
+    let mut out =
+        format!("\n# Values known only at run time come from `{var}=<KIND> {APP_NAME}`.\n");
+    for dynamic in DYNAMIC {
+        let flag = dynamic
+            .long
+            .map_or_else(|| " -f".to_string(), |long| format!(" -l {long} -x"));
+        let _ = writeln!(
+            out,
+            "complete -c {APP_NAME} -n \"{helper} {}\"{flag} -a \"(env {var}={} {APP_NAME})\"",
+            dynamic.command,
+            dynamic.candidates.as_str()
+        );
+    }
+    out
+}
@@ -0,0 +1,225 @@
+//! `debug` subcommands: build and runtime facts for bug reports, and the
+//! command-line interface as data.
+//!
+//! `cli-spec` walks the clap command tree into a [`CliSpec`]. Its shape is a
+//! contract for tools that generate wrappers from it: fields are only ever
+//! added, and anything else bumps [`SPEC_VERSION`]. Hidden commands and
+//! arguments are left out; deprecated spellings are listed separately, with
+//! the version that removes them.
+
+use std::fmt::Write as _;
+
+use anyhow::Result;
+use clap::{ArgAction, CommandFactory as _, Subcommand, ValueHint};
+use serde::Serialize;
+
+use rust_core::{BuildInfo, Deprecation, FeatureStatus, features};
+
+use crate::deprecated::DEPRECATIONS;
+use crate::output::{Output, Report};
+use crate::{APP_NAME, Cli, RuntimeContext};
+
+/// Version of the `cli-spec` layout.
+const SPEC_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, Subcommand)]
+pub enum DebugCommand {
+    /// List optional subsystems: compiled into this build, enabled by the config
+    Features,
+    /// Describe every command and argument as JSON for wrapper and docs generators
+    CliSpec,
+}
+
+pub fn handle_debug(ctx: &RuntimeContext, command: DebugCommand) -> Result<Output> {
+    match command {
+        DebugCommand::Features => Output::new(&FeaturesReport {
+            build: features::build_info(),
+            features: features::detect(&ctx.config),
+        }),
+        DebugCommand::CliSpec => {
+            let cli = Cli::command();
+            Output::new(&CliSpec {
+                spec_version: SPEC_VERSION,
+                version: cli.get_version().map(str::to_string),
+                command: CommandSpec::from(&cli),
+                deprecations: DEPRECATIONS,
+            })
+        }
+    }
+}
+
+/// Result of `debug features`.
+#[derive(Serialize)]
+struct FeaturesReport {
+    build: BuildInfo,
+    features: Vec<FeatureStatus>,
+}
+
+impl Report for FeaturesReport {
+    fn text(&self) -> String {
+        let build = &self.build;
+        let mut out = format!(
+            "{APP_NAME} {} ({} {}, {} build)\n",
+            build.version,
+            build.os,
+            build.arch,
+            if build.debug { "debug" } else { "release" }
+        );
+        let width = self
+            .features
+            .iter()
+            .map(|feature| feature.name.len())
+            .max()
+            .unwrap_or_default();
+        for feature in &self.features {
+            let state = match (feature.compiled, feature.enabled) {
+                (false, _) => "not compiled",
+                (true, false) => "off",
+                (true, true) => "on",
+            };
+            let _ = writeln!(
+                out,
+                "  {:<width$}  {state:<12}  {} [{}]",
+                feature.name, feature.summary, feature.control
+            );
+        }
+        out
+    }
+}
+
+/// Result of `debug cli-spec`.
+#[derive(Serialize)]
+struct CliSpec {
+    spec_version: u32,
+    version: Option<String>,
+    #[serde(flatten)]
+    command: CommandSpec,
+    /// Old spellings still accepted, and what replaces them.
+    deprecations: &'static [Deprecation],
+}
+
+impl Report for CliSpec {
+    fn text(&self) -> String {
+        // A description for programs; there is no prose form.
+        serde_json::to_string_pretty(self).unwrap_or_default() + "\n"
+    }
+}
+
+#[derive(Serialize)]
+struct CommandSpec {
+    name: String,
+    about: Option<String>,
+    aliases: Vec<String>,
+    args: Vec<ArgSpec>,
+    commands: Vec<Self>,
+}
+
+impl From<&clap::Command> for CommandSpec {
+    fn from(cmd: &clap::Command) -> Self {
+        Self {
+            name: cmd.get_name().to_string(),
+            about: cmd.get_about().map(ToString::to_string),
+            aliases: cmd.get_visible_aliases().map(str::to_string).collect(),
+            args: cmd
+                .get_arguments()
+                .filter(|arg| !arg.is_hide_set())
+                .map(ArgSpec::from)
+                .collect(),
+            commands: cmd
+                .get_subcommands()
+                .filter(|sub| !sub.is_hide_set())
+                .map(Self::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ArgSpec {
+    id: String,
+    /// `flag`, `count`, `value`, or `values`.
+    kind: &'static str,
+    long: Option<String>,
+    short: Option<char>,
+    aliases: Vec<String>,
+    positional: bool,
+    required: bool,
+    global: bool,
+    help: Option<String>,
+    value_names: Vec<String>,
+    value_hint: Option<String>,
+    possible_values: Vec<ValueSpec>,
+    defaults: Vec<String>,
+    env: Option<String>,
+}
+
+impl From<&clap::Arg> for ArgSpec {
+    fn from(arg: &clap::Arg) -> Self {
+        let multiple = arg
+            .get_num_args()
+            .is_some_and(|range| range.max_values() > 1);
+        let kind = match arg.get_action() {
+            ArgAction::SetTrue | ArgAction::SetFalse | ArgAction::Help | ArgAction::Version => {
+                "flag"
+            }
+            ArgAction::Count => "count",
+            ArgAction::Append => "values",
+            _ if multiple || arg.get_value_delimiter().is_some() => "values",
+            _ => "value",
+        };
+        let takes_value = matches!(kind, "value" | "values");
+        Self {
+            id: arg.get_id().to_string(),
+            kind,
+            long: arg.get_long().map(str::to_string),
+            short: arg.get_short(),
+            aliases: arg
+                .get_visible_aliases()
+                .unwrap_or_default()
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+            positional: arg.is_positional(),
+            required: arg.is_required_set(),
+            global: arg.is_global_set(),
+            help: arg.get_help().map(ToString::to_string),
+            value_names: if takes_value {
+                arg.get_value_names()
+                    .unwrap_or_default()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect()
+            } else {
+                Vec::new()
+            },
+            value_hint: match arg.get_value_hint() {
+                ValueHint::Unknown => None,
+                hint => Some(format!("{hint:?}")),
+            },
+            possible_values: if takes_value {
+                arg.get_possible_values()
+                    .iter()
+                    .filter(|value| !value.is_hide_set())
+                    .map(|value| ValueSpec {
+                        name: value.get_name().to_string(),
+                        help: value.get_help().map(ToString::to_string),
+                    })
+                    .collect()
+            } else {
+                Vec::new()
+            },
+            defaults: arg
+                .get_default_values()
+                .iter()
+                .map(|value| value.to_string_lossy().into_owned())
+                .collect(),
+            env: arg.get_env().map(|env| env.to_string_lossy().into_owned()),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ValueSpec {
+    name: String,
+    help: Option<String>,
+}
@@ -0,0 +1,45 @@
+//! Old spellings of flags and commands, still accepted until their removal
+//! version (see [`rust_core::deprecation`]).
+//!
+//! To rename a flag or command, rename it in clap and add an entry to
+//! [`DEPRECATIONS`]; [`rewrite`] then maps the old spelling onto the new one
+//! before clap parses the command line. Once this crate's version reaches
+//! the removal version the old spelling fails with a usage error naming the
+//! new one, and the entry can be deleted in a later release.
+
+use std::ffi::OsString;
+
+use clap::CommandFactory as _;
+use clap::error::ErrorKind;
+
+use rust_core::deprecation::{self, Deprecation};
+
+use crate::Cli;
+use crate::alias::command_position;
+
+/// Renamed flags and commands, oldest first.
+pub const DEPRECATIONS: &[Deprecation] = &[Deprecation {
+    old: "--force",
+    new: "--yes",
+    removal: "1.0.0",
+}];
+
+/// `args` with deprecated spellings replaced, and a warning for each to log
+/// once logging is set up.
+///
+/// # Errors
+///
+/// Returns a usage error if `args` use a spelling this version no longer
+/// accepts.
+pub fn rewrite(args: Vec<OsString>) -> Result<(Vec<OsString>, Vec<String>), clap::Error> {
+    let mut cmd = Cli::command();
+    cmd.build();
+    let command_at = command_position(&cmd, &args);
+    let (args, notices) =
+        deprecation::rewrite(DEPRECATIONS, env!("CARGO_PKG_VERSION"), args, command_at)
+            .map_err(|err| cmd.clone().error(ErrorKind::InvalidValue, err))?;
+    if let Some(removed) = notices.iter().find(|notice| notice.removed) {
+        return Err(cmd.error(ErrorKind::UnknownArgument, removed));
+    }
+    Ok((args, notices.iter().map(ToString::to_string).collect()))
+}
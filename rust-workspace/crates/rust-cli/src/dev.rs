@@ -0,0 +1,142 @@
+//! `dev` subcommands: tooling for maintainers working on this template.
+//!
+//! `dev watch-generate` polls the sources the generated artifacts come from
+//! ([`artifacts::SOURCE_DIRS`]) and, once an edit has settled, rebuilds and
+//! runs `generate all` through `cargo run`, since this binary's own config
+//! structs predate the edit. Regenerations are at least `--cooldown` apart,
+//! so a burst of saves costs one rebuild; a build that fails (as it will
+//! mid-edit) is logged and retried on the next change. It runs until
+//! interrupted.
+
+use std::env;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use clap::{Args, Subcommand};
+use log::{info, warn};
+use serde::Serialize;
+
+use rust_core::{artifacts, release};
+
+use crate::output::{Output, Report};
+use crate::{APP_NAME, RuntimeContext};
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum DevCommand {
+    /// Regenerate schema, example config, and docs whenever their sources change
+    WatchGenerate(WatchGenerateCommand),
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct WatchGenerateCommand {
+    /// Workspace root (defaults to the nearest workspace above the current directory)
+    #[arg(long, value_name = "PATH")]
+    root: Option<PathBuf>,
+    /// How often to check the sources for changes (e.g. 500ms, 2s)
+    #[arg(long, value_name = "INTERVAL", default_value = "1s", value_parser = humantime::parse_duration)]
+    interval: Duration,
+    /// Least time between two regenerations (e.g. 10s, 1m)
+    #[arg(long, value_name = "DURATION", default_value = "10s", value_parser = humantime::parse_duration)]
+    cooldown: Duration,
+}
+
+pub fn handle_dev(ctx: &RuntimeContext, command: DevCommand) -> Result<Output> {
+    let DevCommand::WatchGenerate(cmd) = command;
+    let start = match cmd.root {
+        Some(root) => root,
+        None => env::current_dir().context("resolving current directory")?,
+    };
+    let root = release::find_workspace_root(&start)?;
+    let interval = cmd.interval.max(Duration::from_millis(100));
+    info!(
+        "watching {} under {}; interrupt to stop",
+        artifacts::SOURCE_DIRS.join(", "),
+        root.display()
+    );
+
+    let mut report = WatchReport {
+        root,
+        regenerated: 0,
+        failed: 0,
+    };
+    let mut seen = artifacts::source_snapshot(&report.root)?;
+    let mut changed_at: Option<Instant> = None;
+    let mut last_run: Option<Instant> = None;
+    while ctx.op.stop_reason().is_none() {
+        thread::sleep(interval);
+        let current = artifacts::source_snapshot(&report.root)?;
+        if current != seen {
+            seen = current;
+            changed_at = Some(Instant::now());
+            continue;
+        }
+        // Regenerate once the sources have been still for an interval.
+        let settled = changed_at.is_some_and(|at| at.elapsed() >= interval);
+        let cooled = last_run.is_none_or(|at| at.elapsed() >= cmd.cooldown);
+        if settled && cooled {
+            changed_at = None;
+            last_run = Some(Instant::now());
+            if regenerate(ctx, &report.root) {
+                report.regenerated += 1;
+            } else {
+                report.failed += 1;
+            }
+        }
+    }
+    Output::new(&report)
+}
+
+/// Build the workspace's CLI and run `generate all` in `root`; false if
+/// either step failed.
+fn regenerate(ctx: &RuntimeContext, root: &Path) -> bool {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    // This process holds the instance lock.
+    let mut args: Vec<OsString> = ["run", "--quiet", "-p", APP_NAME, "--", "--no-lock"]
+        .map(OsString::from)
+        .into();
+    if ctx.op.dry_run() {
+        args.push("--dry-run".into());
+    }
+    args.extend(["generate", "all", "--root"].map(OsString::from));
+    args.push(root.into());
+
+    info!("sources changed; regenerating artifacts");
+    match Command::new(&cargo).args(&args).current_dir(root).status() {
+        Ok(status) if status.success() => {
+            info!("artifacts regenerated");
+            true
+        }
+        Ok(status) => {
+            warn!("regenerating artifacts failed ({status}); waiting for the next change");
+            false
+        }
+        Err(err) => {
+            warn!("cannot run {}: {err}", cargo.to_string_lossy());
+            false
+        }
+    }
+}
+
+/// Result of `dev watch-generate`, once interrupted.
+#[derive(Debug, Serialize)]
+struct WatchReport {
+    #[serde(with = "rust_core::pathfmt")]
+    root: PathBuf,
+    regenerated: usize,
+    failed: usize,
+}
+
+impl Report for WatchReport {
+    fn text(&self) -> String {
+        format!(
+            "stopped watching {}: {} regenerations, {} failed\n",
+            self.root.display(),
+            self.regenerated,
+            self.failed
+        )
+    }
+}
@@ -0,0 +1,145 @@
+//! NDJSON event stream for `--output ndjson`.
+//!
+//! Each line on stdout is one JSON object whose `event` field says what
+//! happened: `started`, `progress`, and `finished` for every task a command
+//! reports through [`Progress`] (backup files, cache entries, `run` tasks),
+//! `error` when a task or the command fails, and a final `result` carrying
+//! what `--output json` would print. Lines are written as they happen, so a
+//! wrapper can follow a long operation without parsing spinner text. Logs
+//! and task output stay on stderr.
+
+use std::cell::Cell;
+use std::io::{self, Write as _};
+use std::time::Instant;
+
+use log::debug;
+use serde::Serialize;
+use serde_json::Value;
+
+use rust_core::{ErrorReport, Progress, ProgressTask};
+
+/// One line of the stream.
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event<'a> {
+    /// A task began; `total` is its number of steps, if known.
+    Started {
+        task: &'a str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        total: Option<u64>,
+    },
+    /// A task advanced to step `position`, now working on `message`.
+    Progress {
+        task: &'a str,
+        position: u64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        total: Option<u64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        message: Option<&'a str>,
+    },
+    /// A task ended, successfully unless an `error` event preceded it.
+    Finished {
+        task: &'a str,
+        ok: bool,
+        position: u64,
+        elapsed_ms: u64,
+    },
+    /// A task failed.
+    #[serde(rename = "error")]
+    TaskError { task: &'a str, message: &'a str },
+    /// The command failed; the last line of the stream.
+    Error(&'a ErrorReport),
+    /// The command's result; the last line unless an `error` follows.
+    Result { data: &'a Value },
+}
+
+impl Event<'_> {
+    /// The event as one line of JSON, with its newline.
+    pub fn line(&self) -> String {
+        let mut line = serde_json::to_string(self).unwrap_or_else(|err| {
+            format!(r#"{{"event":"error","message":"serializing event: {err}"}}"#)
+        });
+        line.push('\n');
+        line
+    }
+
+    /// Write the event to stdout. A closed stdout is not an error: the
+    /// command carries on without its audience.
+    pub fn emit(&self) {
+        let mut stdout = io::stdout().lock();
+        if let Err(err) = stdout
+            .write_all(self.line().as_bytes())
+            .and_then(|()| stdout.flush())
+        {
+            debug!("cannot write event: {err}");
+        }
+    }
+}
+
+/// Reports progress as events on stdout.
+pub struct Events;
+
+impl Progress for Events {
+    fn start(&self, label: &str, total: Option<u64>) -> Box<dyn ProgressTask + '_> {
+        Event::Started { task: label, total }.emit();
+        Box::new(Task {
+            label: label.to_string(),
+            total,
+            position: Cell::new(0),
+            failed: Cell::new(false),
+            started: Instant::now(),
+        })
+    }
+}
+
+struct Task {
+    label: String,
+    total: Option<u64>,
+    position: Cell<u64>,
+    failed: Cell<bool>,
+    started: Instant,
+}
+
+impl ProgressTask for Task {
+    fn advance(&self, steps: u64) {
+        self.position.set(self.position.get() + steps);
+        Event::Progress {
+            task: &self.label,
+            position: self.position.get(),
+            total: self.total,
+            message: None,
+        }
+        .emit();
+    }
+
+    fn set_message(&self, message: &str) {
+        Event::Progress {
+            task: &self.label,
+            position: self.position.get(),
+            total: self.total,
+            message: Some(message),
+        }
+        .emit();
+    }
+
+    fn fail(&self, message: &str) {
+        self.failed.set(true);
+        Event::TaskError {
+            task: &self.label,
+            message,
+        }
+        .emit();
+    }
+}
+
+impl Drop for Task {
+    fn drop(&mut self) {
+        Event::Finished {
+            task: &self.label,
+            ok: !self.failed.get(),
+            position: self.position.get(),
+            elapsed_ms: u64::try_from(self.started.elapsed().as_millis()).unwrap_or(u64::MAX),
+        }
+        .emit();
+    }
+}
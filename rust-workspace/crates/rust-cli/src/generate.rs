@@ -0,0 +1,255 @@
+//! `generate` subcommands: write and verify every generated artifact.
+//!
+//! The config schema, example config, and config reference come from
+//! `rust-core`; the man page, shell completions, and markdown CLI reference
+//! are rendered from the clap command tree. All of them are recorded in the manifest written by
+//! [`rust_core::write_artifacts`].
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use clap::{Args, CommandFactory, Subcommand, ValueEnum};
+use clap_complete::Generator as _;
+use clap_complete::Shell;
+use serde::Serialize;
+
+use rust_core::artifacts::{self, Artifact, Drift, MANIFEST_FILENAME};
+use rust_core::{Journal, release, ui_spec};
+
+use crate::completions;
+use crate::output::{Output, Report};
+use crate::{APP_NAME, Cli, RuntimeContext};
+
+/// Directory (relative to the artifact root) holding the man page.
+const MAN_DIR: &str = "docs/man";
+
+/// Directory (relative to the artifact root) holding shell completions.
+const COMPLETIONS_DIR: &str = "docs/completions";
+
+/// Path (relative to the artifact root) of the markdown CLI reference.
+const CLI_REFERENCE: &str = "docs/cli.md";
+
+#[derive(Debug, Subcommand)]
+pub enum GenerateCommand {
+    /// Regenerate every artifact and rewrite the manifest
+    All(GenerateArgs),
+    /// Check that committed artifacts match what would be generated now
+    Verify(GenerateArgs),
+    /// Print the settings UI description derived from the config schema
+    UiSpec(UiSpecArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct UiSpecArgs {
+    /// Serialization of the description
+    #[arg(long, value_enum, value_name = "FORMAT", default_value_t = SpecFormat::Json)]
+    format: SpecFormat,
+}
+
+/// Serialization for `generate ui-spec`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum SpecFormat {
+    /// Pretty-printed JSON, as in `examples/config.ui.json`
+    Json,
+    /// YAML
+    Yaml,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct GenerateArgs {
+    /// Artifact root (defaults to the nearest workspace above the current directory)
+    #[arg(long, value_name = "PATH")]
+    root: Option<PathBuf>,
+}
+
+/// Resolve the artifact root: `root` if given, otherwise the nearest workspace.
+fn resolve_root(root: Option<PathBuf>) -> Result<PathBuf> {
+    if let Some(root) = root {
+        return Ok(root);
+    }
+    let cwd = std::env::current_dir().context("resolving current directory")?;
+    release::find_workspace_root(&cwd)
+}
+
+/// Generator identifier recorded in the manifest.
+fn generator(version: &str) -> String {
+    format!("{APP_NAME} {version}")
+}
+
+/// Every artifact, rendered as if the CLI were at `version`.
+///
+/// Taking the version explicitly lets `release stamp` render the man page for
+/// the version it is stamping rather than the running binary's.
+pub fn all_artifacts(version: &str) -> Result<Vec<Artifact>> {
+    let mut all = artifacts::core_artifacts(rust_core::APP_NAME)?;
+    // The build facts in `--version` differ per build, which generated files
+    // must not.
+    let mut cmd = Cli::command()
+        .version(version.to_string())
+        .long_version(None::<&str>);
+    cmd.build();
+
+    let mut man = Vec::new();
+    clap_mangen::Man::new(cmd.clone())
+        .render(&mut man)
+        .context("rendering man page")?;
+    all.push(Artifact::new(format!("{MAN_DIR}/{APP_NAME}.1"), man));
+
+    for shell in Shell::value_variants() {
+        all.push(Artifact::new(
+            format!("{COMPLETIONS_DIR}/{}", shell.file_name(APP_NAME)),
+            completions::script(*shell, &cmd)?,
+        ));
+    }
+
+    all.push(Artifact::new(CLI_REFERENCE, cli_reference(&mut cmd)));
+    Ok(all)
+}
+
+/// Render a markdown reference with the help text of every (sub)command.
+fn cli_reference(cmd: &mut clap::Command) -> String {
+    let mut out = format!(
+        "# {APP_NAME} command reference\n\n\
+         <!-- Generated by `{APP_NAME} generate all`; do not edit by hand. -->\n"
+    );
+    append_command(&mut out, cmd, APP_NAME);
+    out
+}
+
+fn append_command(out: &mut String, cmd: &mut clap::Command, path: &str) {
+    let help = cmd.render_long_help().to_string();
+    let _ = write!(out, "\n## `{path}`\n\n```text\n{}\n```\n", help.trim_end());
+    for sub in cmd.get_subcommands_mut() {
+        if sub.get_name() == "help" {
+            continue;
+        }
+        let sub_path = format!("{path} {}", sub.get_name());
+        append_command(out, sub, &sub_path);
+    }
+}
+
+/// Stage every artifact for `version` under `root` in `journal`.
+pub fn write_all(root: &Path, version: &str, journal: &mut Journal) -> Result<artifacts::Manifest> {
+    let all = all_artifacts(version)?;
+    rust_core::write_artifacts(root, &all, &generator(version), journal)
+}
+
+/// Fail with a list of drifted artifacts unless `root` is current for `version`.
+pub fn verify_all(root: &Path, version: &str) -> Result<()> {
+    let drift = rust_core::verify_artifacts(root, &all_artifacts(version)?)?;
+    ensure_current(root, &drift)
+}
+
+fn ensure_current(root: &Path, drift: &[Drift]) -> Result<()> {
+    if drift.is_empty() {
+        return Ok(());
+    }
+    let details: Vec<String> = drift.iter().map(ToString::to_string).collect();
+    bail!(
+        "generated artifacts under {} are out of date; run `{APP_NAME} generate all`:\n  - {}",
+        root.display(),
+        details.join("\n  - ")
+    )
+}
+
+pub fn handle_generate(ctx: &RuntimeContext, command: GenerateCommand) -> Result<Output> {
+    let version = env!("CARGO_PKG_VERSION");
+    match command {
+        GenerateCommand::All(args) => {
+            let root = resolve_root(args.root)?;
+            let mut journal = ctx.journal("generate all")?;
+            let manifest = write_all(&root, version, &mut journal)?;
+            journal.commit()?;
+            Output::new(&WriteReport {
+                manifest,
+                dry_run: ctx.op.dry_run(),
+            })
+        }
+        GenerateCommand::Verify(args) => {
+            let root = resolve_root(args.root)?;
+            let drift = rust_core::verify_artifacts(&root, &all_artifacts(version)?)?;
+            let output = Output::new(&VerifyReport {
+                current: drift.is_empty(),
+                drift: drift.iter().map(ToString::to_string).collect(),
+                root: root.clone(),
+            })?;
+            match ensure_current(&root, &drift) {
+                Ok(()) => Ok(output),
+                Err(err) => Ok(output.then_fail(err)),
+            }
+        }
+        GenerateCommand::UiSpec(args) => {
+            let json = ui_spec::generate_ui_spec(rust_core::APP_NAME)?;
+            let spec: serde_json::Value =
+                serde_json::from_str(&json).context("parsing generated UI spec")?;
+            let text = match args.format {
+                SpecFormat::Json => json,
+                SpecFormat::Yaml => {
+                    serde_yaml::to_string(&spec).context("serializing UI spec to YAML")?
+                }
+            };
+            Output::new(&UiSpecReport { spec, text })
+        }
+    }
+}
+
+/// Result of `generate ui-spec`: the description, as text in `--format`.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+struct UiSpecReport {
+    spec: serde_json::Value,
+    #[serde(skip)]
+    text: String,
+}
+
+impl Report for UiSpecReport {
+    fn text(&self) -> String {
+        self.text.clone()
+    }
+}
+
+/// Result of `generate all`: the manifest that was (or would be) written.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+struct WriteReport {
+    manifest: artifacts::Manifest,
+    #[serde(skip)]
+    dry_run: bool,
+}
+
+impl Report for WriteReport {
+    fn text(&self) -> String {
+        if self.dry_run {
+            return String::new();
+        }
+        let mut out = String::new();
+        for entry in &self.manifest.artifacts {
+            let _ = writeln!(out, "wrote {}", entry.artifact);
+        }
+        let _ = writeln!(out, "wrote {MANIFEST_FILENAME}");
+        out
+    }
+}
+
+/// Result of `generate verify`.
+#[derive(Debug, Serialize)]
+struct VerifyReport {
+    #[serde(with = "rust_core::pathfmt")]
+    root: PathBuf,
+    current: bool,
+    drift: Vec<String>,
+}
+
+impl Report for VerifyReport {
+    fn text(&self) -> String {
+        if self.current {
+            format!(
+                "generated artifacts under {} are current\n",
+                self.root.display()
+            )
+        } else {
+            String::new()
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! `healthcheck`: one-shot probe for container `HEALTHCHECK`s and systemd
+//! watchdogs.
+//!
+//! Exits 0 when every probe passes and 1 otherwise (Docker reserves 2), and
+//! prints a single JSON line whose `reason` says what failed. It runs before
+//! the usual startup: no lock, no directory creation, no default config.
+
+use std::time::Duration;
+
+use clap::{Args, ValueEnum};
+use serde::Serialize;
+
+use rust_core::health::{self, HealthRequest};
+use rust_core::{AppPaths, ExitCode, HealthProbe, HealthReport};
+
+use crate::CommonOpts;
+use crate::output::{Output, Report};
+
+#[derive(Debug, Clone, Args)]
+pub struct HealthcheckCommand {
+    /// Probes to run instead of `health.probes` (repeat or comma-separate)
+    #[arg(
+        long = "probe",
+        value_enum,
+        value_name = "PROBE",
+        value_delimiter = ','
+    )]
+    probes: Vec<Probe>,
+    /// Daemon to probe instead of `health.daemon`: HOST:PORT or a Unix socket path
+    #[arg(long, value_name = "ADDR")]
+    daemon: Option<String>,
+    /// Time limit for the whole check instead of `health.timeout_ms`
+    #[arg(long = "timeout-ms", value_name = "MS", value_parser = clap::value_parser!(u64).range(1..))]
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Probe {
+    /// The configuration loads and validates
+    Config,
+    /// A file can be created in the state directory
+    State,
+    /// The daemon answers GET /health with 200
+    Daemon,
+}
+
+impl From<Probe> for HealthProbe {
+    fn from(probe: Probe) -> Self {
+        match probe {
+            Probe::Config => Self::Config,
+            Probe::State => Self::State,
+            Probe::Daemon => Self::Daemon,
+        }
+    }
+}
+
+pub fn handle_healthcheck(common: &CommonOpts, cmd: &HealthcheckCommand) -> ExitCode {
+    let report = match AppPaths::discover_in(common.path_mode(), common.config.as_deref()) {
+        Ok(paths) => health::check(HealthRequest {
+            paths,
+            from_env: common.skips_config_file(),
+            probes: (!cmd.probes.is_empty())
+                .then(|| cmd.probes.iter().copied().map(HealthProbe::from).collect()),
+            daemon: cmd.daemon.clone(),
+            timeout: cmd.timeout_ms.map(Duration::from_millis),
+        }),
+        Err(err) => HealthReport {
+            status: "unhealthy",
+            reason: format!("paths: {err}"),
+            elapsed_ms: 0,
+            probes: Vec::new(),
+        },
+    };
+    let printed =
+        Output::new(&HealthResult(&report)).and_then(|output| common.output().emit(output, None));
+    if let Err(err) = printed {
+        let reason = format!("printing report: {err}");
+        println!(
+            "{}",
+            serde_json::json!({"status": "unhealthy", "reason": reason})
+        );
+        return ExitCode::Failure;
+    }
+    if report.is_healthy() {
+        ExitCode::Success
+    } else {
+        ExitCode::Failure
+    }
+}
+
+/// The report; its text form is the one-line JSON that health check logs
+/// show.
+#[derive(Serialize)]
+#[serde(transparent)]
+struct HealthResult<'a>(&'a HealthReport);
+
+impl Report for HealthResult<'_> {
+    fn text(&self) -> String {
+        // `Output::new` has already serialized it once, so this succeeds.
+        serde_json::to_string(self.0).unwrap_or_default() + "\n"
+    }
+}
@@ -0,0 +1,149 @@
+//! `history` subcommands: list, show, and clear the recorded `run`
+//! invocations (see [`rust_core::history`]).
+
+use std::fmt::Write as _;
+use std::time::{Duration, UNIX_EPOCH};
+
+use anyhow::{Result, bail};
+use clap::Subcommand;
+use serde::Serialize;
+
+use rust_core::{RunRecord, history};
+
+use crate::RuntimeContext;
+use crate::output::{Output, Report};
+use crate::run::task_table;
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum HistoryCommand {
+    /// List recorded runs, newest first
+    List {
+        /// Show at most N runs
+        #[arg(long, value_name = "N")]
+        limit: Option<usize>,
+        /// Only show runs of this task
+        #[arg(long, value_name = "NAME")]
+        task: Option<String>,
+    },
+    /// Show one run with the outcome of each of its tasks
+    Show {
+        /// Run id, as listed by `history list`
+        #[arg(value_name = "ID")]
+        id: u64,
+    },
+    /// Remove every recorded run
+    Clear,
+}
+
+pub fn handle_history(ctx: &RuntimeContext, command: HistoryCommand) -> Result<Output> {
+    let state_dir = &ctx.paths.state_dir;
+    match command {
+        HistoryCommand::List { limit, task } => {
+            let runs = history::list(state_dir)?
+                .into_iter()
+                .rev()
+                .filter(|run| task.as_ref().is_none_or(|task| run.task == *task))
+                .take(limit.unwrap_or(usize::MAX))
+                .collect();
+            Output::new(&ListReport(runs))
+        }
+        HistoryCommand::Show { id } => {
+            let Some(run) = history::list(state_dir)?
+                .into_iter()
+                .find(|run| run.id == id)
+            else {
+                bail!("no run {id} in the history; see `history list`");
+            };
+            Output::new(&ShowReport(run))
+        }
+        HistoryCommand::Clear => Output::new(&ClearReport {
+            cleared: history::clear(state_dir, &ctx.op)?,
+            dry_run: ctx.op.dry_run(),
+        }),
+    }
+}
+
+/// `secs` since the Unix epoch as an RFC 3339 timestamp.
+fn timestamp(secs: u64) -> String {
+    humantime::format_rfc3339_seconds(UNIX_EPOCH + Duration::from_secs(secs)).to_string()
+}
+
+/// Result of `history list`.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+struct ListReport(Vec<RunRecord>);
+
+impl Report for ListReport {
+    fn text(&self) -> String {
+        if self.0.is_empty() {
+            return "no runs recorded\n".to_string();
+        }
+        let width = self
+            .0
+            .iter()
+            .map(|run| run.task.len())
+            .max()
+            .unwrap_or_default();
+        let mut out = String::new();
+        for run in &self.0 {
+            let _ = writeln!(
+                out,
+                "{:>5}  {}  {:<width$}  {:<10}  exit {:<3}  {}s",
+                run.id,
+                timestamp(run.started_at),
+                run.task,
+                run.profile,
+                run.exit_code,
+                run.finished_at.saturating_sub(run.started_at)
+            );
+        }
+        out
+    }
+}
+
+/// Result of `history show`.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+struct ShowReport(RunRecord);
+
+impl Report for ShowReport {
+    fn text(&self) -> String {
+        let run = &self.0;
+        let mut out = format!(
+            "run {}: {} (profile {})\n  command:  {}\n  started:  {}\n  finished: {}\n  exit:     {}\n",
+            run.id,
+            run.task,
+            run.profile,
+            run.args.join(" "),
+            timestamp(run.started_at),
+            timestamp(run.finished_at),
+            run.exit_code
+        );
+        if !run.correlation_id.is_empty() {
+            let _ = writeln!(out, "  correlation id: {}", run.correlation_id);
+        }
+        if !run.tasks.is_empty() {
+            out.push('\n');
+            out.push_str(&task_table(&run.tasks, true));
+        }
+        out
+    }
+}
+
+/// Result of `history clear`.
+#[derive(Debug, Serialize)]
+struct ClearReport {
+    cleared: usize,
+    dry_run: bool,
+}
+
+impl Report for ClearReport {
+    fn text(&self) -> String {
+        let verb = if self.dry_run {
+            "would clear"
+        } else {
+            "cleared"
+        };
+        format!("{verb} {} recorded runs\n", self.cleared)
+    }
+}
@@ -0,0 +1,540 @@
+//! The global logger: records as text or JSON, copied to the config's
+//! `logging.file`.
+//!
+//! Logging starts before the config is read, so records go to stderr from
+//! the first line, filtered and formatted by the flags and `RUST_LOG` alone;
+//! once the config is known, [`install`] is called again to apply
+//! `logging.level`, `logging.filters`, and `logging.format`, and [`attach`]
+//! adds the file. The two sinks are filtered and formatted apart: the file
+//! gets what stderr gets unless `logging.file_level` and
+//! `logging.file_format` say otherwise. Text records are `env_logger`'s on
+//! stderr and plain text in the file whatever `--color` says, one record
+//! per line: `<RFC 3339 time> <LEVEL> <target>: <message>`, then any
+//! `key=value` fields. JSON records are one object per line in both places,
+//! as the templates repository's `LOGGING.md` specifies: `time`, `level`,
+//! `msg`, then `target`, `correlation_id`, and the record's fields.
+//! `rust-tui` follows the file in its log view and reads either.
+//!
+//! A third sink keeps the last `logging.ring_buffer` records in memory at
+//! debug level or finer, whatever stderr shows, so that when a command fails
+//! (or under `--diagnostics`) [`dump_recent`] can print the context that
+//! led up to it.
+
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write as _};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock, PoisonError, RwLock};
+use std::time::SystemTime;
+
+use anyhow::{Context, Result};
+use log::kv::{self, Key, Value, VisitSource};
+use log::{Log, Metadata, Record};
+use serde_json::{Map, Value as Json};
+
+use rust_core::logs::LogRecord;
+
+/// The attached log file, once [`attach`] has opened it.
+static FILE: OnceLock<Mutex<File>> = OnceLock::new();
+
+/// Whether records on stderr (or stdout) are written as JSON rather than
+/// text.
+static JSON: AtomicBool = AtomicBool::new(false);
+
+/// Whether records in the file are written as JSON rather than text.
+static FILE_JSON: AtomicBool = AtomicBool::new(false);
+
+/// Also handed every record, whatever the filters; see [`tee`].
+static TEE: OnceLock<fn(&Record<'_>)> = OnceLock::new();
+
+/// What [`install`] set up last.
+static INSTALLED: RwLock<Option<Installed>> = RwLock::new(None);
+
+/// The most recent records, oldest first.
+static RING: Mutex<Ring> = Mutex::new(Ring {
+    records: VecDeque::new(),
+    capacity: 0,
+});
+
+/// The filters of the sinks; [`install`] leaves out a sink whose filter is
+/// `None`, except the file, which then takes what stderr takes.
+pub struct Filters {
+    /// The stderr logger, or `None` under `--quiet`.
+    pub stderr: Option<env_logger::Logger>,
+    /// The file's own filter (`logging.file_level`).
+    pub file: Option<env_logger::Logger>,
+    /// The ring buffer's filter, `None` when it is off.
+    pub ring: Option<env_logger::Logger>,
+}
+
+struct Installed {
+    filters: Filters,
+    correlation_id: String,
+    /// Whether JSON records go to stdout (`--entrypoint`) instead of stderr.
+    stdout: bool,
+}
+
+/// The global logger: an `env_logger` logger that can write JSON instead,
+/// and also appends to the attached file.
+struct Logger;
+
+/// Records kept for [`dump_recent`].
+struct Ring {
+    records: VecDeque<Recent>,
+    capacity: usize,
+}
+
+struct Recent {
+    line: Json,
+    /// Whether stderr showed it.
+    shown: bool,
+}
+
+impl Installed {
+    /// Write `record` to stderr if it passes that filter and keep it in the
+    /// ring buffer if it passes that one. Returns whether it goes to the
+    /// file too, and its JSON line if one was made.
+    fn log(&self, record: &Record<'_>) -> (bool, Option<Json>) {
+        let filters = &self.filters;
+        let stderr = filters
+            .stderr
+            .as_ref()
+            .filter(|inner| inner.matches(record));
+        let to_file = FILE.get().is_some()
+            && filters
+                .file
+                .as_ref()
+                .map_or_else(|| stderr.is_some(), |file| file.matches(record));
+        let to_ring = filters
+            .ring
+            .as_ref()
+            .is_some_and(|ring| ring.matches(record));
+        let json = ((stderr.is_some() && is_json()) || (to_file && is_file_json()) || to_ring)
+            .then(|| json_line(record, &self.correlation_id));
+        if to_ring && let Some(line) = &json {
+            RING.lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(Recent {
+                    line: line.clone(),
+                    shown: stderr.is_some(),
+                });
+        }
+        if let Some(inner) = stderr {
+            match json.as_ref().filter(|_| is_json()) {
+                Some(line) if self.stdout => {
+                    let _ = writeln!(io::stdout().lock(), "{line}");
+                }
+                Some(line) => {
+                    let _ = writeln!(io::stderr().lock(), "{line}");
+                }
+                None => inner.log(record),
+            }
+        }
+        (to_file, json)
+    }
+}
+
+impl Ring {
+    fn push(&mut self, record: Recent) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.records.len() == self.capacity {
+            self.records.pop_front();
+        }
+        self.records.push_back(record);
+    }
+}
+
+/// Log through `filters` from now on: the first call sets the global
+/// logger, later ones replace its filters and formats.
+pub fn install(
+    filters: Filters,
+    correlation_id: &str,
+    stdout: bool,
+) -> Result<(), log::SetLoggerError> {
+    let max_level = filters
+        .stderr
+        .iter()
+        .chain(&filters.file)
+        .chain(&filters.ring)
+        .map(env_logger::Logger::filter)
+        .max()
+        .unwrap_or(log::LevelFilter::Off);
+    let installed = Installed {
+        filters,
+        correlation_id: correlation_id.to_string(),
+        stdout,
+    };
+    let first = INSTALLED
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .replace(installed)
+        .is_none();
+    if first {
+        log::set_boxed_logger(Box::new(Logger))?;
+    }
+    log::set_max_level(max_level);
+    Ok(())
+}
+
+/// Hand every record to `sink` as well from now on, such as
+/// `rust_mcp::logging::to_client` to pass tool calls' records on to MCP
+/// clients. Only the first call has an effect.
+#[cfg(feature = "mcp")]
+pub fn tee(sink: fn(&Record<'_>)) {
+    let _ = TEE.set(sink);
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        let installed = INSTALLED.read().unwrap_or_else(PoisonError::into_inner);
+        installed.as_ref().is_some_and(|installed| {
+            let filters = &installed.filters;
+            filters
+                .stderr
+                .iter()
+                .chain(&filters.file)
+                .chain(&filters.ring)
+                .any(|logger| logger.enabled(metadata))
+        })
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if let Some(tee) = TEE.get() {
+            tee(record);
+        }
+        let (to_file, json) = match INSTALLED
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+        {
+            Some(installed) => installed.log(record),
+            None => return,
+        };
+        if to_file
+            && let Some(file) = FILE.get()
+            && let Ok(mut file) = file.lock()
+        {
+            let _ = match json.filter(|_| is_file_json()) {
+                Some(line) => writeln!(file, "{line}"),
+                None => writeln!(
+                    file,
+                    "{} {:<5} {}: {}{}",
+                    humantime::format_rfc3339_seconds(SystemTime::now()),
+                    record.level(),
+                    record.target(),
+                    record.args(),
+                    text_fields(record)
+                ),
+            };
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(inner) = INSTALLED
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+            .and_then(|installed| installed.filters.stderr.as_ref())
+        {
+            inner.flush();
+        }
+        if let Some(file) = FILE.get()
+            && let Ok(mut file) = file.lock()
+        {
+            let _ = file.flush();
+        }
+    }
+}
+
+/// Write records as JSON (or, with `false`, text) from now on, on stderr
+/// and in the file apart.
+pub fn set_json(stderr: bool, file: bool) {
+    JSON.store(stderr, Ordering::Relaxed);
+    FILE_JSON.store(file, Ordering::Relaxed);
+}
+
+/// Whether records on stderr are written as JSON.
+fn is_json() -> bool {
+    JSON.load(Ordering::Relaxed)
+}
+
+/// Whether records in the file are written as JSON.
+fn is_file_json() -> bool {
+    FILE_JSON.load(Ordering::Relaxed)
+}
+
+/// Append log records from now on to `path`, creating it and its directory.
+/// Only the first call in a process has an effect.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be opened for appending.
+pub fn attach(path: &Path) -> Result<()> {
+    if FILE.get().is_some() {
+        return Ok(());
+    }
+    let _ = FILE.set(Mutex::new(open_append(path)?));
+    Ok(())
+}
+
+/// `path` opened for appending, created along with its directory. Every
+/// write lands at the end of the file as it is then, so lines keep going to
+/// the right place after logrotate's `copytruncate` empties it.
+fn open_append(path: &Path) -> Result<File> {
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent).with_context(|| format!("creating {}", parent.display()))?;
+    }
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("opening log file {}", path.display()))
+}
+
+/// Keep the last `capacity` records for [`dump_recent`] from now on.
+pub fn set_ring_size(capacity: usize) {
+    let mut ring = RING.lock().unwrap_or_else(PoisonError::into_inner);
+    ring.capacity = capacity;
+    let excess = ring.records.len().saturating_sub(capacity);
+    ring.records.drain(..excess);
+}
+
+/// Print the records in the ring buffer that stderr did not show where
+/// log records go: as JSON lines when records are JSON, else as text under
+/// `heading`. Empties the buffer.
+pub fn dump_recent(heading: &str) {
+    let records: Vec<Recent> = RING
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .records
+        .drain(..)
+        .filter(|recent| !recent.shown)
+        .collect();
+    if records.is_empty() {
+        return;
+    }
+    let mut out = String::new();
+    if !is_json() {
+        let _ = writeln!(out, "{heading}");
+    }
+    for recent in &records {
+        if is_json() {
+            let _ = writeln!(out, "{}", recent.line);
+        } else {
+            let mut record = LogRecord::parse(&recent.line.to_string());
+            record.fields.remove("correlation_id");
+            let _ = writeln!(out, "  {record}");
+        }
+    }
+    let stdout = is_json()
+        && INSTALLED
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+            .is_some_and(|installed| installed.stdout);
+    let _ = if stdout {
+        io::stdout().lock().write_all(out.as_bytes())
+    } else {
+        io::stderr().lock().write_all(out.as_bytes())
+    };
+}
+
+/// `record` as one line of JSON.
+fn json_line(record: &Record<'_>, correlation_id: &str) -> Json {
+    let mut line = Map::new();
+    let time = humantime::format_rfc3339_millis(SystemTime::now());
+    line.insert("time".into(), time.to_string().into());
+    line.insert(
+        "level".into(),
+        record.level().as_str().to_ascii_lowercase().into(),
+    );
+    line.insert("msg".into(), record.args().to_string().into());
+    line.insert("target".into(), record.target().into());
+    line.insert("correlation_id".into(), correlation_id.into());
+    let _ = record.key_values().visit(&mut JsonFields(&mut line));
+    Json::Object(line)
+}
+
+/// Adds a record's fields to a JSON line, as numbers and booleans where
+/// they are ones.
+struct JsonFields<'a>(&'a mut Map<String, Json>);
+
+impl<'kvs> VisitSource<'kvs> for JsonFields<'_> {
+    fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), kv::Error> {
+        let value = value
+            .to_bool()
+            .map(Json::from)
+            .or_else(|| value.to_i64().map(Json::from))
+            .or_else(|| value.to_u64().map(Json::from))
+            .or_else(|| value.to_f64().map(Json::from))
+            .unwrap_or_else(|| value.to_string().into());
+        // The fields every line has win over a record's own.
+        self.0.entry(key.as_str()).or_insert(value);
+        Ok(())
+    }
+}
+
+/// A record's fields as ` key=value` pairs.
+fn text_fields(record: &Record<'_>) -> String {
+    struct TextFields(String);
+
+    impl<'kvs> VisitSource<'kvs> for TextFields {
+        fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), kv::Error> {
+            let _ = write!(self.0, " {key}={value}");
+            Ok(())
+        }
+    }
+
+    let mut fields = TextFields(String::new());
+    let _ = record.key_values().visit(&mut fields);
+    fields.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anyhow::ensure;
+    use log::Level;
+    use rust_core::TempScope;
+    use serde_json::json;
+
+    fn recent(n: u64) -> Recent {
+        Recent {
+            line: json!(n),
+            shown: false,
+        }
+    }
+
+    fn lines(records: &VecDeque<Recent>) -> Vec<Json> {
+        records.iter().map(|recent| recent.line.clone()).collect()
+    }
+
+    #[test]
+    fn the_ring_evicts_the_oldest_record_first() {
+        let mut ring = Ring {
+            records: VecDeque::new(),
+            capacity: 3,
+        };
+        (0..5).map(recent).for_each(|record| ring.push(record));
+        assert_eq!(lines(&ring.records), [json!(2), json!(3), json!(4)]);
+
+        let mut off = Ring {
+            records: VecDeque::new(),
+            capacity: 0,
+        };
+        off.push(recent(0));
+        assert!(off.records.is_empty());
+    }
+
+    #[test]
+    fn shrinking_the_ring_keeps_the_newest_records() {
+        set_ring_size(4);
+        let ring = || RING.lock().unwrap_or_else(PoisonError::into_inner);
+        (0..6).map(recent).for_each(|record| ring().push(record));
+        assert_eq!(ring().records.len(), 4);
+        set_ring_size(2);
+        assert_eq!(lines(&ring().records), [json!(4), json!(5)]);
+        set_ring_size(0);
+        assert!(
+            RING.lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .records
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn json_lines_have_the_fixed_keys_then_the_fields() -> Result<()> {
+        let fields = [
+            ("count", Value::from(3_u64)),
+            ("ok", Value::from(true)),
+            ("ratio", Value::from(0.5)),
+            ("task", Value::from("build")),
+            ("msg", Value::from("not the message")),
+        ];
+        let mut line = json_line(
+            &Record::builder()
+                .args(format_args!("ran {} tasks", 3))
+                .level(Level::Warn)
+                .target("rust_cli::run")
+                .key_values(&fields)
+                .build(),
+            "cid-1",
+        );
+        let object = line.as_object_mut().context("not an object")?;
+        let keys: Vec<&str> = object.keys().map(String::as_str).collect();
+        ensure!(
+            keys == [
+                "time",
+                "level",
+                "msg",
+                "target",
+                "correlation_id",
+                "count",
+                "ok",
+                "ratio",
+                "task"
+            ],
+            "{keys:?}"
+        );
+        let time = object.remove("time").context("no time")?;
+        humantime::parse_rfc3339(time.as_str().context("time is not a string")?)?;
+        let expected = json!({
+            "level": "warn",
+            "msg": "ran 3 tasks",
+            "target": "rust_cli::run",
+            "correlation_id": "cid-1",
+            "count": 3,
+            "ok": true,
+            "ratio": 0.5,
+            "task": "build",
+        });
+        ensure!(line == expected, "{line}");
+        Ok(())
+    }
+
+    #[test]
+    fn text_fields_follow_the_message() {
+        let fields = [
+            ("count", Value::from(3_u64)),
+            ("task", Value::from("build")),
+        ];
+        let text = text_fields(
+            &Record::builder()
+                .args(format_args!("ran"))
+                .key_values(&fields)
+                .build(),
+        );
+        assert_eq!(text, " count=3 task=build");
+    }
+
+    #[test]
+    fn the_log_file_is_appended_to_across_truncation() -> Result<()> {
+        let dir = TempScope::new_in(&std::env::temp_dir().join("rust-cli-tests"))?;
+        let path = dir.path().join("logs").join("rust-cli.log");
+        writeln!(open_append(&path)?, "first")?;
+        let mut file = open_append(&path)?;
+        writeln!(file, "second")?;
+        let text = fs::read_to_string(&path)?;
+        ensure!(text == "first\nsecond\n", "{text:?}");
+
+        // logrotate's copytruncate: the copy keeps the lines, the file
+        // starts over, and the open handle writes from its new end.
+        fs::copy(&path, path.with_extension("log.1"))?;
+        File::create(&path)?;
+        writeln!(file, "third")?;
+        let text = fs::read_to_string(&path)?;
+        ensure!(text == "third\n", "{text:?}");
+        let copy = fs::read_to_string(path.with_extension("log.1"))?;
+        ensure!(copy == "first\nsecond\n", "{copy:?}");
+        Ok(())
+    }
+}
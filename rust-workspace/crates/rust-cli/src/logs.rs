@@ -0,0 +1,240 @@
+//! `logs`: the records in `logging.file` and its rotated copies (see
+//! [`rust_core::logs`]), filtered by time and level; with `--audit`, the
+//! MCP server's tool calls (see [`rust_core::audit`]) instead.
+//!
+//! Without `--follow` the records are an ordinary result: text lines in the
+//! format the file's text records have (JSON records included), or the
+//! records as objects in the structured formats. With `--follow` the
+//! matching records are printed, then those appended after them as they
+//! arrive, until interrupted or for `--timeout` seconds: as text lines, or
+//! one JSON object per line under `--output json` and `ndjson`. `logs` only
+//! reads, so it does not take the instance lock and can follow the file
+//! while other invocations write to it.
+
+use std::fmt::Write as _;
+use std::io::{self, Write as _};
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result, bail};
+use clap::{Args, ValueEnum};
+use serde::Serialize;
+
+use rust_core::LogLevel;
+use rust_core::audit::{self, AuditOutcome, AuditRecord};
+use rust_core::logs::{self, Follower, LogFilter, LogRecord};
+
+use crate::RuntimeContext;
+use crate::output::{Output, OutputFormat, Report};
+
+/// How often `--follow` looks for appended records.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Clone, Args)]
+pub struct LogsCommand {
+    /// Keep printing records as they are appended, until interrupted
+    #[arg(short, long)]
+    follow: bool,
+    /// Show the MCP server's audit log of tool calls instead of logging.file
+    #[arg(long, conflicts_with_all = ["follow", "level"])]
+    audit: bool,
+    /// Only records since WHEN: a duration ago (30m, 1h, 2d) or an RFC 3339 time
+    #[arg(long, value_name = "WHEN", value_parser = parse_since)]
+    since: Option<SystemTime>,
+    /// Only records at LEVEL or more severe
+    #[arg(long, value_enum, value_name = "LEVEL")]
+    level: Option<LevelOption>,
+    /// Show only the last N matching records (before following)
+    #[arg(short = 'n', long, value_name = "N")]
+    lines: Option<usize>,
+}
+
+impl LogsCommand {
+    /// Whether the command runs until interrupted.
+    pub const fn follows(&self) -> bool {
+        self.follow
+    }
+}
+
+/// Minimum severity for `--level`.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum LevelOption {
+    /// Errors only
+    Error,
+    /// Warnings and errors
+    Warn,
+    /// Informational records and above
+    Info,
+    /// Debug diagnostics and above
+    Debug,
+    /// Everything
+    Trace,
+}
+
+impl From<LevelOption> for LogLevel {
+    fn from(option: LevelOption) -> Self {
+        match option {
+            LevelOption::Error => Self::Error,
+            LevelOption::Warn => Self::Warn,
+            LevelOption::Info => Self::Info,
+            LevelOption::Debug => Self::Debug,
+            LevelOption::Trace => Self::Trace,
+        }
+    }
+}
+
+/// `--since`: an RFC 3339 time, or a duration before now.
+fn parse_since(value: &str) -> Result<SystemTime, String> {
+    if let Ok(time) = humantime::parse_rfc3339_weak(value) {
+        return Ok(time);
+    }
+    let ago = humantime::parse_duration(value)
+        .map_err(|err| format!("not a duration (1h, 30m) or RFC 3339 time: {err}"))?;
+    SystemTime::now()
+        .checked_sub(ago)
+        .ok_or_else(|| format!("{value} ago is before the epoch"))
+}
+
+pub fn handle_logs(ctx: &RuntimeContext, cmd: &LogsCommand) -> Result<Output> {
+    if cmd.audit {
+        return handle_audit(ctx, cmd);
+    }
+    let Some(file) = &ctx.config.logging.file else {
+        bail!(
+            "no log file to show: set logging.file in {}",
+            ctx.paths.config_file.display()
+        );
+    };
+    let path = Path::new(file);
+    if cmd.follow
+        && !matches!(
+            ctx.common.output(),
+            OutputFormat::Text | OutputFormat::Json | OutputFormat::Ndjson
+        )
+    {
+        bail!("--follow prints records as they arrive; use --output text, json, or ndjson");
+    }
+    // Before reading, so nothing appended in between is missed.
+    let follower = Follower::new(path);
+    let mut filter = LogFilter::new(cmd.since, cmd.level.map(LogLevel::from));
+    let mut records: Vec<LogRecord> = logs::read(path)
+        .with_context(|| format!("reading {}", path.display()))?
+        .into_iter()
+        .filter(|record| filter.keep(record))
+        .collect();
+    if let Some(lines) = cmd.lines {
+        records.drain(..records.len().saturating_sub(lines));
+    }
+    if !cmd.follow {
+        return Ok(Output::new(&LogsReport(records))?.paged());
+    }
+    follow(ctx, &records, filter, follower)
+}
+
+/// Print `backlog`, then the records `follower` finds that `filter`
+/// keeps, until the command is stopped or stdout is closed.
+fn follow(
+    ctx: &RuntimeContext,
+    backlog: &[LogRecord],
+    mut filter: LogFilter,
+    mut follower: Follower,
+) -> Result<Output> {
+    let json = ctx.common.output() != OutputFormat::Text;
+    let print = |records: &[LogRecord]| -> io::Result<()> {
+        let mut stdout = io::stdout().lock();
+        for record in records {
+            if json {
+                writeln!(stdout, "{}", serde_json::to_string(record)?)?;
+            } else {
+                writeln!(stdout, "{record}")?;
+            }
+        }
+        stdout.flush()
+    };
+    let mut printed = print(backlog);
+    while printed.is_ok() && ctx.op.stop_reason().is_none() {
+        thread::sleep(POLL_INTERVAL);
+        let records: Vec<LogRecord> = follower
+            .poll()?
+            .into_iter()
+            .filter(|record| filter.keep(record))
+            .collect();
+        printed = print(&records);
+    }
+    match printed {
+        Err(err) if err.kind() != io::ErrorKind::BrokenPipe => {
+            Err(err).context("writing to stdout")
+        }
+        _ => Ok(Output::streamed()),
+    }
+}
+
+/// `logs --audit`: the recorded tool calls since `--since`, the last `-n`.
+fn handle_audit(ctx: &RuntimeContext, cmd: &LogsCommand) -> Result<Output> {
+    let since = cmd.since.map_or(0, |since| {
+        since
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs())
+    });
+    let mut calls: Vec<AuditRecord> = audit::list(&ctx.paths.state_dir)?
+        .into_iter()
+        .filter(|call| call.started_at >= since)
+        .collect();
+    if let Some(lines) = cmd.lines {
+        calls.drain(..calls.len().saturating_sub(lines));
+    }
+    Ok(Output::new(&AuditReport(calls))?.paged())
+}
+
+/// Result of `logs --audit`.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+struct AuditReport(Vec<AuditRecord>);
+
+impl Report for AuditReport {
+    fn text(&self) -> String {
+        if self.0.is_empty() {
+            return "no matching tool calls\n".to_string();
+        }
+        let mut out = String::new();
+        for call in &self.0 {
+            let at = humantime::format_rfc3339_seconds(
+                UNIX_EPOCH + Duration::from_secs(call.started_at),
+            );
+            let outcome = match &call.outcome {
+                AuditOutcome::Ok => "ok".to_string(),
+                AuditOutcome::Failed => "failed".to_string(),
+                AuditOutcome::Cancelled => "cancelled".to_string(),
+                AuditOutcome::Error { code, message } => format!("error {code}: {message}"),
+            };
+            let _ = write!(out, "{at} {} {}ms {outcome}", call.tool, call.duration_ms);
+            if let Some(client) = &call.client {
+                let _ = write!(out, " client={client}");
+            }
+            if let Some(session) = &call.session {
+                let _ = write!(out, " session={session}");
+            }
+            let _ = writeln!(out, " {}", call.arguments);
+        }
+        out
+    }
+}
+
+/// Result of `logs` without `--follow`.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+struct LogsReport(Vec<LogRecord>);
+
+impl Report for LogsReport {
+    fn text(&self) -> String {
+        if self.0.is_empty() {
+            return "no matching log records\n".to_string();
+        }
+        let mut out = String::new();
+        for record in &self.0 {
+            let _ = writeln!(out, "{record}");
+        }
+        out
+    }
+}
@@ -1,46 +1,433 @@
 //! CLI interface for rust-workspace.
 
+mod alias;
+mod backup;
+mod cache;
+mod clean;
+mod completions;
+mod debug;
+mod deprecated;
+mod dev;
+mod events;
+mod generate;
+mod healthcheck;
+mod history;
+mod log_file;
+mod logs;
+#[cfg(feature = "mcp")]
+mod mcp;
+mod output;
+mod pager;
+mod plugin;
+mod progress;
+mod run;
+mod schema;
+mod self_update;
+#[cfg(feature = "mcp")]
+mod serve;
+mod sync;
+mod update_check;
+mod version;
+
 use std::env;
+use std::ffi::OsString;
+use std::fmt::Write as _;
+use std::fs;
 use std::io::{self, IsTerminal};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
 
-use anyhow::{Context, Result, anyhow};
-use clap::{Args, CommandFactory, Parser, Subcommand, ValueEnum};
+use anyhow::{Context, Result, anyhow, bail};
+use clap::builder::FalseyValueParser;
+use clap::{Args, CommandFactory, FromArgMatches as _, Parser, Subcommand, ValueEnum};
 use clap_complete::Shell;
 use env_logger::fmt::WriteStyle;
-use log::{LevelFilter, debug, info};
+use log::{LevelFilter, debug, info, warn};
+use serde::Serialize;
 
+use rust_core::artifacts::ManifestEntry;
+use rust_core::config;
+use rust_core::ctx::{self, Ctx};
+use rust_core::doctor;
+use rust_core::export::{self, ExportFormat};
+use rust_core::flat;
+use rust_core::input::{self, Input, Source};
+use rust_core::journal::{self, Intent, Recovery};
+use rust_core::ops::{
+    Env, Operation as _, ResolvePaths, ResolvedPaths, ShowConfig, ShowConfigInput, ShownConfig,
+};
 use rust_core::paths::write_default_config;
-use rust_core::{AppConfig, AppPaths, default_cache_dir, default_parallelism};
+use rust_core::release;
+use rust_core::schema::{SchemaRef, local_schema_path, schema_reference, set_config_schema};
+use rust_core::security;
+use rust_core::shutdown;
+use rust_core::watchdog::Watchdog;
+use rust_core::{
+    Action, AppConfig, AppConfigExt as _, AppPaths, ConfigEditor, CoreError, DirHealth, ErrorCode,
+    ErrorReport, EventBus, ExitCode, ExportEntry, InstanceLock, Journal, LogFormat, LogLevel,
+    LoggingConfig, Override, PathMode, ResourceUsage, SettingChange, UsageMeter,
+};
+
+use crate::alias::{AliasCommand, handle_alias};
+use crate::backup::{BackupCommand, handle_backup};
+use crate::cache::{CacheCommand, handle_cache};
+use crate::clean::{CleanCommand, handle_clean};
+use crate::debug::{DebugCommand, handle_debug};
+use crate::dev::{DevCommand, handle_dev};
+use crate::events::Event;
+use crate::generate::{GenerateCommand, handle_generate};
+use crate::healthcheck::{HealthcheckCommand, handle_healthcheck};
+use crate::history::{HistoryCommand, handle_history};
+use crate::logs::{LogsCommand, handle_logs};
+#[cfg(feature = "mcp")]
+use crate::mcp::{McpArgs, handle_mcp};
+use crate::output::{Output, OutputFormat, Report};
+use crate::pager::Pager;
+use crate::plugin::{PluginCommand, handle_plugin};
+use crate::run::{RunCommand, handle_run};
+use crate::schema::{SchemaCommand, handle_schema};
+use crate::self_update::{SelfUpdateCommand, handle_self_update};
+#[cfg(feature = "mcp")]
+use crate::serve::{ServeCommand, handle_serve};
+use crate::sync::{SyncCommand, handle_sync};
+use crate::update_check::UpdateNotice;
+use crate::version::{LONG_VERSION, handle_version};
 
 const APP_NAME: &str = env!("CARGO_PKG_NAME");
 
-fn main() -> anyhow::Result<()> {
-    try_main()
+/// Repository URL, where releases are published.
+const REPO_URL: &str = env!("CARGO_PKG_REPOSITORY");
+
+/// How long past its deadline a command may take to stop on its own
+/// before the watchdog ends the process.
+const WATCHDOG_GRACE: Duration = Duration::from_secs(2);
+
+fn main() -> std::process::ExitCode {
+    if let Some(code) = completions::complete_from_env() {
+        return code.into();
+    }
+    if let Some(code) = update_check::check_from_env() {
+        return code.into();
+    }
+    let args = match alias::expand(env::args_os().collect()) {
+        Ok(args) => args,
+        Err(err) => return report_error(&err, ErrorFormat::Text).into(),
+    };
+    let (args, deprecations) = match deprecated::rewrite(args) {
+        Ok(rewritten) => rewritten,
+        Err(err) => return exit_for_usage(&err),
+    };
+    let (cli, operation) = match parse(&args) {
+        Ok(parsed) => parsed,
+        Err(err) if err.kind() == clap::error::ErrorKind::InvalidSubcommand => {
+            return match plugin::dispatch(&args) {
+                Ok(Some(code)) => code,
+                Ok(None) => exit_for_usage(&err),
+                Err(err) => report_error(&err, ErrorFormat::Text).into(),
+            };
+        }
+        Err(err) => return exit_for_usage(&err),
+    };
+    let format = cli.common.error_format();
+    let (quiet, diagnostics) = (cli.common.quiet, cli.common.diagnostics);
+    let result = try_main(cli, operation, &deprecations);
+    // The context that led up to a failure, just above its report. A JSON
+    // report has stderr to itself, so that it parses.
+    if result.is_err() && !quiet {
+        if format != ErrorFormat::Json {
+            log_file::dump_recent("log records leading up to the error:");
+        }
+    } else if diagnostics {
+        log_file::dump_recent("recent log records:");
+    }
+    match result {
+        Ok(code) => code.into(),
+        Err(err) => report_error(&err, format).into(),
+    }
+}
+
+/// Parse `args`, along with the subcommand path they select (such as
+/// `config migrate`), which names the command in a timeout error.
+fn parse(args: &[OsString]) -> Result<(Cli, String), clap::Error> {
+    let matches = Cli::command().try_get_matches_from(args)?;
+    let mut operation = Vec::new();
+    let mut current = &matches;
+    while let Some((name, sub)) = current.subcommand() {
+        operation.push(name);
+        current = sub;
+    }
+    Ok((Cli::from_arg_matches(&matches)?, operation.join(" ")))
+}
+
+/// How [`report_error`] prints a failed run's error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ErrorFormat {
+    /// `Error[E00NN]: ...` on stderr.
+    Text,
+    /// An [`ErrorReport`] object on stderr.
+    Json,
+    /// An `error` event on stdout, ending the `--output ndjson` stream.
+    Event,
 }
 
-fn try_main() -> Result<()> {
-    let cli = Cli::parse();
+/// Print a failed run's error in `format`.
+fn report_error(err: &anyhow::Error, format: ErrorFormat) -> ExitCode {
+    let report = ErrorReport::from_error(err);
+    if format == ErrorFormat::Event {
+        Event::Error(&report).emit();
+    } else if format == ErrorFormat::Json {
+        match serde_json::to_string(&report) {
+            Ok(line) => eprintln!("{line}"),
+            Err(ser_err) => eprintln!("Error: {err:?} (serializing error report: {ser_err})"),
+        }
+    } else if let Some(code) = report.error_code {
+        eprintln!("Error[{code}]: {err:?}");
+        eprintln!("For more information about this error, run `{APP_NAME} explain {code}`.");
+    } else {
+        eprintln!("Error: {err:?}");
+    }
+    report.kind
+}
 
-    let ctx = RuntimeContext::new(cli.common.clone())?;
-    ctx.init_logging()?;
+/// Print a clap error (or help/version output) and map it to an exit code.
+fn exit_for_usage(err: &clap::Error) -> std::process::ExitCode {
+    let _ = err.print();
+    if err.use_stderr() {
+        ExitCode::Usage.into()
+    } else {
+        ExitCode::Success.into()
+    }
+}
+
+/// `deprecations` are warnings about old spellings on the command line,
+/// logged once logging is set up.
+fn try_main(cli: Cli, operation: String, deprecations: &[String]) -> Result<ExitCode> {
+    // Reports through its exit status and one JSON line, and must not
+    // create files, take the lock, or log.
+    if let Command::Healthcheck(cmd) = &cli.command {
+        return Ok(handle_healthcheck(&cli.common, cmd));
+    }
+
+    let meter = UsageMeter::start();
+    if cli.common.entrypoint {
+        // Before anything spawns a thread, so every thread inherits the mask.
+        shutdown::install()?;
+    }
+    // Inherited from a parent invocation (a builtin task), else new.
+    let correlation_id = ctx::correlation_id();
+    cli.common
+        .init_logging(&correlation_id, &LoggingConfig::default())?;
+    for warning in deprecations {
+        warn!("{warning}");
+    }
+    // These run without loading the config, which may be what is broken.
+    let early = match &cli.command {
+        Command::Explain { code } => Some(handle_explain(*code)),
+        Command::Version => Some(handle_version()),
+        _ => None,
+    };
+    if let Some(output) = early {
+        return cli
+            .common
+            .output()
+            .emit(output?, None)
+            .map(|()| ExitCode::Success);
+    }
+    if cli.common.no_config && writes_config_file(&cli.command) {
+        bail!("`{operation}` writes the config file, which --no-config leaves alone");
+    }
+    let mut ctx =
+        RuntimeContext::new(cli.common.clone(), correlation_id, takes_lock(&cli.command))?;
+    let limit = time_limit(&ctx, &cli.command);
+    ctx.op = ctx.op.with_timeout(limit);
+    ctx.common
+        .init_logging(ctx.op.correlation_id(), &ctx.config.logging)?;
     debug!("resolved paths: {:#?}", ctx.paths);
+    if let Some(file) = &ctx.config.logging.file
+        && !ctx.op.dry_run()
+        && let Err(err) = log_file::attach(Path::new(file))
+    {
+        warn!("not logging to logging.file: {err:#}");
+    }
+
+    let notice = (!matches!(cli.command, Command::SelfUpdate(_)))
+        .then(|| UpdateNotice::start(&ctx))
+        .flatten();
 
-    match cli.command {
+    let watchdog = arm_watchdog(
+        &ctx,
+        limit.filter(|_| !writes_at_length(&cli.command)),
+        operation,
+    )?;
+    let result = match cli.command {
         Command::Run(cmd) => handle_run(&ctx, cmd),
-        Command::Init(cmd) => handle_init(&ctx, cmd),
+        Command::Init { schema } => handle_init(&ctx, schema),
         Command::Config { command } => handle_config(&ctx, command),
-        Command::Completions { shell } => {
-            handle_completions(shell);
-            Ok(())
+        Command::Paths { command } => handle_paths(&ctx, command),
+        Command::Healthcheck(cmd) => return Ok(handle_healthcheck(&ctx.common, &cmd)),
+        Command::History { command } => handle_history(&ctx, command),
+        Command::Logs(cmd) => handle_logs(&ctx, &cmd),
+        Command::Cache { command } => handle_cache(&ctx, command),
+        Command::Clean(cmd) => handle_clean(&ctx, &cmd),
+        Command::Backup { command } => handle_backup(&ctx, command),
+        Command::Sync { command } => handle_sync(&ctx, command),
+        Command::SelfUpdate(cmd) => handle_self_update(&ctx, &cmd),
+        Command::Release { command } => handle_release(&ctx, command),
+        Command::Dev { command } => handle_dev(&ctx, command),
+        Command::Debug { command } => handle_debug(&ctx, command),
+        Command::Generate { command } => handle_generate(&ctx, command),
+        #[cfg(feature = "mcp")]
+        Command::Serve { command } => handle_serve(&ctx, command),
+        #[cfg(feature = "mcp")]
+        Command::Mcp(args) => handle_mcp(&ctx, args),
+        Command::Schema { command } => handle_schema(&ctx, command),
+        Command::Alias { command } => handle_alias(&ctx, command),
+        Command::Plugin { command } => handle_plugin(command),
+        Command::Explain { code } => handle_explain(code),
+        Command::Version => handle_version(),
+        Command::Completions { shell } => handle_completions(shell),
+    };
+    // The limit covers the work, not the time spent reading it in the pager.
+    drop(watchdog);
+    let pager = Pager::select(&ctx.common, &ctx.config.output);
+    let result = result.and_then(|output| {
+        let output = if ctx.op.dry_run() {
+            output.with_plan(&ctx.op.planner().actions())?
+        } else {
+            output
+        };
+        ctx.common.output().emit(output, pager.as_ref())
+    });
+
+    if let Some(signal) = shutdown::requested() {
+        info!("shut down cleanly after {signal}");
+    }
+    let usage = meter.finish();
+    debug!("resource usage: {usage}");
+    if ctx.common.stats {
+        print_stats(&ctx, &usage)?;
+    }
+    if let Some(notice) = notice {
+        notice.finish();
+    }
+    result.map(|()| ExitCode::Success)
+}
+
+/// Whether `command` creates or rewrites the config file.
+const fn writes_config_file(command: &Command) -> bool {
+    matches!(
+        command,
+        Command::Init { .. }
+            | Command::Config {
+                command: ConfigCommand::Reset { .. }
+                    | ConfigCommand::Import { .. }
+                    | ConfigCommand::SetSchema { .. }
+                    | ConfigCommand::Migrate
+            }
+            | Command::Sync {
+                command: SyncCommand::Push | SyncCommand::Pull(_)
+            }
+    )
+}
+
+/// Whether `command` takes the instance lock: those that write the config
+/// file, state, data, or cache do, so two of them never interleave. The
+/// rest only read and run alongside them; `serve` and `mcp` tool calls take
+/// the lock as they need it.
+const fn takes_lock(command: &Command) -> bool {
+    if writes_config_file(command) {
+        return true;
+    }
+    match command {
+        // `release stamp` journals its writes in the state directory.
+        Command::Run(_)
+        | Command::Clean(_)
+        | Command::Sync { .. }
+        | Command::SelfUpdate(_)
+        | Command::Release { .. } => true,
+        Command::Paths {
+            command: PathsCommand::Doctor(cmd),
+        } => cmd.roll_forward || cmd.revert,
+        Command::History { command } => matches!(command, HistoryCommand::Clear),
+        // As does `generate all`.
+        Command::Generate { command } => matches!(command, GenerateCommand::All(_)),
+        Command::Cache { command } => {
+            matches!(command, CacheCommand::Clear(_) | CacheCommand::Repair)
         }
+        // Creating one too, so the archive is not taken mid-write.
+        Command::Backup { command } => !matches!(command, BackupCommand::Verify(_)),
+        _ => false,
+    }
+}
+
+/// Time limit on `command`: `--timeout`, else `runtime.timeout`. `run`
+/// limits each task instead, `healthcheck` has its own deadline, and `dev`
+/// and `serve` run until interrupted, as `logs --follow` does unless
+/// `--timeout` is given. Commands that [write at length](writes_at_length)
+/// only have the limit `--timeout` gives them.
+fn time_limit(ctx: &RuntimeContext, command: &Command) -> Option<Duration> {
+    match command {
+        Command::Run(_) | Command::Healthcheck(_) | Command::Dev { .. } => return None,
+        #[cfg(feature = "mcp")]
+        Command::Serve { .. } => return None,
+        _ => {}
+    }
+    let follows = matches!(command, Command::Logs(cmd) if cmd.follows());
+    if follows || writes_at_length(command) {
+        return ctx.common.timeout.map(Duration::from_secs);
+    }
+    ctx.common
+        .timeout
+        .or(ctx.config.runtime.timeout)
+        .map(Duration::from_secs)
+}
+
+/// Whether `command` can spend minutes writing files or talking to a remote:
+/// `backup create` and `restore`, `sync`, `clean`, and `self-update`. They
+/// are not bound by `runtime.timeout`, and under `--timeout` they stop at
+/// their next safe point only; the watchdog never exits the process in the
+/// middle of their writes.
+const fn writes_at_length(command: &Command) -> bool {
+    match command {
+        Command::Backup { command } => !matches!(command, BackupCommand::Verify(_)),
+        Command::Sync { .. } | Command::Clean(_) | Command::SelfUpdate(_) => true,
+        _ => false,
     }
 }
 
+/// Back up the context deadline: operations stop at `limit` when they next
+/// check their context, and a command still running [`WATCHDOG_GRACE`]
+/// later is reported as timed out and the process exits with the timeout
+/// code, since it may be blocked where it cannot be interrupted.
+#[expect(
+    clippy::exit,
+    reason = "the main thread may be blocked on a file, lock, or socket"
+)]
+fn arm_watchdog(
+    ctx: &RuntimeContext,
+    limit: Option<Duration>,
+    operation: String,
+) -> Result<Option<Watchdog>> {
+    let Some(limit) = limit else {
+        return Ok(None);
+    };
+    let format = ctx.common.error_format();
+    let watchdog = Watchdog::arm(limit + WATCHDOG_GRACE, move || {
+        let err = anyhow::Error::new(CoreError::Timeout(limit))
+            .context(format!("`{APP_NAME} {operation}` did not finish"));
+        let code = report_error(&err, format);
+        std::process::exit(i32::from(code.code()));
+    })
+    .context("starting the timeout watchdog")?;
+    Ok(Some(watchdog))
+}
+
 #[derive(Debug, Parser)]
 #[command(
     author,
     version,
+    long_version = LONG_VERSION,
     about = "A batteries-included Rust CLI template.",
     propagate_version = true
 )]
@@ -57,6 +444,16 @@ pub struct CommonOpts {
     /// Override the config file path
     #[arg(long, value_name = "PATH", global = true)]
     pub config: Option<PathBuf>,
+    /// Ignore the config file: built-in defaults, environment variables, and
+    /// flags only; nothing is read or created
+    #[arg(
+        long = "no-config",
+        global = true,
+        env = no_config_var(),
+        value_parser = FalseyValueParser::new(),
+        conflicts_with = "config"
+    )]
+    pub no_config: bool,
     /// Reduce output to only errors
     #[arg(short, long, action = clap::ArgAction::SetTrue, global = true)]
     pub quiet: bool,
@@ -69,11 +466,20 @@ pub struct CommonOpts {
     /// Enable trace logging (overrides other levels)
     #[arg(long, global = true)]
     pub trace: bool,
-    /// Output machine readable JSON
-    #[arg(long, global = true, conflicts_with = "yaml")]
+    /// Output format for command results
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FORMAT",
+        default_value_t = OutputFormat::Text,
+        global = true
+    )]
+    pub output: OutputFormat,
+    /// Alias for --output json
+    #[arg(long, global = true, hide = true, conflicts_with_all = ["yaml", "output"])]
     pub json: bool,
-    /// Output machine readable YAML
-    #[arg(long, global = true)]
+    /// Alias for --output yaml
+    #[arg(long, global = true, hide = true, conflicts_with = "output")]
     pub yaml: bool,
     /// Disable ANSI colors in output
     #[arg(long = "no-color", global = true, conflicts_with = "color")]
@@ -81,27 +487,251 @@ pub struct CommonOpts {
     /// Control color output (auto, always, never)
     #[arg(long, value_enum, default_value_t = ColorOption::Auto, global = true)]
     pub color: ColorOption,
+    /// Log record format; overrides `logging.format`
+    #[arg(long = "log-format", value_enum, value_name = "FORMAT", global = true)]
+    pub log_format: Option<LogFormatOption>,
     /// Do not change anything on disk
     #[arg(long = "dry-run", global = true)]
     pub dry_run: bool,
     /// Assume "yes" for interactive prompts
-    #[arg(short = 'y', long = "yes", alias = "force", global = true)]
+    #[arg(short = 'y', long = "yes", global = true)]
     pub assume_yes: bool,
     /// Never prompt for input; fail if confirmation would be required
     #[arg(long = "no-input", global = true)]
     pub no_input: bool,
-    /// Maximum seconds to allow an operation to run
+    /// Override a config setting for this run, over the file and environment
+    /// (repeatable), e.g. runtime.timeout=120
+    #[arg(long = "set", value_name = "KEY=VALUE", global = true)]
+    pub set: Vec<Override>,
+    /// Maximum seconds a command (or each task of `run`) may take
     #[arg(long = "timeout", value_name = "SECONDS", global = true)]
     pub timeout: Option<u64>,
     /// Override the degree of parallelism
     #[arg(long = "parallel", value_name = "N", global = true)]
     pub parallel: Option<usize>,
-    /// Disable progress indicators
+    /// Print long output directly instead of through `$PAGER`/less
+    #[arg(long = "no-pager", global = true)]
+    pub no_pager: bool,
+    /// Disable progress bars and spinners (also off with --quiet, structured
+    /// --output, or when stderr is not a terminal)
     #[arg(long = "no-progress", global = true)]
     pub no_progress: bool,
     /// Emit additional diagnostics for troubleshooting
     #[arg(long = "diagnostics", global = true)]
     pub diagnostics: bool,
+    /// Print wall time, CPU time, and peak memory after the command, and for each task `run` ran
+    #[arg(long = "stats", global = true)]
+    pub stats: bool,
+    /// Wait up to SECONDS for another instance to release the lock
+    #[arg(long = "wait", value_name = "SECONDS", global = true)]
+    pub wait: Option<u64>,
+    /// Do not look for a newer release in the background (see `[updates]`)
+    #[arg(long = "no-update-check", global = true)]
+    pub no_update_check: bool,
+    /// Skip the single-instance lock (concurrent runs may corrupt state)
+    #[arg(long = "no-lock", global = true, conflicts_with = "wait")]
+    pub no_lock: bool,
+    /// Use system-wide directories (/etc, /var/lib, /var/cache); the default as root
+    #[arg(long, global = true, conflicts_with = "user")]
+    pub system: bool,
+    /// Use per-user directories even when running as root
+    #[arg(long, global = true)]
+    pub user: bool,
+    /// Container mode: config from environment variables only, JSON logs on stdout,
+    /// no eager directory creation, graceful shutdown on SIGTERM
+    #[arg(long, global = true, conflicts_with = "config")]
+    pub entrypoint: bool,
+}
+
+/// Environment variable that turns on `--no-config`.
+fn no_config_var() -> String {
+    format!("{}_NO_CONFIG", rust_core::env_prefix())
+}
+
+impl CommonOpts {
+    /// Selected output format, honoring the `--json`/`--yaml` aliases.
+    const fn output(&self) -> OutputFormat {
+        if self.json {
+            OutputFormat::Json
+        } else if self.yaml {
+            OutputFormat::Yaml
+        } else {
+            self.output
+        }
+    }
+
+    /// How a failure is reported: as an event under `--output ndjson`, as
+    /// JSON under `--output json` or `--entrypoint`, else as text.
+    const fn error_format(&self) -> ErrorFormat {
+        match self.output() {
+            OutputFormat::Ndjson => ErrorFormat::Event,
+            OutputFormat::Json => ErrorFormat::Json,
+            _ if self.entrypoint => ErrorFormat::Json,
+            _ => ErrorFormat::Text,
+        }
+    }
+
+    /// Whether the config comes from defaults and the environment only, as
+    /// under `--no-config` and `--entrypoint`.
+    const fn skips_config_file(&self) -> bool {
+        self.no_config || self.entrypoint
+    }
+
+    fn path_mode(&self) -> PathMode {
+        if self.system {
+            PathMode::System
+        } else if self.user {
+            PathMode::User
+        } else {
+            PathMode::detect()
+        }
+    }
+
+    /// Set up logging for `logging`; called with the defaults before the
+    /// config is read, then again with the config.
+    ///
+    /// Levels, from weakest to strongest: `logging.level`, then
+    /// `logging.filters` per module, then `RUST_LOG` (its bare level
+    /// replacing `logging.level`, its module directives those of
+    /// `logging.filters`), then the verbosity flags, which replace the
+    /// overall level but leave module directives alone. `--quiet` turns
+    /// logging off. With `logging.file_level`, the log file is filtered by
+    /// it and `logging.filters` alone, so it keeps logging under `--quiet`.
+    fn init_logging(&self, correlation_id: &str, logging: &LoggingConfig) -> Result<()> {
+        let json = self.json_logs(logging.format);
+        let file_json = logging
+            .file_format
+            .map_or(json, |format| format == LogFormat::Json);
+        log_file::set_json(json, file_json);
+        log_file::set_ring_size(logging.ring_buffer);
+        let file = logging
+            .file_level
+            .map(|level| module_filters(level, logging).build());
+        let ring = (logging.ring_buffer > 0)
+            .then(|| module_filters(logging.level.max(LogLevel::Debug), logging).build());
+        if self.quiet {
+            let filters = log_file::Filters {
+                stderr: None,
+                file,
+                ring,
+            };
+            return log_file::install(filters, correlation_id, false)
+                .context("initializing logging");
+        }
+
+        let mut builder = module_filters(logging.level, logging);
+        if let Ok(directives) = env::var("RUST_LOG") {
+            builder.parse_filters(&directives);
+        }
+        if let Some(level) = self.flag_log_level() {
+            builder.filter_level(level);
+        }
+
+        let mut filters = log_file::Filters {
+            stderr: None,
+            file,
+            ring,
+        };
+        if self.entrypoint {
+            filters.stderr = Some(builder.build());
+            return log_file::install(filters, correlation_id, true)
+                .context("initializing JSON logging");
+        }
+
+        let force_color =
+            matches!(self.color, ColorOption::Always) || env::var_os("FORCE_COLOR").is_some();
+        let disable_color = self.no_color
+            || matches!(self.color, ColorOption::Never)
+            || env::var_os("NO_COLOR").is_some()
+            || (!force_color && !io::stderr().is_terminal());
+
+        if disable_color {
+            builder.write_style(WriteStyle::Never);
+        } else if force_color {
+            builder.write_style(WriteStyle::Always);
+        } else {
+            builder.write_style(WriteStyle::Auto);
+        }
+
+        if self.diagnostics {
+            builder.format_timestamp_millis();
+            builder.format_module_path(true);
+            builder.format_target(true);
+        }
+
+        filters.stderr = Some(builder.build());
+        log_file::install(filters, correlation_id, false).or_else(|err| {
+            if self.verbose > 0 {
+                eprintln!("logger already initialized: {err}");
+            }
+            Ok(())
+        })
+    }
+
+    /// Whether log records are JSON, given `logging.format`: `--log-format`
+    /// wins over it, and `--entrypoint` always logs JSON.
+    fn json_logs(&self, configured: LogFormat) -> bool {
+        if self.entrypoint {
+            return true;
+        }
+        match self.log_format.map_or(configured, LogFormat::from) {
+            LogFormat::Text => false,
+            LogFormat::Json => true,
+        }
+    }
+
+    /// Overall level the verbosity flags ask for, if any.
+    const fn flag_log_level(&self) -> Option<LevelFilter> {
+        if self.trace {
+            Some(LevelFilter::Trace)
+        } else if self.debug {
+            Some(LevelFilter::Debug)
+        } else {
+            match self.verbose {
+                0 => None,
+                1 => Some(LevelFilter::Debug),
+                _ => Some(LevelFilter::Trace),
+            }
+        }
+    }
+}
+
+/// A logger builder at `level`, with `logging.filters` over it.
+fn module_filters(level: LogLevel, logging: &LoggingConfig) -> env_logger::Builder {
+    let mut builder = env_logger::Builder::new();
+    builder.filter_level(level_filter(level));
+    for (module, level) in &logging.filters {
+        builder.filter_module(module, level_filter(*level));
+    }
+    builder
+}
+
+const fn level_filter(level: LogLevel) -> LevelFilter {
+    match level {
+        LogLevel::Error => LevelFilter::Error,
+        LogLevel::Warn => LevelFilter::Warn,
+        LogLevel::Info => LevelFilter::Info,
+        LogLevel::Debug => LevelFilter::Debug,
+        LogLevel::Trace => LevelFilter::Trace,
+    }
+}
+
+/// Log record format (`logging.format`).
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum LogFormatOption {
+    /// Human-readable lines.
+    Text,
+    /// One JSON object per line.
+    Json,
+}
+
+impl From<LogFormatOption> for LogFormat {
+    fn from(option: LogFormatOption) -> Self {
+        match option {
+            LogFormatOption::Text => Self::Text,
+            LogFormatOption::Json => Self::Json,
+        }
+    }
 }
 
 /// Color output mode.
@@ -119,13 +749,101 @@ pub enum ColorOption {
 enum Command {
     /// Execute the CLI's primary behavior
     Run(RunCommand),
-    /// Create config directories and default files
-    Init(InitCommand),
+    /// Create config directories and default files (overwrite with --force)
+    Init {
+        /// Where the config's #:schema directive points, for editor validation
+        #[arg(long, value_enum, value_name = "WHERE", default_value_t)]
+        schema: SchemaTarget,
+    },
     /// Inspect and manage configuration
     Config {
         #[command(subcommand)]
         command: ConfigCommand,
     },
+    /// Diagnose the config, data, state, and cache directories
+    Paths {
+        #[command(subcommand)]
+        command: PathsCommand,
+    },
+    /// Probe config, state directory, and daemon; exit 0 if healthy, 1 if not
+    Healthcheck(HealthcheckCommand),
+    /// List, show, and clear the recorded `run` invocations
+    History {
+        #[command(subcommand)]
+        command: HistoryCommand,
+    },
+    /// Show the log file (and its rotated copies), filtered by time and level
+    Logs(LogsCommand),
+    /// Inspect and repair the content cache
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommand,
+    },
+    /// Remove the cache, log file, run history, leftovers, or state by category
+    Clean(CleanCommand),
+    /// Back up, verify, and restore the config, state, and data directories
+    Backup {
+        #[command(subcommand)]
+        command: BackupCommand,
+    },
+    /// Share portable settings between machines through a git remote
+    Sync {
+        #[command(subcommand)]
+        command: SyncCommand,
+    },
+    /// Replace this binary with the latest release (checksum-verified; needs --yes)
+    SelfUpdate(SelfUpdateCommand),
+    /// Maintainer tooling for cutting releases
+    Release {
+        #[command(subcommand)]
+        command: ReleaseCommand,
+    },
+    /// Maintainer tooling for working on this template
+    Dev {
+        #[command(subcommand)]
+        command: DevCommand,
+    },
+    /// Build and runtime facts for bug reports
+    Debug {
+        #[command(subcommand)]
+        command: DebugCommand,
+    },
+    /// Write or verify generated artifacts (schema, example config, man page, completions, docs)
+    Generate {
+        #[command(subcommand)]
+        command: GenerateCommand,
+    },
+    /// Host a server in this process (`serve mcp`: the rust-mcp server, no second binary needed)
+    #[cfg(feature = "mcp")]
+    Serve {
+        #[command(subcommand)]
+        command: ServeCommand,
+    },
+    /// Talk to an MCP server as a client: list tools, call one, read a resource
+    #[cfg(feature = "mcp")]
+    Mcp(McpArgs),
+    /// Publish the config schema to the URL its $id points at
+    Schema {
+        #[command(subcommand)]
+        command: SchemaCommand,
+    },
+    /// Inspect the command aliases defined in [aliases]
+    Alias {
+        #[command(subcommand)]
+        command: AliasCommand,
+    },
+    /// Inspect external subcommands: rust-cli-<name> executables on PATH run as `rust-cli <name>`
+    Plugin {
+        #[command(subcommand)]
+        command: PluginCommand,
+    },
+    /// Explain an error code (e.g. E0002): causes and how to fix it; lists all codes without one
+    Explain {
+        #[arg(value_name = "CODE")]
+        code: Option<ErrorCode>,
+    },
+    /// Print the version, commit, build date, target, rustc, and features
+    Version,
     /// Generate shell completions
     Completions {
         #[arg(value_enum)]
@@ -133,235 +851,911 @@ enum Command {
     },
 }
 
-#[derive(Debug, Clone, Args)]
-struct RunCommand {
-    /// Named task to execute
-    #[arg(value_name = "TASK", default_value = "default")]
-    task: String,
-    /// Override the profile to run under
-    #[arg(long, value_name = "PROFILE")]
-    profile: Option<String>,
-}
-
-#[derive(Debug, Clone, Copy, Args)]
-struct InitCommand {
-    /// Recreate configuration even if it already exists
-    #[arg(long = "force")]
-    force: bool,
-}
-
-#[derive(Debug, Clone, Copy, Subcommand)]
+#[derive(Debug, Clone, Subcommand)]
 enum ConfigCommand {
     /// Output the effective configuration
-    Show,
+    Show {
+        /// Print secret values as they are instead of masking them (keys naming a token,
+        /// password, secret, key, ... or matching security.redact); the text form is then
+        /// the loaded config's debug dump rather than TOML
+        #[arg(long)]
+        show_secrets: bool,
+        /// Mask secret values, as is the default (kept for scripts that pass it)
+        #[arg(long, hide = true, conflicts_with = "show_secrets")]
+        redact: bool,
+        /// Text form: TOML, or flat `dotted.key = value` lines sorted by key for grepping
+        /// and diffing
+        #[arg(long, value_enum, value_name = "FORMAT")]
+        format: Option<ShowFormat>,
+    },
+    /// Set the settings listed in a file in the config file, keeping its comments
+    Import {
+        /// Flat listing (as `config show --format flat` prints), TOML, JSON, or YAML
+        /// settings: a file, a URL, or - for stdin
+        #[arg(value_name = "SOURCE", default_value = "-")]
+        source: Source,
+    },
     /// Print the resolved config file path
     Path,
     /// Print all resolved paths (config, data, state, cache)
     Paths,
     /// Print the JSON schema for the config file
     Schema,
+    /// Print a Markdown reference of every setting: type, default, and description
+    Docs,
     /// Regenerate the default configuration file
-    Reset,
+    Reset {
+        /// Where the config's #:schema directive points, for editor validation
+        #[arg(long, value_enum, value_name = "WHERE", default_value_t)]
+        schema: SchemaTarget,
+    },
+    /// Point the config's #:schema directive at the published schema or a local copy
+    SetSchema {
+        #[arg(value_enum)]
+        target: SchemaTarget,
+    },
+    /// Upgrade the config file to this build's format version, keeping comments
+    Migrate,
+    /// Render the effective configuration as environment variables for a deployment target
+    Export {
+        /// Output format; secret-bearing keys are emitted as references, not values
+        #[arg(long, value_enum, value_name = "FORMAT")]
+        format: ExportTarget,
+    },
+}
+
+/// Where a config file's `#:schema` directive points.
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+enum SchemaTarget {
+    /// The published schema URL; editors need network access
+    #[default]
+    Remote,
+    /// config.schema.json written next to the config file, by relative path
+    Local,
+    /// config.schema.json written next to the config file, by file:// URL
+    FileUrl,
+}
+
+impl From<SchemaTarget> for SchemaRef {
+    fn from(target: SchemaTarget) -> Self {
+        match target {
+            SchemaTarget::Remote => Self::Remote,
+            SchemaTarget::Local => Self::Local,
+            SchemaTarget::FileUrl => Self::FileUrl,
+        }
+    }
+}
+
+/// Text form of `config show`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ShowFormat {
+    /// A TOML document, like the config file
+    Toml,
+    /// `dotted.key = value` lines sorted by key, like `git config --list`
+    Flat,
+}
+
+/// Deployment target for `config export`.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum ExportTarget {
+    /// Kubernetes `ConfigMap` manifest (YAML), for `envFrom`
+    #[value(name = "k8s-configmap")]
+    K8sConfigMap,
+    /// Env file for `docker run --env-file`
+    DockerEnv,
+    /// Env file for systemd's `EnvironmentFile=`
+    SystemdEnv,
+}
+
+impl From<ExportTarget> for ExportFormat {
+    fn from(target: ExportTarget) -> Self {
+        match target {
+            ExportTarget::K8sConfigMap => Self::K8sConfigMap,
+            ExportTarget::DockerEnv => Self::DockerEnv,
+            ExportTarget::SystemdEnv => Self::SystemdEnv,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Subcommand)]
+enum PathsCommand {
+    /// Report existence, permissions, ownership, and disk usage of each
+    /// directory, and any interrupted operation
+    Doctor(DoctorCommand),
+}
+
+#[derive(Debug, Clone, Copy, Args)]
+struct DoctorCommand {
+    /// Finish an interrupted operation by installing the rest of its files
+    #[arg(long, conflicts_with = "revert")]
+    roll_forward: bool,
+    /// Undo an interrupted operation by restoring the files it replaced
+    #[arg(long)]
+    revert: bool,
+}
+
+#[derive(Debug, Subcommand)]
+enum ReleaseCommand {
+    /// Set the version across the workspace and regenerate all artifacts
+    Stamp(StampCommand),
+}
+
+#[derive(Debug, Clone, Args)]
+struct StampCommand {
+    /// Version to stamp (MAJOR.MINOR.PATCH[-pre][+build])
+    #[arg(value_name = "VERSION")]
+    new_version: String,
+    /// Workspace root (defaults to the nearest workspace above the current directory)
+    #[arg(long, value_name = "PATH")]
+    root: Option<PathBuf>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 struct RuntimeContext {
     common: CommonOpts,
+    /// Context handed to core operations; its deadline is set once the
+    /// command is known.
+    op: Ctx,
     paths: AppPaths,
     config: AppConfig,
+    _lock: Option<InstanceLock>,
 }
 
 impl RuntimeContext {
-    fn new(common: CommonOpts) -> Result<Self> {
-        let paths = AppPaths::discover(common.config.as_deref())?;
-        let config = AppConfig::load(&paths, common.dry_run)?;
-        let paths = paths.apply_overrides(&config)?;
+    fn new(common: CommonOpts, correlation_id: String, lock: bool) -> Result<Self> {
+        let events = EventBus::default();
+        events.subscribe(|event| {
+            if log::log_enabled!(log::Level::Debug) {
+                debug!(
+                    "event: {}",
+                    serde_json::to_string(event).unwrap_or_default()
+                );
+            }
+        });
+        let op = Ctx::default()
+            .with_dry_run(common.dry_run)
+            .with_progress(Arc::from(progress::reporter(&common)))
+            .with_events(events)
+            .with_correlation_id(correlation_id);
+        let (paths, config) = if common.skips_config_file() {
+            let paths = AppPaths::discover_in(common.path_mode(), common.config.as_deref())?;
+            let config = AppConfig::from_env_with_overrides(&common.set)?;
+            (paths.apply_overrides(&config)?, config)
+        } else {
+            AppPaths::load(
+                common.path_mode(),
+                common.config.as_deref(),
+                &op,
+                &common.set,
+            )?
+        };
+        for warning in paths.check_ownership()? {
+            warn!("{warning}");
+        }
         let ctx = Self {
             common,
+            op,
             paths,
             config,
+            _lock: None,
         };
         ctx.ensure_directories()?;
-        Ok(ctx)
+        let lock = if lock { ctx.acquire_lock()? } else { None };
+        match journal::pending(&ctx.paths.journal_dir()) {
+            Ok(Some(intent)) => warn!(
+                "`{}` was interrupted and not recovered; see `{APP_NAME} paths doctor`",
+                intent.operation
+            ),
+            Ok(None) => {}
+            Err(err) => warn!("{err:#}"),
+        }
+        Ok(Self { _lock: lock, ..ctx })
     }
 
-    fn init_logging(&self) -> Result<()> {
-        if self.common.quiet {
-            log::set_max_level(LevelFilter::Off);
+    fn ensure_directories(&self) -> Result<()> {
+        if self.op.dry_run() {
+            self.paths.plan_directories(&self.op);
+            self.paths.sweep_stale_temp(&self.op)?;
             return Ok(());
         }
+        if self.common.entrypoint {
+            // Commands create the directories they write to; a container
+            // should not need volumes for directories it never uses.
+            debug!("entrypoint mode: not creating data and state directories up front");
+            self.paths.sweep_stale_temp(&self.op)?;
+            return Ok(());
+        }
+        let created = self.paths.ensure_directories()?;
+        if self.paths.mode == PathMode::System {
+            security::relabel(&created, self.config.security.selinux)?;
+        }
+        self.paths.sweep_stale_temp(&self.op)?;
+        Ok(())
+    }
 
-        let mut builder =
-            env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info"));
+    fn acquire_lock(&self) -> Result<Option<InstanceLock>> {
+        if self.common.no_lock || self.op.dry_run() {
+            debug!("skipping instance lock");
+            return Ok(None);
+        }
+        let wait = self.common.wait.map(Duration::from_secs);
+        Ok(Some(InstanceLock::acquire(&self.paths.lock_file(), wait)?))
+    }
 
-        builder.filter_level(self.effective_log_level());
+    /// Start a journal for an operation that writes several files; under
+    /// `--dry-run` it only plans.
+    fn journal(&self, operation: &str) -> Result<Journal> {
+        if self.op.dry_run() {
+            return Ok(Journal::dry_run(operation, &self.op));
+        }
+        Journal::begin(&self.paths.journal_dir(), operation)
+    }
 
-        let force_color = matches!(self.common.color, ColorOption::Always)
-            || env::var_os("FORCE_COLOR").is_some();
-        let disable_color = self.common.no_color
-            || matches!(self.common.color, ColorOption::Never)
-            || env::var_os("NO_COLOR").is_some()
-            || (!force_color && !io::stderr().is_terminal());
+    /// What a shared [`rust_core::ops`] operation runs against.
+    const fn env(&self) -> Env<'_> {
+        Env {
+            paths: &self.paths,
+            config: &self.config,
+            ctx: &self.op,
+        }
+    }
+}
 
-        if disable_color {
-            builder.write_style(WriteStyle::Never);
-        } else if force_color {
-            builder.write_style(WriteStyle::Always);
+fn print_stats(ctx: &RuntimeContext, usage: &ResourceUsage) -> Result<()> {
+    if matches!(
+        ctx.common.output(),
+        OutputFormat::Json | OutputFormat::Ndjson
+    ) {
+        eprintln!(
+            "{}",
+            serde_json::to_string(usage).context("serializing stats to JSON")?
+        );
+    } else {
+        eprintln!("stats: {usage}");
+    }
+    Ok(())
+}
+
+/// Result of `init` and `config reset`.
+#[derive(Debug, Serialize)]
+struct ConfigWritten {
+    #[serde(with = "rust_core::pathfmt")]
+    config_file: PathBuf,
+    /// Target of the config's `#:schema` directive.
+    schema: String,
+    /// False under `--dry-run`.
+    written: bool,
+}
+
+impl Report for ConfigWritten {
+    fn text(&self) -> String {
+        if self.written {
+            format!("wrote default config to {}\n", self.config_file.display())
         } else {
-            builder.write_style(WriteStyle::Auto);
+            String::new()
         }
+    }
+}
 
-        if self.common.diagnostics {
-            builder.format_timestamp_millis();
-            builder.format_module_path(true);
-            builder.format_target(true);
+/// Point the config's `#:schema` directive at `target` (writing the local
+/// schema copy it needs); under `--dry-run`, only plan it. Returns the
+/// directive's target.
+fn point_schema(ctx: &RuntimeContext, target: SchemaTarget) -> Result<String> {
+    let config_file = &ctx.paths.config_file;
+    if ctx.op.dry_run() {
+        if matches!(target, SchemaTarget::Local | SchemaTarget::FileUrl) {
+            ctx.op.plan(Action::WriteFile {
+                path: local_schema_path(config_file),
+            });
         }
-
-        builder.try_init().or_else(|err| {
-            if self.common.verbose > 0 {
-                eprintln!("logger already initialized: {err}");
-            }
-            Ok(())
-        })
+        ctx.op.plan(Action::WriteFile {
+            path: config_file.clone(),
+        });
+        schema_reference(rust_core::APP_NAME, config_file, target.into())
+    } else {
+        set_config_schema(rust_core::APP_NAME, config_file, target.into())
     }
+}
 
-    const fn effective_log_level(&self) -> LevelFilter {
-        if self.common.trace {
-            LevelFilter::Trace
-        } else if self.common.debug {
-            LevelFilter::Debug
+/// Result of `config set-schema`.
+#[derive(Debug, Serialize)]
+struct SchemaSet<'a> {
+    #[serde(with = "rust_core::pathfmt")]
+    config_file: &'a Path,
+    /// New target of the `#:schema` directive.
+    schema: String,
+    /// The schema copy written for a local target.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        with = "rust_core::pathfmt::option"
+    )]
+    local_schema: Option<PathBuf>,
+    dry_run: bool,
+}
+
+impl Report for SchemaSet<'_> {
+    fn text(&self) -> String {
+        let verb = if self.dry_run {
+            "would point"
         } else {
-            match self.common.verbose {
-                0 => LevelFilter::Info,
-                1 => LevelFilter::Debug,
-                _ => LevelFilter::Trace,
-            }
+            "pointed"
+        };
+        let mut text = format!(
+            "{verb} #:schema in {} at {}\n",
+            self.config_file.display(),
+            self.schema
+        );
+        if let Some(local) = &self.local_schema {
+            let verb = if self.dry_run { "would write" } else { "wrote" };
+            let _ = writeln!(text, "{verb} {}", local.display());
         }
+        text
     }
+}
 
-    fn ensure_directories(&self) -> Result<()> {
-        if self.common.dry_run {
-            self.paths.log_dry_run();
-            return Ok(());
+/// Result of `config migrate`.
+#[derive(Debug, Serialize)]
+struct ConfigMigrated<'a> {
+    #[serde(with = "rust_core::pathfmt")]
+    config_file: &'a Path,
+    from_version: u32,
+    to_version: u32,
+    /// Whether the file needed rewriting.
+    changed: bool,
+    dry_run: bool,
+}
+
+impl Report for ConfigMigrated<'_> {
+    fn text(&self) -> String {
+        let file = self.config_file.display();
+        if !self.changed {
+            format!("{file} is already at format version {}\n", self.to_version)
+        } else if self.dry_run {
+            format!(
+                "would migrate {file} from format version {} to {}\n",
+                self.from_version, self.to_version
+            )
+        } else {
+            format!(
+                "migrated {file} from format version {} to {}\n",
+                self.from_version, self.to_version
+            )
         }
-        self.paths.ensure_directories()
     }
 }
 
-fn handle_run(ctx: &RuntimeContext, cmd: RunCommand) -> Result<()> {
-    let effective = ctx.config.clone().with_profile_override(cmd.profile);
-    let output = if ctx.common.json {
-        serde_json::to_string_pretty(&effective).context("serializing run output to JSON")?
-    } else if ctx.common.yaml {
-        serde_yaml::to_string(&effective).context("serializing run output to YAML")?
+fn handle_init(ctx: &RuntimeContext, schema: SchemaTarget) -> Result<Output> {
+    if ctx.paths.config_file.exists() && !ctx.common.assume_yes {
+        return Err(anyhow!(
+            "config already exists at {} (use --force to overwrite)",
+            ctx.paths.config_file.display()
+        ));
+    }
+
+    if ctx.op.dry_run() {
+        ctx.op.plan(Action::WriteFile {
+            path: ctx.paths.config_file.clone(),
+        });
     } else {
+        write_default_config(&ctx.paths.config_file)?;
+    }
+    Output::new(&ConfigWritten {
+        config_file: ctx.paths.config_file.clone(),
+        schema: point_schema(ctx, schema)?,
+        written: !ctx.op.dry_run(),
+    })
+}
+
+/// Result of `config show`: the config, or its settings with secrets
+/// masked, and the text form asked for.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+struct ConfigShow {
+    settings: ShownConfig,
+    #[serde(skip)]
+    format: Option<ShowFormat>,
+}
+
+impl Report for ConfigShow {
+    fn text(&self) -> String {
+        match (&self.settings, self.format) {
+            (ShownConfig::Full(config), None) => format!("{config:#?}\n"),
+            (settings, Some(ShowFormat::Flat)) => flat::render(settings)
+                .unwrap_or_else(|err| format!("# cannot render as flat settings ({err})\n")),
+            (settings, _) => {
+                // TOML has no null: unset settings are left out.
+                fn strip(value: &mut serde_json::Value) {
+                    if let serde_json::Value::Object(object) = value {
+                        object.retain(|_, child| !child.is_null());
+                        object.values_mut().for_each(strip);
+                    }
+                }
+                let mut settings = serde_json::to_value(settings).unwrap_or_default();
+                strip(&mut settings);
+                toml::to_string_pretty(&settings).unwrap_or_else(|err| {
+                    format!("# cannot render as TOML ({err})\n{settings:#}\n")
+                })
+            }
+        }
+    }
+}
+
+/// Result of `config path`.
+#[derive(Debug, Serialize)]
+struct ConfigPath<'a> {
+    #[serde(with = "rust_core::pathfmt")]
+    config_file: &'a Path,
+}
+
+impl Report for ConfigPath<'_> {
+    fn text(&self) -> String {
+        format!("{}\n", self.config_file.display())
+    }
+}
+
+/// Result of `config paths`.
+impl Report for ResolvedPaths {
+    fn text(&self) -> String {
         format!(
-            "Running task '{}' with profile '{}' (parallelism: {})",
-            cmd.task,
-            effective.profile,
-            effective
-                .runtime
-                .parallelism
-                .unwrap_or_else(default_parallelism)
+            "mode:   {}\nconfig: {}\ndata:   {}\nstate:  {}\ncache:  {}\n",
+            self.mode.name(),
+            self.config.display(),
+            self.data.display(),
+            self.state.display(),
+            self.cache.display()
         )
-    };
+    }
+}
 
-    println!("{output}");
-    Ok(())
+/// Result of `config schema`: the schema itself.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+struct ConfigSchema(serde_json::Value);
+
+impl Report for ConfigSchema {
+    fn text(&self) -> String {
+        format!("{CONFIG_SCHEMA}\n")
+    }
 }
 
-fn handle_init(ctx: &RuntimeContext, cmd: InitCommand) -> Result<()> {
-    if ctx.paths.config_file.exists() && !(cmd.force || ctx.common.assume_yes) {
-        return Err(anyhow!(
-            "config already exists at {} (use --force to overwrite)",
-            ctx.paths.config_file.display()
-        ));
+const CONFIG_SCHEMA: &str = include_str!("../../../examples/config.schema.json");
+
+/// Result of `config docs`: the Markdown reference, as text in either form.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+struct ConfigDocs(String);
+
+impl Report for ConfigDocs {
+    fn text(&self) -> String {
+        self.0.clone()
     }
+}
 
-    if ctx.common.dry_run {
-        info!(
-            "dry-run: would write default config to {}",
-            ctx.paths.config_file.display()
-        );
-        return Ok(());
+/// Result of `config export`: the rendered file as text, the settings as
+/// data.
+#[derive(Debug, Serialize)]
+struct ConfigExport {
+    entries: Vec<ExportEntry>,
+    /// Keys left out because the environment cannot express them.
+    skipped: Vec<String>,
+    #[serde(skip)]
+    rendered: String,
+}
+
+impl Report for ConfigExport {
+    fn text(&self) -> String {
+        self.rendered.clone()
     }
+}
 
-    write_default_config(&ctx.paths.config_file)
+/// Result of `config import`.
+#[derive(Debug, Serialize)]
+struct ConfigImported {
+    #[serde(with = "rust_core::pathfmt")]
+    config_file: PathBuf,
+    source: String,
+    /// Settings that differed from the file (or its defaults), now set; on
+    /// a dry run, that would be.
+    changes: Vec<SettingChange>,
+    /// Keys whose value the listing has masked (as `config show` does),
+    /// left as they were.
+    skipped: Vec<String>,
+    dry_run: bool,
 }
 
-fn handle_config(ctx: &RuntimeContext, command: ConfigCommand) -> Result<()> {
-    match command {
-        ConfigCommand::Show => {
-            if ctx.common.json {
-                println!(
-                    "{}",
-                    serde_json::to_string_pretty(&ctx.config)
-                        .context("serializing config to JSON")?
-                );
-            } else if ctx.common.yaml {
-                println!(
-                    "{}",
-                    serde_yaml::to_string(&ctx.config).context("serializing config to YAML")?
-                );
-            } else {
-                println!("{:#?}", ctx.config);
+impl Report for ConfigImported {
+    fn text(&self) -> String {
+        let verb = if self.dry_run { "would set" } else { "set" };
+        let mut out = String::new();
+        for change in &self.changes {
+            if let Some(value) = &change.after {
+                let _ = writeln!(out, "{verb} {} = {value}", change.key);
             }
-            Ok(())
         }
-        ConfigCommand::Path => {
-            println!("{}", ctx.paths.config_file.display());
-            Ok(())
+        for key in &self.skipped {
+            let _ = writeln!(out, "kept {key}: {} has it redacted", self.source);
         }
-        ConfigCommand::Paths => {
-            let cache_dir = default_cache_dir()?;
-            if ctx.common.json {
-                let paths = serde_json::json!({
-                    "config": ctx.paths.config_file,
-                    "data": ctx.paths.data_dir,
-                    "state": ctx.paths.state_dir,
-                    "cache": cache_dir,
+        if self.changes.is_empty() {
+            let _ = writeln!(
+                out,
+                "{} already has the settings in {}",
+                self.config_file.display(),
+                self.source
+            );
+        }
+        out
+    }
+}
+
+/// Set each setting in `source` in the config file. Going through the
+/// editor means the result is validated before anything is written, and
+/// only the keys that change are rewritten.
+fn import_config(ctx: &RuntimeContext, source: Source) -> Result<Output> {
+    let input = Input::read(source, input::DEFAULT_LIMIT, |url| {
+        let response = ureq::get(url).call().map_err(io::Error::other)?;
+        Ok(Box::new(response.into_body().into_reader()))
+    })?;
+    let settings: serde_json::Value = input.parse()?;
+    let mut editor = ConfigEditor::open(&ctx.paths.config_file)?;
+    let mut skipped = Vec::new();
+    for (key, value) in flat::settings(&settings)? {
+        if value.as_str() == Some(config::REDACTED) {
+            skipped.push(key);
+        } else {
+            editor.set(&key, value);
+        }
+    }
+    let changes = if ctx.op.dry_run() {
+        editor.validate()?;
+        let changes = editor.changes();
+        if !changes.is_empty() {
+            ctx.op.plan(Action::WriteFile {
+                path: ctx.paths.config_file.clone(),
+            });
+        }
+        changes
+    } else {
+        editor.save()?
+    };
+    Output::new(&ConfigImported {
+        config_file: ctx.paths.config_file.clone(),
+        source: input.source.to_string(),
+        changes,
+        skipped,
+        dry_run: ctx.op.dry_run(),
+    })
+}
+
+fn handle_config(ctx: &RuntimeContext, command: ConfigCommand) -> Result<Output> {
+    match command {
+        ConfigCommand::Show {
+            show_secrets,
+            redact: _,
+            format,
+        } => {
+            let settings = ShowConfig.run(
+                ctx.env(),
+                ShowConfigInput {
+                    redact: !show_secrets,
+                },
+            )?;
+            Output::new(&ConfigShow { settings, format }).map(Output::paged)
+        }
+        ConfigCommand::Import { source } => import_config(ctx, source),
+        ConfigCommand::Path => Output::new(&ConfigPath {
+            config_file: &ctx.paths.config_file,
+        }),
+        ConfigCommand::Paths => Output::new(&ResolvePaths.run(ctx.env(), ())?),
+        ConfigCommand::Schema => Output::new(&ConfigSchema(
+            serde_json::from_str(CONFIG_SCHEMA).context("parsing bundled config schema")?,
+        )),
+        ConfigCommand::Docs => Output::new(&ConfigDocs(rust_core::generate_config_docs(
+            rust_core::APP_NAME,
+        )?))
+        .map(Output::paged),
+        ConfigCommand::Reset { schema } => {
+            if ctx.op.dry_run() {
+                ctx.op.plan(Action::WriteFile {
+                    path: ctx.paths.config_file.clone(),
                 });
-                println!(
-                    "{}",
-                    serde_json::to_string_pretty(&paths).context("serializing paths to JSON")?
-                );
-            } else if ctx.common.yaml {
-                let paths = serde_json::json!({
-                    "config": ctx.paths.config_file,
-                    "data": ctx.paths.data_dir,
-                    "state": ctx.paths.state_dir,
-                    "cache": cache_dir,
+            } else {
+                write_default_config(&ctx.paths.config_file)?;
+            }
+            Output::new(&ConfigWritten {
+                config_file: ctx.paths.config_file.clone(),
+                schema: point_schema(ctx, schema)?,
+                written: !ctx.op.dry_run(),
+            })
+        }
+        ConfigCommand::SetSchema { target } => Output::new(&SchemaSet {
+            config_file: &ctx.paths.config_file,
+            schema: point_schema(ctx, target)?,
+            local_schema: matches!(target, SchemaTarget::Local | SchemaTarget::FileUrl)
+                .then(|| local_schema_path(&ctx.paths.config_file)),
+            dry_run: ctx.op.dry_run(),
+        }),
+        ConfigCommand::Migrate => {
+            let config_file = &ctx.paths.config_file;
+            let text = fs::read_to_string(config_file)
+                .with_context(|| format!("reading {}", config_file.display()))?;
+            let (migrated, from_version) = rust_core::migrate::migrate(&text)?;
+            let changed = migrated != text;
+            if changed && ctx.op.dry_run() {
+                ctx.op.plan(Action::WriteFile {
+                    path: config_file.clone(),
                 });
-                println!(
-                    "{}",
-                    serde_yaml::to_string(&paths).context("serializing paths to YAML")?
-                );
+            } else if changed {
+                fs::write(config_file, migrated)
+                    .with_context(|| format!("writing {}", config_file.display()))?;
+            }
+            Output::new(&ConfigMigrated {
+                config_file,
+                from_version,
+                to_version: rust_core::migrate::CONFIG_VERSION,
+                changed,
+                dry_run: ctx.op.dry_run(),
+            })
+        }
+        ConfigCommand::Export { format } => {
+            let (entries, skipped) = export::entries(&ctx.config)?;
+            Output::new(&ConfigExport {
+                entries,
+                skipped,
+                rendered: export::export(&ctx.config, format.into())?,
+            })
+        }
+    }
+}
+
+/// Result of `paths doctor`.
+#[derive(Debug, Serialize)]
+struct DoctorReport {
+    directories: Vec<DirHealth>,
+    /// Operation left unfinished by an interrupted run, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    interrupted: Option<Intent>,
+    /// How `interrupted` was just recovered: `roll_forward` or `revert`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recovered: Option<&'static str>,
+}
+
+impl Report for DoctorReport {
+    fn text(&self) -> String {
+        let mut out = String::new();
+        for dir in &self.directories {
+            let status = if dir.is_healthy() {
+                "ok"
+            } else if dir.exists {
+                "read-only"
+            } else {
+                "missing"
+            };
+            let _ = writeln!(
+                out,
+                "{:<7}{status:<10}{}",
+                dir.kind.name(),
+                dir.path.display()
+            );
+            let unknown = || "-".to_string();
+            let _ = writeln!(
+                out,
+                "       owner {}  mode {}  used {}  free {}",
+                dir.owner.clone().unwrap_or_else(unknown),
+                dir.mode.clone().unwrap_or_else(unknown),
+                dir.used_bytes.map_or_else(unknown, format_bytes),
+                dir.free_bytes.map_or_else(unknown, format_bytes),
+            );
+        }
+        if let Some(intent) = &self.interrupted {
+            let started = humantime::format_rfc3339_seconds(
+                std::time::UNIX_EPOCH + Duration::from_secs(intent.started_at),
+            );
+            let state = if intent.committed {
+                "interrupted while replacing files"
             } else {
-                println!("config: {}", ctx.paths.config_file.display());
-                println!("data:   {}", ctx.paths.data_dir.display());
-                println!("state:  {}", ctx.paths.state_dir.display());
-                println!("cache:  {}", cache_dir.display());
+                "interrupted before changing anything"
+            };
+            let _ = writeln!(
+                out,
+                "\n`{}` (pid {}, started {started}) was {state}",
+                intent.operation, intent.pid
+            );
+            if intent.committed {
+                for entry in &intent.entries {
+                    let _ = writeln!(out, "       {}", entry.target.display());
+                }
             }
-            Ok(())
+            let _ = match self.recovered {
+                Some("roll_forward") => writeln!(out, "rolled forward"),
+                Some(_) => writeln!(out, "reverted"),
+                None => writeln!(
+                    out,
+                    "recover with `{APP_NAME} paths doctor --roll-forward` or `--revert`"
+                ),
+            };
         }
-        ConfigCommand::Schema => {
-            println!("{}", include_str!("../../../examples/config.schema.json"));
-            Ok(())
+        out
+    }
+}
+
+fn handle_paths(ctx: &RuntimeContext, command: PathsCommand) -> Result<Output> {
+    let PathsCommand::Doctor(cmd) = command;
+    let journal_dir = ctx.paths.journal_dir();
+    let recovery = if cmd.roll_forward {
+        Some((Recovery::RollForward, "roll_forward"))
+    } else if cmd.revert {
+        Some((Recovery::Revert, "revert"))
+    } else {
+        None
+    };
+    let (interrupted, recovered) = match recovery {
+        Some((recovery, name)) => {
+            let intent = journal::recover(&journal_dir, recovery, &ctx.op)?;
+            let recovered = intent.as_ref().map(|_| name);
+            (intent, recovered)
         }
-        ConfigCommand::Reset => {
-            if ctx.common.dry_run {
-                info!(
-                    "dry-run: would reset config at {}",
-                    ctx.paths.config_file.display()
-                );
-                return Ok(());
+        None => (journal::pending(&journal_dir)?, None),
+    };
+    let report = DoctorReport {
+        directories: doctor::diagnose(&ctx.paths),
+        interrupted,
+        recovered,
+    };
+    let mut problems: Vec<String> = Vec::new();
+    let unhealthy: Vec<&str> = report
+        .directories
+        .iter()
+        .filter(|dir| !dir.is_healthy())
+        .map(|dir| dir.kind.name())
+        .collect();
+    if !unhealthy.is_empty() {
+        problems.push(format!(
+            "directories missing or not writable: {}",
+            unhealthy.join(", ")
+        ));
+    }
+    if let Some(intent) = &report.interrupted
+        && report.recovered.is_none()
+    {
+        problems.push(format!("`{}` was interrupted", intent.operation));
+    }
+    let output = Output::new(&report)?;
+    if problems.is_empty() {
+        Ok(output)
+    } else {
+        Ok(output.then_fail(anyhow!(problems.join("; "))))
+    }
+}
+
+/// Format a byte count with binary units, e.g. `1.5 MiB`.
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+/// Result of `release stamp`.
+#[derive(Debug, Serialize)]
+struct StampReport {
+    version: String,
+    #[serde(with = "rust_core::pathfmt::seq")]
+    manifests: Vec<PathBuf>,
+    artifacts: Vec<ManifestEntry>,
+    dry_run: bool,
+    #[serde(skip)]
+    root: PathBuf,
+}
+
+impl Report for StampReport {
+    fn text(&self) -> String {
+        let mut out = String::new();
+        for manifest in &self.manifests {
+            let _ = writeln!(out, "stamped {} in {}", self.version, manifest.display());
+        }
+        let _ = writeln!(
+            out,
+            "regenerated {} artifacts under {}",
+            self.artifacts.len(),
+            self.root.display()
+        );
+        out
+    }
+}
+
+fn handle_release(ctx: &RuntimeContext, command: ReleaseCommand) -> Result<Output> {
+    let ReleaseCommand::Stamp(cmd) = command;
+    let start = match cmd.root {
+        Some(root) => root,
+        None => env::current_dir().context("resolving current directory")?,
+    };
+    let root = release::find_workspace_root(&start)?;
+    // Manifests and artifacts change together or not at all.
+    let mut journal = ctx.journal("release stamp")?;
+    let manifests = release::stamp_workspace(&root, &cmd.new_version, &mut journal)?;
+    let manifest = generate::write_all(&root, &cmd.new_version, &mut journal)?;
+    journal.commit()?;
+    if !ctx.op.dry_run() {
+        generate::verify_all(&root, &cmd.new_version)?;
+    }
+
+    Output::new(&StampReport {
+        version: cmd.new_version,
+        manifests,
+        artifacts: manifest.artifacts,
+        dry_run: ctx.op.dry_run(),
+        root,
+    })
+}
+
+/// One entry of `explain`.
+#[derive(Debug, Serialize)]
+struct ExplainEntry {
+    code: ErrorCode,
+    summary: &'static str,
+    explanation: &'static str,
+}
+
+/// Result of `explain`: one code's explanation, or the list of all codes.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+struct Explanations(Vec<ExplainEntry>);
+
+impl Report for Explanations {
+    fn text(&self) -> String {
+        match self.0.as_slice() {
+            [single] => single.explanation.to_string(),
+            all => {
+                let mut out = String::new();
+                for entry in all {
+                    let _ = writeln!(out, "{}  {}", entry.code, entry.summary);
+                }
+                out
             }
-            write_default_config(&ctx.paths.config_file)
         }
     }
 }
 
-fn handle_completions(shell: Shell) {
+fn handle_explain(code: Option<ErrorCode>) -> Result<Output> {
+    let codes = code.map_or_else(|| ErrorCode::ALL.to_vec(), |code| vec![code]);
+    Output::new(&Explanations(
+        codes
+            .into_iter()
+            .map(|code| ExplainEntry {
+                code,
+                summary: code.summary(),
+                explanation: code.explanation(),
+            })
+            .collect(),
+    ))
+}
+
+/// Result of `completions`: the script as text.
+#[derive(Debug, Serialize)]
+struct Completions {
+    shell: String,
+    script: String,
+}
+
+impl Report for Completions {
+    fn text(&self) -> String {
+        self.script.clone()
+    }
+}
+
+fn handle_completions(shell: Shell) -> Result<Output> {
     let mut cmd = Cli::command();
-    clap_complete::generate(shell, &mut cmd, APP_NAME, &mut io::stdout());
+    cmd.build();
+    Output::new(&Completions {
+        shell: shell.to_string(),
+        script: completions::script(shell, &cmd)?,
+    })
 }
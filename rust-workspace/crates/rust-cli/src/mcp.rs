@@ -0,0 +1,285 @@
+//! `mcp`: talk to an MCP server as a client does, to try out this
+//! workspace's server or debug any other.
+//!
+//! Each invocation opens a session (see [`rust_mcp::client`]), makes one
+//! request, and closes it. The server is `--url` over streamable HTTP,
+//! `--stdio` started as a command, or by default this binary's `serve mcp`
+//! with this invocation's config file, directory mode, and `--set`
+//! overrides, logging only its errors. A tool that reports failure prints its result, then exits
+//! non-zero. `mcp` takes no instance lock, since the server's `run_task`
+//! takes it for each call.
+
+use std::env;
+use std::ffi::OsString;
+use std::fmt::Write as _;
+use std::str::FromStr;
+
+use anyhow::{Context, Result, anyhow, bail};
+use clap::{Args, Subcommand};
+use rmcp::model::{CallToolResult, RawContent, ReadResourceResult, ResourceContents, Tool};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use rust_mcp::client::{Connection, Server};
+
+use crate::output::{Output, Report};
+use crate::{CommonOpts, RuntimeContext};
+
+#[derive(Debug, Clone, Args)]
+pub struct McpArgs {
+    #[command(flatten)]
+    server: ServerArgs,
+    #[command(subcommand)]
+    command: McpCommand,
+}
+
+/// Which server to talk to.
+#[derive(Debug, Clone, Args)]
+struct ServerArgs {
+    /// Streamable HTTP endpoint of the server, e.g. `http://127.0.0.1:3001/mcp`
+    #[arg(long, value_name = "URL", global = true, conflicts_with = "stdio")]
+    url: Option<String>,
+    /// Bearer token for --url
+    #[arg(
+        long,
+        value_name = "TOKEN",
+        global = true,
+        env = token_var(),
+        hide_env_values = true
+    )]
+    token: Option<String>,
+    /// Command line starting a stdio server, split like a shell would
+    /// [default: this binary's `serve mcp`]
+    #[arg(long, value_name = "COMMAND", global = true)]
+    stdio: Option<String>,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+enum McpCommand {
+    /// List the server's tools with their descriptions
+    ListTools,
+    /// Call a tool and print its result
+    Call {
+        /// Tool to call, as `list-tools` names it
+        #[arg(value_name = "TOOL")]
+        tool: String,
+        /// Argument to pass (repeatable); VALUE is JSON when it parses as
+        /// JSON, else a string, e.g. `message=hi` or `tasks='["build"]'`
+        #[arg(long = "arg", value_name = "KEY=VALUE")]
+        args: Vec<ToolArgument>,
+    },
+    /// Read a resource and print its contents
+    ReadResource {
+        /// URI of the resource, e.g. `config://current`
+        #[arg(value_name = "URI")]
+        uri: String,
+    },
+}
+
+/// Environment variable with the token for `--url`, so it need not appear
+/// on the command line.
+fn token_var() -> String {
+    format!("{}_MCP_TOKEN", rust_core::env_prefix())
+}
+
+/// One `--arg KEY=VALUE`.
+#[derive(Debug, Clone)]
+struct ToolArgument {
+    key: String,
+    value: Value,
+}
+
+impl FromStr for ToolArgument {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let Some((key, value)) = text.split_once('=') else {
+            return Err(format!("{text:?} is not KEY=VALUE (e.g. message=hi)"));
+        };
+        let value =
+            serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_string()));
+        Ok(Self {
+            key: key.to_string(),
+            value,
+        })
+    }
+}
+
+pub fn handle_mcp(ctx: &RuntimeContext, args: McpArgs) -> Result<Output> {
+    let connection = Connection::open(&server(&ctx.common, args.server)?)?;
+    let output = match args.command {
+        McpCommand::ListTools => connection
+            .list_tools()
+            .and_then(|tools| Output::new(&ToolsReport(tools))),
+        McpCommand::Call { tool, args } => {
+            let arguments: Map<String, Value> =
+                args.into_iter().map(|arg| (arg.key, arg.value)).collect();
+            connection.call_tool(&tool, arguments).and_then(|result| {
+                let failed = result.is_error == Some(true);
+                let output = Output::new(&CallReport(result))?;
+                Ok(if failed {
+                    output.then_fail(anyhow!("{tool} reported failure"))
+                } else {
+                    output
+                })
+            })
+        }
+        McpCommand::ReadResource { uri } => connection
+            .read_resource(&uri)
+            .and_then(|contents| Output::new(&ResourceReport(contents))),
+    };
+    let closed = connection.close();
+    let output = output?;
+    closed.context("closing the MCP session")?;
+    Ok(output)
+}
+
+/// The server `args` name, or this binary's `serve mcp` with the options
+/// of `common` that choose its config.
+fn server(common: &CommonOpts, args: ServerArgs) -> Result<Server> {
+    if let Some(url) = args.url {
+        return Ok(Server::Http {
+            url,
+            token: args.token,
+        });
+    }
+    if let Some(command) = args.stdio {
+        let mut words = match shlex::split(&command) {
+            Some(words) if !words.is_empty() => words.into_iter().map(OsString::from),
+            Some(_) => bail!("--stdio is empty"),
+            None => bail!("--stdio has an unbalanced quote: {command}"),
+        };
+        let program = words.next().context("--stdio is empty")?;
+        return Ok(Server::Stdio {
+            program,
+            args: words.collect(),
+        });
+    }
+    let mut args: Vec<OsString> = Vec::new();
+    if let Some(config) = &common.config {
+        args.extend(["--config".into(), config.into()]);
+    }
+    for (flag, set) in [
+        ("--no-config", common.no_config),
+        ("--system", common.system),
+        ("--user", common.user),
+    ] {
+        if set {
+            args.push(flag.into());
+        }
+    }
+    for set in &common.set {
+        args.push(format!("--set={}={}", set.key, set.value).into());
+    }
+    // The server's records would interleave with this side's; keep its errors.
+    args.extend(["--quiet".into(), "serve".into(), "mcp".into()]);
+    Ok(Server::Stdio {
+        program: env::current_exe()
+            .context("locating this binary to serve MCP")?
+            .into(),
+        args,
+    })
+}
+
+/// Result of `mcp list-tools`.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+struct ToolsReport(Vec<Tool>);
+
+impl Report for ToolsReport {
+    fn text(&self) -> String {
+        if self.0.is_empty() {
+            return "the server offers no tools\n".to_string();
+        }
+        let width = self.0.iter().map(|tool| tool.name.len()).max().unwrap_or(0);
+        let mut out = String::new();
+        for tool in &self.0 {
+            let description = tool
+                .description
+                .as_deref()
+                .and_then(|text| text.lines().next())
+                .unwrap_or_default();
+            let _ = writeln!(out, "{:width$}  {description}", tool.name);
+        }
+        out
+    }
+}
+
+/// Result of `mcp call`.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+struct CallReport(CallToolResult);
+
+impl Report for CallReport {
+    fn text(&self) -> String {
+        let mut out = String::new();
+        for content in &self.0.content {
+            match &content.raw {
+                RawContent::Text(text) => push_line(&mut out, &text.text),
+                RawContent::Image(image) => {
+                    let _ = writeln!(
+                        out,
+                        "[{} image, {} base64 bytes]",
+                        image.mime_type,
+                        image.data.len()
+                    );
+                }
+                RawContent::Audio(audio) => {
+                    let _ = writeln!(
+                        out,
+                        "[{} audio, {} base64 bytes]",
+                        audio.mime_type,
+                        audio.data.len()
+                    );
+                }
+                RawContent::Resource(resource) => push_contents(&mut out, &resource.resource),
+                RawContent::ResourceLink(link) => {
+                    let _ = writeln!(out, "[resource {}]", link.uri);
+                }
+            }
+        }
+        if out.is_empty()
+            && let Some(structured) = &self.0.structured_content
+        {
+            push_line(&mut out, &format!("{structured:#}"));
+        }
+        out
+    }
+}
+
+/// Result of `mcp read-resource`.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+struct ResourceReport(ReadResourceResult);
+
+impl Report for ResourceReport {
+    fn text(&self) -> String {
+        let mut out = String::new();
+        for contents in &self.0.contents {
+            push_contents(&mut out, contents);
+        }
+        out
+    }
+}
+
+/// Text contents as they are; a blob as a note of its size.
+fn push_contents(out: &mut String, contents: &ResourceContents) {
+    match contents {
+        ResourceContents::TextResourceContents { text, .. } => push_line(out, text),
+        ResourceContents::BlobResourceContents {
+            uri,
+            mime_type,
+            blob,
+            ..
+        } => {
+            let kind = mime_type.as_deref().unwrap_or("binary");
+            let _ = writeln!(out, "[{uri}: {kind}, {} base64 bytes]", blob.len());
+        }
+    }
+}
+
+/// `text` with exactly one trailing newline.
+fn push_line(out: &mut String, text: &str) {
+    out.push_str(text.trim_end_matches('\n'));
+    out.push('\n');
+}
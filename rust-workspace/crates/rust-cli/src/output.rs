@@ -0,0 +1,307 @@
+//! Output format selection (`--output`) and rendering of command results.
+//!
+//! Every handler returns an [`Output`] built from a typed result that
+//! implements [`Report`]; `try_main` prints it once with
+//! [`OutputFormat::emit`], through the pager if the result is
+//! [paged](Output::paged). Structured formats are rendered from the
+//! serialized value, so a command cannot support text without also
+//! supporting JSON, YAML, table, CSV, and NDJSON (whose `result` event ends
+//! the stream of [`crate::events`]). Under `--dry-run` the result comes with
+//! the plan of what the command would have changed (see
+//! [`Output::with_plan`]). Table and CSV are derived from the
+//! serialized value: a list of objects becomes one row per element, a single
+//! object one row per (dotted) field, and nested lists are written as
+//! compact JSON in their cell.
+
+use std::fmt::Write as _;
+use std::io::{self, Write as _};
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use log::debug;
+use serde::Serialize;
+use serde_json::Value;
+
+use rust_core::Action;
+
+use crate::events::Event;
+use crate::pager::Pager;
+
+/// How command results are written to stdout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable text
+    #[default]
+    Text,
+    /// Pretty-printed JSON
+    Json,
+    /// YAML
+    Yaml,
+    /// Aligned columns
+    Table,
+    /// Comma-separated values with a header row
+    Csv,
+    /// Newline-delimited JSON events: progress while the command runs, then
+    /// the result
+    Ndjson,
+}
+
+/// A command result: serializable for the structured formats, with its own
+/// text rendering.
+pub trait Report: Serialize {
+    /// Text for `--output text`, with a trailing newline (or empty).
+    fn text(&self) -> String;
+}
+
+/// A handler's result, serialized and rendered as text up front.
+#[derive(Debug)]
+pub struct Output {
+    value: Value,
+    text: String,
+    failure: Option<anyhow::Error>,
+    paged: bool,
+    /// Already written while the command ran; nothing is left to print.
+    streamed: bool,
+}
+
+impl Output {
+    /// Capture `result` for printing.
+    pub fn new<T: Report>(result: &T) -> Result<Self> {
+        Ok(Self {
+            value: serde_json::to_value(result).context("serializing command result")?,
+            text: result.text(),
+            failure: None,
+            paged: false,
+            streamed: false,
+        })
+    }
+
+    /// The result of a command that wrote its output to stdout as it went
+    /// (`logs --follow`), so that none of the formats prints anything more.
+    pub const fn streamed() -> Self {
+        Self {
+            value: Value::Null,
+            text: String::new(),
+            failure: None,
+            paged: false,
+            streamed: true,
+        }
+    }
+
+    /// Show the text form through the pager when stdout is a terminal, for
+    /// results that can run to many screens.
+    pub fn paged(self) -> Self {
+        Self {
+            paged: true,
+            ..self
+        }
+    }
+
+    /// Add the plan a dry run collected: listed after the text, and next to
+    /// the result as `{"result": ..., "plan": [...]}` in the structured
+    /// formats, whether or not anything was planned.
+    pub fn with_plan(self, plan: &[Action]) -> Result<Self> {
+        let mut text = self.text;
+        if !plan.is_empty() {
+            if !text.is_empty() {
+                text.push('\n');
+            }
+            text.push_str("dry-run plan:\n");
+            for action in plan {
+                let _ = writeln!(text, "  {action}");
+            }
+        }
+        let plan = serde_json::to_value(plan).context("serializing dry-run plan")?;
+        Ok(Self {
+            value: serde_json::json!({ "result": self.value, "plan": plan }),
+            text,
+            ..self
+        })
+    }
+
+    /// Fail with `err` after the output has been printed, for commands whose
+    /// result explains the failure (an unhealthy report, a conflict list).
+    pub fn then_fail(self, err: anyhow::Error) -> Self {
+        Self {
+            failure: Some(err),
+            ..self
+        }
+    }
+}
+
+impl OutputFormat {
+    /// Print `output` in this format, then return its failure, if any. A
+    /// closed stdout (`| head`) is not an error.
+    pub fn emit(self, output: Output, pager: Option<&Pager>) -> Result<()> {
+        if output.streamed {
+            return output.failure.map_or(Ok(()), Err);
+        }
+        let rendered = match self {
+            Self::Text => output.text,
+            format => format.render(&output.value)?,
+        };
+        if let Some(pager) = pager.filter(|_| output.paged && self == Self::Text) {
+            match pager.show(&rendered) {
+                Ok(()) => return output.failure.map_or(Ok(()), Err),
+                Err(err) => debug!("cannot start pager, printing instead: {err}"),
+            }
+        }
+        let mut stdout = io::stdout().lock();
+        match stdout
+            .write_all(rendered.as_bytes())
+            .and_then(|()| stdout.flush())
+        {
+            Err(err) if err.kind() == io::ErrorKind::BrokenPipe => {}
+            written => written.context("writing to stdout")?,
+        }
+        output.failure.map_or(Ok(()), Err)
+    }
+
+    /// Render `value` in this structured format; text renders as JSON.
+    pub fn render(self, value: &Value) -> Result<String> {
+        Ok(match self {
+            Self::Text | Self::Json => {
+                let mut json =
+                    serde_json::to_string_pretty(value).context("serializing result to JSON")?;
+                json.push('\n');
+                json
+            }
+            Self::Yaml => serde_yaml::to_string(value).context("serializing result to YAML")?,
+            Self::Ndjson => Event::Result { data: value }.line(),
+            Self::Table | Self::Csv => {
+                let (header, rows) = tabulate(value);
+                if self == Self::Table {
+                    table(&header, &rows)
+                } else {
+                    csv(&header, &rows)
+                }
+            }
+        })
+    }
+}
+
+/// Header and rows for a serialized value.
+fn tabulate(value: &Value) -> (Vec<String>, Vec<Vec<String>>) {
+    match value {
+        Value::Array(items) if items.iter().all(Value::is_object) && !items.is_empty() => {
+            let mut header: Vec<String> = Vec::new();
+            let flattened: Vec<Vec<(String, String)>> = items
+                .iter()
+                .map(|item| {
+                    let mut fields = Vec::new();
+                    flatten("", item, &mut fields);
+                    fields
+                })
+                .collect();
+            for (key, _) in flattened.iter().flatten() {
+                if !header.contains(key) {
+                    header.push(key.clone());
+                }
+            }
+            let rows = flattened
+                .into_iter()
+                .map(|fields| {
+                    header
+                        .iter()
+                        .map(|column| {
+                            fields
+                                .iter()
+                                .find(|(key, _)| key == column)
+                                .map(|(_, cell)| cell.clone())
+                                .unwrap_or_default()
+                        })
+                        .collect()
+                })
+                .collect();
+            (header, rows)
+        }
+        Value::Array(items) => (
+            vec!["value".to_string()],
+            items.iter().map(|item| vec![cell(item)]).collect(),
+        ),
+        Value::Object(_) => {
+            let mut fields = Vec::new();
+            flatten("", value, &mut fields);
+            (
+                vec!["key".to_string(), "value".to_string()],
+                fields
+                    .into_iter()
+                    .map(|(key, cell)| vec![key, cell])
+                    .collect(),
+            )
+        }
+        scalar => (vec!["value".to_string()], vec![vec![cell(scalar)]]),
+    }
+}
+
+/// Flatten nested objects into dotted keys; other values become cells.
+fn flatten(prefix: &str, value: &Value, out: &mut Vec<(String, String)>) {
+    match value {
+        Value::Object(map) => {
+            for (key, inner) in map {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                flatten(&path, inner, out);
+            }
+        }
+        other => out.push((prefix.to_string(), cell(other))),
+    }
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        Value::Bool(_) | Value::Number(_) | Value::Array(_) | Value::Object(_) => value.to_string(),
+    }
+}
+
+fn table(header: &[String], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = header.iter().map(|name| name.chars().count()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let mut out = String::new();
+    let mut line = |cells: &[String]| {
+        let padded: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        let _ = writeln!(out, "{}", padded.join("  ").trim_end());
+    };
+    line(header);
+    line(
+        &widths
+            .iter()
+            .map(|width| "-".repeat(*width))
+            .collect::<Vec<_>>(),
+    );
+    for row in rows {
+        line(row);
+    }
+    out
+}
+
+fn csv(header: &[String], rows: &[Vec<String>]) -> String {
+    let mut out = String::new();
+    for row in std::iter::once(header).chain(rows.iter().map(Vec::as_slice)) {
+        let cells: Vec<String> = row.iter().map(|cell| csv_field(cell)).collect();
+        let _ = writeln!(out, "{}", cells.join(","));
+    }
+    out
+}
+
+/// Quote a CSV field when it contains a separator, quote, or line break.
+fn csv_field(cell: &str) -> String {
+    if cell.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
@@ -0,0 +1,74 @@
+//! Paging long text output through `$PAGER` or `less`, as git does.
+//!
+//! Only results marked with [`Output::paged`](crate::output::Output::paged)
+//! are paged, and only in text format on a terminal stdout. The pager is
+//! `output.pager`, then `$PAGER`, then `less`; `LESS=FRX` (unless already
+//! set) makes less exit at once for output that fits on one screen and keep
+//! colors.
+
+use std::io::{self, IsTerminal as _, Write as _};
+use std::process::{Command, Stdio};
+
+use log::debug;
+use rust_core::OutputConfig;
+
+use crate::CommonOpts;
+use crate::output::OutputFormat;
+
+/// A pager command line.
+#[derive(Debug, Clone)]
+pub struct Pager {
+    program: String,
+    args: Vec<String>,
+}
+
+impl Pager {
+    /// The pager for this invocation, or `None` when output goes straight
+    /// to stdout.
+    pub fn select(common: &CommonOpts, config: &OutputConfig) -> Option<Self> {
+        if common.no_pager
+            || common.entrypoint
+            || common.output() != OutputFormat::Text
+            || !io::stdout().is_terminal()
+        {
+            return None;
+        }
+        let command = config.pager.clone().unwrap_or_else(|| {
+            std::env::var("PAGER")
+                .ok()
+                .filter(|pager| !pager.trim().is_empty())
+                .unwrap_or_else(|| "less".to_string())
+        });
+        let mut words = command.split_whitespace().map(str::to_string);
+        let program = words.next()?;
+        Some(Self {
+            program,
+            args: words.collect(),
+        })
+    }
+
+    /// Show `text` in the pager and wait for it to exit.
+    ///
+    /// Fails only if the pager cannot be started, so the caller can print
+    /// the text itself; quitting the pager early is not an error.
+    pub fn show(&self, text: &str) -> io::Result<()> {
+        let mut command = Command::new(&self.program);
+        command.args(&self.args).stdin(Stdio::piped());
+        if std::env::var_os("LESS").is_none() {
+            command.env("LESS", "FRX");
+        }
+        if std::env::var_os("LV").is_none() {
+            command.env("LV", "-c");
+        }
+        let mut child = command.spawn()?;
+        if let Some(mut stdin) = child.stdin.take()
+            && let Err(err) = stdin.write_all(text.as_bytes())
+        {
+            debug!("pager {} closed its input: {err}", self.program);
+        }
+        if let Err(err) = child.wait() {
+            debug!("waiting for pager {}: {err}", self.program);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,178 @@
+//! External subcommands: `rust-cli foo` runs `rust-cli-foo` from `PATH`
+//! when `foo` is neither a built-in command nor an alias (see
+//! [`rust_core::plugin`]).
+//!
+//! The plugin gets the arguments after its name. Global flags given before
+//! the name are not forwarded as flags; what they select is passed in the
+//! environment instead, so a plugin needs no argument parser to honor them:
+//!
+//! - `<PREFIX>_BIN`: this binary, for calling back into it
+//! - `<PREFIX>_CONFIG_FILE`: the resolved config file (unset under
+//!   `--entrypoint` and `--no-config`, where there is none)
+//! - `<PREFIX>_NO_CONFIG`: `1` under `--no-config`
+//! - `<PREFIX>_OUTPUT`: `text`, `json`, `yaml`, `table`, or `csv`
+//! - `<PREFIX>_COLOR`: `auto`, `always`, or `never`
+//! - `<PREFIX>__SECTION__KEY`: each `--set section.key=value`, as the
+//!   environment override the config loader reads
+
+use std::env;
+use std::ffi::OsString;
+use std::fmt::Write as _;
+use std::process::Command as Process;
+
+use anyhow::{Context, Result};
+use clap::{CommandFactory as _, Subcommand, ValueEnum as _};
+use serde::Serialize;
+
+use rust_core::{AppPaths, config, env_prefix, plugin};
+
+use crate::alias::{command_position, leading_globals};
+use crate::output::{Output, Report};
+use crate::{APP_NAME, Cli, ColorOption, CommonOpts};
+
+#[derive(Debug, Clone, Copy, Subcommand)]
+pub enum PluginCommand {
+    /// List the external subcommands (rust-cli-<name> executables) on PATH
+    List,
+}
+
+/// Run the plugin named in command position of `args`, if there is one.
+/// On unix the plugin replaces this process; elsewhere its exit status is
+/// passed on. `None` means there is no such plugin.
+///
+/// # Errors
+///
+/// Returns an error if the config path cannot be resolved or the plugin
+/// cannot be started.
+pub fn dispatch(args: &[OsString]) -> Result<Option<std::process::ExitCode>> {
+    let mut cmd = Cli::command();
+    cmd.build();
+    let Some(index) = command_position(&cmd, args) else {
+        return Ok(None);
+    };
+    let Some(name) = args.get(index).and_then(|name| name.to_str()) else {
+        return Ok(None);
+    };
+    let Some(path) = plugin::find(APP_NAME, name) else {
+        return Ok(None);
+    };
+    let Some(common) = leading_globals(args, index) else {
+        return Ok(None);
+    };
+    let mut process = Process::new(&path);
+    process.args(args.iter().skip(index + 1));
+    for (var, value) in environment(&common)? {
+        process.env(format!("{}_{var}", env_prefix()), value);
+    }
+    for item in &common.set {
+        process.env(config::env_var_name(&item.key), &item.value);
+    }
+    run(process).with_context(|| format!("running plugin {}", path.display()))
+}
+
+/// The `<PREFIX>_*` variables a plugin gets, without the prefix.
+fn environment(common: &CommonOpts) -> Result<Vec<(&'static str, OsString)>> {
+    let mut vars = Vec::new();
+    if let Ok(exe) = env::current_exe() {
+        vars.push(("BIN", exe.into_os_string()));
+    }
+    if common.no_config {
+        vars.push(("NO_CONFIG", "1".into()));
+    } else if !common.entrypoint {
+        let paths = AppPaths::discover_in(common.path_mode(), common.config.as_deref())?;
+        vars.push(("CONFIG_FILE", paths.config_file.into_os_string()));
+    }
+    let color = if common.no_color {
+        ColorOption::Never
+    } else {
+        common.color
+    };
+    for (var, value) in [
+        ("OUTPUT", common.output().to_possible_value()),
+        ("COLOR", color.to_possible_value()),
+    ] {
+        if let Some(value) = value {
+            vars.push((var, value.get_name().into()));
+        }
+    }
+    Ok(vars)
+}
+
+#[cfg(unix)]
+fn run(mut process: Process) -> Result<Option<std::process::ExitCode>> {
+    use std::os::unix::process::CommandExt as _;
+
+    // Only returns if the plugin could not be started.
+    Err(process.exec().into())
+}
+
+#[cfg(not(unix))]
+fn run(mut process: Process) -> Result<Option<std::process::ExitCode>> {
+    let status = process.status()?;
+    let code = status.code().and_then(|code| u8::try_from(code).ok());
+    Ok(Some(code.map_or_else(
+        || rust_core::ExitCode::Failure.into(),
+        std::process::ExitCode::from,
+    )))
+}
+
+pub fn handle_plugin(command: PluginCommand) -> Result<Output> {
+    match command {
+        PluginCommand::List => {
+            let mut cmd = Cli::command();
+            cmd.build();
+            Output::new(&PluginList(
+                plugin::discover(APP_NAME)
+                    .into_iter()
+                    .map(|plugin| PluginEntry {
+                        shadowed: cmd.find_subcommand(&plugin.name).is_some(),
+                        plugin,
+                    })
+                    .collect(),
+            ))
+        }
+    }
+}
+
+/// One plugin in `plugin list`.
+#[derive(Debug, Serialize)]
+struct PluginEntry {
+    #[serde(flatten)]
+    plugin: plugin::Plugin,
+    /// A built-in command has the same name, so the plugin is never run.
+    shadowed: bool,
+}
+
+/// Result of `plugin list`.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+struct PluginList(Vec<PluginEntry>);
+
+impl Report for PluginList {
+    fn text(&self) -> String {
+        if self.0.is_empty() {
+            return format!("no plugins found; put {APP_NAME}-<name> executables on PATH\n");
+        }
+        let width = self
+            .0
+            .iter()
+            .map(|entry| entry.plugin.name.len())
+            .max()
+            .unwrap_or_default();
+        let mut out = String::new();
+        for entry in &self.0 {
+            let note = if entry.shadowed {
+                "  (shadowed by the built-in command)"
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                out,
+                "{:<width$}  {}{note}",
+                entry.plugin.name,
+                entry.plugin.path.display()
+            );
+        }
+        out
+    }
+}
@@ -0,0 +1,96 @@
+//! Terminal progress display (indicatif) behind `rust_core::Progress`.
+//!
+//! Bars and spinners are drawn on stderr and only when someone is watching:
+//! `--no-progress`, `--quiet`, a structured `--output`, `--entrypoint`, or a
+//! stderr that is not a terminal all select [`NoProgress`] instead. Under
+//! `--output ndjson` progress becomes [`Events`] on stdout, unless
+//! `--no-progress` is given.
+
+use std::io::{self, IsTerminal as _};
+use std::time::Duration;
+
+use indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget, ProgressStyle};
+
+use rust_core::{NoProgress, Progress, ProgressTask};
+
+use crate::CommonOpts;
+use crate::events::Events;
+use crate::output::OutputFormat;
+
+/// Progress reporter for this invocation.
+pub fn reporter(common: &CommonOpts) -> Box<dyn Progress> {
+    if common.output() == OutputFormat::Ndjson {
+        return if common.no_progress {
+            Box::new(NoProgress)
+        } else {
+            Box::new(Events)
+        };
+    }
+    let enabled = !common.no_progress
+        && !common.quiet
+        && !common.entrypoint
+        && common.output() == OutputFormat::Text
+        && io::stderr().is_terminal();
+    if enabled {
+        Box::new(Bars::new())
+    } else {
+        Box::new(NoProgress)
+    }
+}
+
+/// Every task gets its own line; lines disappear when their task finishes.
+struct Bars {
+    multi: MultiProgress,
+}
+
+impl Bars {
+    fn new() -> Self {
+        Self {
+            multi: MultiProgress::with_draw_target(ProgressDrawTarget::stderr()),
+        }
+    }
+}
+
+impl Progress for Bars {
+    fn start(&self, label: &str, total: Option<u64>) -> Box<dyn ProgressTask + '_> {
+        let bar = total.map_or_else(spinner, bar);
+        Box::new(Task(self.multi.add(bar.with_prefix(label.to_string()))))
+    }
+}
+
+const BAR_TEMPLATE: &str = "{prefix:>12} [{bar:30}] {pos}/{len} {wide_msg}";
+
+const SPINNER_TEMPLATE: &str = "{prefix:>12} {spinner} {pos} {wide_msg}";
+
+fn bar(total: u64) -> ProgressBar {
+    let style = ProgressStyle::with_template(BAR_TEMPLATE)
+        .unwrap_or_else(|_| ProgressStyle::default_bar())
+        .progress_chars("=> ");
+    ProgressBar::new(total).with_style(style)
+}
+
+fn spinner() -> ProgressBar {
+    let style = ProgressStyle::with_template(SPINNER_TEMPLATE)
+        .unwrap_or_else(|_| ProgressStyle::default_spinner());
+    let spinner = ProgressBar::new_spinner().with_style(style);
+    spinner.enable_steady_tick(Duration::from_millis(100));
+    spinner
+}
+
+struct Task(ProgressBar);
+
+impl ProgressTask for Task {
+    fn advance(&self, steps: u64) {
+        self.0.inc(steps);
+    }
+
+    fn set_message(&self, message: &str) {
+        self.0.set_message(message.to_string());
+    }
+}
+
+impl Drop for Task {
+    fn drop(&mut self) {
+        self.0.finish_and_clear();
+    }
+}
@@ -0,0 +1,190 @@
+//! `run`: execute a task from the `[tasks]` config table and the tasks it
+//! depends on, through [`rust_core::ops::RunTask`] as `rust-mcp`'s
+//! `run_task` does (see [`rust_core::task`]).
+//!
+//! Shell tasks run through `sh -c`; `builtin` tasks run this binary again,
+//! with `--no-lock` since this run holds the lock, and the same config and
+//! directory mode. Each task gets `<PREFIX>_TASK`, `<PREFIX>_PROFILE`, and
+//! `<PREFIX>_CORRELATION_ID` in its environment, so a builtin task logs
+//! under the same correlation ID as this run. Under a structured `--output`, task output goes to
+//! stderr so stdout carries only the report; under `--output ndjson` each
+//! task is also reported as it starts and ends. Outcomes are recorded in the
+//! state directory for `rust-tui`'s task view, and each invocation in the
+//! run history read by `history` (unless `history.record` is off). Isolated
+//! tasks run under `runs/<correlation ID>/work` in the state directory, so
+//! the history entry of a run leads to what its tasks left behind. A task
+//! that runs over its time limit is killed and `run` exits with the timeout
+//! code; `run` itself has no overall limit.
+
+use std::env;
+use std::ffi::OsString;
+use std::fmt::Write as _;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use clap::Args;
+use serde::Serialize;
+
+use rust_core::ops::{Env, Operation as _, RunReport, RunTask, RunTaskInput};
+use rust_core::task::{TaskRun, TaskStatus};
+use rust_core::{LogFormat, NoProgress, PathMode};
+
+use crate::RuntimeContext;
+use crate::output::{Output, OutputFormat, Report};
+
+#[derive(Debug, Clone, Args)]
+pub struct RunCommand {
+    /// Task from the [tasks] config table
+    #[arg(value_name = "TASK", default_value = "default")]
+    task: String,
+    /// Override the profile to run under
+    #[arg(long, value_name = "PROFILE")]
+    profile: Option<String>,
+    /// Wait until the machine is idle by the [idle] thresholds before starting
+    #[arg(long)]
+    when_idle: bool,
+}
+
+/// Result of `run`: the report, whose text shows each task's resource
+/// usage under `--stats`.
+#[derive(Debug, Serialize)]
+struct RunSummary<'a> {
+    #[serde(flatten)]
+    report: &'a RunReport,
+    #[serde(skip)]
+    stats: bool,
+}
+
+impl Report for RunSummary<'_> {
+    fn text(&self) -> String {
+        let report = self.report;
+        if report.dry_run {
+            let names: Vec<&str> = report.tasks.iter().map(|run| run.name.as_str()).collect();
+            return format!("would run: {}\n", names.join(", "));
+        }
+        task_table(&report.tasks, self.stats)
+    }
+}
+
+/// One line per task with its status, run time, and failure (and, for an
+/// isolated task, the directory it ran in), followed by a summary when there
+/// is more than one. With `usage`, each line ends with the CPU time and
+/// peak RSS of the task's process where they were measured.
+pub fn task_table(tasks: &[TaskRun], usage: bool) -> String {
+    let width = tasks
+        .iter()
+        .map(|run| run.name.len())
+        .max()
+        .unwrap_or_default();
+    let mut out = String::new();
+    for run in tasks {
+        let _ = write!(
+            out,
+            "{:<width$}  {:<9}  {:>7.1}s",
+            run.name,
+            run.status.to_string(),
+            Duration::from_millis(run.duration_ms).as_secs_f64()
+        );
+        if let Some(code) = run.exit_code.filter(|&code| code != 0) {
+            let _ = write!(out, "  exit {code}");
+        }
+        if let Some(message) = &run.message {
+            let _ = write!(out, "  {message}");
+        }
+        if let Some(dir) = run.work_dir.as_deref().filter(|_| !run.status.is_success()) {
+            let _ = write!(out, "  (in {})", rust_core::pathfmt::display(dir));
+        }
+        if let Some(used) = run.usage.filter(|_| usage) {
+            let _ = write!(out, "  [{used}]");
+        }
+        out.push('\n');
+    }
+    if tasks.len() > 1 {
+        let _ = writeln!(out, "{}", summary(tasks));
+    }
+    out
+}
+
+/// How many of `runs` ended each way, e.g. `3 succeeded, 1 failed`.
+fn summary(runs: &[TaskRun]) -> String {
+    let mut counts: Vec<(TaskStatus, usize)> = Vec::new();
+    for run in runs {
+        match counts.iter_mut().find(|(status, _)| *status == run.status) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((run.status, 1)),
+        }
+    }
+    counts
+        .iter()
+        .map(|(status, count)| format!("{count} {status}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub fn handle_run(ctx: &RuntimeContext, cmd: RunCommand) -> Result<Output> {
+    // Spinners would interleave with task output; events go to stdout,
+    // which tasks do not share.
+    let tasks_ctx = if ctx.common.output() == OutputFormat::Ndjson {
+        ctx.op.clone()
+    } else {
+        ctx.op.clone().with_progress(Arc::new(NoProgress))
+    };
+    let env = Env {
+        paths: &ctx.paths,
+        config: &ctx.config,
+        ctx: &tasks_ctx,
+    };
+    let input = RunTaskInput {
+        task: cmd.task,
+        profile: cmd.profile,
+        parallelism: ctx.common.parallel,
+        timeout: ctx.common.timeout,
+        when_idle: cmd.when_idle,
+        binary: env::current_exe().context("locating this binary for builtin tasks")?,
+        globals: builtin_globals(ctx),
+        null_stdin: false,
+        stdout_to_stderr: ctx.common.output() != OutputFormat::Text,
+        capture_output: false,
+        args: env::args_os()
+            .skip(1)
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect(),
+    };
+    let report = RunTask.run(env, input)?;
+    let failure = report.failure();
+    let output = Output::new(&RunSummary {
+        report: &report,
+        stats: ctx.common.stats,
+    })?;
+    Ok(match failure {
+        Some(err) => output.then_fail(err),
+        None => output,
+    })
+}
+
+/// Global flags for builtin tasks: the same config, `--set` overrides, and
+/// directories as this run, without the lock this run already holds.
+fn builtin_globals(ctx: &RuntimeContext) -> Vec<OsString> {
+    let mut globals: Vec<OsString> = vec!["--no-lock".into()];
+    if ctx.common.entrypoint {
+        globals.push("--entrypoint".into());
+    } else if ctx.common.no_config {
+        globals.push("--no-config".into());
+    } else {
+        globals.push("--config".into());
+        globals.push(ctx.paths.config_file.clone().into_os_string());
+    }
+    globals.push(match ctx.paths.mode {
+        PathMode::System => "--system".into(),
+        PathMode::User => "--user".into(),
+    });
+    if let Some(format) = ctx.common.log_format {
+        globals.push(format!("--log-format={}", LogFormat::from(format)).into());
+    }
+    for item in &ctx.common.set {
+        globals.push("--set".into());
+        globals.push(format!("{}={}", item.key, item.value).into());
+    }
+    globals
+}
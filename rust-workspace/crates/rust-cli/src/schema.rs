@@ -0,0 +1,227 @@
+//! `schema publish`: push the config schema to where its `$id` points.
+//!
+//! The generated schema's `$id` and the `#:schema` line of the example
+//! config both name [`schema::schema_url`], a file in the shared schemas
+//! repository. Publishing commits the schema there (or PUTs it to an HTTP
+//! endpoint with the same layout), then fetches the `$id` URL to check that
+//! editors resolving it get the schema this binary generates.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow, bail};
+use clap::Subcommand;
+use log::{info, warn};
+use serde::Serialize;
+use serde_json::Value;
+
+use rust_core::schema::{self, SCHEMAS_BRANCH, SCHEMAS_REPO};
+use rust_core::{Action, env_prefix, generate_schema};
+
+use crate::output::{Output, Report};
+use crate::{APP_NAME, RuntimeContext};
+
+/// Timeout for each HTTP request.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SchemaCommand {
+    /// Publish the config schema to the schemas repository and check that its $id URL resolves
+    Publish {
+        /// Git remote to commit the schema to, or an http(s) URL to PUT it under
+        #[arg(long, value_name = "DEST", default_value = SCHEMAS_REPO)]
+        to: String,
+        /// Branch to commit to (git destinations only)
+        #[arg(long, default_value = SCHEMAS_BRANCH)]
+        branch: String,
+        /// Only check that the $id URL serves the current schema
+        #[arg(long)]
+        check: bool,
+    },
+}
+
+pub fn handle_schema(ctx: &RuntimeContext, command: SchemaCommand) -> Result<Output> {
+    let SchemaCommand::Publish { to, branch, check } = command;
+    let text = generate_schema(rust_core::APP_NAME)?;
+    let generated: Value = serde_json::from_str(&text).context("parsing generated schema")?;
+    let id = generated["$id"]
+        .as_str()
+        .context("generated schema has no $id")?
+        .to_string();
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(REQUEST_TIMEOUT))
+        .user_agent(format!("{APP_NAME}/{}", env!("CARGO_PKG_VERSION")))
+        .build()
+        .into();
+    let path = schema::schema_repo_path(rust_core::APP_NAME);
+
+    let published = if check {
+        None
+    } else if ctx.op.dry_run() {
+        ctx.op.plan(Action::Publish {
+            path: path.clone(),
+            destination: to.clone(),
+        });
+        None
+    } else if to.starts_with("http://") || to.starts_with("https://") {
+        Some(put(&agent, &to, &path, &text)?)
+    } else {
+        let scope = ctx.paths.temp_scope()?;
+        Some(commit(scope.path(), &to, &branch, &path, &text)?)
+    };
+
+    let served = fetch(&agent, &id);
+    let report = PublishReport {
+        id,
+        destination: to,
+        path,
+        published,
+        resolves: served.is_ok(),
+        current: served.as_ref().is_ok_and(|served| *served == generated),
+        dry_run: ctx.op.dry_run(),
+    };
+    let output = Output::new(&report)?;
+    match served {
+        Err(err) if !ctx.op.dry_run() || check => {
+            Ok(output
+                .then_fail(err.context(format!("the schema $id {} does not resolve", report.id))))
+        }
+        Ok(_) if !report.current && check => Ok(output.then_fail(anyhow!(
+            "{} serves an outdated schema; run `schema publish`",
+            report.id
+        ))),
+        Ok(_) if !report.current && report.published.is_some() => {
+            // raw.githubusercontent.com caches files for a few minutes.
+            warn!(
+                "{} does not serve the new schema yet; it may be cached, re-run with --check later",
+                report.id
+            );
+            Ok(output)
+        }
+        _ => Ok(output),
+    }
+}
+
+/// PUT the schema to `path` under `base`. Sends `<PREFIX>_SCHEMA_TOKEN` as
+/// a bearer token when set. Always reports a change, as HTTP cannot tell.
+fn put(agent: &ureq::Agent, base: &str, path: &str, text: &str) -> Result<bool> {
+    let url = format!("{}/{path}", base.trim_end_matches('/'));
+    let mut request = agent.put(&url).header("Content-Type", "application/json");
+    if let Ok(token) = env::var(format!("{}_SCHEMA_TOKEN", env_prefix()))
+        && !token.is_empty()
+    {
+        request = request.header("Authorization", format!("Bearer {token}"));
+    }
+    request
+        .send(text)
+        .with_context(|| format!("uploading schema to {url}"))?;
+    info!("uploaded schema to {url}");
+    Ok(true)
+}
+
+/// Commit the schema to `path` on `branch` of the git remote `repo`, using
+/// `dir` as the working copy. Returns whether anything changed.
+fn commit(dir: &Path, repo: &str, branch: &str, path: &str, text: &str) -> Result<bool> {
+    git(
+        dir,
+        &[
+            "clone", "--quiet", "--depth", "1", "--branch", branch, repo, ".",
+        ],
+    )?;
+    let file = dir.join(path);
+    if let Some(parent) = file.parent() {
+        fs::create_dir_all(parent).with_context(|| format!("creating {}", parent.display()))?;
+    }
+    fs::write(&file, format!("{text}\n")).with_context(|| format!("writing {}", file.display()))?;
+    git(dir, &["add", "--", path])?;
+    if git(dir, &["status", "--porcelain"])?.trim().is_empty() {
+        info!("{path} in {repo} is already current");
+        return Ok(false);
+    }
+    let message = format!(
+        "Update {} config schema ({})",
+        rust_core::APP_NAME,
+        env!("CARGO_PKG_VERSION")
+    );
+    let mut args = Vec::new();
+    let identity = format!("user.email={APP_NAME}@localhost");
+    let name = format!("user.name={APP_NAME}");
+    if git(dir, &["config", "user.email"]).is_err() {
+        args.extend(["-c", identity.as_str(), "-c", name.as_str()]);
+    }
+    args.extend(["commit", "--quiet", "-m", message.as_str()]);
+    git(dir, &args)?;
+    let refspec = format!("HEAD:{branch}");
+    git(dir, &["push", "--quiet", "origin", refspec.as_str()])?;
+    info!("pushed {path} to {repo} ({branch})");
+    Ok(true)
+}
+
+fn git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .output()
+        .context("running git (is it installed?)")?;
+    if !output.status.success() {
+        bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    String::from_utf8(output.stdout).context("git output is not valid UTF-8")
+}
+
+/// The schema served at `url`.
+fn fetch(agent: &ureq::Agent, url: &str) -> Result<Value> {
+    let text = agent
+        .get(url)
+        .call()
+        .and_then(|mut response| response.body_mut().read_to_string())
+        .with_context(|| format!("fetching {url}"))?;
+    serde_json::from_str(&text).with_context(|| format!("{url} is not JSON"))
+}
+
+/// Result of `schema publish`.
+#[derive(Debug, Serialize)]
+struct PublishReport {
+    /// The schema's `$id`, where editors look it up.
+    id: String,
+    destination: String,
+    /// Path of the schema within the destination.
+    path: String,
+    /// Whether publishing changed anything; `None` when nothing was published.
+    published: Option<bool>,
+    /// The `$id` URL serves JSON.
+    resolves: bool,
+    /// The `$id` URL serves exactly the generated schema.
+    current: bool,
+    dry_run: bool,
+}
+
+impl Report for PublishReport {
+    fn text(&self) -> String {
+        let action = match self.published {
+            Some(true) => format!("published {} to {}\n", self.path, self.destination),
+            Some(false) => format!("{} in {} is already current\n", self.path, self.destination),
+            None if self.dry_run => {
+                format!("would publish {} to {}\n", self.path, self.destination)
+            }
+            None => String::new(),
+        };
+        let state = if !self.resolves {
+            "does not resolve"
+        } else if self.current {
+            "serves the current schema"
+        } else {
+            "serves an outdated schema"
+        };
+        format!("{action}{} {state}\n", self.id)
+    }
+}
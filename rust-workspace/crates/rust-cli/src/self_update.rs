@@ -0,0 +1,266 @@
+//! `self-update`: replace this binary with a release published on the
+//! repository's `GitHub` releases page.
+//!
+//! The release is looked up through the `GitHub` API for the repository in
+//! `Cargo.toml` (or `<PREFIX>_UPDATE_API`, for mirrors and `GitHub`
+//! Enterprise). The platform asset is checked against the release's
+//! `SHA256SUMS`, which must be minisign-signed once [`UPDATE_PUBLIC_KEY`] is
+//! set, before it is renamed over the running binary. `--dry-run` stops after
+//! verifying; without `--yes` nothing is downloaded.
+
+use std::env;
+use std::io::Read as _;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow};
+use clap::Args;
+use log::info;
+use serde::Serialize;
+
+use rust_core::update::{self, CHECKSUMS_ASSET, Release, SIGNATURE_SUFFIX};
+use rust_core::{Action, Progress, compat, env_prefix, pathfmt};
+
+use crate::output::{Output, Report};
+use crate::{APP_NAME, REPO_URL, RuntimeContext};
+
+/// minisign public key that signs `SHA256SUMS` (the contents of
+/// `minisign.pub`). While unset, downloads are checked against the checksums
+/// alone; once set, releases without a valid `SHA256SUMS.minisig` are refused.
+const UPDATE_PUBLIC_KEY: Option<&str> = None;
+
+/// Largest release asset accepted.
+const MAX_DOWNLOAD: u64 = 512 * 1024 * 1024;
+
+/// Time limit for each request, including the whole download.
+const REQUEST_TIMEOUT: Duration = compat::minutes(5);
+
+#[derive(Debug, Clone, Args)]
+pub struct SelfUpdateCommand {
+    /// Only report whether a newer release exists
+    #[arg(long)]
+    check: bool,
+    /// Install this release tag (e.g. v1.2.0) instead of the latest, even if
+    /// it is not newer
+    #[arg(long, value_name = "TAG")]
+    tag: Option<String>,
+}
+
+/// Result of `self-update`.
+#[derive(Debug, Serialize)]
+struct UpdateReport {
+    current: String,
+    release: String,
+    #[serde(with = "rust_core::pathfmt")]
+    executable: PathBuf,
+    status: UpdateStatus,
+    /// Asset downloaded and verified, if any.
+    asset: Option<String>,
+    /// Whether the checksums were signature-checked.
+    signed: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum UpdateStatus {
+    UpToDate,
+    Available,
+    Verified,
+    Updated,
+}
+
+impl Report for UpdateReport {
+    fn text(&self) -> String {
+        match self.status {
+            UpdateStatus::UpToDate => format!("{APP_NAME} {} is up to date\n", self.current),
+            UpdateStatus::Available => format!(
+                "{APP_NAME} {} is available (installed: {})\n",
+                self.release, self.current
+            ),
+            UpdateStatus::Verified => format!(
+                "dry-run: verified {} ({}); would replace {}\n",
+                self.asset.as_deref().unwrap_or_default(),
+                self.release,
+                pathfmt::display(&self.executable)
+            ),
+            UpdateStatus::Updated => format!(
+                "updated {} from {} to {}\n",
+                pathfmt::display(&self.executable),
+                self.current,
+                self.release
+            ),
+        }
+    }
+}
+
+pub fn handle_self_update(ctx: &RuntimeContext, cmd: &SelfUpdateCommand) -> Result<Output> {
+    let current = env!("CARGO_PKG_VERSION");
+    let executable = env::current_exe()
+        .and_then(|exe| exe.canonicalize())
+        .context("locating the running binary")?;
+    let client = Client::new(REQUEST_TIMEOUT)?;
+    let release = client.release(cmd.tag.as_deref())?;
+    let version = release.version()?;
+    let mut report = UpdateReport {
+        current: current.to_string(),
+        release: version.to_string(),
+        executable,
+        status: UpdateStatus::UpToDate,
+        asset: None,
+        signed: false,
+    };
+    if cmd.tag.is_none() && version <= update::parse_version(current)? {
+        return Output::new(&report);
+    }
+    report.status = UpdateStatus::Available;
+    if cmd.check {
+        return Output::new(&report);
+    }
+    if !ctx.common.assume_yes && !ctx.op.dry_run() {
+        let hint = anyhow!(
+            "pass --yes to install {} over {}",
+            release.tag_name,
+            pathfmt::display(&report.executable)
+        );
+        return Ok(Output::new(&report)?.then_fail(hint));
+    }
+
+    let name = update::asset_name(APP_NAME);
+    let asset = release.asset(&name).with_context(|| {
+        format!(
+            "release {} has no {name} for this platform",
+            release.tag_name
+        )
+    })?;
+    let sums = client.download_text(&release, CHECKSUMS_ASSET)?;
+    if let Some(public_key) = UPDATE_PUBLIC_KEY {
+        let signature =
+            client.download_text(&release, &format!("{CHECKSUMS_ASSET}{SIGNATURE_SUFFIX}"))?;
+        update::verify_signature(sums.as_bytes(), &signature, public_key)
+            .with_context(|| format!("verifying the signature of {CHECKSUMS_ASSET}"))?;
+        report.signed = true;
+    } else {
+        info!("no update public key is built in; verifying {name} by checksum only");
+    }
+    let expected = update::checksum_for(&sums, &name)
+        .with_context(|| format!("{CHECKSUMS_ASSET} has no entry for {name}"))?;
+    let bytes = client.download(
+        &asset.browser_download_url,
+        ctx.op.progress(),
+        &name,
+        Some(asset.size),
+    )?;
+    update::verify_checksum(&bytes, expected).with_context(|| format!("verifying {name}"))?;
+    report.asset = Some(name);
+
+    if ctx.op.dry_run() {
+        ctx.op.plan(Action::WriteFile {
+            path: report.executable.clone(),
+        });
+        report.status = UpdateStatus::Verified;
+        return Output::new(&report);
+    }
+    update::replace_executable(&report.executable, &bytes)?;
+    report.status = UpdateStatus::Updated;
+    Output::new(&report)
+}
+
+/// The releases API of the configured repository.
+pub struct Client {
+    agent: ureq::Agent,
+    api: String,
+    /// Sent to the `GitHub` API only, to lift its anonymous rate limit.
+    token: Option<String>,
+}
+
+impl Client {
+    /// A client whose requests each give up after `timeout`.
+    pub fn new(timeout: Duration) -> Result<Self> {
+        let (api, token) = match env::var(format!("{}_UPDATE_API", env_prefix())) {
+            Ok(api) if !api.is_empty() => (api.trim_end_matches('/').to_string(), None),
+            _ => {
+                let repo = REPO_URL
+                    .strip_prefix("https://github.com/")
+                    .with_context(|| format!("{REPO_URL} is not a GitHub repository"))?;
+                let repo = repo.trim_end_matches('/').trim_end_matches(".git");
+                let token = env::var("GITHUB_TOKEN")
+                    .ok()
+                    .filter(|token| !token.is_empty());
+                (format!("https://api.github.com/repos/{repo}"), token)
+            }
+        };
+        let agent = ureq::Agent::config_builder()
+            .timeout_global(Some(timeout))
+            .user_agent(format!("{APP_NAME}/{}", env!("CARGO_PKG_VERSION")))
+            .build()
+            .into();
+        Ok(Self { agent, api, token })
+    }
+
+    /// The release tagged `tag`, or the latest one.
+    pub fn release(&self, tag: Option<&str>) -> Result<Release> {
+        let url = tag.map_or_else(
+            || format!("{}/releases/latest", self.api),
+            |tag| format!("{}/releases/tags/{tag}", self.api),
+        );
+        let mut request = self
+            .agent
+            .get(&url)
+            .header("Accept", "application/vnd.github+json");
+        if let Some(token) = &self.token {
+            request = request.header("Authorization", format!("Bearer {token}"));
+        }
+        let text = request
+            .call()
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .with_context(|| format!("fetching {url}"))?;
+        serde_json::from_str(&text).with_context(|| format!("parsing release from {url}"))
+    }
+
+    /// The release asset `name`, as text.
+    fn download_text(&self, release: &Release, name: &str) -> Result<String> {
+        let asset = release
+            .asset(name)
+            .with_context(|| format!("release {} has no {name}", release.tag_name))?;
+        let bytes = self.download(
+            &asset.browser_download_url,
+            &rust_core::NoProgress,
+            name,
+            None,
+        )?;
+        String::from_utf8(bytes).with_context(|| format!("{name} is not UTF-8"))
+    }
+
+    fn download(
+        &self,
+        url: &str,
+        progress: &dyn Progress,
+        label: &str,
+        size: Option<u64>,
+    ) -> Result<Vec<u8>> {
+        let response = self
+            .agent
+            .get(url)
+            .call()
+            .with_context(|| format!("downloading {url}"))?;
+        let mut reader = response
+            .into_body()
+            .into_with_config()
+            .limit(MAX_DOWNLOAD)
+            .reader();
+        let task = progress.start(label, size);
+        let mut bytes = Vec::new();
+        let mut chunk = vec![0; 64 * 1024];
+        loop {
+            let read = reader
+                .read(&mut chunk)
+                .with_context(|| format!("downloading {url}"))?;
+            if read == 0 {
+                break;
+            }
+            bytes.extend_from_slice(chunk.get(..read).unwrap_or_default());
+            task.advance(read as u64);
+        }
+        Ok(bytes)
+    }
+}
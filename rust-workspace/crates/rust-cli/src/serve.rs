@@ -0,0 +1,38 @@
+//! `serve`: host a server in this process, for users who would rather not
+//! install its binary as well.
+//!
+//! `serve mcp` runs the server the `rust-mcp` binary runs (see
+//! [`rust_mcp`]) with this invocation's config file, `--set` overrides, and
+//! directory mode. Its records go to stderr and `logging.file` as any
+//! command's do, and a tool call's records also reach its client. It takes
+//! no instance lock, since `run_task` and `config_set` take it for each
+//! call, and has no time limit: it serves until the client disconnects
+//! (stdio) or SIGINT or SIGTERM, which let the tool calls in flight finish
+//! first. Over stdio, stdout carries the protocol and nothing else.
+
+use anyhow::{Result, bail};
+use clap::Subcommand;
+use rust_mcp::{ServeArgs, Transport};
+
+use crate::RuntimeContext;
+use crate::log_file;
+use crate::output::Output;
+
+#[derive(Debug, Clone, Copy, Subcommand)]
+pub enum ServeCommand {
+    /// Serve this CLI's operations to agents over MCP, as the rust-mcp binary does
+    Mcp(ServeArgs),
+}
+
+pub fn handle_serve(ctx: &RuntimeContext, command: ServeCommand) -> Result<Output> {
+    match command {
+        ServeCommand::Mcp(args) => {
+            if args.transport == Transport::Stdio && ctx.common.entrypoint {
+                bail!("`serve mcp` over stdio cannot log to stdout as --entrypoint does");
+            }
+            log_file::tee(rust_mcp::logging::to_client);
+            rust_mcp::serve(ctx.paths.clone(), ctx.config.clone(), args)?;
+            Ok(Output::streamed())
+        }
+    }
+}
@@ -0,0 +1,195 @@
+//! `sync` subcommands: share portable settings between machines via git.
+
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use anyhow::{Result, anyhow};
+use clap::{Args, Subcommand, ValueEnum};
+use serde::Serialize;
+
+use rust_core::config::{SECRET_MARKERS, SECRET_WORDS};
+use rust_core::sync::{MACHINE_KEYS, Prefer};
+use rust_core::{Action, SettingChange, SyncOutcome, SyncRepo};
+
+use crate::RuntimeContext;
+use crate::output::{Output, Report};
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SyncCommand {
+    /// Set up the sync repository and point it at a git remote
+    Init(InitCommand),
+    /// Send local setting changes to the remote
+    Push,
+    /// Merge setting changes from the remote into the local config
+    Pull(PullCommand),
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct InitCommand {
+    /// Git remote to sync with (any URL `git clone` accepts)
+    #[arg(value_name = "GIT_URL")]
+    url: String,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct PullCommand {
+    /// Resolve settings changed on both sides by keeping this side's value
+    #[arg(long, value_enum, value_name = "SIDE")]
+    prefer: Option<Side>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Side {
+    /// Keep the local value (the next push sends it)
+    Local,
+    /// Take the remote value
+    Remote,
+}
+
+impl From<Side> for Prefer {
+    fn from(side: Side) -> Self {
+        match side {
+            Side::Local => Self::Local,
+            Side::Remote => Self::Remote,
+        }
+    }
+}
+
+pub fn handle_sync(ctx: &RuntimeContext, command: SyncCommand) -> Result<Output> {
+    let repo = SyncRepo::open(&ctx.paths.data_dir);
+    match command {
+        SyncCommand::Init(cmd) => {
+            let remote_has_settings = if ctx.op.dry_run() {
+                ctx.op.plan(Action::CreateDir {
+                    path: repo.root().to_path_buf(),
+                });
+                ctx.op.plan(Action::RunCommand {
+                    command: format!("git fetch {}", cmd.url),
+                });
+                None
+            } else {
+                Some(repo.init(&cmd.url)?)
+            };
+            Output::new(&InitReport {
+                root: repo.root().to_path_buf(),
+                remote: cmd.url,
+                remote_has_settings,
+            })
+        }
+        SyncCommand::Push => Output::new(&OutcomeReport {
+            outcome: repo.push(&ctx.paths.config_file, &ctx.op)?,
+            verb: if ctx.op.dry_run() {
+                "would push"
+            } else {
+                "pushed"
+            },
+        }),
+        SyncCommand::Pull(cmd) => {
+            let outcome = repo.pull(
+                &ctx.paths.config_file,
+                cmd.prefer.map(Prefer::from),
+                &ctx.op,
+            )?;
+            let conflicts = outcome.conflicts.len();
+            let output = Output::new(&OutcomeReport {
+                outcome,
+                verb: if ctx.op.dry_run() {
+                    "would apply"
+                } else {
+                    "applied"
+                },
+            })?;
+            if conflicts == 0 {
+                return Ok(output);
+            }
+            Ok(output.then_fail(anyhow!(
+                "{conflicts} settings changed both here and on the remote; nothing was applied. \
+                 Rerun with --prefer local or --prefer remote"
+            )))
+        }
+    }
+}
+
+/// Result of `sync init`.
+#[derive(Debug, Serialize)]
+struct InitReport {
+    #[serde(with = "rust_core::pathfmt")]
+    root: PathBuf,
+    remote: String,
+    /// Whether the remote already had settings; unknown under `--dry-run`.
+    remote_has_settings: Option<bool>,
+}
+
+impl Report for InitReport {
+    fn text(&self) -> String {
+        let Some(has_settings) = self.remote_has_settings else {
+            return String::new();
+        };
+        let mut out = format!("sync set up at {}\n", self.root.display());
+        out.push_str(if has_settings {
+            "the remote already has settings; run `sync pull` to apply them\n"
+        } else {
+            "the remote is empty; run `sync push` to publish this machine's settings\n"
+        });
+        let _ = writeln!(
+            out,
+            "never synced: {}, keys containing {}, and keys with the word {}",
+            MACHINE_KEYS.join(", "),
+            SECRET_MARKERS.join(", "),
+            SECRET_WORDS.join(", ")
+        );
+        out
+    }
+}
+
+/// Result of `sync push` and `sync pull`.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+struct OutcomeReport {
+    outcome: SyncOutcome,
+    #[serde(skip)]
+    verb: &'static str,
+}
+
+impl Report for OutcomeReport {
+    fn text(&self) -> String {
+        let outcome = &self.outcome;
+        let mut out = String::new();
+        for change in &outcome.changes {
+            let _ = writeln!(out, "{}", describe(change));
+        }
+        for conflict in &outcome.conflicts {
+            let _ = writeln!(
+                out,
+                "! {}: local {}, remote {}",
+                conflict.key,
+                show(conflict.local.as_ref()),
+                show(conflict.remote.as_ref())
+            );
+        }
+        if outcome.changes.is_empty() && outcome.conflicts.is_empty() {
+            out.push_str("already in sync\n");
+        } else if outcome.conflicts.is_empty() {
+            let _ = writeln!(out, "{} {} changes", self.verb, outcome.changes.len());
+        }
+        out
+    }
+}
+
+/// `+ key = value`, `- key = value`, or `~ key: old -> new`.
+fn describe(change: &SettingChange) -> String {
+    match (&change.before, &change.after) {
+        (None, Some(after)) => format!("+ {} = {after}", change.key),
+        (Some(before), None) => format!("- {} = {before}", change.key),
+        (before, after) => format!(
+            "~ {}: {} -> {}",
+            change.key,
+            show(before.as_ref()),
+            show(after.as_ref())
+        ),
+    }
+}
+
+fn show(value: Option<&toml::Value>) -> String {
+    value.map_or_else(|| "(unset)".to_string(), ToString::to_string)
+}
@@ -0,0 +1,113 @@
+//! Background check for a newer release, with a one-line notice.
+//!
+//! At most once per `updates.interval_hours`, the command starts a detached
+//! copy of this binary with `<PREFIX>_UPDATE_CHECK` set to the state
+//! directory ([`check_from_env`]), which asks the releases API for the
+//! latest version and caches the answer in the state directory. Once the
+//! command is done, the notice is printed from that cache, so a new release
+//! shows up on the run after the check. Nothing waits on the network or fails
+//! the command: the check outlives it, and a failed check is retried on the
+//! next run.
+
+use std::env;
+use std::io::{self, IsTerminal as _};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use log::debug;
+
+use rust_core::update::{self, UpdateCheck};
+use rust_core::{ExitCode, compat, env_prefix};
+
+use crate::self_update::Client;
+use crate::{APP_NAME, RuntimeContext};
+
+/// Time limit for the background request.
+const CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The notice to print once the command is done.
+#[derive(Debug)]
+pub struct UpdateNotice {
+    /// The last recorded check.
+    cached: Option<UpdateCheck>,
+}
+
+impl UpdateNotice {
+    /// Start a check in the background if one is due. Disabled by
+    /// `--no-update-check` or `updates.check = false`, and skipped under
+    /// `--quiet`, `--dry-run`, `--entrypoint`, and when stderr is not a
+    /// terminal, where nobody would read the notice.
+    pub fn start(ctx: &RuntimeContext) -> Option<Self> {
+        let common = &ctx.common;
+        if common.no_update_check
+            || !ctx.config.updates.check
+            || common.quiet
+            || common.dry_run
+            || common.entrypoint
+            || !io::stderr().is_terminal()
+        {
+            return None;
+        }
+        let state_dir = &ctx.paths.state_dir;
+        let interval = compat::hours(ctx.config.updates.interval_hours);
+        if update::check_due(state_dir, interval)
+            && let Err(err) = spawn(state_dir)
+        {
+            debug!("not checking for updates: {err:#}");
+        }
+        Some(Self {
+            cached: UpdateCheck::load(state_dir),
+        })
+    }
+
+    /// Print the notice if a newer release is known.
+    pub fn finish(self) {
+        let current = env!("CARGO_PKG_VERSION");
+        if let Some(latest) = self.cached.and_then(|check| check.newer_than(current)) {
+            eprintln!(
+                "{APP_NAME} {latest} is available (installed: {current}); run `{APP_NAME} self-update --yes`"
+            );
+        }
+    }
+}
+
+/// Variable naming the state directory to record a check in, set for the
+/// detached copy instead of running a command.
+fn check_var() -> String {
+    format!("{}_UPDATE_CHECK", env_prefix())
+}
+
+/// Start the check for `state_dir`, detached from this process's
+/// standard streams so it cannot write into the command's output, and (on
+/// unix) in its own process group so closing the terminal does not hang it
+/// up.
+fn spawn(state_dir: &Path) -> io::Result<()> {
+    let mut command = Command::new(env::current_exe()?);
+    command
+        .env(check_var(), state_dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(&mut command, 0);
+    // Never waited for: it may outlive this process, which is the point.
+    let _child = command.spawn()?;
+    Ok(())
+}
+
+/// If the check variable is set, look up the latest release and record it
+/// in the directory it names. Never fails and takes no lock, since it runs
+/// beside the command that started it; a failed check is due again next
+/// time.
+pub fn check_from_env() -> Option<ExitCode> {
+    let state_dir = env::var_os(check_var())?;
+    let _ = check(Path::new(&state_dir));
+    Some(ExitCode::Success)
+}
+
+/// Look up the latest release and record it in `state_dir`.
+fn check(state_dir: &Path) -> anyhow::Result<()> {
+    let release = Client::new(CHECK_TIMEOUT)?.release(None)?;
+    UpdateCheck::now(&release.version()?).save(state_dir)
+}
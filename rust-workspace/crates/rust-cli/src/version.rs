@@ -0,0 +1,62 @@
+//! `version` and `--version`: the release and the build it came from, for bug
+//! reports and fleet inventory. The build facts are recorded by `build.rs`.
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::APP_NAME;
+use crate::output::{Output, Report};
+
+/// Body of `--version`: the version, then the build facts one per line.
+pub const LONG_VERSION: &str = concat!(
+    env!("CARGO_PKG_VERSION"),
+    "\ncommit:   ",
+    env!("BUILD_GIT_COMMIT"),
+    "\nbuilt:    ",
+    env!("BUILD_DATE"),
+    "\ntarget:   ",
+    env!("BUILD_TARGET"),
+    "\nrustc:    ",
+    env!("BUILD_RUSTC"),
+    "\nprofile:  ",
+    env!("BUILD_PROFILE"),
+    "\nfeatures: ",
+    env!("BUILD_FEATURES"),
+);
+
+/// Result of `version`.
+#[derive(Debug, Serialize)]
+struct VersionReport {
+    name: &'static str,
+    version: &'static str,
+    /// Short commit hash, or `unknown` outside a git checkout.
+    commit: &'static str,
+    /// RFC 3339 build time (`SOURCE_DATE_EPOCH` when set).
+    build_date: &'static str,
+    target: &'static str,
+    rustc: &'static str,
+    profile: &'static str,
+    features: Vec<&'static str>,
+}
+
+impl Report for VersionReport {
+    fn text(&self) -> String {
+        format!("{APP_NAME} {LONG_VERSION}\n")
+    }
+}
+
+pub fn handle_version() -> Result<Output> {
+    Output::new(&VersionReport {
+        name: APP_NAME,
+        version: env!("CARGO_PKG_VERSION"),
+        commit: env!("BUILD_GIT_COMMIT"),
+        build_date: env!("BUILD_DATE"),
+        target: env!("BUILD_TARGET"),
+        rustc: env!("BUILD_RUSTC"),
+        profile: env!("BUILD_PROFILE"),
+        features: env!("BUILD_FEATURES")
+            .split(',')
+            .filter(|feature| !feature.is_empty())
+            .collect(),
+    })
+}
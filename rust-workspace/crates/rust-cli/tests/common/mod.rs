@@ -0,0 +1,48 @@
+//! Shared by the integration tests: a scratch directory per test and the
+//! binary pointed at it.
+
+use std::fs;
+use std::ops::Deref;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::Result;
+use rust_core::temp::TempScope;
+
+/// A test's own directory holding `config.toml` and the data, state, and
+/// cache directories, removed on drop whether the test passes or not. It
+/// derefs to its [`Path`].
+#[derive(Debug)]
+pub struct Scratch(TempScope);
+
+impl Scratch {
+    /// A new directory under `<system temp>/rust-cli-tests` whose
+    /// `config.toml` holds `config`.
+    pub fn new(config: &str) -> Result<Self> {
+        let scope = TempScope::new_in(&std::env::temp_dir().join("rust-cli-tests"))?;
+        fs::write(scope.path().join("config.toml"), config)?;
+        Ok(Self(scope))
+    }
+}
+
+impl Deref for Scratch {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        self.0.path()
+    }
+}
+
+/// `rust-cli --config <dir>/config.toml`, with the other directories under
+/// `dir` too and logging off.
+pub fn rust_cli(dir: &Path) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_rust-cli"));
+    command
+        .arg("--config")
+        .arg(dir.join("config.toml"))
+        .env("RUST_WORKSPACE_DATA_DIR", dir.join("data"))
+        .env("RUST_WORKSPACE_STATE_DIR", dir.join("state"))
+        .env("RUST_WORKSPACE_CACHE_DIR", dir.join("cache"))
+        .env("RUST_LOG", "off");
+    command
+}
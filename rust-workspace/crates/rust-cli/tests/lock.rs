@@ -0,0 +1,43 @@
+//! The instance lock: commands that write state wait for it or fail, and
+//! commands that only read run while another instance holds it.
+
+mod common;
+
+use anyhow::{Context as _, Result, ensure};
+use rust_core::lock::{InstanceLock, LOCK_FILENAME};
+
+use common::{Scratch, rust_cli};
+
+#[test]
+fn only_commands_that_write_wait_for_the_lock() -> Result<()> {
+    let dir = Scratch::new("profile = \"locked\"\n")?;
+    let _held = InstanceLock::acquire(&dir.join("state").join(LOCK_FILENAME), None)?;
+    let root = dir.to_str().context("scratch path is not UTF-8")?;
+
+    for read in [
+        &["config", "show"][..],
+        &["history", "list"],
+        &["cache", "stats"],
+    ] {
+        let output = rust_cli(&dir).args(read).output()?;
+        ensure!(
+            output.status.success(),
+            "{read:?} waited for the lock: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    for write in [
+        &["history", "clear"][..],
+        &["cache", "repair"],
+        &["generate", "all", "--root", root],
+        &["release", "stamp", "9.9.9", "--root", root],
+    ] {
+        let output = rust_cli(&dir).args(write).output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        ensure!(
+            !output.status.success() && stderr.contains("E0010"),
+            "{write:?} ran without the lock: {stderr}"
+        );
+    }
+    Ok(())
+}
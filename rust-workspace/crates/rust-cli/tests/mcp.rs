@@ -0,0 +1,64 @@
+//! `mcp`: the client subcommands against this binary's own `serve mcp`,
+//! which they start when no server is named.
+
+#![cfg(feature = "mcp")]
+
+mod common;
+
+use std::path::Path;
+use std::process::Output;
+
+use anyhow::{Context as _, Result, ensure};
+use serde_json::Value;
+
+use common::Scratch;
+
+/// `rust-cli <args>` against the config and directories in `dir`.
+fn rust_cli(dir: &Path, args: &[&str]) -> Result<Output> {
+    common::rust_cli(dir)
+        .args(args)
+        .output()
+        .context("running rust-cli mcp")
+}
+
+#[test]
+fn mcp_talks_to_the_served_server() -> Result<()> {
+    let dir = Scratch::new("profile = \"served\"\n")?;
+
+    let listed = rust_cli(&dir, &["--output", "json", "mcp", "list-tools"])?;
+    ensure!(listed.status.success(), "{listed:?}");
+    let tools: Value = serde_json::from_slice(&listed.stdout)?;
+    let names: Vec<&str> = tools
+        .as_array()
+        .context("tools are not a list")?
+        .iter()
+        .filter_map(|tool| tool["name"].as_str())
+        .collect();
+    ensure!(names.contains(&"get_profile"), "{names:?}");
+
+    let called = rust_cli(&dir, &["mcp", "call", "echo", "--arg", "message=hi"])?;
+    ensure!(called.status.success(), "{called:?}");
+    ensure!(String::from_utf8(called.stdout)? == "Echo: hi\n");
+
+    let read = rust_cli(&dir, &["mcp", "read-resource", "config://current"])?;
+    ensure!(read.status.success(), "{read:?}");
+    ensure!(String::from_utf8(read.stdout)?.contains("served"));
+
+    // Writes are off by default, so the server refuses the call.
+    let refused = rust_cli(
+        &dir,
+        &[
+            "mcp",
+            "call",
+            "config_set",
+            "--arg",
+            "key=profile",
+            "--arg",
+            "value=x",
+        ],
+    )?;
+    ensure!(!refused.status.success(), "{refused:?}");
+    let stderr = String::from_utf8(refused.stderr)?;
+    ensure!(stderr.contains("-32003"), "{stderr}");
+    Ok(())
+}
@@ -0,0 +1,68 @@
+//! `serve mcp`: the MCP server hosted by rust-cli, spoken to over stdio as
+//! an agent would. The server itself is tested in rust-mcp's suite.
+
+#![cfg(feature = "mcp")]
+
+mod common;
+
+use std::io::{BufRead as _, BufReader, Write as _};
+use std::process::Stdio;
+
+use anyhow::{Context as _, Result, ensure};
+use serde_json::{Value, json};
+
+use common::{Scratch, rust_cli};
+
+#[test]
+fn serve_mcp_answers_over_stdio() -> Result<()> {
+    let dir = Scratch::new("profile = \"served\"\n")?;
+    let mut child = rust_cli(&dir)
+        .args(["serve", "mcp"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .context("spawning rust-cli serve mcp")?;
+    let mut stdin = child.stdin.take().context("no stdin")?;
+    for message in [
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": {
+                "protocolVersion": "2025-06-18",
+                "capabilities": {},
+                "clientInfo": { "name": "rust-cli-tests", "version": "0" },
+            },
+        }),
+        json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }),
+        json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "tools/call",
+            "params": { "name": "get_profile", "arguments": {} },
+        }),
+    ] {
+        writeln!(stdin, "{message}")?;
+    }
+    let stdout = child.stdout.take().context("no stdout")?;
+    let mut answers = Vec::new();
+    for line in BufReader::new(stdout).lines() {
+        let answer: Value =
+            serde_json::from_str(&line?).context("every stdout line must be protocol")?;
+        let done = answer["id"] == 2;
+        answers.push(answer);
+        if done {
+            break;
+        }
+    }
+    // End of input ends the session.
+    drop(stdin);
+    let status = child.wait()?;
+    ensure!(status.success(), "{status}");
+    let profile = answers
+        .last()
+        .and_then(|answer| answer.pointer("/result/content/0/text"));
+    ensure!(profile == Some(&json!("served")), "{answers:?}");
+    Ok(())
+}
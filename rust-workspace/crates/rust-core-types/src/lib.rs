@@ -0,0 +1,839 @@
+//! Plain config data types shared by rust-workspace components.
+//!
+//! [`AppConfig`] and its sections, with their serde shape and documented
+//! defaults, and nothing that touches files, the environment, or the
+//! platform. `rust-core` loads, validates, and writes them; this crate lets
+//! embedded and wasm components read the same config without pulling that
+//! in. With default features off it is `no_std` and needs only `alloc`;
+//! the `schema` feature (on by default) derives `JsonSchema`.
+//!
+//! Defaults that depend on the machine, such as the doubled timeout on
+//! Windows, are applied by `rust-core` when it loads a config; the
+//! [`Default`] impls here are the documented values.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString as _};
+use alloc::vec::Vec;
+use core::fmt;
+
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Default `runtime.timeout`, in seconds.
+pub const DEFAULT_TIMEOUT_SECS: u64 = 60;
+
+/// Default zstd compression level for backups.
+pub const DEFAULT_COMPRESSION_LEVEL: i32 = 3;
+
+/// Daemon address probed by default: the API server's default listener.
+pub const DEFAULT_HEALTH_DAEMON: &str = "127.0.0.1:3000";
+
+/// Default time limit for a whole health check, in milliseconds.
+pub const DEFAULT_HEALTH_TIMEOUT_MS: u64 = 1000;
+
+/// Main application configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+#[cfg_attr(
+    feature = "schema",
+    schemars(
+        title = "Application Configuration",
+        description = "Main configuration for the application"
+    )
+)]
+pub struct AppConfig {
+    /// JSON Schema reference for editor support.
+    #[serde(rename = "$schema", default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub schema: Option<String>,
+
+    /// Active configuration profile.
+    #[cfg_attr(feature = "schema", schemars(default = "default_profile"))]
+    pub profile: String,
+
+    /// Logging configuration.
+    pub logging: LoggingConfig,
+
+    /// Runtime behavior configuration.
+    pub runtime: RuntimeConfig,
+
+    /// Custom paths for data and state directories.
+    pub paths: PathsConfig,
+
+    /// Security module integration for system deployments.
+    pub security: SecurityConfig,
+
+    /// Backup archive settings.
+    pub backup: BackupConfig,
+
+    /// Probes run by `healthcheck`.
+    pub health: HealthConfig,
+
+    /// When the machine counts as idle, for deferred work.
+    pub idle: IdleConfig,
+
+    /// How command results are shown.
+    pub output: OutputConfig,
+
+    /// Background check for newer releases.
+    pub updates: UpdatesConfig,
+
+    /// Record of past `run` invocations, shown by `history`.
+    pub history: HistoryConfig,
+
+    /// The long-running server, `rust-api`.
+    pub daemon: DaemonConfig,
+
+    /// The MCP server, `rust-mcp`.
+    pub mcp: McpConfig,
+
+    /// Command aliases: name to the command line it stands for, e.g.
+    /// `st = "config show --output table"`. Built-in commands cannot be
+    /// redefined.
+    pub aliases: BTreeMap<String, String>,
+
+    /// Tasks for `run <TASK>`, by name.
+    pub tasks: BTreeMap<String, TaskConfig>,
+}
+
+#[cfg(feature = "schema")]
+fn default_profile() -> String {
+    "default".to_string()
+}
+
+impl AppConfig {
+    /// Override the profile if a value is provided.
+    #[must_use]
+    pub fn with_profile_override(mut self, profile: Option<String>) -> Self {
+        if let Some(profile) = profile {
+            self.profile = profile;
+        }
+        self
+    }
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            schema: None,
+            profile: "default".to_string(),
+            logging: LoggingConfig::default(),
+            runtime: RuntimeConfig::default(),
+            paths: PathsConfig::default(),
+            security: SecurityConfig::default(),
+            backup: BackupConfig::default(),
+            health: HealthConfig::default(),
+            idle: IdleConfig::default(),
+            output: OutputConfig::default(),
+            updates: UpdatesConfig::default(),
+            history: HistoryConfig::default(),
+            daemon: DaemonConfig::default(),
+            mcp: McpConfig::default(),
+            aliases: BTreeMap::new(),
+            tasks: BTreeMap::new(),
+        }
+    }
+}
+
+/// Logging configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+#[cfg_attr(feature = "schema", schemars(description = "Logging configuration"))]
+pub struct LoggingConfig {
+    /// Log level (error, warn, info, debug, trace).
+    #[cfg_attr(feature = "schema", schemars(default = "default_log_level"))]
+    pub level: LogLevel,
+
+    /// How records are written: `text` (default) or `json` (one object per
+    /// line). The log file gets the same format unless `file_format` says
+    /// otherwise.
+    #[cfg_attr(feature = "schema", schemars(default = "default_log_format"))]
+    pub format: LogFormat,
+
+    /// Levels for single modules and their children, over `level`, e.g.
+    /// `"rust_core::config" = "trace"` or `hyper = "warn"`. `RUST_LOG`
+    /// directives win over these; the verbosity flags replace `level` only.
+    pub filters: BTreeMap<String, LogLevel>,
+
+    /// Optional path for log file output. Supports ~ and environment variables.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+
+    /// Level for the log file alone, e.g. `debug` in the file while stderr
+    /// stays at `warn`. Unset, the file gets what stderr gets. When set,
+    /// the file takes `logging.filters` but not `RUST_LOG`, the verbosity
+    /// flags, or `--quiet`, which are for the terminal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_level: Option<LogLevel>,
+
+    /// Format for the log file alone: `text` or `json`. Unset, the file
+    /// gets the format stderr gets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_format: Option<LogFormat>,
+
+    /// Recent records kept in memory at `debug` level (or `level`, if
+    /// finer) whatever stderr shows, and printed when a command fails or
+    /// under `--diagnostics` (default: 200); 0 turns it off.
+    pub ring_buffer: usize,
+}
+
+/// Log level enumeration for schema validation, ordered from the most
+/// severe (`Error`) to the least.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    /// Only emit error-level messages.
+    Error,
+    /// Emit warnings and errors.
+    Warn,
+    /// Emit informational messages and above (default).
+    #[default]
+    Info,
+    /// Emit debug diagnostics and above.
+    Debug,
+    /// Emit all messages including fine-grained traces.
+    Trace,
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::Warn => write!(f, "warn"),
+            Self::Info => write!(f, "info"),
+            Self::Debug => write!(f, "debug"),
+            Self::Trace => write!(f, "trace"),
+        }
+    }
+}
+
+#[cfg(feature = "schema")]
+const fn default_log_level() -> LogLevel {
+    LogLevel::Info
+}
+
+/// Encoding of log records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Human-readable lines, colored on a terminal (default).
+    #[default]
+    Text,
+    /// One JSON object per record: `time`, `level`, `msg`, then `target`
+    /// and any fields.
+    Json,
+}
+
+impl fmt::Display for LogFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text => write!(f, "text"),
+            Self::Json => write!(f, "json"),
+        }
+    }
+}
+
+#[cfg(feature = "schema")]
+const fn default_log_format() -> LogFormat {
+    LogFormat::Text
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self {
+            level: LogLevel::Info,
+            format: LogFormat::Text,
+            filters: BTreeMap::new(),
+            file: None,
+            file_level: None,
+            file_format: None,
+            ring_buffer: 200,
+        }
+    }
+}
+
+/// Runtime behavior configuration.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+#[cfg_attr(
+    feature = "schema",
+    schemars(description = "Runtime behavior configuration")
+)]
+pub struct RuntimeConfig {
+    /// Worker pool size. Defaults to logical CPU count when unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(range(min = 1)))]
+    pub parallelism: Option<usize>,
+
+    /// Time limit in seconds for a command, and for each task of `run`
+    /// (default: 60; 120 on Windows).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(range(min = 1)))]
+    pub timeout: Option<u64>,
+
+    /// Stop on first error.
+    pub fail_fast: bool,
+
+    /// Warn when the config file was written in another format version
+    /// than this build's, suggesting `config migrate`.
+    pub check_config_version: bool,
+
+    /// Throttle work to save power: `auto` (default) while on battery, `on`
+    /// always, `off` never. Halves parallelism and slows polling loops.
+    pub power_save: PowerSave,
+}
+
+impl Default for RuntimeConfig {
+    fn default() -> Self {
+        Self {
+            parallelism: None,
+            timeout: Some(DEFAULT_TIMEOUT_SECS),
+            fail_fast: true,
+            check_config_version: true,
+            power_save: PowerSave::Auto,
+        }
+    }
+}
+
+/// When to throttle work to save power.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum PowerSave {
+    /// Throttle while the machine runs on battery (default).
+    #[default]
+    Auto,
+    /// Always throttle.
+    On,
+    /// Never throttle.
+    Off,
+}
+
+impl fmt::Display for PowerSave {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Auto => write!(f, "auto"),
+            Self::On => write!(f, "on"),
+            Self::Off => write!(f, "off"),
+        }
+    }
+}
+
+/// Path override configuration.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+#[cfg_attr(
+    feature = "schema",
+    schemars(description = "Custom paths for data and state directories")
+)]
+pub struct PathsConfig {
+    /// Directory for persistent data. Supports ~ and environment variables.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_dir: Option<String>,
+
+    /// Directory for state files. Supports ~ and environment variables.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_dir: Option<String>,
+}
+
+/// Backup archive settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+#[cfg_attr(feature = "schema", schemars(description = "Backup archive settings"))]
+pub struct BackupConfig {
+    /// zstd compression level for `backup create` (default: 3).
+    #[cfg_attr(feature = "schema", schemars(range(min = 1, max = 22)))]
+    pub compression_level: i32,
+
+    /// Glob patterns of archive paths to leave out, e.g. `state/*.log`.
+    pub exclude: Vec<String>,
+}
+
+impl Default for BackupConfig {
+    fn default() -> Self {
+        Self {
+            compression_level: DEFAULT_COMPRESSION_LEVEL,
+            exclude: Vec::new(),
+        }
+    }
+}
+
+/// Probes run by `healthcheck`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+#[cfg_attr(
+    feature = "schema",
+    schemars(description = "Probes run by `healthcheck`")
+)]
+pub struct HealthConfig {
+    /// Probes to run, in any order (default: all of them).
+    pub probes: Vec<HealthProbe>,
+
+    /// Daemon to probe: `host:port` of its HTTP listener, or the path of its
+    /// Unix socket.
+    pub daemon: String,
+
+    /// Time limit for the whole check in milliseconds (default: 1000). Keep
+    /// it below the orchestrator's own probe timeout.
+    #[cfg_attr(feature = "schema", schemars(range(min = 1)))]
+    pub timeout_ms: u64,
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        Self {
+            probes: HealthProbe::ALL.to_vec(),
+            daemon: DEFAULT_HEALTH_DAEMON.to_string(),
+            timeout_ms: DEFAULT_HEALTH_TIMEOUT_MS,
+        }
+    }
+}
+
+/// When the machine counts as idle, for work deferred with `run --when-idle`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+#[cfg_attr(
+    feature = "schema",
+    schemars(description = "When the machine counts as idle, for deferred work")
+)]
+pub struct IdleConfig {
+    /// Highest 1-minute load average per CPU (default: 0.3).
+    #[cfg_attr(feature = "schema", schemars(range(min = 0)))]
+    pub max_load: f64,
+
+    /// Seconds since the last keyboard or mouse input (default: 300; 0
+    /// ignores input). Only measured on macOS.
+    pub min_input_idle: u64,
+
+    /// Seconds between checks while waiting (default: 30).
+    #[cfg_attr(feature = "schema", schemars(range(min = 1)))]
+    pub poll_interval: u64,
+
+    /// Give up after this many seconds (default: 3600; 0 waits
+    /// indefinitely).
+    pub max_wait: u64,
+}
+
+impl Default for IdleConfig {
+    fn default() -> Self {
+        Self {
+            max_load: 0.3,
+            min_input_idle: 300,
+            poll_interval: 30,
+            max_wait: 3600,
+        }
+    }
+}
+
+/// Background check for a newer release than the running binary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+#[cfg_attr(
+    feature = "schema",
+    schemars(description = "Background check for newer releases")
+)]
+pub struct UpdatesConfig {
+    /// Look for a newer release while commands run and print a one-line
+    /// notice when there is one (default: true).
+    pub check: bool,
+
+    /// Hours between checks (default: 24); the result is cached in the
+    /// state directory in between.
+    #[cfg_attr(feature = "schema", schemars(range(min = 1)))]
+    pub interval_hours: u64,
+}
+
+impl Default for UpdatesConfig {
+    fn default() -> Self {
+        Self {
+            check: true,
+            interval_hours: 24,
+        }
+    }
+}
+
+/// The long-running server, `rust-api`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+#[cfg_attr(
+    feature = "schema",
+    schemars(description = "The long-running server, `rust-api`")
+)]
+pub struct DaemonConfig {
+    /// Status page served next to the API.
+    pub http: DaemonHttpConfig,
+}
+
+/// Status page the daemon serves at `/status` to local clients.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+#[cfg_attr(
+    feature = "schema",
+    schemars(description = "Status page the daemon serves at `/status`")
+)]
+pub struct DaemonHttpConfig {
+    /// Serve the page, with a summary of the config, recent runs, and the
+    /// tail of `logging.file` (default: false).
+    pub enabled: bool,
+
+    /// Token the page asks for. Unset, the daemon makes one up when it
+    /// starts and writes it to `status.token` in the state directory,
+    /// readable only by its user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+
+    /// Most runs the page lists, newest first (default: 20).
+    #[cfg_attr(feature = "schema", schemars(range(min = 1)))]
+    pub recent_runs: usize,
+}
+
+impl Default for DaemonHttpConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            token: None,
+            recent_runs: 20,
+        }
+    }
+}
+
+/// The MCP server, `rust-mcp`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+#[cfg_attr(
+    feature = "schema",
+    schemars(description = "The MCP server, `rust-mcp`")
+)]
+pub struct McpConfig {
+    /// Let clients change the config file through the `config_set` tool
+    /// (default: false). Reading it is always allowed, with secrets
+    /// redacted.
+    pub allow_config_writes: bool,
+
+    /// Tools the server offers, by name; unset offers every tool. Others
+    /// are left out of `tools/list` and refused when called.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled_tools: Option<Vec<String>>,
+
+    /// Tools the server does not offer, even when `enabled_tools` lists
+    /// them (default: none), e.g. `["config_set", "run_task"]` for a
+    /// read-only deployment.
+    pub disabled_tools: Vec<String>,
+
+    /// Most detailed server log records sent to a client while its tool
+    /// calls run, as `notifications/message` (default: info). A client's
+    /// `logging/setLevel` can ask for less, not more.
+    #[cfg_attr(feature = "schema", schemars(default = "default_log_level"))]
+    pub log_level: LogLevel,
+
+    /// Seconds the tool calls in flight on SIGINT or SIGTERM get to finish
+    /// before they are cancelled (default: 10). No new calls are taken
+    /// meanwhile.
+    pub shutdown_grace: u64,
+
+    /// Who may connect over `--transport http`.
+    pub auth: McpAuthConfig,
+
+    /// Record of tool calls, shown by `logs --audit`.
+    pub audit: McpAuditConfig,
+
+    /// How often each session may call tools.
+    pub rate_limit: McpRateLimitConfig,
+}
+
+impl Default for McpConfig {
+    fn default() -> Self {
+        Self {
+            allow_config_writes: false,
+            enabled_tools: None,
+            disabled_tools: Vec::new(),
+            log_level: LogLevel::default(),
+            shutdown_grace: 10,
+            auth: McpAuthConfig::default(),
+            audit: McpAuditConfig::default(),
+            rate_limit: McpRateLimitConfig::default(),
+        }
+    }
+}
+
+/// Bearer tokens for `rust-mcp --transport http`. With none set the server
+/// only listens on loopback addresses, and lets every local client in.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+#[cfg_attr(
+    feature = "schema",
+    schemars(description = "Bearer tokens for `rust-mcp --transport http`")
+)]
+pub struct McpAuthConfig {
+    /// Token a client sends as `Authorization: Bearer <token>` to use every
+    /// tool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+
+    /// More clients by name, each with a token of its own and, optionally,
+    /// only some tools.
+    pub clients: BTreeMap<String, McpClientConfig>,
+}
+
+/// A client of `rust-mcp --transport http` with a token of its own.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+#[cfg_attr(
+    feature = "schema",
+    schemars(description = "A client of `rust-mcp --transport http`")
+)]
+pub struct McpClientConfig {
+    /// Token the client sends as `Authorization: Bearer <token>`.
+    pub token: String,
+
+    /// Tools the client may list and call; unset allows every tool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<String>>,
+}
+
+/// Record of MCP tool calls in the state directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+#[cfg_attr(feature = "schema", schemars(description = "Record of MCP tool calls"))]
+pub struct McpAuditConfig {
+    /// Record each tool call with its tool, arguments (secrets redacted),
+    /// client and session, duration, and outcome (default: true).
+    pub record: bool,
+
+    /// Most calls to keep; the oldest go first (default: 10000).
+    #[cfg_attr(feature = "schema", schemars(range(min = 1)))]
+    pub max_entries: usize,
+
+    /// Days to keep a call for (default: 90); 0 keeps calls regardless of
+    /// age.
+    pub max_age_days: u64,
+}
+
+impl Default for McpAuditConfig {
+    fn default() -> Self {
+        Self {
+            record: true,
+            max_entries: 10_000,
+            max_age_days: 90,
+        }
+    }
+}
+
+/// Limits on how often one MCP session may call tools, over a sliding
+/// minute. A call past its limit is refused until the oldest call counted
+/// is a minute old.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+#[cfg_attr(
+    feature = "schema",
+    schemars(description = "How often each MCP session may call tools")
+)]
+pub struct McpRateLimitConfig {
+    /// Tool calls a session may make per minute, together, apart from the
+    /// tools in `tools` (default: 0, no limit).
+    pub calls_per_minute: u32,
+
+    /// Calls per minute to particular tools, by name, each counted on its
+    /// own instead of against `calls_per_minute` (default: none), e.g.
+    /// `{ run_task = 5 }`; 0 lifts the limit on that tool.
+    pub tools: BTreeMap<String, u32>,
+}
+
+/// Record of past `run` invocations in the state directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+#[cfg_attr(
+    feature = "schema",
+    schemars(description = "Record of past `run` invocations")
+)]
+pub struct HistoryConfig {
+    /// Record each `run` with its task, profile, arguments, times, and exit
+    /// status (default: true).
+    pub record: bool,
+
+    /// Most runs to keep; the oldest go first (default: 1000).
+    #[cfg_attr(feature = "schema", schemars(range(min = 1)))]
+    pub max_entries: usize,
+
+    /// Days to keep a run for (default: 90); 0 keeps runs regardless of
+    /// age.
+    pub max_age_days: u64,
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        Self {
+            record: true,
+            max_entries: 1000,
+            max_age_days: 90,
+        }
+    }
+}
+
+/// A task for `run <TASK>`: a shell command or one of this binary's own
+/// commands, run after the tasks it depends on.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+#[cfg_attr(feature = "schema", schemars(description = "A task for `run <TASK>`"))]
+pub struct TaskConfig {
+    /// Shell command, run with `sh -c` (`cmd /C` on Windows).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+
+    /// Command line of this binary to run instead of a shell command, e.g.
+    /// `cache verify`. A task with neither only groups its dependencies.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub builtin: Option<String>,
+
+    /// Working directory (default: the current directory). Supports ~ and
+    /// environment variables.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+
+    /// Environment variables set for the command.
+    pub env: BTreeMap<String, String>,
+
+    /// Run `command` under a pseudo-terminal, for tools that only color
+    /// their output or draw progress when writing to a terminal. The clean
+    /// transcript, escape sequences and redraws removed, goes to
+    /// `runs/<correlation id>/logs/<task>.log` in the state directory. Needs
+    /// the `pty` feature, and a `builtin` task cannot set it.
+    pub pty: bool,
+
+    /// Time limit in seconds (default: `--timeout`, then `runtime.timeout`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(range(min = 1)))]
+    pub timeout: Option<u64>,
+
+    /// Tasks that must succeed before this one starts.
+    pub depends_on: Vec<String>,
+
+    /// Run in a fresh directory of its own under the state directory
+    /// (`runs/<correlation id>/work/<task>`) instead of `cwd`, which is kept
+    /// for inspection after the run.
+    pub isolated: bool,
+
+    /// Files and directories, relative to `cwd`, made available in the
+    /// isolated directory under the same names: symlinked where the platform
+    /// allows, copied otherwise.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub inputs: Vec<String>,
+
+    /// Files and directories, relative to the isolated directory, copied back
+    /// to `cwd` when the task succeeds. One that is missing fails the task.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub outputs: Vec<String>,
+}
+
+/// One check made by `healthcheck`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum HealthProbe {
+    /// The configuration loads and validates.
+    Config,
+    /// A file can be created in the state directory.
+    State,
+    /// The daemon answers `GET /health` with 200.
+    Daemon,
+}
+
+impl HealthProbe {
+    /// Every probe, in the order they run.
+    pub const ALL: [Self; 3] = [Self::Config, Self::State, Self::Daemon];
+
+    /// Name used in config files and reports.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Config => "config",
+            Self::State => "state",
+            Self::Daemon => "daemon",
+        }
+    }
+}
+
+/// How command results are shown.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+#[cfg_attr(
+    feature = "schema",
+    schemars(description = "How command results are shown")
+)]
+pub struct OutputConfig {
+    /// Command that pages long text output on a terminal, e.g. `less -S`.
+    /// Unset uses `$PAGER`, then `less`; an empty string turns paging off.
+    pub pager: Option<String>,
+}
+
+/// Security module integration.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+#[cfg_attr(
+    feature = "schema",
+    schemars(description = "Security module integration for system deployments")
+)]
+pub struct SecurityConfig {
+    /// SELinux labeling of directories created in system mode.
+    pub selinux: SelinuxConfig,
+
+    /// Key fragments whose values `config show` masks, on top of the
+    /// built-in secret markers (token, password, secret, key, ...), e.g.
+    /// `hostname` or `health.daemon`. Matched anywhere in the dotted key,
+    /// ignoring case.
+    pub redact: Vec<String>,
+}
+
+/// SELinux labeling of directories created in system mode.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(default)]
+#[cfg_attr(
+    feature = "schema",
+    schemars(description = "SELinux labeling of directories created in system mode")
+)]
+pub struct SelinuxConfig {
+    /// What to do with newly created data and state directories when
+    /// SELinux is enabled.
+    pub relabel: RelabelMode,
+}
+
+/// How newly created system directories get their SELinux label.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum RelabelMode {
+    /// Leave the inherited label alone.
+    Off,
+    /// Log the label the policy expects and how to apply it (default).
+    #[default]
+    Warn,
+    /// Run `restorecon -R` on the new directories.
+    Restorecon,
+}
@@ -0,0 +1,127 @@
+//! Benchmarks for the work every command pays for before it does anything:
+//! path discovery, config loading, schema generation, and the task
+//! executor's scheduling overhead.
+//!
+//! Run with `cargo bench -p rust-core`; pass a filter such as `config/` to
+//! run one group. Criterion compares each run against the last one saved
+//! under `target/criterion/`, so regressions show up as a change.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::hint::black_box;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use criterion::{Criterion, Throughput};
+use rust_core::task::{self, RunOptions};
+use rust_core::{
+    APP_NAME, AppConfig, AppConfigExt as _, AppPaths, Ctx, PathMode, TaskConfig,
+    generate_example_config, generate_schema,
+};
+
+/// Tasks per executor run.
+const TASKS: usize = 1000;
+
+/// The example config, written to a scratch directory.
+fn example_config() -> Result<PathBuf> {
+    let dir = std::env::temp_dir().join(format!("rust-core-bench-{}", std::process::id()));
+    fs::create_dir_all(&dir)?;
+    let file = dir.join("config.toml");
+    fs::write(&file, generate_example_config(APP_NAME)?)?;
+    Ok(file)
+}
+
+fn paths(c: &mut Criterion) {
+    c.bench_function("paths/discover", |b| {
+        b.iter(|| AppPaths::discover(None));
+    });
+}
+
+fn config(c: &mut Criterion, paths: &AppPaths) {
+    let ctx = Ctx::default().with_dry_run(true);
+    c.bench_function("config/load", |b| {
+        b.iter(|| AppConfig::load(black_box(paths), &ctx));
+    });
+    c.bench_function("config/from_env", |b| b.iter(AppConfig::from_env));
+}
+
+fn schema(c: &mut Criterion) {
+    c.bench_function("schema/generate", |b| {
+        b.iter(|| generate_schema(black_box(APP_NAME)));
+    });
+    c.bench_function("schema/example_config", |b| {
+        b.iter(|| generate_example_config(black_box(APP_NAME)));
+    });
+}
+
+/// Scheduling cost alone: tasks with nothing to run, so no process is
+/// spawned and no poll interval is waited out.
+fn executor(c: &mut Criterion) {
+    let independent: BTreeMap<String, TaskConfig> = (0..TASKS)
+        .map(|index| (format!("task{index:04}"), TaskConfig::default()))
+        .chain(std::iter::once((
+            "all".to_string(),
+            TaskConfig {
+                depends_on: (0..TASKS).map(|index| format!("task{index:04}")).collect(),
+                ..TaskConfig::default()
+            },
+        )))
+        .collect();
+    let chain: BTreeMap<String, TaskConfig> = (0..TASKS)
+        .map(|index| {
+            let task = TaskConfig {
+                depends_on: index
+                    .checked_sub(1)
+                    .map(|previous| format!("task{previous:04}"))
+                    .into_iter()
+                    .collect(),
+                ..TaskConfig::default()
+            };
+            (format!("task{index:04}"), task)
+        })
+        .collect();
+    let last = format!("task{:04}", TASKS - 1);
+    let options = RunOptions {
+        parallelism: 8,
+        fail_fast: true,
+        timeout: None,
+        work_root: None,
+    };
+
+    let ctx = Ctx::default();
+    let mut group = c.benchmark_group("executor");
+    group.throughput(Throughput::Elements(TASKS as u64));
+    for (name, tasks, target) in [
+        ("independent", &independent, "all"),
+        ("chain", &chain, last.as_str()),
+    ] {
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                task::plan(tasks, target)
+                    .map(|order| task::run(tasks, &order, &options, |_, _, _| Ok(None), &ctx))
+            });
+        });
+    }
+    group.finish();
+}
+
+/// Run every group, honouring `cargo bench` arguments such as a filter.
+fn bench(app_paths: &AppPaths) {
+    let mut c = Criterion::default().configure_from_args();
+    paths(&mut c);
+    config(&mut c, app_paths);
+    schema(&mut c);
+    executor(&mut c);
+    c.final_summary();
+}
+
+fn main() -> Result<()> {
+    let file = example_config()?;
+    let app_paths = AppPaths::discover_in(PathMode::User, Some(&file))?;
+
+    bench(&app_paths);
+    if let Some(dir) = file.parent() {
+        fs::remove_dir_all(dir)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,38 @@
+//! Detects std APIs newer than the MSRV (`rust-version` in the workspace
+//! manifest) and enables a `has_*` cfg for each one the compiler has, so
+//! `rust_core::compat` can use std where it can and a fallback elsewhere.
+
+use std::env;
+use std::process::Command;
+
+/// Each cfg and the Rust 1.x minor version that stabilized its API.
+const PROBES: &[(&str, u32)] = &[
+    // File::lock, File::try_lock, File::unlock, TryLockError
+    ("has_file_lock", 89),
+];
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=RUSTC");
+    let minor = rustc_minor();
+    for &(cfg, since) in PROBES {
+        println!("cargo::rustc-check-cfg=cfg({cfg})");
+        // An unknown compiler is assumed to be current.
+        if minor.is_none_or(|minor| minor >= since) {
+            println!("cargo:rustc-cfg={cfg}");
+        }
+    }
+}
+
+/// Minor version of the compiler, e.g. 88 for `rustc 1.88.0 (...)`.
+fn rustc_minor() -> Option<u32> {
+    let rustc = env::var_os("RUSTC")?;
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+    let version = String::from_utf8(output.stdout).ok()?;
+    version
+        .split_whitespace()
+        .nth(1)?
+        .split('.')
+        .nth(1)?
+        .parse()
+        .ok()
+}
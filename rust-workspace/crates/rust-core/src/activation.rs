@@ -0,0 +1,128 @@
+//! systemd socket activation (`sd_listen_fds`).
+//!
+//! With a `.socket` unit, systemd binds the listening sockets itself, starts
+//! the service on the first connection, and passes the sockets as file
+//! descriptors 3, 4, ... with `LISTEN_FDS` (how many) and `LISTEN_PID` (for
+//! whom). The descriptors are taken over by duplicating them with
+//! `pidfd_getfd` and closing the inherited numbers, which keeps this free of
+//! `unsafe`; it needs Linux 5.6 or later, like the rest of socket activation
+//! needs Linux.
+
+use std::net::TcpListener;
+use std::os::fd::OwnedFd;
+
+use crate::error::{CoreError, Result};
+
+/// First descriptor systemd passes (`SD_LISTEN_FDS_START`).
+const LISTEN_FDS_START: i32 = 3;
+
+/// Take the sockets passed by systemd, in `ListenStream=` order.
+///
+/// Returns an empty list when the process was not socket-activated. Call it
+/// once, at startup: the inherited descriptors are closed afterwards, and the
+/// returned ones are close-on-exec.
+///
+/// # Errors
+///
+/// Returns an error if `LISTEN_FDS` names descriptors that cannot be taken
+/// over, or socket activation is requested on a platform without it.
+pub fn listen_fds() -> Result<Vec<OwnedFd>> {
+    let Some(count) = passed(
+        std::env::var("LISTEN_PID").ok().as_deref(),
+        std::env::var("LISTEN_FDS").ok().as_deref(),
+        std::process::id(),
+    ) else {
+        return Ok(Vec::new());
+    };
+    (LISTEN_FDS_START..LISTEN_FDS_START + count)
+        .map(|fd| {
+            let owned = duplicate(fd)?;
+            close(fd)?;
+            Ok(owned)
+        })
+        .collect()
+}
+
+/// The first passed socket as a TCP listener, or `None` without socket
+/// activation. Extra sockets are closed.
+///
+/// # Errors
+///
+/// See [`listen_fds`]; also fails if the first socket is not a TCP socket.
+pub fn tcp_listener() -> Result<Option<TcpListener>> {
+    let Some(fd) = listen_fds()?.into_iter().next() else {
+        return Ok(None);
+    };
+    let listener = TcpListener::from(fd);
+    listener.local_addr().map_err(|err| {
+        CoreError::Config(format!(
+            "socket passed by systemd is not a TCP listener ({err}); use ListenStream=HOST:PORT"
+        ))
+    })?;
+    Ok(Some(listener))
+}
+
+/// Number of descriptors passed to `own_pid`, if any.
+fn passed(listen_pid: Option<&str>, listen_fds: Option<&str>, own_pid: u32) -> Option<i32> {
+    if listen_pid?.parse::<u32>().ok()? != own_pid {
+        return None;
+    }
+    listen_fds?.parse().ok().filter(|count| *count > 0)
+}
+
+#[cfg(target_os = "linux")]
+fn duplicate(fd: i32) -> Result<OwnedFd> {
+    use rustix::process::{PidfdFlags, PidfdGetfdFlags, getpid, pidfd_getfd, pidfd_open};
+
+    let own = pidfd_open(getpid(), PidfdFlags::empty()).map_err(std::io::Error::from)?;
+    pidfd_getfd(&own, fd, PidfdGetfdFlags::empty()).map_err(|err| {
+        CoreError::Io(std::io::Error::new(
+            std::io::Error::from(err).kind(),
+            format!("taking over descriptor {fd} passed in LISTEN_FDS: {err}"),
+        ))
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn close(fd: i32) -> Result<()> {
+    nix::unistd::close(fd).map_err(std::io::Error::from)?;
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn close(_fd: i32) -> Result<()> {
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn duplicate(fd: i32) -> Result<OwnedFd> {
+    Err(CoreError::Unsupported(format!(
+        "socket activation (descriptor {fd} in LISTEN_FDS) outside Linux"
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_descriptors_meant_for_this_process() {
+        assert_eq!(passed(Some("7"), Some("2"), 7), Some(2));
+        assert_eq!(passed(Some("8"), Some("2"), 7), None);
+        assert_eq!(passed(None, Some("2"), 7), None);
+        assert_eq!(passed(Some("7"), Some("0"), 7), None);
+        assert_eq!(passed(Some("7"), Some("x"), 7), None);
+        assert_eq!(passed(Some("7"), None, 7), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn takes_over_a_listening_socket() -> anyhow::Result<()> {
+        use std::os::fd::AsRawFd as _;
+
+        let original = TcpListener::bind("127.0.0.1:0")?;
+        let taken = TcpListener::from(duplicate(original.as_raw_fd())?);
+        anyhow::ensure!(taken.local_addr()? == original.local_addr()?);
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+//! User-defined command aliases from the `[aliases]` config table.
+//!
+//! An alias maps a name to the start of a command line, split like a POSIX
+//! shell would (`st = "config show --output table"`). The binary expands the
+//! first positional argument before parsing, so an alias takes the same
+//! flags and trailing arguments as what it stands for. An alias may start
+//! with another alias; cycles are reported instead of looping. Built-in
+//! commands always win over an alias of the same name.
+
+use std::collections::BTreeMap;
+
+use crate::error::{CoreError, Result};
+
+/// Split an alias expansion into arguments.
+///
+/// # Errors
+///
+/// Returns [`CoreError::Config`] if the expansion is empty or has an
+/// unbalanced quote.
+pub fn split(name: &str, expansion: &str) -> Result<Vec<String>> {
+    match shlex::split(expansion) {
+        Some(words) if !words.is_empty() => Ok(words),
+        Some(_) => Err(CoreError::Config(format!("alias {name} is empty"))),
+        None => Err(CoreError::Config(format!(
+            "alias {name} has an unbalanced quote: {expansion}"
+        ))),
+    }
+}
+
+/// The arguments `name` stands for, following aliases of aliases, or `None`
+/// if `name` is not an alias. `is_builtin` names the commands that may not be
+/// redefined; expansion stops at the first one.
+///
+/// # Errors
+///
+/// Returns [`CoreError::Config`] if an expansion cannot be split or the
+/// aliases form a cycle.
+pub fn resolve(
+    aliases: &BTreeMap<String, String>,
+    name: &str,
+    is_builtin: impl Fn(&str) -> bool,
+) -> Result<Option<Vec<String>>> {
+    if is_builtin(name) || !aliases.contains_key(name) {
+        return Ok(None);
+    }
+    let mut chain: Vec<String> = Vec::new();
+    let mut words = vec![name.to_string()];
+    while let Some((first, rest)) = words.split_first()
+        && !is_builtin(first)
+        && let Some(expansion) = aliases.get(first)
+    {
+        let seen = chain.contains(first);
+        chain.push(first.clone());
+        if seen {
+            return Err(CoreError::Config(format!(
+                "alias cycle: {}",
+                chain.join(" -> ")
+            )));
+        }
+        let mut expanded = split(first, expansion)?;
+        expanded.extend_from_slice(rest);
+        words = expanded;
+    }
+    Ok(Some(words))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aliases(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(name, expansion)| ((*name).to_string(), (*expansion).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn expands_nested_aliases_and_stops_at_builtins() -> anyhow::Result<()> {
+        let table = aliases(&[
+            ("st", "config show --output table"),
+            ("sst", "st --no-pager"),
+            ("config", "paths show"),
+            ("q", "run 'two words'"),
+        ]);
+        let builtin = |name: &str| ["config", "run", "paths"].contains(&name);
+        anyhow::ensure!(
+            resolve(&table, "sst", builtin)?
+                == Some(vec![
+                    "config".into(),
+                    "show".into(),
+                    "--output".into(),
+                    "table".into(),
+                    "--no-pager".into(),
+                ])
+        );
+        anyhow::ensure!(
+            resolve(&table, "q", builtin)? == Some(vec!["run".into(), "two words".into()])
+        );
+        anyhow::ensure!(resolve(&table, "config", builtin)?.is_none());
+        anyhow::ensure!(resolve(&table, "nope", builtin)?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn reports_cycles_and_bad_quoting() -> anyhow::Result<()> {
+        let table = aliases(&[("a", "b -v"), ("b", "a"), ("bad", "run 'oops")]);
+        let err = resolve(&table, "a", |_| false).map_err(|err| err.to_string());
+        anyhow::ensure!(
+            matches!(&err, Err(message) if message.ends_with("alias cycle: a -> b -> a"))
+        );
+        anyhow::ensure!(resolve(&table, "bad", |_| false).is_err());
+        anyhow::ensure!(split("empty", " ").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,596 @@
+//! Generated artifacts and their manifest.
+//!
+//! Every file produced by code generation (schema, example config, man pages,
+//! completions, docs) is an [`Artifact`]. [`write_artifacts`] stages them
+//! in a [`Journal`] under a root directory together with a [`Manifest`] recording each file's
+//! SHA-256 and the generator that produced it. [`verify_artifacts`]
+//! regenerates in memory and reports any [`Drift`] against the committed
+//! copies, so CI can confirm a repository's generated files are current.
+//! A stale JSON file is described by the JSON pointers that changed and a
+//! stale TOML file by its dotted keys, so a failure says what to look at.
+//! [`source_snapshot`] tells a watcher when the sources they are generated
+//! from have changed.
+
+use std::collections::BTreeMap;
+use std::ffi::OsStr;
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::config_docs::{CONFIG_DOCS_FILENAME, generate_config_docs};
+use crate::journal::Journal;
+use crate::schema::{CONFIG_FILENAME, SCHEMA_FILENAME, generate_example_config, generate_schema};
+use crate::ui_spec::{UI_SPEC_FILENAME, generate_ui_spec};
+
+/// Changes listed per stale artifact before the rest are summarized.
+const MAX_CHANGES: usize = 20;
+
+/// Directories, relative to the workspace root, holding the sources the
+/// artifacts are generated from: the config types and the CLI definition.
+pub const SOURCE_DIRS: &[&str] = &[
+    "crates/rust-core-types/src",
+    "crates/rust-core/src",
+    "crates/rust-cli/src",
+];
+
+/// Manifest filename, written at the artifact root.
+pub const MANIFEST_FILENAME: &str = "generated.json";
+
+/// Directory (relative to the artifact root) holding the example config and schema.
+pub const EXAMPLES_DIR: &str = "examples";
+
+/// Directory (relative to the artifact root) holding generated references.
+pub const DOCS_DIR: &str = "docs";
+
+/// A generated file, addressed relative to the artifact root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Artifact {
+    /// Path relative to the artifact root, using `/` separators.
+    pub path: String,
+    /// File contents.
+    pub contents: Vec<u8>,
+}
+
+impl Artifact {
+    /// Create an artifact at `path` (relative, `/`-separated).
+    pub fn new(path: impl Into<String>, contents: impl Into<Vec<u8>>) -> Self {
+        Self {
+            path: path.into(),
+            contents: contents.into(),
+        }
+    }
+
+    /// Hex-encoded SHA-256 of the contents.
+    #[must_use]
+    pub fn sha256(&self) -> String {
+        sha256_hex(&self.contents)
+    }
+
+    /// Absolute location of the artifact under `root`.
+    #[must_use]
+    pub fn location(&self, root: &Path) -> PathBuf {
+        self.path
+            .split('/')
+            .fold(root.to_path_buf(), |path, part| path.join(part))
+    }
+}
+
+/// One manifest record.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// Artifact path relative to the root.
+    pub artifact: String,
+    /// Hex-encoded SHA-256 of the artifact contents.
+    pub sha256: String,
+    /// Generator name and version, e.g. `rust-cli 0.1.0`.
+    pub generator: String,
+}
+
+/// The set of generated artifacts committed under a root.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    /// Entries sorted by artifact path.
+    pub artifacts: Vec<ManifestEntry>,
+}
+
+impl Manifest {
+    /// Build a manifest describing `artifacts` as produced by `generator`.
+    #[must_use]
+    pub fn from_artifacts(artifacts: &[Artifact], generator: &str) -> Self {
+        let mut entries: Vec<ManifestEntry> = artifacts
+            .iter()
+            .map(|artifact| ManifestEntry {
+                artifact: artifact.path.clone(),
+                sha256: artifact.sha256(),
+                generator: generator.to_string(),
+            })
+            .collect();
+        entries.sort_by(|a, b| a.artifact.cmp(&b.artifact));
+        Self { artifacts: entries }
+    }
+
+    /// Load the manifest under `root`, or `None` if it does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest exists but cannot be read or parsed.
+    pub fn load(root: &Path) -> Result<Option<Self>> {
+        let path = root.join(MANIFEST_FILENAME);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let text =
+            fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+        serde_json::from_str(&text)
+            .map(Some)
+            .with_context(|| format!("parsing {}", path.display()))
+    }
+
+    /// Look up the entry for an artifact path.
+    #[must_use]
+    pub fn entry(&self, artifact: &str) -> Option<&ManifestEntry> {
+        self.artifacts
+            .iter()
+            .find(|entry| entry.artifact == artifact)
+    }
+
+    /// Render the manifest as pretty JSON with a trailing newline.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if JSON serialization fails.
+    pub fn to_json(&self) -> Result<String> {
+        let mut json = serde_json::to_string_pretty(self).context("serializing manifest")?;
+        json.push('\n');
+        Ok(json)
+    }
+}
+
+/// A difference between freshly generated and committed artifacts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Drift {
+    /// The artifact has not been written.
+    Missing(String),
+    /// The committed file differs from what the generator produces now.
+    Stale {
+        /// Artifact path relative to the root.
+        path: String,
+        /// What differs, from [`changes`].
+        changes: Vec<String>,
+    },
+    /// The file is current but the manifest has no matching entry.
+    Unrecorded(String),
+}
+
+impl fmt::Display for Drift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing(path) => write!(f, "{path} is missing"),
+            Self::Stale { path, changes } => {
+                write!(f, "{path} is out of date")?;
+                for change in changes.iter().take(MAX_CHANGES) {
+                    write!(f, "\n      {change}")?;
+                }
+                if changes.len() > MAX_CHANGES {
+                    write!(f, "\n      ... and {} more", changes.len() - MAX_CHANGES)?;
+                }
+                Ok(())
+            }
+            Self::Unrecorded(path) => write!(f, "{path} is not recorded in {MANIFEST_FILENAME}"),
+        }
+    }
+}
+
+/// Artifacts generated from `rust-core` alone: the config schema, the
+/// example config, and the settings UI spec under [`EXAMPLES_DIR`], and the
+/// config reference under [`DOCS_DIR`].
+///
+/// # Errors
+///
+/// Returns an error if schema or config serialization fails.
+pub fn core_artifacts(project_name: &str) -> Result<Vec<Artifact>> {
+    Ok(vec![
+        Artifact::new(
+            format!("{EXAMPLES_DIR}/{SCHEMA_FILENAME}"),
+            generate_schema(project_name)?,
+        ),
+        Artifact::new(
+            format!("{EXAMPLES_DIR}/{CONFIG_FILENAME}"),
+            generate_example_config(project_name)?,
+        ),
+        Artifact::new(
+            format!("{EXAMPLES_DIR}/{UI_SPEC_FILENAME}"),
+            generate_ui_spec(project_name)?,
+        ),
+        Artifact::new(
+            format!("{DOCS_DIR}/{CONFIG_DOCS_FILENAME}"),
+            generate_config_docs(project_name)?,
+        ),
+    ])
+}
+
+/// Stage `artifacts` and their manifest under `root` in `journal`.
+///
+/// Nothing is written until the journal is committed; a dry-run journal
+/// only logs the files. Returns the manifest that was (or would be) staged.
+///
+/// # Errors
+///
+/// Returns an error if the manifest cannot be serialized or a file cannot be
+/// staged.
+pub fn write_artifacts(
+    root: &Path,
+    artifacts: &[Artifact],
+    generator: &str,
+    journal: &mut Journal,
+) -> Result<Manifest> {
+    let manifest = Manifest::from_artifacts(artifacts, generator);
+    let manifest_artifact = Artifact::new(MANIFEST_FILENAME, manifest.to_json()?);
+    for artifact in artifacts.iter().chain(std::iter::once(&manifest_artifact)) {
+        journal.write(&artifact.location(root), &artifact.contents)?;
+    }
+    Ok(manifest)
+}
+
+/// Compare `artifacts` against the files and manifest committed under `root`.
+///
+/// Returns every difference found; an empty list means the committed copies
+/// are current.
+///
+/// # Errors
+///
+/// Returns an error if a file or the manifest exists but cannot be read.
+pub fn verify_artifacts(root: &Path, artifacts: &[Artifact]) -> Result<Vec<Drift>> {
+    let manifest = Manifest::load(root)?.unwrap_or_default();
+    let mut drift = Vec::new();
+    for artifact in artifacts {
+        let path = artifact.location(root);
+        if !path.exists() {
+            drift.push(Drift::Missing(artifact.path.clone()));
+            continue;
+        }
+        let committed = fs::read(&path).with_context(|| format!("reading {}", path.display()))?;
+        if committed != artifact.contents {
+            drift.push(Drift::Stale {
+                path: artifact.path.clone(),
+                changes: changes(&artifact.path, &committed, &artifact.contents),
+            });
+        } else if manifest
+            .entry(&artifact.path)
+            .is_none_or(|entry| entry.sha256 != artifact.sha256())
+        {
+            drift.push(Drift::Unrecorded(artifact.path.clone()));
+        }
+    }
+    Ok(drift)
+}
+
+/// Modification time of every `.rs` file under the [`SOURCE_DIRS`] of
+/// `root`. Snapshots taken before and after a source is edited, added, or
+/// removed differ; a missing directory contributes nothing.
+///
+/// # Errors
+///
+/// Returns an error if a source directory exists but cannot be read.
+pub fn source_snapshot(root: &Path) -> Result<BTreeMap<PathBuf, SystemTime>> {
+    let mut snapshot = BTreeMap::new();
+    for dir in SOURCE_DIRS {
+        let dir = root.join(dir);
+        if dir.is_dir() {
+            snapshot_dir(&dir, &mut snapshot)?;
+        }
+    }
+    Ok(snapshot)
+}
+
+fn snapshot_dir(dir: &Path, snapshot: &mut BTreeMap<PathBuf, SystemTime>) -> Result<()> {
+    let entries = fs::read_dir(dir).with_context(|| format!("reading {}", dir.display()))?;
+    for entry in entries {
+        let entry = entry.with_context(|| format!("reading {}", dir.display()))?;
+        let path = entry.path();
+        let meta = entry
+            .metadata()
+            .with_context(|| format!("reading {}", path.display()))?;
+        if meta.is_dir() {
+            snapshot_dir(&path, snapshot)?;
+        } else if path.extension() == Some(OsStr::new("rs")) {
+            let modified = meta
+                .modified()
+                .with_context(|| format!("reading {}", path.display()))?;
+            snapshot.insert(path, modified);
+        }
+    }
+    Ok(())
+}
+
+/// What differs between the `committed` and `generated` copies of the
+/// artifact at `path`.
+///
+/// JSON is compared by JSON pointer and TOML by dotted key; a TOML file
+/// whose values all match has changed comments or layout, reported at the
+/// first differing line, as is any other file.
+#[must_use]
+pub fn changes(path: &str, committed: &[u8], generated: &[u8]) -> Vec<String> {
+    let committed = String::from_utf8_lossy(committed);
+    let generated = String::from_utf8_lossy(generated);
+    let by_value = match Path::new(path).extension().and_then(OsStr::to_str) {
+        Some("json") => match (
+            serde_json::from_str(&committed),
+            serde_json::from_str(&generated),
+        ) {
+            (Ok(committed), Ok(generated)) => json_changes(&committed, &generated),
+            (Err(err), _) => vec![format!("committed copy is not valid JSON: {err}")],
+            (_, Err(err)) => vec![format!("generated copy is not valid JSON: {err}")],
+        },
+        Some("toml") => toml_changes(&committed, &generated),
+        _ => Vec::new(),
+    };
+    if !by_value.is_empty() {
+        return by_value;
+    }
+    let line = committed
+        .lines()
+        .zip(generated.lines())
+        .position(|(committed, generated)| committed != generated)
+        .unwrap_or_else(|| committed.lines().count().min(generated.lines().count()));
+    vec![format!("text differs from line {}", line + 1)]
+}
+
+/// Differences between two JSON values, one per JSON pointer (RFC 6901):
+/// `<pointer>: <committed> -> <generated>`, or `added`/`removed` for keys
+/// and array items present on one side only.
+///
+/// Objects with the same keys in a different order are reported as `keys
+/// reordered`.
+#[must_use]
+pub fn json_changes(committed: &Value, generated: &Value) -> Vec<String> {
+    let mut out = Vec::new();
+    diff_json("", committed, generated, &mut out);
+    out
+}
+
+fn diff_json(pointer: &str, committed: &Value, generated: &Value, out: &mut Vec<String>) {
+    match (committed, generated) {
+        (Value::Object(old), Value::Object(new)) => {
+            for (key, old_value) in old {
+                let child = format!("{pointer}/{}", escape_pointer(key));
+                match new.get(key) {
+                    Some(new_value) => diff_json(&child, old_value, new_value, out),
+                    None => out.push(format!("{child}: removed")),
+                }
+            }
+            for (key, new_value) in new {
+                if !old.contains_key(key) {
+                    let child = format!("{pointer}/{}", escape_pointer(key));
+                    out.push(format!("{child}: added {new_value}"));
+                }
+            }
+            if old.len() == new.len()
+                && old.keys().all(|key| new.contains_key(key))
+                && !old.keys().eq(new.keys())
+            {
+                let pointer = if pointer.is_empty() { "/" } else { pointer };
+                out.push(format!("{pointer}: keys reordered"));
+            }
+        }
+        (Value::Array(old), Value::Array(new)) => {
+            for (index, pair) in old.iter().zip(new).enumerate() {
+                diff_json(&format!("{pointer}/{index}"), pair.0, pair.1, out);
+            }
+            for (index, value) in new.iter().enumerate().skip(old.len()) {
+                out.push(format!("{pointer}/{index}: added {value}"));
+            }
+            for index in new.len()..old.len() {
+                out.push(format!("{pointer}/{index}: removed"));
+            }
+        }
+        _ if committed != generated => {
+            let pointer = if pointer.is_empty() { "/" } else { pointer };
+            out.push(format!("{pointer}: {committed} -> {generated}"));
+        }
+        _ => {}
+    }
+}
+
+/// `key` as a JSON pointer segment.
+fn escape_pointer(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+/// Differences between two TOML documents, one per dotted key:
+/// `<key>: <committed> -> <generated>`, or `added`/`removed`. Tables are
+/// compared key by key; arrays and inline values as a whole.
+#[must_use]
+pub fn toml_changes(committed: &str, generated: &str) -> Vec<String> {
+    let parse = |text: &str| text.parse::<toml::Table>();
+    let (old, new) = match (parse(committed), parse(generated)) {
+        (Ok(old), Ok(new)) => (flatten_toml(old), flatten_toml(new)),
+        (Err(err), _) => return vec![format!("committed copy is not valid TOML: {err}")],
+        (_, Err(err)) => return vec![format!("generated copy is not valid TOML: {err}")],
+    };
+    let mut out = Vec::new();
+    for (key, old_value) in &old {
+        match new.get(key) {
+            Some(new_value) if new_value != old_value => {
+                out.push(format!("{key}: {old_value} -> {new_value}"));
+            }
+            Some(_) => {}
+            None => out.push(format!("{key}: removed")),
+        }
+    }
+    for (key, new_value) in &new {
+        if !old.contains_key(key) {
+            out.push(format!("{key}: added {new_value}"));
+        }
+    }
+    out
+}
+
+/// Leaf values of `table` by dotted key; an empty table is a leaf, so
+/// adding or removing one is reported.
+fn flatten_toml(table: toml::Table) -> BTreeMap<String, String> {
+    fn walk(prefix: &str, table: toml::Table, out: &mut BTreeMap<String, String>) {
+        if table.is_empty() && !prefix.is_empty() {
+            out.insert(prefix.to_string(), "{}".to_string());
+        }
+        for (key, value) in table {
+            let mut path = prefix.to_string();
+            if !path.is_empty() {
+                path.push('.');
+            }
+            let _ = write!(path, "{key}");
+            match value {
+                toml::Value::Table(table) => walk(&path, table, out),
+                value => {
+                    out.insert(path, value.to_string());
+                }
+            }
+        }
+    }
+    let mut out = BTreeMap::new();
+    walk("", table, &mut out);
+    out
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::APP_NAME;
+    use crate::scratch::Scratch;
+
+    #[test]
+    fn verify_reports_missing_stale_and_unrecorded() -> Result<()> {
+        let root = Scratch::new()?;
+        let artifacts = vec![
+            Artifact::new("a.txt", "alpha\n"),
+            Artifact::new("nested/b.txt", "beta\n"),
+        ];
+        let mut journal = Journal::begin(&root.join("journal"), "test")?;
+        write_artifacts(&root, &artifacts, "test 0.0.0", &mut journal)?;
+        journal.commit()?;
+        anyhow::ensure!(
+            verify_artifacts(&root, &artifacts)?.is_empty(),
+            "fresh artifacts reported drift"
+        );
+
+        fs::write(root.join("a.txt"), "edited\n")?;
+        fs::remove_file(root.join("nested").join("b.txt"))?;
+        let extra = Artifact::new("c.txt", "gamma\n");
+        fs::write(root.join("c.txt"), "gamma\n")?;
+        let mut current = artifacts;
+        current.push(extra);
+
+        let drift = verify_artifacts(&root, &current)?;
+        anyhow::ensure!(
+            drift
+                == vec![
+                    Drift::Stale {
+                        path: "a.txt".to_string(),
+                        changes: vec!["text differs from line 1".to_string()],
+                    },
+                    Drift::Missing("nested/b.txt".to_string()),
+                    Drift::Unrecorded("c.txt".to_string()),
+                ],
+            "unexpected drift: {drift:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn changes_name_json_pointers_and_toml_keys() {
+        let json = changes(
+            "x.json",
+            br#"{"a": {"b/c": 1, "gone": true}, "list": [1, 2]}"#,
+            br#"{"a": {"b/c": 2}, "list": [1, 2, 3], "new": "x"}"#,
+        );
+        assert_eq!(
+            json,
+            [
+                "/a/b~1c: 1 -> 2",
+                "/a/gone: removed",
+                "/list/2: added 3",
+                "/new: added \"x\"",
+            ]
+        );
+
+        let toml = changes(
+            "x.toml",
+            b"profile = \"default\"\n[runtime]\ntimeout = 60\n[paths]\n",
+            b"profile = \"default\"\n[runtime]\ntimeout = 30\nfail_fast = true\n",
+        );
+        assert_eq!(
+            toml,
+            [
+                "paths: removed",
+                "runtime.timeout: 60 -> 30",
+                "runtime.fail_fast: added true",
+            ]
+        );
+
+        let reordered = changes("x.json", br#"{"a": 1, "b": 2}"#, br#"{"b": 2, "a": 1}"#);
+        assert_eq!(reordered, ["/: keys reordered"]);
+
+        let comments = changes("x.toml", b"# old\na = 1\n", b"# new\na = 1\n");
+        assert_eq!(comments, ["text differs from line 1"]);
+    }
+
+    #[test]
+    fn sha256_matches_known_digest() {
+        assert_eq!(
+            Artifact::new("x", "abc").sha256(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn source_snapshot_notices_edits_and_new_files() -> Result<()> {
+        let root = Scratch::new()?;
+        let src = root.join("crates/rust-core/src");
+        fs::create_dir_all(src.join("nested"))?;
+        fs::write(src.join("config.rs"), "// config\n")?;
+        fs::write(src.join("notes.md"), "not a source\n")?;
+        let before = source_snapshot(&root)?;
+        anyhow::ensure!(before.len() == 1, "{before:?}");
+
+        let edited = fs::File::options()
+            .write(true)
+            .open(src.join("config.rs"))?;
+        edited.set_modified(SystemTime::now() + std::time::Duration::from_secs(5))?;
+        let after_edit = source_snapshot(&root)?;
+        anyhow::ensure!(after_edit != before);
+        fs::write(src.join("nested/more.rs"), "// more\n")?;
+        anyhow::ensure!(source_snapshot(&root)?.len() == 2);
+        Ok(())
+    }
+
+    #[test]
+    fn committed_core_artifacts_are_current() -> Result<()> {
+        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")?;
+        let workspace_root = Path::new(&manifest_dir)
+            .parent() // crates/
+            .and_then(Path::parent) // workspace root
+            .context("finding workspace root from crate path")?;
+
+        let drift = verify_artifacts(workspace_root, &core_artifacts(APP_NAME)?)?;
+        anyhow::ensure!(
+            drift.is_empty(),
+            "generated artifacts are out of date (run `cargo run -p rust-cli -- generate all`):\n  - {}",
+            drift
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("\n  - ")
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,244 @@
+//! Audit log of MCP tool calls.
+//!
+//! The MCP server [`append`]s one [`AuditRecord`] per tool call to
+//! `mcp-audit.jsonl` in the state directory, one JSON object per line,
+//! including calls that were refused. Lines are only ever added, except by
+//! [`prune`], which drops the calls that fall outside the `[mcp.audit]`
+//! retention limits. [`list`] reads them back, oldest first, for
+//! `logs --audit`.
+
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write as _};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::config::{self, McpAuditConfig, REDACTED};
+use crate::error::{CoreError, IoResultExt as _, Result};
+
+/// File in the state directory holding the audit log.
+pub const AUDIT_FILENAME: &str = "mcp-audit.jsonl";
+
+/// Seconds in a day, for `mcp.audit.max_age_days`.
+const DAY_SECS: u64 = 24 * 60 * 60;
+
+/// One tool call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// When the call arrived, in seconds since the Unix epoch.
+    pub started_at: u64,
+    /// Tool that was called.
+    pub tool: String,
+    /// Arguments it was called with, secrets redacted (see
+    /// [`redact_arguments`]).
+    pub arguments: Value,
+    /// Name of the token the client presented (`mcp.auth.token` or a
+    /// `[mcp.auth.clients]` name); absent over stdio and without tokens.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client: Option<String>,
+    /// The client's `Mcp-Session-Id`; absent over stdio.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session: Option<String>,
+    /// How long the call took, in milliseconds.
+    pub duration_ms: u64,
+    /// How it ended.
+    pub outcome: AuditOutcome,
+}
+
+/// How a tool call ended.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum AuditOutcome {
+    /// The tool did what it was asked.
+    Ok,
+    /// The tool ran and reported failure in its result, as `run_task` does
+    /// for tasks that failed.
+    Failed,
+    /// The client cancelled the call, or the server did when shutting
+    /// down, before it was done.
+    Cancelled,
+    /// The call was refused or failed with a JSON-RPC error.
+    Error {
+        /// JSON-RPC error code.
+        code: i32,
+        /// Error message.
+        message: String,
+    },
+}
+
+/// Add `record` to the audit log in `state_dir`.
+///
+/// # Errors
+///
+/// Returns an error if the log cannot be written.
+pub fn append(state_dir: &Path, record: &AuditRecord) -> Result<()> {
+    let mut line = serde_json::to_string(record)
+        .map_err(|err| CoreError::Serialization(format!("audit log: {err}")))?;
+    line.push('\n');
+    fs::create_dir_all(state_dir).at_path("creating state directory", state_dir)?;
+    let path = state_dir.join(AUDIT_FILENAME);
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| file.write_all(line.as_bytes()))
+        .at_path("appending to audit log", &path)
+}
+
+/// Every recorded call in `state_dir`, oldest first. Nothing recorded yet
+/// is an empty log; lines that do not parse are skipped with a warning.
+///
+/// # Errors
+///
+/// Returns an error if the log exists but cannot be read.
+pub fn list(state_dir: &Path) -> Result<Vec<AuditRecord>> {
+    let path = state_dir.join(AUDIT_FILENAME);
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err).at_path("reading audit log", &path),
+    };
+    Ok(text
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter_map(|(index, line)| match serde_json::from_str(line) {
+            Ok(record) => Some(record),
+            Err(err) => {
+                log::warn!("{}:{}: skipping call: {err}", path.display(), index + 1);
+                None
+            }
+        })
+        .collect())
+}
+
+/// Drop the calls in `state_dir` beyond `retention`, judging age from
+/// `now`, and return how many were dropped. The log is only rewritten when
+/// some were.
+///
+/// # Errors
+///
+/// Returns an error if the log cannot be read or rewritten.
+pub fn prune(state_dir: &Path, retention: &McpAuditConfig, now: u64) -> Result<usize> {
+    let mut records = list(state_dir)?;
+    let before = records.len();
+    if retention.max_age_days > 0 {
+        let cutoff = now.saturating_sub(retention.max_age_days.saturating_mul(DAY_SECS));
+        records.retain(|record| record.started_at >= cutoff);
+    }
+    let excess = records.len().saturating_sub(retention.max_entries.max(1));
+    records.drain(..excess);
+    let dropped = before - records.len();
+    if dropped > 0 {
+        write(state_dir, &records)?;
+    }
+    Ok(dropped)
+}
+
+/// Mask the arguments a log should not hold.
+///
+/// Those are any whose name looks like a secret or contains one of `extra`
+/// (`security.redact`), and the `value` of a call whose `key` names such a
+/// setting, as `config_set`'s does. That `key` is the setting's name, so it
+/// is kept.
+pub fn redact_arguments(arguments: &mut Value, extra: &[String]) {
+    let Value::Object(object) = arguments else {
+        return;
+    };
+    let setting = object.get("key").and_then(Value::as_str);
+    let names_setting = setting.is_some();
+    let secret_setting = setting.is_some_and(|key| config::is_redacted_key(key, extra));
+    for (name, value) in object.iter_mut() {
+        let names_setting = names_setting && name == "key";
+        if (!names_setting && config::is_redacted_key(name, extra))
+            || (secret_setting && name == "value")
+        {
+            *value = REDACTED.into();
+        }
+    }
+}
+
+fn write(state_dir: &Path, records: &[AuditRecord]) -> Result<()> {
+    let mut text = String::new();
+    for record in records {
+        let line = serde_json::to_string(record)
+            .map_err(|err| CoreError::Serialization(format!("audit log: {err}")))?;
+        text.push_str(&line);
+        text.push('\n');
+    }
+    let path = state_dir.join(AUDIT_FILENAME);
+    let partial = path.with_extension("jsonl.partial");
+    fs::write(&partial, text).at_path("writing audit log", &partial)?;
+    fs::rename(&partial, &path).at_path("replacing audit log", &path)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::scratch::Scratch;
+
+    fn call(tool: &str, started_at: u64) -> AuditRecord {
+        AuditRecord {
+            started_at,
+            tool: tool.to_string(),
+            arguments: json!({}),
+            client: None,
+            session: None,
+            duration_ms: 3,
+            outcome: AuditOutcome::Ok,
+        }
+    }
+
+    #[test]
+    fn appends_in_order_and_prunes_by_retention() -> anyhow::Result<()> {
+        let dir = Scratch::new()?;
+        let retention = McpAuditConfig {
+            record: true,
+            max_entries: 2,
+            max_age_days: 1,
+        };
+        anyhow::ensure!(list(&dir)?.is_empty());
+        let now = 10 * DAY_SECS;
+        for (tool, at) in [
+            ("old", now - 2 * DAY_SECS),
+            ("echo", now - 60),
+            ("config_get", now - 30),
+            ("run_task", now),
+        ] {
+            append(&dir, &call(tool, at))?;
+        }
+        anyhow::ensure!(list(&dir)?.len() == 4);
+        anyhow::ensure!(prune(&dir, &retention, now)? == 2);
+        let kept: Vec<String> = list(&dir)?.into_iter().map(|record| record.tool).collect();
+        anyhow::ensure!(kept == ["config_get", "run_task"], "{kept:?}");
+        anyhow::ensure!(prune(&dir, &retention, now)? == 0);
+        Ok(())
+    }
+
+    #[test]
+    fn secret_arguments_are_redacted() {
+        let mut arguments = json!({ "key": "mcp.auth.token", "value": "hunter2" });
+        redact_arguments(&mut arguments, &[]);
+        assert_eq!(
+            arguments,
+            json!({ "key": "mcp.auth.token", "value": REDACTED })
+        );
+
+        let mut arguments = json!({ "message": "hi", "api_token": "t", "site": "s" });
+        redact_arguments(&mut arguments, &["site".to_string()]);
+        assert_eq!(
+            arguments,
+            json!({ "message": "hi", "api_token": REDACTED, "site": REDACTED })
+        );
+
+        let mut arguments = json!({ "key": "runtime.timeout", "value": "90" });
+        redact_arguments(&mut arguments, &[]);
+        assert_eq!(
+            arguments,
+            json!({ "key": "runtime.timeout", "value": "90" })
+        );
+    }
+}
@@ -0,0 +1,639 @@
+//! Backup archives of the application directories.
+//!
+//! [`create`] writes a zstd-compressed tar archive of the config and state
+//! directories, and optionally the data directory, ending with a
+//! `manifest.json` that records every file's size and SHA-256. [`restore`]
+//! extracts into a staging directory and checks each file against the
+//! manifest before copying anything into place, so a truncated or altered
+//! archive leaves the existing install untouched. The cache directory is
+//! never included; it can be rebuilt.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Component, Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result, anyhow, bail};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::APP_NAME;
+use crate::cache::glob_match;
+use crate::ctx::Ctx;
+use crate::lock::LOCK_FILENAME;
+use crate::paths::{AppPaths, DirKind};
+use crate::plan::Action;
+use crate::temp::TempScope;
+
+/// Name of the manifest entry, written last in every archive.
+pub const MANIFEST_NAME: &str = "manifest.json";
+
+/// Archive layout version recorded in the manifest.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Default zstd compression level.
+pub use rust_core_types::DEFAULT_COMPRESSION_LEVEL as DEFAULT_LEVEL;
+
+/// Directories a backup may contain.
+const BACKUP_DIRS: [DirKind; 3] = [DirKind::Config, DirKind::Data, DirKind::State];
+
+/// One file in a backup.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupFile {
+    /// Archive path: the directory name, then the path inside it
+    /// (`state/history.json`).
+    pub path: String,
+    /// Size in bytes.
+    pub size: u64,
+    /// Hex-encoded SHA-256 of the contents.
+    pub sha256: String,
+}
+
+/// Contents of a backup archive's `manifest.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupManifest {
+    /// Archive layout version ([`FORMAT_VERSION`]).
+    pub format: u32,
+    /// Application that wrote the backup.
+    pub app: String,
+    /// Version of the application that wrote the backup.
+    pub version: String,
+    /// Creation time in seconds since the Unix epoch.
+    pub created_at: u64,
+    /// Directories included in the backup.
+    pub dirs: Vec<DirKind>,
+    /// Every file in the archive, in archive order.
+    pub files: Vec<BackupFile>,
+}
+
+impl BackupManifest {
+    /// Combined size of all files in bytes.
+    #[must_use]
+    pub fn total_bytes(&self) -> u64 {
+        self.files.iter().map(|file| file.size).sum()
+    }
+}
+
+/// What [`create`] includes and how hard it compresses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackupOptions {
+    /// Also back up the data directory.
+    pub include_data: bool,
+    /// zstd compression level (1-22).
+    pub level: i32,
+    /// Glob patterns matched against archive paths (`state/*.log`); matching
+    /// files are left out.
+    pub exclude: Vec<String>,
+}
+
+impl Default for BackupOptions {
+    fn default() -> Self {
+        Self {
+            include_data: false,
+            level: DEFAULT_LEVEL,
+            exclude: Vec::new(),
+        }
+    }
+}
+
+/// Outcome of [`restore`].
+#[derive(Debug, Clone, Serialize)]
+pub struct RestoreReport {
+    /// Manifest of the restored archive.
+    pub manifest: BackupManifest,
+    /// Destination of every file restored (or that would be, on a dry run).
+    #[serde(with = "crate::pathfmt::seq")]
+    pub restored: Vec<PathBuf>,
+    /// Destinations among `restored` that replaced an existing file.
+    #[serde(with = "crate::pathfmt::seq")]
+    pub overwritten: Vec<PathBuf>,
+}
+
+/// Write a backup of `paths` to `output`.
+///
+/// The archive is written to `<output>.partial` and renamed into place once
+/// complete. The instance lock file is never included.
+///
+/// # Errors
+///
+/// Returns an error if a directory cannot be read, a file changes size while
+/// it is archived, the archive cannot be written, or `ctx` is cancelled or
+/// runs out of time (the partial archive is then removed).
+pub fn create(
+    paths: &AppPaths,
+    output: &Path,
+    options: &BackupOptions,
+    ctx: &Ctx,
+) -> Result<BackupManifest> {
+    let file_name = output
+        .file_name()
+        .ok_or_else(|| anyhow!("backup output {} has no file name", output.display()))?;
+    let mut partial_name = file_name.to_os_string();
+    partial_name.push(".partial");
+    let partial = output.with_file_name(partial_name);
+
+    let result = write_archive(paths, output, &partial, options, ctx);
+    if result.is_err() {
+        let _ = fs::remove_file(&partial);
+    }
+    let manifest = result?;
+    fs::rename(&partial, output)
+        .with_context(|| format!("moving backup into place at {}", output.display()))?;
+    Ok(manifest)
+}
+
+/// Check every file in `archive` against its manifest without extracting.
+///
+/// # Errors
+///
+/// Returns an error if the archive cannot be read, has no manifest, was
+/// written by another application, any file is missing, unlisted, or
+/// differs from its recorded size and checksum, or `ctx` is cancelled or
+/// runs out of time.
+pub fn verify(archive: &Path, ctx: &Ctx) -> Result<BackupManifest> {
+    scan(archive, None, ctx)
+}
+
+/// Restore `archive` into the directories of `paths`.
+///
+/// Files are verified in a staging directory first; existing files not in
+/// the backup are left alone. Unless `overwrite` is set, restoring fails if
+/// any file would be replaced. On a dry run the archive is only verified
+/// and the report lists what would be written. Cancellation is honoured
+/// until the first file is replaced; after that the restore completes.
+///
+/// # Errors
+///
+/// Returns an error if verification fails, existing files would be replaced
+/// without `overwrite`, a file cannot be written, or `ctx` is cancelled or
+/// runs out of time before files are replaced.
+pub fn restore(
+    paths: &AppPaths,
+    archive: &Path,
+    overwrite: bool,
+    ctx: &Ctx,
+) -> Result<RestoreReport> {
+    let dry_run = ctx.dry_run();
+    let stage = if dry_run {
+        None
+    } else {
+        Some(paths.temp_scope()?)
+    };
+    let manifest = scan(archive, stage.as_ref().map(TempScope::path), ctx)?;
+
+    let mut plan = Vec::with_capacity(manifest.files.len());
+    for file in &manifest.files {
+        let (kind, relative) = locate(&file.path)?;
+        plan.push((file, paths.dir(kind).join(relative)));
+    }
+    let overwritten: Vec<PathBuf> = plan
+        .iter()
+        .filter(|(_, dest)| dest.exists())
+        .map(|(_, dest)| dest.clone())
+        .collect();
+    if !overwrite
+        && !dry_run
+        && let Some(first) = overwritten.first()
+    {
+        bail!(
+            "restoring would overwrite {} existing files (including {}); pass --yes to replace them",
+            overwritten.len(),
+            first.display()
+        );
+    }
+
+    if let Some(stage) = &stage {
+        ctx.check()?;
+        let task = ctx.progress().start("restoring", Some(plan.len() as u64));
+        for (file, dest) in &plan {
+            task.set_message(&file.path);
+            let (kind, relative) = locate(&file.path)?;
+            install(&stage.path().join(kind.name()).join(relative), dest)?;
+            task.advance(1);
+        }
+    } else {
+        for (_, dest) in &plan {
+            ctx.plan(Action::WriteFile { path: dest.clone() });
+        }
+    }
+
+    Ok(RestoreReport {
+        restored: plan.into_iter().map(|(_, dest)| dest).collect(),
+        overwritten,
+        manifest,
+    })
+}
+
+fn write_archive(
+    paths: &AppPaths,
+    output: &Path,
+    partial: &Path,
+    options: &BackupOptions,
+    ctx: &Ctx,
+) -> Result<BackupManifest> {
+    let dirs: Vec<DirKind> = BACKUP_DIRS
+        .into_iter()
+        .filter(|&kind| options.include_data || kind != DirKind::Data)
+        .collect();
+    let skip = [absolute(output), absolute(partial)];
+    let lock_path = format!("{}/{LOCK_FILENAME}", DirKind::State.name());
+
+    let file = File::create(partial)
+        .with_context(|| format!("creating backup file {}", partial.display()))?;
+    let encoder = zstd::Encoder::new(file, options.level).context("starting zstd encoder")?;
+    let mut builder = tar::Builder::new(encoder);
+
+    let mut sources = Vec::new();
+    for &kind in &dirs {
+        sources.extend(
+            walk(paths.dir(kind))?
+                .into_iter()
+                .map(|source| (kind, source)),
+        );
+    }
+    let task = ctx
+        .progress()
+        .start("archiving", Some(sources.len() as u64));
+    let mut files = Vec::new();
+    for (kind, source) in sources {
+        ctx.check()?;
+        task.advance(1);
+        let root = paths.dir(kind);
+        if skip.contains(&absolute(&source)) {
+            continue;
+        }
+        let archive_path = archive_path(kind, root, &source)?;
+        task.set_message(&archive_path);
+        if archive_path == lock_path
+            || options
+                .exclude
+                .iter()
+                .any(|pattern| glob_match(pattern, &archive_path))
+        {
+            log::debug!("excluding {archive_path} from backup");
+            continue;
+        }
+        files.push(append_file(&mut builder, &source, archive_path)?);
+    }
+
+    let manifest = BackupManifest {
+        format: FORMAT_VERSION,
+        app: APP_NAME.to_string(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        created_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs()),
+        dirs,
+        files,
+    };
+    let json = serde_json::to_vec_pretty(&manifest).context("serializing backup manifest")?;
+    let mut header = tar::Header::new_gnu();
+    header.set_size(json.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(manifest.created_at);
+    builder
+        .append_data(&mut header, MANIFEST_NAME, json.as_slice())
+        .context("writing backup manifest")?;
+
+    let encoder = builder.into_inner().context("finishing tar stream")?;
+    let file = encoder.finish().context("finishing zstd stream")?;
+    file.sync_all()
+        .with_context(|| format!("syncing {}", partial.display()))?;
+    Ok(manifest)
+}
+
+fn append_file<W: io::Write>(
+    builder: &mut tar::Builder<W>,
+    source: &Path,
+    archive_path: String,
+) -> Result<BackupFile> {
+    let file = File::open(source).with_context(|| format!("opening {}", source.display()))?;
+    let meta = file
+        .metadata()
+        .with_context(|| format!("reading metadata of {}", source.display()))?;
+    let mut header = tar::Header::new_gnu();
+    header.set_metadata(&meta);
+    let mut reader = HashingReader::new(file.take(meta.len()));
+    builder
+        .append_data(&mut header, &archive_path, &mut reader)
+        .with_context(|| format!("archiving {}", source.display()))?;
+    if reader.bytes != meta.len() {
+        bail!("{} changed size while being backed up", source.display());
+    }
+    Ok(BackupFile {
+        path: archive_path,
+        size: reader.bytes,
+        sha256: reader.hex(),
+    })
+}
+
+/// Read `archive`, hashing every entry and copying it under `stage` when
+/// given, then check the results against the manifest.
+fn scan(archive: &Path, stage: Option<&Path>, ctx: &Ctx) -> Result<BackupManifest> {
+    let file =
+        File::open(archive).with_context(|| format!("opening backup {}", archive.display()))?;
+    let decoder = zstd::Decoder::new(file).context("starting zstd decoder")?;
+    let mut tar = tar::Archive::new(decoder);
+
+    let mut manifest: Option<BackupManifest> = None;
+    let mut found: BTreeMap<String, (u64, String)> = BTreeMap::new();
+    let entries = tar
+        .entries()
+        .with_context(|| format!("reading backup {}", archive.display()))?;
+    // The manifest comes last, so the file count is unknown until the end.
+    let task = ctx.progress().start("verifying", None);
+    for entry in entries {
+        ctx.check()?;
+        task.advance(1);
+        let mut entry = entry.with_context(|| format!("reading backup {}", archive.display()))?;
+        let name = entry
+            .path()
+            .context("reading archive entry path")?
+            .to_str()
+            .ok_or_else(|| anyhow!("archive entry path is not valid UTF-8"))?
+            .to_string();
+        if name == MANIFEST_NAME {
+            let mut json = Vec::new();
+            entry
+                .read_to_end(&mut json)
+                .context("reading backup manifest")?;
+            manifest = Some(serde_json::from_slice(&json).context("parsing backup manifest")?);
+            continue;
+        }
+        task.set_message(&name);
+        let (kind, relative) = locate(&name)?;
+        let staged = stage.map(|stage| stage.join(kind.name()).join(relative));
+        let mut reader = HashingReader::new(&mut entry);
+        if let Some(dest) = &staged {
+            if let Some(parent) = dest.parent() {
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("creating {}", parent.display()))?;
+            }
+            let mut out =
+                File::create(dest).with_context(|| format!("staging {}", dest.display()))?;
+            io::copy(&mut reader, &mut out).with_context(|| format!("extracting {name}"))?;
+        } else {
+            io::copy(&mut reader, &mut io::sink()).with_context(|| format!("reading {name}"))?;
+        }
+        let digest = (reader.bytes, reader.hex());
+        if let Some(dest) = &staged {
+            set_mode(dest, &entry)?;
+        }
+        found.insert(name, digest);
+    }
+
+    let manifest = manifest.ok_or_else(|| {
+        anyhow!(
+            "backup {} has no {MANIFEST_NAME}; it is truncated or not a backup",
+            archive.display()
+        )
+    })?;
+    if manifest.app != APP_NAME {
+        bail!(
+            "backup {} was written by {}, not {APP_NAME}",
+            archive.display(),
+            manifest.app
+        );
+    }
+    if manifest.format > FORMAT_VERSION {
+        bail!(
+            "backup {} uses format {}; this build reads up to {FORMAT_VERSION}",
+            archive.display(),
+            manifest.format
+        );
+    }
+
+    let mut problems = Vec::new();
+    for file in &manifest.files {
+        match found.remove(&file.path) {
+            Some((size, sha256)) if size == file.size && sha256 == file.sha256 => {}
+            Some(_) => problems.push(format!("{} does not match its checksum", file.path)),
+            None => problems.push(format!("{} is missing", file.path)),
+        }
+    }
+    problems.extend(
+        found
+            .into_keys()
+            .map(|path| format!("{path} is not listed in the manifest")),
+    );
+    if !problems.is_empty() {
+        bail!(
+            "backup {} failed verification: {}",
+            archive.display(),
+            problems.join("; ")
+        );
+    }
+    Ok(manifest)
+}
+
+/// Split an archive path into its directory and a safe relative path,
+/// rejecting anything that could escape the destination.
+fn locate(archive_path: &str) -> Result<(DirKind, PathBuf)> {
+    let unsafe_path = || anyhow!("archive entry {archive_path} is not a valid backup path");
+    let (dir, rest) = archive_path.split_once('/').ok_or_else(unsafe_path)?;
+    let kind = BACKUP_DIRS
+        .into_iter()
+        .find(|kind| kind.name() == dir)
+        .ok_or_else(unsafe_path)?;
+    let relative = PathBuf::from(rest);
+    let normal = relative
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)));
+    if rest.is_empty() || !normal {
+        return Err(unsafe_path());
+    }
+    Ok((kind, relative))
+}
+
+fn archive_path(kind: DirKind, root: &Path, source: &Path) -> Result<String> {
+    let relative = source
+        .strip_prefix(root)
+        .with_context(|| format!("{} is outside {}", source.display(), root.display()))?;
+    let mut parts = vec![kind.name()];
+    for component in relative.components() {
+        parts.push(
+            component
+                .as_os_str()
+                .to_str()
+                .ok_or_else(|| anyhow!("{} is not valid UTF-8", source.display()))?,
+        );
+    }
+    Ok(parts.join("/"))
+}
+
+/// Regular files under `root`, sorted; symlinks are skipped and a missing
+/// root yields nothing.
+fn walk(root: &Path) -> Result<Vec<PathBuf>> {
+    if !root.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut files = Vec::new();
+    let mut entries: Vec<_> = fs::read_dir(root)
+        .with_context(|| format!("reading {}", root.display()))?
+        .collect::<io::Result<_>>()
+        .with_context(|| format!("reading {}", root.display()))?;
+    entries.sort_by_key(fs::DirEntry::file_name);
+    for entry in entries {
+        let kind = entry
+            .file_type()
+            .with_context(|| format!("inspecting {}", entry.path().display()))?;
+        if kind.is_dir() {
+            files.extend(walk(&entry.path())?);
+        } else if kind.is_file() {
+            files.push(entry.path());
+        } else {
+            log::debug!("skipping non-regular file {}", entry.path().display());
+        }
+    }
+    Ok(files)
+}
+
+/// Copy a verified staged file to `dest` through a sibling temp file so a
+/// failure never leaves a half-written file behind.
+fn install(staged: &Path, dest: &Path) -> Result<()> {
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent).with_context(|| format!("creating {}", parent.display()))?;
+    }
+    let mut tmp_name = dest.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(".restore");
+    let tmp = dest.with_file_name(tmp_name);
+    fs::copy(staged, &tmp).with_context(|| format!("writing {}", tmp.display()))?;
+    fs::rename(&tmp, dest).with_context(|| format!("restoring {}", dest.display()))?;
+    Ok(())
+}
+
+#[cfg(unix)]
+fn set_mode<R: Read>(path: &Path, entry: &tar::Entry<'_, R>) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt as _;
+
+    let mode = entry.header().mode().context("reading entry mode")? & 0o7777;
+    fs::set_permissions(path, fs::Permissions::from_mode(mode))
+        .with_context(|| format!("setting permissions on {}", path.display()))
+}
+
+#[cfg(not(unix))]
+fn set_mode<R: Read>(_path: &Path, _entry: &tar::Entry<'_, R>) -> Result<()> {
+    Ok(())
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Reader adapter that hashes and counts everything read through it.
+struct HashingReader<R> {
+    inner: R,
+    hasher: Sha256,
+    bytes: u64,
+}
+
+impl<R> HashingReader<R> {
+    fn new(inner: R) -> Self {
+        Self {
+            inner,
+            hasher: Sha256::new(),
+            bytes: 0,
+        }
+    }
+
+    fn hex(&self) -> String {
+        format!("{:x}", self.hasher.clone().finalize())
+    }
+}
+
+impl<R: Read> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        let chunk = buf.get(..read).unwrap_or_default();
+        self.hasher.update(chunk);
+        self.bytes += chunk.len() as u64;
+        Ok(read)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch::Scratch;
+
+    /// Scratch paths with a config file, state, and data to back up.
+    fn scratch_paths(scratch: &Scratch) -> Result<AppPaths> {
+        let paths = scratch.paths();
+        fs::create_dir_all(scratch.join("config"))?;
+        fs::create_dir_all(paths.state_dir.join("nested"))?;
+        fs::create_dir_all(&paths.data_dir)?;
+        fs::write(&paths.config_file, "profile = \"work\"\n")?;
+        fs::write(paths.state_dir.join("nested").join("history.json"), "[]")?;
+        fs::write(paths.state_dir.join("debug.log"), "noise")?;
+        fs::write(paths.state_dir.join(LOCK_FILENAME), "")?;
+        fs::write(paths.data_dir.join("blob"), [7_u8; 64])?;
+        Ok(paths)
+    }
+
+    #[test]
+    fn round_trip_restores_verified_files() -> Result<()> {
+        let scratch = Scratch::new()?;
+        let paths = scratch_paths(&scratch)?;
+        let archive = paths.cache_dir.join("backup.tar.zst");
+        fs::create_dir_all(&paths.cache_dir)?;
+        let options = BackupOptions {
+            exclude: vec!["state/*.log".to_string()],
+            ..BackupOptions::default()
+        };
+        let manifest = create(&paths, &archive, &options, &Ctx::default())?;
+        let listed: Vec<&str> = manifest
+            .files
+            .iter()
+            .map(|file| file.path.as_str())
+            .collect();
+        anyhow::ensure!(
+            listed == ["config/config.toml", "state/nested/history.json"],
+            "{listed:?}"
+        );
+        anyhow::ensure!(verify(&archive, &Ctx::default())? == manifest);
+
+        fs::write(&paths.config_file, "profile = \"broken\"\n")?;
+        let refused = restore(&paths, &archive, false, &Ctx::default());
+        anyhow::ensure!(refused.is_err(), "overwrote without permission");
+        let report = restore(&paths, &archive, true, &Ctx::default())?;
+        anyhow::ensure!(report.overwritten.len() == 2, "{report:?}");
+        anyhow::ensure!(fs::read_to_string(&paths.config_file)? == "profile = \"work\"\n");
+        Ok(())
+    }
+
+    #[test]
+    fn truncated_archive_fails_verification() -> Result<()> {
+        let scratch = Scratch::new()?;
+        let paths = scratch_paths(&scratch)?;
+        let archive = paths.cache_dir.join("backup.tar.zst");
+        fs::create_dir_all(&paths.cache_dir)?;
+        let options = BackupOptions {
+            include_data: true,
+            ..BackupOptions::default()
+        };
+        create(&paths, &archive, &options, &Ctx::default())?;
+        let bytes = fs::read(&archive)?;
+        fs::write(&archive, bytes.get(..bytes.len() / 2).unwrap_or_default())?;
+        anyhow::ensure!(
+            verify(&archive, &Ctx::default()).is_err(),
+            "truncated archive verified"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn locate_rejects_escaping_paths() {
+        for bad in [
+            "state",
+            "state/",
+            "state/../x",
+            "cache/x",
+            "/etc/passwd",
+            "config/./x",
+        ] {
+            assert!(locate(bad).is_err(), "{bad} accepted");
+        }
+        assert!(locate("data/a/b").is_ok());
+    }
+}
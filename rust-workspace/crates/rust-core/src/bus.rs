@@ -0,0 +1,131 @@
+//! In-process event bus.
+//!
+//! Operations announce what happened as an [`Event`] on the [`EventBus`] of
+//! their [`Ctx`](crate::Ctx) without knowing who listens: the binary
+//! subscribes whatever consumes them (the task history writer, logging, a
+//! display) once, up front. Handlers run synchronously on the publishing
+//! thread, in the order they subscribed, so they should be quick; a slow
+//! consumer hands events to a thread of its own. Clones of a bus share
+//! their subscribers.
+
+use std::path::PathBuf;
+use std::sync::{Arc, PoisonError, RwLock};
+
+use serde::Serialize;
+
+use crate::task::{OutputStream, TaskRun};
+
+/// Something that happened, for whoever subscribed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum Event {
+    /// A task's process was started.
+    TaskStarted {
+        /// Task name.
+        task: String,
+    },
+    /// A task whose output is captured wrote a line.
+    TaskOutput {
+        /// Task name.
+        task: String,
+        /// Stream it wrote the line to.
+        stream: OutputStream,
+        /// The line, without its line ending.
+        line: String,
+    },
+    /// A task's outcome is known: it ended, or will not run.
+    TaskFinished {
+        /// How it ended.
+        #[serde(flatten)]
+        run: TaskRun,
+    },
+    /// The config was read again after changing on disk.
+    ConfigReloaded {
+        /// File it was read from.
+        #[serde(with = "crate::pathfmt")]
+        path: PathBuf,
+    },
+    /// An entry was removed from the cache.
+    CacheEvicted {
+        /// Entry key.
+        key: String,
+        /// Payload size in bytes.
+        size: u64,
+    },
+}
+
+/// Called with every published event.
+type Handler = Arc<dyn Fn(&Event) + Send + Sync>;
+
+/// Subscribers to [`Event`]s, shared between clones.
+#[derive(Clone, Default)]
+pub struct EventBus(Arc<RwLock<Vec<Handler>>>);
+
+impl EventBus {
+    /// Call `handler` with every event published from now on, for as long
+    /// as the bus lives.
+    pub fn subscribe(&self, handler: impl Fn(&Event) + Send + Sync + 'static) {
+        self.0
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(Arc::new(handler));
+    }
+
+    /// Pass `event` to each subscriber in turn.
+    pub fn publish(&self, event: &Event) {
+        // Not under the lock, so a handler may subscribe another.
+        let handlers = self
+            .0
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        for handler in handlers {
+            handler(event);
+        }
+    }
+}
+
+impl std::fmt::Debug for EventBus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let subscribers = self.0.read().map_or(0, |handlers| handlers.len());
+        f.debug_struct("EventBus")
+            .field("subscribers", &subscribers)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[test]
+    fn clones_deliver_to_every_subscriber_in_order() -> anyhow::Result<()> {
+        let bus = EventBus::default();
+        // Subscribing after the context took its clone still reaches it.
+        let ctx = crate::Ctx::default().with_events(bus.clone());
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        for name in ["history", "log"] {
+            let seen = Arc::clone(&seen);
+            bus.subscribe(move |event| {
+                if let Ok(mut seen) = seen.lock() {
+                    seen.push((name, event.clone()));
+                }
+            });
+        }
+        let event = Event::CacheEvicted {
+            key: "http/index".to_string(),
+            size: 42,
+        };
+        ctx.publish(&event);
+        let seen = seen.lock().map_err(|err| anyhow::anyhow!("{err}"))?.clone();
+        anyhow::ensure!(seen == [("history", event.clone()), ("log", event.clone())]);
+        anyhow::ensure!(
+            serde_json::to_value(&event)?
+                == serde_json::json!({"event": "cache_evicted", "key": "http/index", "size": 42})
+        );
+        Ok(())
+    }
+}
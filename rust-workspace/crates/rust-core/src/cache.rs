@@ -0,0 +1,776 @@
+//! Content cache under the application cache directory.
+//!
+//! Entries live in `<cache_dir>/entries/`, one file per key. The index is a
+//! single append-only log (`<cache_dir>/index.log`) where every line carries
+//! a CRC-32 of its payload, so a torn write from a crash or a concurrent
+//! writer is detected and skipped on replay instead of corrupting the index.
+//! Writers serialize on an advisory lock, and the log is compacted to its
+//! live records once superseded records pile up.
+//!
+//! Keys of the form `namespace/name` are grouped by namespace (keys without a
+//! `/` belong to [`DEFAULT_NAMESPACE`]) for [`Cache::stats`] and
+//! [`Cache::clear`]. Hit and miss counts per namespace persist in
+//! `<cache_dir>/counters.json`.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::bus::Event;
+use crate::compat;
+use crate::ctx::Ctx;
+use crate::plan::Action;
+
+/// Index log filename inside the cache directory.
+pub const INDEX_FILENAME: &str = "index.log";
+
+/// Directory holding entry payloads inside the cache directory.
+pub const ENTRIES_DIRNAME: &str = "entries";
+
+/// Persisted hit/miss counters inside the cache directory.
+pub const COUNTERS_FILENAME: &str = "counters.json";
+
+/// Namespace for keys without a `namespace/` prefix.
+pub const DEFAULT_NAMESPACE: &str = "default";
+
+/// Lock file serializing index writers.
+const INDEX_LOCK_FILENAME: &str = "index.lock";
+
+/// Superseded records tolerated before an append triggers compaction.
+const COMPACT_SLACK: usize = 256;
+
+/// Metadata for one cached entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheEntry {
+    /// Caller-supplied key.
+    pub key: String,
+    /// Payload size in bytes.
+    pub size: u64,
+    /// CRC-32 of the payload.
+    pub crc32: u32,
+    /// Seconds since the Unix epoch when the entry was stored.
+    pub stored_at: u64,
+}
+
+impl CacheEntry {
+    /// Namespace the entry belongs to (see [`namespace_of`]).
+    #[must_use]
+    pub fn namespace(&self) -> &str {
+        namespace_of(&self.key)
+    }
+}
+
+/// Namespace of a key: the part before the first `/`, or [`DEFAULT_NAMESPACE`].
+#[must_use]
+pub fn namespace_of(key: &str) -> &str {
+    key.split_once('/')
+        .map_or(DEFAULT_NAMESPACE, |(namespace, _)| namespace)
+}
+
+/// Lookup counters for one namespace.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LookupCounts {
+    /// Lookups that returned a verified payload.
+    pub hits: u64,
+    /// Lookups for unknown, missing, or damaged entries.
+    pub misses: u64,
+}
+
+/// Per-namespace cache statistics.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct NamespaceStats {
+    /// Live entries in the namespace.
+    pub entries: usize,
+    /// Total payload size in bytes.
+    pub bytes: u64,
+    /// Persisted lookup counters.
+    #[serde(flatten)]
+    pub lookups: LookupCounts,
+}
+
+/// Cache-wide statistics returned by [`Cache::stats`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct CacheStats {
+    /// Live entries across all namespaces.
+    pub entries: usize,
+    /// Total payload size in bytes.
+    pub payload_bytes: u64,
+    /// Size of the index log in bytes.
+    pub index_bytes: u64,
+    /// Lookup counters summed across namespaces.
+    #[serde(flatten)]
+    pub lookups: LookupCounts,
+    /// Breakdown by namespace, including namespaces that only have counters.
+    pub namespaces: BTreeMap<String, NamespaceStats>,
+}
+
+/// Selects entries for [`Cache::clear`]; unset fields match everything.
+#[derive(Debug, Clone, Default)]
+pub struct ClearFilter {
+    /// Only entries in this namespace.
+    pub namespace: Option<String>,
+    /// Only entries stored longer ago than this.
+    pub older_than: Option<Duration>,
+    /// Only keys matching this glob (`*` and `?` wildcards).
+    pub pattern: Option<String>,
+}
+
+impl ClearFilter {
+    /// Returns true if `entry` is selected, with `now` in Unix seconds.
+    #[must_use]
+    pub fn matches(&self, entry: &CacheEntry, now: u64) -> bool {
+        self.namespace
+            .as_deref()
+            .is_none_or(|namespace| entry.namespace() == namespace)
+            && self
+                .older_than
+                .is_none_or(|age| now.saturating_sub(entry.stored_at) > age.as_secs())
+            && self
+                .pattern
+                .as_deref()
+                .is_none_or(|pattern| glob_match(pattern, &entry.key))
+    }
+}
+
+/// One index log record.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum Record {
+    Put(CacheEntry),
+    Remove { key: String },
+}
+
+/// Result of replaying the index log.
+#[derive(Debug, Default)]
+struct Replay {
+    live: BTreeMap<String, CacheEntry>,
+    records: usize,
+    corrupt: usize,
+}
+
+/// Problems found by [`Cache::verify`] (or fixed by [`Cache::repair`]).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct CacheReport {
+    /// Live entries in the index.
+    pub entries: usize,
+    /// Index lines whose checksum or payload was invalid.
+    pub corrupt_records: usize,
+    /// Keys whose payload file is missing.
+    pub missing: Vec<String>,
+    /// Keys whose payload does not match the recorded size or CRC.
+    pub damaged: Vec<String>,
+    /// Payload files not referenced by the index.
+    #[serde(with = "crate::pathfmt::seq")]
+    pub orphaned: Vec<PathBuf>,
+}
+
+impl CacheReport {
+    /// Returns true if no problems were found.
+    #[must_use]
+    pub const fn is_clean(&self) -> bool {
+        self.corrupt_records == 0
+            && self.missing.is_empty()
+            && self.damaged.is_empty()
+            && self.orphaned.is_empty()
+    }
+}
+
+/// Handle to the cache rooted at a directory.
+#[derive(Debug, Clone)]
+pub struct Cache {
+    root: PathBuf,
+}
+
+impl Cache {
+    /// Open the cache rooted at `root`. Nothing is created until first write.
+    #[must_use]
+    pub fn open(root: &Path) -> Self {
+        Self {
+            root: root.to_path_buf(),
+        }
+    }
+
+    /// Directory the cache is rooted at.
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Store `bytes` under `key`, replacing any previous entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload or index cannot be written.
+    pub fn put(&self, key: &str, bytes: &[u8]) -> Result<CacheEntry> {
+        let _guard = self.lock()?;
+        let entry = CacheEntry {
+            key: key.to_string(),
+            size: bytes.len() as u64,
+            crc32: crc32fast::hash(bytes),
+            stored_at: unix_now(),
+        };
+        let path = self.entry_path(key);
+        write_atomic(&path, bytes)?;
+        self.append(&Record::Put(entry.clone()))?;
+        self.maybe_compact()?;
+        Ok(entry)
+    }
+
+    /// Read the payload for `key`, verifying it against the recorded CRC.
+    ///
+    /// Returns `None` for unknown keys and for payloads that fail
+    /// verification, which are treated as cache misses. Every lookup is
+    /// counted as a hit or miss for its namespace.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index or an existing payload cannot be read.
+    pub fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let found = self.lookup(key)?;
+        if let Err(err) = self.count_lookup(namespace_of(key), found.is_some()) {
+            log::warn!("failed to update cache counters: {err:#}");
+        }
+        Ok(found)
+    }
+
+    fn lookup(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let Some(entry) = self.replay()?.live.remove(key) else {
+            return Ok(None);
+        };
+        let path = self.entry_path(key);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let bytes = fs::read(&path).with_context(|| format!("reading {}", path.display()))?;
+        if bytes.len() as u64 != entry.size || crc32fast::hash(&bytes) != entry.crc32 {
+            log::warn!("cache entry '{key}' failed verification; treating as a miss");
+            return Ok(None);
+        }
+        Ok(Some(bytes))
+    }
+
+    /// Remove the entry for `key`. Returns whether it existed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index cannot be written or the payload removed.
+    pub fn remove(&self, key: &str) -> Result<bool> {
+        let _guard = self.lock()?;
+        if !self.replay()?.live.contains_key(key) {
+            return Ok(false);
+        }
+        self.append(&Record::Remove {
+            key: key.to_string(),
+        })?;
+        remove_if_exists(&self.entry_path(key))?;
+        self.maybe_compact()?;
+        Ok(true)
+    }
+
+    /// All live entries, sorted by key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index cannot be read.
+    pub fn entries(&self) -> Result<Vec<CacheEntry>> {
+        Ok(self.replay()?.live.into_values().collect())
+    }
+
+    /// Entry counts, sizes, and lookup counters, overall and per namespace.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index or counters cannot be read.
+    pub fn stats(&self) -> Result<CacheStats> {
+        let mut stats = CacheStats::default();
+        for (namespace, lookups) in self.load_counters()? {
+            stats.lookups.hits += lookups.hits;
+            stats.lookups.misses += lookups.misses;
+            stats.namespaces.entry(namespace).or_default().lookups = lookups;
+        }
+        for entry in self.replay()?.live.into_values() {
+            stats.entries += 1;
+            stats.payload_bytes += entry.size;
+            let namespace = stats
+                .namespaces
+                .entry(entry.namespace().to_string())
+                .or_default();
+            namespace.entries += 1;
+            namespace.bytes += entry.size;
+        }
+        stats.index_bytes = fs::metadata(self.index_path()).map_or(0, |meta| meta.len());
+        Ok(stats)
+    }
+
+    /// Remove every entry selected by `filter`, announcing each on `ctx`, and
+    /// return them.
+    ///
+    /// On a dry run nothing is removed; the selected entries are only
+    /// returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index cannot be rewritten or a payload removed.
+    pub fn clear(&self, filter: &ClearFilter, ctx: &Ctx) -> Result<Vec<CacheEntry>> {
+        let dry_run = ctx.dry_run();
+        let _guard = if dry_run { None } else { Some(self.lock()?) };
+        let now = unix_now();
+        let (cleared, kept): (Vec<CacheEntry>, Vec<CacheEntry>) = self
+            .replay()?
+            .live
+            .into_values()
+            .partition(|entry| filter.matches(entry, now));
+        if dry_run {
+            for entry in &cleared {
+                ctx.plan(Action::Remove {
+                    path: self.entry_path(&entry.key),
+                });
+            }
+        }
+        if dry_run || cleared.is_empty() {
+            return Ok(cleared);
+        }
+        self.write_index(kept.iter())?;
+        for entry in &cleared {
+            remove_if_exists(&self.entry_path(&entry.key))?;
+            ctx.publish(&Event::CacheEvicted {
+                key: entry.key.clone(),
+                size: entry.size,
+            });
+        }
+        Ok(cleared)
+    }
+
+    /// Check the index and every payload without changing anything.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index or entries directory cannot be read,
+    /// or `ctx` is cancelled or runs out of time.
+    pub fn verify(&self, ctx: &Ctx) -> Result<CacheReport> {
+        let replay = self.replay()?;
+        self.inspect(&replay, ctx)
+    }
+
+    /// Drop corrupt records, missing or damaged entries, and orphaned
+    /// payloads, then compact the index.
+    ///
+    /// On a dry run nothing changes; the returned report lists what would
+    /// be fixed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache cannot be read or rewritten, or `ctx`
+    /// is cancelled or runs out of time before anything is changed.
+    pub fn repair(&self, ctx: &Ctx) -> Result<CacheReport> {
+        let _guard = if ctx.dry_run() {
+            None
+        } else {
+            Some(self.lock()?)
+        };
+        let mut replay = self.replay()?;
+        let report = self.inspect(&replay, ctx)?;
+        if ctx.dry_run() {
+            for key in report.missing.iter().chain(&report.damaged) {
+                ctx.plan(Action::Remove {
+                    path: self.entry_path(key),
+                });
+            }
+            for path in &report.orphaned {
+                ctx.plan(Action::Remove { path: path.clone() });
+            }
+            return Ok(report);
+        }
+        for key in report.missing.iter().chain(&report.damaged) {
+            replay.live.remove(key);
+            remove_if_exists(&self.entry_path(key))?;
+        }
+        for path in &report.orphaned {
+            remove_if_exists(path)?;
+        }
+        self.write_index(replay.live.values())?;
+        Ok(report)
+    }
+
+    /// Rewrite the index so it holds only live records.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index cannot be read or rewritten.
+    pub fn compact(&self) -> Result<()> {
+        let _guard = self.lock()?;
+        let replay = self.replay()?;
+        self.write_index(replay.live.values())
+    }
+
+    fn load_counters(&self) -> Result<BTreeMap<String, LookupCounts>> {
+        let path = self.root.join(COUNTERS_FILENAME);
+        if !path.exists() {
+            return Ok(BTreeMap::new());
+        }
+        let text =
+            fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+        serde_json::from_str(&text).with_context(|| format!("parsing {}", path.display()))
+    }
+
+    fn count_lookup(&self, namespace: &str, hit: bool) -> Result<()> {
+        let _guard = self.lock()?;
+        let mut counters = self.load_counters()?;
+        let counts = counters.entry(namespace.to_string()).or_default();
+        if hit {
+            counts.hits += 1;
+        } else {
+            counts.misses += 1;
+        }
+        let json = serde_json::to_vec_pretty(&counters).context("serializing cache counters")?;
+        write_atomic(&self.root.join(COUNTERS_FILENAME), &json)
+    }
+
+    fn inspect(&self, replay: &Replay, ctx: &Ctx) -> Result<CacheReport> {
+        let mut report = CacheReport {
+            entries: replay.live.len(),
+            corrupt_records: replay.corrupt,
+            ..CacheReport::default()
+        };
+        let task = ctx
+            .progress()
+            .start("checking entries", Some(replay.live.len() as u64));
+        for entry in replay.live.values() {
+            ctx.check()?;
+            task.advance(1);
+            task.set_message(&entry.key);
+            let path = self.entry_path(&entry.key);
+            if !path.exists() {
+                report.missing.push(entry.key.clone());
+                continue;
+            }
+            let bytes = fs::read(&path).with_context(|| format!("reading {}", path.display()))?;
+            if bytes.len() as u64 != entry.size || crc32fast::hash(&bytes) != entry.crc32 {
+                report.damaged.push(entry.key.clone());
+            }
+        }
+
+        let entries_dir = self.root.join(ENTRIES_DIRNAME);
+        if entries_dir.exists() {
+            let known: Vec<PathBuf> = replay.live.keys().map(|key| self.entry_path(key)).collect();
+            for file in fs::read_dir(&entries_dir)
+                .with_context(|| format!("reading {}", entries_dir.display()))?
+            {
+                let path = file
+                    .with_context(|| format!("reading {}", entries_dir.display()))?
+                    .path();
+                if !known.contains(&path) {
+                    report.orphaned.push(path);
+                }
+            }
+            report.orphaned.sort();
+        }
+        Ok(report)
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        let digest = format!("{:x}", Sha256::digest(key.as_bytes()));
+        self.root.join(ENTRIES_DIRNAME).join(digest)
+    }
+
+    fn index_path(&self) -> PathBuf {
+        self.root.join(INDEX_FILENAME)
+    }
+
+    /// Take the writer lock; released when the returned file is dropped.
+    fn lock(&self) -> Result<File> {
+        fs::create_dir_all(&self.root)
+            .with_context(|| format!("creating cache directory {}", self.root.display()))?;
+        let path = self.root.join(INDEX_LOCK_FILENAME);
+        let file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&path)
+            .with_context(|| format!("opening {}", path.display()))?;
+        compat::lock(&file).with_context(|| format!("locking {}", path.display()))?;
+        Ok(file)
+    }
+
+    fn replay(&self) -> Result<Replay> {
+        let path = self.index_path();
+        let mut replay = Replay::default();
+        if !path.exists() {
+            return Ok(replay);
+        }
+        let raw = fs::read(&path).with_context(|| format!("reading {}", path.display()))?;
+        for line in raw.split(|&byte| byte == b'\n') {
+            if line.is_empty() {
+                continue;
+            }
+            replay.records += 1;
+            match decode_line(line) {
+                Some(Record::Put(entry)) => {
+                    replay.live.insert(entry.key.clone(), entry);
+                }
+                Some(Record::Remove { key }) => {
+                    replay.live.remove(&key);
+                }
+                None => replay.corrupt += 1,
+            }
+        }
+        Ok(replay)
+    }
+
+    fn append(&self, record: &Record) -> Result<()> {
+        let path = self.index_path();
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("opening {}", path.display()))?;
+        // One write per record so a crash can tear at most the final line.
+        file.write_all(&encode_line(record)?)
+            .and_then(|()| file.sync_data())
+            .with_context(|| format!("appending to {}", path.display()))
+    }
+
+    fn maybe_compact(&self) -> Result<()> {
+        let replay = self.replay()?;
+        if replay.records > replay.live.len() + COMPACT_SLACK {
+            log::debug!(
+                "compacting cache index ({} records, {} live)",
+                replay.records,
+                replay.live.len()
+            );
+            self.write_index(replay.live.values())?;
+        }
+        Ok(())
+    }
+
+    fn write_index<'a>(&self, entries: impl Iterator<Item = &'a CacheEntry>) -> Result<()> {
+        let mut text = Vec::new();
+        for entry in entries {
+            text.extend(encode_line(&Record::Put(entry.clone()))?);
+        }
+        write_atomic(&self.index_path(), &text)
+    }
+}
+
+fn encode_line(record: &Record) -> Result<Vec<u8>> {
+    let payload = serde_json::to_string(record).context("serializing cache record")?;
+    Ok(format!("{:08x} {payload}\n", crc32fast::hash(payload.as_bytes())).into_bytes())
+}
+
+fn decode_line(line: &[u8]) -> Option<Record> {
+    let text = std::str::from_utf8(line).ok()?;
+    let (crc, payload) = text.split_once(' ')?;
+    let crc = u32::from_str_radix(crc, 16).ok()?;
+    if crc32fast::hash(payload.as_bytes()) != crc {
+        return None;
+    }
+    serde_json::from_str(payload).ok()
+}
+
+/// Write `bytes` to a sibling temp file and rename it into place.
+fn write_atomic(path: &Path, bytes: &[u8]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("creating directory {}", parent.display()))?;
+    }
+    let tmp = path.with_extension(format!("tmp-{}", std::process::id()));
+    fs::write(&tmp, bytes).with_context(|| format!("writing {}", tmp.display()))?;
+    fs::rename(&tmp, path).with_context(|| format!("replacing {}", path.display()))
+}
+
+fn remove_if_exists(path: &Path) -> Result<()> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => {
+            Err(err).with_context(|| format!("removing {}", path.display()))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Match `text` against a glob where `*` matches any run of characters and
+/// `?` matches exactly one.
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    t = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch::Scratch;
+
+    #[test]
+    fn put_get_remove_round_trip() -> Result<()> {
+        let scratch = Scratch::new()?;
+        let cache = Cache::open(&scratch.join("cache"));
+        cache.put("alpha", b"one")?;
+        cache.put("alpha", b"two")?;
+        cache.put("beta", b"three")?;
+        anyhow::ensure!(cache.get("alpha")?.as_deref() == Some(&b"two"[..]));
+        anyhow::ensure!(cache.remove("beta")?, "beta was not removed");
+        anyhow::ensure!(cache.get("beta")?.is_none(), "removed entry still readable");
+        let keys: Vec<String> = cache.entries()?.into_iter().map(|e| e.key).collect();
+        anyhow::ensure!(keys == ["alpha"], "unexpected keys: {keys:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn torn_record_is_skipped_and_repaired() -> Result<()> {
+        let scratch = Scratch::new()?;
+        let cache = Cache::open(&scratch.join("cache"));
+        cache.put("alpha", b"one")?;
+        let mut index = OpenOptions::new()
+            .append(true)
+            .open(cache.root().join(INDEX_FILENAME))?;
+        index.write_all(b"deadbeef {\"op\":\"put\",\"key\":\"be")?;
+        drop(index);
+        fs::write(cache.entry_path("alpha"), b"tampered")?;
+        fs::write(cache.root().join(ENTRIES_DIRNAME).join("stray"), b"x")?;
+
+        let report = cache.verify(&Ctx::default())?;
+        let expected = CacheReport {
+            entries: 1,
+            corrupt_records: 1,
+            missing: Vec::new(),
+            damaged: vec!["alpha".to_string()],
+            orphaned: vec![cache.root().join(ENTRIES_DIRNAME).join("stray")],
+        };
+        anyhow::ensure!(report == expected, "unexpected report: {report:?}");
+        anyhow::ensure!(cache.get("alpha")?.is_none(), "damaged entry was served");
+
+        cache.repair(&Ctx::default())?;
+        let after = cache.verify(&Ctx::default())?;
+        anyhow::ensure!(
+            after == CacheReport::default(),
+            "repair left problems: {after:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn stats_group_by_namespace_and_count_lookups() -> Result<()> {
+        let scratch = Scratch::new()?;
+        let cache = Cache::open(&scratch.join("cache"));
+        cache.put("http/a", b"12345")?;
+        cache.put("http/b", b"123")?;
+        cache.put("plain", b"1")?;
+        cache.get("http/a")?;
+        cache.get("http/missing")?;
+        let stats = cache.stats()?;
+
+        let http = NamespaceStats {
+            entries: 2,
+            bytes: 8,
+            lookups: LookupCounts { hits: 1, misses: 1 },
+        };
+        let default = NamespaceStats {
+            entries: 1,
+            bytes: 1,
+            lookups: LookupCounts::default(),
+        };
+        anyhow::ensure!(
+            stats.namespaces
+                == BTreeMap::from([("default".to_string(), default), ("http".to_string(), http)]),
+            "unexpected namespaces: {:?}",
+            stats.namespaces
+        );
+        anyhow::ensure!(stats.entries == 3 && stats.payload_bytes == 9);
+        anyhow::ensure!(stats.lookups == LookupCounts { hits: 1, misses: 1 });
+        Ok(())
+    }
+
+    #[test]
+    fn clear_filters_by_namespace_pattern_and_age() -> Result<()> {
+        let scratch = Scratch::new()?;
+        let cache = Cache::open(&scratch.join("cache"));
+        for key in ["http/a.json", "http/b.txt", "git/a.json"] {
+            cache.put(key, key.as_bytes())?;
+        }
+        let by_pattern = ClearFilter {
+            pattern: Some("*.json".to_string()),
+            namespace: Some("http".to_string()),
+            ..ClearFilter::default()
+        };
+        let would = cache.clear(&by_pattern, &Ctx::default().with_dry_run(true))?;
+        anyhow::ensure!(cache.entries()?.len() == 3, "dry-run cleared entries");
+        let cleared = cache.clear(&by_pattern, &Ctx::default())?;
+        anyhow::ensure!(would == cleared && cleared.len() == 1 && cleared[0].key == "http/a.json");
+
+        let recent = ClearFilter {
+            older_than: Some(compat::hours(1)),
+            ..ClearFilter::default()
+        };
+        anyhow::ensure!(
+            cache.clear(&recent, &Ctx::default())?.is_empty(),
+            "fresh entries cleared"
+        );
+        anyhow::ensure!(cache.clear(&ClearFilter::default(), &Ctx::default())?.len() == 2);
+        let report = cache.verify(&Ctx::default())?;
+        anyhow::ensure!(
+            report == CacheReport::default(),
+            "clear left problems: {report:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn glob_match_supports_star_and_question_mark() {
+        assert!(glob_match("http/*.json", "http/a/b.json"));
+        assert!(glob_match("?at", "cat"));
+        assert!(!glob_match("?at", "at"));
+        assert!(!glob_match("*.json", "a.txt"));
+        assert!(glob_match("*", ""));
+    }
+
+    #[test]
+    fn index_compacts_after_many_overwrites() -> Result<()> {
+        let scratch = Scratch::new()?;
+        let cache = Cache::open(&scratch.join("cache"));
+        for round in 0..=COMPACT_SLACK + 1 {
+            cache.put("hot", round.to_string().as_bytes())?;
+        }
+        let records = fs::read_to_string(cache.root().join(INDEX_FILENAME))?
+            .lines()
+            .count();
+        anyhow::ensure!(
+            records <= COMPACT_SLACK,
+            "index not compacted: {records} records"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,329 @@
+//! Removal of what the application keeps on disk, by category.
+//!
+//! [`survey`] lists what each [`Category`] holds and how large it is, so a
+//! caller can show the estimate and ask before anything goes; [`clean`] then
+//! removes it. Paths this user may not remove (typically system-mode
+//! directories owned by root) are set aside as [`Skipped`] rather than
+//! failing the whole run, both when surveyed and when removal is refused.
+//! The config file and the instance lock are never touched.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::ctx::Ctx;
+use crate::doctor::{disk_usage, is_writable};
+use crate::error::{CoreError, IoResultExt as _, Result};
+use crate::history::HISTORY_FILENAME;
+use crate::journal;
+use crate::lock::LOCK_FILENAME;
+use crate::paths::AppPaths;
+use crate::plan::Action;
+use crate::task;
+use crate::temp::TEMP_DIRNAME;
+
+/// Extension of files being written when an operation was interrupted.
+const PARTIAL_EXTENSION: &str = "partial";
+
+/// A kind of data [`clean`] can remove.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Category {
+    /// Cache entries, index, and counters under the cache directory.
+    Cache,
+    /// The log file named by `logging.file`.
+    Logs,
+    /// The run history, the last outcome of each task, and the working
+    /// directories of isolated tasks.
+    Runs,
+    /// Temporary directories and `*.partial` files left behind by
+    /// interrupted operations.
+    Artifacts,
+    /// Everything else in the state directory, such as the journal.
+    State,
+}
+
+impl Category {
+    /// Every category, in the order they are reported.
+    pub const ALL: [Self; 5] = [
+        Self::Cache,
+        Self::Logs,
+        Self::Runs,
+        Self::Artifacts,
+        Self::State,
+    ];
+
+    /// Name used in reports.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Cache => "cache",
+            Self::Logs => "logs",
+            Self::Runs => "runs",
+            Self::Artifacts => "artifacts",
+            Self::State => "state",
+        }
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+/// A path left in place, and why.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Skipped {
+    /// File or directory that was not removed.
+    #[serde(with = "crate::pathfmt")]
+    pub path: PathBuf,
+    /// Why it was not removed.
+    pub reason: String,
+}
+
+/// What one category holds; after [`clean`], what was removed from it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Target {
+    /// Category the paths belong to.
+    pub category: Category,
+    /// Files and directories to remove, or that were removed.
+    #[serde(with = "crate::pathfmt::seq")]
+    pub paths: Vec<PathBuf>,
+    /// Total size of the regular files under `paths`.
+    pub bytes: u64,
+    /// Paths that are not (or could not be) removed.
+    pub skipped: Vec<Skipped>,
+}
+
+impl Target {
+    /// Whether there is nothing to remove.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+}
+
+/// List what each of `categories` holds. `log_file` is the configured
+/// `logging.file`, if any.
+///
+/// # Errors
+///
+/// Returns [`CoreError::PathIo`] if a directory exists but cannot be
+/// listed, and [`CoreError::PendingJournal`] if the journal of an
+/// interrupted operation cannot be read.
+pub fn survey(
+    paths: &AppPaths,
+    log_file: Option<&Path>,
+    categories: &[Category],
+) -> Result<Vec<Target>> {
+    let partial = |path: &Path| path.extension().is_some_and(|ext| ext == PARTIAL_EXTENSION);
+    let runs = [
+        paths.state_dir.join(HISTORY_FILENAME),
+        paths.state_dir.join(task::HISTORY_FILENAME),
+        paths.state_dir.join(task::RUNS_DIRNAME),
+    ];
+    let journal_dir = paths.journal_dir();
+
+    let mut targets = Vec::new();
+    for &category in Category::ALL
+        .iter()
+        .filter(|category| categories.contains(category))
+    {
+        let mut candidates = Vec::new();
+        let mut skipped = Vec::new();
+        match category {
+            Category::Cache => {
+                candidates = children(&paths.cache_dir)?;
+                candidates.retain(|path| !path.ends_with(TEMP_DIRNAME));
+            }
+            Category::Logs => candidates.extend(log_file.map(Path::to_path_buf)),
+            Category::Runs => candidates.extend(runs.iter().cloned()),
+            Category::Artifacts => {
+                candidates.push(paths.temp_root());
+                for dir in [&paths.state_dir, &paths.data_dir] {
+                    candidates.extend(children(dir)?.into_iter().filter(|path| partial(path)));
+                }
+            }
+            Category::State => {
+                for path in children(&paths.state_dir)? {
+                    let claimed = path.ends_with(LOCK_FILENAME)
+                        || runs.contains(&path)
+                        || log_file.is_some_and(|file| file == path)
+                        || partial(&path);
+                    if claimed {
+                        continue;
+                    }
+                    if path == journal_dir
+                        && let Some(intent) = journal::pending(&journal_dir).map_err(|err| {
+                            CoreError::PendingJournal(format!("reading the journal: {err:#}"))
+                        })?
+                    {
+                        skipped.push(Skipped {
+                            path,
+                            reason: format!(
+                                "`{}` was interrupted and not recovered",
+                                intent.operation
+                            ),
+                        });
+                        continue;
+                    }
+                    candidates.push(path);
+                }
+            }
+        }
+
+        let mut target = Target {
+            category,
+            paths: Vec::new(),
+            bytes: 0,
+            skipped,
+        };
+        for path in candidates {
+            if fs::symlink_metadata(&path).is_err() {
+                continue;
+            }
+            match removable(&path) {
+                Ok(()) => {
+                    target.bytes += size(&path);
+                    target.paths.push(path);
+                }
+                Err(reason) => target.skipped.push(Skipped { path, reason }),
+            }
+        }
+        targets.push(target);
+    }
+    Ok(targets)
+}
+
+/// Remove every path in `targets` (on a dry run, plan it in `ctx`). A path
+/// whose removal is refused for lack of permission moves to `skipped`, and
+/// its size no longer counts.
+///
+/// # Errors
+///
+/// Returns [`CoreError::PathIo`] if a path cannot be removed for another
+/// reason, and the error of [`Ctx::check`] if `ctx` is cancelled or runs
+/// out of time; what was removed by then stays removed.
+pub fn clean(targets: &mut [Target], ctx: &Ctx) -> Result<()> {
+    for target in targets {
+        let mut removed = Vec::with_capacity(target.paths.len());
+        for path in std::mem::take(&mut target.paths) {
+            if ctx.dry_run() {
+                ctx.plan(Action::Remove { path: path.clone() });
+                removed.push(path);
+                continue;
+            }
+            ctx.check()?;
+            let bytes = size(&path);
+            let result = if path.is_dir() {
+                fs::remove_dir_all(&path)
+            } else {
+                fs::remove_file(&path)
+            };
+            match result {
+                Ok(()) => removed.push(path),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                    target.bytes = target.bytes.saturating_sub(bytes);
+                }
+                Err(err) if err.kind() == io::ErrorKind::PermissionDenied => {
+                    // A directory may be partly removed; count what is left.
+                    target.bytes = target.bytes.saturating_sub(size(&path));
+                    target.skipped.push(Skipped {
+                        path,
+                        reason: format!("permission denied: {err}"),
+                    });
+                }
+                Err(err) => return Err(err).at_path("removing", &path),
+            }
+        }
+        target.paths = removed;
+    }
+    Ok(())
+}
+
+/// The entries of `dir`, sorted; none if it does not exist.
+fn children(dir: &Path) -> Result<Vec<PathBuf>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err).at_path("listing", dir),
+    };
+    let mut children = entries
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<_>>>()
+        .at_path("listing", dir)?;
+    children.sort();
+    Ok(children)
+}
+
+/// Whether this user may remove `path`, which takes write access to the
+/// directory holding it.
+fn removable(path: &Path) -> std::result::Result<(), String> {
+    match path.parent() {
+        Some(parent) if !is_writable(parent) => Err(format!(
+            "permission denied: {} is not writable",
+            parent.display()
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Size of the regular files at or under `path`.
+fn size(path: &Path) -> u64 {
+    match fs::symlink_metadata(path) {
+        Ok(meta) if meta.is_dir() => disk_usage(path),
+        Ok(meta) if meta.is_file() => meta.len(),
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch::Scratch;
+
+    #[test]
+    fn surveys_and_cleans_selected_categories() -> anyhow::Result<()> {
+        let scratch = Scratch::new()?;
+        let paths = scratch.paths();
+        fs::create_dir_all(paths.cache_dir.join("entries"))?;
+        fs::create_dir_all(paths.temp_root().join("scope"))?;
+        fs::create_dir_all(&paths.data_dir)?;
+        fs::create_dir_all(paths.journal_dir())?;
+        fs::write(paths.cache_dir.join("entries/a"), [0_u8; 100])?;
+        fs::write(paths.cache_dir.join("index.log"), [0_u8; 10])?;
+        fs::write(paths.temp_root().join("scope/x"), [0_u8; 7])?;
+        fs::write(paths.state_dir.join(HISTORY_FILENAME), [0_u8; 20])?;
+        fs::write(paths.state_dir.join("runs.jsonl.partial"), [0_u8; 3])?;
+        fs::write(paths.state_dir.join(LOCK_FILENAME), "")?;
+        fs::write(paths.state_dir.join("other.json"), [0_u8; 5])?;
+
+        let all = survey(&paths, None, &Category::ALL)?;
+        let by_category = |targets: &[Target], category| {
+            targets
+                .iter()
+                .find(|target| target.category == category)
+                .map(|target| (target.paths.len(), target.bytes))
+        };
+        anyhow::ensure!(by_category(&all, Category::Cache) == Some((2, 110)));
+        anyhow::ensure!(by_category(&all, Category::Logs) == Some((0, 0)));
+        anyhow::ensure!(by_category(&all, Category::Runs) == Some((1, 20)));
+        anyhow::ensure!(by_category(&all, Category::Artifacts) == Some((2, 10)));
+        // The lock stays; the empty journal goes with the rest of the state.
+        anyhow::ensure!(by_category(&all, Category::State) == Some((2, 5)));
+
+        let mut selected = survey(&paths, None, &[Category::Runs, Category::Cache])?;
+        anyhow::ensure!(selected.len() == 2 && selected[0].category == Category::Cache);
+        clean(&mut selected, &Ctx::default().with_dry_run(true))?;
+        anyhow::ensure!(paths.state_dir.join(HISTORY_FILENAME).exists());
+        clean(&mut selected, &Ctx::default())?;
+        anyhow::ensure!(!paths.state_dir.join(HISTORY_FILENAME).exists());
+        anyhow::ensure!(children(&paths.cache_dir)? == [paths.temp_root()]);
+        anyhow::ensure!(paths.state_dir.join(LOCK_FILENAME).exists());
+        Ok(())
+    }
+}
@@ -0,0 +1,223 @@
+//! Stable error codes and their long-form explanations.
+//!
+//! Every [`CoreError`] variant has an [`ErrorCode`] such as `E0002`, shown
+//! next to the error message and in JSON error reports. `explain <CODE>`
+//! prints the matching text from `src/codes/`, in the style of
+//! `rustc --explain`. Codes are never renumbered or reused: a retired
+//! failure mode keeps its number and explanation.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Serialize, Serializer};
+
+use crate::error::CoreError;
+
+/// Stable identifier for a known failure mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ErrorCode {
+    /// E0001: the configuration could not be loaded.
+    Config,
+    /// E0002: the config file is not valid TOML.
+    ConfigParse,
+    /// E0003: a configuration value is out of range or of the wrong type.
+    Validation,
+    /// E0004: an environment variable is missing or unusable.
+    EnvVar,
+    /// E0005: a path could not be resolved or is unusable.
+    Path,
+    /// E0006: an I/O operation failed.
+    Io,
+    /// E0007: an I/O operation on a specific path failed.
+    PathIo,
+    /// E0008: data could not be serialized or deserialized.
+    Serialization,
+    /// E0009: the current user lacks permission.
+    Permission,
+    /// E0010: another process holds the instance lock.
+    Lock,
+    /// E0011: the request is not supported on this platform or input.
+    Unsupported,
+    /// E0012: an operation exceeded its time limit.
+    Timeout,
+    /// E0013: an interrupted multi-file operation has not been recovered.
+    PendingJournal,
+    /// E0014: an operation was cancelled before it finished.
+    Cancelled,
+    /// E0015: input data is not valid JSON, NDJSON, YAML, or TOML.
+    InputParse,
+    /// E0016: input data is larger than allowed.
+    InputTooLarge,
+    /// E0017: config sync through git could not run.
+    Sync,
+}
+
+impl ErrorCode {
+    /// Every error code, in numeric order.
+    pub const ALL: [Self; 17] = [
+        Self::Config,
+        Self::ConfigParse,
+        Self::Validation,
+        Self::EnvVar,
+        Self::Path,
+        Self::Io,
+        Self::PathIo,
+        Self::Serialization,
+        Self::Permission,
+        Self::Lock,
+        Self::Unsupported,
+        Self::Timeout,
+        Self::PendingJournal,
+        Self::Cancelled,
+        Self::InputParse,
+        Self::InputTooLarge,
+        Self::Sync,
+    ];
+
+    /// The code as written, e.g. `E0002`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Config => "E0001",
+            Self::ConfigParse => "E0002",
+            Self::Validation => "E0003",
+            Self::EnvVar => "E0004",
+            Self::Path => "E0005",
+            Self::Io => "E0006",
+            Self::PathIo => "E0007",
+            Self::Serialization => "E0008",
+            Self::Permission => "E0009",
+            Self::Lock => "E0010",
+            Self::Unsupported => "E0011",
+            Self::Timeout => "E0012",
+            Self::PendingJournal => "E0013",
+            Self::Cancelled => "E0014",
+            Self::InputParse => "E0015",
+            Self::InputTooLarge => "E0016",
+            Self::Sync => "E0017",
+        }
+    }
+
+    /// One-line summary for listings.
+    #[must_use]
+    pub const fn summary(self) -> &'static str {
+        match self {
+            Self::Config => "the configuration could not be loaded",
+            Self::ConfigParse => "the config file is not valid TOML",
+            Self::Validation => "a configuration value is out of range or of the wrong type",
+            Self::EnvVar => "an environment variable is missing or unusable",
+            Self::Path => "a path could not be resolved or is unusable",
+            Self::Io => "an I/O operation failed",
+            Self::PathIo => "an I/O operation on a specific path failed",
+            Self::Serialization => "data could not be serialized or deserialized",
+            Self::Permission => "the current user lacks permission",
+            Self::Lock => "another process holds the instance lock",
+            Self::Unsupported => "the request is not supported here",
+            Self::Timeout => "an operation exceeded its time limit",
+            Self::PendingJournal => "an interrupted operation has not been recovered",
+            Self::Cancelled => "an operation was cancelled before it finished",
+            Self::InputParse => "input data is not valid in its format",
+            Self::InputTooLarge => "input data is larger than allowed",
+            Self::Sync => "config sync through git could not run",
+        }
+    }
+
+    /// Long explanation: what the error means, common causes, and how to fix
+    /// it.
+    #[must_use]
+    pub const fn explanation(self) -> &'static str {
+        match self {
+            Self::Config => include_str!("codes/E0001.md"),
+            Self::ConfigParse => include_str!("codes/E0002.md"),
+            Self::Validation => include_str!("codes/E0003.md"),
+            Self::EnvVar => include_str!("codes/E0004.md"),
+            Self::Path => include_str!("codes/E0005.md"),
+            Self::Io => include_str!("codes/E0006.md"),
+            Self::PathIo => include_str!("codes/E0007.md"),
+            Self::Serialization => include_str!("codes/E0008.md"),
+            Self::Permission => include_str!("codes/E0009.md"),
+            Self::Lock => include_str!("codes/E0010.md"),
+            Self::Unsupported => include_str!("codes/E0011.md"),
+            Self::Timeout => include_str!("codes/E0012.md"),
+            Self::PendingJournal => include_str!("codes/E0013.md"),
+            Self::Cancelled => include_str!("codes/E0014.md"),
+            Self::InputParse => include_str!("codes/E0015.md"),
+            Self::InputTooLarge => include_str!("codes/E0016.md"),
+            Self::Sync => include_str!("codes/E0017.md"),
+        }
+    }
+
+    /// Code of the first [`CoreError`] in `err`'s cause chain, outermost
+    /// first.
+    #[must_use]
+    pub fn from_error(err: &anyhow::Error) -> Option<Self> {
+        err.chain()
+            .find_map(|cause| cause.downcast_ref::<CoreError>())
+            .map(CoreError::code)
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for ErrorCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl FromStr for ErrorCode {
+    type Err = String;
+
+    /// Accepts `E0002`, `e0002`, `0002`, or `2`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s
+            .strip_prefix(['E', 'e'])
+            .unwrap_or(s)
+            .trim_start_matches('0');
+        Self::ALL
+            .into_iter()
+            .find(|code| code.as_str()[1..].trim_start_matches('0') == digits)
+            .ok_or_else(|| {
+                format!(
+                    "unknown error code `{s}` (known: E0001-{})",
+                    Self::ALL[Self::ALL.len() - 1]
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_are_sequential_and_explained() -> anyhow::Result<()> {
+        for (index, code) in ErrorCode::ALL.into_iter().enumerate() {
+            anyhow::ensure!(code.as_str() == format!("E{:04}", index + 1), "{code}");
+            anyhow::ensure!(
+                code.explanation().starts_with(&format!("# {code}:")),
+                "{code} explanation must start with its heading"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parses_loose_spellings_and_finds_code_in_chain() -> anyhow::Result<()> {
+        for spelling in ["E0010", "e0010", "0010", "10"] {
+            anyhow::ensure!(
+                spelling.parse::<ErrorCode>() == Ok(ErrorCode::Lock),
+                "{spelling}"
+            );
+        }
+        anyhow::ensure!("E9999".parse::<ErrorCode>().is_err());
+        let err = anyhow::Error::new(CoreError::Lock("held".to_string())).context("starting run");
+        anyhow::ensure!(ErrorCode::from_error(&err) == Some(ErrorCode::Lock));
+        anyhow::ensure!(ErrorCode::from_error(&anyhow::anyhow!("plain")).is_none());
+        Ok(())
+    }
+}
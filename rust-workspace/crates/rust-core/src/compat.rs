@@ -0,0 +1,159 @@
+//! Fallbacks for std APIs newer than the minimum supported Rust version.
+//!
+//! The workspace's `rust-version` (1.88, the first release with let chains
+//! in edition 2024) is the oldest toolchain it builds with, and clippy's
+//! `incompatible_msrv` lint rejects std APIs stabilized after it. Code uses
+//! such APIs through this module instead: `build.rs` enables a `has_*` cfg
+//! when the compiler provides one, and a fallback stands in otherwise.
+//! APIs the MSRV already covers, such as `IsTerminal` and `OnceLock` (both
+//! 1.70), are used directly.
+//!
+//! To raise the MSRV, bump `rust-version`, drop the probes and fallbacks it
+//! makes unnecessary, and call std directly again.
+
+use std::fs::File;
+use std::io;
+use std::time::Duration;
+
+#[cfg(all(not(has_file_lock), not(unix)))]
+compile_error!("file locking needs Rust 1.89 or newer on this platform");
+
+/// Why [`try_lock`] did not take the lock, like `std::fs::TryLockError`.
+#[derive(Debug)]
+pub enum TryLockError {
+    /// Locking failed for another reason.
+    Error(io::Error),
+    /// Another handle holds the lock.
+    WouldBlock,
+}
+
+/// Take an exclusive advisory lock on `file`, waiting for other holders.
+///
+/// # Errors
+///
+/// Returns an error if the lock cannot be taken.
+pub fn lock(file: &File) -> io::Result<()> {
+    imp::lock(file)
+}
+
+/// Take an exclusive advisory lock on `file` if nobody else holds it.
+///
+/// # Errors
+///
+/// Returns [`TryLockError::WouldBlock`] if another handle holds the lock.
+pub fn try_lock(file: &File) -> Result<(), TryLockError> {
+    imp::try_lock(file)
+}
+
+/// Release a lock taken with [`lock`] or [`try_lock`]. Closing the file
+/// releases it too.
+///
+/// # Errors
+///
+/// Returns an error if the lock cannot be released.
+pub fn unlock(file: &File) -> io::Result<()> {
+    imp::unlock(file)
+}
+
+/// `File::lock` and friends (Rust 1.89).
+#[cfg(has_file_lock)]
+#[expect(
+    clippy::incompatible_msrv,
+    reason = "only built when build.rs found the API"
+)]
+mod imp {
+    use std::fs::{self, File};
+    use std::io;
+
+    use super::TryLockError;
+
+    pub fn lock(file: &File) -> io::Result<()> {
+        file.lock()
+    }
+
+    pub fn try_lock(file: &File) -> Result<(), TryLockError> {
+        file.try_lock().map_err(|err| match err {
+            fs::TryLockError::WouldBlock => TryLockError::WouldBlock,
+            fs::TryLockError::Error(err) => TryLockError::Error(err),
+        })
+    }
+
+    pub fn unlock(file: &File) -> io::Result<()> {
+        file.unlock()
+    }
+}
+
+/// `flock(2)`, for older compilers.
+#[cfg(all(not(has_file_lock), unix))]
+mod imp {
+    use std::fs::File;
+    use std::io;
+
+    use rustix::fs::{FlockOperation, flock};
+    use rustix::io::Errno;
+
+    use super::TryLockError;
+
+    pub fn lock(file: &File) -> io::Result<()> {
+        flock(file, FlockOperation::LockExclusive).map_err(io::Error::from)
+    }
+
+    pub fn try_lock(file: &File) -> Result<(), TryLockError> {
+        match flock(file, FlockOperation::NonBlockingLockExclusive) {
+            Ok(()) => Ok(()),
+            Err(Errno::WOULDBLOCK) => Err(TryLockError::WouldBlock),
+            Err(err) => Err(TryLockError::Error(err.into())),
+        }
+    }
+
+    pub fn unlock(file: &File) -> io::Result<()> {
+        flock(file, FlockOperation::Unlock).map_err(io::Error::from)
+    }
+}
+
+/// `Duration::from_mins`, usable in `const` items before Rust 1.91.
+#[must_use]
+pub const fn minutes(minutes: u64) -> Duration {
+    Duration::from_secs(minutes * 60)
+}
+
+/// `Duration::from_hours`, usable in `const` items before Rust 1.91.
+#[must_use]
+pub const fn hours(hours: u64) -> Duration {
+    Duration::from_secs(hours * 60 * 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::OpenOptions;
+
+    use super::*;
+    use crate::scratch::Scratch;
+
+    #[test]
+    fn a_held_lock_blocks_other_handles() -> anyhow::Result<()> {
+        let dir = Scratch::new()?;
+        let path = dir.join("lock");
+        let open = || {
+            OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(false)
+                .open(&path)
+        };
+        let (first, second) = (open()?, open()?);
+
+        lock(&first)?;
+        anyhow::ensure!(matches!(try_lock(&second), Err(TryLockError::WouldBlock)));
+        unlock(&first)?;
+        anyhow::ensure!(try_lock(&second).is_ok());
+        unlock(&second)?;
+        Ok(())
+    }
+
+    #[test]
+    fn durations_match_std() {
+        assert_eq!(minutes(5), Duration::from_secs(300));
+        assert_eq!(hours(24), Duration::from_secs(86_400));
+    }
+}
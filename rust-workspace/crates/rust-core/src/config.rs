@@ -1,215 +1,834 @@
-//! Configuration types and loading for the application.
+//! Configuration loading for the application.
+//!
+//! The config data types live in `rust-core-types`, which has no I/O and
+//! builds without std, and are re-exported here. This module reads them
+//! from the config file and `<PREFIX>__*` environment variables, applies
+//! the platform's defaults, and validates them, through the
+//! [`AppConfigExt`] and [`RuntimeConfigExt`] traits. An [`Override`]
+//! (`--set key=value`) is the last layer, above the environment.
 
+use std::collections::BTreeMap;
+use std::ffi::OsStr;
+use std::fs;
+use std::io;
 use std::path::Path;
+use std::time::Duration;
 
-use anyhow::Result;
-use config::{Config, Environment, File, FileFormat};
-use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use config::{Config, ConfigError, Environment, File, FileFormat, Source};
 
+pub use rust_core_types::{
+    AppConfig, BackupConfig, DaemonConfig, DaemonHttpConfig, HealthConfig, HealthProbe,
+    HistoryConfig, IdleConfig, LogFormat, LogLevel, LoggingConfig, McpAuditConfig, McpAuthConfig,
+    McpClientConfig, McpConfig, McpRateLimitConfig, OutputConfig, PathsConfig, PowerSave,
+    RelabelMode, RuntimeConfig, SecurityConfig, SelinuxConfig, TaskConfig, UpdatesConfig,
+};
+
+use crate::ctx::Ctx;
+use crate::error::{CoreError, IoResultExt as _, Result, Span};
 use crate::paths::{expand_str_path, write_default_config};
+use crate::plan::Action;
+use crate::platform;
 use crate::{AppPaths, default_parallelism, env_prefix};
 
-/// Main application configuration.
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
-#[serde(default)]
-#[schemars(
-    title = "Application Configuration",
-    description = "Main configuration for the application"
-)]
-pub struct AppConfig {
-    /// JSON Schema reference for editor support.
-    #[serde(rename = "$schema", default, skip_serializing_if = "Option::is_none")]
-    #[schemars(skip)]
-    pub schema: Option<String>,
+/// The default config on this machine: [`AppConfig::default`] with the
+/// platform's defaults applied, as loading an empty config file gives.
+#[must_use]
+pub fn defaults() -> AppConfig {
+    let mut config = AppConfig::default();
+    config.runtime.timeout = Some(platform::current(|defaults| defaults.timeout_secs()));
+    config
+}
 
-    /// Active configuration profile.
-    #[schemars(default = "default_profile")]
-    pub profile: String,
+/// Loading and validation for [`AppConfig`].
+pub trait AppConfigExt: Sized {
+    /// Load configuration from file and environment, creating defaults if
+    /// needed (on a dry run, planning it in `ctx` instead).
+    ///
+    /// Logs a warning if the file's format version differs from this
+    /// build's, unless `runtime.check_config_version` is off.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config file cannot be read, parsed, or written.
+    fn load(paths: &AppPaths, ctx: &Ctx) -> Result<Self> {
+        Self::load_with_overrides(paths, ctx, &[])
+    }
 
-    /// Logging configuration.
-    pub logging: LoggingConfig,
+    /// [`Self::load`], with `overrides` applied over the file and the
+    /// environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Self::load`], and [`CoreError::Validation`]
+    /// for an override of the wrong type or out of range.
+    fn load_with_overrides(paths: &AppPaths, ctx: &Ctx, overrides: &[Override]) -> Result<Self>;
 
-    /// Runtime behavior configuration.
-    pub runtime: RuntimeConfig,
+    /// Load configuration from a specific path.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::Unsupported`] for a non-TOML file extension,
+    /// [`CoreError::ConfigParse`] (with the error's line and column) for
+    /// malformed TOML, [`CoreError::Validation`] for values of the wrong type
+    /// or out of range, and [`CoreError::PathIo`] if the file cannot be read.
+    fn load_from_path(config_file: &Path) -> Result<Self>;
 
-    /// Custom paths for data and state directories.
-    pub paths: PathsConfig,
-}
+    /// Load configuration from defaults and `<PREFIX>__*` environment
+    /// variables only, without reading or creating a config file. Used in
+    /// containers, where the environment is the whole configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::Validation`] for values of the wrong type or out
+    /// of range, and [`CoreError::EnvVar`] if a path references an unset
+    /// variable.
+    fn from_env() -> Result<Self> {
+        Self::from_env_with_overrides(&[])
+    }
 
-fn default_profile() -> String {
-    "default".to_string()
-}
+    /// [`Self::from_env`], with `overrides` applied over the environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Self::from_env`].
+    fn from_env_with_overrides(overrides: &[Override]) -> Result<Self>;
 
-impl AppConfig {
-    /// Override the profile if a value is provided.
-    #[must_use]
-    pub fn with_profile_override(mut self, profile: Option<String>) -> Self {
-        if let Some(profile) = profile {
-            self.profile = profile;
-        }
-        self
-    }
+    /// Load from config file `text` and `vars` standing in for the process
+    /// environment, as [`Self::load_from_path`] would from a file holding
+    /// `text` with `vars` set. The fuzz targets use this so they touch
+    /// neither the filesystem nor the real environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Self::load_from_path`].
+    #[cfg(feature = "fuzzing")]
+    fn from_str_and_env(text: &str, vars: config::Map<String, String>) -> Result<Self>;
 
-    /// Load configuration from file and environment, creating defaults if needed.
+    /// Check the value ranges documented in the schema, which TOML parsing
+    /// alone does not enforce.
     ///
     /// # Errors
     ///
-    /// Returns an error if the config file cannot be read, parsed, or written.
-    pub fn load(paths: &AppPaths, dry_run: bool) -> Result<Self> {
+    /// Returns [`CoreError::Validation`] naming the first out-of-range key.
+    fn validate(&self) -> Result<()>;
+}
+
+impl AppConfigExt for AppConfig {
+    fn load_with_overrides(paths: &AppPaths, ctx: &Ctx, overrides: &[Override]) -> Result<Self> {
         if !paths.config_file.exists() {
-            if dry_run {
-                log::info!(
-                    "dry-run: would create default config at {}",
-                    paths.config_file.display()
-                );
+            if ctx.dry_run() {
+                ctx.plan(Action::WriteFile {
+                    path: paths.config_file.clone(),
+                });
             } else {
                 write_default_config(&paths.config_file)?;
             }
         }
 
-        Self::load_from_path(&paths.config_file)
+        let config = from_file(&paths.config_file, overrides)?;
+        if config.runtime.check_config_version
+            && let Some(mismatch) = crate::migrate::check(&paths.config_file)?
+        {
+            log::warn!("{mismatch}");
+        }
+        Ok(config)
     }
 
-    /// Load configuration from a specific path.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the config file cannot be read or parsed.
-    pub fn load_from_path(config_file: &Path) -> Result<Self> {
-        let env_prefix = env_prefix();
-        let built = Config::builder()
-            .set_default("profile", "default")?
-            .set_default("logging.level", "info")?
-            .set_default("runtime.parallelism", default_parallelism() as i64)?
-            .set_default("runtime.timeout", 60_i64)?
-            .set_default("runtime.fail_fast", true)?
-            .add_source(
-                File::from(config_file)
-                    .format(FileFormat::Toml)
-                    .required(false),
-            )
-            .add_source(Environment::with_prefix(env_prefix.as_str()).separator("__"))
-            .build()?;
+    fn load_from_path(config_file: &Path) -> Result<Self> {
+        from_file(config_file, &[])
+    }
+
+    fn from_env_with_overrides(overrides: &[Override]) -> Result<Self> {
+        from_sources(None, overrides)
+    }
 
-        let mut config: Self = built.try_deserialize()?;
+    #[cfg(feature = "fuzzing")]
+    fn from_str_and_env(text: &str, vars: config::Map<String, String>) -> Result<Self> {
+        check_toml(text, Path::new("<input>"))?;
+        from_layers(layered(
+            Some(File::from_str(text, FileFormat::Toml)),
+            env_source().source(Some(vars)),
+            &[],
+        ))
+    }
 
-        if let Some(ref file) = config.logging.file {
-            let expanded = expand_str_path(file)?;
-            config.logging.file = Some(expanded.display().to_string());
+    fn validate(&self) -> Result<()> {
+        let invalid = |message: &str| Err(CoreError::Validation(message.to_string()));
+        if self.runtime.parallelism == Some(0) {
+            return invalid("runtime.parallelism must be at least 1");
+        }
+        if self.runtime.timeout == Some(0) {
+            return invalid("runtime.timeout must be at least 1 second");
+        }
+        if !(1..=22).contains(&self.backup.compression_level) {
+            return invalid("backup.compression_level must be between 1 and 22");
+        }
+        if self.health.probes.is_empty() {
+            return invalid("health.probes must name at least one probe");
+        }
+        if self.health.timeout_ms == 0 {
+            return invalid("health.timeout_ms must be at least 1");
+        }
+        if self.idle.max_load.is_nan() || self.idle.max_load < 0.0 {
+            return invalid("idle.max_load must be at least 0");
+        }
+        if self.idle.poll_interval == 0 {
+            return invalid("idle.poll_interval must be at least 1 second");
+        }
+        if self.updates.interval_hours == 0 {
+            return invalid("updates.interval_hours must be at least 1");
+        }
+        if self.history.max_entries == 0 {
+            return invalid("history.max_entries must be at least 1");
+        }
+        if self.mcp.audit.max_entries == 0 {
+            return invalid("mcp.audit.max_entries must be at least 1");
+        }
+        if self.daemon.http.recent_runs == 0 {
+            return invalid("daemon.http.recent_runs must be at least 1");
+        }
+        if self.daemon.http.token.as_deref().is_some_and(str::is_empty) {
+            return invalid(
+                "daemon.http.token must not be empty; leave it unset for a generated one",
+            );
+        }
+        if self.mcp.auth.token.as_deref().is_some_and(str::is_empty) {
+            return invalid("mcp.auth.token must not be empty; leave it unset for no token");
+        }
+        if let Some(name) = self
+            .mcp
+            .auth
+            .clients
+            .iter()
+            .find_map(|(name, client)| client.token.is_empty().then_some(name))
+        {
+            return Err(CoreError::Validation(format!(
+                "mcp.auth.clients.{name}.token must be set"
+            )));
         }
+        for (name, expansion) in &self.aliases {
+            if name.is_empty() || name.starts_with('-') || name.contains(char::is_whitespace) {
+                return Err(CoreError::Validation(format!(
+                    "aliases: {name:?} is not a command name"
+                )));
+            }
+            crate::alias::split(name, expansion).map_err(|_| {
+                CoreError::Validation(format!(
+                    "aliases.{name}: {expansion:?} is not a command line"
+                ))
+            })?;
+        }
+        for (name, task) in &self.tasks {
+            validate_task(name, task)?;
+            crate::task::plan(&self.tasks, name)?;
+        }
+        Ok(())
+    }
+}
 
-        Ok(config)
+/// Defaults, then `config_file` (if any), then the environment.
+fn from_file(config_file: &Path, overrides: &[Override]) -> Result<AppConfig> {
+    if let Some(ext) = config_file.extension().and_then(OsStr::to_str)
+        && !ext.eq_ignore_ascii_case("toml")
+    {
+        return Err(CoreError::Unsupported(format!(
+            "config file {} is .{ext}; only TOML is supported",
+            config_file.display()
+        )));
+    }
+    check_syntax(config_file)?;
+
+    from_sources(Some(config_file), overrides)
+}
+
+fn from_sources(config_file: Option<&Path>, overrides: &[Override]) -> Result<AppConfig> {
+    from_layers(layered(
+        config_file.map(|file| File::from(file).format(FileFormat::Toml).required(false)),
+        env_source(),
+        overrides,
+    ))
+}
+
+/// Deserialize, expand `logging.file`, and validate.
+fn from_layers(layers: std::result::Result<Config, ConfigError>) -> Result<AppConfig> {
+    let mut config: AppConfig = layers
+        .and_then(Config::try_deserialize)
+        .map_err(config_error)?;
+
+    if let Some(ref file) = config.logging.file {
+        let expanded = expand_str_path(file)?;
+        config.logging.file = Some(expanded.display().to_string());
+    }
+
+    config.validate()?;
+    Ok(config)
+}
+
+/// `<PREFIX>__*` environment overrides.
+fn env_source() -> Environment {
+    Environment::with_prefix(env_prefix().as_str()).separator("__")
+}
+
+/// Defaults, then the config file (if any), then environment overrides,
+/// then `overrides`.
+fn layered(
+    file: Option<impl Source + Send + Sync + 'static>,
+    env: Environment,
+    overrides: &[Override],
+) -> std::result::Result<Config, ConfigError> {
+    let builder = Config::builder()
+        .set_default("profile", "default")?
+        .set_default("logging.level", "info")?
+        .set_default("logging.format", "text")?
+        .set_default("runtime.parallelism", default_parallelism() as i64)?
+        .set_default(
+            "runtime.timeout",
+            platform::current(|defaults| defaults.timeout_secs()) as i64,
+        )?
+        .set_default("runtime.fail_fast", true)?
+        .set_default("runtime.check_config_version", true)?
+        .set_default("updates.check", true)?
+        .set_default("runtime.power_save", "auto")?;
+    let builder = match file {
+        Some(file) => builder.add_source(file),
+        None => builder,
+    };
+    let mut builder = builder.add_source(env);
+    for item in overrides {
+        builder = builder.set_override(item.key.as_str(), item.config_value())?;
     }
+    builder.build()
 }
 
-impl Default for AppConfig {
-    fn default() -> Self {
-        Self {
-            schema: None,
-            profile: "default".to_string(),
-            logging: LoggingConfig::default(),
-            runtime: RuntimeConfig::default(),
-            paths: PathsConfig::default(),
+/// One `key=value` setting that takes precedence over the config file and
+/// the environment, as given to `--set`.
+///
+/// The value is read as a TOML value when it is one (`120`, `true`,
+/// `["config", "state"]`) and as a string otherwise, so `logging.level=debug`
+/// needs no quotes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Override {
+    /// Dotted setting key, e.g. `runtime.timeout`.
+    pub key: String,
+    /// Value as written after the `=`.
+    pub value: String,
+}
+
+impl Override {
+    /// The value as TOML: parsed when it is a TOML value, else a string.
+    #[must_use]
+    pub fn parsed(&self) -> toml::Value {
+        toml::from_str::<toml::Table>(&format!("value = {}", self.value))
+            .ok()
+            .and_then(|mut table| table.remove("value"))
+            .unwrap_or_else(|| toml::Value::String(self.value.clone()))
+    }
+
+    fn config_value(&self) -> config::Value {
+        let origin = format!("--set {}", self.key);
+        config::Value::new(Some(&origin), toml_to_config(self.parsed()))
+    }
+}
+
+impl std::str::FromStr for Override {
+    type Err = CoreError;
+
+    fn from_str(text: &str) -> Result<Self> {
+        let Some((key, value)) = text.split_once('=') else {
+            return Err(CoreError::Validation(format!(
+                "{text:?} is not KEY=VALUE (e.g. runtime.timeout=120)"
+            )));
+        };
+        let key = key.trim();
+        if key.split('.').any(|part| part.trim().is_empty()) {
+            return Err(CoreError::Validation(format!(
+                "{text:?} does not start with a setting key"
+            )));
         }
+        Ok(Self {
+            key: key.to_string(),
+            value: value.to_string(),
+        })
     }
 }
 
-/// Logging configuration.
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
-#[serde(default)]
-#[schemars(description = "Logging configuration")]
-pub struct LoggingConfig {
-    /// Log level (error, warn, info, debug, trace).
-    #[schemars(default = "default_log_level")]
-    pub level: LogLevel,
+fn toml_to_config(value: toml::Value) -> config::ValueKind {
+    use config::ValueKind;
+    match value {
+        toml::Value::String(text) => ValueKind::String(text),
+        toml::Value::Integer(number) => ValueKind::I64(number),
+        toml::Value::Float(number) => ValueKind::Float(number),
+        toml::Value::Boolean(flag) => ValueKind::Boolean(flag),
+        toml::Value::Datetime(datetime) => ValueKind::String(datetime.to_string()),
+        toml::Value::Array(items) => ValueKind::Array(
+            items
+                .into_iter()
+                .map(|item| config::Value::new(None, toml_to_config(item)))
+                .collect(),
+        ),
+        toml::Value::Table(table) => ValueKind::Table(
+            table
+                .into_iter()
+                .map(|(key, item)| (key, config::Value::new(None, toml_to_config(item))))
+                .collect(),
+        ),
+    }
+}
+
+/// Parse the file as plain TOML first so syntax errors carry a location,
+/// which the `config` crate's errors do not.
+fn check_syntax(config_file: &Path) -> Result<()> {
+    let text = match fs::read_to_string(config_file) {
+        Ok(text) => text,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err).at_path("reading config file", config_file),
+    };
+    check_toml(&text, config_file)
+}
+
+/// Parse `text`, the contents of `config_file`, as plain TOML.
+fn check_toml(text: &str, config_file: &Path) -> Result<()> {
+    toml::from_str::<toml::Table>(text)
+        .map(drop)
+        .map_err(|err| CoreError::ConfigParse {
+            path: config_file.to_path_buf(),
+            span: err
+                .span()
+                .map(|span| Span::locate(text, span.start, span.end)),
+            message: err.message().to_string(),
+        })
+}
+
+/// Key name fragments that mark a setting as secret-bearing. Such keys are
+/// never synced and are exported as references instead of values.
+pub const SECRET_MARKERS: &[&str] = &[
+    "secret",
+    "token",
+    "password",
+    "passwd",
+    "api_key",
+    "apikey",
+    "credential",
+    "private_key",
+];
+
+/// Words that mark a setting as secret-bearing only as a whole segment of
+/// the key (split at `.`, `_`, and `-`): `ssh_key` and `signing.key`, but
+/// not `keymap` or `monkey`.
+pub const SECRET_WORDS: &[&str] = &["key"];
+
+/// Returns true if the dotted `key` looks like it holds a secret.
+#[must_use]
+pub fn is_secret_key(key: &str) -> bool {
+    let lower = key.to_ascii_lowercase();
+    SECRET_MARKERS.iter().any(|marker| lower.contains(marker))
+        || lower
+            .split(['.', '_', '-'])
+            .any(|word| SECRET_WORDS.contains(&word))
+}
+
+/// Returns true if `presented` is the configured `token`. The comparison
+/// does not stop at the first difference, so the time taken does not tell
+/// how much of a guess was right.
+#[must_use]
+pub fn token_matches(presented: &str, token: &str) -> bool {
+    let (a, b) = (presented.as_bytes(), token.as_bytes());
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// Placeholder for a value left out by [`redacted`].
+pub const REDACTED: &str = "<redacted>";
 
-    /// Optional path for log file output. Supports ~ and environment variables.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub file: Option<String>,
+/// Returns true if the dotted `key` is masked when the config is shared:
+/// it looks like a secret, or contains one of the `extra` fragments
+/// (`security.redact`), ignoring case.
+#[must_use]
+pub fn is_redacted_key(key: &str, extra: &[String]) -> bool {
+    let lower = key.to_ascii_lowercase();
+    is_secret_key(key)
+        || extra
+            .iter()
+            .any(|fragment| !fragment.is_empty() && lower.contains(&fragment.to_ascii_lowercase()))
 }
 
-/// Log level enumeration for schema validation.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, Default)]
-#[serde(rename_all = "lowercase")]
-pub enum LogLevel {
-    /// Only emit error-level messages.
-    Error,
-    /// Emit warnings and errors.
-    Warn,
-    /// Emit informational messages and above (default).
-    #[default]
-    Info,
-    /// Emit debug diagnostics and above.
-    Debug,
-    /// Emit all messages including fine-grained traces.
-    Trace,
+/// `config` as a JSON value, safe to paste into an issue.
+///
+/// The value of every key matched by [`is_redacted_key`] is replaced by
+/// [`REDACTED`]; a matching table, such as a task named `deploy_token`, is
+/// masked whole.
+///
+/// # Errors
+///
+/// Returns [`CoreError::Serialization`] if the config cannot be serialized.
+pub fn redacted(config: &AppConfig) -> Result<serde_json::Value> {
+    fn mask(prefix: &str, value: &mut serde_json::Value, extra: &[String]) {
+        let serde_json::Value::Object(object) = value else {
+            return;
+        };
+        for (key, child) in object.iter_mut() {
+            let path = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{prefix}.{key}")
+            };
+            if child.is_null() {
+                continue;
+            }
+            if is_redacted_key(&path, extra) {
+                *child = serde_json::Value::String(REDACTED.to_string());
+            } else {
+                mask(&path, child, extra);
+            }
+        }
+    }
+    let mut value = serde_json::to_value(config)
+        .map_err(|err| CoreError::Serialization(format!("config: {err}")))?;
+    mask("", &mut value, &config.security.redact);
+    Ok(value)
 }
 
-impl std::fmt::Display for LogLevel {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Error => write!(f, "error"),
-            Self::Warn => write!(f, "warn"),
-            Self::Info => write!(f, "info"),
-            Self::Debug => write!(f, "debug"),
-            Self::Trace => write!(f, "trace"),
+/// `text`, the contents of `config_file`, with the same keys as
+/// [`redacted`] masked; comments and layout are kept.
+///
+/// # Errors
+///
+/// Returns [`CoreError::ConfigParse`] if `text` is not valid TOML.
+pub fn redacted_text(text: &str, config_file: &Path, extra: &[String]) -> Result<String> {
+    fn mask(prefix: &str, table: &mut dyn toml_edit::TableLike, extra: &[String]) {
+        for (key, item) in table.iter_mut() {
+            let path = if prefix.is_empty() {
+                key.get().to_string()
+            } else {
+                format!("{prefix}.{}", key.get())
+            };
+            if is_redacted_key(&path, extra) {
+                *item = toml_edit::value(REDACTED);
+            } else if let Some(child) = item.as_table_like_mut() {
+                mask(&path, child, extra);
+            } else if let Some(tables) = item.as_array_of_tables_mut() {
+                for child in tables.iter_mut() {
+                    mask(&path, child, extra);
+                }
+            }
         }
     }
+    check_toml(text, config_file)?;
+    let mut doc: toml_edit::DocumentMut =
+        text.parse()
+            .map_err(|err: toml_edit::TomlError| CoreError::ConfigParse {
+                path: config_file.to_path_buf(),
+                span: None,
+                message: err.message().to_string(),
+            })?;
+    mask("", doc.as_table_mut(), extra);
+    Ok(doc.to_string())
 }
 
-const fn default_log_level() -> LogLevel {
-    LogLevel::Info
+/// Environment variable that overrides the dotted `key`, e.g.
+/// `RUST_WORKSPACE__LOGGING__LEVEL` for `logging.level`.
+#[must_use]
+pub fn env_var_name(key: &str) -> String {
+    format!(
+        "{}__{}",
+        env_prefix(),
+        key.replace('.', "__").to_ascii_uppercase()
+    )
 }
 
-impl Default for LoggingConfig {
-    fn default() -> Self {
-        Self {
-            level: LogLevel::Info,
-            file: None,
+/// Flatten nested tables into dotted keys; arrays and scalars are leaves.
+pub(crate) fn flatten(prefix: &str, table: &toml::Table, out: &mut BTreeMap<String, toml::Value>) {
+    for (key, value) in table {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        match value {
+            toml::Value::Table(inner) => flatten(&path, inner, out),
+            leaf => {
+                out.insert(path, leaf.clone());
+            }
         }
     }
 }
 
-/// Runtime behavior configuration.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema)]
-#[serde(default)]
-#[schemars(description = "Runtime behavior configuration")]
-pub struct RuntimeConfig {
-    /// Worker pool size. Defaults to logical CPU count when unset.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[schemars(range(min = 1))]
-    pub parallelism: Option<usize>,
+fn config_error(err: ConfigError) -> CoreError {
+    match err {
+        ConfigError::Type { .. } | ConfigError::Message(_) | ConfigError::NotFound(_) => {
+            CoreError::Validation(err.to_string())
+        }
+        other => CoreError::Config(other.to_string()),
+    }
+}
+
+/// Shortest interval a polling loop may use while saving power.
+pub const POWER_SAVE_POLL: Duration = Duration::from_secs(1);
+
+/// Power-aware runtime settings for [`RuntimeConfig`].
+pub trait RuntimeConfigExt {
+    /// Whether to throttle now, per `power_save`. Asks the platform about
+    /// the battery on every call under `auto`.
+    fn power_saving(&self) -> bool;
+
+    /// Worker pool size to use: `parallelism` (or the platform default),
+    /// halved while saving power. Logs the reduction.
+    fn effective_parallelism(&self) -> usize;
+
+    /// Interval for a polling loop that would otherwise wait `interval`:
+    /// at least [`POWER_SAVE_POLL`] while saving power. Logs the change.
+    fn poll_interval(&self, interval: Duration) -> Duration;
+}
+
+impl RuntimeConfigExt for RuntimeConfig {
+    fn power_saving(&self) -> bool {
+        match self.power_save {
+            PowerSave::On => true,
+            PowerSave::Off => false,
+            PowerSave::Auto => platform::current(|defaults| defaults.on_battery()),
+        }
+    }
 
-    /// Timeout in seconds for long-running operations (default: 60).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[schemars(range(min = 1))]
-    pub timeout: Option<u64>,
+    fn effective_parallelism(&self) -> usize {
+        let configured = self.parallelism.unwrap_or_else(default_parallelism);
+        if !self.power_saving() || configured == 1 {
+            return configured;
+        }
+        let reduced = (configured / 2).max(1);
+        log::info!(
+            "saving power (runtime.power_save = {}): parallelism {configured} -> {reduced}",
+            self.power_save
+        );
+        reduced
+    }
 
-    /// Stop on first error.
-    pub fail_fast: bool,
+    fn poll_interval(&self, interval: Duration) -> Duration {
+        if interval >= POWER_SAVE_POLL || !self.power_saving() {
+            return interval;
+        }
+        log::info!(
+            "saving power (runtime.power_save = {}): polling every {POWER_SAVE_POLL:?} instead of {interval:?}",
+            self.power_save
+        );
+        POWER_SAVE_POLL
+    }
 }
 
-impl Default for RuntimeConfig {
-    fn default() -> Self {
-        Self {
-            parallelism: None,
-            timeout: Some(60),
-            fail_fast: true,
+/// Check one task: its name, that it sets at most one of `command` and
+/// `builtin`, that only a `command` runs under a pty, its timeout, that `builtin` is a command line, and that its
+/// inputs and outputs are relative paths of an isolated task.
+fn validate_task(name: &str, task: &TaskConfig) -> Result<()> {
+    let invalid = |message: String| Err(CoreError::Validation(format!("tasks.{name}{message}")));
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return Err(CoreError::Validation(format!(
+            "tasks: {name:?} is not a task name"
+        )));
+    }
+    if task.command.is_some() && task.builtin.is_some() {
+        return invalid(": set either command or builtin, not both".to_string());
+    }
+    if task.pty && task.builtin.is_some() {
+        return invalid(".pty: a builtin runs in-process; use command for a pty".to_string());
+    }
+    if task.timeout == Some(0) {
+        return invalid(".timeout must be at least 1 second".to_string());
+    }
+    if let Some(builtin) = &task.builtin
+        && shlex::split(builtin).is_none_or(|words| words.is_empty())
+    {
+        return invalid(format!(".builtin: {builtin:?} is not a command line"));
+    }
+    for (field, entries) in [("inputs", &task.inputs), ("outputs", &task.outputs)] {
+        if !task.isolated && !entries.is_empty() {
+            return invalid(format!(".{field} needs isolated = true"));
+        }
+        if let Some(entry) = entries
+            .iter()
+            .find(|entry| !crate::task::is_plain_relative(entry))
+        {
+            return invalid(format!(
+                ".{field}: {entry:?} is not a relative path inside the task's directory"
+            ));
         }
     }
+    Ok(())
 }
 
-/// Path override configuration.
-#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
-#[serde(default)]
-#[schemars(description = "Custom paths for data and state directories")]
-pub struct PathsConfig {
-    /// Directory for persistent data. Supports ~ and environment variables.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub data_dir: Option<String>,
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch::Scratch;
+
+    fn scratch_config(dir: &Path, name: &str, body: &str) -> anyhow::Result<std::path::PathBuf> {
+        let path = dir.join(name);
+        fs::write(&path, body)?;
+        Ok(path)
+    }
+
+    #[test]
+    fn errors_are_matchable() -> anyhow::Result<()> {
+        let dir = Scratch::new()?;
+        let syntax = scratch_config(&dir, "syntax.toml", "profile = \"a\"\n[runtime\n")?;
+        let range = scratch_config(&dir, "range.toml", "[runtime]\nparallelism = 0\n")?;
+        let yaml = scratch_config(&dir, "config.yaml", "profile: a\n")?;
+
+        match AppConfig::load_from_path(&syntax) {
+            Err(CoreError::ConfigParse {
+                span: Some(span), ..
+            }) => {
+                anyhow::ensure!(span.line == 2, "span {span:?}");
+            }
+            other => anyhow::bail!("expected ConfigParse, got {other:?}"),
+        }
+        anyhow::ensure!(matches!(
+            AppConfig::load_from_path(&range),
+            Err(CoreError::Validation(_))
+        ));
+        anyhow::ensure!(matches!(
+            AppConfig::load_from_path(&yaml),
+            Err(CoreError::Unsupported(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn overrides_win_over_the_file() -> anyhow::Result<()> {
+        let dir = Scratch::new()?;
+        let file = dir.join("config.toml");
+        fs::write(
+            &file,
+            "[runtime]\ntimeout = 30\nfail_fast = true\n\n\
+             [logging.filters]\n\"rust_core::config\" = \"trace\"\n",
+        )?;
+        let overrides: Vec<Override> = [
+            "runtime.timeout=120",
+            "runtime.fail_fast=false",
+            "logging.level=debug",
+            "logging.filters.hyper=warn",
+            r#"health.probes=["config"]"#,
+        ]
+        .iter()
+        .map(|text| text.parse())
+        .collect::<Result<_>>()?;
+        let config = from_file(&file, &overrides)?;
+        anyhow::ensure!(config.runtime.timeout == Some(120) && !config.runtime.fail_fast);
+        anyhow::ensure!(matches!(config.logging.level, LogLevel::Debug));
+        // Module paths are keys, `::` and all, next to the overridden ones.
+        let filters: Vec<_> = config.logging.filters.keys().collect();
+        anyhow::ensure!(filters == ["hyper", "rust_core::config"], "{filters:?}");
+        anyhow::ensure!(config.health.probes == [HealthProbe::Config]);
+
+        let zero: Override = "runtime.timeout=0".parse()?;
+        anyhow::ensure!(matches!(
+            from_file(&file, &[zero]),
+            Err(CoreError::Validation(_))
+        ));
+        anyhow::ensure!("runtime.timeout".parse::<Override>().is_err());
+        anyhow::ensure!("=5".parse::<Override>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn redaction_masks_secrets_and_configured_keys() -> anyhow::Result<()> {
+        let mut config = defaults();
+        config.tasks.insert(
+            "deploy".to_string(),
+            TaskConfig {
+                command: Some("./deploy".to_string()),
+                env: BTreeMap::from([
+                    ("API_TOKEN".to_string(), "hunter2".to_string()),
+                    ("REGION".to_string(), "eu".to_string()),
+                ]),
+                ..TaskConfig::default()
+            },
+        );
+        config.security.redact = vec!["Daemon".to_string()];
+        let value = redacted(&config)?;
+        let at = |pointer: &str| value.pointer(pointer).and_then(serde_json::Value::as_str);
+        anyhow::ensure!(at("/tasks/deploy/env/API_TOKEN") == Some(REDACTED));
+        anyhow::ensure!(at("/tasks/deploy/env/REGION") == Some("eu"));
+        anyhow::ensure!(at("/health/daemon") == Some(REDACTED));
+        anyhow::ensure!(at("/profile") == Some("default"));
+        anyhow::ensure!(!value.to_string().contains("hunter2"));
+        Ok(())
+    }
 
-    /// Directory for state files. Supports ~ and environment variables.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub state_dir: Option<String>,
+    #[test]
+    fn key_is_a_secret_only_as_a_whole_word() {
+        for key in [
+            "signing.key",
+            "tasks.deploy.env.SSH_KEY",
+            "license-key",
+            "api_key",
+        ] {
+            assert!(is_secret_key(key), "{key}");
+        }
+        for key in ["tui.keymap", "tasks.monkey.command", "hotkeys"] {
+            assert!(!is_secret_key(key), "{key}");
+        }
+    }
+
+    #[test]
+    fn tokens_match_only_in_full() {
+        assert!(token_matches("hunter2", "hunter2"));
+        assert!(!token_matches("hunter", "hunter2"));
+        assert!(!token_matches("hunter3", "hunter2"));
+        assert!(!token_matches("", "hunter2"));
+    }
+
+    #[test]
+    fn redacted_text_keeps_comments_and_masks_secrets() -> anyhow::Result<()> {
+        let text = "# mine\nprofile = \"work\"\n\n[tasks.deploy.env]\nAPI_TOKEN = \"hunter2\" # keep\nREGION = \"eu\"\n\n[mcp.auth.clients.ci]\ntoken = \"s3cret\"\n";
+        let shown = redacted_text(text, Path::new("config.toml"), &["region".to_string()])?;
+        anyhow::ensure!(shown.contains("# mine") && shown.contains("profile = \"work\""));
+        anyhow::ensure!(
+            !shown.contains("hunter2") && !shown.contains("s3cret"),
+            "{shown}"
+        );
+        anyhow::ensure!(!shown.contains("\"eu\""), "{shown}");
+        anyhow::ensure!(matches!(
+            redacted_text("[broken", Path::new("config.toml"), &[]),
+            Err(CoreError::ConfigParse { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn isolated_task_paths_stay_inside_their_directory() {
+        let check = |isolated, inputs: &[&str]| {
+            let task = TaskConfig {
+                isolated,
+                inputs: inputs.iter().map(|input| (*input).to_string()).collect(),
+                ..TaskConfig::default()
+            };
+            validate_task("build", &task).map_err(|err| err.to_string())
+        };
+        assert_eq!(check(true, &["src", "./Cargo.toml"]), Ok(()));
+        assert_eq!(
+            check(false, &["src"]),
+            Err("invalid configuration: tasks.build.inputs needs isolated = true".to_string())
+        );
+        assert!(check(true, &["../secrets"]).is_err());
+        assert!(check(true, &["/etc/passwd"]).is_err());
+    }
+
+    #[test]
+    fn pty_tasks_need_a_command() {
+        let check = |command: Option<&str>, builtin: Option<&str>| {
+            let task = TaskConfig {
+                command: command.map(str::to_string),
+                builtin: builtin.map(str::to_string),
+                pty: true,
+                ..TaskConfig::default()
+            };
+            validate_task("fmt", &task).map_err(|err| err.to_string())
+        };
+        assert_eq!(check(Some("cargo fmt"), None), Ok(()));
+        assert_eq!(
+            check(None, Some("clean --dry-run")),
+            Err(
+                "invalid configuration: tasks.fmt.pty: a builtin runs in-process; use command for a pty"
+                    .to_string()
+            )
+        );
+    }
 }
@@ -0,0 +1,253 @@
+//! Markdown reference for the config file, generated from its schema.
+//!
+//! Each section gets a table of its settings with their dotted key, type,
+//! default, and description, so the reference cannot drift from
+//! [`AppConfig`](crate::AppConfig). The schema is walked as for the
+//! [settings UI spec](crate::ui_spec): nested sections are flattened into
+//! their top-level section under dotted keys. Tables of named sections,
+//! such as `tasks`, are documented too, with an entry's settings under
+//! `tasks.<name>`.
+
+use std::fmt::Write as _;
+
+use anyhow::{Context, Result, bail};
+use serde_json::Value;
+
+use crate::schema::generate_schema;
+use crate::ui_spec::{UiField, description, field, is_section, resolve};
+
+/// Generated config reference filename, under the docs directory.
+pub const CONFIG_DOCS_FILENAME: &str = "config.md";
+
+/// Section heading for top-level settings that are not in a section.
+const GENERAL_SECTION: &str = "General";
+
+/// One table of the reference.
+struct Section {
+    heading: String,
+    description: Option<String>,
+    fields: Vec<UiField>,
+}
+
+/// Generate the Markdown config reference.
+///
+/// # Errors
+///
+/// Returns an error if the schema cannot be generated or has an unexpected
+/// shape.
+pub fn generate_config_docs(project_name: &str) -> Result<String> {
+    let schema: Value = serde_json::from_str(&generate_schema(project_name)?)
+        .context("parsing generated JSON schema")?;
+    config_docs(&schema, project_name)
+}
+
+/// Render the reference for a draft-07 `schema` of the config.
+///
+/// # Errors
+///
+/// Returns an error if the schema has no top-level properties or refers to
+/// a definition it does not contain.
+pub fn config_docs(schema: &Value, project_name: &str) -> Result<String> {
+    let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
+        bail!("config schema has no properties");
+    };
+    let mut general = Section {
+        heading: GENERAL_SECTION.to_string(),
+        description: None,
+        fields: Vec::new(),
+    };
+    let mut sections = Vec::new();
+    for (key, property) in properties {
+        if key.starts_with('$') {
+            continue;
+        }
+        let node = resolve(schema, property)?;
+        let (prefix, section) = match table_entry(schema, node)? {
+            Some(entry) => (format!("{key}.<name>"), entry),
+            None if is_section(node) => (key.clone(), node),
+            None => {
+                general.fields.push(field(schema, key, property)?);
+                continue;
+            }
+        };
+        let mut fields = Vec::new();
+        collect_fields(schema, &prefix, section, &mut fields)?;
+        sections.push(Section {
+            heading: format!("`{key}`"),
+            description: description(property).or_else(|| description(node)),
+            fields,
+        });
+    }
+    if !general.fields.is_empty() {
+        sections.insert(0, general);
+    }
+
+    let mut out = format!(
+        "# {project_name} configuration reference\n\n\
+         <!-- Generated from the config schema by `generate all`; do not edit by hand. -->\n\n\
+         Every setting of the config file, by section. Keys are dotted paths into the file; \
+         `<name>` stands for the name of an entry in a table such as `[tasks.<name>]`.\n"
+    );
+    for section in sections.iter().filter(|section| !section.fields.is_empty()) {
+        let _ = write!(out, "\n## {}\n\n", section.heading);
+        if let Some(description) = &section.description {
+            let _ = write!(out, "{description}\n\n");
+        }
+        out.push_str("| Key | Type | Default | Description |\n|---|---|---|---|\n");
+        for field in &section.fields {
+            let _ = writeln!(
+                out,
+                "| `{}` | {} | {} | {} |",
+                field.key,
+                type_cell(field),
+                default_cell(field),
+                cell(field.description.as_deref().unwrap_or_default())
+            );
+        }
+    }
+    Ok(out)
+}
+
+fn collect_fields(
+    schema: &Value,
+    prefix: &str,
+    section: &Value,
+    out: &mut Vec<UiField>,
+) -> Result<()> {
+    let Some(properties) = section.get("properties").and_then(Value::as_object) else {
+        return Ok(());
+    };
+    for (name, property) in properties {
+        let key = format!("{prefix}.{name}");
+        let node = resolve(schema, property)?;
+        if let Some(entry) = table_entry(schema, node)? {
+            collect_fields(schema, &format!("{key}.<name>"), entry, out)?;
+        } else if is_section(node) {
+            collect_fields(schema, &key, node, out)?;
+        } else {
+            out.push(field(schema, &key, property)?);
+        }
+    }
+    Ok(())
+}
+
+/// The section each entry of `node` is, if `node` is a table of named
+/// sections such as `tasks`.
+fn table_entry<'a>(schema: &'a Value, node: &'a Value) -> Result<Option<&'a Value>> {
+    match node.get("additionalProperties") {
+        Some(entry) if entry.is_object() => {
+            let entry = resolve(schema, entry)?;
+            Ok(is_section(entry).then_some(entry))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// The JSON type, with the choices or range the schema allows.
+fn type_cell(field: &UiField) -> String {
+    let mut kind = field.value_type.clone();
+    if !field.options.is_empty() {
+        let choices: Vec<String> = field
+            .options
+            .iter()
+            .map(|option| format!("`{}`", option.value))
+            .collect();
+        let quantifier = if kind == "array" { "any" } else { "one" };
+        let _ = write!(kind, ", {quantifier} of {}", choices.join(", "));
+    }
+    match (field.constraints.min, field.constraints.max) {
+        (Some(min), Some(max)) => {
+            let _ = write!(kind, ", {min} to {max}");
+        }
+        (Some(min), None) => {
+            let _ = write!(kind, ", at least {min}");
+        }
+        (None, Some(max)) => {
+            let _ = write!(kind, ", at most {max}");
+        }
+        (None, None) => {}
+    }
+    kind
+}
+
+/// The default as JSON, or whether the setting may be left unset.
+fn default_cell(field: &UiField) -> String {
+    match &field.default {
+        Some(default) => cell(&format!("`{default}`")),
+        None if field.optional => "unset".to_string(),
+        None => "none".to_string(),
+    }
+}
+
+/// `text` safe inside a table cell.
+fn cell(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn documents_sections_tables_and_general_settings() -> anyhow::Result<()> {
+        let schema = json!({
+            "properties": {
+                "profile": { "type": "string", "default": "default", "description": "Active profile" },
+                "runtime": { "$ref": "#/definitions/Runtime", "description": "Execution settings" },
+                "tasks": {
+                    "type": "object",
+                    "additionalProperties": { "$ref": "#/definitions/Task" },
+                },
+            },
+            "definitions": {
+                "Runtime": {
+                    "type": "object",
+                    "properties": {
+                        "timeout": { "type": ["integer", "null"], "minimum": 1, "description": "Seconds | limit" },
+                        "mode": { "enum": ["fast", "safe"], "default": "safe" },
+                    },
+                },
+                "Task": {
+                    "type": "object",
+                    "properties": { "command": { "type": ["string", "null"] } },
+                },
+            },
+        });
+        let docs = config_docs(&schema, "demo")?;
+        anyhow::ensure!(
+            docs.starts_with("# demo configuration reference\n"),
+            "{docs}"
+        );
+        for line in [
+            "## General",
+            "| `profile` | string | `\"default\"` | Active profile |",
+            "## `runtime`\n\nExecution settings\n",
+            "| `runtime.timeout` | integer, at least 1 | unset | Seconds \\| limit |",
+            "| `runtime.mode` | string, one of `fast`, `safe` | `\"safe\"` |  |",
+            "## `tasks`",
+            "| `tasks.<name>.command` | string | unset |  |",
+        ] {
+            anyhow::ensure!(docs.contains(line), "missing {line:?} in\n{docs}");
+        }
+        anyhow::ensure!(
+            docs.find("## General") < docs.find("## `runtime`"),
+            "general settings come first"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn covers_the_real_config() -> anyhow::Result<()> {
+        let docs = generate_config_docs("demo")?;
+        for key in [
+            "runtime.timeout",
+            "backup.compression_level",
+            "tasks.<name>.command",
+        ] {
+            anyhow::ensure!(docs.contains(&format!("| `{key}` |")), "no {key}");
+        }
+        Ok(())
+    }
+}
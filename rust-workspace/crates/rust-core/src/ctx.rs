@@ -0,0 +1,275 @@
+//! Per-operation context threaded through core operations.
+//!
+//! A [`Ctx`] carries what an operation needs to know about the invocation
+//! it serves: whether it is a dry run (and the [`Planner`] its intended
+//! changes go to), the deadline it must finish by, a [`CancelToken`] another
+//! thread can trip, where to report progress, the [`EventBus`] it announces
+//! what happened on, and a correlation ID naming the invocation in logs and
+//! child processes.
+//!
+//! Operations call [`Ctx::check`] between steps, so a cancelled or overdue
+//! operation stops at a clean point with [`CoreError::Cancelled`] or
+//! [`CoreError::Timeout`]. A shutdown signal (see [`crate::shutdown`])
+//! cancels every context. Tests build a [`Ctx::default`] and adjust it with
+//! the `with_*` methods.
+
+use std::collections::hash_map::RandomState;
+use std::env;
+use std::fmt;
+use std::hash::{BuildHasher as _, Hasher as _};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::bus::{Event, EventBus};
+use crate::env_prefix;
+use crate::error::{CoreError, Result};
+use crate::plan::{Action, Planner};
+use crate::progress::{NoProgress, Progress};
+use crate::shutdown;
+
+/// A flag shared between the contexts cloned from one another; cancelling
+/// any clone cancels them all.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    /// Ask every operation holding this token to stop.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether [`CancelToken::cancel`] has been called.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// Settings shared by every step of one operation.
+#[derive(Clone)]
+pub struct Ctx {
+    dry_run: bool,
+    planner: Planner,
+    /// When the operation must be done by, and the limit it was set from.
+    deadline: Option<(Instant, Duration)>,
+    cancel: CancelToken,
+    progress: Arc<dyn Progress>,
+    events: EventBus,
+    correlation_id: String,
+}
+
+impl Default for Ctx {
+    /// A real run with no deadline, no progress display, no event
+    /// subscribers, and the correlation ID from [`correlation_id`].
+    fn default() -> Self {
+        Self {
+            dry_run: false,
+            planner: Planner::default(),
+            deadline: None,
+            cancel: CancelToken::default(),
+            progress: Arc::new(NoProgress),
+            events: EventBus::default(),
+            correlation_id: correlation_id(),
+        }
+    }
+}
+
+impl fmt::Debug for Ctx {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Ctx")
+            .field("dry_run", &self.dry_run)
+            .field("deadline", &self.deadline)
+            .field("cancelled", &self.cancel.is_cancelled())
+            .field("correlation_id", &self.correlation_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Ctx {
+    /// Only report what would change instead of changing it.
+    #[must_use]
+    pub const fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Give the operation `limit` from now to finish; `None` removes the
+    /// deadline.
+    #[must_use]
+    pub fn with_timeout(mut self, limit: Option<Duration>) -> Self {
+        self.deadline = limit.map(|limit| (Instant::now() + limit, limit));
+        self
+    }
+
+    /// Share `token` with the code that may cancel the operation.
+    #[must_use]
+    pub fn with_cancel(mut self, token: CancelToken) -> Self {
+        self.cancel = token;
+        self
+    }
+
+    /// Report progress to `progress`.
+    #[must_use]
+    pub fn with_progress(mut self, progress: Arc<dyn Progress>) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    /// Announce events on `events`, and so to its subscribers.
+    #[must_use]
+    pub fn with_events(mut self, events: EventBus) -> Self {
+        self.events = events;
+        self
+    }
+
+    /// Name the invocation `id` instead.
+    #[must_use]
+    pub fn with_correlation_id(mut self, id: impl Into<String>) -> Self {
+        self.correlation_id = id.into();
+        self
+    }
+
+    /// Whether the operation only reports what it would change.
+    #[must_use]
+    pub const fn dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Record that a dry run would take `action`.
+    pub fn plan(&self, action: Action) {
+        self.planner.record(action);
+    }
+
+    /// The plan shared by this context and its clones.
+    #[must_use]
+    pub const fn planner(&self) -> &Planner {
+        &self.planner
+    }
+
+    /// When the operation must be done by, if it has a deadline.
+    #[must_use]
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline.map(|(at, _)| at)
+    }
+
+    /// Time left before the deadline (zero once it has passed), if there is
+    /// one.
+    #[must_use]
+    pub fn remaining(&self) -> Option<Duration> {
+        self.deadline()
+            .map(|at| at.saturating_duration_since(Instant::now()))
+    }
+
+    /// The token that cancels this operation.
+    #[must_use]
+    pub const fn cancel_token(&self) -> &CancelToken {
+        &self.cancel
+    }
+
+    /// Where the operation reports progress.
+    #[must_use]
+    pub fn progress(&self) -> &dyn Progress {
+        self.progress.as_ref()
+    }
+
+    /// The bus the operation announces events on.
+    #[must_use]
+    pub const fn events(&self) -> &EventBus {
+        &self.events
+    }
+
+    /// Announce `event` to the subscribers of this context's bus.
+    pub fn publish(&self, event: &Event) {
+        self.events.publish(event);
+    }
+
+    /// Identifier of the invocation this operation belongs to.
+    #[must_use]
+    pub fn correlation_id(&self) -> &str {
+        &self.correlation_id
+    }
+
+    /// Why the operation must stop now, if it must: a shutdown signal, a
+    /// cancelled token, or a passed deadline, in that order.
+    #[must_use]
+    pub fn stop_reason(&self) -> Option<CoreError> {
+        if let Some(signal) = shutdown::requested() {
+            return Some(CoreError::Cancelled(format!(
+                "shutdown requested ({signal})"
+            )));
+        }
+        if self.cancel.is_cancelled() {
+            return Some(CoreError::Cancelled("cancel requested".to_string()));
+        }
+        match self.deadline {
+            Some((at, limit)) if Instant::now() >= at => Some(CoreError::Timeout(limit)),
+            _ => None,
+        }
+    }
+
+    /// Fail if the operation must stop; long operations call this between
+    /// steps.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::Cancelled`] after a shutdown signal or
+    /// cancellation, or [`CoreError::Timeout`] once the deadline has passed.
+    pub fn check(&self) -> Result<()> {
+        self.stop_reason().map_or(Ok(()), Err)
+    }
+}
+
+/// Environment variable carrying the correlation ID to child processes,
+/// e.g. `RUST_WORKSPACE_CORRELATION_ID`.
+#[must_use]
+pub fn correlation_env_var() -> String {
+    format!("{}_CORRELATION_ID", env_prefix())
+}
+
+/// The correlation ID for this process: the one inherited through
+/// [`correlation_env_var`] when this process was started by another
+/// invocation, otherwise a new random one (16 hex digits).
+#[must_use]
+pub fn correlation_id() -> String {
+    if let Some(id) = env::var(correlation_env_var())
+        .ok()
+        .filter(|id| !id.trim().is_empty())
+    {
+        return id;
+    }
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u32(std::process::id());
+    if let Ok(now) = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+        hasher.write_u128(now.as_nanos());
+    }
+    format!("{:016x}", hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_reports_cancellation_and_deadline() -> anyhow::Result<()> {
+        let ctx = Ctx::default().with_dry_run(true);
+        anyhow::ensure!(ctx.dry_run() && ctx.check().is_ok() && ctx.remaining().is_none());
+        anyhow::ensure!(ctx.correlation_id().len() == 16);
+
+        // Contexts given the same token are cancelled together.
+        let token = CancelToken::default();
+        let cancellable = ctx.clone().with_cancel(token.clone());
+        token.cancel();
+        anyhow::ensure!(matches!(cancellable.check(), Err(CoreError::Cancelled(_))));
+        anyhow::ensure!(ctx.check().is_ok());
+
+        let overdue = ctx.with_timeout(Some(Duration::ZERO));
+        anyhow::ensure!(overdue.remaining() == Some(Duration::ZERO));
+        anyhow::ensure!(matches!(
+            overdue.check(),
+            Err(CoreError::Timeout(limit)) if limit == Duration::ZERO
+        ));
+        anyhow::ensure!(overdue.with_timeout(None).check().is_ok());
+        Ok(())
+    }
+}
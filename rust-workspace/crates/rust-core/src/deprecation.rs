@@ -0,0 +1,234 @@
+//! Deprecated flags and subcommands, and the version that removes them.
+//!
+//! A binary lists its renamed flags and commands as [`Deprecation`]s and
+//! passes the command line through [`rewrite`] before parsing it. Until the
+//! declared removal version an old spelling is replaced by the new one and
+//! reported, so the caller can warn; from that version on it is left alone
+//! and reported as removed, so the caller can fail with a pointer to the new
+//! spelling rather than a bare "unknown argument". This lets an interface
+//! change without breaking scripts from one release to the next.
+
+use std::ffi::OsString;
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::error::{CoreError, Result};
+
+/// An old spelling, what replaces it, and when it stops being accepted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Deprecation {
+    /// Old spelling: a long flag (`--force`) or the words of a subcommand
+    /// path from the top (`cache purge`).
+    pub old: &'static str,
+    /// Spelling that replaces it, of the same kind.
+    pub new: &'static str,
+    /// First version that no longer accepts `old`.
+    pub removal: &'static str,
+}
+
+impl Deprecation {
+    /// Whether `old` is a flag rather than a subcommand path.
+    #[must_use]
+    pub fn is_flag(&self) -> bool {
+        self.old.starts_with("--")
+    }
+
+    /// Whether `version` no longer accepts `old`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::Validation`] if `removal` or `version` is not a
+    /// semantic version.
+    pub fn is_removed(&self, version: &str) -> Result<bool> {
+        let parse = |text: &str| {
+            semver::Version::parse(text).map_err(|err| {
+                CoreError::Validation(format!("deprecation of `{}`: {text}: {err}", self.old))
+            })
+        };
+        Ok(parse(version)? >= parse(self.removal)?)
+    }
+}
+
+/// One use of an old spelling on a command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Notice {
+    /// The deprecation that matched.
+    pub deprecation: Deprecation,
+    /// Whether the current version no longer accepts it; if not, it was
+    /// replaced by the new spelling.
+    pub removed: bool,
+}
+
+impl fmt::Display for Notice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Deprecation { old, new, removal } = self.deprecation;
+        if self.removed {
+            write!(f, "`{old}` was removed in {removal}; use `{new}` instead")
+        } else {
+            write!(
+                f,
+                "`{old}` is deprecated and will be removed in {removal}; use `{new}` instead"
+            )
+        }
+    }
+}
+
+/// `args` with each old spelling in `registry` that `version` still accepts
+/// replaced by its new one, and a [`Notice`] for every old spelling found.
+///
+/// Flags are matched up to a `--`, alone or as `--old=value`. A subcommand
+/// path is matched word by word at `command_at`, the index of the first
+/// positional argument (there is none if it is `None`). Removed spellings
+/// are left as they are.
+///
+/// # Errors
+///
+/// Returns [`CoreError::Validation`] if a matching entry's removal version,
+/// or `version`, is not a semantic version.
+pub fn rewrite(
+    registry: &[Deprecation],
+    version: &str,
+    args: Vec<OsString>,
+    command_at: Option<usize>,
+) -> Result<(Vec<OsString>, Vec<Notice>)> {
+    let mut notices = Vec::new();
+    let mut args = args;
+    for deprecation in registry {
+        let found = if deprecation.is_flag() {
+            flag_positions(&args, deprecation.old)
+        } else {
+            command_at
+                .filter(|&at| starts_with_words(&args[at..], deprecation.old))
+                .into_iter()
+                .collect()
+        };
+        if found.is_empty() {
+            continue;
+        }
+        let removed = deprecation.is_removed(version)?;
+        notices.push(Notice {
+            deprecation: *deprecation,
+            removed,
+        });
+        if removed {
+            continue;
+        }
+        // Back to front, so the earlier positions stay valid.
+        for &at in found.iter().rev() {
+            args = if deprecation.is_flag() {
+                replace_flag(args, at, deprecation)
+            } else {
+                replace_words(args, at, deprecation)
+            };
+        }
+    }
+    Ok((args, notices))
+}
+
+/// Positions of `flag` (or `flag=value`) before any `--`.
+fn flag_positions(args: &[OsString], flag: &str) -> Vec<usize> {
+    args.iter()
+        .map(|arg| arg.to_str())
+        .take_while(|arg| *arg != Some("--"))
+        .enumerate()
+        .filter(|(_, arg)| {
+            arg.is_some_and(|arg| {
+                arg.strip_prefix(flag)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('='))
+            })
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+fn starts_with_words(args: &[OsString], words: &str) -> bool {
+    let words: Vec<&str> = words.split_whitespace().collect();
+    args.len() >= words.len()
+        && args
+            .iter()
+            .zip(&words)
+            .all(|(arg, word)| arg.to_str() == Some(word))
+}
+
+fn replace_flag(mut args: Vec<OsString>, at: usize, deprecation: &Deprecation) -> Vec<OsString> {
+    let value = args[at]
+        .to_str()
+        .and_then(|arg| arg.strip_prefix(deprecation.old))
+        .unwrap_or_default()
+        .to_string();
+    args[at] = OsString::from(format!("{}{value}", deprecation.new));
+    args
+}
+
+fn replace_words(args: Vec<OsString>, at: usize, deprecation: &Deprecation) -> Vec<OsString> {
+    let old = deprecation.old.split_whitespace().count();
+    let mut replaced: Vec<OsString> = args.iter().take(at).cloned().collect();
+    replaced.extend(deprecation.new.split_whitespace().map(OsString::from));
+    replaced.extend(args.into_iter().skip(at + old));
+    replaced
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &[Deprecation] = &[
+        Deprecation {
+            old: "--force",
+            new: "--yes",
+            removal: "1.0.0",
+        },
+        Deprecation {
+            old: "--out",
+            new: "--output",
+            removal: "0.2.0",
+        },
+        Deprecation {
+            old: "cache purge",
+            new: "cache clear",
+            removal: "1.0.0",
+        },
+    ];
+
+    fn args(line: &str) -> Vec<OsString> {
+        line.split_whitespace().map(OsString::from).collect()
+    }
+
+    #[test]
+    fn rewrites_until_the_removal_version() -> anyhow::Result<()> {
+        let (rewritten, notices) = rewrite(
+            REGISTRY,
+            "0.1.0",
+            args("app --out=json cache purge --force -- --force"),
+            Some(2),
+        )?;
+        anyhow::ensure!(rewritten == args("app --output=json cache clear --yes -- --force"));
+        anyhow::ensure!(notices.len() == 3 && notices.iter().all(|notice| !notice.removed));
+        anyhow::ensure!(
+            notices[0].to_string()
+                == "`--force` is deprecated and will be removed in 1.0.0; use `--yes` instead"
+        );
+
+        // A subcommand path only matches in command position, a flag only whole.
+        let (rewritten, notices) =
+            rewrite(REGISTRY, "0.1.0", args("app run cache purge"), Some(1))?;
+        anyhow::ensure!(rewritten == args("app run cache purge") && notices.is_empty());
+        anyhow::ensure!(
+            rewrite(REGISTRY, "0.1.0", args("app --forced"), None)?
+                .1
+                .is_empty()
+        );
+
+        let (rewritten, notices) = rewrite(REGISTRY, "0.2.0", args("app --out json"), None)?;
+        anyhow::ensure!(rewritten == args("app --out json"));
+        anyhow::ensure!(
+            notices
+                .iter()
+                .map(ToString::to_string)
+                .eq(["`--out` was removed in 0.2.0; use `--output` instead".to_string()])
+        );
+        anyhow::ensure!(rewrite(REGISTRY, "next", args("app --force"), None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,173 @@
+//! Health report for the application directories.
+//!
+//! [`diagnose`] inspects the config, data, state, and cache directories and
+//! reports whether each exists and is writable, its owner and mode (unix),
+//! the free space on its filesystem, and how much space it uses. Nothing is
+//! created or written while inspecting.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::paths::{AppPaths, DirKind};
+
+/// Health of one application directory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DirHealth {
+    /// Which directory this is.
+    pub kind: DirKind,
+    /// Resolved path.
+    #[serde(with = "crate::pathfmt")]
+    pub path: PathBuf,
+    /// Whether the path exists and is a directory.
+    pub exists: bool,
+    /// Whether the current user can create files in it.
+    pub writable: bool,
+    /// Owning user name (or numeric uid if it has no name), where available.
+    pub owner: Option<String>,
+    /// Permission bits in octal, e.g. `0755`, where available.
+    pub mode: Option<String>,
+    /// Bytes used by files under the directory.
+    pub used_bytes: Option<u64>,
+    /// Bytes available to the current user on its filesystem, where available.
+    pub free_bytes: Option<u64>,
+}
+
+impl DirHealth {
+    /// Returns true if the directory exists and is writable.
+    #[must_use]
+    pub const fn is_healthy(&self) -> bool {
+        self.exists && self.writable
+    }
+}
+
+/// Inspect every application directory.
+#[must_use]
+pub fn diagnose(paths: &AppPaths) -> Vec<DirHealth> {
+    DirKind::ALL
+        .iter()
+        .map(|&kind| inspect(kind, paths.dir(kind)))
+        .collect()
+}
+
+/// Inspect a single directory.
+#[must_use]
+pub fn inspect(kind: DirKind, path: &Path) -> DirHealth {
+    let exists = path.is_dir();
+    let (owner, mode) = if exists {
+        owner_and_mode(path)
+    } else {
+        (None, None)
+    };
+    DirHealth {
+        kind,
+        path: path.to_path_buf(),
+        exists,
+        writable: exists && is_writable(path),
+        owner,
+        mode,
+        used_bytes: exists.then(|| disk_usage(path)),
+        free_bytes: nearest_existing(path).and_then(free_space),
+    }
+}
+
+/// Total size of regular files under `path`; unreadable entries are skipped
+/// and symlinks are not followed.
+#[must_use]
+pub fn disk_usage(path: &Path) -> u64 {
+    let Ok(entries) = fs::read_dir(path) else {
+        return 0;
+    };
+    entries
+        .filter_map(Result::ok)
+        .map(|entry| match entry.file_type() {
+            Ok(kind) if kind.is_dir() => disk_usage(&entry.path()),
+            Ok(kind) if kind.is_file() => entry.metadata().map_or(0, |meta| meta.len()),
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Free space is reported for the filesystem a missing directory would be
+/// created on.
+fn nearest_existing(path: &Path) -> Option<&Path> {
+    path.ancestors().find(|ancestor| ancestor.exists())
+}
+
+/// Lossless-or-zero conversion for platform-sized integers.
+#[cfg(unix)]
+fn to_u64<T: TryInto<u64>>(value: T) -> u64 {
+    value.try_into().unwrap_or_default()
+}
+
+#[cfg(unix)]
+pub(crate) fn is_writable(path: &Path) -> bool {
+    nix::unistd::access(path, nix::unistd::AccessFlags::W_OK).is_ok()
+}
+
+#[cfg(not(unix))]
+pub(crate) fn is_writable(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|meta| !meta.permissions().readonly())
+}
+
+#[cfg(unix)]
+fn owner_and_mode(path: &Path) -> (Option<String>, Option<String>) {
+    use std::os::unix::fs::MetadataExt as _;
+
+    let Ok(meta) = fs::metadata(path) else {
+        return (None, None);
+    };
+    (
+        Some(crate::paths::user_name(meta.uid())),
+        Some(format!("{:04o}", meta.mode() & 0o7777)),
+    )
+}
+
+#[cfg(not(unix))]
+const fn owner_and_mode(_path: &Path) -> (Option<String>, Option<String>) {
+    (None, None)
+}
+
+#[cfg(unix)]
+fn free_space(path: &Path) -> Option<u64> {
+    let stat = nix::sys::statvfs::statvfs(path).ok()?;
+    Some(to_u64(stat.blocks_available()).saturating_mul(to_u64(stat.fragment_size())))
+}
+
+#[cfg(not(unix))]
+const fn free_space(_path: &Path) -> Option<u64> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch::Scratch;
+
+    #[test]
+    fn inspect_reports_usage_and_missing_dirs() -> anyhow::Result<()> {
+        let root = Scratch::new()?;
+        fs::create_dir_all(root.join("nested"))?;
+        fs::write(root.join("a"), [0_u8; 10])?;
+        fs::write(root.join("nested").join("b"), [0_u8; 5])?;
+
+        let present = inspect(DirKind::Data, &root);
+        let missing = inspect(DirKind::Cache, &root.join("absent"));
+
+        anyhow::ensure!(present.is_healthy(), "scratch dir not healthy: {present:?}");
+        anyhow::ensure!(
+            present.used_bytes == Some(15),
+            "usage: {:?}",
+            present.used_bytes
+        );
+        anyhow::ensure!(
+            !missing.exists && !missing.writable && missing.used_bytes.is_none(),
+            "missing dir misreported: {missing:?}"
+        );
+        if cfg!(unix) {
+            anyhow::ensure!(present.mode.is_some() && present.free_bytes.is_some());
+        }
+        Ok(())
+    }
+}
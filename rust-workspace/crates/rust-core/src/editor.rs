@@ -0,0 +1,182 @@
+//! Edit the config file from a settings UI.
+//!
+//! A [`ConfigEditor`] holds the effective value of every setting (the file's
+//! values over the defaults, without environment overrides, which are not
+//! the file's to keep) as flattened dotted keys, the same shape the settings
+//! UI spec ([`crate::ui_spec`]) describes. Front ends read and set values by
+//! key, ask for the pending [`SettingChange`]s, and [save](ConfigEditor::save)
+//! once the result validates; saving rewrites only the changed keys, so the
+//! user's comments and layout survive. Nothing here depends on a toolkit: the
+//! TUI, a desktop shell, or a web form drive the same calls.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::config::{AppConfig, AppConfigExt as _, flatten};
+use crate::error::{CoreError, IoResultExt as _, Result, Span};
+use crate::sync::{SettingChange, Settings, apply, diff, render};
+
+/// Pending edits to one config file.
+#[derive(Debug, Clone)]
+pub struct ConfigEditor {
+    path: PathBuf,
+    text: String,
+    saved: Settings,
+    values: Settings,
+}
+
+impl ConfigEditor {
+    /// Load `config_file`; a missing file starts from the defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not valid TOML.
+    pub fn open(config_file: &Path) -> Result<Self> {
+        let text = match fs::read_to_string(config_file) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err).at_path("reading config file", config_file),
+        };
+        let file: toml::Table = toml::from_str(&text).map_err(|err| CoreError::ConfigParse {
+            path: config_file.to_path_buf(),
+            span: err
+                .span()
+                .map(|span| Span::locate(&text, span.start, span.end)),
+            message: err.message().to_string(),
+        })?;
+        let defaults = toml::Table::try_from(crate::config::defaults())
+            .map_err(|err| CoreError::Serialization(err.to_string()))?;
+        let mut values = Settings::new();
+        flatten("", &defaults, &mut values);
+        flatten("", &file, &mut values);
+        Ok(Self {
+            path: config_file.to_path_buf(),
+            text,
+            saved: values.clone(),
+            values,
+        })
+    }
+
+    /// The file being edited.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Current value of `key`, or `None` if it is unset.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&toml::Value> {
+        self.values.get(key)
+    }
+
+    /// All current values by dotted key.
+    #[must_use]
+    pub const fn values(&self) -> &Settings {
+        &self.values
+    }
+
+    /// Set `key` to `value`; checked by [`validate`](Self::validate).
+    pub fn set(&mut self, key: &str, value: toml::Value) {
+        self.values.insert(key.to_string(), value);
+    }
+
+    /// Remove `key` so its default (or nothing, for optional keys) applies.
+    pub fn unset(&mut self, key: &str) {
+        self.values.remove(key);
+    }
+
+    /// Forget unsaved edits.
+    pub fn revert(&mut self) {
+        self.values.clone_from(&self.saved);
+    }
+
+    /// Edits made since the file was opened or last saved.
+    #[must_use]
+    pub fn changes(&self) -> Vec<SettingChange> {
+        diff(&self.saved, &self.values)
+    }
+
+    /// The configuration the current values describe.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::Validation`] if a value has the wrong type or is
+    /// out of range.
+    pub fn validate(&self) -> Result<AppConfig> {
+        let text = render(&self.values)?;
+        let config: AppConfig = toml::from_str(&text)
+            .map_err(|err| CoreError::Validation(err.message().to_string()))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Validate, then write the changed keys to the file, keeping everything
+    /// else in it as it was. Returns the changes written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if validation fails or the file cannot be written.
+    pub fn save(&mut self) -> Result<Vec<SettingChange>> {
+        self.validate()?;
+        let changes = self.changes();
+        if changes.is_empty() {
+            return Ok(changes);
+        }
+        let text = apply(&self.text, &changes)?;
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).at_path("creating config directory", parent)?;
+        }
+        let partial = self.path.with_extension("toml.partial");
+        fs::write(&partial, &text).at_path("writing config file", &partial)?;
+        fs::rename(&partial, &self.path).at_path("replacing config file", &self.path)?;
+        self.text = text;
+        self.saved.clone_from(&self.values);
+        Ok(changes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch::Scratch;
+
+    #[test]
+    fn saves_only_changed_keys_and_keeps_comments() -> anyhow::Result<()> {
+        let dir = Scratch::new()?;
+        let path = dir.join("config.toml");
+        fs::write(&path, "# mine\nprofile = \"work\" # keep\n")?;
+
+        let mut editor = ConfigEditor::open(&path)?;
+        anyhow::ensure!(editor.get("runtime.timeout") == Some(&toml::Value::Integer(60)));
+        editor.set("runtime.timeout", toml::Value::Integer(90));
+        editor.set("profile", toml::Value::String("home".to_string()));
+        let written = editor.save()?;
+        anyhow::ensure!(written.len() == 2);
+        anyhow::ensure!(editor.changes().is_empty());
+
+        let text = fs::read_to_string(&path)?;
+        anyhow::ensure!(text.starts_with("# mine\nprofile = \"home\" # keep\n"));
+        anyhow::ensure!(text.contains("timeout = 90"));
+        anyhow::ensure!(!text.contains("fail_fast"));
+        Ok(())
+    }
+
+    #[test]
+    fn refuses_to_save_invalid_values() -> anyhow::Result<()> {
+        let scratch = Scratch::new()?;
+        let path = scratch.join("config").join("config.toml");
+        let mut editor = ConfigEditor::open(&path)?;
+        editor.set("backup.compression_level", toml::Value::Integer(99));
+        anyhow::ensure!(matches!(editor.save(), Err(CoreError::Validation(_))));
+        editor.set(
+            "backup.compression_level",
+            toml::Value::String("max".into()),
+        );
+        anyhow::ensure!(matches!(editor.validate(), Err(CoreError::Validation(_))));
+        anyhow::ensure!(!path.exists());
+        editor.revert();
+        anyhow::ensure!(editor.changes().is_empty());
+        Ok(())
+    }
+}
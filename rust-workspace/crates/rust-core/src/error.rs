@@ -1,7 +1,21 @@
 //! Error types for the core library.
+//!
+//! The configuration, path, lock, and temp-directory APIs return
+//! [`CoreError`] so library consumers can match on what went wrong; the
+//! binaries wrap it in `anyhow` at their boundary. I/O failures on a known
+//! path carry the path and the underlying [`std::io::Error`] as their source.
+//! Each variant has a stable [`ErrorCode`] (see [`CoreError::code`]).
+
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use thiserror::Error;
 
+use crate::codes::ErrorCode;
+use crate::input::Format;
+
 /// Core library error type.
 #[derive(Debug, Error)]
 pub enum CoreError {
@@ -9,18 +23,221 @@ pub enum CoreError {
     #[error("configuration error: {0}")]
     Config(String),
 
+    /// The config file is not valid TOML.
+    #[error("invalid config file {}: {message}", location(.path, .span.as_ref()))]
+    ConfigParse {
+        /// Config file that failed to parse.
+        path: PathBuf,
+        /// Where in the file the error is, when the parser reports it.
+        span: Option<Span>,
+        /// Parser message.
+        message: String,
+    },
+
+    /// A configuration value is out of range or of the wrong type.
+    #[error("invalid configuration: {0}")]
+    Validation(String),
+
+    /// An environment variable is missing or unusable.
+    #[error("environment variable {name}: {message}")]
+    EnvVar {
+        /// Variable name.
+        name: String,
+        /// What is wrong with it.
+        message: String,
+    },
+
     /// A path resolution or validation error.
     #[error("path error: {0}")]
     Path(String),
 
     /// An I/O error.
     #[error("IO error: {0}")]
-    Io(#[from] std::io::Error),
+    Io(#[from] io::Error),
+
+    /// An I/O operation on a specific path failed.
+    #[error("{action} {}", .path.display())]
+    PathIo {
+        /// What was being done, e.g. `creating data directory`.
+        action: &'static str,
+        /// Path the operation was on.
+        path: PathBuf,
+        /// Underlying I/O error.
+        #[source]
+        source: io::Error,
+    },
 
     /// A serialization or deserialization error.
     #[error("serialization error: {0}")]
     Serialization(String),
+
+    /// The current user lacks permission, e.g. on a directory owned by
+    /// another account.
+    #[error("permission error: {0}")]
+    Permission(String),
+
+    /// A resource is held by another process; retrying later may succeed.
+    #[error("{0}")]
+    Lock(String),
+
+    /// The request is valid but not supported here (platform, format, ...).
+    #[error("unsupported: {0}")]
+    Unsupported(String),
+
+    /// An operation exceeded its time limit.
+    #[error("timed out after {}s", .0.as_secs())]
+    Timeout(Duration),
+
+    /// An earlier multi-file operation was interrupted and its journal has
+    /// not been recovered.
+    #[error("unfinished operation: {0}")]
+    PendingJournal(String),
+
+    /// An operation was cancelled, e.g. by a shutdown signal, before it
+    /// finished.
+    #[error("cancelled: {0}")]
+    Cancelled(String),
+
+    /// Input data is not valid in the format it was read as.
+    #[error("invalid {format} in {}: {message}", input_location(.input, .span.as_ref()))]
+    InputParse {
+        /// Where the input came from: `stdin`, a path, or a URL.
+        input: String,
+        /// Format the input was parsed as.
+        format: Format,
+        /// Where in the input the error is, when the parser reports it.
+        span: Option<Span>,
+        /// Parser message.
+        message: String,
+    },
+
+    /// Config sync through git could not run: it is not set up, the remote
+    /// is ahead, or `git` failed.
+    #[error("sync error: {0}")]
+    Sync(String),
+
+    /// Input data is larger than the caller allows.
+    #[error("{input} is larger than the {limit}-byte input limit")]
+    InputTooLarge {
+        /// Where the input came from: `stdin`, a path, or a URL.
+        input: String,
+        /// Largest accepted size, in bytes.
+        limit: u64,
+    },
+}
+
+impl CoreError {
+    /// Stable code identifying this failure mode, e.g. `E0002`.
+    #[must_use]
+    pub const fn code(&self) -> ErrorCode {
+        match self {
+            Self::Config(_) => ErrorCode::Config,
+            Self::ConfigParse { .. } => ErrorCode::ConfigParse,
+            Self::Validation(_) => ErrorCode::Validation,
+            Self::EnvVar { .. } => ErrorCode::EnvVar,
+            Self::Path(_) => ErrorCode::Path,
+            Self::Io(_) => ErrorCode::Io,
+            Self::PathIo { .. } => ErrorCode::PathIo,
+            Self::Serialization(_) => ErrorCode::Serialization,
+            Self::Permission(_) => ErrorCode::Permission,
+            Self::Lock(_) => ErrorCode::Lock,
+            Self::Unsupported(_) => ErrorCode::Unsupported,
+            Self::Timeout(_) => ErrorCode::Timeout,
+            Self::PendingJournal(_) => ErrorCode::PendingJournal,
+            Self::Cancelled(_) => ErrorCode::Cancelled,
+            Self::InputParse { .. } => ErrorCode::InputParse,
+            Self::InputTooLarge { .. } => ErrorCode::InputTooLarge,
+            Self::Sync(_) => ErrorCode::Sync,
+        }
+    }
+}
+
+/// Location of an error within a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    /// Byte offset where the error starts.
+    pub start: usize,
+    /// Byte offset where the error ends (exclusive).
+    pub end: usize,
+    /// 1-based line of `start`.
+    pub line: usize,
+    /// 1-based column (in characters) of `start`.
+    pub column: usize,
+}
+
+impl Span {
+    /// Build a span for the byte range `start..end` of `text`.
+    #[must_use]
+    pub fn locate(text: &str, start: usize, end: usize) -> Self {
+        let before = text.get(..start).unwrap_or(text);
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        Self {
+            start,
+            end,
+            line: before.matches('\n').count() + 1,
+            column: before
+                .get(line_start..)
+                .map_or(0, |col| col.chars().count())
+                + 1,
+        }
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+fn location(path: &Path, span: Option<&Span>) -> String {
+    span.map_or_else(
+        || path.display().to_string(),
+        |span| format!("{}:{span}", path.display()),
+    )
+}
+
+fn input_location(input: &str, span: Option<&Span>) -> String {
+    span.map_or_else(|| input.to_string(), |span| format!("{input}:{span}"))
+}
+
+/// Attach a path and action to I/O errors as [`CoreError::PathIo`].
+pub(crate) trait IoResultExt<T> {
+    /// Map an I/O error to [`CoreError::PathIo`].
+    fn at_path(self, action: &'static str, path: &Path) -> Result<T>;
+}
+
+impl<T> IoResultExt<T> for io::Result<T> {
+    fn at_path(self, action: &'static str, path: &Path) -> Result<T> {
+        self.map_err(|source| CoreError::PathIo {
+            action,
+            path: path.to_path_buf(),
+            source,
+        })
+    }
 }
 
 /// Result type alias using `CoreError`.
 pub type Result<T> = std::result::Result<T, CoreError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn span_reports_line_and_column() {
+        let text = "profile = \"a\"\n[logging]\nlevel = ?\n";
+        let start = text.find('?').unwrap_or_default();
+        let span = Span::locate(text, start, start + 1);
+        assert_eq!((span.line, span.column), (3, 9));
+        let err = CoreError::ConfigParse {
+            path: PathBuf::from("config.toml"),
+            span: Some(span),
+            message: "invalid string".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "invalid config file config.toml:3:9: invalid string"
+        );
+        assert_eq!(err.code().as_str(), "E0002");
+    }
+}
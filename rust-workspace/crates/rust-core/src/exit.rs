@@ -0,0 +1,295 @@
+//! Process exit codes classified by failure type.
+//!
+//! Binaries exit with an [`ExitCode`] derived from the error that ended the
+//! run instead of a blanket `1`, so wrapper scripts can tell a bad config
+//! from a held lock or an I/O failure. Codes follow `sysexits.h` where one
+//! fits, plus the shell conventions for timeouts (124) and SIGINT (130).
+//! [`ErrorReport`] packages the same classification as a serializable object
+//! for callers that parse errors instead of reading them, together with the
+//! stable [`ErrorCode`] when the failure is a known one.
+
+use std::fmt;
+use std::io;
+
+use serde::Serialize;
+
+use crate::codes::ErrorCode;
+use crate::error::CoreError;
+
+/// Exit code for a process run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExitCode {
+    /// The command succeeded.
+    Success,
+    /// An unclassified failure.
+    Failure,
+    /// The configuration could not be loaded or is invalid.
+    Config,
+    /// The command line was invalid.
+    Usage,
+    /// Input data could not be parsed or serialized.
+    Data,
+    /// A required input file or directory does not exist.
+    NoInput,
+    /// An internal invariant was violated.
+    Software,
+    /// An I/O operation failed.
+    Io,
+    /// A temporary condition (such as a held lock); retrying may succeed.
+    TempFail,
+    /// Permission was denied.
+    NoPerm,
+    /// The operation exceeded its time limit.
+    Timeout,
+    /// The run was interrupted (SIGINT / Ctrl-C).
+    Interrupted,
+}
+
+impl ExitCode {
+    /// Every exit code, in numeric order.
+    pub const ALL: [Self; 12] = [
+        Self::Success,
+        Self::Failure,
+        Self::Config,
+        Self::Usage,
+        Self::Data,
+        Self::NoInput,
+        Self::Software,
+        Self::Io,
+        Self::TempFail,
+        Self::NoPerm,
+        Self::Timeout,
+        Self::Interrupted,
+    ];
+
+    /// Numeric process exit code.
+    #[must_use]
+    pub const fn code(self) -> u8 {
+        match self {
+            Self::Success => 0,
+            Self::Failure => 1,
+            Self::Config => 2,
+            Self::Usage => 64,
+            Self::Data => 65,
+            Self::NoInput => 66,
+            Self::Software => 70,
+            Self::Io => 74,
+            Self::TempFail => 75,
+            Self::NoPerm => 77,
+            Self::Timeout => 124,
+            Self::Interrupted => 130,
+        }
+    }
+
+    /// One-line description for help text and docs.
+    #[must_use]
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Failure => "unclassified failure",
+            Self::Config => "configuration error",
+            Self::Usage => "invalid command-line usage",
+            Self::Data => "malformed input data",
+            Self::NoInput => "input file or directory not found",
+            Self::Software => "internal error",
+            Self::Io => "I/O error",
+            Self::TempFail => "temporary failure (e.g. lock held); retry",
+            Self::NoPerm => "permission denied",
+            Self::Timeout => "timed out",
+            Self::Interrupted => "interrupted",
+        }
+    }
+
+    /// Generic remediation hint for this class of failure, if there is one.
+    #[must_use]
+    pub const fn hint(self) -> Option<&'static str> {
+        match self {
+            Self::Config => Some("check the config file and environment overrides"),
+            Self::Usage => Some("run with --help for usage"),
+            Self::NoInput => Some("check that the path exists"),
+            Self::TempFail => Some("another instance may be running; retry or pass --wait"),
+            Self::NoPerm => Some("check ownership and permissions of the path"),
+            Self::Timeout => Some("raise --timeout or runtime.timeout"),
+            Self::Success
+            | Self::Failure
+            | Self::Data
+            | Self::Software
+            | Self::Io
+            | Self::Interrupted => None,
+        }
+    }
+
+    /// Classify an error by walking its cause chain, outermost first.
+    ///
+    /// The first cause with a known type decides the code; errors with no
+    /// recognizable cause map to [`ExitCode::Failure`].
+    #[must_use]
+    pub fn from_error(err: &anyhow::Error) -> Self {
+        err.chain().find_map(classify).unwrap_or(Self::Failure)
+    }
+
+    /// Classify an I/O error by its kind.
+    #[must_use]
+    pub const fn from_io_kind(kind: io::ErrorKind) -> Self {
+        match kind {
+            io::ErrorKind::NotFound => Self::NoInput,
+            io::ErrorKind::PermissionDenied | io::ErrorKind::ReadOnlyFilesystem => Self::NoPerm,
+            io::ErrorKind::TimedOut => Self::Timeout,
+            io::ErrorKind::Interrupted => Self::Interrupted,
+            io::ErrorKind::WouldBlock => Self::TempFail,
+            _ => Self::Io,
+        }
+    }
+}
+
+impl fmt::Display for ExitCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.code(), self.description())
+    }
+}
+
+impl From<ExitCode> for std::process::ExitCode {
+    fn from(code: ExitCode) -> Self {
+        Self::from(code.code())
+    }
+}
+
+/// Machine-readable description of a failed run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ErrorReport {
+    /// Numeric exit code the process ends with.
+    pub code: u8,
+    /// Failure class, e.g. `config` or `temp_fail`.
+    pub kind: ExitCode,
+    /// Stable code of a known failure mode, e.g. `E0010`; see `explain`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<ErrorCode>,
+    /// Outermost error message.
+    pub message: String,
+    /// Underlying causes, outermost first, excluding `message`.
+    pub chain: Vec<String>,
+    /// Suggested remediation, if one applies to this class of failure.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+}
+
+impl ErrorReport {
+    /// Build a report by classifying `err` and flattening its cause chain.
+    #[must_use]
+    pub fn from_error(err: &anyhow::Error) -> Self {
+        let kind = ExitCode::from_error(err);
+        Self {
+            code: kind.code(),
+            kind,
+            error_code: ErrorCode::from_error(err),
+            message: err.to_string(),
+            chain: err.chain().skip(1).map(ToString::to_string).collect(),
+            hint: kind.hint().map(str::to_string),
+        }
+    }
+}
+
+fn classify(cause: &(dyn std::error::Error + 'static)) -> Option<ExitCode> {
+    if let Some(core) = cause.downcast_ref::<CoreError>() {
+        return Some(match core {
+            CoreError::Config(_)
+            | CoreError::ConfigParse { .. }
+            | CoreError::Validation(_)
+            | CoreError::EnvVar { .. } => ExitCode::Config,
+            CoreError::Path(_) | CoreError::Unsupported(_) => ExitCode::Usage,
+            CoreError::Io(source) | CoreError::PathIo { source, .. } => {
+                ExitCode::from_io_kind(source.kind())
+            }
+            CoreError::Serialization(_)
+            | CoreError::InputParse { .. }
+            | CoreError::InputTooLarge { .. } => ExitCode::Data,
+            CoreError::Permission(_) => ExitCode::NoPerm,
+            CoreError::Lock(_) => ExitCode::TempFail,
+            CoreError::Timeout(_) => ExitCode::Timeout,
+            CoreError::PendingJournal(_) | CoreError::Sync(_) => ExitCode::Failure,
+            CoreError::Cancelled(_) => ExitCode::Interrupted,
+        });
+    }
+    if cause.is::<config::ConfigError>() {
+        return Some(ExitCode::Config);
+    }
+    if let Some(io) = cause.downcast_ref::<io::Error>() {
+        return Some(ExitCode::from_io_kind(io.kind()));
+    }
+    if cause.is::<serde_json::Error>()
+        || cause.is::<serde_yaml::Error>()
+        || cause.is::<toml::de::Error>()
+        || cause.is::<toml::ser::Error>()
+    {
+        return Some(ExitCode::Data);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use anyhow::Context as _;
+
+    use super::*;
+
+    #[test]
+    fn classifies_through_context_layers() {
+        let not_found: anyhow::Result<()> =
+            Err(io::Error::from(io::ErrorKind::NotFound)).context("reading input");
+        let locked = anyhow::Error::new(CoreError::Lock("held".to_string()));
+        let timeout =
+            anyhow::Error::new(CoreError::Timeout(Duration::from_secs(5))).context("running task");
+        let parse = serde_json::from_str::<u8>("x")
+            .map_err(anyhow::Error::from)
+            .context("parsing");
+        let codes = [
+            not_found.err().map(|err| ExitCode::from_error(&err)),
+            Some(ExitCode::from_error(&locked)),
+            Some(ExitCode::from_error(&timeout)),
+            parse.err().map(|err| ExitCode::from_error(&err)),
+            Some(ExitCode::from_error(&anyhow::anyhow!("plain"))),
+        ];
+        assert_eq!(
+            codes,
+            [
+                Some(ExitCode::NoInput),
+                Some(ExitCode::TempFail),
+                Some(ExitCode::Timeout),
+                Some(ExitCode::Data),
+                Some(ExitCode::Failure),
+            ]
+        );
+    }
+
+    #[test]
+    fn report_flattens_chain() -> anyhow::Result<()> {
+        let err = anyhow::Error::new(CoreError::Lock("held by pid 7".to_string()))
+            .context("acquiring lock")
+            .context("starting run");
+        let report = ErrorReport::from_error(&err);
+        anyhow::ensure!(report.code == 75 && report.kind == ExitCode::TempFail);
+        anyhow::ensure!(report.message == "starting run");
+        anyhow::ensure!(
+            report.chain == ["acquiring lock", "held by pid 7"],
+            "{:?}",
+            report.chain
+        );
+        let json = serde_json::to_value(&report)?;
+        anyhow::ensure!(
+            json["kind"] == "temp_fail"
+                && json["error_code"] == "E0010"
+                && json["hint"].is_string(),
+            "{json}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn codes_are_unique_and_sorted() {
+        let codes: Vec<u8> = ExitCode::ALL.iter().map(|code| code.code()).collect();
+        assert!(codes.windows(2).all(|pair| pair[0] < pair[1]), "{codes:?}");
+    }
+}
@@ -0,0 +1,294 @@
+//! Render the effective configuration for deployment targets.
+//!
+//! Every setting becomes the `<PREFIX>__SECTION__KEY` environment variable
+//! that [`AppConfigExt::load`](crate::AppConfigExt::load) reads back, so the output can be dropped into a
+//! Kubernetes `ConfigMap`, a `docker run --env-file` file, or a systemd
+//! `EnvironmentFile=`. Secret-bearing keys ([`is_secret_key`]) are never
+//! written as values: they become a `secretKeyRef`, a pass-through from the
+//! host environment, or a pointer to a separate root-only file. Arrays are
+//! left out because the environment layer cannot express them.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use serde::Serialize;
+
+use crate::APP_NAME;
+use crate::config::{AppConfig, env_var_name, flatten, is_secret_key};
+use crate::error::{CoreError, Result};
+
+/// Output format for [`export`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// A Kubernetes `ConfigMap` manifest (YAML) for `envFrom`.
+    K8sConfigMap,
+    /// A `docker run --env-file` file.
+    DockerEnv,
+    /// A systemd `EnvironmentFile=` file.
+    SystemdEnv,
+}
+
+/// One exported setting.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ExportEntry {
+    /// Dotted config key, e.g. `logging.level`.
+    pub key: String,
+    /// Environment variable that sets it.
+    pub env: String,
+    /// Rendered value; `None` for secret-bearing keys, which are exported
+    /// as references.
+    pub value: Option<String>,
+}
+
+/// Settings of `config` as environment entries, plus the dotted keys that
+/// cannot be exported (arrays).
+///
+/// # Errors
+///
+/// Returns [`CoreError::Serialization`] if the config cannot be converted to
+/// TOML values.
+pub fn entries(config: &AppConfig) -> Result<(Vec<ExportEntry>, Vec<String>)> {
+    let table = toml::Table::try_from(config)
+        .map_err(|err| CoreError::Serialization(format!("effective config: {err}")))?;
+    let mut flat = BTreeMap::new();
+    flatten("", &table, &mut flat);
+    let mut entries = Vec::new();
+    let mut skipped = Vec::new();
+    for (key, value) in flat {
+        if key == "$schema" {
+            continue;
+        }
+        let value = match value {
+            toml::Value::String(text) => text,
+            toml::Value::Array(_) | toml::Value::Table(_) => {
+                skipped.push(key);
+                continue;
+            }
+            scalar => scalar.to_string(),
+        };
+        entries.push(ExportEntry {
+            env: env_var_name(&key),
+            value: (!is_secret_key(&key)).then_some(value),
+            key,
+        });
+    }
+    Ok((entries, skipped))
+}
+
+/// Render the effective `config` in `format`.
+///
+/// # Errors
+///
+/// Returns [`CoreError::Serialization`] if the config cannot be converted,
+/// or [`CoreError::Unsupported`] if a value contains a newline, which env
+/// files cannot hold.
+pub fn export(config: &AppConfig, format: ExportFormat) -> Result<String> {
+    let (entries, skipped) = entries(config)?;
+    let mut out = match format {
+        ExportFormat::K8sConfigMap => k8s_configmap(&entries)?,
+        ExportFormat::DockerEnv => docker_env(&entries)?,
+        ExportFormat::SystemdEnv => systemd_env(&entries)?,
+    };
+    if !skipped.is_empty() {
+        let _ = writeln!(
+            out,
+            "# not exported (lists cannot be set from the environment): {}",
+            skipped.join(", ")
+        );
+    }
+    Ok(out)
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConfigMap<'a> {
+    api_version: &'static str,
+    kind: &'static str,
+    metadata: Metadata,
+    data: BTreeMap<&'a str, &'a str>,
+}
+
+#[derive(Serialize)]
+struct Metadata {
+    name: String,
+}
+
+fn k8s_configmap(entries: &[ExportEntry]) -> Result<String> {
+    let manifest = ConfigMap {
+        api_version: "v1",
+        kind: "ConfigMap",
+        metadata: Metadata {
+            name: format!("{APP_NAME}-config"),
+        },
+        data: entries
+            .iter()
+            .filter_map(|entry| Some((entry.env.as_str(), entry.value.as_deref()?)))
+            .collect(),
+    };
+    let yaml = serde_yaml::to_string(&manifest)
+        .map_err(|err| CoreError::Serialization(format!("ConfigMap: {err}")))?;
+    let mut out = format!(
+        "# Effective {APP_NAME} configuration. Load it into a container with\n\
+         # envFrom: [{{configMapRef: {{name: {APP_NAME}-config}}}}]\n{yaml}"
+    );
+    let secrets: Vec<&ExportEntry> = entries.iter().filter(|e| e.value.is_none()).collect();
+    if !secrets.is_empty() {
+        let _ = writeln!(
+            out,
+            "# Secret-bearing keys are not in the ConfigMap; add them to the container's env:"
+        );
+        for entry in secrets {
+            let _ = writeln!(
+                out,
+                "#   - name: {}\n#     valueFrom:\n#       secretKeyRef: {{name: {APP_NAME}-secrets, key: {}}}",
+                entry.env, entry.env
+            );
+        }
+    }
+    Ok(out)
+}
+
+fn docker_env(entries: &[ExportEntry]) -> Result<String> {
+    let mut out = format!(
+        "# Effective {APP_NAME} configuration for `docker run --env-file`.\n\
+         # Secret-bearing variables are listed without a value; Docker passes\n\
+         # them through from the environment of the `docker run` command.\n"
+    );
+    for entry in entries {
+        match &entry.value {
+            Some(value) => {
+                let _ = writeln!(out, "{}={}", entry.env, single_line(entry, value)?);
+            }
+            None => {
+                let _ = writeln!(out, "{}", entry.env);
+            }
+        }
+    }
+    Ok(out)
+}
+
+fn systemd_env(entries: &[ExportEntry]) -> Result<String> {
+    let mut out = format!("# Effective {APP_NAME} configuration for systemd's EnvironmentFile=.\n");
+    let mut secrets = Vec::new();
+    for entry in entries {
+        match &entry.value {
+            Some(value) => {
+                let _ = writeln!(
+                    out,
+                    "{}={}",
+                    entry.env,
+                    systemd_quote(single_line(entry, value)?)
+                );
+            }
+            None => secrets.push(entry.env.as_str()),
+        }
+    }
+    if !secrets.is_empty() {
+        let _ = writeln!(
+            out,
+            "# Secret-bearing variables are not included. Set {} in a root-only file\n\
+             # loaded with a second EnvironmentFile=/etc/{APP_NAME}/secrets.env (mode 0600).",
+            secrets.join(", ")
+        );
+    }
+    Ok(out)
+}
+
+fn single_line<'a>(entry: &ExportEntry, value: &'a str) -> Result<&'a str> {
+    if value.contains(['\n', '\r']) {
+        return Err(CoreError::Unsupported(format!(
+            "{} contains a newline, which env files cannot hold",
+            entry.key
+        )));
+    }
+    Ok(value)
+}
+
+/// Double-quote values that systemd would otherwise split or strip.
+fn systemd_quote(value: &str) -> String {
+    if !value.is_empty()
+        && !value
+            .contains(|c: char| c.is_whitespace() || matches!(c, '"' | '\'' | '\\' | '#' | '$'))
+    {
+        return value.to_string();
+    }
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('$', "\\$");
+    format!("\"{escaped}\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_use_env_names_and_skip_lists() -> anyhow::Result<()> {
+        let mut config = AppConfig::default();
+        config.backup.exclude = vec!["state/*.log".to_string()];
+        let (entries, skipped) = entries(&config)?;
+        let level = entries
+            .iter()
+            .find(|entry| entry.key == "logging.level")
+            .ok_or_else(|| anyhow::anyhow!("logging.level missing"))?;
+        anyhow::ensure!(level.env == "RUST_WORKSPACE__LOGGING__LEVEL", "{level:?}");
+        anyhow::ensure!(level.value.as_deref() == Some("info"), "{level:?}");
+        anyhow::ensure!(
+            skipped
+                == [
+                    "backup.exclude",
+                    "health.probes",
+                    "mcp.disabled_tools",
+                    "security.redact"
+                ],
+            "{skipped:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn secrets_are_references_in_every_format() -> anyhow::Result<()> {
+        let entries = [
+            ExportEntry {
+                key: "profile".to_string(),
+                env: env_var_name("profile"),
+                value: Some("two words".to_string()),
+            },
+            ExportEntry {
+                key: "remote.api_token".to_string(),
+                env: env_var_name("remote.api_token"),
+                value: None,
+            },
+        ];
+        let docker = docker_env(&entries)?;
+        anyhow::ensure!(
+            docker
+                .contains("RUST_WORKSPACE__PROFILE=two words\nRUST_WORKSPACE__REMOTE__API_TOKEN\n"),
+            "{docker}"
+        );
+        let systemd = systemd_env(&entries)?;
+        anyhow::ensure!(
+            systemd.contains("RUST_WORKSPACE__PROFILE=\"two words\"\n"),
+            "{systemd}"
+        );
+        anyhow::ensure!(!systemd.contains("API_TOKEN="), "{systemd}");
+        let k8s = k8s_configmap(&entries)?;
+        let manifest: serde_yaml::Value = serde_yaml::from_str(&k8s)?;
+        anyhow::ensure!(manifest["kind"] == "ConfigMap", "{k8s}");
+        anyhow::ensure!(
+            manifest["data"]["RUST_WORKSPACE__PROFILE"] == "two words",
+            "{k8s}"
+        );
+        anyhow::ensure!(
+            manifest["data"]
+                .get("RUST_WORKSPACE__REMOTE__API_TOKEN")
+                .is_none()
+        );
+        anyhow::ensure!(
+            k8s.contains("secretKeyRef: {name: rust-workspace-secrets"),
+            "{k8s}"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+//! Which optional subsystems a build contains and which the configuration
+//! turns on, for bug reports and fleet inventory (`debug features`).
+//!
+//! A subsystem is either compiled in or not (a cargo feature or target
+//! platform), and a compiled-in one may still be off in the configuration.
+//! New optional subsystems add an entry to [`detect`].
+
+use serde::Serialize;
+
+use crate::config::{AppConfig, HealthProbe, RelabelMode};
+
+/// One optional subsystem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct FeatureStatus {
+    /// Stable identifier, e.g. `pty`.
+    pub name: &'static str,
+    /// What the subsystem does.
+    pub summary: &'static str,
+    /// Whether this build contains it.
+    pub compiled: bool,
+    /// Whether it is active: compiled in and switched on.
+    pub enabled: bool,
+    /// What switches it: a cargo feature, platform, or config key.
+    pub control: &'static str,
+}
+
+/// The build itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct BuildInfo {
+    /// Workspace version.
+    pub version: &'static str,
+    /// Target operating system, e.g. `linux`.
+    pub os: &'static str,
+    /// Target architecture, e.g. `x86_64`.
+    pub arch: &'static str,
+    /// Whether debug assertions are on (a development build).
+    pub debug: bool,
+}
+
+/// Describe this build.
+#[must_use]
+pub const fn build_info() -> BuildInfo {
+    BuildInfo {
+        version: env!("CARGO_PKG_VERSION"),
+        os: std::env::consts::OS,
+        arch: std::env::consts::ARCH,
+        debug: cfg!(debug_assertions),
+    }
+}
+
+/// Report every optional subsystem under `config`.
+#[must_use]
+pub fn detect(config: &AppConfig) -> Vec<FeatureStatus> {
+    let linux = cfg!(target_os = "linux");
+    vec![
+        FeatureStatus {
+            name: "pty",
+            summary: "run `pty = true` tasks under a pseudo-terminal and keep a clean transcript",
+            compiled: cfg!(feature = "pty"),
+            enabled: cfg!(feature = "pty"),
+            control: "cargo feature `pty`",
+        },
+        FeatureStatus {
+            name: "selinux-relabel",
+            summary: "label newly created system directories for SELinux",
+            compiled: linux,
+            enabled: linux && config.security.selinux.relabel != RelabelMode::Off,
+            control: "security.selinux.relabel",
+        },
+        FeatureStatus {
+            name: "socket-activation",
+            summary: "serve on sockets passed by systemd (rust-api)",
+            compiled: linux,
+            enabled: linux,
+            control: "Linux; used when LISTEN_FDS is set",
+        },
+        FeatureStatus {
+            name: "log-file",
+            summary: "write logs to a file as well as stderr",
+            compiled: true,
+            enabled: config.logging.file.is_some(),
+            control: "logging.file",
+        },
+        FeatureStatus {
+            name: "daemon-probe",
+            summary: "healthcheck probes the daemon's GET /health",
+            compiled: true,
+            enabled: config.health.probes.contains(&HealthProbe::Daemon),
+            control: "health.probes",
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn follows_config_switches() -> anyhow::Result<()> {
+        let find = |features: &[FeatureStatus], name: &str| {
+            features
+                .iter()
+                .find(|feature| feature.name == name)
+                .map(|feature| feature.enabled)
+        };
+        let mut config = AppConfig::default();
+        let defaults = detect(&config);
+        anyhow::ensure!(find(&defaults, "log-file") == Some(false));
+        anyhow::ensure!(find(&defaults, "daemon-probe") == Some(true));
+        anyhow::ensure!(find(&defaults, "pty") == Some(cfg!(feature = "pty")));
+
+        config.logging.file = Some("app.log".to_string());
+        config.health.probes = vec![HealthProbe::Config];
+        config.security.selinux.relabel = RelabelMode::Off;
+        let changed = detect(&config);
+        anyhow::ensure!(find(&changed, "log-file") == Some(true));
+        anyhow::ensure!(find(&changed, "daemon-probe") == Some(false));
+        anyhow::ensure!(find(&changed, "selinux-relabel") == Some(false));
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+//! The flat form of a configuration: one `dotted.key = value` line per
+//! setting, sorted by key, like `git config --list`.
+//!
+//! Values are written as TOML values, and keys that are not bare TOML keys
+//! have the offending parts quoted (`logging.filters."rust_core::task"`), so
+//! a flat listing greps and diffs well across machines and is at the same
+//! time a valid TOML document: reading it back needs nothing beyond a TOML
+//! parser. Unset optional settings and empty tables have no line.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use serde::Serialize;
+
+use crate::config::flatten;
+use crate::error::{CoreError, Result};
+use crate::sync::Settings;
+
+/// The settings in `value` (a config, or any table of settings) by dotted
+/// key, leaving out unset ones.
+///
+/// # Errors
+///
+/// Returns [`CoreError::Serialization`] if `value` is not a table or holds
+/// something TOML cannot express.
+pub fn settings(value: &impl Serialize) -> Result<Settings> {
+    fn strip(value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(object) => {
+                object.retain(|_, child| !child.is_null());
+                object.values_mut().for_each(strip);
+            }
+            serde_json::Value::Array(items) => items.iter_mut().for_each(strip),
+            _ => {}
+        }
+    }
+    let mut value = serde_json::to_value(value)
+        .map_err(|err| CoreError::Serialization(format!("settings: {err}")))?;
+    strip(&mut value);
+    let table = toml::Table::try_from(value)
+        .map_err(|err| CoreError::Serialization(format!("settings: {err}")))?;
+    let mut settings = Settings::new();
+    flatten("", &table, &mut settings);
+    Ok(settings)
+}
+
+/// `value` in the flat form, one line per setting.
+///
+/// # Errors
+///
+/// Returns the errors of [`settings`].
+pub fn render(value: &impl Serialize) -> Result<String> {
+    let lines: BTreeMap<String, String> = settings(value)?
+        .into_iter()
+        .map(|(key, value)| (quote_key(&key), value.to_string()))
+        .collect();
+    let mut text = String::new();
+    for (key, value) in lines {
+        let _ = writeln!(text, "{key} = {value}");
+    }
+    Ok(text)
+}
+
+/// `key` with each part that is not a bare TOML key quoted.
+fn quote_key(key: &str) -> String {
+    let bare = |part: &str| {
+        !part.is_empty()
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    };
+    key.split('.')
+        .map(|part| {
+            if bare(part) {
+                part.to_string()
+            } else {
+                toml::Value::String(part.to_string()).to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{AppConfig, LogLevel};
+
+    #[test]
+    fn lines_are_sorted_toml_that_reads_back() -> anyhow::Result<()> {
+        let mut config = AppConfig::default();
+        config.runtime.timeout = Some(90);
+        config
+            .logging
+            .filters
+            .insert("rust_core::task".to_string(), LogLevel::Debug);
+        let text = render(&config)?;
+        let lines: Vec<&str> = text.lines().collect();
+        anyhow::ensure!(lines.is_sorted(), "{text}");
+        anyhow::ensure!(lines.contains(&"runtime.timeout = 90"));
+        anyhow::ensure!(lines.contains(&r#"logging.filters."rust_core::task" = "debug""#));
+        // Unset optional settings have no line.
+        anyhow::ensure!(!text.contains("logging.file ="));
+
+        let back: AppConfig = toml::from_str(&text)?;
+        anyhow::ensure!(settings(&back)? == settings(&config)?);
+        Ok(())
+    }
+}
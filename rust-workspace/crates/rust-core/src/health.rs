@@ -0,0 +1,447 @@
+//! Liveness probes for container `HEALTHCHECK`s and supervisor watchdogs.
+//!
+//! [`check`] loads the configuration, then runs the selected probes: does
+//! the config validate, can a file be created in the state directory, and
+//! does the daemon answer `GET /health`. Everything runs on a worker thread
+//! under one deadline, so a hung mount or an unresponsive daemon turns into
+//! a failed probe instead of a hung health check. Nothing is created except
+//! the probe file, which is removed again.
+
+use std::fs;
+use std::io::{self, Write as _};
+use std::net::{TcpStream, ToSocketAddrs as _};
+use std::path::Path;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::config::{AppConfig, AppConfigExt as _, HealthProbe};
+use crate::paths::AppPaths;
+
+/// Daemon address probed by default: the API server's default listener.
+pub use rust_core_types::DEFAULT_HEALTH_DAEMON as DEFAULT_DAEMON;
+/// Default time limit for a whole check, in milliseconds.
+pub use rust_core_types::DEFAULT_HEALTH_TIMEOUT_MS as DEFAULT_TIMEOUT_MS;
+
+/// What to check and how long to allow.
+#[derive(Debug, Clone)]
+pub struct HealthRequest {
+    /// Discovered paths, before config overrides are applied.
+    pub paths: AppPaths,
+    /// Read the configuration from the environment only (container mode, or
+    /// no config file wanted).
+    pub from_env: bool,
+    /// Probes to run instead of `health.probes`.
+    pub probes: Option<Vec<HealthProbe>>,
+    /// Daemon address instead of `health.daemon`.
+    pub daemon: Option<String>,
+    /// Time limit instead of `health.timeout_ms`.
+    pub timeout: Option<Duration>,
+}
+
+/// Result of one probe.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProbeResult {
+    /// Which probe ran.
+    pub probe: HealthProbe,
+    /// Whether it passed.
+    pub ok: bool,
+    /// What was checked, or why it failed.
+    pub detail: String,
+}
+
+/// Outcome of [`check`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HealthReport {
+    /// `healthy` or `unhealthy`.
+    pub status: &'static str,
+    /// `ok`, or the first failure as `probe: detail`.
+    pub reason: String,
+    /// Wall time of the whole check.
+    pub elapsed_ms: u64,
+    /// Every selected probe, in the order they ran.
+    pub probes: Vec<ProbeResult>,
+}
+
+impl HealthReport {
+    /// Whether every selected probe passed.
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        self.status == "healthy"
+    }
+}
+
+enum Event {
+    /// Config loaded (or not); the selected probes and time limit are known.
+    Planned {
+        probes: Vec<HealthProbe>,
+        timeout: Duration,
+    },
+    Done(ProbeResult),
+}
+
+/// Run the probes of `request` and report on them. Never fails: every
+/// problem, including running out of time, becomes a failed probe.
+#[must_use]
+pub fn check(request: HealthRequest) -> HealthReport {
+    let start = Instant::now();
+    let default_timeout = request
+        .timeout
+        .unwrap_or(Duration::from_millis(DEFAULT_TIMEOUT_MS));
+    let (sender, receiver) = mpsc::channel();
+    let spawned = std::thread::Builder::new()
+        .name("healthcheck".to_string())
+        .spawn(move || run(request, start, &sender));
+    // Until the worker has loaded the config, that is all that can hang.
+    let mut planned = vec![HealthProbe::Config];
+    let mut deadline = start + default_timeout;
+    let mut results = Vec::new();
+    if let Err(err) = spawned {
+        results.push(failed(
+            HealthProbe::Config,
+            format!("cannot start probe thread: {err}"),
+        ));
+    } else {
+        while results.len() < planned.len() {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match receiver.recv_timeout(remaining) {
+                Ok(Event::Planned { probes, timeout }) => {
+                    planned = probes;
+                    deadline = start + timeout;
+                }
+                Ok(Event::Done(result)) => results.push(result),
+                Err(_) => break,
+            }
+        }
+    }
+    let limit = deadline.saturating_duration_since(start);
+    for probe in planned.iter().skip(results.len()) {
+        results.push(failed(
+            *probe,
+            format!("no answer within {} ms", limit.as_millis()),
+        ));
+    }
+    let reason = results.iter().find(|result| !result.ok).map_or_else(
+        || "ok".to_string(),
+        |result| format!("{}: {}", result.probe.as_str(), result.detail),
+    );
+    let healthy = results.iter().all(|result| result.ok);
+    HealthReport {
+        status: if healthy { "healthy" } else { "unhealthy" },
+        reason,
+        elapsed_ms: u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX),
+        probes: results,
+    }
+}
+
+/// Worker: load the config, announce the plan, then run the probes in order.
+fn run(request: HealthRequest, start: Instant, events: &mpsc::Sender<Event>) {
+    let loaded = if request.from_env {
+        AppConfig::from_env()
+    } else {
+        AppConfig::load_from_path(&request.paths.config_file)
+    };
+    let (config, config_result) = match loaded {
+        Ok(config) => {
+            let detail = if request.from_env {
+                "environment configuration is valid".to_string()
+            } else if request.paths.config_file.exists() {
+                format!("{} is valid", request.paths.config_file.display())
+            } else {
+                "no config file; defaults are valid".to_string()
+            };
+            (config, passed(HealthProbe::Config, detail))
+        }
+        Err(err) => (
+            crate::config::defaults(),
+            failed(HealthProbe::Config, err.to_string()),
+        ),
+    };
+    let mut probes = request
+        .probes
+        .unwrap_or_else(|| config.health.probes.clone());
+    probes.sort_unstable();
+    probes.dedup();
+    let timeout = request
+        .timeout
+        .unwrap_or_else(|| Duration::from_millis(config.health.timeout_ms));
+    let daemon = request
+        .daemon
+        .unwrap_or_else(|| config.health.daemon.clone());
+    let deadline = start + timeout;
+    let planned = Event::Planned {
+        probes: probes.clone(),
+        timeout,
+    };
+    if events.send(planned).is_err() {
+        return;
+    }
+    let mut config_result = Some(config_result);
+    for probe in probes {
+        let result = match probe {
+            HealthProbe::Config => match config_result.take() {
+                Some(result) => result,
+                None => continue,
+            },
+            HealthProbe::State => match request.paths.clone().apply_overrides(&config) {
+                Ok(paths) => probe_state(&paths.state_dir),
+                Err(err) => failed(HealthProbe::State, err.to_string()),
+            },
+            HealthProbe::Daemon => probe_daemon(&daemon, deadline),
+        };
+        if events.send(Event::Done(result)).is_err() {
+            return;
+        }
+    }
+}
+
+const fn passed(probe: HealthProbe, detail: String) -> ProbeResult {
+    ProbeResult {
+        probe,
+        ok: true,
+        detail,
+    }
+}
+
+const fn failed(probe: HealthProbe, detail: String) -> ProbeResult {
+    ProbeResult {
+        probe,
+        ok: false,
+        detail,
+    }
+}
+
+/// Create, write, and remove a file in `state_dir`; never create the
+/// directory itself.
+fn probe_state(state_dir: &Path) -> ProbeResult {
+    let file = state_dir.join(format!(".healthcheck-{}", std::process::id()));
+    let attempt = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&file)
+        .and_then(|mut handle| handle.write_all(b"ok\n"));
+    let removed = fs::remove_file(&file);
+    match attempt.and(removed) {
+        Ok(()) => passed(
+            HealthProbe::State,
+            format!("{} is writable", state_dir.display()),
+        ),
+        Err(err) => failed(
+            HealthProbe::State,
+            format!("{} is not writable: {err}", state_dir.display()),
+        ),
+    }
+}
+
+/// Send `GET /health` to `daemon` and require a 200 answer before
+/// `deadline`.
+fn probe_daemon(daemon: &str, deadline: Instant) -> ProbeResult {
+    match request_health(daemon, deadline) {
+        Ok(status) if status == "200" => {
+            passed(HealthProbe::Daemon, format!("{daemon} answered {status}"))
+        }
+        Ok(status) => failed(
+            HealthProbe::Daemon,
+            format!("{daemon} answered {status} to GET /health"),
+        ),
+        Err(err) => failed(HealthProbe::Daemon, format!("{daemon}: {err}")),
+    }
+}
+
+/// Status code of the daemon's answer to `GET /health`.
+fn request_health(daemon: &str, deadline: Instant) -> io::Result<String> {
+    let remaining = || {
+        let left = deadline.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            Err(io::Error::new(io::ErrorKind::TimedOut, "out of time"))
+        } else {
+            Ok(left)
+        }
+    };
+    let request = "GET /health HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n";
+    let mut response = Vec::new();
+    if let Some(socket) = unix_socket_path(daemon) {
+        #[cfg(unix)]
+        {
+            let mut stream = std::os::unix::net::UnixStream::connect(socket)?;
+            stream.set_read_timeout(Some(remaining()?))?;
+            stream.set_write_timeout(Some(remaining()?))?;
+            stream.write_all(request.as_bytes())?;
+            read_status_line(&mut stream, &mut response)?;
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = socket;
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "Unix sockets are not available on this platform",
+            ));
+        }
+    } else {
+        let address = daemon.to_socket_addrs()?.next().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::NotFound, "address resolves to nothing")
+        })?;
+        let mut stream = TcpStream::connect_timeout(&address, remaining()?)?;
+        stream.set_read_timeout(Some(remaining()?))?;
+        stream.set_write_timeout(Some(remaining()?))?;
+        stream.write_all(request.as_bytes())?;
+        read_status_line(&mut stream, &mut response)?;
+    }
+    parse_status(&response)
+}
+
+/// `unix:/run/app.sock` or an absolute path names a Unix socket.
+fn unix_socket_path(daemon: &str) -> Option<&Path> {
+    daemon
+        .strip_prefix("unix:")
+        .or_else(|| daemon.starts_with('/').then_some(daemon))
+        .map(Path::new)
+}
+
+/// Read until the end of the status line (or the connection closes).
+fn read_status_line(stream: &mut impl io::Read, response: &mut Vec<u8>) -> io::Result<()> {
+    let mut chunk = [0_u8; 256];
+    while !response.contains(&b'\n') && response.len() < 4096 {
+        let read = stream.read(&mut chunk)?;
+        if read == 0 {
+            break;
+        }
+        response.extend_from_slice(&chunk[..read]);
+    }
+    Ok(())
+}
+
+/// Status code from `HTTP/1.1 200 OK`.
+fn parse_status(response: &[u8]) -> io::Result<String> {
+    let text = String::from_utf8_lossy(response);
+    let line = text.lines().next().unwrap_or_default();
+    let mut parts = line.split_whitespace();
+    match (parts.next(), parts.next()) {
+        (Some(version), Some(status)) if version.starts_with("HTTP/") => Ok(status.to_string()),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("not an HTTP response: {line:?}"),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch::Scratch;
+    use std::io::Read as _;
+    use std::net::TcpListener;
+
+    #[test]
+    fn reports_each_selected_probe_and_first_failure() -> anyhow::Result<()> {
+        let scratch = Scratch::new()?;
+        let paths = scratch.paths();
+        fs::create_dir_all(&paths.state_dir)?;
+        let report = check(HealthRequest {
+            paths: paths.clone(),
+            from_env: false,
+            probes: Some(vec![HealthProbe::State, HealthProbe::Config]),
+            daemon: None,
+            timeout: Some(Duration::from_secs(5)),
+        });
+        anyhow::ensure!(report.is_healthy() && report.reason == "ok", "{report:?}");
+        anyhow::ensure!(report.status == "healthy");
+        let order: Vec<HealthProbe> = report.probes.iter().map(|p| p.probe).collect();
+        anyhow::ensure!(
+            order == [HealthProbe::Config, HealthProbe::State],
+            "{order:?}"
+        );
+        anyhow::ensure!(fs::read_dir(&paths.state_dir)?.next().is_none());
+
+        fs::remove_dir(&paths.state_dir)?;
+        let report = check(HealthRequest {
+            paths,
+            from_env: false,
+            probes: Some(vec![HealthProbe::State]),
+            daemon: None,
+            timeout: Some(Duration::from_secs(5)),
+        });
+        anyhow::ensure!(!report.is_healthy() && report.status == "unhealthy");
+        anyhow::ensure!(report.reason.starts_with("state: "), "{}", report.reason);
+        anyhow::ensure!(report.probes.len() == 1, "{report:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_config_fails_the_config_probe() -> anyhow::Result<()> {
+        let scratch = Scratch::new()?;
+        let paths = scratch.paths();
+        fs::create_dir_all(paths.dir(crate::paths::DirKind::Config))?;
+        fs::write(&paths.config_file, "[backup]\ncompression_level = 99\n")?;
+        let report = check(HealthRequest {
+            paths,
+            from_env: false,
+            probes: Some(vec![HealthProbe::Config]),
+            daemon: None,
+            timeout: Some(Duration::from_secs(5)),
+        });
+        anyhow::ensure!(report.reason.contains("compression_level"), "{report:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn daemon_probe_reads_the_status_line() -> anyhow::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let address = listener.local_addr()?.to_string();
+        let server = std::thread::spawn(move || -> io::Result<()> {
+            for status in ["200 OK", "503 Service Unavailable"] {
+                let (mut stream, _) = listener.accept()?;
+                let mut request = [0_u8; 512];
+                let _ = stream.read(&mut request)?;
+                write!(stream, "HTTP/1.1 {status}\r\ncontent-length: 0\r\n\r\n")?;
+            }
+            Ok(())
+        });
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let up = probe_daemon(&address, deadline);
+        anyhow::ensure!(up.ok, "{up:?}");
+        let down = probe_daemon(&address, deadline);
+        anyhow::ensure!(!down.ok && down.detail.contains("503"), "{down:?}");
+        server
+            .join()
+            .map_err(|_| anyhow::anyhow!("server thread panicked"))??;
+        Ok(())
+    }
+
+    #[test]
+    fn silent_daemon_times_out() -> anyhow::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let address = listener.local_addr()?.to_string();
+        let scratch = Scratch::new()?;
+        let report = check(HealthRequest {
+            paths: scratch.paths(),
+            from_env: false,
+            probes: Some(vec![HealthProbe::Daemon]),
+            daemon: Some(address),
+            timeout: Some(Duration::from_millis(200)),
+        });
+        anyhow::ensure!(report.reason.starts_with("daemon: "), "{report:?}");
+        anyhow::ensure!(report.elapsed_ms < 2000, "{report:?}");
+        drop(listener);
+        Ok(())
+    }
+
+    #[test]
+    fn parses_status_and_unix_socket_spellings() {
+        assert_eq!(
+            parse_status(b"HTTP/1.1 204 No Content\r\n").ok().as_deref(),
+            Some("204")
+        );
+        assert!(parse_status(b"SSH-2.0-OpenSSH\r\n").is_err());
+        assert_eq!(
+            unix_socket_path("unix:/run/a.sock"),
+            Some(Path::new("/run/a.sock"))
+        );
+        assert_eq!(
+            unix_socket_path("/run/a.sock"),
+            Some(Path::new("/run/a.sock"))
+        );
+        assert_eq!(unix_socket_path("127.0.0.1:3000"), None);
+    }
+}
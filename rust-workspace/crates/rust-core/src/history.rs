@@ -0,0 +1,255 @@
+//! History of `run` invocations.
+//!
+//! [`record`] adds one [`RunRecord`] per `run` to `runs.jsonl` in the state
+//! directory, one JSON object per line, and drops the runs that fall
+//! outside the `[history]` retention limits. [`list`] reads them back,
+//! oldest first, and [`clear`] removes them. Unlike the per-task outcomes
+//! kept by [`crate::task::record`], this keeps every run, for auditing and
+//! "what did I run yesterday".
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::{self, HistoryConfig, REDACTED};
+use crate::ctx::Ctx;
+use crate::error::{CoreError, IoResultExt as _, Result};
+use crate::plan::Action;
+use crate::task::TaskRun;
+
+/// File in the state directory holding the run history.
+pub const HISTORY_FILENAME: &str = "runs.jsonl";
+
+/// Seconds in a day, for `history.max_age_days`.
+const DAY_SECS: u64 = 24 * 60 * 60;
+
+/// One `run` invocation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunRecord {
+    /// Sequence number, one more than the previous run's; assigned by
+    /// [`record`].
+    pub id: u64,
+    /// Correlation ID of the invocation (see [`crate::ctx`]); empty for
+    /// runs recorded before it was kept.
+    #[serde(default)]
+    pub correlation_id: String,
+    /// Task that was run.
+    pub task: String,
+    /// Profile it ran under.
+    pub profile: String,
+    /// Command-line arguments after the program name, with secret `--set`
+    /// values masked by [`redact_args`].
+    pub args: Vec<String>,
+    /// Start time, in seconds since the Unix epoch.
+    pub started_at: u64,
+    /// End time, in seconds since the Unix epoch.
+    pub finished_at: u64,
+    /// Exit status the invocation ended with.
+    pub exit_code: u8,
+    /// Outcome of each task, dependencies first.
+    pub tasks: Vec<TaskRun>,
+}
+
+/// Add `run` to the history in `state_dir` under the next id, then drop
+/// runs beyond `retention`, judging age by `run.finished_at`. Returns the
+/// id given to `run`.
+///
+/// # Errors
+///
+/// Returns an error if the history cannot be read or written.
+pub fn record(state_dir: &Path, mut run: RunRecord, retention: &HistoryConfig) -> Result<u64> {
+    let mut runs = list(state_dir)?;
+    run.id = runs.last().map_or(1, |last| last.id + 1);
+    let id = run.id;
+    let now = run.finished_at;
+    runs.push(run);
+    prune(&mut runs, retention, now);
+    write(state_dir, &runs)?;
+    Ok(id)
+}
+
+/// `args` with secret `--set` values masked, so the history never holds them.
+///
+/// Each `--set KEY=VALUE` or `--set=KEY=VALUE` whose key [names a
+/// secret](config::is_redacted_key), or contains one of `extra`
+/// (`security.redact`), has its value replaced by [`REDACTED`].
+#[must_use]
+pub fn redact_args(args: Vec<String>, extra: &[String]) -> Vec<String> {
+    let redact = |setting: &str| match setting.split_once('=') {
+        Some((key, _)) if config::is_redacted_key(key, extra) => format!("{key}={REDACTED}"),
+        _ => setting.to_string(),
+    };
+    let mut after_set = false;
+    args.into_iter()
+        .map(|arg| {
+            let redacted = if after_set {
+                redact(&arg)
+            } else if let Some(setting) = arg.strip_prefix("--set=") {
+                format!("--set={}", redact(setting))
+            } else {
+                arg.clone()
+            };
+            after_set = arg == "--set";
+            redacted
+        })
+        .collect()
+}
+
+/// Every recorded run in `state_dir`, oldest first. Nothing recorded yet is
+/// an empty history; lines that do not parse are skipped with a warning.
+///
+/// # Errors
+///
+/// Returns an error if the history exists but cannot be read.
+pub fn list(state_dir: &Path) -> Result<Vec<RunRecord>> {
+    let path = state_dir.join(HISTORY_FILENAME);
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err).at_path("reading run history", &path),
+    };
+    Ok(text
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter_map(|(index, line)| match serde_json::from_str(line) {
+            Ok(run) => Some(run),
+            Err(err) => {
+                log::warn!("{}:{}: skipping run: {err}", path.display(), index + 1);
+                None
+            }
+        })
+        .collect())
+}
+
+/// Remove the run history from `state_dir` (unless this is a dry run) and
+/// return how many runs it held.
+///
+/// # Errors
+///
+/// Returns an error if the history cannot be read or removed.
+pub fn clear(state_dir: &Path, ctx: &Ctx) -> Result<usize> {
+    let count = list(state_dir)?.len();
+    let path = state_dir.join(HISTORY_FILENAME);
+    if ctx.dry_run() {
+        if count > 0 {
+            ctx.plan(Action::Remove { path });
+        }
+    } else {
+        match fs::remove_file(&path) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => {
+                return Err(err).at_path("removing run history", &path);
+            }
+            _ => {}
+        }
+    }
+    Ok(count)
+}
+
+/// Keep the newest `max_entries` runs that finished within `max_age_days`
+/// of `now`.
+fn prune(runs: &mut Vec<RunRecord>, retention: &HistoryConfig, now: u64) {
+    if retention.max_age_days > 0 {
+        let cutoff = now.saturating_sub(retention.max_age_days.saturating_mul(DAY_SECS));
+        runs.retain(|run| run.finished_at >= cutoff);
+    }
+    let excess = runs.len().saturating_sub(retention.max_entries.max(1));
+    runs.drain(..excess);
+}
+
+fn write(state_dir: &Path, runs: &[RunRecord]) -> Result<()> {
+    let mut text = String::new();
+    for run in runs {
+        let line = serde_json::to_string(run)
+            .map_err(|err| CoreError::Serialization(format!("run history: {err}")))?;
+        text.push_str(&line);
+        text.push('\n');
+    }
+    fs::create_dir_all(state_dir).at_path("creating state directory", state_dir)?;
+    let path = state_dir.join(HISTORY_FILENAME);
+    let partial = path.with_extension("jsonl.partial");
+    fs::write(&partial, text).at_path("writing run history", &partial)?;
+    fs::rename(&partial, &path).at_path("replacing run history", &path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch::Scratch;
+
+    fn run(task: &str, finished_at: u64) -> RunRecord {
+        RunRecord {
+            id: 0,
+            correlation_id: "0123456789abcdef".to_string(),
+            task: task.to_string(),
+            profile: "default".to_string(),
+            args: vec!["run".to_string(), task.to_string()],
+            started_at: finished_at.saturating_sub(5),
+            finished_at,
+            exit_code: 0,
+            tasks: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn records_in_order_and_applies_retention() -> anyhow::Result<()> {
+        let dir = Scratch::new()?;
+        let retention = HistoryConfig {
+            record: true,
+            max_entries: 2,
+            max_age_days: 1,
+        };
+        anyhow::ensure!(list(&dir)?.is_empty());
+        let now = 10 * DAY_SECS;
+        anyhow::ensure!(record(&dir, run("old", now - 2 * DAY_SECS), &retention)? == 1);
+        anyhow::ensure!(record(&dir, run("build", now - 60), &retention)? == 2);
+        // The first run is past max_age_days by now.
+        anyhow::ensure!(list(&dir)?.iter().map(|run| run.id).eq([2]));
+        record(&dir, run("test", now - 30), &retention)?;
+        record(&dir, run("lint", now), &retention)?;
+        let kept: Vec<(u64, String)> = list(&dir)?
+            .into_iter()
+            .map(|run| (run.id, run.task))
+            .collect();
+        anyhow::ensure!(
+            kept == [(3, "test".to_string()), (4, "lint".to_string())],
+            "{kept:?}"
+        );
+
+        let dry_run = Ctx::default().with_dry_run(true);
+        anyhow::ensure!(clear(&dir, &dry_run)? == 2 && list(&dir)?.len() == 2);
+        anyhow::ensure!(clear(&dir, &Ctx::default())? == 2 && list(&dir)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn secret_overrides_are_masked() {
+        let args = [
+            "--set",
+            "mcp.auth.token=hunter2",
+            "--set=tasks.deploy.env.SITE_KEY=s3cret",
+            "--set",
+            "runtime.timeout=30",
+            "--set=daemon.pid=7",
+            "run",
+            "mcp.auth.token=kept",
+        ]
+        .map(str::to_string);
+        let redacted = redact_args(args.to_vec(), &["daemon".to_string()]);
+        assert_eq!(
+            redacted,
+            [
+                "--set",
+                "mcp.auth.token=<redacted>",
+                "--set=tasks.deploy.env.SITE_KEY=<redacted>",
+                "--set",
+                "runtime.timeout=30",
+                "--set=daemon.pid=<redacted>",
+                "run",
+                "mcp.auth.token=kept",
+            ]
+        );
+    }
+}
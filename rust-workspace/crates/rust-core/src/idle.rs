@@ -0,0 +1,192 @@
+//! Idle detection, so heavy background work can wait for a quiet machine.
+//!
+//! A [`Sample`] reads the 1-minute load average (Linux, macOS) and the time
+//! since the last keyboard or mouse input (macOS). Whatever a platform cannot
+//! measure is `None` and never holds work back. The `[idle]` thresholds
+//! ([`IdleConfig`]) decide whether a sample counts as idle, and
+//! [`wait_until_idle`] is the hook deferred work calls before it starts.
+
+use std::time::{Duration, Instant};
+
+use crate::config::IdleConfig;
+use crate::ctx::Ctx;
+
+/// Machine activity at one moment.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Sample {
+    /// 1-minute load average divided by the number of CPUs.
+    pub load_per_cpu: Option<f64>,
+    /// Time since the last keyboard or mouse input.
+    pub input_idle: Option<Duration>,
+}
+
+impl Sample {
+    /// Measure the machine now.
+    #[must_use]
+    pub fn take() -> Self {
+        let cpus = std::thread::available_parallelism().map_or(1, std::num::NonZero::get);
+        Self {
+            load_per_cpu: load_average().map(|load| load / cpus as f64),
+            input_idle: input_idle(),
+        }
+    }
+
+    /// Why the machine is busy by `config`'s thresholds, or `None` if it is
+    /// idle.
+    #[must_use]
+    pub fn busy_reason(&self, config: &IdleConfig) -> Option<String> {
+        if let Some(load) = self.load_per_cpu
+            && load > config.max_load
+        {
+            return Some(format!(
+                "load {load:.2} per CPU is above idle.max_load ({})",
+                config.max_load
+            ));
+        }
+        let wanted = Duration::from_secs(config.min_input_idle);
+        if let Some(idle) = self.input_idle
+            && idle < wanted
+        {
+            return Some(format!(
+                "last input {}s ago, less than idle.min_input_idle ({}s)",
+                idle.as_secs(),
+                config.min_input_idle
+            ));
+        }
+        None
+    }
+}
+
+/// How [`wait_until_idle`] ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdleWait {
+    /// The machine is idle; start the work.
+    Idle,
+    /// `idle.max_wait` passed first; the last reason it was busy.
+    TimedOut(String),
+    /// The context was cancelled or ran out of time, e.g. on a shutdown
+    /// signal.
+    Stopped,
+}
+
+/// Block until the machine is idle by `config`.
+///
+/// Checks every `idle.poll_interval` seconds, for at most `idle.max_wait`
+/// seconds (0 waits indefinitely). `ctx` is checked about once a second and
+/// ends the wait early once it must stop. Logs why the work is deferred.
+#[must_use]
+pub fn wait_until_idle(config: &IdleConfig, ctx: &Ctx) -> IdleWait {
+    let started = Instant::now();
+    let max_wait = Duration::from_secs(config.max_wait);
+    let poll = Duration::from_secs(config.poll_interval.max(1));
+    let mut logged = false;
+    loop {
+        let Some(reason) = Sample::take().busy_reason(config) else {
+            return IdleWait::Idle;
+        };
+        if logged {
+            log::debug!("still busy: {reason}");
+        } else {
+            log::info!("deferring until the machine is idle: {reason}");
+            logged = true;
+        }
+        let next = Instant::now() + poll;
+        while Instant::now() < next {
+            if ctx.stop_reason().is_some() {
+                return IdleWait::Stopped;
+            }
+            if config.max_wait > 0 && started.elapsed() >= max_wait {
+                return IdleWait::TimedOut(reason);
+            }
+            std::thread::sleep(Duration::from_secs(1).min(next - Instant::now()));
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn load_average() -> Option<f64> {
+    std::fs::read_to_string("/proc/loadavg")
+        .ok()?
+        .split_whitespace()
+        .next()?
+        .parse()
+        .ok()
+}
+
+/// From `sysctl -n vm.loadavg`, which prints `{ 1.23 1.10 0.98 }`.
+#[cfg(target_os = "macos")]
+fn load_average() -> Option<f64> {
+    let output = std::process::Command::new("sysctl")
+        .args(["-n", "vm.loadavg"])
+        .output()
+        .ok()?;
+    String::from_utf8_lossy(&output.stdout)
+        .split_whitespace()
+        .find_map(|field| field.parse().ok())
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+const fn load_average() -> Option<f64> {
+    None
+}
+
+/// `HIDIdleTime` (nanoseconds) from the I/O registry.
+#[cfg(target_os = "macos")]
+fn input_idle() -> Option<Duration> {
+    let output = std::process::Command::new("ioreg")
+        .args(["-c", "IOHIDSystem", "-d", "4", "-r", "-k", "HIDIdleTime"])
+        .output()
+        .ok()?;
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find_map(|line| {
+            let (_, nanos) = line.split_once("\"HIDIdleTime\" = ")?;
+            nanos.trim().parse().ok().map(Duration::from_nanos)
+        })
+}
+
+#[cfg(not(target_os = "macos"))]
+const fn input_idle() -> Option<Duration> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ctx::CancelToken;
+
+    #[test]
+    fn busy_until_every_measured_threshold_is_met() -> anyhow::Result<()> {
+        let config = IdleConfig {
+            max_load: 0.5,
+            min_input_idle: 300,
+            ..IdleConfig::default()
+        };
+        let sample = |load: Option<f64>, input_secs: Option<u64>| Sample {
+            load_per_cpu: load,
+            input_idle: input_secs.map(Duration::from_secs),
+        };
+        anyhow::ensure!(sample(Some(0.2), Some(600)).busy_reason(&config).is_none());
+        anyhow::ensure!(sample(None, None).busy_reason(&config).is_none());
+        let loaded = sample(Some(0.9), Some(600)).busy_reason(&config);
+        anyhow::ensure!(loaded.is_some_and(|reason| reason.contains("max_load")));
+        let typing = sample(Some(0.2), Some(10)).busy_reason(&config);
+        anyhow::ensure!(typing.is_some_and(|reason| reason.contains("min_input_idle")));
+        Ok(())
+    }
+
+    #[test]
+    fn stops_waiting_when_asked() -> anyhow::Result<()> {
+        let busy = IdleConfig {
+            max_load: -1.0,
+            ..IdleConfig::default()
+        };
+        if Sample::take().load_per_cpu.is_some() {
+            let token = CancelToken::default();
+            token.cancel();
+            let ctx = Ctx::default().with_cancel(token);
+            anyhow::ensure!(wait_until_idle(&busy, &ctx) == IdleWait::Stopped);
+        }
+        Ok(())
+    }
+}
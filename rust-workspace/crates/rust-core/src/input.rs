@@ -0,0 +1,404 @@
+//! Input data from stdin, a file, or a URL, in JSON, NDJSON, YAML, or TOML.
+//!
+//! A command that takes data accepts a [`Source`] argument: `-` for stdin,
+//! an `http(s)://` URL, or else a file path. [`Input::read`] reads it whole,
+//! up to a size limit, and [`Format::sniff`] tells the format from the
+//! content, so a pipe and a file without an extension work the same;
+//! [`Input::with_format`] overrides the guess. [`Input::parse`] then
+//! deserializes it, reporting the line and column of the first problem as
+//! [`CoreError::InputParse`]. Fetching a URL is left to the binary, which
+//! passes a function that opens it; nothing here touches the network.
+
+use std::convert::Infallible;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use serde::Serialize;
+use serde::de::{DeserializeOwned, IgnoredAny};
+
+use crate::error::{CoreError, IoResultExt as _, Result, Span};
+
+/// Input size limit, in bytes, for callers without a better one.
+pub const DEFAULT_LIMIT: u64 = 64 * 1024 * 1024;
+
+/// Where input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// Standard input, written `-`.
+    Stdin,
+    /// A file.
+    File(PathBuf),
+    /// An `http://` or `https://` URL.
+    Url(String),
+}
+
+impl FromStr for Source {
+    type Err = Infallible;
+
+    /// `-` is stdin and `http(s)://...` a URL; anything else is a path.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(if s == "-" {
+            Self::Stdin
+        } else if s.starts_with("http://") || s.starts_with("https://") {
+            Self::Url(s.to_string())
+        } else {
+            Self::File(PathBuf::from(s))
+        })
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Stdin => f.write_str("stdin"),
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Url(url) => f.write_str(url),
+        }
+    }
+}
+
+/// A data format [`Input`] can be parsed from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// One JSON value.
+    Json,
+    /// One JSON value per line, parsed as an array of them.
+    Ndjson,
+    /// A YAML document.
+    Yaml,
+    /// A TOML document.
+    Toml,
+}
+
+impl Format {
+    /// Every format, in the order [`Format::from_str`] lists them.
+    pub const ALL: [Self; 4] = [Self::Json, Self::Ndjson, Self::Yaml, Self::Toml];
+
+    /// Lowercase name, as accepted by [`Format::from_str`].
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Ndjson => "ndjson",
+            Self::Yaml => "yaml",
+            Self::Toml => "toml",
+        }
+    }
+
+    /// The format `text` is most likely in. Content that parses in some
+    /// format gets that format; otherwise the guess is made from how it
+    /// starts, so the parse error comes from the parser the author meant.
+    #[must_use]
+    pub fn sniff(text: &str) -> Self {
+        let is_json = |text: &str| serde_json::from_str::<IgnoredAny>(text).is_ok();
+        let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+        let first = lines.next().unwrap_or_default();
+        let several = lines.next().is_some();
+        let is_toml = || toml::from_str::<toml::Table>(text).is_ok();
+        match first.chars().next() {
+            Some('{' | '[') if is_json(text) => Self::Json,
+            Some('{' | '[') if several && is_json(first) => Self::Ndjson,
+            Some('[') if looks_like_toml(first) || is_toml() => Self::Toml,
+            Some('{' | '[') => Self::Json,
+            _ if !first.is_empty() && is_toml() => Self::Toml,
+            _ => match serde_yaml::from_str::<serde_yaml::Value>(text) {
+                Ok(serde_yaml::Value::Mapping(_) | serde_yaml::Value::Sequence(_)) => Self::Yaml,
+                _ if looks_like_toml(first) => Self::Toml,
+                _ => Self::Yaml,
+            },
+        }
+    }
+}
+
+/// Whether `line` is a TOML table header or `key = value` line.
+fn looks_like_toml(line: &str) -> bool {
+    let header = line.starts_with('[') && line.ends_with(']') && !line.contains(',');
+    let assignment = line.split_once('=').is_some_and(|(key, _)| {
+        let key = key.trim();
+        !key.is_empty()
+            && key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '"'))
+    });
+    header || assignment
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Self::Json => "JSON",
+            Self::Ndjson => "NDJSON",
+            Self::Yaml => "YAML",
+            Self::Toml => "TOML",
+        })
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    /// Accepts the names of [`Format::name`] in any case, plus `yml` and
+    /// `jsonl`.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "yml" => Ok(Self::Yaml),
+            "jsonl" => Ok(Self::Ndjson),
+            name => Self::ALL
+                .into_iter()
+                .find(|format| format.name() == name)
+                .ok_or_else(|| format!("unknown format `{s}` (known: json, ndjson, yaml, toml)")),
+        }
+    }
+}
+
+/// Input data read whole, with the format it will be parsed as.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    /// Where it came from.
+    pub source: Source,
+    /// Format [`Input::parse`] uses: sniffed, or set with
+    /// [`Input::with_format`].
+    pub format: Format,
+    /// The content, without a leading byte order mark.
+    pub text: String,
+}
+
+impl Input {
+    /// Read `source` whole, failing beyond `limit` bytes. A URL is opened
+    /// with `fetch`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::InputTooLarge`] past `limit`,
+    /// [`CoreError::InputParse`] if the content is not UTF-8, and an I/O
+    /// error if `source` cannot be opened or read.
+    pub fn read(
+        source: Source,
+        limit: u64,
+        fetch: impl FnOnce(&str) -> io::Result<Box<dyn Read>>,
+    ) -> Result<Self> {
+        match &source {
+            Source::Stdin => Self::from_reader(source, io::stdin().lock(), limit),
+            Source::File(path) => {
+                let file = File::open(path).at_path("opening input", path)?;
+                Self::from_reader(source, file, limit)
+            }
+            Source::Url(url) => {
+                let reader = fetch(url).map_err(|err| {
+                    CoreError::Io(io::Error::new(err.kind(), format!("fetching {url}: {err}")))
+                })?;
+                Self::from_reader(source, reader, limit)
+            }
+        }
+    }
+
+    /// Read the content of `source` from `reader`, failing beyond `limit`
+    /// bytes.
+    ///
+    /// # Errors
+    ///
+    /// As for [`Input::read`], once `source` is open.
+    pub fn from_reader(source: Source, reader: impl Read, limit: u64) -> Result<Self> {
+        let mut bytes = Vec::new();
+        let read = reader.take(limit.saturating_add(1)).read_to_end(&mut bytes);
+        match (read, &source) {
+            (Err(err), Source::File(path)) => return Err(err).at_path("reading input", path),
+            (Err(err), _) => return Err(CoreError::Io(err)),
+            (Ok(_), _) => {}
+        }
+        if bytes.len() as u64 > limit {
+            return Err(CoreError::InputTooLarge {
+                input: source.to_string(),
+                limit,
+            });
+        }
+        let mut text = String::from_utf8(bytes).map_err(|err| {
+            let at = err.utf8_error().valid_up_to();
+            let valid = String::from_utf8_lossy(&err.as_bytes()[..at]).into_owned();
+            CoreError::InputParse {
+                input: source.to_string(),
+                format: Format::sniff(&valid),
+                span: Some(Span::locate(&valid, at, at)),
+                message: "not valid UTF-8".to_string(),
+            }
+        })?;
+        if text.starts_with('\u{feff}') {
+            text.drain(..'\u{feff}'.len_utf8());
+        }
+        Ok(Self {
+            source,
+            format: Format::sniff(&text),
+            text,
+        })
+    }
+
+    /// Parse as `format` instead of the sniffed format.
+    #[must_use]
+    pub fn with_format(self, format: Format) -> Self {
+        Self { format, ..self }
+    }
+
+    /// Deserialize the content as [`Input::format`]. NDJSON deserializes
+    /// as an array of its lines.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::InputParse`] if the content is not valid in its
+    /// format or does not match `T`.
+    pub fn parse<T: DeserializeOwned>(&self) -> Result<T> {
+        let text = self.text.as_str();
+        match self.format {
+            Format::Json => serde_json::from_str(text).map_err(|err| {
+                self.error(at_line(text, err.line(), err.column()), &err.to_string())
+            }),
+            Format::Ndjson => {
+                let mut records = Vec::new();
+                for (index, line) in text.lines().enumerate() {
+                    if line.trim().is_empty() {
+                        continue;
+                    }
+                    let record: serde_json::Value = serde_json::from_str(line).map_err(|err| {
+                        self.error(at_line(text, index + 1, err.column()), &err.to_string())
+                    })?;
+                    records.push(record);
+                }
+                serde_json::from_value(serde_json::Value::Array(records))
+                    .map_err(|err| self.error(None, &err.to_string()))
+            }
+            Format::Yaml => serde_yaml::from_str(text).map_err(|err| {
+                let span = err
+                    .location()
+                    .map(|location| Span::locate(text, location.index(), location.index()));
+                self.error(span, &err.to_string())
+            }),
+            Format::Toml => toml::from_str(text).map_err(|err| {
+                let span = err
+                    .span()
+                    .map(|span| Span::locate(text, span.start, span.end));
+                self.error(span, err.message())
+            }),
+        }
+    }
+
+    fn error(&self, span: Option<Span>, message: &str) -> CoreError {
+        // serde_json and serde_yaml end their messages with a location,
+        // relative to the line for NDJSON.
+        let message = message
+            .rsplit_once(" at line ")
+            .filter(|(_, at)| {
+                at.split(" column ")
+                    .all(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+            })
+            .map_or(message, |(message, _)| message);
+        CoreError::InputParse {
+            input: self.source.to_string(),
+            format: self.format,
+            span,
+            message: message.to_string(),
+        }
+    }
+}
+
+/// The span of 1-based `line` and `column` in `text`, as JSON parsers
+/// report them; `None` for line 0, where there is no location.
+fn at_line(text: &str, line: usize, column: usize) -> Option<Span> {
+    let start: usize = text
+        .split_inclusive('\n')
+        .take(line.checked_sub(1)?)
+        .map(str::len)
+        .sum();
+    let mut start = (start + column.saturating_sub(1)).min(text.len());
+    while !text.is_char_boundary(start) {
+        start -= 1;
+    }
+    Some(Span {
+        start,
+        end: start,
+        line,
+        column: column.max(1),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    type Records = Vec<BTreeMap<String, u32>>;
+
+    fn input(text: &str) -> Result<Input> {
+        Input::from_reader(Source::Stdin, text.as_bytes(), 1024)
+    }
+
+    #[test]
+    fn sniffs_each_format_by_content() -> anyhow::Result<()> {
+        for (text, format) in [
+            ("{\"a\": 1}", Format::Json),
+            ("[{\"a\": 1},\n {\"a\": 2}]", Format::Json),
+            ("{\"a\": 1}\n{\"a\": 2}\n", Format::Ndjson),
+            ("a: 1\nb: [2]\n", Format::Yaml),
+            ("- a: 1\n", Format::Yaml),
+            ("a = 1\n", Format::Toml),
+            ("[section]\na = 1\n", Format::Toml),
+            // Broken input still goes to the parser it was meant for.
+            ("{\"a\": 1,}", Format::Json),
+            ("{\"a\": 1}\n{\"a\": }\n", Format::Ndjson),
+            ("a = \n", Format::Toml),
+        ] {
+            anyhow::ensure!(Format::sniff(text) == format, "{text:?}");
+        }
+        anyhow::ensure!("YML".parse::<Format>() == Ok(Format::Yaml));
+        anyhow::ensure!("-".parse::<Source>() == Ok(Source::Stdin));
+        anyhow::ensure!(matches!("https://x/a.json".parse(), Ok(Source::Url(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn parses_and_locates_errors() -> anyhow::Result<()> {
+        let expected: Records = vec![BTreeMap::from([("a".to_string(), 1)])];
+        for text in ["\u{feff}[{\"a\": 1}]", "- a: 1\n"] {
+            anyhow::ensure!(input(text)?.parse::<Records>()? == expected, "{text:?}");
+        }
+        let lines = input("{\"a\": 1}\n\n{\"a\": 2}\n")?.parse::<Records>()?;
+        anyhow::ensure!(lines.len() == 2 && lines[1]["a"] == 2);
+
+        let errors = [
+            ("{\"a\": 1}\n{\"a\": x}\n", "stdin:2:7"),
+            ("{\n  \"a\": 1,\n}", "stdin:3:1"),
+            ("a = 1\nb = \n", "stdin:2:5"),
+            ("a: 1\n b: 2\n", "stdin:2:3"),
+        ];
+        for (text, location) in errors {
+            let err = input(text)?.parse::<serde_json::Value>();
+            let Err(err @ CoreError::InputParse { .. }) = err else {
+                anyhow::bail!("{text:?} parsed: {err:?}");
+            };
+            anyhow::ensure!(
+                err.to_string().contains(&format!(" in {location}: ")),
+                "{err}"
+            );
+        }
+
+        let large = Input::from_reader(Source::Stdin, &[b' '; 11][..], 10);
+        anyhow::ensure!(matches!(
+            large,
+            Err(CoreError::InputTooLarge { limit: 10, .. })
+        ));
+        let forced = input("a: 1")?
+            .with_format(Format::Json)
+            .parse::<serde_json::Value>();
+        anyhow::ensure!(matches!(
+            forced,
+            Err(CoreError::InputParse {
+                format: Format::Json,
+                ..
+            })
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,423 @@
+//! Write-ahead journal for operations that change several files.
+//!
+//! A [`Journal`] stages every new file under `<state_dir>/journal/` and
+//! backs up the file it replaces, leaving the targets alone. [`Journal::commit`]
+//! first records the commit in `intent.json` (synced to disk), then copies
+//! the staged files into place and removes the journal. A run that dies
+//! before the commit record changed nothing; one that dies after it left
+//! some targets new and some old. Either way the journal stays behind:
+//! [`pending`] finds it on the next start, and [`recover`] rolls the
+//! operation forward or reverts it to the backups. [`Journal::begin`]
+//! refuses to start while an interrupted operation is unrecovered.
+
+use std::ffi::OsString;
+use std::fs::{self, File};
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::ctx::Ctx;
+use crate::error::CoreError;
+use crate::plan::{Action, Planner};
+
+/// Journal directory name inside the state directory.
+pub const JOURNAL_DIRNAME: &str = "journal";
+
+/// Filename of the operation record inside the journal directory.
+const INTENT_FILENAME: &str = "intent.json";
+
+/// What an operation set out to do, as recorded in `intent.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Intent {
+    /// Operation name, e.g. `release stamp`.
+    pub operation: String,
+    /// Process that ran it.
+    pub pid: u32,
+    /// Start time, in seconds since the Unix epoch.
+    pub started_at: u64,
+    /// Whether the commit point was reached, so targets may have changed.
+    pub committed: bool,
+    /// Files the operation writes, in order.
+    pub entries: Vec<JournalEntry>,
+}
+
+/// One file written by an operation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// File being replaced or created.
+    #[serde(with = "crate::pathfmt")]
+    pub target: PathBuf,
+    /// Whether it existed before, and so has a backup to revert to.
+    pub existed: bool,
+}
+
+/// How to finish an interrupted operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Recovery {
+    /// Install every staged file, completing the operation.
+    RollForward,
+    /// Restore every target to its state before the operation.
+    Revert,
+}
+
+/// A multi-file operation in progress.
+///
+/// Dropping a journal that was never committed discards it; the targets
+/// were not touched.
+#[derive(Debug)]
+pub struct Journal {
+    /// `None` in dry-run mode, where writes are only planned.
+    dir: Option<PathBuf>,
+    /// Where a dry run records the files it would write.
+    plan: Planner,
+    intent: Intent,
+    finished: bool,
+}
+
+impl Journal {
+    /// Start `operation` with a journal in `dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::PendingJournal`] if an earlier operation in
+    /// `dir` was interrupted and not recovered, or an error if the journal
+    /// cannot be created.
+    pub fn begin(dir: &Path, operation: &str) -> Result<Self> {
+        if let Some(intent) = pending(dir)? {
+            return Err(CoreError::PendingJournal(format!(
+                "`{}` (pid {}) was interrupted{}; run `paths doctor --roll-forward` or `paths doctor --revert` first",
+                intent.operation,
+                intent.pid,
+                if intent.committed {
+                    " while replacing files"
+                } else {
+                    " before changing anything"
+                }
+            ))
+            .into());
+        }
+        // Left behind by a run that died before writing its intent.
+        if dir.exists() {
+            fs::remove_dir_all(dir).with_context(|| format!("removing {}", dir.display()))?;
+        }
+        fs::create_dir_all(dir).with_context(|| format!("creating journal {}", dir.display()))?;
+        let journal = Self {
+            dir: Some(dir.to_path_buf()),
+            plan: Planner::default(),
+            intent: Intent {
+                operation: operation.to_string(),
+                pid: std::process::id(),
+                started_at: SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |elapsed| elapsed.as_secs()),
+                committed: false,
+                entries: Vec::new(),
+            },
+            finished: false,
+        };
+        write_intent(dir, &journal.intent)?;
+        Ok(journal)
+    }
+
+    /// A journal for a dry run: writes are added to `ctx`'s plan and
+    /// nothing is changed.
+    #[must_use]
+    pub fn dry_run(operation: &str, ctx: &Ctx) -> Self {
+        Self {
+            dir: None,
+            plan: ctx.planner().clone(),
+            intent: Intent {
+                operation: operation.to_string(),
+                pid: std::process::id(),
+                started_at: 0,
+                committed: false,
+                entries: Vec::new(),
+            },
+            finished: false,
+        }
+    }
+
+    /// Whether this journal only plans.
+    #[must_use]
+    pub const fn is_dry_run(&self) -> bool {
+        self.dir.is_none()
+    }
+
+    /// Stage `contents` to replace (or create) `target` on commit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the staged copy or the backup cannot be written.
+    pub fn write(&mut self, target: &Path, contents: &[u8]) -> Result<()> {
+        let Some(dir) = &self.dir else {
+            self.plan.record(Action::WriteFile {
+                path: target.to_path_buf(),
+            });
+            return Ok(());
+        };
+        let index = self.intent.entries.len();
+        write_synced(&staged_path(dir, index), contents)?;
+        let existed = target.exists();
+        if existed {
+            let backup = backup_path(dir, index);
+            fs::copy(target, &backup)
+                .with_context(|| format!("backing up {}", target.display()))?;
+            File::open(&backup)
+                .and_then(|file| file.sync_all())
+                .with_context(|| format!("syncing {}", backup.display()))?;
+        }
+        self.intent.entries.push(JournalEntry {
+            target: target.to_path_buf(),
+            existed,
+        });
+        Ok(())
+    }
+
+    /// Files staged so far.
+    #[must_use]
+    pub fn entries(&self) -> &[JournalEntry] {
+        &self.intent.entries
+    }
+
+    /// Record the commit, install every staged file, and remove the journal.
+    ///
+    /// If installing fails part-way, the journal is kept for [`recover`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the commit cannot be recorded or a file cannot be
+    /// installed.
+    pub fn commit(mut self) -> Result<()> {
+        self.finished = true;
+        let Some(dir) = self.dir.take() else {
+            return Ok(());
+        };
+        self.intent.committed = true;
+        write_intent(&dir, &self.intent)?;
+        apply(&dir, &self.intent, Recovery::RollForward)?;
+        fs::remove_dir_all(&dir).with_context(|| format!("removing journal {}", dir.display()))
+    }
+}
+
+impl Drop for Journal {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+        if let Some(dir) = &self.dir
+            && let Err(err) = fs::remove_dir_all(dir)
+        {
+            log::warn!("discarding journal {}: {err}", dir.display());
+        }
+    }
+}
+
+/// The interrupted operation recorded in `dir`, if any.
+///
+/// # Errors
+///
+/// Returns an error if the record exists but cannot be read or parsed.
+pub fn pending(dir: &Path) -> Result<Option<Intent>> {
+    let path = dir.join(INTENT_FILENAME);
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err).with_context(|| format!("reading {}", path.display())),
+    };
+    serde_json::from_str(&text)
+        .with_context(|| format!("parsing {}", path.display()))
+        .map(Some)
+}
+
+/// Finish the interrupted operation in `dir` and remove its journal.
+///
+/// An operation interrupted before its commit changed nothing, so either
+/// way its journal is simply discarded. On a dry run the files that would
+/// change are only planned. Returns the recovered operation.
+///
+/// # Errors
+///
+/// Returns an error if the journal cannot be read or a file cannot be
+/// restored; the journal is kept so recovery can be retried.
+pub fn recover(dir: &Path, recovery: Recovery, ctx: &Ctx) -> Result<Option<Intent>> {
+    let Some(intent) = pending(dir)? else {
+        return Ok(None);
+    };
+    if ctx.dry_run() {
+        if intent.committed {
+            for entry in &intent.entries {
+                let path = entry.target.clone();
+                // Reverting a file the operation created removes it.
+                ctx.plan(if recovery == Recovery::Revert && !entry.existed {
+                    Action::Remove { path }
+                } else {
+                    Action::WriteFile { path }
+                });
+            }
+        }
+        ctx.plan(Action::Remove {
+            path: dir.to_path_buf(),
+        });
+        return Ok(Some(intent));
+    }
+    if intent.committed {
+        apply(dir, &intent, recovery)?;
+    }
+    fs::remove_dir_all(dir).with_context(|| format!("removing journal {}", dir.display()))?;
+    Ok(Some(intent))
+}
+
+/// Bring every target of a committed `intent` to its new or its old state.
+/// Safe to repeat after an interruption.
+fn apply(dir: &Path, intent: &Intent, recovery: Recovery) -> Result<()> {
+    for (index, entry) in intent.entries.iter().enumerate() {
+        match recovery {
+            Recovery::RollForward => install(&staged_path(dir, index), &entry.target)?,
+            Recovery::Revert if entry.existed => {
+                install(&backup_path(dir, index), &entry.target)?;
+            }
+            Recovery::Revert => match fs::remove_file(&entry.target) {
+                Err(err) if err.kind() != std::io::ErrorKind::NotFound => {
+                    return Err(err)
+                        .with_context(|| format!("removing {}", entry.target.display()));
+                }
+                _ => {}
+            },
+        }
+    }
+    Ok(())
+}
+
+/// Copy `source` next to `target` and rename it into place, so `target` is
+/// never half-written even when the journal is on another filesystem.
+fn install(source: &Path, target: &Path) -> Result<()> {
+    let contents = fs::read(source).with_context(|| format!("reading {}", source.display()))?;
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("creating directory {}", parent.display()))?;
+    }
+    // Built from the name as it is, so a non-Unicode one stays distinct.
+    let mut name = OsString::from(".");
+    name.push(target.file_name().unwrap_or_default());
+    name.push(format!(".journal-{}", std::process::id()));
+    let tmp = target.with_file_name(name);
+    write_synced(&tmp, &contents)?;
+    fs::rename(&tmp, target).with_context(|| format!("replacing {}", target.display()))
+}
+
+/// Write the operation record atomically and durably.
+fn write_intent(dir: &Path, intent: &Intent) -> Result<()> {
+    let json = serde_json::to_vec_pretty(intent).context("serializing journal intent")?;
+    let path = dir.join(INTENT_FILENAME);
+    let tmp = path.with_extension("tmp");
+    write_synced(&tmp, &json)?;
+    fs::rename(&tmp, &path).with_context(|| format!("replacing {}", path.display()))?;
+    // Make the rename itself durable; directories cannot be opened for
+    // syncing on every platform, so this is best effort.
+    if let Ok(handle) = File::open(dir) {
+        let _ = handle.sync_all();
+    }
+    Ok(())
+}
+
+fn write_synced(path: &Path, bytes: &[u8]) -> Result<()> {
+    let mut file = File::create(path).with_context(|| format!("creating {}", path.display()))?;
+    file.write_all(bytes)
+        .and_then(|()| file.sync_all())
+        .with_context(|| format!("writing {}", path.display()))
+}
+
+fn staged_path(dir: &Path, index: usize) -> PathBuf {
+    dir.join(format!("{index}.new"))
+}
+
+fn backup_path(dir: &Path, index: usize) -> PathBuf {
+    dir.join(format!("{index}.old"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch::Scratch;
+
+    #[test]
+    fn interrupted_commit_rolls_forward_or_reverts() -> Result<()> {
+        let scratch = Scratch::new()?;
+        let root = scratch.join("root");
+        let journal_dir = root.join(JOURNAL_DIRNAME);
+        let (kept, added) = (root.join("kept.txt"), root.join("nested").join("added.txt"));
+        for recovery in [Recovery::RollForward, Recovery::Revert] {
+            fs::create_dir_all(&root)?;
+            fs::write(&kept, "old\n")?;
+            let mut journal = Journal::begin(&journal_dir, "test")?;
+            journal.write(&kept, b"new\n")?;
+            journal.write(&added, b"added\n")?;
+            // Simulate dying right after the commit record, before install.
+            journal.intent.committed = true;
+            write_intent(&journal_dir, &journal.intent)?;
+            journal.finished = true;
+            drop(journal);
+
+            anyhow::ensure!(pending(&journal_dir)?.is_some_and(|intent| intent.committed));
+            let blocked = Journal::begin(&journal_dir, "next").map(|_| ());
+            anyhow::ensure!(
+                crate::ErrorCode::from_error(&blocked.err().context("begin was not refused")?)
+                    == Some(crate::ErrorCode::PendingJournal)
+            );
+            anyhow::ensure!(recover(&journal_dir, recovery, &Ctx::default())?.is_some());
+            anyhow::ensure!(pending(&journal_dir)?.is_none());
+            let expected = match recovery {
+                Recovery::RollForward => ("new\n", true),
+                Recovery::Revert => ("old\n", false),
+            };
+            anyhow::ensure!(fs::read_to_string(&kept)? == expected.0, "{recovery:?}");
+            anyhow::ensure!(added.exists() == expected.1, "{recovery:?}");
+            fs::remove_dir_all(&root)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn uncommitted_journal_changes_nothing() -> Result<()> {
+        let root = Scratch::new()?;
+        let journal_dir = root.join(JOURNAL_DIRNAME);
+        let target = root.join("file.txt");
+        fs::write(&target, "old\n")?;
+        let mut journal = Journal::begin(&journal_dir, "test")?;
+        journal.write(&target, b"new\n")?;
+        drop(journal);
+        anyhow::ensure!(!journal_dir.exists());
+        anyhow::ensure!(fs::read_to_string(&target)? == "old\n");
+
+        let mut journal = Journal::begin(&journal_dir, "test")?;
+        journal.write(&target, b"new\n")?;
+        journal.commit()?;
+        anyhow::ensure!(!journal_dir.exists());
+        anyhow::ensure!(fs::read_to_string(&target)? == "new\n");
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_unicode_targets_stay_distinct() -> Result<()> {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt as _;
+
+        let root = Scratch::new()?;
+        let journal_dir = root.join(JOURNAL_DIRNAME);
+        // Both render as `caf\u{fffd}.txt` when converted lossily.
+        let targets =
+            [b"caf\xe9.txt", b"caf\xff.txt"].map(|name| root.join(OsStr::from_bytes(name)));
+        let mut journal = Journal::begin(&journal_dir, "test")?;
+        for (target, contents) in targets.iter().zip(["first", "second"]) {
+            journal.write(target, contents.as_bytes())?;
+        }
+        journal.commit()?;
+        anyhow::ensure!(fs::read_to_string(&targets[0])? == "first");
+        anyhow::ensure!(fs::read_to_string(&targets[1])? == "second");
+        Ok(())
+    }
+}
@@ -2,19 +2,141 @@
 //!
 //! This crate provides:
 //! - Configuration loading and management
-//! - XDG-compliant path resolution
+//! - User-defined command aliases and external (`PATH`) subcommands
+//! - Deprecated flag and subcommand spellings with removal versions
+//! - Input data from stdin, files, or URLs with JSON/NDJSON/YAML/TOML sniffing
+//! - Comment-preserving config editing for settings front ends
+//! - Effective config export for Kubernetes, Docker, and systemd
+//! - Flat `dotted.key = value` listing of the config for grepping and diffing
+//! - Platform-dependent defaults behind an overridable provider
+//! - XDG-compliant path resolution and directory health reports
+//! - Lossless JSON encoding and display of non-Unicode and extended-length
+//!   paths
+//! - Crash-safe content cache with a checksummed index
+//! - Per-category cleanup of cache, logs, run history, and state
+//! - Write-ahead journal for operations that change several files
+//! - Verified backup and restore of the application directories
+//! - Config sync between machines through a git remote
+//! - Single-instance advisory locking
+//! - Audit log of MCP tool calls, with retention
+//! - Reading the log file back (both formats, rotated copies, following)
+//! - Graceful shutdown on SIGTERM/SIGINT
+//! - Watchdog time limit for operations that cannot be interrupted
+//! - systemd readiness and watchdog notifications (`sd_notify`)
+//! - systemd socket activation (`LISTEN_FDS`)
+//! - Health probes for container and supervisor checks
+//! - Operations shared by the CLI and MCP front ends (show config, resolve
+//!   paths, run a task) with typed inputs and outputs
+//! - Task runner for the `[tasks]` config table (dependencies, parallelism,
+//!   timeouts)
+//! - Idle detection (load, input idle time) for deferred work
+//! - Per-invocation and per-task resource usage measurement
+//! - Build and optional-subsystem inventory
+//! - Progress reporting trait for long-running operations
+//! - In-process event bus (task started/finished, config reloaded, cache
+//!   evicted)
+//! - Per-operation context (dry run, deadline, cancellation, progress,
+//!   correlation ID)
+//! - Dry-run plans of the changes an operation would make
+//! - Managed temporary directories under the cache dir
+//! - Release helpers (workspace version stamping)
+//! - Self-update artifact selection and checksum/minisign verification
 //! - Schema and example config generation
-//! - Common types and error handling
+//! - Settings UI description derived from the config schema
+//! - Markdown config reference generated from the config schema
+//! - SELinux relabeling hooks for system-mode directories
+//! - Generated artifact manifests (write and verify committed outputs)
+//! - Pseudo-terminal command capture (`pty` feature)
+//! - Common types, error handling, stable error codes, and exit code
+//!   classification
 
+pub mod activation;
+pub mod alias;
+pub mod artifacts;
+pub mod audit;
+pub mod backup;
+pub mod bus;
+pub mod cache;
+pub mod clean;
+pub mod codes;
+pub mod compat;
 pub mod config;
+pub mod config_docs;
+pub mod ctx;
+pub mod deprecation;
+pub mod doctor;
+pub mod editor;
 pub mod error;
+pub mod exit;
+pub mod export;
+pub mod features;
+pub mod flat;
+pub mod health;
+pub mod history;
+pub mod idle;
+pub mod input;
+pub mod journal;
+pub mod lock;
+pub mod logs;
+pub mod migrate;
+pub mod notify;
+pub mod ops;
+pub mod pathfmt;
 pub mod paths;
+pub mod plan;
+pub mod platform;
+pub mod plugin;
+pub mod progress;
+#[cfg(feature = "pty")]
+pub mod pty;
+pub mod release;
 pub mod schema;
+#[cfg(test)]
+mod scratch;
+pub mod security;
+pub mod shutdown;
+pub mod sync;
+pub mod task;
+pub mod temp;
+pub mod ui_spec;
+pub mod update;
+pub mod usage;
+pub mod watchdog;
 
-pub use config::{AppConfig, LogLevel, LoggingConfig, PathsConfig, RuntimeConfig};
-pub use error::{CoreError, Result};
-pub use paths::{AppPaths, default_cache_dir};
-pub use schema::{generate_example_config, generate_schema, write_generated_files};
+pub use artifacts::{Artifact, Drift, Manifest, verify_artifacts, write_artifacts};
+pub use audit::AuditRecord;
+pub use backup::{BackupManifest, BackupOptions, RestoreReport};
+pub use bus::{Event, EventBus};
+pub use cache::{Cache, CacheEntry, CacheReport, CacheStats, ClearFilter};
+pub use codes::ErrorCode;
+pub use config::{
+    AppConfig, AppConfigExt, BackupConfig, DaemonConfig, DaemonHttpConfig, HealthConfig,
+    HealthProbe, HistoryConfig, IdleConfig, LogFormat, LogLevel, LoggingConfig, McpAuditConfig,
+    McpAuthConfig, McpClientConfig, McpConfig, McpRateLimitConfig, OutputConfig, Override,
+    PathsConfig, PowerSave, RelabelMode, RuntimeConfig, RuntimeConfigExt, SecurityConfig,
+    SelinuxConfig, TaskConfig, UpdatesConfig,
+};
+pub use config_docs::generate_config_docs;
+pub use ctx::{CancelToken, Ctx};
+pub use deprecation::Deprecation;
+pub use doctor::DirHealth;
+pub use editor::ConfigEditor;
+pub use error::{CoreError, Result, Span};
+pub use exit::{ErrorReport, ExitCode};
+pub use export::{ExportEntry, ExportFormat};
+pub use features::{BuildInfo, FeatureStatus};
+pub use health::{HealthReport, HealthRequest, ProbeResult};
+pub use history::RunRecord;
+pub use input::{Input, Source};
+pub use journal::Journal;
+pub use lock::InstanceLock;
+pub use paths::{AppPaths, DirKind, PathMode, default_cache_dir};
+pub use plan::{Action, Planner};
+pub use progress::{NoProgress, Progress, ProgressTask};
+pub use schema::{SchemaRef, generate_example_config, generate_schema};
+pub use sync::{SettingChange, SyncConflict, SyncOutcome, SyncRepo};
+pub use temp::TempScope;
+pub use usage::{ChildUsage, ResourceUsage, UsageMeter};
 
 /// Application name used for config directories and environment prefix.
 /// Override this constant when scaffolding a new project.
@@ -35,8 +157,9 @@ pub fn env_prefix() -> String {
         .collect()
 }
 
-/// Returns the default parallelism based on available CPU cores.
+/// Returns the default parallelism from the active
+/// [`platform::Defaults`] provider (by default, the available CPU cores).
 #[must_use]
 pub fn default_parallelism() -> usize {
-    std::thread::available_parallelism().map_or(1, std::num::NonZero::get)
+    platform::current(|defaults| defaults.parallelism())
 }
@@ -0,0 +1,186 @@
+//! Advisory single-instance lock held for the lifetime of a process.
+//!
+//! Invocations that write to the state or data directories take
+//! `<state_dir>/app.lock` so concurrent runs cannot corrupt each other. The
+//! lock is advisory (`flock`/`LockFileEx`) and released automatically when
+//! the holding process exits, even after a crash.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::compat::{self, TryLockError};
+use crate::ctx::CancelToken;
+use crate::error::{CoreError, IoResultExt as _, Result};
+use crate::shutdown;
+
+/// Lock file name inside the state directory.
+pub const LOCK_FILENAME: &str = "app.lock";
+
+/// How often a waiting acquisition retries the lock.
+const RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// An acquired advisory lock; released when dropped.
+#[derive(Debug)]
+pub struct InstanceLock {
+    file: File,
+    path: PathBuf,
+}
+
+impl InstanceLock {
+    /// Acquire the lock at `path`, creating the file if needed.
+    ///
+    /// With `wait` set to `None` this fails immediately when another process
+    /// holds the lock; otherwise it retries until the duration elapses or a
+    /// shutdown is [requested](shutdown::requested).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lock file cannot be opened or written, or if
+    /// the lock is still held by another process when the wait expires.
+    pub fn acquire(path: &Path, wait: Option<Duration>) -> Result<Self> {
+        Self::acquire_cancellable(path, wait, &CancelToken::default())
+    }
+
+    /// [`acquire`](Self::acquire), giving up as soon as `cancel` is
+    /// tripped, for a caller that must stop waiting when its request is
+    /// cancelled.
+    ///
+    /// # Errors
+    ///
+    /// As [`acquire`](Self::acquire), and [`CoreError::Cancelled`] if
+    /// `cancel` is tripped while waiting.
+    pub fn acquire_cancellable(
+        path: &Path,
+        wait: Option<Duration>,
+        cancel: &CancelToken,
+    ) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).at_path("creating lock directory", parent)?;
+        }
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)
+            .at_path("opening lock file", path)?;
+
+        let deadline = wait.map(|wait| Instant::now() + wait);
+        loop {
+            match compat::try_lock(&file) {
+                Ok(()) => break,
+                Err(TryLockError::WouldBlock) => match deadline {
+                    _ if shutdown::requested().is_some() => {
+                        return Err(CoreError::Lock(format!(
+                            "shutdown requested while waiting for {}",
+                            path.display()
+                        )));
+                    }
+                    _ if cancel.is_cancelled() => {
+                        return Err(CoreError::Cancelled(format!(
+                            "cancelled while waiting for {}",
+                            path.display()
+                        )));
+                    }
+                    Some(deadline) if Instant::now() < deadline => thread::sleep(RETRY_INTERVAL),
+                    _ => return Err(held_error(path, wait)),
+                },
+                Err(TryLockError::Error(err)) => {
+                    return Err(err).at_path("locking", path);
+                }
+            }
+        }
+
+        let mut lock = Self {
+            file,
+            path: path.to_path_buf(),
+        };
+        lock.record_owner()?;
+        log::debug!("acquired instance lock {}", path.display());
+        Ok(lock)
+    }
+
+    /// Path of the lock file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Write the holder's PID so a blocked invocation can report it.
+    fn record_owner(&mut self) -> Result<()> {
+        self.file
+            .set_len(0)
+            .and_then(|()| writeln!(self.file, "{}", std::process::id()))
+            .at_path("writing lock owner to", &self.path)
+    }
+}
+
+impl Drop for InstanceLock {
+    fn drop(&mut self) {
+        // Closing the handle releases the lock as well; unlocking first just
+        // makes the release immediate for waiters.
+        let _ = compat::unlock(&self.file);
+    }
+}
+
+fn held_error(path: &Path, wait: Option<Duration>) -> CoreError {
+    let owner = fs::read_to_string(path)
+        .ok()
+        .map(|text| text.trim().to_string())
+        .filter(|pid| !pid.is_empty())
+        .map_or_else(String::new, |pid| format!(" (pid {pid})"));
+    let waited = wait.map_or_else(String::new, |wait| {
+        format!(" after waiting {}s", wait.as_secs())
+    });
+    CoreError::Lock(format!(
+        "another instance holds the lock {}{owner}{waited}; retry with --wait <SECONDS> or bypass with --no-lock",
+        path.display()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch::Scratch;
+
+    #[test]
+    fn second_acquire_fails_fast_while_held() -> anyhow::Result<()> {
+        let scratch = Scratch::new()?;
+        let path = scratch.join(LOCK_FILENAME);
+        let first = InstanceLock::acquire(&path, None)?;
+        let second = InstanceLock::acquire(&path, None);
+        anyhow::ensure!(second.is_err(), "second lock unexpectedly acquired");
+        drop(first);
+        let third = InstanceLock::acquire(&path, Some(Duration::from_millis(200)));
+        anyhow::ensure!(third.is_ok(), "lock not released on drop: {third:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn cancelling_stops_the_wait() -> anyhow::Result<()> {
+        let scratch = Scratch::new()?;
+        let path = scratch.join(LOCK_FILENAME);
+        let _held = InstanceLock::acquire(&path, None)?;
+        let cancel = CancelToken::default();
+        let canceller = cancel.clone();
+        let tripped = std::thread::spawn(move || {
+            thread::sleep(Duration::from_millis(150));
+            canceller.cancel();
+        });
+        let started = Instant::now();
+        let waited =
+            InstanceLock::acquire_cancellable(&path, Some(Duration::from_secs(30)), &cancel);
+        tripped
+            .join()
+            .map_err(|_| anyhow::anyhow!("canceller panicked"))?;
+        anyhow::ensure!(matches!(waited, Err(CoreError::Cancelled(_))), "{waited:?}");
+        anyhow::ensure!(
+            started.elapsed() < Duration::from_secs(5),
+            "waited out the timeout"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,378 @@
+//! Reading the log file back: the records `rust-cli` writes to
+//! `logging.file`, in either format, from the file and the rotated copies
+//! next to it, and the records appended to it as it grows.
+//!
+//! Text records are `<RFC 3339 time> <LEVEL> <target>: <message>`, with any
+//! fields left in the message; JSON records are objects with `time`,
+//! `level`, `msg`, `target`, and the record's fields. A line that is
+//! neither (the rest of a multi-line message) becomes a record with only a
+//! message, which [`LogFilter`] keeps or drops along with the one before it.
+//! Rotated copies are the siblings named `<file>.<N>` or `<file>-<N>`
+//! (logrotate's numbered and date suffixes); compressed ones are skipped.
+
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, Read as _, Seek as _, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::config::LogLevel;
+use crate::error::Result;
+
+/// One record from the log file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LogRecord {
+    /// When it was logged (RFC 3339), as written.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<String>,
+    /// Its level.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<LogLevel>,
+    /// The module that logged it.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub target: String,
+    /// The message; for a text record, followed by its `key=value` fields.
+    pub msg: String,
+    /// The rest of a JSON record: `correlation_id` and the record's fields.
+    #[serde(flatten)]
+    pub fields: Map<String, Value>,
+}
+
+impl LogRecord {
+    /// Parse one line of the log file. Never fails: a line in neither
+    /// format is a record with only a message.
+    #[must_use]
+    pub fn parse(line: &str) -> Self {
+        Self::parse_json(line)
+            .or_else(|| Self::parse_text(line))
+            .unwrap_or_else(|| Self {
+                time: None,
+                level: None,
+                target: String::new(),
+                msg: line.to_string(),
+                fields: Map::new(),
+            })
+    }
+
+    fn parse_json(line: &str) -> Option<Self> {
+        if !line.starts_with('{') {
+            return None;
+        }
+        let Ok(Value::Object(mut fields)) = serde_json::from_str(line) else {
+            return None;
+        };
+        let mut take = |key| match fields.remove(key) {
+            Some(Value::String(value)) => Some(value),
+            _ => None,
+        };
+        let msg = take("msg")?;
+        let time = take("time");
+        let level = take("level").and_then(|name| level(&name));
+        let target = take("target").unwrap_or_default();
+        Some(Self {
+            time,
+            level,
+            target,
+            msg,
+            fields,
+        })
+    }
+
+    fn parse_text(line: &str) -> Option<Self> {
+        let (time, rest) = line.split_once(' ')?;
+        humantime::parse_rfc3339_weak(time).ok()?;
+        let (name, rest) = rest.trim_start().split_once(' ')?;
+        let level = level(name)?;
+        let (target, msg) = rest.trim_start().split_once(": ")?;
+        Some(Self {
+            time: Some(time.to_string()),
+            level: Some(level),
+            target: target.to_string(),
+            msg: msg.to_string(),
+            fields: Map::new(),
+        })
+    }
+
+    /// [`time`](Self::time) as a point in time, if it has one that parses.
+    #[must_use]
+    pub fn timestamp(&self) -> Option<SystemTime> {
+        humantime::parse_rfc3339_weak(self.time.as_deref()?).ok()
+    }
+
+    /// Whether this is the rest of the record before it: a line with
+    /// neither a time nor a level.
+    #[must_use]
+    pub const fn is_continuation(&self) -> bool {
+        self.time.is_none() && self.level.is_none()
+    }
+}
+
+/// The record as the text format writes it, fields last.
+impl fmt::Display for LogRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_continuation() {
+            return f.write_str(&self.msg);
+        }
+        let level = self
+            .level
+            .map_or_else(|| "-".to_string(), |level| level.to_string().to_uppercase());
+        write!(
+            f,
+            "{} {level:<5} {}: {}",
+            self.time.as_deref().unwrap_or("-"),
+            self.target,
+            self.msg
+        )?;
+        for (key, value) in &self.fields {
+            match value {
+                Value::String(value) => write!(f, " {key}={value}")?,
+                value => write!(f, " {key}={value}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `name` as a level, in any case.
+fn level(name: &str) -> Option<LogLevel> {
+    Some(match name.to_ascii_lowercase().as_str() {
+        "error" => LogLevel::Error,
+        "warn" | "warning" => LogLevel::Warn,
+        "info" => LogLevel::Info,
+        "debug" => LogLevel::Debug,
+        "trace" => LogLevel::Trace,
+        _ => return None,
+    })
+}
+
+/// Which records to keep, seen in order.
+#[derive(Debug, Clone, Copy)]
+pub struct LogFilter {
+    since: Option<SystemTime>,
+    level: Option<LogLevel>,
+    /// Whether the last record was kept, which decides for continuations.
+    kept: bool,
+}
+
+impl LogFilter {
+    /// Keep records logged at or after `since` that are at least as severe
+    /// as `level`; `None` does not filter.
+    #[must_use]
+    pub const fn new(since: Option<SystemTime>, level: Option<LogLevel>) -> Self {
+        Self {
+            since,
+            level,
+            kept: since.is_none() && level.is_none(),
+        }
+    }
+
+    /// Whether to keep `record`, the next one in the file.
+    pub fn keep(&mut self, record: &LogRecord) -> bool {
+        if !record.is_continuation() {
+            self.kept = self
+                .level
+                .is_none_or(|max| record.level.is_some_and(|level| level <= max))
+                && self
+                    .since
+                    .is_none_or(|since| record.timestamp().is_some_and(|logged| logged >= since));
+        }
+        self.kept
+    }
+}
+
+/// `path` and its rotated copies that exist, oldest first: the copies by
+/// modification time, then `path` itself.
+///
+/// # Errors
+///
+/// Returns [`crate::CoreError::Io`] if the directory cannot be read.
+pub fn files(path: &Path) -> Result<Vec<PathBuf>> {
+    let mut rotated = Vec::new();
+    if let Some(name) = path.file_name().and_then(|name| name.to_str()) {
+        let dir = path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or_else(|| Path::new("."));
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err.into()),
+        };
+        for entry in entries {
+            let entry = entry?;
+            let is_rotation = entry.file_name().to_str().is_some_and(|file_name| {
+                file_name
+                    .strip_prefix(name)
+                    .and_then(|rest| rest.strip_prefix('.').or_else(|| rest.strip_prefix('-')))
+                    .is_some_and(|suffix| {
+                        !suffix.is_empty() && suffix.bytes().all(|byte| byte.is_ascii_digit())
+                    })
+            });
+            if is_rotation {
+                rotated.push((entry.metadata()?.modified()?, entry.path()));
+            }
+        }
+    }
+    rotated.sort();
+    let mut files: Vec<PathBuf> = rotated.into_iter().map(|(_, file)| file).collect();
+    if path.is_file() {
+        files.push(path.to_path_buf());
+    }
+    Ok(files)
+}
+
+/// The records in `path` and its rotated copies, oldest first.
+///
+/// # Errors
+///
+/// Returns [`crate::CoreError::Io`] if a file cannot be read.
+pub fn read(path: &Path) -> Result<Vec<LogRecord>> {
+    let mut records = Vec::new();
+    for file in files(path)? {
+        records.extend(parse_lines(&String::from_utf8_lossy(&fs::read(file)?)));
+    }
+    Ok(records)
+}
+
+fn parse_lines(text: &str) -> impl Iterator<Item = LogRecord> + '_ {
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(LogRecord::parse)
+}
+
+/// The records appended to a log file since the last look.
+#[derive(Debug)]
+pub struct Follower {
+    path: PathBuf,
+    offset: u64,
+    /// The start of a line not yet ended.
+    partial: String,
+}
+
+impl Follower {
+    /// Follow `path` from its current end; a file that does not exist yet
+    /// is followed from its start once it does.
+    #[must_use]
+    pub fn new(path: &Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            offset: fs::metadata(path).map_or(0, |meta| meta.len()),
+            partial: String::new(),
+        }
+    }
+
+    /// The complete records appended since the last call. A file that
+    /// shrank was truncated or rotated, so it is read again from its start.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::CoreError::Io`] if the file exists but cannot be
+    /// read.
+    pub fn poll(&mut self) -> Result<Vec<LogRecord>> {
+        let mut file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err.into()),
+        };
+        let len = file.metadata()?.len();
+        if len < self.offset {
+            self.offset = 0;
+            self.partial.clear();
+        }
+        if len == self.offset {
+            return Ok(Vec::new());
+        }
+        file.seek(SeekFrom::Start(self.offset))?;
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)?;
+        self.offset += bytes.len() as u64;
+        self.partial.push_str(&String::from_utf8_lossy(&bytes));
+        let Some(end) = self.partial.rfind('\n') else {
+            return Ok(Vec::new());
+        };
+        let complete: String = self.partial.drain(..=end).collect();
+        Ok(parse_lines(&complete).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write as _;
+    use std::time::Duration;
+
+    use super::*;
+    use crate::scratch::Scratch;
+
+    #[test]
+    fn records_parse_from_both_formats_and_filter() -> anyhow::Result<()> {
+        let text = LogRecord::parse("2026-10-16T14:55:33Z WARN  rust_cli: disk low free=3");
+        anyhow::ensure!(
+            text == LogRecord {
+                time: Some("2026-10-16T14:55:33Z".to_string()),
+                level: Some(LogLevel::Warn),
+                target: "rust_cli".to_string(),
+                msg: "disk low free=3".to_string(),
+                fields: Map::new(),
+            }
+        );
+        let json = LogRecord::parse(
+            r#"{"time":"2026-10-16T15:00:00.250Z","level":"debug","msg":"task hello ran","target":"rust_core::task","task":"hello"}"#,
+        );
+        anyhow::ensure!(json.level == Some(LogLevel::Debug));
+        anyhow::ensure!(json.fields == serde_json::from_str::<Map<_, _>>(r#"{"task":"hello"}"#)?);
+        anyhow::ensure!(
+            json.to_string()
+                == "2026-10-16T15:00:00.250Z DEBUG rust_core::task: task hello ran task=hello"
+        );
+        let continuation = LogRecord::parse("  at line 2");
+        anyhow::ensure!(continuation.is_continuation() && continuation.msg == "  at line 2");
+
+        let since = humantime::parse_rfc3339("2026-10-16T14:58:00Z")?;
+        let mut filter = LogFilter::new(Some(since), None);
+        let kept: Vec<bool> = [&text, &continuation, &json, &continuation]
+            .into_iter()
+            .map(|record| filter.keep(record))
+            .collect();
+        anyhow::ensure!(kept == [false, false, true, true], "{kept:?}");
+        let mut filter = LogFilter::new(None, Some(LogLevel::Warn));
+        anyhow::ensure!(filter.keep(&text) && !filter.keep(&json));
+        Ok(())
+    }
+
+    #[test]
+    fn rotated_copies_come_first_and_appends_are_followed() -> anyhow::Result<()> {
+        let dir = Scratch::new()?;
+        let log = dir.join("app.log");
+        let now = SystemTime::now();
+        for (name, line, age) in [
+            ("app.log.2", "oldest", 120),
+            ("app.log.1", "older", 60),
+            ("app.log.3.gz", "compressed", 180),
+        ] {
+            let file = File::create(dir.join(name))?;
+            writeln!(&file, "{line}")?;
+            file.set_modified(now - Duration::from_secs(age))?;
+        }
+        fs::write(&log, "current\n")?;
+        let messages: Vec<String> = read(&log)?.into_iter().map(|record| record.msg).collect();
+        anyhow::ensure!(messages == ["oldest", "older", "current"], "{messages:?}");
+
+        let mut follower = Follower::new(&log);
+        anyhow::ensure!(follower.poll()?.is_empty());
+        let mut file = fs::OpenOptions::new().append(true).open(&log)?;
+        write!(file, "next\nhalf")?;
+        let messages: Vec<String> = follower.poll()?.into_iter().map(|r| r.msg).collect();
+        anyhow::ensure!(messages == ["next"], "{messages:?}");
+        writeln!(file, " done")?;
+        let messages: Vec<String> = follower.poll()?.into_iter().map(|r| r.msg).collect();
+        anyhow::ensure!(messages == ["half done"], "{messages:?}");
+        // Truncated (or rotated): read again from the start.
+        fs::write(&log, "fresh\n")?;
+        let messages: Vec<String> = follower.poll()?.into_iter().map(|r| r.msg).collect();
+        anyhow::ensure!(messages == ["fresh"], "{messages:?}");
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+//! Config file format versions and migration between them.
+//!
+//! Generated config files carry a `# config-version: N` header line naming
+//! the format they were written in. A change that renames or removes keys
+//! bumps [`CONFIG_VERSION`] and adds a step to [`MIGRATIONS`]; loading an
+//! older file then warns (unless `runtime.check_config_version` is off) and
+//! [`migrate`] applies the missing steps. A file without the line predates
+//! versioning and counts as version 1.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use toml_edit::DocumentMut;
+
+use crate::error::{CoreError, IoResultExt as _, Result};
+
+/// Format version of config files this build writes.
+pub const CONFIG_VERSION: u32 = 1;
+
+/// Start of the header line that records the format version.
+const MARKER: &str = "# config-version:";
+
+/// One upgrade step, editing the document in place.
+type Migration = fn(&mut DocumentMut);
+
+/// Upgrade steps: `MIGRATIONS[n]` turns version `n + 1` into `n + 2`.
+const MIGRATIONS: &[Migration] = &[];
+
+/// The header line recording [`CONFIG_VERSION`].
+#[must_use]
+pub fn version_line() -> String {
+    format!("{MARKER} {CONFIG_VERSION}")
+}
+
+/// Format version of config `text`: its `# config-version:` line in the
+/// leading comments, or 1 without one.
+#[must_use]
+pub fn file_version(text: &str) -> u32 {
+    text.lines()
+        .take_while(|line| line.trim_start().starts_with('#') || line.trim().is_empty())
+        .find_map(|line| line.trim().strip_prefix(MARKER))
+        .and_then(|version| version.trim().parse().ok())
+        .unwrap_or(1)
+}
+
+/// A config file written in another format version than this build's.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VersionMismatch {
+    /// The config file.
+    #[serde(with = "crate::pathfmt")]
+    pub path: PathBuf,
+    /// Its format version.
+    pub file_version: u32,
+    /// This build's format version ([`CONFIG_VERSION`]).
+    pub current_version: u32,
+}
+
+impl VersionMismatch {
+    /// Whether the file was written by a newer build.
+    #[must_use]
+    pub const fn is_newer(&self) -> bool {
+        self.file_version > self.current_version
+    }
+}
+
+impl fmt::Display for VersionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "config file {} uses format version {}, ",
+            self.path.display(),
+            self.file_version
+        )?;
+        if self.is_newer() {
+            write!(
+                f,
+                "newer than this build's {}; settings it relies on may be ignored, so upgrade this binary",
+                self.current_version
+            )
+        } else {
+            write!(
+                f,
+                "older than this build's {}; run `config migrate` to update it (or set runtime.check_config_version = false)",
+                self.current_version
+            )
+        }
+    }
+}
+
+/// Compare the format version of `config_file` with this build's. A
+/// missing file has no mismatch.
+///
+/// # Errors
+///
+/// Returns an error if the file exists but cannot be read.
+pub fn check(config_file: &Path) -> Result<Option<VersionMismatch>> {
+    let text = match fs::read_to_string(config_file) {
+        Ok(text) => text,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err).at_path("reading config file", config_file),
+    };
+    let file_version = file_version(&text);
+    Ok((file_version != CONFIG_VERSION).then(|| VersionMismatch {
+        path: config_file.to_path_buf(),
+        file_version,
+        current_version: CONFIG_VERSION,
+    }))
+}
+
+/// Config `text` upgraded to [`CONFIG_VERSION`].
+///
+/// Its version line is updated, or added after a leading `#:schema`
+/// directive; comments and layout are kept. Returns the new text and the
+/// version it started at.
+///
+/// # Errors
+///
+/// Returns [`CoreError::Validation`] if the file is newer than this build,
+/// and [`CoreError::Config`] if it is not valid TOML.
+pub fn migrate(text: &str) -> Result<(String, u32)> {
+    let from = file_version(text);
+    if from > CONFIG_VERSION {
+        return Err(CoreError::Validation(format!(
+            "config format version {from} is newer than this build's {CONFIG_VERSION}; it cannot be migrated down"
+        )));
+    }
+    let mut text = text.to_string();
+    let steps = MIGRATIONS
+        .get(from.saturating_sub(1) as usize..)
+        .unwrap_or_default();
+    if !steps.is_empty() {
+        let mut doc: DocumentMut = text
+            .parse()
+            .map_err(|err| CoreError::Config(format!("parsing config file: {err}")))?;
+        for step in steps {
+            step(&mut doc);
+        }
+        text = doc.to_string();
+    }
+    Ok((with_version_line(&text), from))
+}
+
+/// `text` with its version line set to [`CONFIG_VERSION`].
+fn with_version_line(text: &str) -> String {
+    let line = version_line();
+    let mut out = String::with_capacity(text.len() + line.len() + 1);
+    let mut replaced = false;
+    for current in text.split_inclusive('\n') {
+        if !replaced && current.trim().starts_with(MARKER) {
+            replaced = true;
+            out.push_str(&line);
+            if current.ends_with('\n') {
+                out.push('\n');
+            }
+        } else {
+            out.push_str(current);
+        }
+    }
+    if replaced {
+        return out;
+    }
+    match text.split_once('\n') {
+        Some((first, rest)) if first.trim().starts_with("#:schema") => {
+            format!("{first}\n{line}\n{rest}")
+        }
+        _ => format!("{line}\n{text}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_older_version_has_a_migration() -> anyhow::Result<()> {
+        anyhow::ensure!(MIGRATIONS.len() + 1 == CONFIG_VERSION as usize);
+        Ok(())
+    }
+
+    #[test]
+    fn version_line_is_read_and_stamped() -> anyhow::Result<()> {
+        anyhow::ensure!(file_version("profile = \"dev\"\n") == 1);
+        anyhow::ensure!(file_version("#:schema x\n# config-version: 7\n\nprofile = 1\n") == 7);
+        anyhow::ensure!(file_version("profile = 1\n# config-version: 7\n") == 1);
+
+        let (text, from) = migrate("#:schema x\n# mine\nprofile = \"dev\"\n")?;
+        anyhow::ensure!(from == 1);
+        anyhow::ensure!(
+            text == format!(
+                "#:schema x\n{}\n# mine\nprofile = \"dev\"\n",
+                version_line()
+            ),
+            "{text}"
+        );
+        anyhow::ensure!(migrate(&text)?.0 == text);
+
+        let newer = format!("{MARKER} {}\n", CONFIG_VERSION + 1);
+        anyhow::ensure!(matches!(migrate(&newer), Err(CoreError::Validation(_))));
+        Ok(())
+    }
+}
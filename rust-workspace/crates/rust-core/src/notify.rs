@@ -0,0 +1,150 @@
+//! systemd service notifications (`sd_notify`).
+//!
+//! Under `Type=notify`, systemd passes a datagram socket in `NOTIFY_SOCKET`
+//! and only considers the service started once it receives `READY=1`. With
+//! `WatchdogSec=` it also sets `WATCHDOG_USEC` and restarts the service when
+//! `WATCHDOG=1` pings stop arriving, so pings must come from the loop whose
+//! liveness they vouch for, not from a helper thread. Outside systemd the
+//! notifications do nothing and return `Ok(false)`.
+
+use std::time::Duration;
+
+use crate::error::Result;
+
+/// Send a raw notification such as `READY=1` or `STATUS=...`.
+///
+/// Returns whether a notification socket was found and the message sent.
+///
+/// # Errors
+///
+/// Returns an error if `NOTIFY_SOCKET` is set but the message cannot be
+/// delivered.
+pub fn notify(state: &str) -> Result<bool> {
+    match std::env::var("NOTIFY_SOCKET") {
+        Ok(socket) if !socket.is_empty() => {
+            send(&socket, state)?;
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
+/// Tell systemd that startup finished (`READY=1`).
+///
+/// # Errors
+///
+/// See [`notify`].
+pub fn ready() -> Result<bool> {
+    notify("READY=1")
+}
+
+/// Tell systemd that shutdown began (`STOPPING=1`).
+///
+/// # Errors
+///
+/// See [`notify`].
+pub fn stopping() -> Result<bool> {
+    notify("STOPPING=1")
+}
+
+/// Reset the watchdog timer (`WATCHDOG=1`).
+///
+/// # Errors
+///
+/// See [`notify`].
+pub fn watchdog() -> Result<bool> {
+    notify("WATCHDOG=1")
+}
+
+/// How often to call [`watchdog`]: half of `WATCHDOG_USEC`, or `None` when
+/// the watchdog is off or meant for another process (`WATCHDOG_PID`).
+#[must_use]
+pub fn watchdog_interval() -> Option<Duration> {
+    ping_interval(
+        std::env::var("WATCHDOG_USEC").ok().as_deref(),
+        std::env::var("WATCHDOG_PID").ok().as_deref(),
+        std::process::id(),
+    )
+}
+
+fn ping_interval(usec: Option<&str>, pid: Option<&str>, own_pid: u32) -> Option<Duration> {
+    if let Some(pid) = pid
+        && pid.parse::<u32>().ok() != Some(own_pid)
+    {
+        return None;
+    }
+    let usec: u64 = usec?.parse().ok().filter(|usec| *usec > 0)?;
+    Some(Duration::from_micros(usec / 2))
+}
+
+/// Deliver `state` to the socket at `socket`: a path, or `@name` for a
+/// Linux abstract socket.
+#[cfg(unix)]
+fn send(socket: &str, state: &str) -> Result<()> {
+    use std::os::unix::net::UnixDatagram;
+    use std::path::Path;
+
+    use crate::error::IoResultExt as _;
+
+    let sender = UnixDatagram::unbound()?;
+    if let Some(name) = socket.strip_prefix('@') {
+        #[cfg(target_os = "linux")]
+        {
+            use std::os::linux::net::SocketAddrExt as _;
+            use std::os::unix::net::SocketAddr;
+
+            let address = SocketAddr::from_abstract_name(name.as_bytes())?;
+            sender.send_to_addr(state.as_bytes(), &address)?;
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            return Err(crate::error::CoreError::Unsupported(format!(
+                "abstract notify socket @{name} outside Linux"
+            )));
+        }
+    } else {
+        sender
+            .send_to(state.as_bytes(), socket)
+            .at_path("sending service notification to", Path::new(socket))?;
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn send(_socket: &str, _state: &str) -> Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pings_at_half_the_watchdog_interval_for_this_process() {
+        assert_eq!(
+            ping_interval(Some("30000000"), None, 7),
+            Some(Duration::from_secs(15))
+        );
+        assert_eq!(
+            ping_interval(Some("30000000"), Some("7"), 7),
+            Some(Duration::from_secs(15))
+        );
+        assert_eq!(ping_interval(Some("30000000"), Some("8"), 7), None);
+        assert_eq!(ping_interval(Some("0"), None, 7), None);
+        assert_eq!(ping_interval(None, None, 7), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn delivers_to_a_socket_path() -> anyhow::Result<()> {
+        let path = std::env::temp_dir().join(format!("rust-core-notify-{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let receiver = std::os::unix::net::UnixDatagram::bind(&path)?;
+        send(&path.display().to_string(), "READY=1")?;
+        let mut buf = [0_u8; 64];
+        let len = receiver.recv(&mut buf)?;
+        anyhow::ensure!(&buf[..len] == b"READY=1");
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+}
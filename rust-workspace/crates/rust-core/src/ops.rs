@@ -0,0 +1,803 @@
+//! Operations every front end offers, with typed inputs and outputs.
+//!
+//! `rust-cli` subcommands and `rust-mcp` tools both call these instead of
+//! each doing the work themselves, so `config show` and `config_show`, or
+//! `run` and `run_task`, cannot drift apart. An [`Operation`] takes the
+//! resolved paths, the effective config, and the operation context in an
+//! [`Env`], plus its own input; the front end decides how to present the
+//! output. [`REGISTRY`] lists every operation by the name the MCP server
+//! offers it under.
+
+use std::ffi::OsString;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context as _, anyhow, bail};
+use log::{info, warn};
+use serde::Serialize;
+
+use crate::bus::Event;
+use crate::config::{self, AppConfig, RuntimeConfigExt as _, TaskConfig};
+use crate::ctx::{Ctx, correlation_env_var};
+use crate::error::CoreError;
+use crate::exit::ExitCode;
+use crate::history::{self, RunRecord};
+use crate::idle::{self, IdleWait};
+use crate::paths::{AppPaths, PathMode};
+use crate::plan::Action;
+use crate::task::{self, OutputReaders, RunOptions, TaskProcess, TaskRun, TaskStatus};
+
+/// What every operation runs against.
+#[derive(Debug, Clone, Copy)]
+pub struct Env<'a> {
+    /// Resolved application directories.
+    pub paths: &'a AppPaths,
+    /// Effective configuration.
+    pub config: &'a AppConfig,
+    /// Dry run, cancellation, progress, events, and correlation ID.
+    pub ctx: &'a Ctx,
+}
+
+/// One operation, run the same way by every front end.
+pub trait Operation {
+    /// Name the MCP server offers it under.
+    const NAME: &'static str;
+    /// What it does, for listings.
+    const DESCRIPTION: &'static str;
+    /// What the caller asks for.
+    type Input;
+    /// What it returns, for the front end to present.
+    type Output: Serialize;
+
+    /// Run the operation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error classified by [`ExitCode::from_error`] when the
+    /// operation cannot be done.
+    fn run(&self, env: Env<'_>, input: Self::Input) -> anyhow::Result<Self::Output>;
+}
+
+/// An operation's name and description, for [`REGISTRY`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Info {
+    /// [`Operation::NAME`].
+    pub name: &'static str,
+    /// [`Operation::DESCRIPTION`].
+    pub description: &'static str,
+}
+
+const fn info<O: Operation>() -> Info {
+    Info {
+        name: O::NAME,
+        description: O::DESCRIPTION,
+    }
+}
+
+/// Every operation.
+pub const REGISTRY: [Info; 3] = [
+    info::<ShowConfig>(),
+    info::<ResolvePaths>(),
+    info::<RunTask>(),
+];
+
+/// The effective config, optionally with secrets masked.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ShowConfig;
+
+/// Input of [`ShowConfig`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ShowConfigInput {
+    /// Mask secret values as [`config::redacted`] does.
+    pub redact: bool,
+}
+
+/// Output of [`ShowConfig`].
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum ShownConfig {
+    /// The config as loaded.
+    Full(Box<AppConfig>),
+    /// Its settings with secret values masked.
+    Redacted(serde_json::Value),
+}
+
+impl Operation for ShowConfig {
+    const NAME: &'static str = "config_show";
+    const DESCRIPTION: &'static str =
+        "The effective configuration: file, environment, and defaults";
+    type Input = ShowConfigInput;
+    type Output = ShownConfig;
+
+    fn run(&self, env: Env<'_>, input: ShowConfigInput) -> anyhow::Result<ShownConfig> {
+        Ok(if input.redact {
+            ShownConfig::Redacted(config::redacted(env.config)?)
+        } else {
+            ShownConfig::Full(Box::new(env.config.clone()))
+        })
+    }
+}
+
+/// The directory mode, config file, and data, state, and cache directories.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ResolvePaths;
+
+/// Output of [`ResolvePaths`].
+#[derive(Debug, Clone, Serialize)]
+pub struct ResolvedPaths {
+    /// Per-user or system-wide.
+    pub mode: PathMode,
+    /// Config file.
+    #[serde(with = "crate::pathfmt")]
+    pub config: PathBuf,
+    /// Data directory.
+    #[serde(with = "crate::pathfmt")]
+    pub data: PathBuf,
+    /// State directory.
+    #[serde(with = "crate::pathfmt")]
+    pub state: PathBuf,
+    /// Cache directory.
+    #[serde(with = "crate::pathfmt")]
+    pub cache: PathBuf,
+}
+
+impl Operation for ResolvePaths {
+    const NAME: &'static str = "config_paths";
+    const DESCRIPTION: &'static str =
+        "The config file and the data, state, and cache directories in use";
+    type Input = ();
+    type Output = ResolvedPaths;
+
+    fn run(&self, env: Env<'_>, (): ()) -> anyhow::Result<ResolvedPaths> {
+        Ok(ResolvedPaths {
+            mode: env.paths.mode,
+            config: env.paths.config_file.clone(),
+            data: env.paths.data_dir.clone(),
+            state: env.paths.state_dir.clone(),
+            cache: env.paths.cache_dir.clone(),
+        })
+    }
+}
+
+/// A task from the `[tasks]` config table and the tasks it depends on (see
+/// [`task`]).
+///
+/// Each task gets `<PREFIX>_TASK`, `<PREFIX>_PROFILE`, and the correlation
+/// ID variable in its environment. Outcomes are recorded in the state
+/// directory as they are known, and the run in the run history unless
+/// `history.record` is off. Under a dry run the tasks are planned, not run.
+/// The caller holds the instance lock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunTask;
+
+/// How long a run that captures task output waits, after its last task
+/// ends, for the rest of that output.
+pub const OUTPUT_WAIT: Duration = Duration::from_secs(1);
+
+/// Input of [`RunTask`].
+#[derive(Debug, Clone, Default)]
+pub struct RunTaskInput {
+    /// Task to run.
+    pub task: String,
+    /// Profile to run under instead of the configured one.
+    pub profile: Option<String>,
+    /// Most tasks at once instead of `runtime.parallelism`.
+    pub parallelism: Option<usize>,
+    /// Seconds for tasks without their own `timeout` instead of
+    /// `runtime.timeout`.
+    pub timeout: Option<u64>,
+    /// Wait until the machine is idle by the `[idle]` thresholds first.
+    pub when_idle: bool,
+    /// Binary `builtin` tasks run.
+    pub binary: PathBuf,
+    /// Global flags put before a `builtin` task's arguments.
+    pub globals: Vec<OsString>,
+    /// Give tasks no stdin.
+    pub null_stdin: bool,
+    /// Send task stdout to stderr, where it does not mix with a report.
+    pub stdout_to_stderr: bool,
+    /// Pipe task stdout and stderr and announce each line on the context's
+    /// event bus as [`Event::TaskOutput`] instead; the run waits up to
+    /// [`OUTPUT_WAIT`] after the last task for the rest of it.
+    pub capture_output: bool,
+    /// Command line recorded in the run history.
+    pub args: Vec<String>,
+}
+
+/// Output of [`RunTask`].
+#[derive(Debug, Serialize)]
+pub struct RunReport {
+    /// Task asked for.
+    pub task: String,
+    /// Profile it ran under.
+    pub profile: String,
+    /// False when the run was stopped before the first task started.
+    pub started: bool,
+    /// Most tasks that ran at once.
+    pub parallelism: usize,
+    /// Each task in the order it was planned, dependencies first.
+    pub tasks: Vec<TaskRun>,
+    /// Under a dry run, `tasks` lists what would run, as skipped.
+    pub dry_run: bool,
+    /// The limit the first task killed for running over it had.
+    #[serde(skip)]
+    limit: Option<Duration>,
+}
+
+impl RunReport {
+    /// Whether the tasks ran and all succeeded.
+    #[must_use]
+    pub fn succeeded(&self) -> bool {
+        self.started && self.tasks.iter().all(|run| run.status.is_success())
+    }
+
+    /// Why the run is a failure, if a task did not succeed. A task killed
+    /// for running over its limit makes it a [`CoreError::Timeout`], so the
+    /// run exits with the timeout code.
+    #[must_use]
+    pub fn failure(&self) -> Option<anyhow::Error> {
+        let unsuccessful: Vec<String> = self
+            .tasks
+            .iter()
+            .filter(|run| !run.status.is_success())
+            .map(|run| format!("{} ({})", run.name, run.status))
+            .collect();
+        if self.dry_run || unsuccessful.is_empty() {
+            return None;
+        }
+        let message = format!(
+            "task '{}' did not succeed: {}",
+            self.task,
+            unsuccessful.join(", ")
+        );
+        Some(match self.limit {
+            Some(limit) => anyhow::Error::new(CoreError::Timeout(limit)).context(message),
+            None => anyhow!(message),
+        })
+    }
+}
+
+impl Operation for RunTask {
+    const NAME: &'static str = "run_task";
+    const DESCRIPTION: &'static str =
+        "Run a task from the [tasks] config table and the tasks it depends on";
+    type Input = RunTaskInput;
+    type Output = RunReport;
+
+    fn run(&self, env: Env<'_>, input: RunTaskInput) -> anyhow::Result<RunReport> {
+        let config = env.config.clone().with_profile_override(input.profile);
+        let order = task::plan(&config.tasks, &input.task)?;
+        let ctx = env.ctx;
+        let mut report = RunReport {
+            task: input.task,
+            profile: config.profile.clone(),
+            started: false,
+            parallelism: input
+                .parallelism
+                .unwrap_or_else(|| config.runtime.effective_parallelism()),
+            tasks: Vec::new(),
+            dry_run: ctx.dry_run(),
+            limit: None,
+        };
+        if ctx.dry_run() {
+            for name in &order {
+                ctx.plan(Action::RunTask { name: name.clone() });
+            }
+            report.tasks = order.into_iter().map(skipped).collect();
+            return Ok(report);
+        }
+        if input.when_idle
+            && let IdleWait::TimedOut(reason) = idle::wait_until_idle(&config.idle, ctx)
+        {
+            bail!(
+                "task '{}' not started: the machine was not idle within idle.max_wait ({}s): {reason}",
+                report.task,
+                config.idle.max_wait
+            );
+        }
+        if let Some(reason) = ctx.stop_reason() {
+            info!("{reason}; not starting task '{}'", report.task);
+            return Ok(report);
+        }
+        report.started = true;
+
+        let prefix = crate::env_prefix();
+        let correlation_var = correlation_env_var();
+        let default_timeout = input
+            .timeout
+            .or(config.runtime.timeout)
+            .map(Duration::from_secs);
+        let options = RunOptions {
+            parallelism: report.parallelism,
+            fail_fast: config.runtime.fail_fast,
+            timeout: default_timeout,
+            work_root: Some(task::work_root(&env.paths.state_dir, ctx.correlation_id())),
+        };
+        record_outcomes(ctx, env.paths.state_dir.clone());
+        let started_at = epoch_secs();
+        let mut output = OutputReaders::default();
+        let sink = PtySink::of(input.capture_output, input.stdout_to_stderr);
+        report.tasks = task::run(
+            &config.tasks,
+            &order,
+            &options,
+            |name, task, work| {
+                let vars = vec![
+                    (format!("{prefix}_TASK"), name.to_string()),
+                    (format!("{prefix}_PROFILE"), config.profile.clone()),
+                    (correlation_var.clone(), ctx.correlation_id().to_string()),
+                ];
+                if task.pty
+                    && let Some(command) = &task.command
+                {
+                    return start_in_pty(env, sink, name, command, task, work, vars).map(Some);
+                }
+                let Some(mut process) = task::process(task, &input.binary, &input.globals)? else {
+                    return Ok(None);
+                };
+                if let Some(work) = work {
+                    process.current_dir(work);
+                }
+                process.envs(vars);
+                if input.null_stdin {
+                    process.stdin(Stdio::null());
+                }
+                if input.capture_output {
+                    process.stdout(Stdio::piped()).stderr(Stdio::piped());
+                } else if input.stdout_to_stderr {
+                    process.stdout(Stdio::from(io::stderr()));
+                }
+                let mut child = process
+                    .spawn()
+                    .with_context(|| format!("starting task {name}"))?;
+                output.read(name, &mut child, ctx.events());
+                Ok(Some(child.into()))
+            },
+            ctx,
+        );
+        output.finish(OUTPUT_WAIT);
+        report.limit = limit_hit(&config, &report.tasks, default_timeout);
+        if config.history.record {
+            remember(env, &config, &report, input.args, started_at);
+        }
+        Ok(report)
+    }
+}
+
+/// The time limit of the first task in `runs` that ran out of time, if one
+/// did: its own, or `default` for a task that does not set one.
+fn limit_hit(config: &AppConfig, runs: &[TaskRun], default: Option<Duration>) -> Option<Duration> {
+    runs.iter()
+        .find(|run| run.status == TaskStatus::TimedOut)
+        .and_then(|run| {
+            config
+                .tasks
+                .get(&run.name)
+                .and_then(|task| task.timeout.map(Duration::from_secs))
+                .or(default)
+        })
+}
+
+/// Where what a `pty` task renders goes, as [`RunTaskInput`] says for task
+/// output.
+#[derive(Debug, Clone, Copy)]
+enum PtySink {
+    /// Clean lines announced as [`Event::TaskOutput`].
+    Events,
+    /// Rendered bytes to stderr.
+    Stderr,
+    /// Rendered bytes to stdout.
+    Stdout,
+}
+
+impl PtySink {
+    /// The sink for [`RunTaskInput`]'s `capture_output` and
+    /// `stdout_to_stderr`.
+    const fn of(capture_output: bool, stdout_to_stderr: bool) -> Self {
+        if capture_output {
+            Self::Events
+        } else if stdout_to_stderr {
+            Self::Stderr
+        } else {
+            Self::Stdout
+        }
+    }
+}
+
+/// Start `command` for `pty` task `name` under a pseudo-terminal, keeping
+/// its clean transcript at [`task::log_path`].
+#[cfg(feature = "pty")]
+fn start_in_pty(
+    env: Env<'_>,
+    sink: PtySink,
+    name: &str,
+    command: &str,
+    task: &TaskConfig,
+    work: Option<&Path>,
+    vars: Vec<(String, String)>,
+) -> anyhow::Result<TaskProcess> {
+    use std::io::Write as _;
+
+    use crate::paths::expand_str_path;
+    use crate::pty::{self, PtyOptions};
+    use crate::task::OutputStream;
+
+    let cwd = match work {
+        Some(work) => Some(work.to_path_buf()),
+        None => task.cwd.as_deref().map(expand_str_path).transpose()?,
+    };
+    let options = PtyOptions {
+        cwd,
+        env: task.env.clone().into_iter().chain(vars).collect(),
+        log_file: Some(task::log_path(
+            &env.paths.state_dir,
+            env.ctx.correlation_id(),
+            name,
+        )?),
+    };
+    let (events, task) = (env.ctx.events().clone(), name.to_string());
+    let mut pending = Vec::new();
+    let on_output = move |chunk: &[u8]| match sink {
+        PtySink::Events => {
+            pending.extend_from_slice(chunk);
+            let end = if chunk.is_empty() {
+                pending.len()
+            } else {
+                pending
+                    .iter()
+                    .rposition(|&byte| byte == b'\n')
+                    .map_or(0, |at| at + 1)
+            };
+            let lines = pty::clean_transcript(&String::from_utf8_lossy(&pending[..end]));
+            pending.drain(..end);
+            for line in lines.lines() {
+                events.publish(&Event::TaskOutput {
+                    task: task.clone(),
+                    stream: OutputStream::Stdout,
+                    line: line.to_string(),
+                });
+            }
+        }
+        PtySink::Stderr => {
+            let _ = io::stderr().write_all(chunk);
+        }
+        PtySink::Stdout => {
+            let mut stdout = io::stdout();
+            let _ = stdout.write_all(chunk).and_then(|()| stdout.flush());
+        }
+    };
+    pty::spawn_in_pty(command, &options, on_output)
+        .map(TaskProcess::from)
+        .with_context(|| format!("starting task {name} under a pseudo-terminal"))
+}
+
+/// Without the `pty` feature, a `pty` task cannot start.
+#[cfg(not(feature = "pty"))]
+fn start_in_pty(
+    _env: Env<'_>,
+    _sink: PtySink,
+    name: &str,
+    _command: &str,
+    _task: &TaskConfig,
+    _work: Option<&Path>,
+    _vars: Vec<(String, String)>,
+) -> anyhow::Result<TaskProcess> {
+    bail!("task {name} sets pty, but this build has no `pty` feature")
+}
+
+/// `name` as a dry run lists it.
+const fn skipped(name: String) -> TaskRun {
+    TaskRun {
+        name,
+        status: TaskStatus::Skipped,
+        exit_code: None,
+        duration_ms: 0,
+        message: None,
+        work_dir: None,
+        usage: None,
+    }
+}
+
+/// Store each task outcome as soon as it is known, so a run that is killed
+/// still leaves the tasks that finished recorded.
+fn record_outcomes(ctx: &Ctx, state_dir: PathBuf) {
+    ctx.events().subscribe(move |event| {
+        if let Event::TaskFinished { run } = event
+            && let Err(err) = task::record(&state_dir, std::slice::from_ref(run))
+        {
+            warn!("could not record task history: {err}");
+        }
+    });
+}
+
+/// Add the run to the run history; failing to is only logged.
+fn remember(
+    env: Env<'_>,
+    config: &AppConfig,
+    report: &RunReport,
+    args: Vec<String>,
+    started_at: u64,
+) {
+    let run = RunRecord {
+        id: 0,
+        correlation_id: env.ctx.correlation_id().to_string(),
+        task: report.task.clone(),
+        profile: report.profile.clone(),
+        args: history::redact_args(args, &config.security.redact),
+        started_at,
+        finished_at: epoch_secs(),
+        exit_code: report
+            .failure()
+            .map_or(0, |err| ExitCode::from_error(&err).code()),
+        tasks: report.tasks.clone(),
+    };
+    if let Err(err) = history::record(&env.paths.state_dir, run, &config.history) {
+        warn!("could not record run history: {err}");
+    }
+}
+
+/// Seconds since the Unix epoch.
+fn epoch_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::bus::EventBus;
+    use crate::scratch::Scratch;
+    use crate::task::OutputStream;
+
+    fn tasks(config: &mut AppConfig) {
+        for (name, command, depends_on) in [
+            ("prepare", "true", vec![]),
+            ("build", "true", vec!["prepare".to_string()]),
+            ("broken", "exit 3", vec![]),
+        ] {
+            config.tasks.insert(
+                name.to_string(),
+                TaskConfig {
+                    command: Some(command.to_string()),
+                    depends_on,
+                    ..TaskConfig::default()
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn registry_names_are_unique() {
+        let mut names: Vec<&str> = REGISTRY.iter().map(|op| op.name).collect();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), REGISTRY.len());
+    }
+
+    #[test]
+    fn show_config_masks_secrets_when_asked() -> anyhow::Result<()> {
+        let scratch = Scratch::new()?;
+        let paths = scratch.paths();
+        let mut config = AppConfig::default();
+        config.security.redact = vec!["profile".to_string()];
+        let ctx = Ctx::default();
+        let env = Env {
+            paths: &paths,
+            config: &config,
+            ctx: &ctx,
+        };
+        let shown = serde_json::to_value(ShowConfig.run(env, ShowConfigInput { redact: true })?)?;
+        anyhow::ensure!(shown["profile"] == config::REDACTED, "{shown}");
+        let full = serde_json::to_value(ShowConfig.run(env, ShowConfigInput::default())?)?;
+        anyhow::ensure!(full["profile"] == config.profile.as_str(), "{full}");
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_paths_reports_the_directories_in_use() -> anyhow::Result<()> {
+        let scratch = Scratch::new()?;
+        let paths = scratch.paths();
+        let config = AppConfig::default();
+        let ctx = Ctx::default();
+        let env = Env {
+            paths: &paths,
+            config: &config,
+            ctx: &ctx,
+        };
+        let resolved = ResolvePaths.run(env, ())?;
+        anyhow::ensure!(
+            resolved.config == paths.config_file && resolved.state == paths.state_dir,
+            "{resolved:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn run_task_runs_dependencies_and_records_the_run() -> anyhow::Result<()> {
+        let scratch = Scratch::new()?;
+        let paths = scratch.paths();
+        let mut config = AppConfig::default();
+        tasks(&mut config);
+        let ctx = Ctx::default();
+        let env = Env {
+            paths: &paths,
+            config: &config,
+            ctx: &ctx,
+        };
+        let input = |task: &str| RunTaskInput {
+            task: task.to_string(),
+            null_stdin: true,
+            args: vec!["run".to_string(), task.to_string()],
+            ..RunTaskInput::default()
+        };
+        let report = RunTask.run(env, input("build"))?;
+        let names: Vec<&str> = report.tasks.iter().map(|run| run.name.as_str()).collect();
+        anyhow::ensure!(names == ["prepare", "build"], "{names:?}");
+        anyhow::ensure!(
+            report.succeeded() && report.failure().is_none(),
+            "{report:?}"
+        );
+
+        let report = RunTask.run(env, input("broken"))?;
+        anyhow::ensure!(!report.succeeded(), "{report:?}");
+        let failure = report.failure().map(|err| err.to_string());
+        anyhow::ensure!(
+            failure.as_deref() == Some("task 'broken' did not succeed: broken (failed)"),
+            "{failure:?}"
+        );
+        let runs = history::list(&paths.state_dir)?;
+        let codes: Vec<u8> = runs.iter().map(|run| run.exit_code).collect();
+        anyhow::ensure!(codes == [0, ExitCode::Failure.code()], "{codes:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn run_task_announces_captured_output_by_line() -> anyhow::Result<()> {
+        let scratch = Scratch::new()?;
+        let paths = scratch.paths();
+        let mut config = AppConfig::default();
+        config.tasks.insert(
+            "chatty".to_string(),
+            TaskConfig {
+                command: Some("echo one; echo two >&2; printf three".to_string()),
+                ..TaskConfig::default()
+            },
+        );
+        let bus = EventBus::default();
+        let lines = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&lines);
+        bus.subscribe(move |event| {
+            if let Event::TaskOutput { task, stream, line } = event
+                && let Ok(mut seen) = seen.lock()
+            {
+                seen.push((task.clone(), *stream, line.clone()));
+            }
+        });
+        let ctx = Ctx::default().with_events(bus);
+        let env = Env {
+            paths: &paths,
+            config: &config,
+            ctx: &ctx,
+        };
+        let input = RunTaskInput {
+            task: "chatty".to_string(),
+            null_stdin: true,
+            capture_output: true,
+            ..RunTaskInput::default()
+        };
+        anyhow::ensure!(RunTask.run(env, input)?.succeeded());
+        let mut lines = lines
+            .lock()
+            .map_err(|err| anyhow::anyhow!("{err}"))?
+            .clone();
+        lines.sort_by(|a, b| a.2.cmp(&b.2));
+        let chatty = |stream, line: &str| ("chatty".to_string(), stream, line.to_string());
+        anyhow::ensure!(
+            lines
+                == [
+                    chatty(OutputStream::Stdout, "one"),
+                    chatty(OutputStream::Stdout, "three"),
+                    chatty(OutputStream::Stderr, "two"),
+                ],
+            "{lines:?}"
+        );
+        Ok(())
+    }
+
+    #[cfg(all(unix, feature = "pty"))]
+    #[test]
+    fn run_task_runs_pty_tasks_in_a_terminal_and_keeps_the_transcript() -> anyhow::Result<()> {
+        use std::fs;
+
+        let scratch = Scratch::new()?;
+        let paths = scratch.paths();
+        let mut config = AppConfig::default();
+        for (name, command, timeout) in [
+            (
+                "tty",
+                r"test -t 1 && printf '\033[1mbold\033[0m\n10%%\r100%%\n' && printf done",
+                None,
+            ),
+            ("slow", "sleep 5", Some(1)),
+        ] {
+            config.tasks.insert(
+                name.to_string(),
+                TaskConfig {
+                    command: Some(command.to_string()),
+                    pty: true,
+                    timeout,
+                    ..TaskConfig::default()
+                },
+            );
+        }
+        let bus = EventBus::default();
+        let lines = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&lines);
+        bus.subscribe(move |event| {
+            if let Event::TaskOutput { line, .. } = event
+                && let Ok(mut seen) = seen.lock()
+            {
+                seen.push(line.clone());
+            }
+        });
+        let ctx = Ctx::default().with_events(bus);
+        let env = Env {
+            paths: &paths,
+            config: &config,
+            ctx: &ctx,
+        };
+        let input = |task: &str| RunTaskInput {
+            task: task.to_string(),
+            capture_output: true,
+            ..RunTaskInput::default()
+        };
+        let report = RunTask.run(env, input("tty"))?;
+        anyhow::ensure!(report.succeeded(), "{report:?}");
+        let lines = lines
+            .lock()
+            .map_err(|err| anyhow::anyhow!("{err}"))?
+            .clone();
+        anyhow::ensure!(lines == ["bold", "100%", "done"], "{lines:?}");
+        let log = task::log_path(&paths.state_dir, ctx.correlation_id(), "tty")?;
+        anyhow::ensure!(fs::read_to_string(&log)? == "bold\n100%\ndone");
+
+        let report = RunTask.run(env, input("slow"))?;
+        let statuses: Vec<TaskStatus> = report.tasks.iter().map(|run| run.status).collect();
+        anyhow::ensure!(statuses == [TaskStatus::TimedOut], "{report:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn run_task_under_dry_run_only_plans() -> anyhow::Result<()> {
+        let scratch = Scratch::new()?;
+        let paths = scratch.paths();
+        let mut config = AppConfig::default();
+        tasks(&mut config);
+        let ctx = Ctx::default().with_dry_run(true);
+        let env = Env {
+            paths: &paths,
+            config: &config,
+            ctx: &ctx,
+        };
+        let input = RunTaskInput {
+            task: "build".to_string(),
+            ..RunTaskInput::default()
+        };
+        let report = RunTask.run(env, input)?;
+        anyhow::ensure!(report.dry_run && report.failure().is_none(), "{report:?}");
+        anyhow::ensure!(
+            report
+                .tasks
+                .iter()
+                .all(|run| run.status == TaskStatus::Skipped),
+            "{report:?}"
+        );
+        anyhow::ensure!(ctx.planner().actions().len() == 2 && !paths.state_dir.exists());
+        Ok(())
+    }
+}
@@ -0,0 +1,307 @@
+//! Paths in text and structured output, whatever bytes they hold.
+//!
+//! serde writes a [`Path`] as a string and fails outright on one that is not
+//! valid Unicode, which a filename on Unix (arbitrary bytes) or Windows
+//! (unpaired UTF-16 surrogates) may well be. Fields marked
+//! `#[serde(with = "rust_core::pathfmt")]` (or [`option`], [`seq`]) are
+//! written as a plain string when they can be, and otherwise as an object
+//! carrying a lossy rendering for people and the exact OS encoding for
+//! programs:
+//!
+//! ```json
+//! {"lossy": "caf\ufffd.txt", "unix": "Y2Fm6S50eHQ="}
+//! ```
+//!
+//! `unix` holds the raw bytes, `windows` the UTF-16 code units in little
+//! endian, both base64. Reading accepts either form back. [`display`] is the
+//! counterpart for text: it shows extended-length (`\\?\`) paths, which
+//! canonicalizing yields on Windows, in their everyday form.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Write `path` losslessly; for `#[serde(with = "rust_core::pathfmt")]`.
+///
+/// # Errors
+///
+/// Returns the serializer's error.
+pub fn serialize<S: Serializer>(path: &Path, serializer: S) -> Result<S::Ok, S::Error> {
+    Encoded(path).serialize(serializer)
+}
+
+/// Read a path written by [`serialize`], or any plain string.
+///
+/// # Errors
+///
+/// Returns an error if the value is neither, or holds an encoding for
+/// another platform.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<PathBuf, D::Error> {
+    Decoded::deserialize(deserializer).map(|decoded| decoded.0)
+}
+
+/// [`serialize`] and [`deserialize`] for `Option<PathBuf>`.
+pub mod option {
+    use super::{Decoded, Encoded};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::path::PathBuf;
+
+    /// Write the path, if any, losslessly.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serializer's error.
+    pub fn serialize<S: Serializer>(
+        path: &Option<PathBuf>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        path.as_deref().map(Encoded).serialize(serializer)
+    }
+
+    /// Read a path written by [`serialize`], or `null`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not a path or `null`.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<PathBuf>, D::Error> {
+        Ok(Option::<Decoded>::deserialize(deserializer)?.map(|decoded| decoded.0))
+    }
+}
+
+/// [`serialize`] and [`deserialize`] for `Vec<PathBuf>`.
+pub mod seq {
+    use super::{Decoded, Encoded};
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::path::PathBuf;
+
+    /// Write each path losslessly.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serializer's error.
+    pub fn serialize<S: Serializer>(paths: &[PathBuf], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(paths.iter().map(|path| Encoded(path)))
+    }
+
+    /// Read a list of paths written by [`serialize`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not a list of paths.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<PathBuf>, D::Error> {
+        let decoded = Vec::<Decoded>::deserialize(deserializer)?;
+        Ok(decoded.into_iter().map(|decoded| decoded.0).collect())
+    }
+}
+
+/// `path` for people, with the extended-length prefix Windows adds to
+/// canonical paths (`\\?\C:\…`, `\\?\UNC\server\…`) left off. Anything else
+/// shows as [`Path::display`] does.
+#[must_use]
+pub const fn display(path: &Path) -> Display<'_> {
+    Display(path)
+}
+
+/// Helper returned by [`display`].
+#[derive(Debug, Clone, Copy)]
+pub struct Display<'a>(&'a Path);
+
+impl fmt::Display for Display<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // On Unix `\\?\` is an ordinary (if odd) name and stays as it is.
+        #[cfg(windows)]
+        if let Some(plain) = self.0.to_str().and_then(without_verbatim_prefix) {
+            return f.pad(&plain);
+        }
+        fmt::Display::fmt(&self.0.display(), f)
+    }
+}
+
+/// `text` without a `\\?\` prefix that only marks it extended-length, if it
+/// has one.
+#[cfg(any(windows, test))]
+fn without_verbatim_prefix(text: &str) -> Option<String> {
+    if let Some(share) = text.strip_prefix(r"\\?\UNC\") {
+        return Some(format!(r"\\{share}"));
+    }
+    let rest = text.strip_prefix(r"\\?\")?;
+    let bytes = rest.as_bytes();
+    // A drive path; other verbatim forms (volume GUIDs) have no plain one.
+    (bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':')
+        .then(|| rest.to_string())
+}
+
+/// A path as [`serialize`] writes it.
+struct Encoded<'a>(&'a Path);
+
+impl Serialize for Encoded<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if let Some(text) = self.0.to_str() {
+            return serializer.serialize_str(text);
+        }
+        let mut raw = Raw {
+            lossy: self.0.to_string_lossy().into_owned(),
+            unix: None,
+            windows: None,
+        };
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStrExt as _;
+            raw.unix = Some(STANDARD.encode(self.0.as_os_str().as_bytes()));
+        }
+        #[cfg(windows)]
+        {
+            use std::os::windows::ffi::OsStrExt as _;
+            let bytes: Vec<u8> = self
+                .0
+                .as_os_str()
+                .encode_wide()
+                .flat_map(u16::to_le_bytes)
+                .collect();
+            raw.windows = Some(STANDARD.encode(bytes));
+        }
+        raw.serialize(serializer)
+    }
+}
+
+/// A path as [`deserialize`] reads it.
+struct Decoded(PathBuf);
+
+impl<'de> Deserialize<'de> for Decoded {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match Repr::deserialize(deserializer)? {
+            Repr::Text(text) => Ok(Self(PathBuf::from(text))),
+            Repr::Raw(raw) => raw.decode().map(Self).map_err(D::Error::custom),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Repr {
+    Text(String),
+    Raw(Raw),
+}
+
+/// The object form of a path that is not valid Unicode.
+#[derive(Serialize, Deserialize)]
+struct Raw {
+    lossy: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    unix: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    windows: Option<String>,
+}
+
+impl Raw {
+    fn decode(self) -> Result<PathBuf, String> {
+        #[cfg(unix)]
+        if let Some(encoded) = &self.unix {
+            use std::os::unix::ffi::OsStringExt as _;
+            let bytes = STANDARD
+                .decode(encoded)
+                .map_err(|err| format!("path {}: {err}", self.lossy))?;
+            return Ok(PathBuf::from(std::ffi::OsString::from_vec(bytes)));
+        }
+        #[cfg(windows)]
+        if let Some(encoded) = &self.windows {
+            use std::os::windows::ffi::OsStringExt as _;
+            let bytes = STANDARD
+                .decode(encoded)
+                .map_err(|err| format!("path {}: {err}", self.lossy))?;
+            let units = bytes.chunks_exact(2);
+            if !units.remainder().is_empty() {
+                return Err(format!("path {}: odd number of UTF-16 bytes", self.lossy));
+            }
+            let units: Vec<u16> = units
+                .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+                .collect();
+            return Ok(PathBuf::from(std::ffi::OsString::from_wide(&units)));
+        }
+        Err(format!(
+            "path {} was written on another platform and cannot be restored here",
+            self.lossy
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Report {
+        #[serde(with = "crate::pathfmt")]
+        path: PathBuf,
+        #[serde(with = "crate::pathfmt::option")]
+        log: Option<PathBuf>,
+        #[serde(with = "crate::pathfmt::seq")]
+        removed: Vec<PathBuf>,
+    }
+
+    #[test]
+    fn unicode_paths_stay_plain_strings() -> anyhow::Result<()> {
+        let report = Report {
+            path: PathBuf::from("dir with spaces/naïve\n-name.txt"),
+            log: None,
+            removed: vec![PathBuf::from("x".repeat(300))],
+        };
+        let json = serde_json::to_value(&report)?;
+        anyhow::ensure!(json["path"] == "dir with spaces/naïve\n-name.txt");
+        anyhow::ensure!(
+            json["log"].is_null() && json["removed"][0].as_str().map(str::len) == Some(300)
+        );
+        anyhow::ensure!(serde_json::from_value::<Report>(json)? == report);
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_unicode_paths_round_trip() -> anyhow::Result<()> {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt as _;
+
+        let weird = Path::new(OsStr::from_bytes(b"caf\xe9.txt")).to_path_buf();
+        let report = Report {
+            path: weird.clone(),
+            log: Some(Path::new("logs").join(&weird)),
+            removed: vec![weird.clone(), PathBuf::from("plain")],
+        };
+        // Plain serde gives up on these.
+        anyhow::ensure!(serde_json::to_string(&weird).is_err());
+        let json = serde_json::to_value(&report)?;
+        anyhow::ensure!(
+            json["path"] == serde_json::json!({"lossy": "caf\u{fffd}.txt", "unix": "Y2Fm6S50eHQ="})
+        );
+        anyhow::ensure!(json["removed"][1] == "plain");
+        anyhow::ensure!(serde_json::from_value::<Report>(json)? == report);
+
+        // An encoding from elsewhere is refused rather than guessed at.
+        let foreign = serde_json::json!({"lossy": "x", "windows": "eAA="});
+        anyhow::ensure!(deserialize(foreign).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn drops_only_the_extended_length_prefix() {
+        assert_eq!(
+            without_verbatim_prefix(r"\\?\C:\Users\me\app").as_deref(),
+            Some(r"C:\Users\me\app")
+        );
+        assert_eq!(
+            without_verbatim_prefix(r"\\?\UNC\server\share\x").as_deref(),
+            Some(r"\\server\share\x")
+        );
+        assert_eq!(without_verbatim_prefix(r"\\?\Volume{0b1c}\x"), None);
+        assert_eq!(without_verbatim_prefix(r"C:\plain"), None);
+        assert_eq!(display(Path::new("/srv/app")).to_string(), "/srv/app");
+    }
+}
@@ -2,30 +2,105 @@
 
 use std::env;
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
-use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
 
-use crate::{APP_NAME, AppConfig};
+use crate::cache::Cache;
+use crate::config::{AppConfigExt as _, Override};
+use crate::ctx::Ctx;
+use crate::error::{CoreError, IoResultExt as _, Result};
+use crate::journal::JOURNAL_DIRNAME;
+use crate::lock::LOCK_FILENAME;
+use crate::pathfmt;
+use crate::plan::Action;
+use crate::platform;
+use crate::temp::{self, STALE_AFTER, TEMP_DIRNAME, TempScope};
+use crate::{APP_NAME, AppConfig, env_prefix};
+
+/// Whether directories resolve per user (XDG) or system-wide for services.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PathMode {
+    /// Per-user XDG / platform directories.
+    #[default]
+    User,
+    /// System-wide directories (`/etc`, `/var/lib`, `/var/cache`) for
+    /// service deployments.
+    System,
+}
+
+impl PathMode {
+    /// System mode when running as root (euid 0), user mode otherwise.
+    #[must_use]
+    pub fn detect() -> Self {
+        if running_as_root() {
+            Self::System
+        } else {
+            Self::User
+        }
+    }
+
+    /// Lowercase name, as used in output.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::User => "user",
+            Self::System => "system",
+        }
+    }
+}
+
+/// Returns true if the effective user is root. Always false off unix.
+#[must_use]
+pub fn running_as_root() -> bool {
+    #[cfg(unix)]
+    {
+        nix::unistd::geteuid().is_root()
+    }
+    #[cfg(not(unix))]
+    {
+        false
+    }
+}
 
 /// Application paths for config, data, and state directories.
 #[derive(Debug, Clone)]
 pub struct AppPaths {
+    /// Whether these paths are per-user or system-wide.
+    pub mode: PathMode,
     /// Path to the configuration file.
     pub config_file: PathBuf,
     /// Directory for persistent application data.
     pub data_dir: PathBuf,
     /// Directory for application state files.
     pub state_dir: PathBuf,
+    /// Directory for disposable cached data and temp scopes.
+    pub cache_dir: PathBuf,
 }
 
 impl AppPaths {
-    /// Discover application paths, optionally overriding the config file location.
+    /// Discover application paths in the mode chosen by [`PathMode::detect`].
     ///
     /// # Errors
     ///
     /// Returns an error if paths cannot be resolved or expanded.
     pub fn discover(override_path: Option<&Path>) -> Result<Self> {
+        Self::discover_in(PathMode::detect(), override_path)
+    }
+
+    /// Discover application paths, optionally overriding the config file location.
+    ///
+    /// Each directory can be pinned with `<PREFIX>_CONFIG_DIR`,
+    /// `<PREFIX>_DATA_DIR`, `<PREFIX>_STATE_DIR`, or `<PREFIX>_CACHE_DIR`
+    /// (see [`dir_env_var`]), which take precedence over the XDG and platform
+    /// defaults for `mode`. An explicit `override_path` still wins for the
+    /// config file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if paths cannot be resolved or expanded.
+    pub fn discover_in(mode: PathMode, override_path: Option<&Path>) -> Result<Self> {
         let config_file = match override_path {
             Some(path) => {
                 let expanded = expand_path(path)?;
@@ -35,61 +110,258 @@ impl AppPaths {
                     expanded
                 }
             }
-            None => default_config_dir()?.join("config.toml"),
+            None => env_dir_override(DirKind::Config)?
+                .map_or_else(|| default_dir(mode, DirKind::Config), Ok)?
+                .join("config.toml"),
         };
 
         if config_file.parent().is_none() {
-            return Err(anyhow!(
+            return Err(CoreError::Path(format!(
                 "invalid config file path: {}",
                 config_file.display()
-            ));
+            )));
         }
 
-        let data_dir = default_data_dir()?;
-        let state_dir = default_state_dir()?;
+        let resolve = |kind| env_dir_override(kind)?.map_or_else(|| default_dir(mode, kind), Ok);
+        let data_dir = resolve(DirKind::Data)?;
+        let state_dir = resolve(DirKind::State)?;
+        let cache_dir = resolve(DirKind::Cache)?;
 
         Ok(Self {
+            mode,
             config_file,
             data_dir,
             state_dir,
+            cache_dir,
         })
     }
 
     /// Apply path overrides from configuration.
     ///
+    /// Directories pinned through their environment variable keep the
+    /// environment value; the config file only overrides the defaults.
+    ///
     /// # Errors
     ///
     /// Returns an error if override paths cannot be expanded.
     pub fn apply_overrides(mut self, cfg: &AppConfig) -> Result<Self> {
-        if let Some(ref data_override) = cfg.paths.data_dir {
+        if let Some(ref data_override) = cfg.paths.data_dir
+            && env_dir_override(DirKind::Data)?.is_none()
+        {
             self.data_dir = expand_str_path(data_override)?;
         }
-        if let Some(ref state_override) = cfg.paths.state_dir {
+        if let Some(ref state_override) = cfg.paths.state_dir
+            && env_dir_override(DirKind::State)?.is_none()
+        {
             self.state_dir = expand_str_path(state_override)?;
         }
         Ok(self)
     }
 
-    /// Ensure all required directories exist.
+    /// Discover the paths, load the config they name with `overrides` on
+    /// top, and move the data and state directories where its `[paths]`
+    /// table puts them. Every binary that loads the config starts here.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Self::discover_in`],
+    /// [`AppConfigExt::load_with_overrides`], and [`Self::apply_overrides`].
+    pub fn load(
+        mode: PathMode,
+        override_path: Option<&Path>,
+        ctx: &Ctx,
+        overrides: &[Override],
+    ) -> Result<(Self, AppConfig)> {
+        let paths = Self::discover_in(mode, override_path)?;
+        let config = AppConfig::load_with_overrides(&paths, ctx, overrides)?;
+        Ok((paths.apply_overrides(&config)?, config))
+    }
+
+    /// Ensure all required directories exist, returning those that had to be
+    /// created (for relabeling via [`crate::security::relabel`]).
     ///
     /// # Errors
     ///
     /// Returns an error if directories cannot be created.
-    pub fn ensure_directories(&self) -> Result<()> {
-        fs::create_dir_all(&self.data_dir)
-            .with_context(|| format!("creating data directory {}", self.data_dir.display()))?;
-        fs::create_dir_all(&self.state_dir)
-            .with_context(|| format!("creating state directory {}", self.state_dir.display()))?;
-        Ok(())
+    pub fn ensure_directories(&self) -> Result<Vec<PathBuf>> {
+        let mut created = Vec::new();
+        for kind in [DirKind::Data, DirKind::State] {
+            let dir = self.dir(kind);
+            if dir.is_dir() {
+                continue;
+            }
+            fs::create_dir_all(dir).at_path("creating directory", dir)?;
+            created.push(dir.to_path_buf());
+        }
+        Ok(created)
     }
 
-    /// Log directory creation in dry-run mode.
-    pub fn log_dry_run(&self) {
-        log::info!(
-            "dry-run: would ensure data dir {} and state dir {}",
-            self.data_dir.display(),
-            self.state_dir.display()
-        );
+    /// Resolve a user-supplied path and require that it stays inside `base`.
+    ///
+    /// The path is expanded (`~`, environment variables), joined onto `base`
+    /// when relative, and canonicalized so both `..` traversal and symlinks
+    /// are followed before the containment check. Paths that do not exist
+    /// yet are allowed as long as their nearest existing ancestor lies inside
+    /// `base`, and a symlink whose target is missing is judged by its target.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `base` cannot be canonicalized, expansion fails, or
+    /// the resolved path escapes `base`.
+    pub fn resolve_within(base: &Path, user_supplied: impl AsRef<Path>) -> Result<PathBuf> {
+        let user_supplied = user_supplied.as_ref();
+        let base = base
+            .canonicalize()
+            .at_path("resolving base directory", base)?;
+        let expanded = expand_path(user_supplied)?;
+        let resolved = canonicalize_lenient(&base.join(expanded))?;
+        if resolved.starts_with(&base) {
+            Ok(resolved)
+        } else {
+            Err(CoreError::Path(format!(
+                "path {} escapes {}",
+                user_supplied.display(),
+                pathfmt::display(&base)
+            )))
+        }
+    }
+
+    /// Directory holding temp scopes (`<cache_dir>/tmp`).
+    #[must_use]
+    pub fn temp_root(&self) -> PathBuf {
+        self.cache_dir.join(TEMP_DIRNAME)
+    }
+
+    /// Create a temp directory under the cache dir that is removed on drop.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be created.
+    pub fn temp_scope(&self) -> Result<TempScope> {
+        TempScope::new_in(&self.temp_root())
+    }
+
+    /// Remove temp scopes abandoned by earlier runs (only log them on a dry
+    /// run).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the temp root exists but cannot be read.
+    pub fn sweep_stale_temp(&self, ctx: &Ctx) -> Result<usize> {
+        temp::sweep_stale(&self.temp_root(), STALE_AFTER, ctx)
+    }
+
+    /// Directory of the given kind; for [`DirKind::Config`] this is the
+    /// directory containing the config file.
+    #[must_use]
+    pub fn dir(&self, kind: DirKind) -> &Path {
+        match kind {
+            DirKind::Config => self.config_file.parent().unwrap_or_else(|| Path::new(".")),
+            DirKind::Data => &self.data_dir,
+            DirKind::State => &self.state_dir,
+            DirKind::Cache => &self.cache_dir,
+        }
+    }
+
+    /// Check that existing directories belong to the effective user.
+    ///
+    /// Mixing `sudo` with per-user directories tends to leave files owned by
+    /// the wrong account. A directory owned by someone else that is still
+    /// writable (typically root working in a user's directories) produces a
+    /// warning, which is returned; one that is not writable is an error with
+    /// a hint on how to fix it. Always succeeds off unix.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::Permission`] naming every unwritable directory
+    /// owned by another user.
+    pub fn check_ownership(&self) -> Result<Vec<String>> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt as _;
+
+            let euid = nix::unistd::geteuid().as_raw();
+            let mut warnings = Vec::new();
+            let mut errors = Vec::new();
+            for kind in DirKind::ALL {
+                let dir = self.dir(kind);
+                let Ok(meta) = fs::metadata(dir) else {
+                    continue;
+                };
+                if meta.uid() == euid {
+                    continue;
+                }
+                let owner = user_name(meta.uid());
+                let writable = nix::unistd::access(dir, nix::unistd::AccessFlags::W_OK).is_ok();
+                if writable {
+                    warnings.push(format!(
+                        "{} directory {} is owned by {owner}; files created now will belong to {}",
+                        kind.name(),
+                        dir.display(),
+                        user_name(euid)
+                    ));
+                } else {
+                    errors.push(format!(
+                        "{} directory {} is owned by {owner} and not writable by {}",
+                        kind.name(),
+                        dir.display(),
+                        user_name(euid)
+                    ));
+                }
+            }
+            if errors.is_empty() {
+                return Ok(warnings);
+            }
+            let hint = match self.mode {
+                PathMode::User => format!(
+                    "this usually happens after running with sudo; fix with `sudo chown -R {} <dir>`, or use --system for a service install",
+                    user_name(euid)
+                ),
+                PathMode::System => {
+                    "run as root or as the service account that owns these directories, or use --user"
+                        .to_string()
+                }
+            };
+            Err(CoreError::Permission(format!(
+                "{}; {hint}",
+                errors.join("; ")
+            )))
+        }
+        #[cfg(not(unix))]
+        {
+            Ok(Vec::new())
+        }
+    }
+
+    /// Content cache rooted at the cache directory.
+    #[must_use]
+    pub fn cache(&self) -> Cache {
+        Cache::open(&self.cache_dir)
+    }
+
+    /// Path of the single-instance lock file inside the state directory.
+    #[must_use]
+    pub fn lock_file(&self) -> PathBuf {
+        self.state_dir.join(LOCK_FILENAME)
+    }
+
+    /// Directory of the write-ahead journal (`<state_dir>/journal`).
+    #[must_use]
+    pub fn journal_dir(&self) -> PathBuf {
+        self.state_dir.join(JOURNAL_DIRNAME)
+    }
+
+    /// Record in `ctx`'s plan the directories [`Self::ensure_directories`]
+    /// would create.
+    pub fn plan_directories(&self, ctx: &Ctx) {
+        for kind in [DirKind::Data, DirKind::State] {
+            let dir = self.dir(kind);
+            if !dir.is_dir() {
+                ctx.plan(Action::CreateDir {
+                    path: dir.to_path_buf(),
+                });
+            }
+        }
     }
 }
 
@@ -97,34 +369,187 @@ impl std::fmt::Display for AppPaths {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "config: {}, data: {}, state: {}",
+            "config: {}, data: {}, state: {}, cache: {}",
             self.config_file.display(),
             self.data_dir.display(),
-            self.state_dir.display()
+            self.state_dir.display(),
+            self.cache_dir.display()
         )
     }
 }
 
 /// Expand a `PathBuf`, resolving ~ and environment variables.
 ///
+/// A path that is not valid Unicode is expanded a component at a time:
+/// those that are valid are expanded (`~` only in the first), the others are
+/// kept byte for byte.
+///
 /// # Errors
 ///
 /// Returns an error if shell expansion fails.
 pub fn expand_path(path: &Path) -> Result<PathBuf> {
-    path.to_str()
-        .map_or_else(|| Ok(path.to_path_buf()), expand_str_path)
+    if let Some(text) = path.to_str() {
+        return expand_str_path(text);
+    }
+    let mut expanded = PathBuf::new();
+    for (index, component) in path.components().enumerate() {
+        match component.as_os_str().to_str() {
+            Some(text) if index == 0 => expanded.push(expand_str_path(text)?),
+            Some(text) => {
+                let text = shellexpand::env(text).map_err(|err| CoreError::EnvVar {
+                    name: err.var_name,
+                    message: format!("{} (expanding {})", err.cause, path.display()),
+                })?;
+                // A value starting with a separator continues the path, as it
+                // would in a string, rather than replacing it.
+                expanded.push(text.trim_start_matches(std::path::is_separator));
+            }
+            None => expanded.push(component),
+        }
+    }
+    Ok(expanded)
 }
 
 /// Expand a string path, resolving ~ and environment variables.
 ///
 /// # Errors
 ///
-/// Returns an error if shell expansion fails.
+/// Returns [`CoreError::EnvVar`] if a referenced variable is unset or not
+/// valid Unicode.
 pub fn expand_str_path(text: &str) -> Result<PathBuf> {
-    let expanded = shellexpand::full(text).context("expanding path")?;
+    let expanded = shellexpand::full(text).map_err(|err| CoreError::EnvVar {
+        name: err.var_name,
+        message: format!("{} (expanding {text})", err.cause),
+    })?;
     Ok(PathBuf::from(expanded.to_string()))
 }
 
+/// Application directories that can be pinned through the environment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DirKind {
+    /// Directory containing `config.toml`.
+    Config,
+    /// Persistent data directory.
+    Data,
+    /// State directory.
+    State,
+    /// Cache directory.
+    Cache,
+}
+
+impl DirKind {
+    /// Every directory kind, in display order.
+    pub const ALL: [Self; 4] = [Self::Config, Self::Data, Self::State, Self::Cache];
+
+    /// Lowercase name used in reports (`config`, `data`, `state`, `cache`).
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Config => "config",
+            Self::Data => "data",
+            Self::State => "state",
+            Self::Cache => "cache",
+        }
+    }
+
+    const fn suffix(self) -> &'static str {
+        match self {
+            Self::Config => "CONFIG_DIR",
+            Self::Data => "DATA_DIR",
+            Self::State => "STATE_DIR",
+            Self::Cache => "CACHE_DIR",
+        }
+    }
+
+    /// `XDG_*` variable, path under the home directory, and Windows known
+    /// directory variable for the per-user base directory.
+    const fn base_vars(self) -> (&'static str, &'static str, &'static str) {
+        match self {
+            Self::Config => ("XDG_CONFIG_HOME", ".config", "APPDATA"),
+            Self::Data => ("XDG_DATA_HOME", ".local/share", "APPDATA"),
+            Self::State => ("XDG_STATE_HOME", ".local/state", "LOCALAPPDATA"),
+            Self::Cache => ("XDG_CACHE_HOME", ".cache", "LOCALAPPDATA"),
+        }
+    }
+}
+
+/// Name of the environment variable that pins a directory, e.g.
+/// `RUST_WORKSPACE_DATA_DIR`.
+#[must_use]
+pub fn dir_env_var(kind: DirKind) -> String {
+    format!("{}_{}", env_prefix(), kind.suffix())
+}
+
+/// Read a directory override from the environment, ignoring empty values.
+///
+/// # Errors
+///
+/// Returns an error if the value cannot be expanded.
+pub fn env_dir_override(kind: DirKind) -> Result<Option<PathBuf>> {
+    match env::var_os(dir_env_var(kind)) {
+        Some(value) if !value.is_empty() => expand_path(Path::new(&value)).map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Dangling symlinks [`canonicalize_lenient`] follows before giving up, as
+/// many as Linux follows in one lookup.
+const MAX_LINK_HOPS: u32 = 40;
+
+/// Canonicalize a path that may not exist yet.
+///
+/// The longest existing ancestor is canonicalized (resolving symlinks) and the
+/// missing tail is normalized lexically. The first missing component can
+/// still be a symlink whose target is missing; it is followed by hand, so
+/// where it points counts rather than where it sits.
+fn canonicalize_lenient(path: &Path) -> Result<PathBuf> {
+    canonicalize_following(path, MAX_LINK_HOPS)
+}
+
+/// [`canonicalize_lenient`], following at most `hops` dangling symlinks.
+fn canonicalize_following(path: &Path, hops: u32) -> Result<PathBuf> {
+    let components: Vec<Component<'_>> = path.components().collect();
+    for split in (1..=components.len()).rev() {
+        let prefix: PathBuf = components[..split].iter().collect();
+        let mut resolved = match prefix.canonicalize() {
+            Ok(resolved) => resolved,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(err) => {
+                return Err(err).at_path("resolving", &prefix);
+            }
+        };
+        let tail = &components[split..];
+        for (index, component) in tail.iter().enumerate() {
+            match component {
+                Component::ParentDir => {
+                    resolved.pop();
+                }
+                Component::Normal(name) => {
+                    resolved.push(name);
+                    if let Ok(target) = fs::read_link(&resolved) {
+                        if hops == 0 {
+                            return Err(CoreError::Path(format!(
+                                "too many symlinks resolving {}",
+                                path.display()
+                            )));
+                        }
+                        resolved.pop();
+                        let rest: PathBuf = tail[index + 1..].iter().collect();
+                        return canonicalize_following(&resolved.join(target).join(rest), hops - 1);
+                    }
+                }
+                Component::CurDir | Component::RootDir | Component::Prefix(_) => {}
+            }
+        }
+        return Ok(resolved);
+    }
+    Err(CoreError::Path(format!(
+        "cannot resolve {}",
+        path.display()
+    )))
+}
+
 /// Resolve a base directory deterministically (pure; unit-tested below).
 ///
 /// An explicit, absolute `XDG_*` path wins on any OS; otherwise `~/<unix_rel>`
@@ -147,9 +572,19 @@ fn resolve_base(
     }
 }
 
+/// The active [`Defaults`](crate::platform::Defaults) provider's per-user
+/// base directory for `kind`.
+fn base_dir(kind: DirKind) -> Result<PathBuf> {
+    platform::current(|defaults| defaults.user_base_dir(kind)).ok_or_else(|| CoreError::EnvVar {
+        name: kind.base_vars().0.to_string(),
+        message: "not set, and no home directory to fall back on".to_string(),
+    })
+}
+
 /// Read the relevant env vars and resolve a base dir via [`resolve_base`].
 /// Zero-dependency: no `dirs` crate (whose macOS default we deliberately avoid).
-fn base_dir(xdg_var: &str, unix_rel: &str, win_var: &str) -> Result<PathBuf> {
+pub(crate) fn native_base_dir(kind: DirKind) -> Option<PathBuf> {
+    let (xdg_var, unix_rel, win_var) = kind.base_vars();
     resolve_base(
         env::var_os(xdg_var).map(PathBuf::from),
         env::var_os("HOME").map(PathBuf::from),
@@ -157,7 +592,62 @@ fn base_dir(xdg_var: &str, unix_rel: &str, win_var: &str) -> Result<PathBuf> {
         cfg!(windows),
         unix_rel,
     )
-    .ok_or_else(|| anyhow!("unable to determine base directory ({xdg_var})"))
+}
+
+/// Default directory of `kind` for `mode`.
+///
+/// # Errors
+///
+/// Returns an error if no per-user base directory can be determined.
+pub fn default_dir(mode: PathMode, kind: DirKind) -> Result<PathBuf> {
+    match (mode, kind) {
+        (PathMode::System, kind) => Ok(system_dir(kind)),
+        (PathMode::User, DirKind::Config) => default_config_dir(),
+        (PathMode::User, DirKind::Data) => default_data_dir(),
+        (PathMode::User, DirKind::State) => default_state_dir(),
+        (PathMode::User, DirKind::Cache) => default_cache_dir(),
+    }
+}
+
+/// System-wide directory of `kind` used in [`PathMode::System`], from the
+/// active [`Defaults`](crate::platform::Defaults) provider.
+#[must_use]
+pub fn system_dir(kind: DirKind) -> PathBuf {
+    platform::current(|defaults| defaults.system_dir(kind))
+}
+
+/// The built-in system directories.
+///
+/// On unix: `/etc/<app>`, `/var/lib/<app>`, `/var/lib/<app>/state`, and
+/// `/var/cache/<app>`. On Windows everything lives under
+/// `%ProgramData%\<app>`.
+pub(crate) fn native_system_dir(kind: DirKind) -> PathBuf {
+    if cfg!(windows) {
+        let base = env::var_os("PROGRAMDATA")
+            .map_or_else(|| PathBuf::from(r"C:\ProgramData"), PathBuf::from)
+            .join(APP_NAME);
+        return match kind {
+            DirKind::Config => base,
+            DirKind::Data => base.join("data"),
+            DirKind::State => base.join("state"),
+            DirKind::Cache => base.join("cache"),
+        };
+    }
+    match kind {
+        DirKind::Config => Path::new("/etc").join(APP_NAME),
+        DirKind::Data => Path::new("/var/lib").join(APP_NAME),
+        DirKind::State => Path::new("/var/lib").join(APP_NAME).join("state"),
+        DirKind::Cache => Path::new("/var/cache").join(APP_NAME),
+    }
+}
+
+/// User name for `uid`, or the numeric uid if it has no passwd entry.
+#[cfg(unix)]
+pub(crate) fn user_name(uid: u32) -> String {
+    nix::unistd::User::from_uid(nix::unistd::Uid::from_raw(uid))
+        .ok()
+        .flatten()
+        .map_or_else(|| uid.to_string(), |user| user.name)
 }
 
 /// Get the default configuration directory.
@@ -169,7 +659,7 @@ fn base_dir(xdg_var: &str, unix_rel: &str, win_var: &str) -> Result<PathBuf> {
 ///
 /// Returns an error if no base directory can be determined.
 pub fn default_config_dir() -> Result<PathBuf> {
-    Ok(base_dir("XDG_CONFIG_HOME", ".config", "APPDATA")?.join(APP_NAME))
+    Ok(base_dir(DirKind::Config)?.join(APP_NAME))
 }
 
 /// Get the default data directory (`XDG_DATA_HOME`; else `~/.local/share` / `%APPDATA%`).
@@ -178,7 +668,7 @@ pub fn default_config_dir() -> Result<PathBuf> {
 ///
 /// Returns an error if no base directory can be determined.
 pub fn default_data_dir() -> Result<PathBuf> {
-    Ok(base_dir("XDG_DATA_HOME", ".local/share", "APPDATA")?.join(APP_NAME))
+    Ok(base_dir(DirKind::Data)?.join(APP_NAME))
 }
 
 /// Get the default state directory (`XDG_STATE_HOME`; else `~/.local/state` / `%LOCALAPPDATA%`).
@@ -187,7 +677,7 @@ pub fn default_data_dir() -> Result<PathBuf> {
 ///
 /// Returns an error if no base directory can be determined.
 pub fn default_state_dir() -> Result<PathBuf> {
-    Ok(base_dir("XDG_STATE_HOME", ".local/state", "LOCALAPPDATA")?.join(APP_NAME))
+    Ok(base_dir(DirKind::State)?.join(APP_NAME))
 }
 
 /// Get the default cache directory (`XDG_CACHE_HOME`; else `~/.cache` / `%LOCALAPPDATA%`).
@@ -196,29 +686,35 @@ pub fn default_state_dir() -> Result<PathBuf> {
 ///
 /// Returns an error if no base directory can be determined.
 pub fn default_cache_dir() -> Result<PathBuf> {
-    Ok(base_dir("XDG_CACHE_HOME", ".cache", "LOCALAPPDATA")?.join(APP_NAME))
+    Ok(base_dir(DirKind::Cache)?.join(APP_NAME))
 }
 
-/// Write the default configuration file to the specified path.
+/// Write the default configuration file to the specified path, with a
+/// `#:schema` directive naming the published schema.
 ///
 /// # Errors
 ///
 /// Returns an error if the file cannot be written or the directory cannot be created.
 pub fn write_default_config(path: &Path) -> Result<()> {
     if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent)
-            .with_context(|| format!("creating config directory {}", parent.display()))?;
+        fs::create_dir_all(parent).at_path("creating config directory", parent)?;
     }
 
-    let config = AppConfig::default();
-    let toml_str = toml::to_string_pretty(&config).context("serializing default config to TOML")?;
+    let config = crate::config::defaults();
+    let toml_str = toml::to_string_pretty(&config)
+        .map_err(|err| CoreError::Serialization(format!("default config: {err}")))?;
     let mut body = default_config_header(path);
     body.push_str(&toml_str);
-    fs::write(path, body).with_context(|| format!("writing config file to {}", path.display()))
+    fs::write(path, body).at_path("writing config file", path)
 }
 
 fn default_config_header(path: &Path) -> String {
     let mut buffer = String::new();
+    buffer.push_str("#:schema ");
+    buffer.push_str(&crate::schema::schema_url(APP_NAME));
+    buffer.push('\n');
+    buffer.push_str(&crate::migrate::version_line());
+    buffer.push('\n');
     buffer.push_str("# Configuration for ");
     buffer.push_str(APP_NAME);
     buffer.push('\n');
@@ -232,6 +728,144 @@ fn default_config_header(path: &Path) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::scratch::Scratch;
+
+    /// A scratch directory with an `inside` directory, and its canonical path.
+    fn scratch_dir() -> Result<(Scratch, PathBuf)> {
+        let scratch = Scratch::new()?;
+        fs::create_dir_all(scratch.join("inside"))?;
+        let dir = scratch.canonicalize()?;
+        Ok((scratch, dir))
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn system_mode_uses_fhs_locations() -> anyhow::Result<()> {
+        let dirs = DirKind::ALL.map(|kind| default_dir(PathMode::System, kind));
+        let expected = [
+            format!("/etc/{APP_NAME}"),
+            format!("/var/lib/{APP_NAME}"),
+            format!("/var/lib/{APP_NAME}/state"),
+            format!("/var/cache/{APP_NAME}"),
+        ];
+        for (dir, expected) in dirs.into_iter().zip(expected) {
+            let dir = dir?;
+            anyhow::ensure!(dir == Path::new(&expected), "got {}", dir.display());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_within_accepts_nested_and_missing_paths() -> anyhow::Result<()> {
+        let (_scratch, base) = scratch_dir()?;
+        let existing = AppPaths::resolve_within(&base, "inside")?;
+        let missing = AppPaths::resolve_within(&base, "inside/new/../file.txt")?;
+        anyhow::ensure!(
+            existing == base.join("inside"),
+            "got {}",
+            existing.display()
+        );
+        anyhow::ensure!(
+            missing == base.join("inside/file.txt"),
+            "got {}",
+            missing.display()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_within_rejects_parent_traversal() -> anyhow::Result<()> {
+        let (_scratch, base) = scratch_dir()?;
+        let base = base.join("inside");
+        for escape in ["..", "../outside", "new/../../x", "/etc/passwd"] {
+            let got = AppPaths::resolve_within(&base, escape);
+            anyhow::ensure!(got.is_err(), "{escape} resolved to {got:?}");
+        }
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn resolve_within_rejects_symlink_escape() -> anyhow::Result<()> {
+        let (_scratch, base) = scratch_dir()?;
+        let base = base.join("inside");
+        let link = base.join("escape");
+        if !link.exists() {
+            std::os::unix::fs::symlink(env::temp_dir(), &link)?;
+        }
+        let got = AppPaths::resolve_within(&base, "escape/file");
+        anyhow::ensure!(got.is_err(), "symlink escape resolved to {got:?}");
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn resolve_within_follows_dangling_symlinks() -> anyhow::Result<()> {
+        let (_scratch, root) = scratch_dir()?;
+        let base = root.join("inside");
+        std::os::unix::fs::symlink(root.join("outside_missing"), base.join("link"))?;
+        let got = AppPaths::resolve_within(&base, "link/file.txt");
+        anyhow::ensure!(got.is_err(), "dangling symlink escape resolved to {got:?}");
+
+        std::os::unix::fs::symlink("later/dir", base.join("inner"))?;
+        let got = AppPaths::resolve_within(&base, "inner/file.txt")?;
+        anyhow::ensure!(
+            got == base.join("later/dir/file.txt"),
+            "got {}",
+            got.display()
+        );
+
+        std::os::unix::fs::symlink("loop", base.join("loop"))?;
+        anyhow::ensure!(AppPaths::resolve_within(&base, "loop/x").is_err());
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_unicode_names_survive_expansion_and_resolution() -> anyhow::Result<()> {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt as _;
+
+        let weird = OsStr::from_bytes(b"caf\xe9");
+        let home = expand_str_path("~")?;
+        let got = expand_path(&Path::new("~").join(weird).join("x"))?;
+        anyhow::ensure!(got == home.join(weird).join("x"), "got {}", got.display());
+
+        // The longest name most file systems allow, not valid Unicode.
+        let mut long = vec![b'n'; 254];
+        long.push(0xff);
+        let long = OsStr::from_bytes(&long);
+        let (_scratch, base) = scratch_dir()?;
+        let base = base.join("inside");
+        fs::write(base.join(long), "")?;
+        let resolved = AppPaths::resolve_within(&base, Path::new(long))?;
+        anyhow::ensure!(resolved == base.join(long));
+        let missing = AppPaths::resolve_within(&base, Path::new(weird).join("..").join(long))?;
+        anyhow::ensure!(missing == base.join(long));
+        Ok(())
+    }
+
+    #[test]
+    fn dir_env_vars_use_the_app_prefix() {
+        let names: Vec<String> = [
+            DirKind::Config,
+            DirKind::Data,
+            DirKind::State,
+            DirKind::Cache,
+        ]
+        .into_iter()
+        .map(dir_env_var)
+        .collect();
+        assert_eq!(
+            names,
+            [
+                "RUST_WORKSPACE_CONFIG_DIR",
+                "RUST_WORKSPACE_DATA_DIR",
+                "RUST_WORKSPACE_STATE_DIR",
+                "RUST_WORKSPACE_CACHE_DIR",
+            ]
+        );
+    }
 
     #[test]
     fn xdg_absolute_path_wins_on_any_os() {
@@ -0,0 +1,123 @@
+//! Plans of what a dry run would change.
+//!
+//! On a dry run, operations record each change they would make as an
+//! [`Action`] in the [`Planner`] of their [`Ctx`](crate::Ctx) instead of
+//! making it. The caller reads the collected plan back with
+//! [`Planner::actions`] once the command is done and presents it as a
+//! whole, as text or structured output, rather than leaving it scattered
+//! through the log.
+
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use serde::Serialize;
+
+/// One change a dry run would make.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum Action {
+    /// Create a directory and any missing parents.
+    CreateDir {
+        /// Directory to create.
+        #[serde(with = "crate::pathfmt")]
+        path: PathBuf,
+    },
+    /// Create or replace a file.
+    WriteFile {
+        /// File to write.
+        #[serde(with = "crate::pathfmt")]
+        path: PathBuf,
+    },
+    /// Remove a file or a directory tree.
+    Remove {
+        /// File or directory to remove.
+        #[serde(with = "crate::pathfmt")]
+        path: PathBuf,
+    },
+    /// Run a configured task.
+    RunTask {
+        /// Task name.
+        name: String,
+    },
+    /// Run an external command.
+    RunCommand {
+        /// Command line, as a shell would show it.
+        command: String,
+    },
+    /// Send a file somewhere outside this machine.
+    Publish {
+        /// What is sent.
+        path: String,
+        /// Where it goes: a URL or a repository.
+        destination: String,
+    },
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CreateDir { path } => write!(f, "create directory {}", path.display()),
+            Self::WriteFile { path } => write!(f, "write {}", path.display()),
+            Self::Remove { path } => write!(f, "remove {}", path.display()),
+            Self::RunTask { name } => write!(f, "run task {name}"),
+            Self::RunCommand { command } => write!(f, "run `{command}`"),
+            Self::Publish { path, destination } => write!(f, "publish {path} to {destination}"),
+        }
+    }
+}
+
+/// The actions recorded so far, shared between the contexts cloned from
+/// one another.
+#[derive(Debug, Clone, Default)]
+pub struct Planner(Arc<Mutex<Vec<Action>>>);
+
+impl Planner {
+    /// Add `action` to the plan, unless it is already there (several steps
+    /// may write the same file).
+    pub fn record(&self, action: Action) {
+        let mut actions = self.lock();
+        if !actions.contains(&action) {
+            log::debug!("dry-run: would {action}");
+            actions.push(action);
+        }
+    }
+
+    /// Every recorded action, in the order it was recorded.
+    #[must_use]
+    pub fn actions(&self) -> Vec<Action> {
+        self.lock().clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<Action>> {
+        // A panic while pushing cannot leave the list half-updated.
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clones_share_one_plan_without_repeats() -> anyhow::Result<()> {
+        let planner = Planner::default();
+        let shared = planner.clone();
+        shared.record(Action::CreateDir {
+            path: PathBuf::from("/srv/data"),
+        });
+        for _ in 0..2 {
+            planner.record(Action::RunTask {
+                name: "build".to_string(),
+            });
+        }
+        let actions = planner.actions();
+        anyhow::ensure!(actions.len() == 2);
+        anyhow::ensure!(actions[0].to_string() == "create directory /srv/data");
+        anyhow::ensure!(
+            serde_json::to_value(&actions[1])?
+                == serde_json::json!({"action": "run_task", "name": "build"})
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,269 @@
+//! Defaults that depend on the platform.
+//!
+//! The `Default` impls of the config sections, the `runtime.*` fallbacks, and
+//! the default directories ask a [`Defaults`] provider instead of hard-coding
+//! values, so a default can differ by OS or machine state. [`Native`] is used
+//! unless [`with_defaults`] installs another provider for the current
+//! thread; tests do that to get predictable values without touching the
+//! environment. Generated files (schema, example config) are rendered with
+//! [`Fixed::documented`] so they come out the same on every platform.
+
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use crate::paths::{self, DirKind};
+
+/// Timeout for long-running operations, in seconds, where nothing is known
+/// to be slower.
+pub use rust_core_types::DEFAULT_TIMEOUT_SECS;
+
+/// Source of platform-dependent defaults.
+pub trait Defaults {
+    /// Worker pool size when `runtime.parallelism` is unset.
+    fn parallelism(&self) -> usize;
+
+    /// Default `runtime.timeout`, in seconds.
+    fn timeout_secs(&self) -> u64;
+
+    /// Whether the machine is running on battery, which
+    /// `runtime.power_save = "auto"` saves power for.
+    fn on_battery(&self) -> bool;
+
+    /// Per-user base directory for `kind` (such as `~/.config`), before the
+    /// application name is appended; `None` if it cannot be determined.
+    fn user_base_dir(&self, kind: DirKind) -> Option<PathBuf>;
+
+    /// Directory for `kind` in system mode (such as `/etc/<app>`).
+    fn system_dir(&self, kind: DirKind) -> PathBuf;
+}
+
+/// Defaults for the machine the process runs on.
+///
+/// Parallelism is the number of logical CPUs. The timeout is doubled on
+/// Windows, where process start-up and on-access virus scanning make long
+/// operations slower. Battery power is detected on macOS and Linux; Windows
+/// always reports mains. Directories follow XDG on every unix, including
+/// macOS, and the known folders on Windows.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Native;
+
+impl Defaults for Native {
+    fn parallelism(&self) -> usize {
+        std::thread::available_parallelism().map_or(1, std::num::NonZero::get)
+    }
+
+    fn timeout_secs(&self) -> u64 {
+        if cfg!(windows) {
+            DEFAULT_TIMEOUT_SECS * 2
+        } else {
+            DEFAULT_TIMEOUT_SECS
+        }
+    }
+
+    fn on_battery(&self) -> bool {
+        on_battery()
+    }
+
+    fn user_base_dir(&self, kind: DirKind) -> Option<PathBuf> {
+        paths::native_base_dir(kind)
+    }
+
+    fn system_dir(&self, kind: DirKind) -> PathBuf {
+        paths::native_system_dir(kind)
+    }
+}
+
+/// Whether the Mac is running on battery, asked once per process from
+/// `pmset`.
+#[cfg(target_os = "macos")]
+fn on_battery() -> bool {
+    static ON_BATTERY: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+    *ON_BATTERY.get_or_init(|| {
+        std::process::Command::new("pmset")
+            .args(["-g", "batt"])
+            .output()
+            .is_ok_and(|output| String::from_utf8_lossy(&output.stdout).contains("'Battery Power'"))
+    })
+}
+
+/// Whether a battery is discharging, read from `/sys/class/power_supply` on
+/// each call, since a laptop can be unplugged while a long run is going.
+#[cfg(target_os = "linux")]
+fn on_battery() -> bool {
+    let Ok(supplies) = std::fs::read_dir("/sys/class/power_supply") else {
+        return false;
+    };
+    supplies.flatten().any(|supply| {
+        let read = |name| std::fs::read_to_string(supply.path().join(name)).unwrap_or_default();
+        read("type").trim() == "Battery" && read("status").trim() == "Discharging"
+    })
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+const fn on_battery() -> bool {
+    false
+}
+
+/// The same values on every machine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixed {
+    /// Worker pool size.
+    pub parallelism: usize,
+    /// `runtime.timeout`, in seconds.
+    pub timeout_secs: u64,
+    /// Whether to report running on battery.
+    pub on_battery: bool,
+    /// Directory holding every other directory: per-user bases are
+    /// `<root>/<kind>`, system directories `<root>/system/<kind>`.
+    pub root: PathBuf,
+}
+
+impl Fixed {
+    /// The values the documentation states, for generated files.
+    #[must_use]
+    pub fn documented() -> Self {
+        Self {
+            parallelism: 1,
+            timeout_secs: DEFAULT_TIMEOUT_SECS,
+            on_battery: false,
+            root: PathBuf::from("/"),
+        }
+    }
+}
+
+impl Defaults for Fixed {
+    fn parallelism(&self) -> usize {
+        self.parallelism
+    }
+
+    fn timeout_secs(&self) -> u64 {
+        self.timeout_secs
+    }
+
+    fn on_battery(&self) -> bool {
+        self.on_battery
+    }
+
+    fn user_base_dir(&self, kind: DirKind) -> Option<PathBuf> {
+        Some(self.root.join(kind.name()))
+    }
+
+    fn system_dir(&self, kind: DirKind) -> PathBuf {
+        self.root.join("system").join(kind.name())
+    }
+}
+
+thread_local! {
+    static OVERRIDE: RefCell<Option<Rc<dyn Defaults>>> = const { RefCell::new(None) };
+}
+
+/// Call `f` with the provider active on this thread.
+pub fn current<R>(f: impl FnOnce(&dyn Defaults) -> R) -> R {
+    // Clone the provider out so `f` may itself read defaults.
+    match OVERRIDE.with_borrow(Clone::clone) {
+        Some(provider) => f(provider.as_ref()),
+        None => f(&Native),
+    }
+}
+
+/// Run `f` with `provider` supplying defaults on the current thread only;
+/// threads `f` spawns still see [`Native`]. The previous provider is
+/// restored afterwards, so calls nest.
+pub fn with_defaults<R>(provider: impl Defaults + 'static, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<Rc<dyn Defaults>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            OVERRIDE.with_borrow_mut(|slot| *slot = previous);
+        }
+    }
+
+    let _restore = Restore(OVERRIDE.replace(Some(Rc::new(provider))));
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use std::time::Duration;
+
+    use super::*;
+    use crate::config::POWER_SAVE_POLL;
+    use crate::paths::{PathMode, default_dir};
+    use crate::{PowerSave, RuntimeConfig, RuntimeConfigExt as _, default_parallelism};
+
+    fn fixed(timeout_secs: u64) -> Fixed {
+        Fixed {
+            parallelism: 3,
+            timeout_secs,
+            on_battery: false,
+            root: PathBuf::from("/fixed"),
+        }
+    }
+
+    #[test]
+    fn overrides_apply_to_this_thread_and_nest() -> anyhow::Result<()> {
+        let timeout = || crate::config::defaults().runtime.timeout;
+        let native_timeout = timeout();
+        with_defaults(fixed(7), || -> anyhow::Result<()> {
+            anyhow::ensure!(timeout() == Some(7));
+            anyhow::ensure!(default_parallelism() == 3);
+            anyhow::ensure!(
+                default_dir(PathMode::User, DirKind::State)?
+                    == Path::new("/fixed/state").join(crate::APP_NAME)
+            );
+            anyhow::ensure!(
+                default_dir(PathMode::System, DirKind::Cache)? == Path::new("/fixed/system/cache")
+            );
+            anyhow::ensure!(with_defaults(fixed(9), || { timeout() == Some(9) }));
+            anyhow::ensure!(timeout() == Some(7));
+            let other_thread = std::thread::spawn(timeout).join();
+            anyhow::ensure!(other_thread.ok() == Some(native_timeout));
+            Ok(())
+        })?;
+        anyhow::ensure!(timeout() == native_timeout);
+        Ok(())
+    }
+
+    #[test]
+    fn power_save_throttles_on_battery() -> anyhow::Result<()> {
+        let on_battery = Fixed {
+            on_battery: true,
+            ..fixed(7)
+        };
+        let mut runtime = RuntimeConfig {
+            parallelism: Some(8),
+            ..RuntimeConfig::default()
+        };
+        let tick = Duration::from_millis(100);
+        with_defaults(on_battery, || -> anyhow::Result<()> {
+            anyhow::ensure!(runtime.effective_parallelism() == 4);
+            anyhow::ensure!(runtime.poll_interval(tick) == POWER_SAVE_POLL);
+            runtime.power_save = PowerSave::Off;
+            anyhow::ensure!(runtime.effective_parallelism() == 8);
+            anyhow::ensure!(runtime.poll_interval(tick) == tick);
+            Ok(())
+        })?;
+        with_defaults(fixed(7), || -> anyhow::Result<()> {
+            runtime.power_save = PowerSave::Auto;
+            anyhow::ensure!(runtime.effective_parallelism() == 8);
+            runtime.power_save = PowerSave::On;
+            anyhow::ensure!(runtime.effective_parallelism() == 4);
+            runtime.parallelism = Some(1);
+            anyhow::ensure!(runtime.effective_parallelism() == 1);
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn generated_files_do_not_depend_on_the_provider() -> anyhow::Result<()> {
+        let native = crate::generate_example_config("app")?;
+        let fixed = with_defaults(fixed(7), || crate::generate_example_config("app"))?;
+        anyhow::ensure!(native == fixed);
+        anyhow::ensure!(native.contains(&format!("timeout = {DEFAULT_TIMEOUT_SECS}")));
+        Ok(())
+    }
+}
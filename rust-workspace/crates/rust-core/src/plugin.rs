@@ -0,0 +1,156 @@
+//! External subcommands found on `PATH`, as git and cargo have them.
+//!
+//! An executable named `<app>-<name>` (`rust-cli-deploy`, or
+//! `rust-cli-deploy.exe` on Windows) extends the binary with a `<name>`
+//! command: the binary runs it for any command it does not know itself.
+//! Lookup follows `PATH` order, so the first match wins, as it would in a
+//! shell. Running the plugin is left to the binary.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+/// An external subcommand.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Plugin {
+    /// Command name, e.g. `deploy`.
+    pub name: String,
+    /// Executable that implements it.
+    #[serde(with = "crate::pathfmt")]
+    pub path: PathBuf,
+}
+
+/// File name of the executable implementing `name` for `app`.
+#[must_use]
+pub fn executable_name(app: &str, name: &str) -> String {
+    format!("{app}-{name}{}", env::consts::EXE_SUFFIX)
+}
+
+/// The executable implementing `name` on `PATH`, if any.
+#[must_use]
+pub fn find(app: &str, name: &str) -> Option<PathBuf> {
+    find_in(app, name, &env::var_os("PATH")?)
+}
+
+/// The executable implementing `name` in the directories of `search_path`
+/// (a `PATH`-style list). Names that could reach outside those directories
+/// are never found.
+#[must_use]
+pub fn find_in(app: &str, name: &str, search_path: &OsStr) -> Option<PathBuf> {
+    if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\']) {
+        return None;
+    }
+    let file = executable_name(app, name);
+    env::split_paths(search_path)
+        .map(|dir| dir.join(&file))
+        .find(|path| is_executable(path))
+}
+
+/// Every plugin for `app` on `PATH`, by name.
+#[must_use]
+pub fn discover(app: &str) -> Vec<Plugin> {
+    env::var_os("PATH").map_or_else(Vec::new, |path| discover_in(app, &path))
+}
+
+/// Every plugin for `app` in the directories of `search_path`, by name;
+/// where several directories have one, the earliest wins.
+#[must_use]
+pub fn discover_in(app: &str, search_path: &OsStr) -> Vec<Plugin> {
+    let prefix = format!("{app}-");
+    let mut found = BTreeMap::new();
+    for dir in env::split_paths(search_path) {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let file = entry.file_name();
+            let Some(name) = file
+                .to_str()
+                .and_then(|file| file.strip_prefix(&prefix))
+                .and_then(|rest| rest.strip_suffix(env::consts::EXE_SUFFIX))
+                .filter(|name| !name.is_empty())
+            else {
+                continue;
+            };
+            let path = entry.path();
+            if !found.contains_key(name) && is_executable(&path) {
+                found.insert(name.to_string(), path);
+            }
+        }
+    }
+    found
+        .into_iter()
+        .map(|(name, path)| Plugin { name, path })
+        .collect()
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt as _;
+
+    fs::metadata(path).is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch::Scratch;
+
+    #[cfg(unix)]
+    #[test]
+    fn finds_executables_in_path_order() -> anyhow::Result<()> {
+        use std::os::unix::fs::PermissionsExt as _;
+
+        let root = Scratch::new()?;
+        let (first, second) = (root.join("first"), root.join("second"));
+        for (dir, files) in [
+            (
+                &first,
+                &[("app-deploy", 0o755), ("app-notes.txt", 0o644)][..],
+            ),
+            (
+                &second,
+                &[
+                    ("app-deploy", 0o755),
+                    ("app-lint", 0o755),
+                    ("other-tool", 0o755),
+                ][..],
+            ),
+        ] {
+            fs::create_dir_all(dir)?;
+            for &(file, mode) in files {
+                let path = dir.join(file);
+                fs::write(&path, "#!/bin/sh\n")?;
+                fs::set_permissions(&path, fs::Permissions::from_mode(mode))?;
+            }
+        }
+        let search_path = env::join_paths([&first, &second])?;
+
+        anyhow::ensure!(find_in("app", "deploy", &search_path) == Some(first.join("app-deploy")));
+        anyhow::ensure!(find_in("app", "lint", &search_path) == Some(second.join("app-lint")));
+        anyhow::ensure!(find_in("app", "notes.txt", &search_path).is_none());
+        anyhow::ensure!(find_in("app", "../first/app-deploy", &search_path).is_none());
+        let names: Vec<_> = discover_in("app", &search_path)
+            .into_iter()
+            .map(|plugin| (plugin.name, plugin.path))
+            .collect();
+        anyhow::ensure!(
+            names
+                == [
+                    ("deploy".to_string(), first.join("app-deploy")),
+                    ("lint".to_string(), second.join("app-lint")),
+                ],
+            "{names:?}"
+        );
+        Ok(())
+    }
+}
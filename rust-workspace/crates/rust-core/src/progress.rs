@@ -0,0 +1,43 @@
+//! Progress reporting for long-running operations.
+//!
+//! Library code reports through [`Progress`] without knowing whether or how
+//! it is displayed: a binary passes a terminal implementation (rust-cli's
+//! uses indicatif, or JSON events under `--output ndjson`), tests and quiet
+//! runs pass [`NoProgress`]. Several tasks may be live at once; each
+//! finishes when its [`ProgressTask`] is dropped.
+
+/// A sink for progress reports.
+pub trait Progress: Sync {
+    /// Start a task labelled `label`: a bar over `total` steps, or a spinner
+    /// when the total is unknown.
+    fn start(&self, label: &str, total: Option<u64>) -> Box<dyn ProgressTask + '_>;
+}
+
+/// One running task; dropping it marks the task finished.
+pub trait ProgressTask {
+    /// Record `steps` more units of work done.
+    fn advance(&self, steps: u64);
+
+    /// Show what the task is working on right now, e.g. a file name.
+    fn set_message(&self, message: &str);
+
+    /// Record that the task failed, and why. It still finishes when
+    /// dropped. Displays that only show activity can ignore this.
+    fn fail(&self, _message: &str) {}
+}
+
+/// Reports nothing.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoProgress;
+
+impl Progress for NoProgress {
+    fn start(&self, _label: &str, _total: Option<u64>) -> Box<dyn ProgressTask + '_> {
+        Box::new(Self)
+    }
+}
+
+impl ProgressTask for NoProgress {
+    fn advance(&self, _steps: u64) {}
+
+    fn set_message(&self, _message: &str) {}
+}
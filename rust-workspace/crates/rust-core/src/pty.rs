@@ -0,0 +1,324 @@
+//! Pseudo-terminal capture for commands that behave differently without a TTY.
+//!
+//! Many tools only emit colors, progress bars, or interactive redraws when
+//! stdout is a terminal. [`run_in_pty`] runs a shell command under a
+//! pseudo-terminal so it renders as it would interactively, and returns both
+//! the rendered byte stream and a clean, log-friendly transcript;
+//! [`spawn_in_pty`] starts one without waiting, for tasks with `pty = true`.
+
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use portable_pty::{Child, ChildKiller, CommandBuilder, ExitStatus, PtySize, native_pty_system};
+
+use crate::usage::{self, ChildUsage};
+
+/// Default terminal width used for captured sessions.
+pub const DEFAULT_COLS: u16 = 120;
+
+/// Default terminal height used for captured sessions.
+pub const DEFAULT_ROWS: u16 = 40;
+
+/// How often a session checks whether its command has exited once the
+/// terminal is closed.
+const REAP_POLL: Duration = Duration::from_millis(10);
+
+/// Options for running a command under a pseudo-terminal.
+#[derive(Debug, Clone, Default)]
+pub struct PtyOptions {
+    /// Working directory for the command. Defaults to the current directory.
+    pub cwd: Option<PathBuf>,
+    /// Extra environment variables set for the command.
+    pub env: Vec<(String, String)>,
+    /// Path that receives the clean transcript once the command finishes.
+    pub log_file: Option<PathBuf>,
+}
+
+/// Output captured from a command run under a pseudo-terminal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PtyOutput {
+    /// Output exactly as rendered by the terminal, including ANSI sequences.
+    pub rendered: String,
+    /// Output with escape sequences and carriage-return redraws removed.
+    pub clean: String,
+    /// Exit code reported by the child process.
+    pub exit_code: u32,
+    /// CPU time and peak RSS of the command, where they can be measured.
+    pub usage: Option<ChildUsage>,
+}
+
+impl PtyOutput {
+    /// Returns true if the command exited successfully.
+    #[must_use]
+    pub const fn success(&self) -> bool {
+        self.exit_code == 0
+    }
+}
+
+/// Run a shell command under a pseudo-terminal and capture its output.
+///
+/// The command runs via `sh -c` on unix and `cmd /C` on Windows. When
+/// `options.log_file` is set, the clean transcript is written there.
+///
+/// # Errors
+///
+/// Returns an error if the pseudo-terminal cannot be opened, the command
+/// cannot be spawned, or the log file cannot be written.
+pub fn run_in_pty(command: &str, options: &PtyOptions) -> Result<PtyOutput> {
+    spawn_in_pty(command, options, |_| {})?.wait()
+}
+
+/// Start a shell command under a pseudo-terminal without waiting for it.
+///
+/// The command runs as in [`run_in_pty`]. `on_output` gets the rendered
+/// bytes as they arrive, from a thread of the session's own, and an empty
+/// slice once the output has ended.
+///
+/// # Errors
+///
+/// Returns an error if the pseudo-terminal cannot be opened or the command
+/// cannot be spawned.
+pub fn spawn_in_pty(
+    command: &str,
+    options: &PtyOptions,
+    mut on_output: impl FnMut(&[u8]) + Send + 'static,
+) -> Result<PtySession> {
+    let pair = native_pty_system()
+        .openpty(PtySize {
+            rows: DEFAULT_ROWS,
+            cols: DEFAULT_COLS,
+            pixel_width: 0,
+            pixel_height: 0,
+        })
+        .context("opening pseudo-terminal")?;
+
+    let mut builder = if cfg!(windows) {
+        let mut builder = CommandBuilder::new("cmd");
+        builder.args(["/C", command]);
+        builder
+    } else {
+        let mut builder = CommandBuilder::new("sh");
+        builder.args(["-c", command]);
+        builder
+    };
+    if builder.get_env("TERM").is_none() {
+        builder.env("TERM", "xterm-256color");
+    }
+    if let Some(ref cwd) = options.cwd {
+        builder.cwd(cwd);
+    }
+    for (key, value) in &options.env {
+        builder.env(key, value);
+    }
+
+    let mut child = pair
+        .slave
+        .spawn_command(builder)
+        .with_context(|| format!("spawning `{command}` under a pseudo-terminal"))?;
+    // Drop our copy of the slave so the reader sees EOF once the child exits.
+    drop(pair.slave);
+
+    let mut reader = pair
+        .master
+        .try_clone_reader()
+        .context("cloning pseudo-terminal reader")?;
+    let killer = child.clone_killer();
+    let master = pair.master;
+    let log_file = options.log_file.clone();
+    let session = thread::spawn(move || {
+        let raw = read_until_closed(&mut reader, &mut on_output);
+        let (status, usage) = reap(child.as_mut()).context("waiting for pseudo-terminal child")?;
+        drop(master);
+
+        let rendered = String::from_utf8_lossy(&raw).into_owned();
+        let output = PtyOutput {
+            clean: clean_transcript(&rendered),
+            rendered,
+            exit_code: status.exit_code(),
+            usage,
+        };
+        if let Some(ref log_file) = log_file {
+            write_log(log_file, &output.clean)?;
+        }
+        Ok(output)
+    });
+    Ok(PtySession {
+        killer,
+        session: Some(session),
+    })
+}
+
+/// Wait for `child` to exit, polling so its usage can be [measured as it is
+/// reaped](usage::reaping).
+fn reap(child: &mut (dyn Child + Send + Sync)) -> io::Result<(ExitStatus, Option<ChildUsage>)> {
+    loop {
+        let (status, usage) = usage::reaping(|| child.try_wait());
+        if let Some(status) = status? {
+            return Ok((status, usage));
+        }
+        thread::sleep(REAP_POLL);
+    }
+}
+
+/// A command started by [`spawn_in_pty`].
+#[derive(Debug)]
+pub struct PtySession {
+    killer: Box<dyn ChildKiller + Send + Sync>,
+    session: Option<JoinHandle<Result<PtyOutput>>>,
+}
+
+impl PtySession {
+    /// Whether the command has exited and its output is all read.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.session.as_ref().is_none_or(JoinHandle::is_finished)
+    }
+
+    /// Wait for the command to exit and return its output, once the clean
+    /// transcript is written to the log file if one was asked for.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the command cannot be waited for, the log file
+    /// cannot be written, or the output was already taken.
+    pub fn wait(&mut self) -> Result<PtyOutput> {
+        self.session
+            .take()
+            .context("pseudo-terminal output already taken")?
+            .join()
+            .map_err(|_| anyhow::anyhow!("pseudo-terminal reader panicked"))?
+    }
+
+    /// Stop the command: hang up on it (terminate it on Windows).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signal cannot be sent.
+    pub fn kill(&mut self) -> io::Result<()> {
+        self.killer.kill()
+    }
+}
+
+/// Read everything from a pseudo-terminal master until the slave side closes.
+///
+/// Linux reports a closed slave as `EIO` rather than EOF, so any read error
+/// ends the capture.
+fn read_until_closed(reader: &mut dyn Read, on_output: &mut impl FnMut(&[u8])) -> Vec<u8> {
+    let mut raw = Vec::new();
+    let mut chunk = [0_u8; 4096];
+    loop {
+        match reader.read(&mut chunk) {
+            Ok(0) | Err(_) => {
+                on_output(&[]);
+                break;
+            }
+            Ok(n) => {
+                on_output(&chunk[..n]);
+                raw.extend_from_slice(&chunk[..n]);
+            }
+        }
+    }
+    raw
+}
+
+fn write_log(path: &Path, clean: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("creating log directory {}", parent.display()))?;
+    }
+    fs::write(path, clean).with_context(|| format!("writing pty log to {}", path.display()))
+}
+
+/// Turn rendered terminal output into a plain transcript.
+///
+/// Strips ANSI escape sequences and keeps only the final state of lines that
+/// were redrawn with carriage returns (spinners, progress bars).
+#[must_use]
+pub fn clean_transcript(rendered: &str) -> String {
+    let stripped = strip_ansi(rendered);
+    let mut clean = String::with_capacity(stripped.len());
+    for line in stripped.split_inclusive('\n') {
+        let (body, newline) = line
+            .strip_suffix('\n')
+            .map_or((line, false), |body| (body, true));
+        let body = body.trim_end_matches('\r');
+        let visible = body.rsplit('\r').next().unwrap_or(body);
+        clean.push_str(visible);
+        if newline {
+            clean.push('\n');
+        }
+    }
+    clean
+}
+
+/// Remove ANSI escape sequences (CSI, OSC, and two-byte escapes) from text.
+#[must_use]
+pub fn strip_ansi(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            // CSI: parameters and intermediates, terminated by a final byte.
+            Some('[') => {
+                for next in chars.by_ref() {
+                    if ('\u{40}'..='\u{7e}').contains(&next) {
+                        break;
+                    }
+                }
+            }
+            // OSC: terminated by BEL or ST (ESC \).
+            Some(']') => {
+                while let Some(next) = chars.next() {
+                    if next == '\u{7}' {
+                        break;
+                    }
+                    if next == '\u{1b}' && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip_ansi_removes_csi_and_osc() {
+        let text = "\u{1b}[1;32mok\u{1b}[0m \u{1b}]0;title\u{7}done\u{1b}]8;;x\u{1b}\\";
+        assert_eq!(strip_ansi(text), "ok done");
+    }
+
+    #[test]
+    fn clean_transcript_keeps_final_redraw() {
+        let rendered = "building  10%\rbuilding  50%\rbuilding 100%\r\nfinished\r\n";
+        assert_eq!(clean_transcript(rendered), "building 100%\nfinished\n");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn run_in_pty_reports_a_terminal() -> Result<()> {
+        let output = run_in_pty("test -t 1 && printf tty", &PtyOptions::default())?;
+        let expected = PtyOutput {
+            rendered: "tty".to_string(),
+            clean: "tty".to_string(),
+            exit_code: 0,
+            usage: output.usage,
+        };
+        anyhow::ensure!(output == expected, "unexpected pty output: {output:?}");
+        Ok(())
+    }
+}
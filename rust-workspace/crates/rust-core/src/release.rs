@@ -0,0 +1,212 @@
+//! Release helpers: workspace version stamping.
+//!
+//! [`stamp_workspace`] rewrites the `version` key of the workspace root and
+//! every member manifest that declares its own version, so a release bumps
+//! every crate in one step. Crates using `version.workspace = true` follow
+//! the root automatically.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, anyhow, bail};
+
+use crate::journal::Journal;
+
+/// Manifest sections whose `version` key is stamped.
+const VERSION_SECTIONS: &[&str] = &["workspace.package", "package"];
+
+/// Find the workspace root by walking up from `start` to the first
+/// `Cargo.toml` that declares a `[workspace]` table.
+///
+/// # Errors
+///
+/// Returns an error if no workspace manifest is found.
+pub fn find_workspace_root(start: &Path) -> Result<PathBuf> {
+    for dir in start.ancestors() {
+        let manifest = dir.join("Cargo.toml");
+        if let Ok(text) = fs::read_to_string(&manifest)
+            && text.lines().any(|line| line.trim() == "[workspace]")
+        {
+            return Ok(dir.to_path_buf());
+        }
+    }
+    Err(anyhow!(
+        "no Cargo workspace found at or above {}",
+        start.display()
+    ))
+}
+
+/// Validate that `version` is a semantic version (`MAJOR.MINOR.PATCH[-pre][+build]`).
+///
+/// # Errors
+///
+/// Returns an error describing the first problem found.
+pub fn validate_version(version: &str) -> Result<()> {
+    let (rest, build) = version
+        .split_once('+')
+        .map_or((version, None), |(rest, build)| (rest, Some(build)));
+    let (core, pre) = rest
+        .split_once('-')
+        .map_or((rest, None), |(core, pre)| (core, Some(pre)));
+    let parts: Vec<&str> = core.split('.').collect();
+    let valid = build.is_none_or(|build| !build.is_empty())
+        && pre.is_none_or(|pre| !pre.is_empty())
+        && parts.len() == 3
+        && parts.iter().all(|part| {
+            !part.is_empty()
+                && part.chars().all(|c| c.is_ascii_digit())
+                && (part.len() == 1 || !part.starts_with('0'))
+        });
+    if valid {
+        Ok(())
+    } else {
+        bail!("invalid version '{version}': expected MAJOR.MINOR.PATCH[-pre][+build]")
+    }
+}
+
+/// Return the manifest text with its package version set to `version`, or
+/// `None` if the manifest declares no literal version.
+#[must_use]
+pub fn stamp_manifest(text: &str, version: &str) -> Option<String> {
+    let mut section = String::new();
+    let mut changed = false;
+    let mut out = String::with_capacity(text.len());
+    for line in text.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if let Some(header) = trimmed
+            .strip_prefix('[')
+            .and_then(|rest| rest.strip_suffix(']'))
+        {
+            section = header.trim().to_string();
+        } else if VERSION_SECTIONS.contains(&section.as_str())
+            && let Some(value) = trimmed
+                .strip_prefix("version")
+                .map(str::trim_start)
+                .and_then(|rest| rest.strip_prefix('='))
+            && value.trim().starts_with('"')
+        {
+            let indent = &line[..line.len() - line.trim_start().len()];
+            let newline = if line.ends_with('\n') { "\n" } else { "" };
+            let _ = write!(out, "{indent}version = \"{version}\"{newline}");
+            changed = true;
+            continue;
+        }
+        out.push_str(line);
+    }
+    changed.then_some(out)
+}
+
+/// Manifest paths for the workspace root and all listed members.
+///
+/// Member entries ending in `/*` are expanded to their subdirectories.
+///
+/// # Errors
+///
+/// Returns an error if the root manifest cannot be read or parsed.
+pub fn workspace_manifests(root: &Path) -> Result<Vec<PathBuf>> {
+    let root_manifest = root.join("Cargo.toml");
+    let text = fs::read_to_string(&root_manifest)
+        .with_context(|| format!("reading {}", root_manifest.display()))?;
+    let parsed: toml::Table =
+        toml::from_str(&text).with_context(|| format!("parsing {}", root_manifest.display()))?;
+    let members = parsed
+        .get("workspace")
+        .and_then(|workspace| workspace.get("members"))
+        .and_then(toml::Value::as_array)
+        .map(|members| {
+            members
+                .iter()
+                .filter_map(toml::Value::as_str)
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    let mut manifests = vec![root_manifest];
+    for member in members {
+        if let Some(parent) = member.strip_suffix("/*") {
+            let dir = root.join(parent);
+            let mut children: Vec<PathBuf> = fs::read_dir(&dir)
+                .with_context(|| format!("reading {}", dir.display()))?
+                .filter_map(|entry| entry.ok().map(|entry| entry.path().join("Cargo.toml")))
+                .filter(|manifest| manifest.is_file())
+                .collect();
+            children.sort();
+            manifests.extend(children);
+        } else {
+            manifests.push(root.join(member).join("Cargo.toml"));
+        }
+    }
+    Ok(manifests)
+}
+
+/// Stamp `version` into every workspace manifest that declares one.
+///
+/// The new manifests are staged in `journal` and written when it is
+/// committed. Returns the manifests that will be changed.
+///
+/// # Errors
+///
+/// Returns an error if the version is invalid or a manifest cannot be read
+/// or staged.
+pub fn stamp_workspace(root: &Path, version: &str, journal: &mut Journal) -> Result<Vec<PathBuf>> {
+    validate_version(version)?;
+    let mut changed = Vec::new();
+    for manifest in workspace_manifests(root)? {
+        let text = fs::read_to_string(&manifest)
+            .with_context(|| format!("reading {}", manifest.display()))?;
+        let Some(stamped) = stamp_manifest(&text, version) else {
+            continue;
+        };
+        if stamped == text {
+            continue;
+        }
+        journal.write(&manifest, stamped.as_bytes())?;
+        changed.push(manifest);
+    }
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_version_accepts_semver_only() {
+        for good in [
+            "0.1.0",
+            "1.20.3",
+            "1.0.0-rc.1",
+            "1.0.0+build.5",
+            "2.0.0-beta+exp",
+        ] {
+            assert!(validate_version(good).is_ok(), "{good} rejected");
+        }
+        for bad in ["1.0", "v1.0.0", "01.0.0", "1.0.0-", "1.0.0+", "1.a.0", ""] {
+            assert!(validate_version(bad).is_err(), "{bad} accepted");
+        }
+    }
+
+    #[test]
+    fn stamp_manifest_only_touches_package_versions() {
+        let text = "[workspace.package]\nversion = \"0.1.0\"\nedition = \"2024\"\n\n\
+                    [workspace.dependencies]\nlog = { version = \"0.4\" }\n\
+                    toml = \"0.9\"\n";
+        let stamped = stamp_manifest(text, "1.2.3");
+        assert_eq!(
+            stamped.as_deref(),
+            Some(
+                "[workspace.package]\nversion = \"1.2.3\"\nedition = \"2024\"\n\n\
+                 [workspace.dependencies]\nlog = { version = \"0.4\" }\n\
+                 toml = \"0.9\"\n"
+            )
+        );
+    }
+
+    #[test]
+    fn stamp_manifest_skips_inherited_versions() {
+        let text = "[package]\nname = \"x\"\nversion.workspace = true\n";
+        assert_eq!(stamp_manifest(text, "1.2.3"), None);
+    }
+}
@@ -0,0 +1,43 @@
+//! Scratch directories for the unit tests.
+//!
+//! Each [`Scratch`] is a [`TempScope`] under `<system temp>/rust-core-tests`,
+//! so it has a name of its own and is removed when the test drops it, pass or
+//! fail; tests running in parallel never share one.
+
+use std::ops::Deref;
+use std::path::Path;
+
+use crate::error::Result;
+use crate::paths::{AppPaths, PathMode};
+use crate::temp::TempScope;
+
+/// A test's own directory, removed on drop. It derefs to its [`Path`].
+#[derive(Debug)]
+pub struct Scratch(TempScope);
+
+impl Scratch {
+    /// A new, empty scratch directory.
+    pub fn new() -> Result<Self> {
+        TempScope::new_in(&std::env::temp_dir().join("rust-core-tests")).map(Self)
+    }
+
+    /// User-mode paths with the config file and the data, state, and cache
+    /// directories inside the scratch directory, none of them created.
+    pub fn paths(&self) -> AppPaths {
+        AppPaths {
+            mode: PathMode::User,
+            config_file: self.join("config").join("config.toml"),
+            data_dir: self.join("data"),
+            state_dir: self.join("state"),
+            cache_dir: self.join("cache"),
+        }
+    }
+}
+
+impl Deref for Scratch {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        self.0.path()
+    }
+}
@@ -0,0 +1,130 @@
+//! Security module hooks for directories created in system mode.
+//!
+//! A service's data and state directories created under `/var/lib` inherit
+//! the SELinux label of their parent instead of the one the policy assigns,
+//! so a confined daemon can be silently denied access to its own files.
+//! [`relabel`] either runs `restorecon` on new directories or logs the label
+//! the policy expects, as chosen by `[security.selinux] relabel`. AppArmor
+//! confines by path rather than label, so the standard system locations need
+//! no extra work there; its presence is only noted at debug level.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{Context, Result, bail};
+
+use crate::config::{RelabelMode, SelinuxConfig};
+
+/// Present when the SELinux filesystem is mounted, i.e. SELinux is enabled.
+const SELINUX_ENFORCE: &str = "/sys/fs/selinux/enforce";
+
+/// Present when the AppArmor LSM is loaded.
+const APPARMOR_DIR: &str = "/sys/kernel/security/apparmor";
+
+/// Returns true if SELinux is enabled (enforcing or permissive).
+#[must_use]
+pub fn selinux_enabled() -> bool {
+    cfg!(target_os = "linux") && Path::new(SELINUX_ENFORCE).exists()
+}
+
+/// Returns true if AppArmor is loaded.
+#[must_use]
+pub fn apparmor_enabled() -> bool {
+    cfg!(target_os = "linux") && Path::new(APPARMOR_DIR).is_dir()
+}
+
+/// Apply the SELinux labeling policy to directories that were just created.
+///
+/// Does nothing when `dirs` is empty, SELinux is disabled, or relabeling is
+/// turned off.
+///
+/// # Errors
+///
+/// Returns an error if `restorecon` was requested but could not be run or
+/// exited unsuccessfully.
+pub fn relabel(dirs: &[PathBuf], config: SelinuxConfig) -> Result<()> {
+    if dirs.is_empty() {
+        return Ok(());
+    }
+    if apparmor_enabled() {
+        log::debug!("AppArmor is loaded; it confines by path, so new directories need no labels");
+    }
+    if !selinux_enabled() {
+        return Ok(());
+    }
+    match config.relabel {
+        RelabelMode::Off => Ok(()),
+        RelabelMode::Warn => {
+            for dir in dirs {
+                warn_inherited_label(dir);
+            }
+            Ok(())
+        }
+        RelabelMode::Restorecon => restorecon(dirs),
+    }
+}
+
+/// The SELinux context the loaded policy assigns to `path`, if
+/// `matchpathcon` is installed and knows it.
+#[must_use]
+pub fn expected_label(path: &Path) -> Option<String> {
+    let output = Command::new("matchpathcon")
+        .arg("-n")
+        .arg(path)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let label = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (!label.is_empty()).then_some(label)
+}
+
+fn warn_inherited_label(dir: &Path) {
+    let expected = expected_label(dir).map_or_else(
+        || "the label from its file contexts".to_string(),
+        |label| format!("`{label}`"),
+    );
+    log::warn!(
+        "{} was created with an inherited SELinux label; the policy expects {expected}. \
+         Run `restorecon -R {}` or set security.selinux.relabel = \"restorecon\"",
+        dir.display(),
+        dir.display()
+    );
+}
+
+fn restorecon(dirs: &[PathBuf]) -> Result<()> {
+    let status = Command::new("restorecon")
+        .arg("-R")
+        .args(dirs)
+        .status()
+        .context("running restorecon (is policycoreutils installed?)")?;
+    if !status.success() {
+        let dirs: Vec<String> = dirs.iter().map(|dir| dir.display().to_string()).collect();
+        bail!("restorecon {status} while relabeling {}", dirs.join(", "));
+    }
+    log::debug!("restored SELinux labels on {} directories", dirs.len());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SecurityConfig;
+
+    #[test]
+    fn relabel_mode_parses_from_config() -> Result<()> {
+        let parsed: SecurityConfig = toml::from_str("[selinux]\nrelabel = \"restorecon\"\n")?;
+        anyhow::ensure!(parsed.selinux.relabel == RelabelMode::Restorecon);
+        anyhow::ensure!(SecurityConfig::default().selinux.relabel == RelabelMode::Warn);
+        Ok(())
+    }
+
+    #[test]
+    fn nothing_to_relabel_is_a_no_op() -> Result<()> {
+        let config = SelinuxConfig {
+            relabel: RelabelMode::Restorecon,
+        };
+        relabel(&[], config)
+    }
+}
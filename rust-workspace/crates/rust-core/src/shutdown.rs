@@ -0,0 +1,92 @@
+//! Graceful shutdown on SIGTERM and SIGINT.
+//!
+//! Container runtimes stop a container by sending SIGTERM to PID 1, and the
+//! kernel discards signals that PID 1 has no handler for, so without
+//! [`install`] the process ignores the stop request until it is killed.
+//! [`install`] blocks both signals in the calling thread (and every thread it
+//! spawns afterwards) and starts a watcher thread that waits for them. The
+//! first signal is recorded for long-running work to poll with
+//! [`requested`]; a second one exits immediately with the interrupted exit
+//! code. Child processes start with the default signal mask, so they still
+//! receive signals normally.
+
+use std::sync::atomic::{AtomicI32, Ordering};
+
+use crate::error::Result;
+
+/// Number of the first shutdown signal received, or 0.
+static RECEIVED: AtomicI32 = AtomicI32::new(0);
+
+/// Start handling SIGTERM and SIGINT as shutdown requests.
+///
+/// Call this from the main thread before spawning other threads; threads
+/// started earlier keep the default disposition. Does nothing on platforms
+/// without Unix signals.
+///
+/// # Errors
+///
+/// Returns an error if the signal mask cannot be changed or the watcher
+/// thread cannot be started.
+pub fn install() -> Result<()> {
+    #[cfg(unix)]
+    {
+        use nix::sys::signal::{SigSet, Signal};
+
+        let mut signals = SigSet::empty();
+        signals.add(Signal::SIGTERM);
+        signals.add(Signal::SIGINT);
+        signals.thread_block().map_err(std::io::Error::from)?;
+        std::thread::Builder::new()
+            .name("shutdown".to_string())
+            .spawn(move || watch(&signals))?;
+    }
+    Ok(())
+}
+
+/// Name of the signal that requested shutdown (e.g. `SIGTERM`), if any.
+#[must_use]
+pub fn requested() -> Option<&'static str> {
+    match RECEIVED.load(Ordering::SeqCst) {
+        0 => None,
+        #[cfg(unix)]
+        signal => nix::sys::signal::Signal::try_from(signal)
+            .ok()
+            .map(nix::sys::signal::Signal::as_str),
+        #[cfg(not(unix))]
+        _ => Some("signal"),
+    }
+}
+
+#[cfg(unix)]
+#[expect(
+    clippy::exit,
+    reason = "a second signal must end the process even if the main thread is stuck"
+)]
+fn watch(signals: &nix::sys::signal::SigSet) {
+    loop {
+        let Ok(signal) = signals.wait() else {
+            continue;
+        };
+        if RECEIVED
+            .compare_exchange(0, signal as i32, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err()
+        {
+            log::warn!("received {} again; exiting immediately", signal.as_str());
+            std::process::exit(i32::from(crate::ExitCode::Interrupted.code()));
+        }
+        log::info!(
+            "received {}; finishing current work before exiting (send again to force)",
+            signal.as_str()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nothing_requested_by_default() {
+        assert_eq!(requested(), None);
+    }
+}
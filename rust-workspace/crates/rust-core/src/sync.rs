@@ -0,0 +1,566 @@
+//! Sync a curated subset of the config between machines through git.
+//!
+//! The sync repository lives at `<data_dir>/sync` and holds a single
+//! `config.toml` with the portable settings: machine-specific keys
+//! ([`MACHINE_KEYS`]) and anything that looks like a secret
+//! ([`is_secret_key`]) never leave the machine. The repository's `HEAD` is
+//! the state both sides last agreed on, so [`SyncRepo::pull`] can do a
+//! key-level three-way merge and report conflicts instead of overwriting
+//! local edits, and [`SyncRepo::push`] refuses to run while the remote has
+//! changes that have not been pulled. Git is driven through the `git`
+//! binary, so the user's credentials and transports just work.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde::Serialize;
+
+use crate::APP_NAME;
+use crate::config::{flatten, is_secret_key};
+use crate::ctx::Ctx;
+use crate::error::{CoreError, IoResultExt as _, Result};
+use crate::plan::Action;
+
+/// Directory of the sync repository inside the data directory.
+pub const SYNC_DIRNAME: &str = "sync";
+
+/// File holding the curated settings inside the sync repository.
+pub const SYNCED_FILE: &str = "config.toml";
+
+/// Branch used on the remote.
+pub const BRANCH: &str = "main";
+
+/// Machine-specific keys that are never synced, matched as dotted prefixes.
+pub const MACHINE_KEYS: &[&str] = &[
+    "$schema",
+    "paths",
+    "logging.file",
+    "runtime.parallelism",
+    "runtime.power_save",
+    "security",
+];
+
+/// Flattened settings: dotted key to leaf value (arrays are leaves).
+pub type Settings = BTreeMap<String, toml::Value>;
+
+/// Returns true if `key` (dotted) may be synced.
+#[must_use]
+pub fn is_synced_key(key: &str) -> bool {
+    let machine = MACHINE_KEYS.iter().any(|prefix| {
+        key == *prefix
+            || key
+                .strip_prefix(prefix)
+                .is_some_and(|rest| rest.starts_with('.'))
+    });
+    !machine && !is_secret_key(key)
+}
+
+/// Parse TOML text and keep only the settings that may be synced.
+///
+/// # Errors
+///
+/// Returns [`CoreError::Serialization`] if `text` is not valid TOML.
+pub fn curate(text: &str) -> Result<Settings> {
+    let table: toml::Table = toml::from_str(text)
+        .map_err(|err| CoreError::Serialization(format!("parsing config for sync: {err}")))?;
+    let mut settings = Settings::new();
+    flatten("", &table, &mut settings);
+    settings.retain(|key, _| is_synced_key(key));
+    Ok(settings)
+}
+
+/// Render settings back into a TOML document.
+///
+/// # Errors
+///
+/// Returns [`CoreError::Serialization`] if the settings cannot be
+/// serialized.
+pub fn render(settings: &Settings) -> Result<String> {
+    let mut root = toml::Table::new();
+    for (key, value) in settings {
+        let mut parts: Vec<&str> = key.split('.').collect();
+        let leaf = parts.pop().unwrap_or_default();
+        let mut table = &mut root;
+        for part in parts {
+            let entry = table
+                .entry(part)
+                .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+            table = entry.as_table_mut().ok_or_else(|| {
+                CoreError::Serialization(format!("sync key {key} collides with a non-table value"))
+            })?;
+        }
+        table.insert(leaf.to_string(), value.clone());
+    }
+    toml::to_string_pretty(&root)
+        .map_err(|err| CoreError::Serialization(format!("rendering synced config: {err}")))
+}
+
+/// One setting that differs between two sides.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SettingChange {
+    /// Dotted key.
+    pub key: String,
+    /// Value before the change; `None` when the key is added.
+    pub before: Option<toml::Value>,
+    /// Value after the change; `None` when the key is removed.
+    pub after: Option<toml::Value>,
+}
+
+/// A setting changed differently on this machine and on the remote.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SyncConflict {
+    /// Dotted key.
+    pub key: String,
+    /// Value in the local config (`None` if removed locally).
+    pub local: Option<toml::Value>,
+    /// Value on the remote (`None` if removed remotely).
+    pub remote: Option<toml::Value>,
+}
+
+/// Outcome of a push or pull.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SyncOutcome {
+    /// Changes sent to the remote (push) or applied locally (pull); on a
+    /// dry run, the changes that would be.
+    pub changes: Vec<SettingChange>,
+    /// Keys changed on both sides; a pull with conflicts applies nothing.
+    pub conflicts: Vec<SyncConflict>,
+}
+
+/// Which side wins when a setting changed both locally and remotely.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Prefer {
+    /// Keep the local value; the next push sends it.
+    Local,
+    /// Take the remote value.
+    Remote,
+}
+
+/// Changes that turn `from` into `to`.
+#[must_use]
+pub fn diff(from: &Settings, to: &Settings) -> Vec<SettingChange> {
+    let keys: BTreeSet<&String> = from.keys().chain(to.keys()).collect();
+    keys.into_iter()
+        .filter_map(|key| {
+            let (before, after) = (from.get(key), to.get(key));
+            (before != after).then(|| SettingChange {
+                key: key.clone(),
+                before: before.cloned(),
+                after: after.cloned(),
+            })
+        })
+        .collect()
+}
+
+/// Three-way merge of remote changes into `local`, relative to `base`.
+///
+/// Keys changed only remotely become changes to apply; keys changed on both
+/// sides to different values are conflicts.
+#[must_use]
+pub fn merge(base: &Settings, local: &Settings, remote: &Settings) -> SyncOutcome {
+    let mut outcome = SyncOutcome::default();
+    for change in diff(base, remote) {
+        let local_value = local.get(&change.key);
+        if local_value == change.after.as_ref() {
+            continue;
+        }
+        if local_value == change.before.as_ref() {
+            outcome.changes.push(SettingChange {
+                before: local_value.cloned(),
+                ..change
+            });
+        } else {
+            outcome.conflicts.push(SyncConflict {
+                key: change.key,
+                local: local_value.cloned(),
+                remote: change.after,
+            });
+        }
+    }
+    outcome
+}
+
+/// Apply `changes` to config text, keeping its comments and layout.
+///
+/// # Errors
+///
+/// Returns [`CoreError::Config`] if `text` is not valid TOML or a key runs
+/// through a non-table value.
+pub fn apply(text: &str, changes: &[SettingChange]) -> Result<String> {
+    let mut doc: toml_edit::DocumentMut = text
+        .parse()
+        .map_err(|err| CoreError::Config(format!("parsing local config: {err}")))?;
+    for change in changes {
+        let mut parts: Vec<&str> = change.key.split('.').collect();
+        let leaf = parts.pop().unwrap_or_default();
+        let mut table = doc.as_table_mut();
+        for part in parts {
+            table = table
+                .entry(part)
+                .or_insert_with(toml_edit::table)
+                .as_table_mut()
+                .ok_or_else(|| {
+                    CoreError::Config(format!("cannot set {}: {part} is not a table", change.key))
+                })?;
+        }
+        match &change.after {
+            Some(value) => {
+                let mut value: toml_edit::Value = value.to_string().parse().map_err(|err| {
+                    CoreError::Config(format!("converting value of {}: {err}", change.key))
+                })?;
+                // Replace in place so comments attached to the key survive.
+                if let Some(existing) = table.get_mut(leaf).and_then(toml_edit::Item::as_value_mut)
+                {
+                    *value.decor_mut() = existing.decor().clone();
+                    *existing = value;
+                } else {
+                    table.insert(leaf, toml_edit::Item::Value(value));
+                }
+            }
+            None => {
+                table.remove(leaf);
+            }
+        }
+    }
+    Ok(doc.to_string())
+}
+
+/// The git repository used for syncing.
+#[derive(Debug, Clone)]
+pub struct SyncRepo {
+    root: PathBuf,
+}
+
+impl SyncRepo {
+    /// Sync repository under `data_dir`.
+    #[must_use]
+    pub fn open(data_dir: &Path) -> Self {
+        Self {
+            root: data_dir.join(SYNC_DIRNAME),
+        }
+    }
+
+    /// Repository directory.
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns true once [`SyncRepo::init`] has run.
+    #[must_use]
+    pub fn is_initialized(&self) -> bool {
+        self.root.join(".git").is_dir()
+    }
+
+    /// Create the repository and point it at `url`.
+    ///
+    /// If the remote already has synced settings they become the base, so
+    /// the next pull applies them; otherwise the first push creates the
+    /// branch.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::Sync`] if the repository already exists, `git`
+    /// is not installed, or the remote cannot be fetched, and
+    /// [`CoreError::PathIo`] if the directory cannot be created.
+    pub fn init(&self, url: &str) -> Result<bool> {
+        if self.is_initialized() {
+            return Err(CoreError::Sync(format!(
+                "sync is already set up at {}; remove it to start over",
+                self.root.display()
+            )));
+        }
+        fs::create_dir_all(&self.root).at_path("creating sync repository", &self.root)?;
+        self.git(&["init", "--quiet", "--initial-branch", BRANCH])?;
+        self.git(&["remote", "add", "origin", url])?;
+        self.git(&["fetch", "--quiet", "origin"])?;
+        let has_remote = self.has_remote_branch();
+        if has_remote {
+            self.git(&["checkout", "--quiet", "-B", BRANCH, &remote_ref()])?;
+        }
+        Ok(has_remote)
+    }
+
+    /// Send the synced settings from the config file at `config_file` to the
+    /// remote. On a dry run nothing is committed or pushed; the outcome
+    /// lists what would be sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::Sync`] if sync is not set up, the remote has
+    /// changes that have not been pulled, or a git command fails,
+    /// [`CoreError::PathIo`] if a file cannot be read or written, and the
+    /// error of [`Ctx::check`] if `ctx` is cancelled or runs out of time
+    /// before the commit.
+    pub fn push(&self, config_file: &Path, ctx: &Ctx) -> Result<SyncOutcome> {
+        self.require_initialized()?;
+        let local = curate(&read_config(config_file)?)?;
+        self.git(&["fetch", "--quiet", "origin"])?;
+        if self.remote_is_ahead()? {
+            return Err(CoreError::Sync(
+                "the remote has changes this machine has not pulled; run `sync pull` first"
+                    .to_string(),
+            ));
+        }
+        let changes = diff(&self.base()?, &local);
+        if !changes.is_empty() && ctx.dry_run() {
+            ctx.plan(Action::WriteFile {
+                path: self.root.join(SYNCED_FILE),
+            });
+            ctx.plan(Action::RunCommand {
+                command: format!("git push origin {BRANCH}"),
+            });
+        }
+        if changes.is_empty() || ctx.dry_run() {
+            return Ok(SyncOutcome {
+                changes,
+                conflicts: Vec::new(),
+            });
+        }
+        let file = self.root.join(SYNCED_FILE);
+        fs::write(&file, render(&local)?).at_path("writing synced settings", &file)?;
+        ctx.check()?;
+        self.git(&["add", SYNCED_FILE])?;
+        self.commit(&format!(
+            "Update {APP_NAME} settings ({} keys)",
+            changes.len()
+        ))?;
+        self.git(&["push", "--quiet", "--set-upstream", "origin", BRANCH])?;
+        Ok(SyncOutcome {
+            changes,
+            conflicts: Vec::new(),
+        })
+    }
+
+    /// Merge remote changes into the config file at `config_file`.
+    ///
+    /// Conflicts are resolved in favour of `prefer` when given. Nothing is
+    /// written when conflicts remain or on a dry run; the outcome lists what
+    /// would change.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::Sync`] if sync is not set up or a git command
+    /// fails, [`CoreError::PathIo`] if the config cannot be read or
+    /// written, and the error of [`Ctx::check`] if `ctx` is cancelled or
+    /// runs out of time before the config is written.
+    pub fn pull(
+        &self,
+        config_file: &Path,
+        prefer: Option<Prefer>,
+        ctx: &Ctx,
+    ) -> Result<SyncOutcome> {
+        self.require_initialized()?;
+        self.git(&["fetch", "--quiet", "origin"])?;
+        if !self.has_remote_branch() {
+            return Ok(SyncOutcome::default());
+        }
+        let text = read_config(config_file)?;
+        let remote = self.settings_at(&remote_ref())?;
+        let mut outcome = merge(&self.base()?, &curate(&text)?, &remote);
+        match prefer {
+            Some(Prefer::Local) => outcome.conflicts.clear(),
+            Some(Prefer::Remote) => {
+                let taken = outcome.conflicts.drain(..).map(|conflict| SettingChange {
+                    key: conflict.key,
+                    before: conflict.local,
+                    after: conflict.remote,
+                });
+                outcome.changes.extend(taken);
+                outcome.changes.sort_by(|a, b| a.key.cmp(&b.key));
+            }
+            None => {}
+        }
+        if ctx.dry_run() && outcome.conflicts.is_empty() && !outcome.changes.is_empty() {
+            ctx.plan(Action::WriteFile {
+                path: config_file.to_path_buf(),
+            });
+        }
+        if ctx.dry_run() || !outcome.conflicts.is_empty() {
+            return Ok(outcome);
+        }
+        ctx.check()?;
+        if !outcome.changes.is_empty() {
+            fs::write(config_file, apply(&text, &outcome.changes)?)
+                .at_path("writing config file", config_file)?;
+        }
+        self.git(&["checkout", "--quiet", "-B", BRANCH, &remote_ref()])?;
+        Ok(outcome)
+    }
+
+    /// Settings recorded at the last sync (empty before the first one).
+    fn base(&self) -> Result<Settings> {
+        if self
+            .git(&["rev-parse", "--verify", "--quiet", "HEAD"])
+            .is_err()
+        {
+            return Ok(Settings::new());
+        }
+        self.settings_at("HEAD")
+    }
+
+    fn settings_at(&self, rev: &str) -> Result<Settings> {
+        curate(&self.git(&["show", &format!("{rev}:{SYNCED_FILE}")])?)
+    }
+
+    fn has_remote_branch(&self) -> bool {
+        self.git(&["rev-parse", "--verify", "--quiet", &remote_ref()])
+            .is_ok()
+    }
+
+    fn remote_is_ahead(&self) -> Result<bool> {
+        if !self.has_remote_branch() {
+            return Ok(false);
+        }
+        let range = if self
+            .git(&["rev-parse", "--verify", "--quiet", "HEAD"])
+            .is_ok()
+        {
+            format!("HEAD..{}", remote_ref())
+        } else {
+            remote_ref()
+        };
+        Ok(self.git(&["rev-list", "--count", &range])?.trim() != "0")
+    }
+
+    fn require_initialized(&self) -> Result<()> {
+        if self.is_initialized() {
+            Ok(())
+        } else {
+            Err(CoreError::Sync(
+                "sync is not set up; run `sync init <GIT_URL>` first".to_string(),
+            ))
+        }
+    }
+
+    /// Commit staged changes, falling back to an app identity when git has
+    /// no user configured.
+    fn commit(&self, message: &str) -> Result<()> {
+        let has_identity = self.git(&["config", "user.email"]).is_ok();
+        let mut args = Vec::new();
+        let identity = format!("user.email={APP_NAME}@localhost");
+        let name = format!("user.name={APP_NAME}");
+        if !has_identity {
+            args.extend(["-c", identity.as_str(), "-c", name.as_str()]);
+        }
+        args.extend(["commit", "--quiet", "-m", message]);
+        self.git(&args).map(drop)
+    }
+
+    fn git(&self, args: &[&str]) -> Result<String> {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(&self.root)
+            .args(args)
+            .env("GIT_TERMINAL_PROMPT", "0")
+            .output()
+            .map_err(|err| CoreError::Sync(format!("running git (is it installed?): {err}")))?;
+        if !output.status.success() {
+            return Err(CoreError::Sync(format!(
+                "git {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        String::from_utf8(output.stdout)
+            .map_err(|_| CoreError::Sync("git output is not valid UTF-8".to_string()))
+    }
+}
+
+/// Config file text; a missing file has no settings.
+fn read_config(path: &Path) -> Result<String> {
+    match fs::read_to_string(path) {
+        Ok(text) => Ok(text),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(String::new()),
+        Err(err) => Err(err).at_path("reading config file", path),
+    }
+}
+
+fn remote_ref() -> String {
+    format!("origin/{BRANCH}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOCAL: &str = r#"# my settings
+profile = "work"
+
+[logging]
+level = "debug"
+file = "/home/me/app.log"
+
+[runtime]
+parallelism = 8
+timeout = 30
+
+[paths]
+data_dir = "/srv/app"
+
+[remote]
+api_token = "hunter2"
+"#;
+
+    #[test]
+    fn curate_drops_machine_keys_and_secrets() -> anyhow::Result<()> {
+        let settings = curate(LOCAL)?;
+        let keys: Vec<&str> = settings.keys().map(String::as_str).collect();
+        anyhow::ensure!(
+            keys == ["logging.level", "profile", "runtime.timeout"],
+            "{keys:?}"
+        );
+        anyhow::ensure!(curate(&render(&settings)?)? == settings);
+        Ok(())
+    }
+
+    #[test]
+    fn merge_applies_remote_edits_and_flags_conflicts() -> anyhow::Result<()> {
+        let base =
+            curate("profile = \"work\"\n[runtime]\ntimeout = 60\n[logging]\nlevel = \"info\"\n")?;
+        let local =
+            curate("profile = \"home\"\n[runtime]\ntimeout = 60\n[logging]\nlevel = \"info\"\n")?;
+        let remote =
+            curate("profile = \"play\"\n[runtime]\ntimeout = 90\n[logging]\nlevel = \"info\"\n")?;
+        let outcome = merge(&base, &local, &remote);
+        let changed: Vec<&str> = outcome.changes.iter().map(|c| c.key.as_str()).collect();
+        let conflicted: Vec<&str> = outcome.conflicts.iter().map(|c| c.key.as_str()).collect();
+        anyhow::ensure!(changed == ["runtime.timeout"], "{changed:?}");
+        anyhow::ensure!(conflicted == ["profile"], "{conflicted:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn apply_keeps_comments() -> anyhow::Result<()> {
+        let changes = [
+            SettingChange {
+                key: "profile".to_string(),
+                before: Some(toml::Value::String("work".to_string())),
+                after: Some(toml::Value::String("home".to_string())),
+            },
+            SettingChange {
+                key: "runtime.timeout".to_string(),
+                before: Some(toml::Value::Integer(30)),
+                after: Some(toml::Value::Integer(90)),
+            },
+            SettingChange {
+                key: "backup.exclude".to_string(),
+                before: None,
+                after: Some(toml::Value::Array(vec![toml::Value::String(
+                    "state/*.log".to_string(),
+                )])),
+            },
+        ];
+        let updated = apply(LOCAL, &changes)?;
+        anyhow::ensure!(updated.starts_with("# my settings"), "{updated}");
+        let settings = curate(&updated)?;
+        anyhow::ensure!(
+            settings.get("runtime.timeout") == Some(&toml::Value::Integer(90)),
+            "{updated}"
+        );
+        anyhow::ensure!(settings.contains_key("backup.exclude"), "{updated}");
+        Ok(())
+    }
+}
@@ -0,0 +1,1211 @@
+//! Task runner for the `[tasks]` config table.
+//!
+//! Each [`TaskConfig`] runs a shell command or a command line of the binary
+//! itself, after the tasks it depends on. [`plan`] lists a task and its
+//! dependencies so that every task comes after what it needs, and reports
+//! unknown names and cycles. [`run`] starts tasks as soon as their
+//! dependencies have succeeded, at most `parallelism` at a time, and kills
+//! any that exceed their time limit. A task whose dependency failed is
+//! skipped. With `fail_fast`, the first failure cancels the tasks still
+//! running and stops new ones from starting.
+//!
+//! A task that sets `isolated` runs in a directory of its own under
+//! [`work_root`], named after it, instead of its `cwd`: its `inputs` are
+//! symlinked (on Windows, copied) in from `cwd` first, and once it succeeds
+//! its `outputs` are copied back. The directory is left in place, so what a
+//! failed task saw and wrote can be looked at afterwards; `clean --runs`
+//! removes it.
+//!
+//! A task that sets `pty` runs its command under a pseudo-terminal (with the
+//! `pty` feature), and the clean transcript of what it wrote is kept at
+//! [`log_path`].
+//!
+//! [`record`] keeps the last outcome of each task in the state directory,
+//! where [`history`] reads it back for status views. [`OutputReaders`]
+//! announce what a task with piped stdout and stderr writes, line by line,
+//! for callers that pass it on rather than let it reach a terminal.
+
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::io::{self, BufRead as _, BufReader, Read};
+use std::path::{Component, Path, PathBuf};
+use std::process::{Child, Command};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::bus::{Event, EventBus};
+use crate::config::TaskConfig;
+use crate::ctx::Ctx;
+use crate::error::{CoreError, IoResultExt as _, Result};
+use crate::pathfmt;
+use crate::paths::expand_str_path;
+use crate::progress::ProgressTask;
+use crate::usage::{self, ChildUsage};
+
+/// How often running tasks are checked for exit.
+const POLL: Duration = Duration::from_millis(20);
+
+/// Why tasks are cancelled or skipped after a failure under `fail_fast`.
+const FAIL_FAST: &str = "an earlier task failed (runtime.fail_fast)";
+
+/// File in the state directory holding the last outcome of each task.
+pub const HISTORY_FILENAME: &str = "tasks.json";
+
+/// Directory in the state directory holding what each run leaves behind.
+pub const RUNS_DIRNAME: &str = "runs";
+
+/// Directory of a run holding the transcripts of its `pty` tasks.
+pub const LOGS_DIRNAME: &str = "logs";
+
+/// Directory under which the isolated tasks of run `run_id` (its
+/// correlation ID) get their working directories:
+/// `<state_dir>/runs/<run_id>/work`.
+#[must_use]
+pub fn work_root(state_dir: &Path, run_id: &str) -> PathBuf {
+    state_dir.join(RUNS_DIRNAME).join(run_id).join("work")
+}
+
+/// Where the clean transcript of `pty` task `name` in run `run_id` is kept:
+/// `<state_dir>/runs/<run_id>/logs/<name>.log`.
+///
+/// # Errors
+///
+/// Returns an error if `name` cannot name a file.
+pub fn log_path(state_dir: &Path, run_id: &str, name: &str) -> Result<PathBuf> {
+    if !is_plain_relative(name) {
+        return Err(CoreError::Validation(format!(
+            "task name {name:?} cannot name a log file"
+        )));
+    }
+    Ok(state_dir
+        .join(RUNS_DIRNAME)
+        .join(run_id)
+        .join(LOGS_DIRNAME)
+        .join(format!("{name}.log")))
+}
+
+/// Whether `entry` names something inside the directory it is joined to:
+/// relative, with no `..`, and not the directory itself.
+#[must_use]
+pub fn is_plain_relative(entry: &str) -> bool {
+    let mut components = Path::new(entry).components();
+    components
+        .clone()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+        && components.any(|component| matches!(component, Component::Normal(_)))
+}
+
+/// Every task `target` needs, dependencies first, ending with `target`.
+///
+/// # Errors
+///
+/// Returns [`CoreError::Config`] if `target` is not defined, and
+/// [`CoreError::Validation`] if a dependency is undefined or the
+/// dependencies form a cycle.
+pub fn plan(tasks: &BTreeMap<String, TaskConfig>, target: &str) -> Result<Vec<String>> {
+    if !tasks.contains_key(target) {
+        let defined: Vec<&str> = tasks.keys().map(String::as_str).collect();
+        return Err(CoreError::Config(if defined.is_empty() {
+            format!("no task named '{target}'; define tasks under [tasks] in the config file")
+        } else {
+            format!("no task named '{target}' (defined: {})", defined.join(", "))
+        }));
+    }
+    let mut order = Vec::new();
+    visit(tasks, target, &mut Vec::new(), &mut order)?;
+    Ok(order)
+}
+
+fn visit(
+    tasks: &BTreeMap<String, TaskConfig>,
+    name: &str,
+    path: &mut Vec<String>,
+    order: &mut Vec<String>,
+) -> Result<()> {
+    if order.iter().any(|done| done == name) {
+        return Ok(());
+    }
+    if path.iter().any(|open| open == name) {
+        path.push(name.to_string());
+        return Err(CoreError::Validation(format!(
+            "tasks: dependency cycle: {}",
+            path.join(" -> ")
+        )));
+    }
+    path.push(name.to_string());
+    for dependency in tasks.get(name).map_or(&[][..], |task| &task.depends_on) {
+        if !tasks.contains_key(dependency) {
+            return Err(CoreError::Validation(format!(
+                "tasks.{name}.depends_on: no task named '{dependency}'"
+            )));
+        }
+        visit(tasks, dependency, path, order)?;
+    }
+    path.pop();
+    order.push(name.to_string());
+    Ok(())
+}
+
+/// The process that runs `task`, or `None` for a task that only groups
+/// its dependencies. A `builtin` runs `binary` with `globals` in front of
+/// its own words.
+///
+/// # Errors
+///
+/// Returns an error if `cwd` references an unset variable or `builtin` is
+/// not a command line.
+pub fn process(task: &TaskConfig, binary: &Path, globals: &[OsString]) -> Result<Option<Command>> {
+    let mut process = if let Some(command) = &task.command {
+        shell(command)
+    } else if let Some(builtin) = &task.builtin {
+        let words = shlex::split(builtin).ok_or_else(|| {
+            CoreError::Validation(format!("builtin {builtin:?} is not a command line"))
+        })?;
+        let mut process = Command::new(binary);
+        process.args(globals).args(words);
+        process
+    } else {
+        return Ok(None);
+    };
+    if let Some(cwd) = &task.cwd {
+        process.current_dir(expand_str_path(cwd)?);
+    }
+    process.envs(&task.env);
+    Ok(Some(process))
+}
+
+#[cfg(unix)]
+fn shell(command: &str) -> Command {
+    let mut process = Command::new("sh");
+    process.arg("-c").arg(command);
+    process
+}
+
+#[cfg(not(unix))]
+fn shell(command: &str) -> Command {
+    let mut process = Command::new("cmd");
+    process.arg("/C").arg(command);
+    process
+}
+
+/// Limits for [`run`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunOptions {
+    /// Most tasks running at once.
+    pub parallelism: usize,
+    /// Cancel running tasks and start no new ones after the first failure.
+    pub fail_fast: bool,
+    /// Time limit for tasks that do not set their own.
+    pub timeout: Option<Duration>,
+    /// Where isolated tasks get their working directories (see
+    /// [`work_root`]); without one they run in their `cwd` like any other.
+    pub work_root: Option<PathBuf>,
+}
+
+/// The stream a line of task output was written to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputStream {
+    /// Standard output.
+    Stdout,
+    /// Standard error.
+    Stderr,
+}
+
+/// Threads reading the piped stdout and stderr of tasks and announcing each
+/// line as an [`Event::TaskOutput`] as soon as it is written, so the output
+/// is passed on as it comes rather than collected.
+#[derive(Debug, Default)]
+pub struct OutputReaders(Vec<JoinHandle<()>>);
+
+impl OutputReaders {
+    /// Read whichever of `child`'s stdout and stderr are piped, publishing
+    /// their lines as task `name`'s on `events`. Invalid UTF-8 is replaced.
+    pub fn read(&mut self, name: &str, child: &mut Child, events: &EventBus) {
+        if let Some(stdout) = child.stdout.take() {
+            self.spawn(name, OutputStream::Stdout, stdout, events);
+        }
+        if let Some(stderr) = child.stderr.take() {
+            self.spawn(name, OutputStream::Stderr, stderr, events);
+        }
+    }
+
+    fn spawn(
+        &mut self,
+        name: &str,
+        stream: OutputStream,
+        pipe: impl Read + Send + 'static,
+        events: &EventBus,
+    ) {
+        let task = name.to_string();
+        let events = events.clone();
+        self.0.push(thread::spawn(move || {
+            let mut reader = BufReader::new(pipe);
+            let mut line = Vec::new();
+            while reader
+                .read_until(b'\n', &mut line)
+                .is_ok_and(|read| read > 0)
+            {
+                let text = String::from_utf8_lossy(&line);
+                events.publish(&Event::TaskOutput {
+                    task: task.clone(),
+                    stream,
+                    line: text.trim_end_matches(['\n', '\r']).to_string(),
+                });
+                line.clear();
+            }
+        }));
+    }
+
+    /// Wait until every stream is read to its end, for at most `wait`: a
+    /// process a task left running in the background can hold one open.
+    pub fn finish(self, wait: Duration) {
+        let deadline = Instant::now() + wait;
+        while self.0.iter().any(|reader| !reader.is_finished()) && Instant::now() < deadline {
+            thread::sleep(POLL);
+        }
+    }
+}
+
+/// How a task ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskStatus {
+    /// Exited with status 0, or had nothing to run.
+    Succeeded,
+    /// Exited with another status, or could not be started.
+    Failed,
+    /// Killed after exceeding its time limit.
+    TimedOut,
+    /// Not started: a dependency did not succeed, or `fail_fast` stopped
+    /// the run.
+    Skipped,
+    /// Stopped because shutdown was requested or, under `fail_fast`,
+    /// another task failed; or not started because of shutdown.
+    Cancelled,
+}
+
+impl TaskStatus {
+    /// Whether the task ran to success.
+    #[must_use]
+    pub const fn is_success(self) -> bool {
+        matches!(self, Self::Succeeded)
+    }
+
+    /// Whether the task ran and failed, which halts a `fail_fast` run.
+    #[must_use]
+    pub const fn is_failure(self) -> bool {
+        matches!(self, Self::Failed | Self::TimedOut)
+    }
+}
+
+impl fmt::Display for TaskStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Succeeded => "succeeded",
+            Self::Failed => "failed",
+            Self::TimedOut => "timed out",
+            Self::Skipped => "skipped",
+            Self::Cancelled => "cancelled",
+        })
+    }
+}
+
+/// Outcome of one task.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskRun {
+    /// Task name.
+    pub name: String,
+    /// How it ended.
+    pub status: TaskStatus,
+    /// Exit status of the command, when it exited on its own.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+    /// Wall-clock run time in milliseconds.
+    pub duration_ms: u64,
+    /// Why it failed or was not started.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Directory it ran in, if it was isolated.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::pathfmt::option"
+    )]
+    pub work_dir: Option<PathBuf>,
+    /// CPU time and peak RSS of its process, where they were measured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage: Option<ChildUsage>,
+}
+
+/// The last recorded outcome of a task.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LastRun {
+    /// How the task ended.
+    #[serde(flatten)]
+    pub run: TaskRun,
+    /// When it ended, in seconds since the Unix epoch.
+    pub finished_at: u64,
+}
+
+/// Store `runs` as the last outcome of their tasks in `state_dir`, keeping
+/// what was recorded for other tasks.
+///
+/// # Errors
+///
+/// Returns an error if the history cannot be read or written.
+pub fn record(state_dir: &Path, runs: &[TaskRun]) -> Result<()> {
+    let mut last = history(state_dir)?;
+    let finished_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    for run in runs {
+        last.insert(
+            run.name.clone(),
+            LastRun {
+                run: run.clone(),
+                finished_at,
+            },
+        );
+    }
+    let json = serde_json::to_string_pretty(&last)
+        .map_err(|err| CoreError::Serialization(format!("task history: {err}")))?;
+    fs::create_dir_all(state_dir).at_path("creating state directory", state_dir)?;
+    let path = state_dir.join(HISTORY_FILENAME);
+    let partial = path.with_extension("json.partial");
+    fs::write(&partial, json).at_path("writing task history", &partial)?;
+    fs::rename(&partial, &path).at_path("replacing task history", &path)
+}
+
+/// The last recorded outcome of each task in `state_dir`, by name. Nothing
+/// recorded yet is an empty history.
+///
+/// # Errors
+///
+/// Returns an error if the history exists but cannot be read or parsed.
+pub fn history(state_dir: &Path) -> Result<BTreeMap<String, LastRun>> {
+    let path = state_dir.join(HISTORY_FILENAME);
+    match fs::read_to_string(&path) {
+        Ok(text) => serde_json::from_str(&text).map_err(|err| {
+            CoreError::Serialization(format!("task history {}: {err}", path.display()))
+        }),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(err) => Err(err).at_path("reading task history", &path),
+    }
+}
+
+/// The process of a started task.
+#[derive(Debug)]
+pub enum TaskProcess {
+    /// A child process with its own stdio.
+    Child(Child),
+    /// A command under a pseudo-terminal, for a task with `pty = true`.
+    #[cfg(feature = "pty")]
+    Pty(crate::pty::PtySession),
+}
+
+impl From<Child> for TaskProcess {
+    fn from(child: Child) -> Self {
+        Self::Child(child)
+    }
+}
+
+#[cfg(feature = "pty")]
+impl From<crate::pty::PtySession> for TaskProcess {
+    fn from(session: crate::pty::PtySession) -> Self {
+        Self::Pty(session)
+    }
+}
+
+/// How a task's process ended.
+struct Exit {
+    success: bool,
+    /// None if a signal ended it.
+    code: Option<i32>,
+    usage: Option<ChildUsage>,
+}
+
+impl TaskProcess {
+    /// How it ended, once it has exited.
+    fn try_wait(&mut self) -> io::Result<Option<Exit>> {
+        match self {
+            Self::Child(child) => {
+                let (status, usage) = usage::reaping(|| child.try_wait());
+                Ok(status?.map(|status| Exit {
+                    success: status.success(),
+                    code: status.code(),
+                    usage,
+                }))
+            }
+            #[cfg(feature = "pty")]
+            Self::Pty(session) => {
+                if !session.is_finished() {
+                    return Ok(None);
+                }
+                let output = session.wait().map_err(io::Error::other)?;
+                Ok(Some(Exit {
+                    success: output.success(),
+                    code: i32::try_from(output.exit_code).ok(),
+                    usage: output.usage,
+                }))
+            }
+        }
+    }
+
+    /// Kill it and wait for it to go, returning its usage.
+    fn kill(&mut self) -> Option<ChildUsage> {
+        match self {
+            Self::Child(child) => {
+                let _ = child.kill();
+                usage::reaping(|| child.wait()).1
+            }
+            #[cfg(feature = "pty")]
+            Self::Pty(session) => {
+                let _ = session.kill();
+                session.wait().ok().and_then(|output| output.usage)
+            }
+        }
+    }
+}
+
+/// A task that has been started.
+struct Running<'a> {
+    name: &'a str,
+    child: TaskProcess,
+    started: Instant,
+    deadline: Option<Instant>,
+    isolation: Option<Isolation>,
+    /// Finishes the task's progress report when dropped.
+    progress: Box<dyn ProgressTask + 'a>,
+}
+
+/// Run the tasks in `order` (as returned by [`plan`]) and report each one,
+/// in `order`.
+///
+/// `start` launches a task's process, in the directory it is given for an
+/// isolated task, or returns `None` when it has nothing to run. An isolated
+/// task whose outputs cannot be collected fails. `ctx` is checked between polls; once it must stop (cancelled or
+/// past its deadline), running tasks are killed and nothing else starts.
+/// Under `fail_fast` the same happens after the first failure. Every task
+/// that is started is reported to the context's progress as a task of its
+/// own, failed unless it succeeded, and announced on its event bus, as is
+/// every outcome once known.
+pub fn run<'a>(
+    tasks: &BTreeMap<String, TaskConfig>,
+    order: &'a [String],
+    options: &RunOptions,
+    mut start: impl FnMut(&str, &TaskConfig, Option<&Path>) -> anyhow::Result<Option<TaskProcess>>,
+    ctx: &'a Ctx,
+) -> Vec<TaskRun> {
+    let mut done: BTreeMap<&str, TaskRun> = BTreeMap::new();
+    let mut running: Vec<Running<'_>> = Vec::new();
+    let mut waiting: Vec<&str> = order.iter().map(String::as_str).collect();
+    loop {
+        let stopping = stop_message(ctx);
+        let failed = |done: &BTreeMap<&str, TaskRun>| {
+            options.fail_fast && done.values().any(|run| run.status.is_failure())
+        };
+        let cancel = stopping
+            .as_deref()
+            .or_else(|| failed(&done).then_some(FAIL_FAST));
+        running.retain_mut(|task| match reap(task, cancel) {
+            Some(result) => {
+                let result = conclude(task, result);
+                if !result.status.is_success() {
+                    task.progress.fail(&describe(&result));
+                }
+                settle(&mut done, task.name, result, ctx);
+                false
+            }
+            None => true,
+        });
+        let halted = stopping.is_some() || failed(&done);
+        if !halted {
+            waiting.retain(|&name| {
+                let Some(task) = tasks.get(name) else {
+                    return false;
+                };
+                let mut blocked = false;
+                for dependency in &task.depends_on {
+                    match done.get(dependency.as_str()) {
+                        Some(run) if !run.status.is_success() => {
+                            let message = format!("dependency {dependency} {}", run.status);
+                            let skipped = not_started(name, TaskStatus::Skipped, message);
+                            settle(&mut done, name, skipped, ctx);
+                            return false;
+                        }
+                        Some(_) => {}
+                        None => blocked = true,
+                    }
+                }
+                if blocked || running.len() >= options.parallelism.max(1) {
+                    return true;
+                }
+                if let Some(task) = launch(name, task, options, &mut start, &mut done, ctx) {
+                    running.push(task);
+                }
+                false
+            });
+        }
+        if running.is_empty() && (halted || waiting.is_empty()) {
+            let (status, message) = stopping.map_or_else(
+                || (TaskStatus::Skipped, FAIL_FAST.to_string()),
+                |why| (TaskStatus::Cancelled, why),
+            );
+            for name in waiting {
+                settle(
+                    &mut done,
+                    name,
+                    not_started(name, status, message.clone()),
+                    ctx,
+                );
+            }
+            break;
+        }
+        thread::sleep(POLL);
+    }
+    order
+        .iter()
+        .filter_map(|name| done.remove(name.as_str()))
+        .collect()
+}
+
+/// Start `name`, in a working directory of its own if it is isolated, and
+/// return it if it is now running. A task with nothing to run, or that could
+/// not be started, is settled instead.
+fn launch<'a>(
+    name: &'a str,
+    task: &TaskConfig,
+    options: &RunOptions,
+    start: &mut impl FnMut(&str, &TaskConfig, Option<&Path>) -> anyhow::Result<Option<TaskProcess>>,
+    done: &mut BTreeMap<&'a str, TaskRun>,
+    ctx: &'a Ctx,
+) -> Option<Running<'a>> {
+    let started = Instant::now();
+    let reported = ctx.progress().start(name, None);
+    let isolation = options
+        .work_root
+        .as_deref()
+        .filter(|_| task.isolated)
+        .map(|root| Isolation::prepare(name, task, root))
+        .transpose();
+    let launched = match &isolation {
+        Ok(isolation) => {
+            let work = isolation.as_ref().map(|isolation| isolation.work.as_path());
+            start(name, task, work).map_err(|err| format!("{err:#}"))
+        }
+        Err(err) => Err(format!("preparing its working directory: {err}")),
+    };
+    match (launched, isolation) {
+        (Ok(Some(child)), Ok(isolation)) => {
+            log::info!(task = name; "task {name} started");
+            ctx.publish(&Event::TaskStarted {
+                task: name.to_string(),
+            });
+            let limit = task.timeout.map(Duration::from_secs).or(options.timeout);
+            return Some(Running {
+                name,
+                child,
+                started,
+                deadline: limit.map(|limit| started + limit),
+                isolation,
+                progress: reported,
+            });
+        }
+        (Ok(_), _) => {
+            let result = finished(name, TaskStatus::Succeeded, None, started, None);
+            settle(done, name, result, ctx);
+        }
+        (Err(message), _) => {
+            let mut result = finished(name, TaskStatus::Failed, None, started, Some(message));
+            if task.isolated {
+                result.work_dir = options.work_root.as_ref().map(|root| root.join(name));
+            }
+            reported.fail(&describe(&result));
+            settle(done, name, result, ctx);
+        }
+    }
+    None
+}
+
+/// The outcome of `task`, which ended as `result` says, once its outputs
+/// are collected if it is isolated.
+fn conclude(task: &Running<'_>, mut result: TaskRun) -> TaskRun {
+    if let Some(isolation) = &task.isolation {
+        result.work_dir = Some(isolation.work.clone());
+        if result.status.is_success()
+            && let Err(err) = isolation.collect()
+        {
+            result.status = TaskStatus::Failed;
+            result.message = Some(format!("collecting outputs: {err}"));
+        }
+    }
+    result
+}
+
+/// Record `run` as the outcome of `name`, log it, and announce it on `ctx`.
+fn settle<'a>(done: &mut BTreeMap<&'a str, TaskRun>, name: &'a str, run: TaskRun, ctx: &Ctx) {
+    log::info!(
+        task = name, status:% = run.status, duration_ms = run.duration_ms;
+        "task {name} {}", describe(&run)
+    );
+    ctx.publish(&Event::TaskFinished { run: run.clone() });
+    done.insert(name, run);
+}
+
+/// Why `ctx` stops the run, if it does, as a message for the tasks it
+/// cancels.
+fn stop_message(ctx: &Ctx) -> Option<String> {
+    ctx.stop_reason().map(|reason| match reason {
+        CoreError::Cancelled(why) => why,
+        other => other.to_string(),
+    })
+}
+
+/// The result of `task` if it has ended, killing it first if it ran out of
+/// time or the run is being cancelled for the given reason.
+fn reap(task: &mut Running<'_>, cancel: Option<&str>) -> Option<TaskRun> {
+    match task.child.try_wait() {
+        Ok(Some(exit)) => {
+            let outcome = if exit.success {
+                TaskStatus::Succeeded
+            } else {
+                TaskStatus::Failed
+            };
+            let mut result = finished(task.name, outcome, exit.code, task.started, None);
+            result.usage = exit.usage;
+            Some(result)
+        }
+        Ok(None) => {
+            let (status, message) = if let Some(reason) = cancel {
+                (TaskStatus::Cancelled, reason.to_string())
+            } else if task
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+            {
+                let limit = task
+                    .deadline
+                    .map_or(0, |deadline| (deadline - task.started).as_secs());
+                (TaskStatus::TimedOut, format!("killed after {limit}s"))
+            } else {
+                return None;
+            };
+            let usage = task.child.kill();
+            let mut result = finished(task.name, status, None, task.started, Some(message));
+            result.usage = usage;
+            Some(result)
+        }
+        Err(err) => Some(finished(
+            task.name,
+            TaskStatus::Failed,
+            None,
+            task.started,
+            Some(format!("waiting for the process: {err}")),
+        )),
+    }
+}
+
+fn finished(
+    name: &str,
+    status: TaskStatus,
+    exit_code: Option<i32>,
+    started: Instant,
+    message: Option<String>,
+) -> TaskRun {
+    TaskRun {
+        name: name.to_string(),
+        status,
+        exit_code,
+        duration_ms: u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX),
+        message,
+        work_dir: None,
+        usage: None,
+    }
+}
+
+fn not_started(name: &str, status: TaskStatus, message: String) -> TaskRun {
+    TaskRun {
+        name: name.to_string(),
+        status,
+        exit_code: None,
+        duration_ms: 0,
+        message: Some(message),
+        work_dir: None,
+        usage: None,
+    }
+}
+
+/// An isolated task's working directory, and the directory its inputs come
+/// from and its outputs go back to.
+#[derive(Debug)]
+struct Isolation {
+    work: PathBuf,
+    home: PathBuf,
+    outputs: Vec<String>,
+}
+
+impl Isolation {
+    /// Create a fresh working directory for `name` under `root` and bring in
+    /// the task's inputs.
+    fn prepare(name: &str, task: &TaskConfig, root: &Path) -> Result<Self> {
+        if !is_plain_relative(name) {
+            return Err(CoreError::Validation(format!(
+                "task name {name:?} cannot name a directory"
+            )));
+        }
+        let home = match &task.cwd {
+            Some(cwd) => expand_str_path(cwd)?,
+            None => {
+                std::env::current_dir().at_path("reading the current directory", Path::new("."))?
+            }
+        };
+        let home = home
+            .canonicalize()
+            .at_path("resolving the task directory", &home)?;
+        let work = root.join(name);
+        match fs::remove_dir_all(&work) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => {
+                return Err(err).at_path("removing an old working directory", &work);
+            }
+            _ => {}
+        }
+        fs::create_dir_all(&work).at_path("creating the working directory", &work)?;
+        for input in &task.inputs {
+            let from = home.join(input);
+            if fs::symlink_metadata(&from).is_err() {
+                return Err(CoreError::Path(format!(
+                    "input {input} not found in {}",
+                    pathfmt::display(&home)
+                )));
+            }
+            let to = work.join(input);
+            if let Some(parent) = to.parent() {
+                fs::create_dir_all(parent).at_path("creating the working directory", parent)?;
+            }
+            link(&from, &to).at_path("bringing in an input", &to)?;
+        }
+        log::debug!(task = name; "task {name} runs in {}", pathfmt::display(&work));
+        Ok(Self {
+            work,
+            home,
+            outputs: task.outputs.clone(),
+        })
+    }
+
+    /// Copy the outputs back to the task's own directory. One reached
+    /// through a linked input is already there and is left alone.
+    fn collect(&self) -> Result<()> {
+        let work = self
+            .work
+            .canonicalize()
+            .at_path("resolving the working directory", &self.work)?;
+        for output in &self.outputs {
+            let from = self.work.join(output);
+            let resolved = match from.canonicalize() {
+                Ok(resolved) => resolved,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                    return Err(CoreError::Path(format!("output {output} was not produced")));
+                }
+                Err(err) => return Err(err).at_path("resolving an output", &from),
+            };
+            if !resolved.starts_with(&work) {
+                continue;
+            }
+            let to = self.home.join(output);
+            if let Some(parent) = to.parent() {
+                fs::create_dir_all(parent).at_path("creating an output directory", parent)?;
+            }
+            copy_tree(&from, &to).at_path("copying an output", &to)?;
+        }
+        Ok(())
+    }
+}
+
+/// Make `from` available at `to`.
+#[cfg(unix)]
+fn link(from: &Path, to: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(from, to)
+}
+
+/// Make `from` available at `to`. Symlinks need a privilege on Windows that
+/// most users lack, so this copies.
+#[cfg(not(unix))]
+fn link(from: &Path, to: &Path) -> io::Result<()> {
+    copy_tree(from, to)
+}
+
+/// Copy the file or directory `from` to `to`, replacing files already there.
+fn copy_tree(from: &Path, to: &Path) -> io::Result<()> {
+    if fs::metadata(from)?.is_dir() {
+        fs::create_dir_all(to)?;
+        for entry in fs::read_dir(from)? {
+            let entry = entry?;
+            copy_tree(&entry.path(), &to.join(entry.file_name()))?;
+        }
+        Ok(())
+    } else {
+        fs::copy(from, to).map(drop)
+    }
+}
+
+fn describe(run: &TaskRun) -> String {
+    let mut text = format!("{} after {}ms", run.status, run.duration_ms);
+    if let Some(code) = run.exit_code.filter(|&code| code != 0) {
+        let _ = write!(text, " (exit {code})");
+    }
+    if let Some(message) = &run.message {
+        let _ = write!(text, ": {message}");
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::ctx::CancelToken;
+    use crate::progress::Progress;
+    use crate::scratch::Scratch;
+
+    /// Records what each task reports, as `(task, "start" | "fail" | "finish")`.
+    #[derive(Default)]
+    struct Recorder(Mutex<Vec<(String, &'static str)>>);
+
+    impl Recorder {
+        fn push(&self, task: &str, what: &'static str) {
+            if let Ok(mut reports) = self.0.lock() {
+                reports.push((task.to_string(), what));
+            }
+        }
+
+        fn of(&self, task: &str) -> Vec<&'static str> {
+            self.0.lock().map_or_else(
+                |_| Vec::new(),
+                |reports| {
+                    reports
+                        .iter()
+                        .filter(|(name, _)| name == task)
+                        .map(|&(_, what)| what)
+                        .collect()
+                },
+            )
+        }
+    }
+
+    impl Progress for Recorder {
+        fn start(&self, label: &str, _total: Option<u64>) -> Box<dyn ProgressTask + '_> {
+            self.push(label, "start");
+            Box::new(Reported(self, label.to_string()))
+        }
+    }
+
+    struct Reported<'a>(&'a Recorder, String);
+
+    impl ProgressTask for Reported<'_> {
+        fn advance(&self, _steps: u64) {}
+
+        fn set_message(&self, _message: &str) {}
+
+        fn fail(&self, _message: &str) {
+            self.0.push(&self.1, "fail");
+        }
+    }
+
+    impl Drop for Reported<'_> {
+        fn drop(&mut self) {
+            self.0.push(&self.1, "finish");
+        }
+    }
+
+    fn task(command: Option<&str>, depends_on: &[&str]) -> TaskConfig {
+        TaskConfig {
+            command: command.map(str::to_string),
+            depends_on: depends_on.iter().map(|dep| (*dep).to_string()).collect(),
+            ..TaskConfig::default()
+        }
+    }
+
+    #[test]
+    fn plans_dependencies_first_and_reports_cycles() -> anyhow::Result<()> {
+        let mut tasks = BTreeMap::from([
+            ("build".to_string(), task(None, &["fmt", "lint"])),
+            ("fmt".to_string(), task(None, &[])),
+            ("lint".to_string(), task(None, &["fmt"])),
+        ]);
+        anyhow::ensure!(plan(&tasks, "build")? == ["fmt", "lint", "build"]);
+        anyhow::ensure!(plan(&tasks, "nope").is_err());
+        tasks.insert("fmt".to_string(), task(None, &["build"]));
+        let cycle = plan(&tasks, "build").err().map(|err| err.to_string());
+        anyhow::ensure!(
+            cycle.as_deref()
+                == Some("invalid configuration: tasks: dependency cycle: build -> fmt -> build"),
+            "{cycle:?}"
+        );
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn skips_dependents_of_failures_and_kills_overruns() -> anyhow::Result<()> {
+        let tasks = BTreeMap::from([
+            ("ok".to_string(), task(Some("true"), &[])),
+            ("bad".to_string(), task(Some("exit 3"), &[])),
+            ("after".to_string(), task(Some("true"), &["bad"])),
+            (
+                "slow".to_string(),
+                TaskConfig {
+                    timeout: Some(1),
+                    ..task(Some("sleep 5"), &[])
+                },
+            ),
+            ("all".to_string(), task(None, &["ok", "after", "slow"])),
+        ]);
+        let order = plan(&tasks, "all")?;
+        let options = RunOptions {
+            parallelism: 4,
+            fail_fast: false,
+            timeout: None,
+            work_root: None,
+        };
+        let start =
+            |_: &str, task: &TaskConfig, _: Option<&Path>| -> anyhow::Result<Option<TaskProcess>> {
+                Ok(match process(task, Path::new("unused"), &[])? {
+                    Some(mut process) => Some(process.spawn()?.into()),
+                    None => None,
+                })
+            };
+        let recorder = Arc::new(Recorder::default());
+        let ctx = Ctx::default().with_progress(recorder.clone());
+        let runs = run(&tasks, &order, &options, start, &ctx);
+        let statuses: Vec<(&str, TaskStatus, Option<i32>)> = runs
+            .iter()
+            .map(|run| (run.name.as_str(), run.status, run.exit_code))
+            .collect();
+        anyhow::ensure!(
+            statuses
+                == [
+                    ("ok", TaskStatus::Succeeded, Some(0)),
+                    ("bad", TaskStatus::Failed, Some(3)),
+                    ("after", TaskStatus::Skipped, None),
+                    ("slow", TaskStatus::TimedOut, None),
+                    ("all", TaskStatus::Skipped, None),
+                ],
+            "{statuses:?}"
+        );
+        for (name, expected) in [
+            ("ok", &["start", "finish"][..]),
+            ("bad", &["start", "fail", "finish"]),
+            ("after", &[]),
+            ("slow", &["start", "fail", "finish"]),
+        ] {
+            let reported = recorder.of(name);
+            anyhow::ensure!(reported == expected, "{name}: {reported:?}");
+        }
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn fail_fast_cancels_running_tasks() -> anyhow::Result<()> {
+        let tasks = BTreeMap::from([
+            ("bad".to_string(), task(Some("exit 1"), &[])),
+            ("long".to_string(), task(Some("sleep 5"), &[])),
+            ("later".to_string(), task(Some("true"), &["bad"])),
+            ("all".to_string(), task(None, &["bad", "long", "later"])),
+        ]);
+        let order = plan(&tasks, "all")?;
+        let options = RunOptions {
+            parallelism: 2,
+            fail_fast: true,
+            timeout: None,
+            work_root: None,
+        };
+        let start =
+            |_: &str, task: &TaskConfig, _: Option<&Path>| -> anyhow::Result<Option<TaskProcess>> {
+                Ok(match process(task, Path::new("unused"), &[])? {
+                    Some(mut process) => Some(process.spawn()?.into()),
+                    None => None,
+                })
+            };
+        let ctx = Ctx::default();
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&events);
+        ctx.events().subscribe(move |event| {
+            if let Ok(mut seen) = seen.lock() {
+                seen.push(event.clone());
+            }
+        });
+        let began = Instant::now();
+        let runs = run(&tasks, &order, &options, start, &ctx);
+        let statuses: Vec<(&str, TaskStatus)> = runs
+            .iter()
+            .map(|run| (run.name.as_str(), run.status))
+            .collect();
+        anyhow::ensure!(
+            statuses
+                == [
+                    ("bad", TaskStatus::Failed),
+                    ("long", TaskStatus::Cancelled),
+                    ("later", TaskStatus::Skipped),
+                    ("all", TaskStatus::Skipped),
+                ],
+            "{statuses:?}"
+        );
+        anyhow::ensure!(began.elapsed() < Duration::from_secs(4));
+        // Both processes were announced, and every outcome as it was known.
+        let events = events
+            .lock()
+            .map_err(|err| anyhow::anyhow!("{err}"))?
+            .clone();
+        let started = events
+            .iter()
+            .filter(|event| matches!(event, Event::TaskStarted { .. }))
+            .count();
+        let finished: Vec<TaskRun> = events
+            .into_iter()
+            .filter_map(|event| match event {
+                Event::TaskFinished { run } => Some(run),
+                _ => None,
+            })
+            .collect();
+        anyhow::ensure!(started == 2 && finished.len() == runs.len());
+        anyhow::ensure!(finished.iter().all(|run| runs.contains(run)));
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn isolated_tasks_link_inputs_and_collect_outputs() -> anyhow::Result<()> {
+        let scratch = Scratch::new()?;
+        let home = scratch.join("project");
+        fs::create_dir_all(home.join("src"))?;
+        fs::write(home.join("src/main.txt"), "input")?;
+        let isolated = |command: &str, outputs: &[&str]| TaskConfig {
+            cwd: home.to_str().map(str::to_string),
+            isolated: true,
+            inputs: vec!["src".to_string()],
+            outputs: outputs.iter().map(|output| (*output).to_string()).collect(),
+            ..task(Some(command), &[])
+        };
+        let tasks = BTreeMap::from([
+            (
+                "build".to_string(),
+                isolated(
+                    "mkdir out && cp src/main.txt out/copy.txt && touch scratch.txt",
+                    &["out", "src/main.txt"],
+                ),
+            ),
+            ("forgetful".to_string(), isolated("true", &["missing"])),
+            ("all".to_string(), task(None, &["build", "forgetful"])),
+        ]);
+        let order = plan(&tasks, "all")?;
+        let root = work_root(&scratch.join("state"), "0123456789abcdef");
+        let options = RunOptions {
+            parallelism: 2,
+            fail_fast: false,
+            timeout: None,
+            work_root: Some(root.clone()),
+        };
+        let start = |_: &str, task: &TaskConfig, work: Option<&Path>| -> anyhow::Result<_> {
+            Ok(match process(task, Path::new("unused"), &[])? {
+                Some(mut process) => {
+                    process.current_dir(work.unwrap_or(&scratch));
+                    Some(process.spawn()?.into())
+                }
+                None => None,
+            })
+        };
+        let runs = run(&tasks, &order, &options, start, &Ctx::default());
+        let outcome = |name: &str| runs.iter().find(|run| run.name == name);
+        anyhow::ensure!(
+            outcome("build").map(|run| (run.status, run.work_dir.clone()))
+                == Some((TaskStatus::Succeeded, Some(root.join("build")))),
+            "{runs:?}"
+        );
+        // Outputs come back; the input reached through its link stays as it
+        // was, and what was not declared stays behind for inspection.
+        anyhow::ensure!(fs::read_to_string(home.join("out/copy.txt"))? == "input");
+        anyhow::ensure!(fs::read_to_string(home.join("src/main.txt"))? == "input");
+        anyhow::ensure!(!home.join("scratch.txt").exists());
+        anyhow::ensure!(root.join("build/scratch.txt").exists());
+        let forgetful = outcome("forgetful").and_then(|run| run.message.clone());
+        anyhow::ensure!(
+            forgetful.as_deref()
+                == Some("collecting outputs: path error: output missing was not produced"),
+            "{forgetful:?}"
+        );
+        anyhow::ensure!(is_plain_relative("out/./report.json") && !is_plain_relative("."));
+        anyhow::ensure!(!is_plain_relative("../up") && !is_plain_relative("/etc"));
+        Ok(())
+    }
+
+    #[test]
+    fn cancelled_context_starts_nothing() -> anyhow::Result<()> {
+        let tasks = BTreeMap::from([
+            ("fmt".to_string(), task(Some("true"), &[])),
+            ("build".to_string(), task(Some("true"), &["fmt"])),
+        ]);
+        let order = plan(&tasks, "build")?;
+        let token = CancelToken::default();
+        token.cancel();
+        let ctx = Ctx::default().with_cancel(token);
+        let mut started = 0;
+        let start =
+            |_: &str, _: &TaskConfig, _: Option<&Path>| -> anyhow::Result<Option<TaskProcess>> {
+                started += 1;
+                Ok(None)
+            };
+        let options = RunOptions {
+            parallelism: 1,
+            fail_fast: false,
+            timeout: None,
+            work_root: None,
+        };
+        let runs = run(&tasks, &order, &options, start, &ctx);
+        anyhow::ensure!(started == 0);
+        anyhow::ensure!(
+            runs.iter().all(|run| run.status == TaskStatus::Cancelled
+                && run.message.as_deref() == Some("cancel requested")),
+            "{runs:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn history_keeps_the_last_run_of_each_task() -> anyhow::Result<()> {
+        let dir = Scratch::new()?;
+        let outcome = |name: &str, status| TaskRun {
+            name: name.to_string(),
+            status,
+            exit_code: None,
+            duration_ms: 5,
+            message: None,
+            work_dir: None,
+            usage: None,
+        };
+        anyhow::ensure!(history(&dir)?.is_empty());
+        record(
+            &dir,
+            &[
+                outcome("build", TaskStatus::Succeeded),
+                outcome("test", TaskStatus::Failed),
+            ],
+        )?;
+        record(&dir, &[outcome("test", TaskStatus::Succeeded)])?;
+        let last = history(&dir)?;
+        let statuses: Vec<_> = last.values().map(|last| last.run.status).collect();
+        anyhow::ensure!(
+            last.keys().eq(["build", "test"])
+                && statuses == [TaskStatus::Succeeded, TaskStatus::Succeeded],
+            "{last:?}"
+        );
+        Ok(())
+    }
+}
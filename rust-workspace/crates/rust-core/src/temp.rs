@@ -0,0 +1,166 @@
+//! Managed temporary directories under the application cache directory.
+//!
+//! [`TempScope`] is an RAII directory under `<cache_dir>/tmp` that is removed
+//! when dropped. Scopes left behind by crashed or killed processes are swept
+//! on startup by [`sweep_stale`] once they are older than [`STALE_AFTER`].
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::compat;
+use crate::ctx::Ctx;
+use crate::error::{IoResultExt as _, Result};
+use crate::plan::Action;
+
+/// Directory name for temp scopes inside the cache directory.
+pub const TEMP_DIRNAME: &str = "tmp";
+
+/// Age after which an abandoned temp scope is considered stale.
+pub const STALE_AFTER: Duration = compat::hours(24);
+
+/// Disambiguates scopes created by the same process in the same instant.
+static SCOPE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// A temporary directory that is removed when dropped.
+#[derive(Debug)]
+pub struct TempScope {
+    path: PathBuf,
+    keep: bool,
+}
+
+impl TempScope {
+    /// Create a new uniquely named scope inside `root`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be created.
+    pub fn new_in(root: &Path) -> Result<Self> {
+        fs::create_dir_all(root).at_path("creating temp root", root)?;
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_nanos());
+        let counter = SCOPE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = root.join(format!("{}-{nanos}-{counter}", std::process::id()));
+        fs::create_dir(&path).at_path("creating temp directory", &path)?;
+        log::debug!("created temp scope {}", path.display());
+        Ok(Self { path, keep: false })
+    }
+
+    /// Path of the temporary directory.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Keep the directory on disk instead of removing it on drop.
+    ///
+    /// Useful for inspecting intermediate files after a failure; the sweep
+    /// still removes it once it becomes stale.
+    #[must_use]
+    pub fn keep(mut self) -> PathBuf {
+        self.keep = true;
+        self.path.clone()
+    }
+}
+
+impl Drop for TempScope {
+    fn drop(&mut self) {
+        if self.keep {
+            return;
+        }
+        if let Err(err) = fs::remove_dir_all(&self.path) {
+            log::warn!(
+                "failed to remove temp directory {}: {err}",
+                self.path.display()
+            );
+        }
+    }
+}
+
+/// Remove temp scopes in `root` last modified more than `max_age` ago.
+///
+/// On a dry run nothing is removed; the stale entries are only logged.
+/// Returns the number of stale entries found.
+///
+/// # Errors
+///
+/// Returns an error if `root` exists but cannot be read.
+pub fn sweep_stale(root: &Path, max_age: Duration, ctx: &Ctx) -> Result<usize> {
+    if !root.exists() {
+        return Ok(0);
+    }
+    let now = SystemTime::now();
+    let mut stale = 0;
+    for entry in fs::read_dir(root).at_path("reading", root)? {
+        let entry = entry.at_path("reading", root)?;
+        let age = entry
+            .metadata()
+            .and_then(|meta| meta.modified())
+            .ok()
+            .and_then(|modified| now.duration_since(modified).ok());
+        if age.is_none_or(|age| age <= max_age) {
+            continue;
+        }
+        stale += 1;
+        let path = entry.path();
+        if ctx.dry_run() {
+            ctx.plan(Action::Remove { path });
+            continue;
+        }
+        let removed = if path.is_dir() {
+            fs::remove_dir_all(&path)
+        } else {
+            fs::remove_file(&path)
+        };
+        match removed {
+            Ok(()) => log::debug!("removed stale temp entry {}", path.display()),
+            Err(err) => log::warn!("failed to remove {}: {err}", path.display()),
+        }
+    }
+    Ok(stale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch::Scratch;
+
+    #[test]
+    fn scope_is_removed_on_drop_unless_kept() -> anyhow::Result<()> {
+        let root = Scratch::new()?;
+        let scope = TempScope::new_in(&root)?;
+        let dropped = scope.path().to_path_buf();
+        anyhow::ensure!(dropped.is_dir(), "scope directory was not created");
+        drop(scope);
+        anyhow::ensure!(!dropped.exists(), "scope directory survived drop");
+
+        let kept = TempScope::new_in(&root)?.keep();
+        anyhow::ensure!(kept.is_dir(), "kept scope was removed");
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_only_counts_entries_older_than_max_age() -> anyhow::Result<()> {
+        let root = Scratch::new()?;
+        let kept = TempScope::new_in(&root)?.keep();
+        anyhow::ensure!(
+            sweep_stale(&root, STALE_AFTER, &Ctx::default())? == 0,
+            "fresh entry swept"
+        );
+        std::thread::sleep(Duration::from_millis(20));
+        anyhow::ensure!(
+            sweep_stale(
+                &root,
+                Duration::from_millis(1),
+                &Ctx::default().with_dry_run(true)
+            )? == 1,
+            "stale entry not found"
+        );
+        anyhow::ensure!(kept.exists(), "dry-run sweep removed an entry");
+        sweep_stale(&root, Duration::from_millis(1), &Ctx::default())?;
+        anyhow::ensure!(!kept.exists(), "stale entry survived sweep");
+        Ok(())
+    }
+}
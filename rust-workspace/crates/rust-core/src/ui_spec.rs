@@ -0,0 +1,425 @@
+//! Settings UI description generated from the config schema.
+//!
+//! A settings front-end (a Tauri or egui panel, a web form) renders one
+//! group per top-level config section and one widget per setting. Deriving
+//! the description from the JSON schema keeps such panels in step with
+//! [`AppConfig`](crate::AppConfig): a new field shows up with its label,
+//! default, and limits as soon as the artifacts are regenerated. Nested
+//! sections are flattened into their top-level group under dotted keys.
+//! Tables whose entries are sections themselves, such as `tasks`, have no
+//! sensible form control and are left to the config file.
+
+use anyhow::{Context, Result, bail};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::config::{env_var_name, is_secret_key};
+use crate::schema::generate_schema;
+
+/// Generated UI spec filename.
+pub const UI_SPEC_FILENAME: &str = "config.ui.json";
+
+/// Version of the UI spec layout; bumped on incompatible changes.
+pub const UI_SPEC_VERSION: u32 = 1;
+
+/// Group holding top-level settings that are not in a section.
+const GENERAL_GROUP: &str = "general";
+
+/// Description of a settings UI.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UiSpec {
+    /// Layout version ([`UI_SPEC_VERSION`]).
+    pub spec_version: u32,
+    /// Window or page title.
+    pub title: String,
+    /// Sections, in schema order with general settings first.
+    pub groups: Vec<UiGroup>,
+}
+
+/// One section of the settings UI.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UiGroup {
+    /// Config section, e.g. `backup`.
+    pub key: String,
+    /// Heading.
+    pub label: String,
+    /// What the section configures.
+    pub description: Option<String>,
+    /// Settings in the section.
+    pub fields: Vec<UiField>,
+}
+
+/// One setting.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UiField {
+    /// Dotted config key, e.g. `backup.compression_level`.
+    pub key: String,
+    /// Field label.
+    pub label: String,
+    /// Help text from the config docs.
+    pub description: Option<String>,
+    /// Suggested control.
+    pub widget: Widget,
+    /// JSON type of the value: `string`, `integer`, `number`, `boolean`,
+    /// `array`, or `object`.
+    pub value_type: String,
+    /// Whether the setting may be left unset.
+    pub optional: bool,
+    /// Default value, if the schema has one.
+    pub default: Option<Value>,
+    /// Value limits.
+    pub constraints: Constraints,
+    /// Choices for `select` and `multiselect`.
+    pub options: Vec<UiOption>,
+    /// Environment variable that overrides the setting.
+    pub env: String,
+}
+
+/// Suggested control for a setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Widget {
+    /// On/off switch.
+    Toggle,
+    /// Bounded number.
+    Slider,
+    /// Unbounded number.
+    Number,
+    /// Free text.
+    Text,
+    /// Text that must not be shown, e.g. a token.
+    Password,
+    /// File or directory path.
+    Path,
+    /// One of fixed choices.
+    Select,
+    /// Any of fixed choices.
+    Multiselect,
+    /// List of free-text entries.
+    List,
+    /// Named free-text entries, such as `aliases`. A [`ConfigEditor`]
+    /// holds each entry under `<key>.<name>`.
+    ///
+    /// [`ConfigEditor`]: crate::ConfigEditor
+    Map,
+}
+
+/// Value limits from the schema.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct Constraints {
+    /// Smallest allowed number.
+    pub min: Option<f64>,
+    /// Largest allowed number.
+    pub max: Option<f64>,
+}
+
+/// One choice of a `select` or `multiselect`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UiOption {
+    /// Value written to the config.
+    pub value: String,
+    /// What the choice does.
+    pub description: Option<String>,
+}
+
+/// Generate the settings UI description as pretty-printed JSON.
+///
+/// # Errors
+///
+/// Returns an error if the schema cannot be generated or has an unexpected
+/// shape.
+pub fn generate_ui_spec(project_name: &str) -> Result<String> {
+    let schema: Value = serde_json::from_str(&generate_schema(project_name)?)
+        .context("parsing generated JSON schema")?;
+    let spec = ui_spec(&schema, &format!("{project_name} settings"))?;
+    let mut json = serde_json::to_string_pretty(&spec).context("serializing UI spec")?;
+    json.push('\n');
+    Ok(json)
+}
+
+/// Build the UI description from a draft-07 `schema` of the config.
+///
+/// # Errors
+///
+/// Returns an error if the schema has no top-level properties or refers to
+/// a definition it does not contain.
+pub fn ui_spec(schema: &Value, title: &str) -> Result<UiSpec> {
+    let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
+        bail!("config schema has no properties");
+    };
+    let mut general = UiGroup {
+        key: GENERAL_GROUP.to_string(),
+        label: "General".to_string(),
+        description: None,
+        fields: Vec::new(),
+    };
+    let mut groups = Vec::new();
+    for (key, property) in properties {
+        if key.starts_with('$') {
+            continue;
+        }
+        let node = resolve(schema, property)?;
+        if is_table_of_sections(schema, node)? {
+            continue;
+        }
+        if is_section(node) {
+            let mut group = UiGroup {
+                key: key.clone(),
+                label: label(key),
+                description: description(property).or_else(|| description(node)),
+                fields: Vec::new(),
+            };
+            collect_fields(schema, key, node, &mut group.fields)?;
+            groups.push(group);
+        } else {
+            general.fields.push(field(schema, key, property)?);
+        }
+    }
+    if !general.fields.is_empty() {
+        groups.insert(0, general);
+    }
+    Ok(UiSpec {
+        spec_version: UI_SPEC_VERSION,
+        title: title.to_string(),
+        groups,
+    })
+}
+
+fn collect_fields(
+    schema: &Value,
+    prefix: &str,
+    section: &Value,
+    out: &mut Vec<UiField>,
+) -> Result<()> {
+    let Some(properties) = section.get("properties").and_then(Value::as_object) else {
+        return Ok(());
+    };
+    for (name, property) in properties {
+        let key = format!("{prefix}.{name}");
+        let node = resolve(schema, property)?;
+        if is_table_of_sections(schema, node)? {
+            continue;
+        }
+        if is_section(node) {
+            collect_fields(schema, &key, node, out)?;
+        } else {
+            out.push(field(schema, &key, property)?);
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn field(schema: &Value, key: &str, property: &Value) -> Result<UiField> {
+    let node = resolve(schema, property)?;
+    let (value_type, optional) = value_type(node);
+    let name = key.rsplit('.').next().unwrap_or(key);
+    let mut options = choices(node);
+    let widget = match value_type.as_str() {
+        "boolean" => Widget::Toggle,
+        "integer" | "number" if node.get("minimum").is_some() && node.get("maximum").is_some() => {
+            Widget::Slider
+        }
+        "integer" | "number" => Widget::Number,
+        "array" => {
+            let items = node
+                .get("items")
+                .map(|items| resolve(schema, items))
+                .transpose()?;
+            options = items.map(choices).unwrap_or_default();
+            if options.is_empty() {
+                Widget::List
+            } else {
+                Widget::Multiselect
+            }
+        }
+        "object" => Widget::Map,
+        _ if !options.is_empty() => Widget::Select,
+        _ if is_secret_key(key) => Widget::Password,
+        _ if name.ends_with("_dir") || name == "file" || name.ends_with("_file") => Widget::Path,
+        _ => Widget::Text,
+    };
+    Ok(UiField {
+        key: key.to_string(),
+        label: label(name),
+        description: description(property).or_else(|| description(node)),
+        widget,
+        value_type,
+        optional,
+        default: property
+            .get("default")
+            .or_else(|| node.get("default"))
+            .filter(|default| !default.is_null())
+            .cloned(),
+        constraints: Constraints {
+            min: node.get("minimum").and_then(Value::as_f64),
+            max: node.get("maximum").and_then(Value::as_f64),
+        },
+        options,
+        env: env_var_name(key),
+    })
+}
+
+/// Follow `$ref` and single-element `allOf` wrappers to the definition.
+pub(crate) fn resolve<'a>(schema: &'a Value, node: &'a Value) -> Result<&'a Value> {
+    if let Some(reference) = node.get("$ref").and_then(Value::as_str) {
+        let name = reference.trim_start_matches("#/definitions/");
+        let Some(definition) = schema.get("definitions").and_then(|defs| defs.get(name)) else {
+            bail!("config schema refers to missing definition {reference}");
+        };
+        return resolve(schema, definition);
+    }
+    match node
+        .get("allOf")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+    {
+        Some([inner]) => resolve(schema, inner),
+        _ => Ok(node),
+    }
+}
+
+pub(crate) fn is_section(node: &Value) -> bool {
+    node.get("type").and_then(Value::as_str) == Some("object") && node.get("properties").is_some()
+}
+
+/// Whether `node` is a table of named sections, such as `tasks`.
+fn is_table_of_sections(schema: &Value, node: &Value) -> Result<bool> {
+    match node.get("additionalProperties") {
+        Some(entry) if entry.is_object() => Ok(is_section(resolve(schema, entry)?)),
+        _ => Ok(false),
+    }
+}
+
+/// The value's JSON type and whether `null` is allowed.
+fn value_type(node: &Value) -> (String, bool) {
+    match node.get("type") {
+        Some(Value::String(kind)) => (kind.clone(), false),
+        Some(Value::Array(kinds)) => {
+            let mut optional = false;
+            let mut kind = String::from("string");
+            for entry in kinds.iter().filter_map(Value::as_str) {
+                if entry == "null" {
+                    optional = true;
+                } else {
+                    kind = entry.to_string();
+                }
+            }
+            (kind, optional)
+        }
+        // `oneOf` of string constants (enums) carries no top-level type.
+        _ => ("string".to_string(), false),
+    }
+}
+
+/// Fixed choices from `oneOf` constants or `enum`.
+fn choices(node: &Value) -> Vec<UiOption> {
+    if let Some(variants) = node.get("oneOf").and_then(Value::as_array) {
+        return variants
+            .iter()
+            .filter_map(|variant| {
+                let value = variant.get("const").or_else(|| {
+                    variant
+                        .get("enum")
+                        .and_then(Value::as_array)
+                        .and_then(|values| values.first())
+                })?;
+                Some(UiOption {
+                    value: value.as_str()?.to_string(),
+                    description: description(variant),
+                })
+            })
+            .collect();
+    }
+    node.get("enum")
+        .and_then(Value::as_array)
+        .map(|values| {
+            values
+                .iter()
+                .filter_map(Value::as_str)
+                .map(|value| UiOption {
+                    value: value.to_string(),
+                    description: None,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub(crate) fn description(node: &Value) -> Option<String> {
+    node.get("description")
+        .and_then(Value::as_str)
+        .map(|text| text.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+/// `compression_level` -> `Compression level`.
+fn label(name: &str) -> String {
+    let words = name.replace('_', " ");
+    let mut chars = words.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec() -> anyhow::Result<UiSpec> {
+        let schema: Value = serde_json::from_str(&generate_schema("demo")?)?;
+        ui_spec(&schema, "demo settings")
+    }
+
+    fn find<'a>(spec: &'a UiSpec, key: &str) -> anyhow::Result<&'a UiField> {
+        spec.groups
+            .iter()
+            .flat_map(|group| &group.fields)
+            .find(|field| field.key == key)
+            .ok_or_else(|| anyhow::anyhow!("no field {key}"))
+    }
+
+    #[test]
+    fn maps_schema_types_to_widgets() -> anyhow::Result<()> {
+        let spec = spec()?;
+        let level = find(&spec, "backup.compression_level")?;
+        anyhow::ensure!(level.widget == Widget::Slider, "{level:?}");
+        anyhow::ensure!(level.constraints.min == Some(1.0) && level.constraints.max == Some(22.0));
+        anyhow::ensure!(level.default == Some(Value::from(3)));
+
+        let log_level = find(&spec, "logging.level")?;
+        anyhow::ensure!(log_level.widget == Widget::Select, "{log_level:?}");
+        anyhow::ensure!(
+            log_level
+                .options
+                .iter()
+                .any(|option| option.value == "debug")
+        );
+
+        let probes = find(&spec, "health.probes")?;
+        anyhow::ensure!(probes.widget == Widget::Multiselect, "{probes:?}");
+
+        let file = find(&spec, "logging.file")?;
+        anyhow::ensure!(file.widget == Widget::Path && file.optional, "{file:?}");
+
+        anyhow::ensure!(find(&spec, "runtime.fail_fast")?.widget == Widget::Toggle);
+        anyhow::ensure!(find(&spec, "backup.exclude")?.widget == Widget::List);
+        anyhow::ensure!(find(&spec, "aliases")?.widget == Widget::Map);
+        anyhow::ensure!(find(&spec, "tasks").is_err(), "tasks belong in the file");
+        Ok(())
+    }
+
+    #[test]
+    fn groups_sections_and_flattens_nested_ones() -> anyhow::Result<()> {
+        let spec = spec()?;
+        anyhow::ensure!(spec.groups.first().map(|group| group.key.as_str()) == Some("general"));
+        let relabel = find(&spec, "security.selinux.relabel")?;
+        anyhow::ensure!(relabel.env == "RUST_WORKSPACE__SECURITY__SELINUX__RELABEL");
+        let security = spec
+            .groups
+            .iter()
+            .find(|group| group.key == "security")
+            .ok_or_else(|| anyhow::anyhow!("no security group"))?;
+        anyhow::ensure!(security.fields.len() == 2, "{security:?}");
+        anyhow::ensure!(find(&spec, "$schema").is_err());
+        Ok(())
+    }
+}
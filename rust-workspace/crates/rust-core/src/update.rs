@@ -0,0 +1,388 @@
+//! Self-update: picking a release artifact and verifying it before it
+//! replaces the running binary.
+//!
+//! A release carries one raw binary per platform, named by [`asset_name`]
+//! (`rust-cli-linux-x86_64`, `rust-cli-windows-x86_64.exe`), and a
+//! [`CHECKSUMS_ASSET`] file in `sha256sum` format covering them. A binary
+//! that embeds a minisign public key also requires the checksum file to be
+//! signed ([`SIGNATURE_SUFFIX`]), so a tampered download fails either the
+//! checksum or the signature. Fetching is left to the binary; nothing here
+//! touches the network.
+//!
+//! The binary also looks for a newer release in the background now and
+//! then; [`UpdateCheck`] caches what it found in the state directory so
+//! the next runs can show the notice without asking again.
+
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result, bail, ensure};
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD;
+use blake2::Blake2b512;
+use ed25519_dalek::{Signature, Verifier as _, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest as _, Sha256};
+
+/// Release file listing the SHA-256 of every other asset.
+pub const CHECKSUMS_ASSET: &str = "SHA256SUMS";
+
+/// Suffix of the minisign signature of [`CHECKSUMS_ASSET`].
+pub const SIGNATURE_SUFFIX: &str = ".minisig";
+
+/// State file holding the last [`UpdateCheck`].
+pub const CHECK_FILENAME: &str = "update-check.json";
+
+/// The outcome of the last background update check.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpdateCheck {
+    /// When the check ran, in seconds since the Unix epoch.
+    pub checked_at: u64,
+    /// Version of the latest release found.
+    pub latest: String,
+}
+
+impl UpdateCheck {
+    /// A check that found `latest` just now.
+    #[must_use]
+    pub fn now(latest: &semver::Version) -> Self {
+        Self {
+            checked_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+            latest: latest.to_string(),
+        }
+    }
+
+    /// The last check recorded in `state_dir`. An unreadable or corrupt
+    /// file counts as no check, so the next run simply checks again.
+    #[must_use]
+    pub fn load(state_dir: &Path) -> Option<Self> {
+        let text = fs::read_to_string(state_dir.join(CHECK_FILENAME)).ok()?;
+        serde_json::from_str(&text).ok()
+    }
+
+    /// Record this check in `state_dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self, state_dir: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self).context("serializing update check")?;
+        fs::create_dir_all(state_dir)
+            .with_context(|| format!("creating {}", state_dir.display()))?;
+        let path = state_dir.join(CHECK_FILENAME);
+        let partial = path.with_extension("json.partial");
+        fs::write(&partial, json).with_context(|| format!("writing {}", partial.display()))?;
+        fs::rename(&partial, &path).with_context(|| format!("replacing {}", path.display()))
+    }
+
+    /// Whether at least `interval` has passed since this check as of `now`.
+    /// A check dated in the future (a clock change) is due as well.
+    #[must_use]
+    pub fn is_due(&self, interval: Duration, now: SystemTime) -> bool {
+        let checked_at = UNIX_EPOCH + Duration::from_secs(self.checked_at);
+        now.duration_since(checked_at)
+            .map_or(true, |elapsed| elapsed >= interval)
+    }
+
+    /// The latest release, if it is newer than `current`.
+    #[must_use]
+    pub fn newer_than(&self, current: &str) -> Option<semver::Version> {
+        let latest = parse_version(&self.latest).ok()?;
+        (latest > parse_version(current).ok()?).then_some(latest)
+    }
+}
+
+/// Whether a background update check is due for `state_dir`: there is no
+/// recorded check, or the last one is at least `interval` old.
+#[must_use]
+pub fn check_due(state_dir: &Path, interval: Duration) -> bool {
+    UpdateCheck::load(state_dir).is_none_or(|last| last.is_due(interval, SystemTime::now()))
+}
+
+/// A published release, as the `GitHub` releases API returns it.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Release {
+    /// Git tag, e.g. `v1.2.0`.
+    pub tag_name: String,
+    /// Files attached to the release.
+    pub assets: Vec<Asset>,
+}
+
+/// A file attached to a release.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Asset {
+    /// File name.
+    pub name: String,
+    /// Where to download it.
+    pub browser_download_url: String,
+    /// Size in bytes.
+    pub size: u64,
+}
+
+impl Release {
+    /// The attached file called `name`.
+    #[must_use]
+    pub fn asset(&self, name: &str) -> Option<&Asset> {
+        self.assets.iter().find(|asset| asset.name == name)
+    }
+
+    /// The version the tag names.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tag is not a semantic version.
+    pub fn version(&self) -> Result<semver::Version> {
+        parse_version(&self.tag_name)
+    }
+}
+
+/// Name of the release asset holding `bin` for this platform.
+#[must_use]
+pub fn asset_name(bin: &str) -> String {
+    format!(
+        "{bin}-{}-{}{}",
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        std::env::consts::EXE_SUFFIX
+    )
+}
+
+/// Parse a version or release tag, with or without a leading `v`.
+///
+/// # Errors
+///
+/// Returns an error if `tag` is not a semantic version.
+pub fn parse_version(tag: &str) -> Result<semver::Version> {
+    let version = tag.strip_prefix('v').unwrap_or(tag);
+    semver::Version::parse(version)
+        .with_context(|| format!("release tag {tag} is not a semantic version"))
+}
+
+/// Expected SHA-256 of `name` in a `sha256sum` listing.
+#[must_use]
+pub fn checksum_for<'a>(sums: &'a str, name: &str) -> Option<&'a str> {
+    sums.lines().find_map(|line| {
+        let (digest, file) = line.split_once(char::is_whitespace)?;
+        // `sha256sum --binary` marks each file name with `*`.
+        let file = file.trim_start().trim_start_matches('*');
+        (file == name).then_some(digest)
+    })
+}
+
+/// Check that `bytes` hash to `expected` (hex).
+///
+/// # Errors
+///
+/// Returns an error if the digests differ.
+pub fn verify_checksum(bytes: &[u8], expected: &str) -> Result<()> {
+    let actual = format!("{:x}", Sha256::digest(bytes));
+    ensure!(
+        actual.eq_ignore_ascii_case(expected),
+        "checksum mismatch: expected {expected}, got {actual}"
+    );
+    Ok(())
+}
+
+/// Check a minisign `signature` (the text of a `.minisig` file) of `data`
+/// against `public_key` (the text of `minisign.pub`, or just its key line).
+///
+/// Both the signature and the signed trusted comment must verify.
+///
+/// # Errors
+///
+/// Returns an error if either is malformed, the signature was made with
+/// another key, or it does not match.
+pub fn verify_signature(data: &[u8], signature: &str, public_key: &str) -> Result<()> {
+    let key = decode(key_line(public_key), "minisign public key")?;
+    let Some((b"Ed", key)) = key.split_first_chunk::<2>() else {
+        bail!("not a minisign public key");
+    };
+    let Some((key_id, key)) = key.split_first_chunk::<8>() else {
+        bail!("minisign public key is truncated");
+    };
+    let key = <[u8; 32]>::try_from(key).context("minisign public key has the wrong length")?;
+    let key = VerifyingKey::from_bytes(&key).context("invalid minisign public key")?;
+
+    let mut lines = signature.lines();
+    let (Some(_untrusted), Some(signature), Some(trusted), Some(global)) =
+        (lines.next(), lines.next(), lines.next(), lines.next())
+    else {
+        bail!("minisign signature has fewer than four lines");
+    };
+    let trusted = trusted
+        .strip_prefix("trusted comment: ")
+        .context("minisign signature has no trusted comment")?;
+    let signature = decode(signature, "minisign signature")?;
+    let Some((algorithm, signature)) = signature.split_first_chunk::<2>() else {
+        bail!("minisign signature is truncated");
+    };
+    let Some((signed_by, signature)) = signature.split_first_chunk::<8>() else {
+        bail!("minisign signature is truncated");
+    };
+    ensure!(
+        signed_by == key_id,
+        "signature was made with a different key"
+    );
+    let signature =
+        <[u8; 64]>::try_from(signature).context("minisign signature has the wrong length")?;
+
+    // "ED" signs the BLAKE2b-512 hash of the data (minisign's default since
+    // 0.8); "Ed" signs the data itself.
+    let hashed;
+    let message: &[u8] = match algorithm {
+        b"ED" => {
+            hashed = Blake2b512::digest(data);
+            &hashed
+        }
+        b"Ed" => data,
+        _ => bail!("unknown minisign signature algorithm"),
+    };
+    key.verify(message, &Signature::from_bytes(&signature))
+        .context("signature does not match")?;
+
+    let global = decode(global, "minisign trusted comment signature")?;
+    let global = <[u8; 64]>::try_from(global.as_slice())
+        .context("minisign trusted comment signature has the wrong length")?;
+    let mut comment = signature.to_vec();
+    comment.extend_from_slice(trusted.as_bytes());
+    key.verify(&comment, &Signature::from_bytes(&global))
+        .context("trusted comment signature does not match")?;
+    Ok(())
+}
+
+/// The base64 line of a public key file.
+fn key_line(text: &str) -> &str {
+    text.lines()
+        .map(str::trim)
+        .rfind(|line| !line.is_empty() && !line.starts_with("untrusted comment:"))
+        .unwrap_or_default()
+}
+
+fn decode(text: &str, what: &str) -> Result<Vec<u8>> {
+    STANDARD
+        .decode(text.trim())
+        .with_context(|| format!("{what} is not base64"))
+}
+
+/// Replace the executable at `exe` with `bytes`, keeping its permissions.
+///
+/// The new file is written next to `exe` and renamed over it, so the swap
+/// is atomic and a failed write leaves the old binary in place. Windows
+/// cannot overwrite a running executable but can rename it, so there the
+/// old one is first moved aside to `<name>.old`.
+///
+/// # Errors
+///
+/// Returns an error if the new file cannot be written or moved into place.
+pub fn replace_executable(exe: &Path, bytes: &[u8]) -> Result<()> {
+    let staged = exe.with_extension("update");
+    let swap = || -> Result<()> {
+        fs::write(&staged, bytes).with_context(|| format!("writing {}", staged.display()))?;
+        let permissions = fs::metadata(exe)
+            .with_context(|| format!("reading {}", exe.display()))?
+            .permissions();
+        fs::set_permissions(&staged, permissions)
+            .with_context(|| format!("setting permissions of {}", staged.display()))?;
+        #[cfg(windows)]
+        {
+            let old = exe.with_extension("old");
+            let _ = fs::remove_file(&old);
+            fs::rename(exe, &old).with_context(|| format!("moving {} aside", exe.display()))?;
+        }
+        fs::rename(&staged, exe).with_context(|| format!("replacing {}", exe.display()))
+    };
+    swap().inspect_err(|_| {
+        let _ = fs::remove_file(&staged);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use ed25519_dalek::{Signer as _, SigningKey};
+
+    use super::*;
+    use crate::compat;
+    use crate::scratch::Scratch;
+
+    #[test]
+    fn finds_checksums_and_compares_versions() -> Result<()> {
+        let digest = format!("{:x}", Sha256::digest(b"binary"));
+        let sums = format!("0000  rust-cli-other\n{digest} *rust-cli-linux-x86_64\n");
+        ensure!(checksum_for(&sums, "rust-cli-linux-x86_64") == Some(digest.as_str()));
+        ensure!(checksum_for(&sums, "rust-cli").is_none());
+        verify_checksum(b"binary", &digest.to_uppercase())?;
+        ensure!(verify_checksum(b"tampered", &digest).is_err());
+        ensure!(parse_version("v1.2.0")? > parse_version("0.10.3")?);
+        ensure!(parse_version("latest").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn update_checks_are_cached_and_expire() -> Result<()> {
+        let dir = Scratch::new()?;
+        let day = compat::hours(24);
+        ensure!(UpdateCheck::load(&dir).is_none() && check_due(&dir, day));
+
+        let check = UpdateCheck::now(&parse_version("v1.2.0")?);
+        check.save(&dir)?;
+        ensure!(UpdateCheck::load(&dir).as_ref() == Some(&check));
+        ensure!(!check_due(&dir, day));
+        let checked_at = UNIX_EPOCH + Duration::from_secs(check.checked_at);
+        ensure!(check.is_due(day, checked_at + day));
+        ensure!(check.is_due(day, checked_at - Duration::from_secs(1)));
+        ensure!(check.newer_than("1.1.9") == Some(parse_version("1.2.0")?));
+        ensure!(check.newer_than("1.2.0").is_none());
+
+        fs::write(dir.join(CHECK_FILENAME), "{")?;
+        ensure!(UpdateCheck::load(&dir).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn verifies_minisign_signatures() -> Result<()> {
+        let signer = SigningKey::from_bytes(&[7; 32]);
+        let key_id = [1, 2, 3, 4, 5, 6, 7, 8];
+        let public_key = [b"Ed".as_slice(), &key_id, signer.verifying_key().as_bytes()].concat();
+        let public_key = format!(
+            "untrusted comment: minisign public key\n{}\n",
+            STANDARD.encode(public_key)
+        );
+        let data = b"SHA256SUMS contents";
+        let signature = signer.sign(&Blake2b512::digest(data)).to_bytes();
+        let trusted = "timestamp:1767225600\tfile:SHA256SUMS\thashed";
+        let global = signer.sign(&[signature.as_slice(), trusted.as_bytes()].concat());
+        let minisig = |key_id: &[u8]| {
+            format!(
+                "untrusted comment: signature\n{}\ntrusted comment: {trusted}\n{}\n",
+                STANDARD.encode([b"ED".as_slice(), key_id, &signature].concat()),
+                STANDARD.encode(global.to_bytes())
+            )
+        };
+
+        verify_signature(data, &minisig(&key_id), &public_key)?;
+        ensure!(verify_signature(b"tampered", &minisig(&key_id), &public_key).is_err());
+        ensure!(verify_signature(data, &minisig(&[0; 8]), &public_key).is_err());
+        let forged = minisig(&key_id).replace("hashed", "trusted");
+        ensure!(verify_signature(data, &forged, &public_key).is_err());
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn replaces_an_executable_keeping_its_mode() -> Result<()> {
+        use std::os::unix::fs::PermissionsExt as _;
+
+        let dir = Scratch::new()?;
+        let exe = dir.join("rust-cli");
+        fs::write(&exe, b"old")?;
+        fs::set_permissions(&exe, fs::Permissions::from_mode(0o750))?;
+        replace_executable(&exe, b"new")?;
+        ensure!(fs::read(&exe)? == b"new");
+        ensure!(fs::metadata(&exe)?.permissions().mode() & 0o777 == 0o750);
+        ensure!(!exe.with_extension("update").exists());
+        Ok(())
+    }
+}
@@ -0,0 +1,251 @@
+//! Per-invocation and per-task resource usage measurement.
+//!
+//! [`UsageMeter`] samples `getrusage` for the process and its waited-for
+//! children when started and again when finished, so the reported CPU time
+//! covers both in-process work and spawned commands. [`reaping`] measures
+//! one child instead, by how much the usage of the waited-for children grows
+//! while it is reaped. Platforms without `getrusage` only report wall time.
+
+use std::fmt;
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+/// Resource usage for a single command or task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ResourceUsage {
+    /// Elapsed wall-clock time in milliseconds.
+    pub wall_ms: u64,
+    /// CPU time spent in user mode in milliseconds, where available.
+    pub user_cpu_ms: Option<u64>,
+    /// CPU time spent in kernel mode in milliseconds, where available.
+    pub sys_cpu_ms: Option<u64>,
+    /// Peak resident set size in kibibytes, where available.
+    pub max_rss_kib: Option<u64>,
+}
+
+impl fmt::Display for ResourceUsage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "wall {}", format_ms(self.wall_ms))?;
+        if let Some(user) = self.user_cpu_ms {
+            write!(f, ", user {}", format_ms(user))?;
+        }
+        if let Some(sys) = self.sys_cpu_ms {
+            write!(f, ", sys {}", format_ms(sys))?;
+        }
+        if let Some(rss) = self.max_rss_kib {
+            write!(f, ", max rss {:.1} MiB", rss as f64 / 1024.0)?;
+        }
+        Ok(())
+    }
+}
+
+/// CPU time and peak RSS of one child process and the children it waited
+/// for, as [`reaping`] measured them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChildUsage {
+    /// CPU time spent in user mode in milliseconds.
+    pub user_cpu_ms: u64,
+    /// CPU time spent in kernel mode in milliseconds.
+    pub sys_cpu_ms: u64,
+    /// Peak resident set size in kibibytes. Only known when the child used
+    /// more than every child reaped before it, since the kernel keeps only
+    /// the largest. Linux counts a forked child's memory from before it
+    /// exec'd, so this is at least what this process used when it started
+    /// the child.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_rss_kib: Option<u64>,
+}
+
+impl fmt::Display for ChildUsage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "user {}, sys {}",
+            format_ms(self.user_cpu_ms),
+            format_ms(self.sys_cpu_ms)
+        )?;
+        if let Some(rss) = self.max_rss_kib {
+            write!(f, ", max rss {:.1} MiB", rss as f64 / 1024.0)?;
+        }
+        Ok(())
+    }
+}
+
+/// Held while a child is reaped, so [`reaping`] sees no other child's usage.
+static REAPING: Mutex<()> = Mutex::new(());
+
+/// Run `reap`, which waits for (or polls) one child, and return what it
+/// returned with the child's usage if it reaped it.
+///
+/// The usage is how much `getrusage(RUSAGE_CHILDREN)` grew across `reap`.
+/// Reaping through this function on any thread is serialized, so `reap`
+/// must not block; a child reaped elsewhere in the process at the same
+/// moment is counted too. It is `None` where `getrusage` is not available
+/// and when `reap` reaped nothing.
+pub fn reaping<T>(reap: impl FnOnce() -> T) -> (T, Option<ChildUsage>) {
+    let _serial = REAPING.lock().unwrap_or_else(PoisonError::into_inner);
+    let before = children();
+    let reaped = reap();
+    let usage = match (before, children()) {
+        (Some(before), Some(after)) if after != before => Some(ChildUsage {
+            user_cpu_ms: duration_ms(after.user.saturating_sub(before.user)),
+            sys_cpu_ms: duration_ms(after.sys.saturating_sub(before.sys)),
+            max_rss_kib: (after.max_rss_kib > before.max_rss_kib).then_some(after.max_rss_kib),
+        }),
+        _ => None,
+    };
+    (reaped, usage)
+}
+
+fn format_ms(ms: u64) -> String {
+    format!("{:.3}s", Duration::from_millis(ms).as_secs_f64())
+}
+
+/// CPU counters captured at one point in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CpuSample {
+    user: Duration,
+    sys: Duration,
+    max_rss_kib: u64,
+}
+
+/// Measures resource usage between [`UsageMeter::start`] and [`UsageMeter::finish`].
+#[derive(Debug, Clone, Copy)]
+pub struct UsageMeter {
+    started: Instant,
+    baseline: Option<CpuSample>,
+}
+
+impl UsageMeter {
+    /// Start measuring.
+    #[must_use]
+    pub fn start() -> Self {
+        Self {
+            started: Instant::now(),
+            baseline: sample(),
+        }
+    }
+
+    /// Stop measuring and return the usage accumulated since [`Self::start`].
+    ///
+    /// Peak RSS is a process-lifetime high-water mark, so it is reported as-is
+    /// rather than as a delta.
+    #[must_use]
+    pub fn finish(&self) -> ResourceUsage {
+        let wall_ms = duration_ms(self.started.elapsed());
+        match (self.baseline, sample()) {
+            (Some(before), Some(after)) => ResourceUsage {
+                wall_ms,
+                user_cpu_ms: Some(duration_ms(after.user.saturating_sub(before.user))),
+                sys_cpu_ms: Some(duration_ms(after.sys.saturating_sub(before.sys))),
+                max_rss_kib: Some(after.max_rss_kib),
+            },
+            _ => ResourceUsage {
+                wall_ms,
+                user_cpu_ms: None,
+                sys_cpu_ms: None,
+                max_rss_kib: None,
+            },
+        }
+    }
+}
+
+fn duration_ms(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+#[cfg(unix)]
+fn sample() -> Option<CpuSample> {
+    use nix::sys::resource::UsageWho;
+
+    let own = rusage(UsageWho::RUSAGE_SELF)?;
+    let children = rusage(UsageWho::RUSAGE_CHILDREN)?;
+    Some(CpuSample {
+        user: own.user + children.user,
+        sys: own.sys + children.sys,
+        max_rss_kib: own.max_rss_kib.max(children.max_rss_kib),
+    })
+}
+
+/// Usage of the children reaped so far.
+#[cfg(unix)]
+fn children() -> Option<CpuSample> {
+    rusage(nix::sys::resource::UsageWho::RUSAGE_CHILDREN)
+}
+
+#[cfg(unix)]
+fn rusage(who: nix::sys::resource::UsageWho) -> Option<CpuSample> {
+    use nix::sys::resource::getrusage;
+    use nix::sys::time::TimeValLike;
+
+    let usage = getrusage(who).ok()?;
+    let to_duration = |us: i64| Duration::from_micros(u64::try_from(us).unwrap_or_default());
+    let rss = u64::try_from(usage.max_rss()).unwrap_or_default();
+    Some(CpuSample {
+        user: to_duration(usage.user_time().num_microseconds()),
+        sys: to_duration(usage.system_time().num_microseconds()),
+        // macOS reports ru_maxrss in bytes; Linux and the BSDs use kibibytes.
+        max_rss_kib: if cfg!(target_os = "macos") {
+            rss / 1024
+        } else {
+            rss
+        },
+    })
+}
+
+#[cfg(not(unix))]
+const fn sample() -> Option<CpuSample> {
+    None
+}
+
+#[cfg(not(unix))]
+const fn children() -> Option<CpuSample> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_includes_available_fields() {
+        let usage = ResourceUsage {
+            wall_ms: 1_250,
+            user_cpu_ms: Some(40),
+            sys_cpu_ms: None,
+            max_rss_kib: Some(2_048),
+        };
+        assert_eq!(
+            usage.to_string(),
+            "wall 1.250s, user 0.040s, max rss 2.0 MiB"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn reaping_measures_the_child_it_reaps() -> anyhow::Result<()> {
+        let mut child = std::process::Command::new("sh")
+            .args(["-c", "i=0; while [ $i -lt 20000 ]; do i=$((i+1)); done"])
+            .spawn()?;
+        let (status, usage) = loop {
+            let (status, usage) = reaping(|| child.try_wait());
+            if let Some(status) = status? {
+                break (status, usage);
+            }
+            std::thread::sleep(Duration::from_millis(5));
+        };
+        anyhow::ensure!(status.success());
+        let usage = usage.ok_or_else(|| anyhow::anyhow!("no usage for the child"))?;
+        anyhow::ensure!(usage.user_cpu_ms + usage.sys_cpu_ms > 0, "{usage:?}");
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn meter_reports_cpu_and_rss_on_unix() {
+        let usage = UsageMeter::start().finish();
+        assert!(usage.user_cpu_ms.is_some() && usage.max_rss_kib.is_some_and(|rss| rss > 0));
+    }
+}
@@ -0,0 +1,87 @@
+//! Time limit for a whole operation, enforced from a separate thread.
+//!
+//! Most commands cannot be interrupted from within: they block on a file, a
+//! lock, or the network. [`Watchdog::arm`] starts a thread that waits out
+//! the limit and then calls its `on_expiry` hook, which reports the timeout
+//! and ends the process. Dropping the watchdog before then disarms it.
+//! Child processes with their own limits, such as tasks, are killed by the
+//! task runner instead (see [`crate::task`]).
+
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use crate::error::Result;
+
+/// An armed time limit; disarmed when dropped.
+#[derive(Debug)]
+pub struct Watchdog {
+    /// Dropped to wake the thread before the limit.
+    disarm: Option<Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Watchdog {
+    /// Call `on_expiry` once `limit` has passed, unless the watchdog is
+    /// dropped first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the watchdog thread cannot be started.
+    pub fn arm(limit: Duration, on_expiry: impl FnOnce() + Send + 'static) -> Result<Self> {
+        let (disarm, disarmed) = mpsc::channel::<()>();
+        let thread = thread::Builder::new()
+            .name("watchdog".to_string())
+            .spawn(move || {
+                if disarmed.recv_timeout(limit) == Err(RecvTimeoutError::Timeout) {
+                    on_expiry();
+                }
+            })?;
+        Ok(Self {
+            disarm: Some(disarm),
+            thread: Some(thread),
+        })
+    }
+}
+
+impl Drop for Watchdog {
+    fn drop(&mut self) {
+        drop(self.disarm.take());
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use super::*;
+    use crate::compat;
+
+    fn flagged() -> (Arc<AtomicBool>, impl FnOnce() + Send + 'static) {
+        let flag = Arc::new(AtomicBool::new(false));
+        let set = Arc::clone(&flag);
+        (flag, move || set.store(true, Ordering::SeqCst))
+    }
+
+    #[test]
+    fn expires_after_the_limit() -> anyhow::Result<()> {
+        let (expired, on_expiry) = flagged();
+        let watchdog = Watchdog::arm(Duration::from_millis(10), on_expiry)?;
+        thread::sleep(Duration::from_millis(200));
+        drop(watchdog);
+        anyhow::ensure!(expired.load(Ordering::SeqCst));
+        Ok(())
+    }
+
+    #[test]
+    fn dropping_disarms_it() -> anyhow::Result<()> {
+        let (expired, on_expiry) = flagged();
+        drop(Watchdog::arm(compat::minutes(1), on_expiry)?);
+        anyhow::ensure!(!expired.load(Ordering::SeqCst));
+        Ok(())
+    }
+}
@@ -0,0 +1,481 @@
+//! Desktop settings editor and task launcher built on egui.
+//!
+//! Shows that rust-core is not tied to a terminal. The settings tab is laid
+//! out from the settings UI spec ([`rust_core::ui_spec`]) and edits the
+//! config file through [`ConfigEditor`], so the user's comments survive a
+//! save. The tasks tab lists tasks the way shell completion does
+//! (`<PREFIX>_COMPLETE=tasks rust-cli`) and runs them through rust-cli on a
+//! worker thread, so the window stays responsive while a task runs.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+use anyhow::{Context as _, Result, anyhow};
+use eframe::egui;
+use rust_core::ui_spec::{UiField, UiSpec, Widget, ui_spec};
+use rust_core::{APP_NAME, AppPaths, ConfigEditor, PathMode, env_prefix, generate_schema};
+
+fn main() -> Result<()> {
+    // Only the config file is used, which `[paths]` cannot move, so unlike
+    // the other binaries this skips `AppPaths::load`: a config that fails to
+    // load must still open here to be fixed.
+    let paths = AppPaths::discover_in(PathMode::detect(), None)?;
+    let schema: serde_json::Value =
+        serde_json::from_str(&generate_schema(APP_NAME)?).context("parsing config schema")?;
+    let spec = ui_spec(&schema, &format!("{APP_NAME} settings"))?;
+    let editor = ConfigEditor::open(&paths.config_file)?;
+    let app = App::new(spec, editor, cli_path());
+    let options = eframe::NativeOptions {
+        viewport: egui::ViewportBuilder::default()
+            .with_title(app.spec.title.as_str())
+            .with_inner_size([760.0, 560.0]),
+        ..Default::default()
+    };
+    eframe::run_native(APP_NAME, options, Box::new(|_| Ok(Box::new(app))))
+        .map_err(|err| anyhow!("running the window: {err}"))
+}
+
+/// rust-cli next to this binary, else from `PATH`.
+fn cli_path() -> PathBuf {
+    std::env::current_exe()
+        .ok()
+        .map(|exe| exe.with_file_name(format!("rust-cli{}", std::env::consts::EXE_SUFFIX)))
+        .filter(|path| path.is_file())
+        .unwrap_or_else(|| PathBuf::from("rust-cli"))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tab {
+    Settings,
+    Tasks,
+}
+
+/// Outcome of the last button press, shown under the toolbar.
+#[derive(Debug)]
+enum Status {
+    Info(String),
+    Error(String),
+}
+
+struct App {
+    spec: UiSpec,
+    editor: ConfigEditor,
+    /// Text typed into text-like fields, kept even while it does not parse.
+    drafts: BTreeMap<String, String>,
+    status: Option<Status>,
+    tab: Tab,
+    cli: PathBuf,
+    tasks: Vec<String>,
+    /// Task runs, newest first.
+    runs: Vec<Run>,
+}
+
+impl App {
+    fn new(spec: UiSpec, editor: ConfigEditor, cli: PathBuf) -> Self {
+        let tasks = list_tasks(&cli);
+        Self {
+            spec,
+            editor,
+            drafts: BTreeMap::new(),
+            status: None,
+            tab: Tab::Settings,
+            cli,
+            tasks,
+            runs: Vec::new(),
+        }
+    }
+
+    fn settings(&mut self, ui: &mut egui::Ui) {
+        let Self {
+            spec,
+            editor,
+            drafts,
+            status,
+            ..
+        } = self;
+        let pending = editor.changes().len();
+        ui.horizontal(|ui| {
+            let save = egui::Button::new(format!("Save {pending} change(s)"));
+            if ui.add_enabled(pending > 0, save).clicked() {
+                *status = Some(match editor.save() {
+                    Ok(changes) => Status::Info(format!(
+                        "saved {} change(s) to {}",
+                        changes.len(),
+                        editor.path().display()
+                    )),
+                    Err(err) => Status::Error(err.to_string()),
+                });
+            }
+            if ui
+                .add_enabled(pending > 0, egui::Button::new("Revert"))
+                .clicked()
+            {
+                editor.revert();
+                drafts.clear();
+                *status = None;
+            }
+            if ui.button("Reload").clicked() {
+                match ConfigEditor::open(editor.path()) {
+                    Ok(reopened) => {
+                        *editor = reopened;
+                        drafts.clear();
+                        *status = Some(Status::Info("reloaded from disk".to_string()));
+                    }
+                    Err(err) => *status = Some(Status::Error(err.to_string())),
+                }
+            }
+        });
+        let error = ui.visuals().error_fg_color;
+        if let Err(err) = editor.validate() {
+            ui.colored_label(error, err.to_string());
+        }
+        match status {
+            Some(Status::Info(text)) => {
+                ui.label(text.as_str());
+            }
+            Some(Status::Error(text)) => {
+                ui.colored_label(error, text.as_str());
+            }
+            None => {}
+        }
+        ui.separator();
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            for group in &spec.groups {
+                egui::CollapsingHeader::new(group.label.as_str())
+                    .default_open(true)
+                    .show(ui, |ui| {
+                        if let Some(description) = &group.description {
+                            ui.weak(description.as_str());
+                        }
+                        egui::Grid::new(group.key.as_str())
+                            .num_columns(2)
+                            .striped(true)
+                            .show(ui, |ui| {
+                                for field in &group.fields {
+                                    if let Some(rejected) = field_row(ui, field, editor, drafts) {
+                                        *status = Some(Status::Error(rejected));
+                                    }
+                                }
+                            });
+                    });
+            }
+        });
+    }
+
+    fn tasks(&mut self, ui: &mut egui::Ui) {
+        for run in &mut self.runs {
+            run.poll();
+        }
+        if self.tasks.is_empty() {
+            ui.label(format!(
+                "No tasks found. Is {} installed?",
+                self.cli.display()
+            ));
+        }
+        let mut started = None;
+        ui.horizontal_wrapped(|ui| {
+            for task in &self.tasks {
+                if ui.button(format!("Run {task}")).clicked() {
+                    started = Some(task.clone());
+                }
+            }
+        });
+        if let Some(task) = started {
+            self.runs
+                .insert(0, Run::start(&self.cli, task, ui.ctx().clone()));
+        }
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            for run in &self.runs {
+                ui.separator();
+                ui.strong(run.task.as_str());
+                let Some(text) = &run.output else {
+                    ui.spinner();
+                    continue;
+                };
+                ui.monospace(text.as_str());
+            }
+        });
+    }
+}
+
+impl eframe::App for App {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        egui::TopBottomPanel::top("tabs").show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.selectable_value(&mut self.tab, Tab::Settings, "Settings");
+                ui.selectable_value(&mut self.tab, Tab::Tasks, "Tasks");
+                ui.separator();
+                ui.weak(self.editor.path().display().to_string());
+            });
+        });
+        egui::CentralPanel::default().show(ctx, |ui| match self.tab {
+            Tab::Settings => self.settings(ui),
+            Tab::Tasks => self.tasks(ui),
+        });
+    }
+}
+
+/// One setting's label and control; returns why typed text was rejected.
+fn field_row(
+    ui: &mut egui::Ui,
+    field: &UiField,
+    editor: &mut ConfigEditor,
+    drafts: &mut BTreeMap<String, String>,
+) -> Option<String> {
+    let label = ui.label(field.label.as_str());
+    if let Some(description) = &field.description {
+        label.on_hover_text(format!("{description}\n\n{} / {}", field.key, field.env));
+    }
+    let mut rejected = None;
+    match field.widget {
+        Widget::Toggle => {
+            let mut on = editor
+                .get(&field.key)
+                .and_then(toml::Value::as_bool)
+                .unwrap_or_default();
+            if ui.checkbox(&mut on, "").changed() {
+                editor.set(&field.key, toml::Value::Boolean(on));
+            }
+        }
+        Widget::Slider => {
+            let min = field.constraints.min.unwrap_or(0.0);
+            let max = field.constraints.max.unwrap_or(100.0);
+            let integer = field.value_type == "integer";
+            let mut value = editor.get(&field.key).and_then(number).unwrap_or(min);
+            let mut slider = egui::Slider::new(&mut value, min..=max);
+            if integer {
+                slider = slider.integer();
+            }
+            if ui.add(slider).changed() {
+                let value = if integer {
+                    toml::Value::Integer(value.round() as i64)
+                } else {
+                    toml::Value::Float(value)
+                };
+                editor.set(&field.key, value);
+            }
+        }
+        Widget::Select => {
+            let current = editor
+                .get(&field.key)
+                .and_then(toml::Value::as_str)
+                .unwrap_or_default()
+                .to_string();
+            let mut selected = current.clone();
+            egui::ComboBox::from_id_salt(field.key.as_str())
+                .selected_text(selected.as_str())
+                .show_ui(ui, |ui| {
+                    for option in &field.options {
+                        let choice = ui.selectable_value(
+                            &mut selected,
+                            option.value.clone(),
+                            option.value.as_str(),
+                        );
+                        if let Some(description) = &option.description {
+                            choice.on_hover_text(description.as_str());
+                        }
+                    }
+                });
+            if selected != current {
+                editor.set(&field.key, toml::Value::String(selected));
+            }
+        }
+        Widget::Multiselect => {
+            let chosen: Vec<String> = editor
+                .get(&field.key)
+                .and_then(toml::Value::as_array)
+                .map(|items| {
+                    items
+                        .iter()
+                        .filter_map(toml::Value::as_str)
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default();
+            let mut next = chosen.clone();
+            ui.horizontal_wrapped(|ui| {
+                for option in &field.options {
+                    let mut on = chosen.contains(&option.value);
+                    if ui.checkbox(&mut on, option.value.as_str()).changed() {
+                        if on {
+                            next.push(option.value.clone());
+                        } else {
+                            next.retain(|value| value != &option.value);
+                        }
+                    }
+                }
+            });
+            if next != chosen {
+                let items = next.into_iter().map(toml::Value::String).collect();
+                editor.set(&field.key, toml::Value::Array(items));
+            }
+        }
+        Widget::Map => {
+            let prefix = format!("{}.", field.key);
+            let entries: Vec<(String, String)> = editor
+                .values()
+                .iter()
+                .filter_map(|(key, value)| {
+                    Some((key.strip_prefix(&prefix)?.to_string(), display(value)))
+                })
+                .collect();
+            ui.vertical(|ui| {
+                for (name, value) in entries {
+                    let key = format!("{prefix}{name}");
+                    ui.horizontal(|ui| {
+                        ui.label(name.as_str());
+                        let mut text = value;
+                        if ui.text_edit_singleline(&mut text).changed() {
+                            editor.set(&key, toml::Value::String(text));
+                        }
+                        if ui.small_button("Remove").clicked() {
+                            editor.unset(&key);
+                        }
+                    });
+                }
+                let draft = drafts.entry(field.key.clone()).or_default();
+                let add = ui.add(egui::TextEdit::singleline(draft).hint_text("name = value"));
+                if add.lost_focus() && ui.input(|input| input.key_pressed(egui::Key::Enter)) {
+                    match draft.split_once('=') {
+                        Some((name, value)) if !name.trim().is_empty() => {
+                            let value = toml::Value::String(value.trim().to_string());
+                            editor.set(&format!("{prefix}{}", name.trim()), value);
+                            draft.clear();
+                        }
+                        _ => rejected = Some(format!("{}: enter name = value", field.label)),
+                    }
+                }
+            });
+        }
+        Widget::Number | Widget::Text | Widget::Password | Widget::Path | Widget::List => {
+            let draft = drafts
+                .entry(field.key.clone())
+                .or_insert_with(|| editor.get(&field.key).map(display).unwrap_or_default());
+            let hint = field
+                .default
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default();
+            let edit = egui::TextEdit::singleline(draft)
+                .password(field.widget == Widget::Password)
+                .hint_text(hint);
+            if ui.add(edit).changed() {
+                match parse(field, draft) {
+                    Ok(Some(value)) => editor.set(&field.key, value),
+                    Ok(None) => editor.unset(&field.key),
+                    Err(err) => rejected = Some(format!("{}: {err}", field.label)),
+                }
+            }
+        }
+    }
+    ui.end_row();
+    rejected
+}
+
+fn number(value: &toml::Value) -> Option<f64> {
+    match value {
+        toml::Value::Integer(integer) => Some(*integer as f64),
+        toml::Value::Float(float) => Some(*float),
+        _ => None,
+    }
+}
+
+/// A value as typed into a text field; lists are comma-separated.
+fn display(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(text) => text.clone(),
+        toml::Value::Array(items) => items.iter().map(display).collect::<Vec<_>>().join(", "),
+        other => other.to_string(),
+    }
+}
+
+/// The value typed into a text field; empty text unsets the key, so its
+/// default applies.
+fn parse(field: &UiField, text: &str) -> Result<Option<toml::Value>, &'static str> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Ok(None);
+    }
+    let value = match field.value_type.as_str() {
+        "integer" => toml::Value::Integer(text.parse().map_err(|_| "expected a whole number")?),
+        "number" => toml::Value::Float(text.parse().map_err(|_| "expected a number")?),
+        "array" => toml::Value::Array(
+            text.split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(|item| toml::Value::String(item.to_string()))
+                .collect(),
+        ),
+        _ => toml::Value::String(text.to_string()),
+    };
+    Ok(Some(value))
+}
+
+/// Task names, asked from rust-cli the way shell completion does.
+fn list_tasks(cli: &Path) -> Vec<String> {
+    Command::new(cli)
+        .env(format!("{}_COMPLETE", env_prefix()), "tasks")
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| {
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// A task started from the tasks tab.
+struct Run {
+    task: String,
+    /// What rust-cli printed, once it has exited.
+    output: Option<String>,
+    receiver: Receiver<String>,
+}
+
+impl Run {
+    /// Run `task` on a worker thread that repaints `ctx` when it finishes.
+    fn start(cli: &Path, task: String, ctx: egui::Context) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let cli = cli.to_path_buf();
+        let name = task.clone();
+        thread::spawn(move || {
+            let output = run_task(&cli, &name).unwrap_or_else(|err| format!("{err:#}"));
+            let _ = sender.send(output);
+            ctx.request_repaint();
+        });
+        Self {
+            task,
+            output: None,
+            receiver,
+        }
+    }
+
+    fn poll(&mut self) {
+        if self.output.is_none()
+            && let Ok(output) = self.receiver.try_recv()
+        {
+            self.output = Some(output);
+        }
+    }
+}
+
+/// Run `task` through rust-cli and return everything it printed.
+fn run_task(cli: &Path, task: &str) -> Result<String> {
+    let output = Command::new(cli)
+        .args(["run", task, "--output", "json"])
+        .output()
+        .with_context(|| format!("starting {}", cli.display()))?;
+    let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+    text.push_str(&String::from_utf8_lossy(&output.stderr));
+    if !output.status.success() {
+        let _ = writeln!(text, "{} exited with {}", cli.display(), output.status);
+    }
+    Ok(text)
+}
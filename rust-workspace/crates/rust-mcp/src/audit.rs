@@ -0,0 +1,119 @@
+//! Every tool call in the audit log (see [`rust_core::audit`]), written
+//! when the call ends, refused calls included: the tool, its arguments
+//! with secrets redacted, the client's token name and session over HTTP,
+//! the duration, and the outcome. A call that did not succeed after its
+//! request was cancelled is recorded as cancelled.
+//!
+//! A call whose record cannot be written still goes through, with a
+//! warning. The log is pruned to `[mcp.audit]` with the first call and
+//! then at most once every [`PRUNE_EVERY`].
+
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use axum::http::request::Parts;
+use rmcp::ErrorData as McpError;
+use rmcp::RoleServer;
+use rmcp::model::{CallToolRequestParams, CallToolResult};
+use rmcp::service::RequestContext;
+use serde_json::Value;
+use tokio_util::sync::CancellationToken;
+
+use rust_core::audit::{self, AuditOutcome, AuditRecord};
+use rust_core::{AppConfig, AppPaths};
+
+use crate::auth::Client;
+
+/// How often a long-running server prunes the log.
+const PRUNE_EVERY: Duration = Duration::from_secs(60 * 60);
+
+/// When the log was last pruned, shared by every session.
+#[derive(Clone, Default)]
+pub struct Audit {
+    pruned: Arc<Mutex<Option<Instant>>>,
+}
+
+/// A call being audited, from [`Audit::start`].
+pub struct Call {
+    started: Instant,
+    record: AuditRecord,
+    /// The request's token, tripped if the call is cancelled.
+    cancelled: CancellationToken,
+}
+
+impl Audit {
+    /// Start auditing the call `request` makes; `None` when
+    /// `mcp.audit.record` is off.
+    pub fn start(
+        request: &CallToolRequestParams,
+        context: &RequestContext<RoleServer>,
+        config: &AppConfig,
+    ) -> Option<Call> {
+        if !config.mcp.audit.record {
+            return None;
+        }
+        let mut arguments = request
+            .arguments
+            .clone()
+            .map_or_else(|| Value::Object(serde_json::Map::new()), Value::Object);
+        audit::redact_arguments(&mut arguments, &config.security.redact);
+        let session = context.extensions.get::<Parts>().and_then(|parts| {
+            let id = parts.headers.get("mcp-session-id")?;
+            Some(id.to_str().ok()?.to_string())
+        });
+        Some(Call {
+            started: Instant::now(),
+            cancelled: context.ct.clone(),
+            record: AuditRecord {
+                started_at: unix_now(),
+                tool: request.name.to_string(),
+                arguments,
+                client: Client::of(context),
+                session,
+                duration_ms: 0,
+                outcome: AuditOutcome::Ok,
+            },
+        })
+    }
+
+    /// Record `call` with how it ended, then prune the log if it is due.
+    pub fn finish(
+        &self,
+        mut call: Call,
+        result: &Result<CallToolResult, McpError>,
+        paths: &AppPaths,
+        config: &AppConfig,
+    ) {
+        call.record.duration_ms =
+            u64::try_from(call.started.elapsed().as_millis()).unwrap_or(u64::MAX);
+        call.record.outcome = match result {
+            Ok(result) if result.is_error != Some(true) => AuditOutcome::Ok,
+            _ if call.cancelled.is_cancelled() => AuditOutcome::Cancelled,
+            Ok(_) => AuditOutcome::Failed,
+            Err(err) => AuditOutcome::Error {
+                code: err.code.0,
+                message: err.message.to_string(),
+            },
+        };
+        if let Err(err) = audit::append(&paths.state_dir, &call.record) {
+            log::warn!("could not audit a {} call: {err}", call.record.tool);
+        }
+        let mut pruned = self.pruned.lock().unwrap_or_else(PoisonError::into_inner);
+        if pruned.is_some_and(|at| at.elapsed() < PRUNE_EVERY) {
+            return;
+        }
+        *pruned = Some(Instant::now());
+        drop(pruned);
+        match audit::prune(&paths.state_dir, &config.mcp.audit, unix_now()) {
+            Ok(0) => {}
+            Ok(dropped) => log::debug!("pruned {dropped} calls from the audit log"),
+            Err(err) => log::warn!("could not prune the audit log: {err}"),
+        }
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs())
+}
@@ -0,0 +1,162 @@
+//! Bearer tokens for the HTTP transport, from `[mcp.auth]`.
+//!
+//! Every request to the endpoint must carry one of the configured tokens
+//! as `Authorization: Bearer <token>`, checked without timing leaks. The
+//! token decides the request's [`Scope`], which the handlers read back out
+//! of the request context to hide and refuse the tools it does not allow,
+//! and its name, which the audit log records.
+//! Tokens are read when the server starts; changing them takes a restart.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use anyhow::{Result, bail};
+use axum::extract::{Request, State};
+use axum::http::request::Parts;
+use axum::http::{StatusCode, header};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use log::{debug, warn};
+use rmcp::RoleServer;
+use rmcp::service::RequestContext;
+
+use rust_core::{McpAuthConfig, config};
+
+/// The tools a request's client may use.
+#[derive(Debug, Clone)]
+pub enum Scope {
+    /// Every tool.
+    All,
+    /// Only the named tools.
+    Tools(Arc<[String]>),
+}
+
+impl Scope {
+    /// Whether the client may list and call `tool`.
+    pub fn allows(&self, tool: &str) -> bool {
+        match self {
+            Self::All => true,
+            Self::Tools(tools) => tools.iter().any(|allowed| allowed == tool),
+        }
+    }
+
+    /// The scope of the request behind `context`. Over stdio there is no
+    /// HTTP request and the client may use everything; an HTTP request the
+    /// middleware did not scope may use nothing.
+    pub fn of(context: &RequestContext<RoleServer>) -> Self {
+        context
+            .extensions
+            .get::<Parts>()
+            .map_or(Self::All, |parts| {
+                parts
+                    .extensions
+                    .get::<Self>()
+                    .cloned()
+                    .unwrap_or_else(|| Self::Tools(Arc::new([])))
+            })
+    }
+}
+
+/// The name of the token a request's client presented, for the audit log.
+#[derive(Debug, Clone)]
+pub struct Client(pub String);
+
+impl Client {
+    /// The client behind `context`; `None` over stdio and when no tokens
+    /// are configured.
+    pub fn of(context: &RequestContext<RoleServer>) -> Option<String> {
+        let parts = context.extensions.get::<Parts>()?;
+        parts
+            .extensions
+            .get::<Self>()
+            .map(|client| client.0.clone())
+    }
+}
+
+/// The configured tokens, each with its client's name and scope.
+#[derive(Debug, Clone, Default)]
+pub struct Tokens(Arc<[(String, String, Scope)]>);
+
+impl Tokens {
+    /// The tokens in `auth`, checked against `bind`: serving beyond this
+    /// machine takes at least one.
+    pub fn new(auth: &McpAuthConfig, bind: SocketAddr) -> Result<Self> {
+        let mut tokens = Vec::new();
+        if let Some(token) = &auth.token {
+            tokens.push(("mcp.auth.token".to_string(), token.clone(), Scope::All));
+        }
+        for (name, client) in &auth.clients {
+            let scope = client
+                .tools
+                .as_ref()
+                .map_or(Scope::All, |tools| Scope::Tools(tools.clone().into()));
+            tokens.push((name.clone(), client.token.clone(), scope));
+        }
+        if tokens.is_empty() {
+            if !bind.ip().is_loopback() {
+                bail!(
+                    "refusing to serve on {bind} without a token: set mcp.auth.token \
+                     (or RUST_WORKSPACE__MCP__AUTH__TOKEN), or bind a loopback address"
+                );
+            }
+            warn!("no mcp.auth token is set: every local client may connect and use every tool");
+        }
+        Ok(Self(tokens.into()))
+    }
+
+    /// The name and scope of the client `presented` belongs to. Every token
+    /// is compared in full, so the time taken does not tell which one, or
+    /// how much of it, a guess got right.
+    fn find(&self, presented: &str) -> Option<(&str, &Scope)> {
+        self.0.iter().fold(None, |found, (name, token, scope)| {
+            if config::token_matches(presented, token) {
+                Some((name.as_str(), scope))
+            } else {
+                found
+            }
+        })
+    }
+}
+
+/// Let requests with a configured token through, scoped to its client;
+/// refuse the rest with 401. Without tokens every request may use
+/// everything.
+pub async fn authenticate(
+    State(tokens): State<Tokens>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    if tokens.0.is_empty() {
+        request.extensions_mut().insert(Scope::All);
+        return next.run(request).await;
+    }
+    let presented = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    let Some((name, scope)) = presented.and_then(|token| tokens.find(token)) else {
+        let why = if presented.is_some() {
+            "an unknown"
+        } else {
+            "no"
+        };
+        warn!("refusing an MCP request with {why} bearer token");
+        // The body is left unread, so the server drops the connection after
+        // answering; saying so keeps the client from sending its next request
+        // down it.
+        return (
+            StatusCode::UNAUTHORIZED,
+            [
+                (header::WWW_AUTHENTICATE, "Bearer"),
+                (header::CONNECTION, "close"),
+            ],
+            "pass a token from [mcp.auth] as Authorization: Bearer <token>\n",
+        )
+            .into_response();
+    };
+    debug!("MCP request from client {name}");
+    request.extensions_mut().insert(scope.clone());
+    request.extensions_mut().insert(Client(name.to_string()));
+    next.run(request).await
+}
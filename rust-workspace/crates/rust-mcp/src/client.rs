@@ -0,0 +1,418 @@
+//! A client for any MCP server (the `client` feature), behind `rust-cli mcp`.
+//!
+//! [`Connection::open`] starts a stdio server as a child process, or
+//! reaches a streamable HTTP one by URL, and completes the handshake; its
+//! methods then list tools, call one, or read a resource from blocking
+//! code. HTTP requests go through `ureq`, as the CLI's others do, and the
+//! event stream answering each one is read on a thread of its own. The
+//! client does not open the standalone GET stream, so notifications the
+//! server sends outside a request are not received.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fmt;
+use std::io::{BufRead, BufReader};
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context as _, Result, anyhow};
+use axum::http::{HeaderName, HeaderValue, StatusCode};
+use rmcp::ServiceExt as _;
+use rmcp::model::{
+    CallToolRequestParams, CallToolResult, ClientJsonRpcMessage, ReadResourceRequestParams,
+    ReadResourceResult, ServerJsonRpcMessage, Tool,
+};
+use rmcp::service::{RoleClient, RunningService, ServiceError};
+use rmcp::transport::StreamableHttpClientTransport;
+use rmcp::transport::streamable_http_client::{
+    SseError, StreamableHttpClient, StreamableHttpClientTransportConfig, StreamableHttpError,
+    StreamableHttpPostResponse,
+};
+use serde_json::{Map, Value};
+use sse_stream::Sse;
+use tokio::process::{Child, Command};
+use tokio::runtime::Runtime;
+use tokio::sync::mpsc;
+
+/// How long a stdio server may take to exit once its stdin closes.
+const EXIT_WAIT: Duration = Duration::from_secs(5);
+
+/// How long to wait for an HTTP server to accept the connection.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Header carrying the session, as the transport names it.
+const SESSION_HEADER: &str = "Mcp-Session-Id";
+
+/// Where the server is.
+#[derive(Debug, Clone)]
+pub enum Server {
+    /// A command that speaks MCP on its stdin and stdout; its stderr is
+    /// this process's.
+    Stdio {
+        /// Program to run.
+        program: OsString,
+        /// Its arguments.
+        args: Vec<OsString>,
+    },
+    /// A streamable HTTP endpoint, e.g. `http://127.0.0.1:3001/mcp`.
+    Http {
+        /// URL of the endpoint.
+        url: String,
+        /// Bearer token to present, for a server with `[mcp.auth]` tokens.
+        token: Option<String>,
+    },
+}
+
+/// A session with a server, from [`Connection::open`] until
+/// [`Connection::close`].
+pub struct Connection {
+    runtime: Runtime,
+    service: RunningService<RoleClient, ()>,
+    child: Option<Child>,
+}
+
+impl fmt::Debug for Connection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Connection")
+            .field("server", &self.service.peer_info())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Connection {
+    /// Reach `server` and complete the handshake.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the command cannot be started, the endpoint
+    /// cannot be reached, or the handshake fails.
+    pub fn open(server: &Server) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .context("starting the async runtime")?;
+        let (service, child) = runtime.block_on(async {
+            match server {
+                Server::Stdio { program, args } => {
+                    let mut child = Command::new(program)
+                        .args(args)
+                        .stdin(Stdio::piped())
+                        .stdout(Stdio::piped())
+                        .kill_on_drop(true)
+                        .spawn()
+                        .with_context(|| format!("starting {}", program.to_string_lossy()))?;
+                    let stdout = child.stdout.take().context("the server has no stdout")?;
+                    let stdin = child.stdin.take().context("the server has no stdin")?;
+                    let service = ().serve((stdout, stdin)).await.map_err(|err| {
+                        anyhow!(
+                            "MCP handshake with {} failed: {err}",
+                            program.to_string_lossy()
+                        )
+                    })?;
+                    Ok::<_, anyhow::Error>((service, Some(child)))
+                }
+                Server::Http { url, token } => {
+                    let mut config = StreamableHttpClientTransportConfig::with_uri(url.as_str());
+                    if let Some(token) = token {
+                        config = config.auth_header(token.as_str());
+                    }
+                    let transport = StreamableHttpClientTransport::with_client(Http::new(), config);
+                    let service = ()
+                        .serve(transport)
+                        .await
+                        .map_err(|err| anyhow!("MCP handshake with {url} failed: {err}"))?;
+                    Ok((service, None))
+                }
+            }
+        })?;
+        Ok(Self {
+            runtime,
+            service,
+            child,
+        })
+    }
+
+    /// Every tool the server lists, across all pages.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn list_tools(&self) -> Result<Vec<Tool>> {
+        self.runtime
+            .block_on(self.service.list_all_tools())
+            .map_err(|err| failed("tools/list", err))
+    }
+
+    /// Call tool `name` with `arguments`. A tool that ran and failed is
+    /// still `Ok`, with `is_error` set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server refuses the call or it fails with a
+    /// JSON-RPC error.
+    pub fn call_tool(&self, name: &str, arguments: Map<String, Value>) -> Result<CallToolResult> {
+        let request = CallToolRequestParams::new(name.to_string()).with_arguments(arguments);
+        self.runtime
+            .block_on(self.service.call_tool(request))
+            .map_err(|err| failed(&format!("calling {name}"), err))
+    }
+
+    /// The contents of the resource at `uri`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the read fails.
+    pub fn read_resource(&self, uri: &str) -> Result<ReadResourceResult> {
+        self.runtime
+            .block_on(
+                self.service
+                    .read_resource(ReadResourceRequestParams::new(uri)),
+            )
+            .map_err(|err| failed(&format!("reading {uri}"), err))
+    }
+
+    /// End the session: over HTTP, delete it; over stdio, close the
+    /// server's stdin and wait for it to exit, killing it if it takes
+    /// longer than [`EXIT_WAIT`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the session does not end cleanly.
+    pub fn close(self) -> Result<()> {
+        let Self {
+            runtime,
+            service,
+            child,
+        } = self;
+        let result = runtime.block_on(async {
+            service.cancel().await?;
+            if let Some(mut child) = child
+                && tokio::time::timeout(EXIT_WAIT, child.wait()).await.is_err()
+            {
+                log::warn!("the server did not exit after the session, killing it");
+                child.kill().await?;
+            }
+            Ok(())
+        });
+        // An event stream still open on its reader thread is abandoned.
+        runtime.shutdown_background();
+        result
+    }
+}
+
+/// `err` from `what`, with the JSON-RPC error's code when there is one.
+fn failed(what: &str, err: ServiceError) -> anyhow::Error {
+    match err {
+        ServiceError::McpError(error) => {
+            let data = error
+                .data
+                .map(|data| format!(" {data}"))
+                .unwrap_or_default();
+            anyhow!(
+                "{what} failed: {} (JSON-RPC error {}){data}",
+                error.message,
+                error.code.0
+            )
+        }
+        err => anyhow!("{what} failed: {err}"),
+    }
+}
+
+/// The streamable HTTP client side on `ureq`.
+#[derive(Clone)]
+struct Http {
+    agent: ureq::Agent,
+}
+
+type HttpError = StreamableHttpError<ureq::Error>;
+
+impl Http {
+    fn new() -> Self {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_connect(Some(CONNECT_TIMEOUT))
+            .user_agent(format!(
+                "{}/{}",
+                rust_core::APP_NAME,
+                env!("CARGO_PKG_VERSION")
+            ))
+            .build()
+            .into();
+        Self { agent }
+    }
+
+    /// The request headers every message carries.
+    fn headers(
+        session: Option<&str>,
+        token: Option<&str>,
+        custom: HashMap<HeaderName, HeaderValue>,
+    ) -> Vec<(String, String)> {
+        let mut headers = vec![(
+            "Accept".to_string(),
+            "application/json, text/event-stream".to_string(),
+        )];
+        if let Some(session) = session {
+            headers.push((SESSION_HEADER.to_string(), session.to_string()));
+        }
+        if let Some(token) = token {
+            headers.push(("Authorization".to_string(), format!("Bearer {token}")));
+        }
+        headers.extend(custom.into_iter().filter_map(|(name, value)| {
+            Some((name.as_str().to_string(), value.to_str().ok()?.to_string()))
+        }));
+        headers
+    }
+}
+
+impl StreamableHttpClient for Http {
+    type Error = ureq::Error;
+
+    async fn post_message(
+        &self,
+        uri: Arc<str>,
+        message: ClientJsonRpcMessage,
+        session_id: Option<Arc<str>>,
+        auth_header: Option<String>,
+        custom_headers: HashMap<HeaderName, HeaderValue>,
+    ) -> Result<StreamableHttpPostResponse, HttpError> {
+        let body = serde_json::to_string(&message)?;
+        let headers = Self::headers(
+            session_id.as_deref(),
+            auth_header.as_deref(),
+            custom_headers,
+        );
+        let agent = self.agent.clone();
+        let response = tokio::task::spawn_blocking(move || {
+            let mut request = agent
+                .post(uri.as_ref())
+                .header("Content-Type", "application/json");
+            for (name, value) in headers {
+                request = request.header(name, value);
+            }
+            request.send(body)
+        })
+        .await?
+        .map_err(StreamableHttpError::Client)?;
+        let status = response.status();
+        if matches!(status, StatusCode::ACCEPTED | StatusCode::NO_CONTENT) {
+            return Ok(StreamableHttpPostResponse::Accepted);
+        }
+        if status == StatusCode::NOT_FOUND && session_id.is_some() {
+            return Err(StreamableHttpError::SessionExpired);
+        }
+        let session = response
+            .headers()
+            .get(SESSION_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let content_type = response
+            .headers()
+            .get("content-type")
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let mut body = response.into_body();
+        if !status.is_success() {
+            let text = tokio::task::spawn_blocking(move || body.read_to_string())
+                .await?
+                .unwrap_or_default();
+            return Err(StreamableHttpError::UnexpectedServerResponse(Cow::Owned(
+                format!("HTTP {status}: {}", text.trim()),
+            )));
+        }
+        match content_type.as_deref() {
+            Some(kind) if kind.starts_with("text/event-stream") => {
+                let (sender, receiver) = mpsc::unbounded_channel();
+                let reader = BufReader::new(body.into_reader());
+                std::thread::spawn(move || read_events(reader, &sender));
+                let events = futures_util::stream::unfold(receiver, |mut receiver| async move {
+                    let event = receiver.recv().await?;
+                    Some((event, receiver))
+                });
+                Ok(StreamableHttpPostResponse::Sse(Box::pin(events), session))
+            }
+            Some(kind) if kind.starts_with("application/json") => {
+                let text = tokio::task::spawn_blocking(move || body.read_to_string())
+                    .await?
+                    .map_err(StreamableHttpError::Client)?;
+                let message: ServerJsonRpcMessage = serde_json::from_str(&text)?;
+                Ok(StreamableHttpPostResponse::Json(message, session))
+            }
+            _ => Err(StreamableHttpError::UnexpectedContentType(content_type)),
+        }
+    }
+
+    async fn delete_session(
+        &self,
+        uri: Arc<str>,
+        session_id: Arc<str>,
+        auth_header: Option<String>,
+        custom_headers: HashMap<HeaderName, HeaderValue>,
+    ) -> Result<(), HttpError> {
+        let headers = Self::headers(Some(&session_id), auth_header.as_deref(), custom_headers);
+        let agent = self.agent.clone();
+        let response = tokio::task::spawn_blocking(move || {
+            let mut request = agent.delete(uri.as_ref());
+            for (name, value) in headers {
+                request = request.header(name, value);
+            }
+            request.call()
+        })
+        .await?
+        .map_err(StreamableHttpError::Client)?;
+        let status = response.status();
+        if status.is_success() || status == StatusCode::METHOD_NOT_ALLOWED {
+            Ok(())
+        } else {
+            Err(StreamableHttpError::UnexpectedServerResponse(Cow::Owned(
+                format!("HTTP {status} deleting the session"),
+            )))
+        }
+    }
+
+    async fn get_stream(
+        &self,
+        _uri: Arc<str>,
+        _session_id: Arc<str>,
+        _last_event_id: Option<String>,
+        _auth_header: Option<String>,
+        _custom_headers: HashMap<HeaderName, HeaderValue>,
+    ) -> Result<futures_util::stream::BoxStream<'static, Result<Sse, SseError>>, HttpError> {
+        Err(StreamableHttpError::ServerDoesNotSupportSse)
+    }
+}
+
+/// Send the events of the stream `body` as they arrive, until it ends or
+/// nobody is listening.
+fn read_events(body: impl BufRead, sender: &mpsc::UnboundedSender<Result<Sse, SseError>>) {
+    let mut event = Sse::default();
+    for line in body.lines() {
+        let Ok(line) = line else {
+            return;
+        };
+        if line.is_empty() {
+            let done = std::mem::take(&mut event);
+            if (done.data.is_some() || done.id.is_some()) && sender.send(Ok(done)).is_err() {
+                return;
+            }
+            continue;
+        }
+        let (field, value) = line.split_once(':').unwrap_or((&line, ""));
+        let value = value.strip_prefix(' ').unwrap_or(value);
+        match field {
+            "data" => match &mut event.data {
+                Some(data) => {
+                    data.push('\n');
+                    data.push_str(value);
+                }
+                None => event.data = Some(value.to_string()),
+            },
+            "event" => event.event = Some(value.to_string()),
+            "id" => event.id = Some(value.to_string()),
+            "retry" => event.retry = value.parse().ok(),
+            // Comments (keep-alives) and unknown fields.
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! The config tools' work: settings by dotted key, the keys
+//! `config show --format flat` lists, read from the effective config with
+//! secrets redacted; and changes to the config file through the editor
+//! `config import` uses, so a change is validated before it is written and
+//! the file keeps its comments; and what a reload changed.
+
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use rust_core::config::{self, REDACTED};
+use rust_core::editor::ConfigEditor;
+use rust_core::lock::InstanceLock;
+use rust_core::sync::SettingChange;
+use rust_core::{AppConfig, AppPaths, Override, flat};
+
+use crate::error::McpToolError;
+
+/// How long `config_set` and `run_task` wait for a CLI invocation holding
+/// the instance lock to finish.
+pub const LOCK_WAIT: Duration = Duration::from_secs(5);
+
+/// The value of `key`; for a section such as `runtime`, its settings by
+/// the rest of their keys. `None` when there is no such setting.
+pub fn get(config: &AppConfig, key: &str) -> Result<Option<Value>, McpToolError> {
+    let settings = flat::settings(&config::redacted(config)?)?;
+    if let Some(value) = settings.get(key) {
+        return Ok(Some(serde_json::to_value(value)?));
+    }
+    let prefix = format!("{key}.");
+    let mut section = Map::new();
+    for (name, value) in &settings {
+        if let Some(rest) = name.strip_prefix(&prefix) {
+            section.insert(rest.to_string(), serde_json::to_value(value)?);
+        }
+    }
+    Ok((!section.is_empty()).then_some(Value::Object(section)))
+}
+
+/// What `config_set` did.
+#[derive(Debug, Serialize)]
+pub struct Written {
+    config_file: String,
+    /// Empty when the file already had the value.
+    changes: Vec<SettingChange>,
+}
+
+/// Set `key` to `value` in the config file. `value` reads as after
+/// `--set KEY=`: a TOML value when it is one, else a string.
+pub fn set(paths: &AppPaths, key: &str, value: &str) -> Result<Written, McpToolError> {
+    let item: Override = format!("{key}={value}").parse()?;
+    // Or a client could let itself in further than its token does.
+    if item.key == "mcp" || item.key.starts_with("mcp.") {
+        return Err(McpToolError::forbidden(format_args!(
+            "{} cannot be set over MCP; edit the config file",
+            item.key
+        )));
+    }
+    let mut editor = ConfigEditor::open(&paths.config_file)?;
+    editor.set(&item.key, item.parsed());
+    let updated = editor.validate()?;
+    // Keys the config has no field for pass validation and do nothing.
+    if !flat::settings(&updated)?.contains_key(&item.key) {
+        return Err(McpToolError::invalid_params(format_args!(
+            "{} is not a setting",
+            item.key
+        )));
+    }
+    let _lock = InstanceLock::acquire(&paths.lock_file(), Some(LOCK_WAIT))?;
+    let mut changes = editor.save()?;
+    for change in &mut changes {
+        if config::is_redacted_key(&change.key, &updated.security.redact) {
+            for side in [&mut change.before, &mut change.after] {
+                if side.is_some() {
+                    *side = Some(REDACTED.into());
+                }
+            }
+        }
+    }
+    Ok(Written {
+        config_file: paths.config_file.display().to_string(),
+        changes,
+    })
+}
+
+/// What `reload_config` did.
+#[derive(Debug, Serialize)]
+pub struct Reloaded {
+    config_file: String,
+    /// The settings whose effective value changed, by dotted key; empty
+    /// when the reload changed nothing.
+    pub changed: Vec<String>,
+}
+
+impl Reloaded {
+    /// The keys of the settings that differ between `before` and `after`,
+    /// set in either.
+    pub fn between(
+        paths: &AppPaths,
+        before: &AppConfig,
+        after: &AppConfig,
+    ) -> Result<Self, McpToolError> {
+        let before = flat::settings(before)?;
+        let mut after = flat::settings(after)?;
+        let mut changed: Vec<String> = before
+            .into_iter()
+            .filter_map(|(key, value)| (after.remove(&key) != Some(value)).then_some(key))
+            .collect();
+        changed.extend(after.into_keys());
+        changed.sort_unstable();
+        Ok(Self {
+            config_file: paths.config_file.display().to_string(),
+            changed,
+        })
+    }
+}
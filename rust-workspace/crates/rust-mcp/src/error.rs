@@ -0,0 +1,121 @@
+//! Tool failures as JSON-RPC errors: the code says whether the client asked
+//! for something it cannot have or the server failed, and `data` carries the
+//! [`ErrorReport`] `rust-cli --json` prints for the same failure, so a
+//! client can tell a bad key from a held lock without reading the message.
+//!
+//! Tool code returns [`McpToolError`] (or an `anyhow::Error`, classified by
+//! its causes as the CLI's exit codes are) and `?` turns it into the
+//! [`McpError`] rmcp sends. A task that ran and failed is not a tool error:
+//! `run_task` reports it in its result.
+
+use std::fmt;
+use std::time::Duration;
+
+use rmcp::ErrorData as McpError;
+use rmcp::model::{CallToolResult, ErrorCode};
+use serde::Serialize;
+
+use rust_core::{CoreError, ErrorReport, ExitCode};
+
+/// The settings or the client's token do not allow the call. JSON-RPC
+/// leaves -32000 to -32099 to servers.
+pub const FORBIDDEN: ErrorCode = ErrorCode(-32003);
+
+/// The session made as many calls as `[mcp.rate_limit]` allows for now.
+pub const RATE_LIMITED: ErrorCode = ErrorCode(-32004);
+
+/// Why a tool call failed.
+#[derive(Debug)]
+pub enum McpToolError {
+    /// The arguments name no such thing or hold an unusable value: an
+    /// unknown setting or task, a value that does not parse or validate.
+    InvalidParams(anyhow::Error),
+    /// The server's settings or the client's token do not allow the call.
+    Forbidden(anyhow::Error),
+    /// The session is over its `[mcp.rate_limit]`; a call may be made
+    /// again after `retry_after`, which `data` carries in seconds.
+    RateLimited {
+        error: anyhow::Error,
+        retry_after: Duration,
+    },
+    /// The call was fine but failed on the server: I/O, a held lock,
+    /// serialization.
+    Internal(anyhow::Error),
+}
+
+impl McpToolError {
+    pub fn invalid_params(message: impl fmt::Display) -> Self {
+        Self::InvalidParams(anyhow::anyhow!("{message}"))
+    }
+
+    pub fn forbidden(message: impl fmt::Display) -> Self {
+        Self::Forbidden(anyhow::anyhow!("{message}"))
+    }
+
+    const fn code(&self) -> ErrorCode {
+        match self {
+            Self::InvalidParams(_) => ErrorCode::INVALID_PARAMS,
+            Self::Forbidden(_) => FORBIDDEN,
+            Self::RateLimited { .. } => RATE_LIMITED,
+            Self::Internal(_) => ErrorCode::INTERNAL_ERROR,
+        }
+    }
+
+    const fn error(&self) -> &anyhow::Error {
+        match self {
+            Self::InvalidParams(err)
+            | Self::Forbidden(err)
+            | Self::RateLimited { error: err, .. }
+            | Self::Internal(err) => err,
+        }
+    }
+}
+
+impl fmt::Display for McpToolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#}", self.error())
+    }
+}
+
+/// Config, usage, data, and missing-input failures are the arguments'
+/// fault; the rest the server's.
+impl From<anyhow::Error> for McpToolError {
+    fn from(err: anyhow::Error) -> Self {
+        match ExitCode::from_error(&err) {
+            ExitCode::Config | ExitCode::Usage | ExitCode::Data | ExitCode::NoInput => {
+                Self::InvalidParams(err)
+            }
+            _ => Self::Internal(err),
+        }
+    }
+}
+
+impl From<CoreError> for McpToolError {
+    fn from(err: CoreError) -> Self {
+        anyhow::Error::new(err).into()
+    }
+}
+
+impl From<serde_json::Error> for McpToolError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Internal(anyhow::Error::new(err).context("serializing the result"))
+    }
+}
+
+impl From<McpToolError> for McpError {
+    fn from(err: McpToolError) -> Self {
+        let mut data = serde_json::to_value(ErrorReport::from_error(err.error())).ok();
+        if let McpToolError::RateLimited { retry_after, .. } = &err
+            && let Some(serde_json::Value::Object(data)) = &mut data
+        {
+            data.insert("retry_after".into(), retry_after.as_secs().into());
+        }
+        Self::new(err.code(), err.error().to_string(), data)
+    }
+}
+
+/// A successful result carrying `value` as structured content.
+pub fn structured(value: impl Serialize) -> Result<CallToolResult, McpError> {
+    let value = serde_json::to_value(value).map_err(McpToolError::from)?;
+    Ok(CallToolResult::structured(value))
+}
@@ -0,0 +1,124 @@
+//! The streamable HTTP transport: the MCP endpoint at `/mcp`, one session
+//! per client, with server messages streamed back as server-sent events.
+//!
+//! Requests must carry a token from `[mcp.auth]` (see [`crate::auth`]).
+//! Each client's `initialize` opens a session; past `--max-sessions` open
+//! sessions, new clients get 503 until one ends (the client deletes it, or
+//! it sits idle for [`SESSION_IDLE`]). On SIGINT or SIGTERM new clients
+//! get 503 while the tool calls in flight drain (see [`crate::shutdown`]);
+//! then the open sessions' streams close and the server exits once the
+//! requests in flight are answered.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use axum::Router;
+use axum::extract::{Request, State};
+use axum::http::StatusCode;
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use log::{info, warn};
+use rmcp::transport::streamable_http_server::session::local::{LocalSessionManager, SessionConfig};
+use rmcp::transport::{StreamableHttpServerConfig, StreamableHttpService};
+use tokio_util::sync::CancellationToken;
+
+use crate::McpServer;
+use crate::auth::{self, Tokens};
+use crate::shutdown::{self, Drain, Signals};
+
+/// Path of the MCP endpoint.
+pub const ENDPOINT: &str = "/mcp";
+
+/// How long a session may go without a request before it is closed, so a
+/// client that went away without saying so does not hold a slot forever.
+const SESSION_IDLE: Duration = Duration::from_secs(30 * 60);
+
+/// Serve `server` on `bind` until SIGINT or SIGTERM, and its tool calls
+/// have drained.
+pub async fn serve(
+    server: McpServer,
+    signals: Signals,
+    bind: SocketAddr,
+    max_sessions: usize,
+) -> Result<()> {
+    let tokens = Tokens::new(&server.config().mcp.auth, bind)?;
+    let shutdown = CancellationToken::new();
+    let sessions = Arc::new(LocalSessionManager {
+        sessions: tokio::sync::RwLock::default(),
+        session_config: SessionConfig {
+            keep_alive: Some(SESSION_IDLE),
+            ..SessionConfig::default()
+        },
+    });
+    let drain = server.drain.clone();
+    let stop = shutdown.clone();
+    let draining = server.clone();
+    tokio::spawn(async move {
+        shutdown::wait(&draining, signals).await;
+        stop.cancel();
+    });
+    let service = StreamableHttpService::new(
+        move || Ok(server.for_session()),
+        Arc::clone(&sessions),
+        StreamableHttpServerConfig {
+            cancellation_token: shutdown.child_token(),
+            ..StreamableHttpServerConfig::default()
+        },
+    );
+    let app = Router::new()
+        .nest_service(ENDPOINT, service)
+        .layer(middleware::from_fn_with_state(
+            Limit {
+                sessions,
+                max: max_sessions,
+                drain,
+            },
+            limit_sessions,
+        ))
+        .layer(middleware::from_fn_with_state(tokens, auth::authenticate));
+
+    let listener = tokio::net::TcpListener::bind(bind)
+        .await
+        .with_context(|| format!("binding {bind}"))?;
+    info!(
+        "serving MCP over streamable HTTP at http://{}{ENDPOINT}",
+        listener.local_addr()?
+    );
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown.cancelled_owned())
+        .await
+        .context("serving MCP over HTTP")
+}
+
+#[derive(Clone)]
+struct Limit {
+    sessions: Arc<LocalSessionManager>,
+    max: usize,
+    drain: Drain,
+}
+
+/// Refuse requests that would open a session past the limit, or while
+/// shutting down. Requests in an existing session carry its
+/// `Mcp-Session-Id` and always pass.
+async fn limit_sessions(State(limit): State<Limit>, request: Request, next: Next) -> Response {
+    if !request.headers().contains_key("mcp-session-id") {
+        if limit.drain.is_draining() {
+            return (StatusCode::SERVICE_UNAVAILABLE, "shutting down").into_response();
+        }
+        let open = limit.sessions.sessions.read().await.len();
+        if open >= limit.max {
+            warn!("refusing a new MCP client: {open} sessions open");
+            return (
+                StatusCode::SERVICE_UNAVAILABLE,
+                format!(
+                    "too many clients: {open} sessions open, the limit is {}",
+                    limit.max
+                ),
+            )
+                .into_response();
+        }
+    }
+    next.run(request).await
+}
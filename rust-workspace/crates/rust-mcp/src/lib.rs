@@ -0,0 +1,596 @@
+//! MCP server for rust-workspace, as a library so that `rust-cli serve mcp`
+//! can host it in-process as well as the `rust-mcp` binary.
+//!
+//! It speaks over stdio by default, for a client that starts it; with
+//! `--transport http` it runs as a network service instead (see [`http`]).
+//! A host with a logger of its own passes records on to clients with
+//! [`logging::to_client`]. The `client` feature adds the other side,
+//! [`client::Connection`], which `rust-cli mcp` uses to talk to any server.
+
+mod audit;
+mod auth;
+#[cfg(feature = "client")]
+pub mod client;
+mod config;
+mod error;
+mod http;
+pub mod logging;
+mod progress;
+mod prompts;
+mod rate_limit;
+mod resources;
+mod run;
+mod shutdown;
+mod subscriptions;
+#[cfg(feature = "testing")]
+pub mod testing;
+
+use std::net::SocketAddr;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Instant;
+
+use anyhow::{Context as _, Result};
+use clap::{Args, ValueEnum};
+use rmcp::{
+    ErrorData as McpError, ServerHandler, ServiceExt,
+    handler::server::router::prompt::PromptRouter,
+    handler::server::tool::{ToolCallContext, ToolRouter},
+    handler::server::wrapper::Parameters,
+    model::{
+        CallToolRequestParams, CallToolResult, Content, GetPromptRequestParams, GetPromptResult,
+        ListPromptsResult, ListResourcesResult, ListToolsResult, LoggingLevel,
+        PaginatedRequestParams, ReadResourceRequestParams, ReadResourceResult, ServerCapabilities,
+        ServerInfo, SetLevelRequestParams, SubscribeRequestParams, Tool, UnsubscribeRequestParams,
+    },
+    prompt_handler,
+    schemars::JsonSchema,
+    serde::{Deserialize, Serialize},
+    service::{RequestContext, RoleServer},
+    tool, tool_router,
+    transport::io::stdio,
+};
+
+use rmcp::schemars;
+
+use rust_core::ops::{Env, Operation, ResolvePaths, ShowConfig, ShowConfigInput};
+use rust_core::{AppConfig, AppConfigExt as _, AppPaths, CancelToken, Ctx, McpConfig};
+
+use crate::audit::Audit;
+use crate::config::Reloaded;
+use crate::error::McpToolError;
+use crate::rate_limit::RateLimiter;
+use crate::shutdown::Drain;
+use crate::subscriptions::Subscriptions;
+
+/// How to serve, as `rust-mcp` and `rust-cli serve mcp` take it.
+#[derive(Debug, Clone, Copy, Args)]
+pub struct ServeArgs {
+    /// How clients reach the server
+    #[arg(long, value_enum, default_value_t = Transport::Stdio)]
+    pub transport: Transport,
+    /// Address to listen on with `--transport http`
+    #[arg(long, value_name = "ADDR", default_value = "127.0.0.1:3001")]
+    pub bind: SocketAddr,
+    /// Most clients connected at once with `--transport http`; more are refused with 503
+    #[arg(long, value_name = "N", default_value = "32")]
+    pub max_sessions: NonZeroUsize,
+}
+
+/// How clients reach the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Transport {
+    /// Newline-delimited JSON-RPC on stdin and stdout, for a client that starts the server
+    Stdio,
+    /// Streamable HTTP at /mcp on --bind, streaming over server-sent events (also accepted
+    /// as `sse`)
+    #[value(alias = "sse")]
+    Http,
+}
+
+/// Serve `config` as `args` says, until the client disconnects (stdio) or
+/// SIGINT or SIGTERM.
+///
+/// On a signal, tool calls in flight get `mcp.shutdown_grace` seconds to
+/// finish, and the logger is flushed on the way out.
+/// Tool calls' log records reach their clients up to `mcp.log_level`.
+///
+/// On Unix, SIGHUP reloads the config file as the `reload_config` tool
+/// does.
+///
+/// # Errors
+///
+/// Returns an error if the transport cannot be set up (a bind address in
+/// use, a non-loopback address without tokens) or fails.
+pub fn serve(paths: AppPaths, config: AppConfig, args: ServeArgs) -> Result<()> {
+    logging::forward_up_to(config.mcp.log_level);
+    let server = McpServer::new(paths, config);
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .context("starting the async runtime")?;
+    let result = runtime.block_on(async {
+        #[cfg(unix)]
+        reload_on_hangup(server.clone())?;
+        let signals = shutdown::Signals::listen()?;
+        if args.transport == Transport::Http {
+            return http::serve(server, signals, args.bind, args.max_sessions.get()).await;
+        }
+        let draining = server.clone();
+        let service = server
+            .serve(stdio())
+            .await
+            .map_err(|e| anyhow::anyhow!("MCP server error: {e}"))?;
+        let stop = service.cancellation_token();
+        tokio::spawn(async move {
+            shutdown::wait(&draining, signals).await;
+            stop.cancel();
+        });
+        service.waiting().await?;
+        Ok(())
+    });
+    // Without waiting for the thread still blocked reading stdin.
+    runtime.shutdown_background();
+    log::logger().flush();
+    result
+}
+
+/// Reload the config file each time the process gets SIGHUP, until it
+/// exits. The handler is in place once this returns.
+#[cfg(unix)]
+fn reload_on_hangup(server: McpServer) -> Result<()> {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    let mut hangups = signal(SignalKind::hangup()).context("listening for SIGHUP")?;
+    tokio::spawn(async move {
+        while hangups.recv().await.is_some() {
+            match server.reload().await {
+                Ok(reloaded) => log::info!(
+                    "reloaded the config on SIGHUP; {} settings changed",
+                    reloaded.changed.len()
+                ),
+                Err(err) => log::warn!("keeping the config; reloading it on SIGHUP failed: {err}"),
+            }
+        }
+    });
+    Ok(())
+}
+
+/// Parameters for the echo tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+struct EchoParams {
+    /// The message to echo back
+    message: String,
+}
+
+/// Parameters for the `config_get` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+struct ConfigGetParams {
+    /// Dotted setting key, e.g. `runtime.timeout`, or a section such as `runtime`
+    key: String,
+}
+
+/// Parameters for the `config_set` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+struct ConfigSetParams {
+    /// Dotted setting key, e.g. `runtime.timeout`
+    key: String,
+    /// New value, as after `--set KEY=`: a TOML value (`120`, `true`, `["a", "b"]`) or a bare string
+    value: String,
+}
+
+/// Parameters for the `run_task` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+struct RunTaskParams {
+    /// Task from the [tasks] config table; `default` when left out
+    task: Option<String>,
+    /// Profile to run under instead of the configured one
+    profile: Option<String>,
+    /// Time limit in seconds for tasks that do not set their own, instead of runtime.timeout
+    timeout: Option<u64>,
+}
+
+/// Whether the deployment `mcp` configures offers `tool`, per
+/// `mcp.enabled_tools` and `mcp.disabled_tools`.
+fn offers(mcp: &McpConfig, tool: &str) -> bool {
+    mcp.enabled_tools
+        .as_ref()
+        .is_none_or(|enabled| enabled.iter().any(|name| name == tool))
+        && !mcp.disabled_tools.iter().any(|name| name == tool)
+}
+
+/// Refuse the resource at `uri` unless the client's [`auth::Scope`]
+/// allows the tool that returns the same thing.
+fn resource_allowed(uri: &str, context: &RequestContext<RoleServer>) -> Result<(), McpError> {
+    let tool = resources::tool(uri)?;
+    if auth::Scope::of(context).allows(tool) {
+        Ok(())
+    } else {
+        Err(McpToolError::forbidden(format_args!(
+            "this client may not read {uri}: it needs the {tool} tool"
+        ))
+        .into())
+    }
+}
+
+#[derive(Clone)]
+struct McpServer {
+    paths: Arc<AppPaths>,
+    /// Reloaded after `config_set` writes the file, by `reload_config`,
+    /// and on SIGHUP.
+    config: Arc<RwLock<AppConfig>>,
+    subscriptions: Subscriptions,
+    /// Tool calls in flight, for shutdown to wait for.
+    drain: Drain,
+    audit: Audit,
+    /// This session's recent calls, for `[mcp.rate_limit]`.
+    rate_limit: RateLimiter,
+    /// The level this session's client asked for with `logging/setLevel`.
+    client_log_level: Arc<RwLock<Option<LoggingLevel>>>,
+    tool_router: ToolRouter<Self>,
+    prompt_router: PromptRouter<Self>,
+}
+
+impl McpServer {
+    fn new(paths: AppPaths, config: AppConfig) -> Self {
+        let server = Self {
+            paths: Arc::new(paths),
+            config: Arc::new(RwLock::new(config)),
+            client_log_level: Arc::default(),
+            subscriptions: Subscriptions::default(),
+            drain: Drain::default(),
+            audit: Audit::default(),
+            rate_limit: RateLimiter::default(),
+            tool_router: Self::tool_router(),
+            prompt_router: Self::prompt_router(),
+        };
+        server.check_tool_names(&server.config().mcp);
+        server
+    }
+
+    /// A server for another client, sharing the config but not the
+    /// client's settings, subscriptions, or rate limits.
+    fn for_session(&self) -> Self {
+        Self {
+            client_log_level: Arc::default(),
+            subscriptions: self.subscriptions.for_session(),
+            rate_limit: RateLimiter::default(),
+            ..self.clone()
+        }
+    }
+
+    /// Warn of names in `mcp.enabled_tools`, `mcp.disabled_tools`, and
+    /// `mcp.rate_limit.tools` that are not tools, which are likely typos.
+    fn check_tool_names(&self, mcp: &McpConfig) {
+        let listed = mcp
+            .enabled_tools
+            .iter()
+            .flatten()
+            .chain(&mcp.disabled_tools)
+            .chain(mcp.rate_limit.tools.keys());
+        for name in listed {
+            if !self.tool_router.has_route(name) {
+                log::warn!("mcp: there is no tool named {name}");
+            }
+        }
+    }
+
+    fn config(&self) -> AppConfig {
+        self.config
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Load the config file again and use it from now on, telling clients
+    /// subscribed to the config resources if it changed. On failure the
+    /// config in use stays.
+    async fn reload(&self) -> Result<Reloaded, McpToolError> {
+        let config = AppConfig::load(&self.paths, &Ctx::default()).map_err(|err| {
+            McpToolError::Internal(anyhow::Error::new(err).context("reloading config"))
+        })?;
+        let reloaded = Reloaded::between(&self.paths, &self.config(), &config)?;
+        self.check_tool_names(&config.mcp);
+        logging::forward_up_to(config.mcp.log_level);
+        *self.config.write().unwrap_or_else(PoisonError::into_inner) = config;
+        // The environment is as it was, so a change came from the file.
+        if !reloaded.changed.is_empty() {
+            for uri in [resources::CURRENT_CONFIG, resources::CONFIG_FILE] {
+                self.subscriptions.updated(uri).await;
+            }
+        }
+        Ok(reloaded)
+    }
+
+    /// Call a tool, unless the deployment or the client's scope does not
+    /// allow it or the server is shutting down.
+    async fn dispatch(
+        &self,
+        request: CallToolRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        if self.tool_router.has_route(&request.name) && !offers(&self.config().mcp, &request.name) {
+            return Err(McpToolError::forbidden(format_args!(
+                "the {} tool is turned off by mcp.enabled_tools or mcp.disabled_tools",
+                request.name
+            ))
+            .into());
+        }
+        if !auth::Scope::of(&context).allows(&request.name) {
+            return Err(McpToolError::forbidden(format_args!(
+                "this client may not use the {} tool",
+                request.name
+            ))
+            .into());
+        }
+        self.rate_limit
+            .check(&request.name, &self.config().mcp.rate_limit, Instant::now())?;
+        let peer = context.peer.clone();
+        let ct = context.ct.clone();
+        let client_level = *self
+            .client_log_level
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        let call = self
+            .tool_router
+            .call(ToolCallContext::new(self, request, context));
+        let call = logging::forward(peer, self.config().mcp.log_level, client_level, call);
+        self.drain.call(ct, call).await?
+    }
+
+    /// Run a shared operation and return its output as structured content.
+    fn run<O: Operation>(
+        &self,
+        operation: &O,
+        input: O::Input,
+    ) -> Result<CallToolResult, McpError> {
+        let config = self.config();
+        let env = Env {
+            paths: &self.paths,
+            config: &config,
+            ctx: &Ctx::default(),
+        };
+        error::structured(operation.run(env, input).map_err(McpToolError::from)?)
+    }
+}
+
+#[tool_router]
+impl McpServer {
+    /// Get the current configuration profile
+    #[tool(description = "Returns the current configuration profile name")]
+    async fn get_profile(&self) -> Result<CallToolResult, McpError> {
+        Ok(CallToolResult::success(vec![Content::text(
+            self.config().profile,
+        )]))
+    }
+
+    /// Echo a message back
+    #[tool(description = "Echoes the provided message back")]
+    async fn echo(
+        &self,
+        Parameters(params): Parameters<EchoParams>,
+    ) -> Result<CallToolResult, McpError> {
+        Ok(CallToolResult::success(vec![Content::text(format!(
+            "Echo: {}",
+            params.message
+        ))]))
+    }
+
+    /// Get runtime configuration, as structured content (and its JSON text
+    /// for clients that only read text)
+    #[tool(description = "Returns the runtime configuration including parallelism and timeout")]
+    async fn get_runtime_config(&self) -> Result<CallToolResult, McpError> {
+        error::structured(self.config().runtime)
+    }
+
+    /// Show the effective configuration
+    #[tool(
+        description = "Returns the effective configuration (file, environment, and defaults) with secret values redacted"
+    )]
+    async fn config_show(&self) -> Result<CallToolResult, McpError> {
+        self.run(&ShowConfig, ShowConfigInput { redact: true })
+    }
+
+    /// Show the resolved config file and directories
+    #[tool(
+        description = "Returns the directory mode (user or system), the config file, and the data, state, and cache directories this server uses"
+    )]
+    async fn config_paths(&self) -> Result<CallToolResult, McpError> {
+        self.run(&ResolvePaths, ())
+    }
+
+    /// Get the config file's JSON schema
+    #[tool(
+        description = "Returns the JSON schema (draft-07) of the config file: every setting's type, default, allowed values, and description. Check a change against it before calling config_set"
+    )]
+    async fn get_config_schema(&self) -> Result<CallToolResult, McpError> {
+        error::structured(resources::config_schema().map_err(McpToolError::from)?)
+    }
+
+    /// Get one setting, or a section's settings, by dotted key
+    #[tool(
+        description = "Returns the value of a setting by dotted key (e.g. runtime.timeout), or a section's settings by the rest of their keys; secret values are redacted"
+    )]
+    async fn config_get(
+        &self,
+        Parameters(params): Parameters<ConfigGetParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let value = config::get(&self.config(), &params.key)?.ok_or_else(|| {
+            McpToolError::invalid_params(format_args!("{} is not a setting", params.key))
+        })?;
+        error::structured(serde_json::json!({ "key": params.key, "value": value }))
+    }
+
+    /// Set one setting in the config file, when `mcp.allow_config_writes`
+    /// allows it
+    #[tool(
+        description = "Sets a setting in the config file by dotted key, keeping the file's comments; the result is validated before it is written. Only allowed when the config sets mcp.allow_config_writes = true"
+    )]
+    async fn config_set(
+        &self,
+        Parameters(params): Parameters<ConfigSetParams>,
+    ) -> Result<CallToolResult, McpError> {
+        if !self.config().mcp.allow_config_writes {
+            return Err(McpToolError::forbidden(format_args!(
+                "config writes are off; set mcp.allow_config_writes = true in {} to allow them",
+                self.paths.config_file.display()
+            ))
+            .into());
+        }
+        let written = config::set(&self.paths, &params.key, &params.value)?;
+        self.reload().await?;
+        error::structured(written)
+    }
+
+    /// Load the config file again, for edits made since the server started
+    #[tool(
+        description = "Reloads the config file, so edits made to it since the server started take effect, and returns the keys of the settings whose effective value changed. Clients subscribed to config://current or config://file are notified when any did. Changes to mcp.auth and the transport take a restart"
+    )]
+    async fn reload_config(&self) -> Result<CallToolResult, McpError> {
+        error::structured(self.reload().await?)
+    }
+
+    /// Run a task and the tasks it depends on, as `rust-cli run` does,
+    /// reporting each task's start and outcome as progress
+    #[tool(
+        description = "Runs a task from the [tasks] config table and the tasks it depends on, as `rust-cli run TASK` does, and returns each task's outcome. Sends a progress notification as each task starts and ends when the request has a progress token; cancelling the request kills the running tasks"
+    )]
+    async fn run_task(
+        &self,
+        Parameters(params): Parameters<RunTaskParams>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        let cancel = CancelToken::default();
+        let (progress, forward) = progress::for_request(context, cancel.clone());
+        let paths = Arc::clone(&self.paths);
+        let config = self.config();
+        let task = params.task.unwrap_or_else(|| "default".to_string());
+        let sink = logging::current();
+        let running = tokio::task::spawn_blocking(move || {
+            logging::on_thread(sink, || {
+                run::run(
+                    &paths,
+                    &config,
+                    &task,
+                    params.profile,
+                    params.timeout,
+                    cancel,
+                    progress,
+                )
+            })
+        });
+        let (report, ()) = tokio::join!(running, forward);
+        let report = report.map_err(|err| {
+            McpToolError::Internal(anyhow::Error::new(err).context("running the task"))
+        })??;
+        if report.succeeded {
+            return error::structured(report);
+        }
+        // The tasks ran; which did not succeed is the result, not an error.
+        let value = serde_json::to_value(report).map_err(McpToolError::from)?;
+        Ok(CallToolResult::structured_error(value))
+    }
+}
+
+#[prompt_handler]
+impl ServerHandler for McpServer {
+    fn get_info(&self) -> ServerInfo {
+        let mut info = ServerInfo::default();
+        info.instructions = Some("MCP server for rust-workspace template".to_string());
+        info.capabilities = ServerCapabilities::builder()
+            .enable_tools()
+            .enable_resources()
+            .enable_resources_subscribe()
+            .enable_prompts()
+            .enable_logging()
+            .build();
+        info
+    }
+
+    /// The tools the deployment offers and the client's [`auth::Scope`]
+    /// allows.
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, McpError> {
+        let scope = auth::Scope::of(&context);
+        let mcp = self.config().mcp;
+        let mut tools = self.tool_router.list_all();
+        tools.retain(|tool| offers(&mcp, &tool.name) && scope.allows(&tool.name));
+        Ok(ListToolsResult::with_all_items(tools))
+    }
+
+    /// Each call, refused ones included, is recorded in the audit log.
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        let config = self.config();
+        let audited = Audit::start(&request, &context, &config);
+        let result = self.dispatch(request, context).await;
+        if let Some(call) = audited {
+            self.audit.finish(call, &result, &self.paths, &config);
+        }
+        result
+    }
+
+    async fn set_level(
+        &self,
+        request: SetLevelRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<(), McpError> {
+        *self
+            .client_log_level
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Some(request.level);
+        Ok(())
+    }
+
+    fn get_tool(&self, name: &str) -> Option<Tool> {
+        self.tool_router.get(name).cloned()
+    }
+
+    /// The resources whose tool the client's [`auth::Scope`] allows.
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<ListResourcesResult, McpError> {
+        let scope = auth::Scope::of(&context);
+        let mut listed = resources::list(&self.paths);
+        listed
+            .retain(|resource| resources::tool(&resource.uri).is_ok_and(|tool| scope.allows(tool)));
+        Ok(ListResourcesResult::with_all_items(listed))
+    }
+
+    async fn read_resource(
+        &self,
+        request: ReadResourceRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<ReadResourceResult, McpError> {
+        resource_allowed(&request.uri, &context)?;
+        let contents = resources::read(&request.uri, &self.paths, &self.config())?;
+        Ok(ReadResourceResult::new(vec![contents]))
+    }
+
+    async fn subscribe(
+        &self,
+        request: SubscribeRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<(), McpError> {
+        resource_allowed(&request.uri, &context)?;
+        self.subscriptions.subscribe(request.uri, context.peer);
+        Ok(())
+    }
+
+    async fn unsubscribe(
+        &self,
+        request: UnsubscribeRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<(), McpError> {
+        self.subscriptions.unsubscribe(&request.uri);
+        Ok(())
+    }
+}
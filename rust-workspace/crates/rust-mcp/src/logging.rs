@@ -0,0 +1,175 @@
+//! Server log records sent to the client whose tool call produced them, as
+//! `notifications/message`.
+//!
+//! Records still go to stderr as `RUST_LOG` says. While a tool call runs,
+//! its records (from async code, or from a blocking thread the tool hands
+//! its [`Sink`] to) are also sent to the calling client, up to the more
+//! restrictive of `mcp.log_level` and the level the client asked for with
+//! `logging/setLevel`. Records from outside tool calls reach no client, and
+//! each client only sees its own calls' records.
+//!
+//! The `rust-mcp` binary installs a logger that does this with [`init`]; a
+//! host with a logger of its own, such as `rust-cli serve mcp`, has it call
+//! [`to_client`] with each record instead.
+
+use std::cell::RefCell;
+use std::future::Future;
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use rmcp::RoleServer;
+use rmcp::model::{LoggingLevel, LoggingMessageNotificationParam};
+use rmcp::service::Peer;
+use tokio::sync::mpsc::{self, UnboundedSender};
+
+use rust_core::LogLevel;
+
+tokio::task_local! {
+    static SINK: Sink;
+}
+
+thread_local! {
+    static BLOCKING_SINK: RefCell<Option<Sink>> = const { RefCell::new(None) };
+}
+
+/// Where the records of one tool call go.
+#[derive(Clone)]
+pub(crate) struct Sink {
+    level: LevelFilter,
+    sender: UnboundedSender<LoggingMessageNotificationParam>,
+}
+
+/// Log to stderr per `RUST_LOG`; clients get records once [`forward_up_to`]
+/// says how many.
+///
+/// # Errors
+///
+/// Returns an error if a logger is already installed.
+pub fn init() -> Result<(), log::SetLoggerError> {
+    let stderr = env_logger::Builder::from_default_env().build();
+    let max = stderr.filter();
+    log::set_boxed_logger(Box::new(Logger { stderr }))?;
+    log::set_max_level(max);
+    Ok(())
+}
+
+/// Let records up to `level` through for clients, from the config.
+pub fn forward_up_to(level: LogLevel) {
+    log::set_max_level(log::max_level().max(level_filter(level)));
+}
+
+/// Run `call` with its records sent to `peer`, up to `server` (the
+/// configured level) and `client` (the level it asked for, if it did).
+/// Returns once `call` is done and its records are sent.
+pub(crate) async fn forward<T>(
+    peer: Peer<RoleServer>,
+    server: LogLevel,
+    client: Option<LoggingLevel>,
+    call: impl Future<Output = T>,
+) -> T {
+    let (sender, mut records) = mpsc::unbounded_channel();
+    let server = level_filter(server);
+    let level = client.map_or(server, |client| server.min(client_filter(client)));
+    let send = async move {
+        while let Some(record) = records.recv().await {
+            if let Err(err) = peer.notify_logging_message(record).await {
+                // Outside the call's scope, so this goes to stderr only.
+                log::debug!("could not send a log record to the client: {err}");
+                break;
+            }
+        }
+    };
+    let (result, ()) = tokio::join!(SINK.scope(Sink { level, sender }, call), send);
+    result
+}
+
+/// The sink of the tool call running on this task or thread, to hand to
+/// another thread with [`on_thread`].
+pub(crate) fn current() -> Option<Sink> {
+    SINK.try_with(Clone::clone)
+        .ok()
+        .or_else(|| BLOCKING_SINK.with(|cell| cell.borrow().clone()))
+}
+
+/// Run `work` with its records on this thread going to `sink`.
+pub(crate) fn on_thread<T>(sink: Option<Sink>, work: impl FnOnce() -> T) -> T {
+    let previous = BLOCKING_SINK.with(|cell| cell.replace(sink));
+    let result = work();
+    BLOCKING_SINK.with(|cell| cell.replace(previous));
+    result
+}
+
+struct Logger {
+    stderr: env_logger::Logger,
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if self.stderr.matches(record) {
+            self.stderr.log(record);
+        }
+        to_client(record);
+    }
+
+    fn flush(&self) {
+        self.stderr.flush();
+    }
+}
+
+/// Send `record` to the client whose tool call logged it, if its level is
+/// one the client gets. Records logged outside a tool call go nowhere.
+pub fn to_client(record: &Record<'_>) {
+    let send = |sink: &Sink| {
+        if record.level() <= sink.level {
+            let message = LoggingMessageNotificationParam {
+                level: client_level(record.level()),
+                logger: Some(record.target().to_string()),
+                data: record.args().to_string().into(),
+            };
+            // The call is over once the receiver is gone.
+            let _ = sink.sender.send(message);
+        }
+    };
+    if SINK.try_with(|sink| send(sink)).is_err() {
+        BLOCKING_SINK.with(|cell| {
+            if let Some(sink) = cell.borrow().as_ref() {
+                send(sink);
+            }
+        });
+    }
+}
+
+const fn level_filter(level: LogLevel) -> LevelFilter {
+    match level {
+        LogLevel::Error => LevelFilter::Error,
+        LogLevel::Warn => LevelFilter::Warn,
+        LogLevel::Info => LevelFilter::Info,
+        LogLevel::Debug => LevelFilter::Debug,
+        LogLevel::Trace => LevelFilter::Trace,
+    }
+}
+
+/// The records a client asking for `level` gets. MCP's levels are syslog's:
+/// finer than `log`'s above `warning`, and without `trace`.
+const fn client_filter(level: LoggingLevel) -> LevelFilter {
+    match level {
+        LoggingLevel::Debug => LevelFilter::Trace,
+        LoggingLevel::Info | LoggingLevel::Notice => LevelFilter::Info,
+        LoggingLevel::Warning => LevelFilter::Warn,
+        LoggingLevel::Error => LevelFilter::Error,
+        // Nothing here logs above `error`.
+        LoggingLevel::Critical | LoggingLevel::Alert | LoggingLevel::Emergency => LevelFilter::Off,
+    }
+}
+
+const fn client_level(level: Level) -> LoggingLevel {
+    match level {
+        Level::Error => LoggingLevel::Error,
+        Level::Warn => LoggingLevel::Warning,
+        Level::Info => LoggingLevel::Info,
+        Level::Debug | Level::Trace => LoggingLevel::Debug,
+    }
+}
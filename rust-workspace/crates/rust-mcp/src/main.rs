@@ -1,46 +1,49 @@
-//! MCP server for rust-workspace.
+//! `rust-mcp`: the MCP server of this workspace (see the `rust_mcp`
+//! library) as a binary of its own.
 
 use std::path::PathBuf;
-use std::sync::Arc;
 
 use anyhow::Result;
 use clap::{Args, Parser};
-use rmcp::{
-    ErrorData as McpError, ServerHandler, ServiceExt,
-    handler::server::tool::ToolRouter,
-    handler::server::wrapper::Parameters,
-    model::{CallToolResult, Content, ServerCapabilities, ServerInfo},
-    schemars::JsonSchema,
-    serde::{Deserialize, Serialize},
-    tool, tool_handler, tool_router,
-    transport::io::stdio,
-};
 
-use rmcp::schemars;
-
-use rust_core::{AppConfig, AppPaths};
-
-fn main() -> anyhow::Result<()> {
-    try_main()
+use rust_core::{AppPaths, Ctx, ErrorCode, ExitCode, PathMode};
+use rust_mcp::{ServeArgs, logging};
+
+fn main() -> std::process::ExitCode {
+    let cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        Err(err) => {
+            let _ = err.print();
+            let code = if err.use_stderr() {
+                ExitCode::Usage
+            } else {
+                ExitCode::Success
+            };
+            return code.into();
+        }
+    };
+    match try_main(&cli) {
+        Ok(()) => ExitCode::Success.into(),
+        Err(err) => {
+            match ErrorCode::from_error(&err) {
+                Some(code) => eprintln!("Error[{code}]: {err:?}"),
+                None => eprintln!("Error: {err:?}"),
+            }
+            ExitCode::from_error(&err).into()
+        }
+    }
 }
 
-#[tokio::main]
-async fn try_main() -> Result<()> {
-    let cli = Cli::parse();
-    let paths = AppPaths::discover(cli.common.config.as_deref())?;
-    let config = AppConfig::load(&paths, false)?;
-
-    let server = McpServer::new(config);
-    let transport = stdio();
-
-    let service = server
-        .serve(transport)
-        .await
-        .map_err(|e| anyhow::anyhow!("MCP server error: {e}"))?;
+fn try_main(cli: &Cli) -> Result<()> {
+    logging::init()?;
 
-    service.waiting().await?;
-
-    Ok(())
+    let (paths, config) = AppPaths::load(
+        PathMode::detect(),
+        cli.common.config.as_deref(),
+        &Ctx::default(),
+        &[],
+    )?;
+    rust_mcp::serve(paths, config, cli.serve)
 }
 
 #[derive(Debug, Parser)]
@@ -48,6 +51,8 @@ async fn try_main() -> Result<()> {
 struct Cli {
     #[command(flatten)]
     common: CommonOpts,
+    #[command(flatten)]
+    serve: ServeArgs,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -56,66 +61,3 @@ struct CommonOpts {
     #[arg(long, value_name = "PATH")]
     config: Option<PathBuf>,
 }
-
-/// Parameters for the echo tool
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
-struct EchoParams {
-    /// The message to echo back
-    message: String,
-}
-
-#[derive(Clone)]
-struct McpServer {
-    config: Arc<AppConfig>,
-    tool_router: ToolRouter<Self>,
-}
-
-impl McpServer {
-    fn new(config: AppConfig) -> Self {
-        Self {
-            config: Arc::new(config),
-            tool_router: Self::tool_router(),
-        }
-    }
-}
-
-#[tool_router]
-impl McpServer {
-    /// Get the current configuration profile
-    #[tool(description = "Returns the current configuration profile name")]
-    async fn get_profile(&self) -> Result<CallToolResult, McpError> {
-        Ok(CallToolResult::success(vec![Content::text(
-            self.config.profile.clone(),
-        )]))
-    }
-
-    /// Echo a message back
-    #[tool(description = "Echoes the provided message back")]
-    async fn echo(
-        &self,
-        Parameters(params): Parameters<EchoParams>,
-    ) -> Result<CallToolResult, McpError> {
-        Ok(CallToolResult::success(vec![Content::text(format!(
-            "Echo: {}",
-            params.message
-        ))]))
-    }
-
-    /// Get runtime configuration
-    #[tool(description = "Returns the runtime configuration including parallelism and timeout")]
-    async fn get_runtime_config(&self) -> Result<CallToolResult, McpError> {
-        let json =
-            serde_json::to_string_pretty(&self.config.runtime).unwrap_or_else(|_| "{}".to_string());
-        Ok(CallToolResult::success(vec![Content::text(json)]))
-    }
-}
-
-#[tool_handler]
-impl ServerHandler for McpServer {
-    fn get_info(&self) -> ServerInfo {
-        let mut info = ServerInfo::default();
-        info.instructions = Some("MCP server for rust-workspace template".to_string());
-        info.capabilities = ServerCapabilities::builder().enable_tools().build();
-        info
-    }
-}
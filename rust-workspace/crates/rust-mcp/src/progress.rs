@@ -0,0 +1,156 @@
+//! MCP progress notifications behind `rust_core::Progress`, so a tool hands
+//! core operations the same kind of reporter rust-cli's bars implement.
+//!
+//! A request carries at most one progress token, and its notifications one
+//! number that only grows, so the tasks an operation starts are added up: a
+//! task with a total counts its steps, one without counts one step when it
+//! finishes. The total grows as tasks start. Each start, message, and finish
+//! is sent with the task's label. Requests without a progress token get no
+//! notifications.
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use rmcp::RoleServer;
+use rmcp::model::{ProgressNotificationParam, ProgressToken};
+use rmcp::service::RequestContext;
+use rust_core::{CancelToken, Progress, ProgressTask};
+use tokio::sync::mpsc::{self, UnboundedSender};
+
+/// Reports progress on one request.
+pub struct Notifier {
+    token: Option<ProgressToken>,
+    sender: UnboundedSender<ProgressNotificationParam>,
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    next_id: u64,
+    /// Steps of finished tasks.
+    finished: u64,
+    /// Steps done and total steps of each running task, by id.
+    running: BTreeMap<u64, (u64, u64)>,
+}
+
+impl State {
+    fn progress(&self) -> (u64, u64) {
+        self.running.values().fold(
+            (self.finished, self.finished),
+            |(done, total), (task_done, task_total)| (done + task_done, total + task_total),
+        )
+    }
+}
+
+/// The reporter for the request behind `context`, and the future that sends
+/// its notifications and trips `cancel` if the client cancels the request.
+/// Run the future alongside the work; it ends once the reporter is dropped.
+pub fn for_request(
+    context: RequestContext<RoleServer>,
+    cancel: CancelToken,
+) -> (Arc<Notifier>, impl Future<Output = ()> + Send) {
+    let (sender, mut updates) = mpsc::unbounded_channel();
+    let notifier = Arc::new(Notifier {
+        token: context.meta.get_progress_token(),
+        sender,
+        state: Mutex::default(),
+    });
+    let forward = async move {
+        loop {
+            tokio::select! {
+                () = context.ct.cancelled(), if !cancel.is_cancelled() => cancel.cancel(),
+                update = updates.recv() => {
+                    let Some(update) = update else { break };
+                    if let Err(err) = context.peer.notify_progress(update).await {
+                        log::debug!("could not send progress: {err}");
+                    }
+                }
+            }
+        }
+    };
+    (notifier, forward)
+}
+
+impl Notifier {
+    /// Apply a change to the counts and send the result with the message
+    /// the change returns. The lock is held while sending, so updates go
+    /// out in the order they were made.
+    fn update(&self, change: impl FnOnce(&mut State) -> String) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let message = change(&mut state);
+        let Some(token) = self.token.clone() else {
+            return;
+        };
+        let (done, total) = state.progress();
+        #[expect(clippy::cast_precision_loss, reason = "step counts are small")]
+        let update = ProgressNotificationParam::new(token, done as f64)
+            .with_total(total as f64)
+            .with_message(message);
+        // The request is over once the receiver is gone.
+        let _ = self.sender.send(update);
+        drop(state);
+    }
+}
+
+impl Progress for Notifier {
+    fn start(&self, label: &str, total: Option<u64>) -> Box<dyn ProgressTask + '_> {
+        let mut id = 0;
+        self.update(|state| {
+            id = state.next_id;
+            state.next_id += 1;
+            state.running.insert(id, (0, total.unwrap_or(1)));
+            format!("{label} started")
+        });
+        Box::new(Task {
+            notifier: self,
+            id,
+            label: label.to_string(),
+            failure: Mutex::new(None),
+        })
+    }
+}
+
+struct Task<'a> {
+    notifier: &'a Notifier,
+    id: u64,
+    label: String,
+    failure: Mutex<Option<String>>,
+}
+
+impl ProgressTask for Task<'_> {
+    fn advance(&self, steps: u64) {
+        self.notifier.update(|state| {
+            if let Some((done, total)) = state.running.get_mut(&self.id) {
+                *done = (*done + steps).min(*total);
+            }
+            self.label.clone()
+        });
+    }
+
+    fn set_message(&self, message: &str) {
+        self.notifier
+            .update(|_| format!("{}: {message}", self.label));
+    }
+
+    fn fail(&self, message: &str) {
+        *self.failure.lock().unwrap_or_else(PoisonError::into_inner) = Some(message.to_string());
+    }
+}
+
+impl Drop for Task<'_> {
+    fn drop(&mut self) {
+        let outcome = self
+            .failure
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take()
+            .unwrap_or_else(|| "finished".to_string());
+        self.notifier.update(|state| {
+            if let Some((_, total)) = state.running.remove(&self.id) {
+                state.finished += total;
+            }
+            format!("{} {outcome}", self.label)
+        });
+    }
+}
@@ -0,0 +1,114 @@
+//! Prompt templates: messages a client fills in and hands to its model,
+//! with the server's own state embedded so the model does not have to call
+//! tools to gather it first. A new prompt is another `#[prompt]` method in
+//! the block below.
+
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use rmcp::{
+    ErrorData as McpError,
+    handler::server::wrapper::Parameters,
+    model::{
+        AnnotateAble as _, PromptMessage, PromptMessageContent, PromptMessageRole,
+        RawEmbeddedResource,
+    },
+    prompt, prompt_router,
+    schemars::{self, JsonSchema},
+    serde::{Deserialize, Serialize},
+};
+
+use rust_core::LogLevel;
+use rust_core::logs::{self, LogFilter, LogRecord};
+
+use crate::McpServer;
+use crate::resources::{self, CURRENT_CONFIG};
+
+/// How far back `diagnose-config` looks for warnings and errors.
+const RECENT: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Most log records `diagnose-config` embeds, newest kept.
+const MAX_RECORDS: usize = 20;
+
+/// Arguments for the `diagnose-config` prompt
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DiagnoseConfigArgs {
+    /// What is going wrong, in the user's words
+    problem: Option<String>,
+}
+
+#[prompt_router(vis = "pub(crate)")]
+impl McpServer {
+    /// Diagnose the configuration from the effective config and the
+    /// warnings and errors logged in the last day
+    #[prompt(
+        name = "diagnose-config",
+        description = "Review the effective configuration (embedded, secrets redacted) against the warnings and errors logged in the last day, and suggest fixes as config changes"
+    )]
+    async fn diagnose_config(
+        &self,
+        Parameters(args): Parameters<DiagnoseConfigArgs>,
+    ) -> Result<Vec<PromptMessage>, McpError> {
+        let config = self.config();
+        let mut ask = String::from(
+            "Diagnose this rust-workspace configuration. Point out settings that look wrong, \
+             inconsistent, or risky, and give each fix as a dotted key and its new value.",
+        );
+        if let Some(problem) = args.problem.filter(|problem| !problem.trim().is_empty()) {
+            ask.push_str("\n\nThe problem: ");
+            ask.push_str(problem.trim());
+        }
+        let config_json = resources::read(CURRENT_CONFIG, &self.paths, &config)?;
+        Ok(vec![
+            PromptMessage::new_text(PromptMessageRole::User, ask),
+            PromptMessage::new(
+                PromptMessageRole::User,
+                PromptMessageContent::Resource {
+                    resource: RawEmbeddedResource::new(config_json).no_annotation(),
+                },
+            ),
+            PromptMessage::new_text(
+                PromptMessageRole::User,
+                recent_problems(config.logging.file.as_deref().map(Path::new)),
+            ),
+        ])
+    }
+}
+
+/// The warnings and errors in `log_file` from the last [`RECENT`], as text
+/// for the prompt.
+fn recent_problems(log_file: Option<&Path>) -> String {
+    let Some(log_file) = log_file else {
+        return "logging.file is not set, so there are no logged warnings or errors to go by."
+            .to_string();
+    };
+    let records = match logs::read(log_file) {
+        Ok(records) => records,
+        Err(err) => {
+            return format!(
+                "The log file {} could not be read: {err}",
+                log_file.display()
+            );
+        }
+    };
+    let mut filter = LogFilter::new(SystemTime::now().checked_sub(RECENT), Some(LogLevel::Warn));
+    let recent: Vec<LogRecord> = records
+        .into_iter()
+        .filter(|record| filter.keep(record))
+        .collect();
+    if recent.is_empty() {
+        return format!(
+            "Nothing was logged at warn level or above in the last day ({}).",
+            log_file.display()
+        );
+    }
+    let mut text = format!(
+        "Warnings and errors logged in the last day ({}):\n",
+        log_file.display()
+    );
+    for record in &recent[recent.len().saturating_sub(MAX_RECORDS)..] {
+        text.push_str(&record.to_string());
+        text.push('\n');
+    }
+    text
+}
@@ -0,0 +1,86 @@
+//! Per-session limits on tool calls (`[mcp.rate_limit]`), so one runaway
+//! agent cannot keep a networked server busy for everyone.
+//!
+//! Each session counts its calls over a sliding minute: calls to a tool in
+//! `mcp.rate_limit.tools` against that tool's own limit, the rest against
+//! `calls_per_minute` together. A call past its limit is refused with
+//! [`RATE_LIMITED`](crate::error::RATE_LIMITED), saying which limit and how
+//! long until a call is allowed again; refused calls are not counted. The
+//! limits are read at each call, so a reload changes them for sessions
+//! already open.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use rust_core::McpRateLimitConfig;
+
+use crate::error::McpToolError;
+
+/// The window the limits count calls over.
+const WINDOW: Duration = Duration::from_secs(60);
+
+/// When a session's counted calls were made, by the tool they count
+/// against (`None` for `calls_per_minute`), oldest first.
+type Calls = BTreeMap<Option<String>, VecDeque<Instant>>;
+
+/// One session's recent calls.
+#[derive(Clone, Default)]
+pub struct RateLimiter {
+    calls: Arc<Mutex<Calls>>,
+}
+
+impl RateLimiter {
+    /// Count a call to `tool` made at `now`, or refuse it if `limits` allow
+    /// no more for the time being.
+    pub fn check(
+        &self,
+        tool: &str,
+        limits: &McpRateLimitConfig,
+        now: Instant,
+    ) -> Result<(), McpToolError> {
+        let (bucket, limit, setting) = match limits.tools.get(tool) {
+            Some(&limit) => (
+                Some(tool.to_string()),
+                limit,
+                format!("mcp.rate_limit.tools.{tool}"),
+            ),
+            None => (
+                None,
+                limits.calls_per_minute,
+                "mcp.rate_limit.calls_per_minute".to_string(),
+            ),
+        };
+        if limit == 0 {
+            return Ok(());
+        }
+        let mut calls = self.calls.lock().unwrap_or_else(PoisonError::into_inner);
+        let made = calls.entry(bucket).or_default();
+        while made
+            .front()
+            .is_some_and(|&at| now.saturating_duration_since(at) >= WINDOW)
+        {
+            made.pop_front();
+        }
+        let full = made.len() >= usize::try_from(limit).unwrap_or(usize::MAX);
+        let oldest = made.front().copied().filter(|_| full);
+        if oldest.is_none() {
+            made.push_back(now);
+        }
+        drop(calls);
+        let Some(oldest) = oldest else {
+            return Ok(());
+        };
+        let wait = WINDOW.saturating_sub(now.saturating_duration_since(oldest));
+        // Whole seconds, rounded up, so a client that waits is let in.
+        let retry_after = Duration::from_secs(wait.as_secs() + u64::from(wait.subsec_nanos() > 0));
+        Err(McpToolError::RateLimited {
+            error: anyhow::anyhow!(
+                "rate limit reached: {limit} calls per minute ({setting}); \
+                 retry the {tool} call in {}s",
+                retry_after.as_secs()
+            ),
+            retry_after,
+        })
+    }
+}
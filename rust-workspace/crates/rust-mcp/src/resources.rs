@@ -0,0 +1,128 @@
+//! Resources a client can attach as context: the effective config (secrets
+//! redacted, as `config_show` returns it), the config file as written with
+//! the same secrets masked, the resolved paths (as `config_paths` returns them), and the config file's
+//! JSON schema (as `get_config_schema` returns it).
+//!
+//! Each resource holds what one tool returns, and a client may list, read,
+//! and subscribe to it only if its scope allows that tool (see [`tool`]).
+//! The config resources change when a reload changes a setting; the paths
+//! and the schema never do.
+
+use std::fs;
+use std::io;
+
+use rmcp::ErrorData as McpError;
+use rmcp::model::{AnnotateAble as _, RawResource, Resource, ResourceContents};
+use serde::Serialize;
+use serde_json::Value;
+
+use rust_core::ops::{Env, Operation as _, ResolvePaths, ShowConfig, ShowConfigInput};
+use rust_core::{AppConfig, AppPaths, Ctx, config};
+
+/// The effective config, as JSON.
+pub const CURRENT_CONFIG: &str = "config://current";
+/// The config file, as TOML, secrets redacted.
+pub const CONFIG_FILE: &str = "config://file";
+/// The resolved config, data, state, and cache paths, as JSON.
+pub const PATHS: &str = "paths://";
+/// The JSON schema of the config file.
+pub const CONFIG_SCHEMA: &str = "schema://config";
+
+const JSON: &str = "application/json";
+const TOML: &str = "application/toml";
+
+/// Every resource, for `resources/list`.
+pub fn list(paths: &AppPaths) -> Vec<Resource> {
+    vec![
+        RawResource::new(CURRENT_CONFIG, "config")
+            .with_title("Effective configuration")
+            .with_description(
+                "The configuration in effect (file, environment, and defaults), secrets redacted",
+            )
+            .with_mime_type(JSON)
+            .no_annotation(),
+        RawResource::new(CONFIG_FILE, "config-file")
+            .with_title("Config file")
+            .with_description(format!(
+                "The config file as written, {}, secrets redacted",
+                paths.config_file.display()
+            ))
+            .with_mime_type(TOML)
+            .no_annotation(),
+        RawResource::new(PATHS, "paths")
+            .with_title("Resolved paths")
+            .with_description("The config file and the data, state, and cache directories")
+            .with_mime_type(JSON)
+            .no_annotation(),
+        RawResource::new(CONFIG_SCHEMA, "config-schema")
+            .with_title("Config schema")
+            .with_description(
+                "JSON schema (draft-07) of the config file: every setting's type, default, and description",
+            )
+            .with_mime_type(JSON)
+            .no_annotation(),
+    ]
+}
+
+/// The contents of the resource at `uri`.
+pub fn read(uri: &str, paths: &AppPaths, config: &AppConfig) -> Result<ResourceContents, McpError> {
+    let env = Env {
+        paths,
+        config,
+        ctx: &Ctx::default(),
+    };
+    match uri {
+        CURRENT_CONFIG => json(
+            uri,
+            ShowConfig
+                .run(env, ShowConfigInput { redact: true })
+                .map_err(internal)?,
+        ),
+        CONFIG_FILE => match fs::read_to_string(&paths.config_file) {
+            Ok(text) => {
+                let text =
+                    config::redacted_text(&text, &paths.config_file, &config.security.redact)
+                        .map_err(internal)?;
+                Ok(ResourceContents::text(text, uri).with_mime_type(TOML))
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Err(McpError::resource_not_found(
+                format!("no config file at {}", paths.config_file.display()),
+                None,
+            )),
+            Err(err) => Err(internal(err)),
+        },
+        PATHS => json(uri, ResolvePaths.run(env, ()).map_err(internal)?),
+        CONFIG_SCHEMA => json(uri, config_schema().map_err(internal)?),
+        _ => Err(not_found(uri)),
+    }
+}
+
+/// The tool that returns what the resource at `uri` holds, which a
+/// client's scope must allow for it to see the resource.
+pub fn tool(uri: &str) -> Result<&'static str, McpError> {
+    match uri {
+        CURRENT_CONFIG | CONFIG_FILE => Ok("config_show"),
+        PATHS => Ok("config_paths"),
+        CONFIG_SCHEMA => Ok("get_config_schema"),
+        _ => Err(not_found(uri)),
+    }
+}
+
+/// The JSON schema of the config file, with the defaults it documents.
+pub fn config_schema() -> anyhow::Result<Value> {
+    let schema = rust_core::generate_schema(rust_core::APP_NAME)?;
+    Ok(serde_json::from_str(&schema)?)
+}
+
+fn not_found(uri: &str) -> McpError {
+    McpError::resource_not_found(format!("no resource {uri}"), None)
+}
+
+fn json(uri: &str, value: impl Serialize) -> Result<ResourceContents, McpError> {
+    let text = serde_json::to_string_pretty(&value).map_err(internal)?;
+    Ok(ResourceContents::text(text, uri).with_mime_type(JSON))
+}
+
+fn internal(err: impl std::fmt::Display) -> McpError {
+    McpError::internal_error(err.to_string(), None)
+}
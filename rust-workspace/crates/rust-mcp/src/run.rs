@@ -0,0 +1,130 @@
+//! The `run_task` tool's work: [`rust_core::ops::RunTask`], as `rust-cli
+//! run` does it, under the instance lock so it does not overlap a CLI
+//! invocation.
+//!
+//! `builtin` tasks run the `rust-cli` next to this binary with the same
+//! config file and directory mode. Tasks get no stdin: over stdio, stdin
+//! and stdout carry the protocol. What they write is logged line by line as
+//! it comes, at info with the logger `task.NAME.stdout` or
+//! `task.NAME.stderr`, so it reaches the calling client as
+//! `notifications/message` (see [`crate::logging`]) while the task runs
+//! rather than in one piece with the result. The invocation is recorded in
+//! the run history as `mcp run_task TASK`.
+
+use std::env;
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use anyhow::{Context as _, Result};
+use serde::Serialize;
+
+use rust_core::bus::Event;
+use rust_core::lock::InstanceLock;
+use rust_core::ops::{Env, Operation as _, RunReport, RunTask, RunTaskInput};
+use rust_core::task::OutputStream;
+use rust_core::{AppConfig, AppPaths, CancelToken, Ctx, PathMode, Progress};
+
+use crate::config::LOCK_WAIT;
+use crate::error::McpToolError;
+use crate::logging;
+
+/// Result of `run_task`.
+#[derive(Debug, Serialize)]
+pub struct RunTaskResult {
+    #[serde(flatten)]
+    run: RunReport,
+    /// Whether every task succeeded.
+    pub succeeded: bool,
+}
+
+/// Run `name` under `profile` (else the configured one), with `timeout`
+/// seconds for tasks that do not set their own (else `runtime.timeout`).
+/// Each task is reported to `progress` as it starts and ends; tripping
+/// `cancel` stops waiting for the lock, kills the running tasks, and starts
+/// no more.
+pub fn run(
+    paths: &AppPaths,
+    config: &AppConfig,
+    name: &str,
+    profile: Option<String>,
+    timeout: Option<u64>,
+    cancel: CancelToken,
+    progress: Arc<dyn Progress + Send + Sync>,
+) -> Result<RunTaskResult, McpToolError> {
+    let _lock = InstanceLock::acquire_cancellable(&paths.lock_file(), Some(LOCK_WAIT), &cancel)?;
+    let ctx = Ctx::default().with_cancel(cancel).with_progress(progress);
+    // Taken once the run is over: a process a task left running may still
+    // be writing, and the call must not wait for it.
+    let sink = Arc::new(Mutex::new(logging::current()));
+    let output = Arc::clone(&sink);
+    ctx.events().subscribe(move |event| {
+        if let Event::TaskOutput { task, stream, line } = event {
+            let stream = match stream {
+                OutputStream::Stdout => "stdout",
+                OutputStream::Stderr => "stderr",
+            };
+            let sink = output
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .clone();
+            logging::on_thread(sink, || {
+                log::info!(target: &format!("task.{task}.{stream}"), "{line}");
+            });
+        }
+    });
+    let mut args = vec![
+        "mcp".to_string(),
+        "run_task".to_string(),
+        name.to_string(),
+        format!(
+            "--profile={}",
+            profile.as_deref().unwrap_or(&config.profile)
+        ),
+    ];
+    args.extend(timeout.map(|timeout| format!("--timeout={timeout}")));
+    let input = RunTaskInput {
+        task: name.to_string(),
+        profile,
+        timeout,
+        binary: cli_binary()?,
+        globals: builtin_globals(paths),
+        null_stdin: true,
+        capture_output: true,
+        args,
+        ..RunTaskInput::default()
+    };
+    let env = Env {
+        paths,
+        config,
+        ctx: &ctx,
+    };
+    let run = RunTask.run(env, input);
+    sink.lock().unwrap_or_else(PoisonError::into_inner).take();
+    let run = run?;
+    Ok(RunTaskResult {
+        succeeded: run.succeeded(),
+        run,
+    })
+}
+
+/// The `rust-cli` binary `builtin` tasks run: the one installed or built
+/// next to this one.
+fn cli_binary() -> Result<PathBuf> {
+    let this = env::current_exe().context("locating this binary for builtin tasks")?;
+    Ok(this.with_file_name(format!("rust-cli{}", env::consts::EXE_SUFFIX)))
+}
+
+/// Global flags for builtin tasks: this server's config file and
+/// directories, without the lock the run already holds.
+fn builtin_globals(paths: &AppPaths) -> Vec<OsString> {
+    vec![
+        "--no-lock".into(),
+        "--config".into(),
+        paths.config_file.clone().into_os_string(),
+        match paths.mode {
+            PathMode::System => "--system".into(),
+            PathMode::User => "--user".into(),
+        },
+    ]
+}
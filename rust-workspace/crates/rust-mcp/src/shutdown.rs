@@ -0,0 +1,152 @@
+//! Graceful shutdown: on SIGINT or SIGTERM the server stops taking tool
+//! calls and gives those in flight `mcp.shutdown_grace` seconds to finish.
+//! Past that, or on a second signal, it cancels them, which kills the tasks
+//! `run_task` started, and waits for them to report before the transport
+//! closes. Calls made while draining fail as cancelled.
+//!
+//! Under `rust-cli --entrypoint`, whose signal watcher takes the signals
+//! first (see [`rust_core::shutdown`]), the first signal is seen through
+//! [`rust_core::shutdown::requested`], and the watcher exits on the second.
+
+use std::future::Future;
+use std::time::Duration;
+
+use anyhow::{Context as _, Result};
+use log::{info, warn};
+use tokio_util::sync::CancellationToken;
+use tokio_util::task::TaskTracker;
+
+use rust_core::CoreError;
+
+use crate::McpServer;
+use crate::error::McpToolError;
+
+/// How often to look for a signal `rust_core::shutdown` took.
+const POLL: Duration = Duration::from_millis(200);
+
+/// How long the transport stays open after the last call ends, for its
+/// answer to go out: a call is done before rmcp writes its answer.
+const FLUSH: Duration = Duration::from_millis(200);
+
+/// The tool calls in flight, shared by every session.
+#[derive(Clone, Default)]
+pub struct Drain {
+    calls: TaskTracker,
+    /// Cancelled once the calls have had their grace period.
+    expired: CancellationToken,
+}
+
+impl Drain {
+    /// Run a tool call, cancelling `ct` (its request's token) if the grace
+    /// period runs out before it is done. Fails without running it once the
+    /// server is shutting down.
+    pub async fn call<T>(
+        &self,
+        ct: CancellationToken,
+        call: impl Future<Output = T>,
+    ) -> Result<T, McpToolError> {
+        if self.is_draining() {
+            return Err(CoreError::Cancelled("the server is shutting down".to_string()).into());
+        }
+        let _running = self.calls.token();
+        tokio::pin!(call);
+        tokio::select! {
+            result = &mut call => return Ok(result),
+            () = self.expired.cancelled() => ct.cancel(),
+        }
+        Ok(call.await)
+    }
+
+    /// Whether the server has stopped taking calls.
+    pub fn is_draining(&self) -> bool {
+        self.calls.is_closed()
+    }
+
+    /// Stop taking calls and wait for those in flight: up to `grace`, or
+    /// until `cut_short` resolves, then cancel them and wait for them to
+    /// end and their answers to go out.
+    async fn drain(&self, grace: Duration, cut_short: impl Future<Output = ()>) {
+        self.calls.close();
+        let running = self.calls.len();
+        if running == 0 {
+            return;
+        }
+        info!(
+            "waiting up to {}s for {running} tool calls to finish",
+            grace.as_secs()
+        );
+        tokio::select! {
+            () = self.calls.wait() => {}
+            () = tokio::time::sleep(grace) => self.expire().await,
+            () = cut_short => self.expire().await,
+        }
+        tokio::time::sleep(FLUSH).await;
+    }
+
+    /// Cancel the calls still running and wait for them to end.
+    async fn expire(&self) {
+        warn!("cancelling {} tool calls still running", self.calls.len());
+        self.expired.cancel();
+        self.calls.wait().await;
+    }
+}
+
+/// SIGINT and SIGTERM, caught from when [`Signals::listen`] returns so a
+/// signal the moment serving starts does not kill the process.
+pub struct Signals {
+    #[cfg(unix)]
+    interrupt: tokio::signal::unix::Signal,
+    #[cfg(unix)]
+    terminate: tokio::signal::unix::Signal,
+}
+
+impl Signals {
+    /// Start catching the signals. Needs the runtime.
+    pub fn listen() -> Result<Self> {
+        #[cfg(unix)]
+        {
+            use tokio::signal::unix::{SignalKind, signal};
+
+            Ok(Self {
+                interrupt: signal(SignalKind::interrupt()).context("listening for SIGINT")?,
+                terminate: signal(SignalKind::terminate()).context("listening for SIGTERM")?,
+            })
+        }
+        #[cfg(not(unix))]
+        Ok(Self {})
+    }
+
+    /// Resolve on the next signal.
+    async fn next(&mut self) {
+        #[cfg(unix)]
+        tokio::select! {
+            _ = self.interrupt.recv() => {}
+            _ = self.terminate.recv() => {}
+        }
+        #[cfg(not(unix))]
+        if let Err(err) = tokio::signal::ctrl_c().await {
+            warn!("cannot listen for Ctrl-C: {err}");
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
+/// Wait for a signal, then drain `server`'s tool calls; a second signal
+/// cuts the grace period short. Returns once none are running, for the
+/// caller to close the transport.
+pub async fn wait(server: &McpServer, mut signals: Signals) {
+    tokio::select! {
+        () = signals.next() => {}
+        () = requested() => {}
+    }
+    info!("shutting down; no more tool calls");
+    let grace = Duration::from_secs(server.config().mcp.shutdown_grace);
+    server.drain.drain(grace, signals.next()).await;
+}
+
+/// Resolve once `rust_core::shutdown` has taken a signal.
+async fn requested() {
+    while rust_core::shutdown::requested().is_none() {
+        tokio::time::sleep(POLL).await;
+    }
+}
@@ -0,0 +1,67 @@
+//! Resource subscriptions: clients that sent `resources/subscribe` for a
+//! resource get `notifications/resources/updated` when it changes, until
+//! they unsubscribe or disconnect.
+//!
+//! Subscriptions are kept per session, in one table every session shares,
+//! so a change one client makes (or a reload on SIGHUP) reaches the others.
+//! A client whose notification cannot be sent has gone and is forgotten.
+
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use rmcp::RoleServer;
+use rmcp::model::ResourceUpdatedNotificationParam;
+use rmcp::service::Peer;
+
+/// Who to notify, by session and resource URI.
+type Peers = BTreeMap<(u64, String), Peer<RoleServer>>;
+
+/// The subscriptions of every session, seen from one of them.
+#[derive(Clone, Default)]
+pub struct Subscriptions {
+    session: u64,
+    sessions: Arc<AtomicU64>,
+    peers: Arc<Mutex<Peers>>,
+}
+
+impl Subscriptions {
+    /// The same table, seen from a new session.
+    pub fn for_session(&self) -> Self {
+        Self {
+            session: self.sessions.fetch_add(1, Ordering::Relaxed) + 1,
+            ..self.clone()
+        }
+    }
+
+    /// Notify `peer`, this session's client, when `uri` changes.
+    pub fn subscribe(&self, uri: String, peer: Peer<RoleServer>) {
+        self.lock().insert((self.session, uri), peer);
+    }
+
+    /// Stop notifying this session's client of changes to `uri`.
+    pub fn unsubscribe(&self, uri: &str) {
+        self.lock().remove(&(self.session, uri.to_string()));
+    }
+
+    /// Tell every client subscribed to `uri` that it changed.
+    pub async fn updated(&self, uri: &str) {
+        let subscribed: Vec<_> = self
+            .lock()
+            .iter()
+            .filter(|((_, subscribed), _)| subscribed == uri)
+            .map(|(key, peer)| (key.clone(), peer.clone()))
+            .collect();
+        for (key, peer) in subscribed {
+            let update = ResourceUpdatedNotificationParam::new(uri);
+            if let Err(err) = peer.notify_resource_updated(update).await {
+                log::debug!("dropping a subscription to {uri}: {err}");
+                self.lock().remove(&key);
+            }
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Peers> {
+        self.peers.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
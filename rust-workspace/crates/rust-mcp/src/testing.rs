@@ -0,0 +1,180 @@
+//! An in-process harness for testing the server (the `testing` feature).
+//!
+//! [`TestClient::start`] serves the server over an in-memory duplex pipe
+//! and hands back a typed client, so a test asserts on `Tool`s,
+//! `CallToolResult`s, and `ErrorData` without spawning a binary or parsing
+//! JSON-RPC lines. The client speaks the protocol as a real one does, with
+//! the stdio client's scope: every tool the config offers.
+//! `tests/mcp/client.rs` remains the way to test the binary and its flags
+//! end to end.
+
+use std::fmt;
+
+use anyhow::{Context as _, Result, anyhow, bail};
+use rmcp::ServiceExt as _;
+use rmcp::model::{
+    CallToolRequestParams, CallToolResult, ErrorData, ReadResourceRequestParams, ResourceContents,
+    Tool,
+};
+use rmcp::service::{RoleClient, RunningService, ServiceError};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tokio::io::duplex;
+use tokio::task::JoinHandle;
+
+use rust_core::{AppConfig, AppPaths};
+
+use crate::McpServer;
+
+/// Bytes either side of the pipe buffers before the writer waits.
+const PIPE_BUFFER: usize = 64 * 1024;
+
+/// A client with a completed handshake, talking to a server running on
+/// this runtime. Dropping it ends the session.
+pub struct TestClient {
+    client: RunningService<RoleClient, ()>,
+    server: JoinHandle<Result<()>>,
+}
+
+impl fmt::Debug for TestClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TestClient").finish_non_exhaustive()
+    }
+}
+
+impl TestClient {
+    /// Serve `config`, with `paths` for its files and directories, and
+    /// connect to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the handshake fails.
+    pub async fn start(paths: AppPaths, config: AppConfig) -> Result<Self> {
+        let (client_end, server_end) = duplex(PIPE_BUFFER);
+        let server = McpServer::new(paths, config);
+        let server = tokio::spawn(async move {
+            let service = server
+                .serve(server_end)
+                .await
+                .map_err(|err| anyhow!("MCP server error: {err}"))?;
+            service.waiting().await?;
+            Ok(())
+        });
+        let client =
+            ().serve(client_end)
+                .await
+                .map_err(|err| anyhow!("MCP handshake failed: {err}"))?;
+        Ok(Self { client, server })
+    }
+
+    /// The tools from `tools/list`, every page of it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn tools(&self) -> Result<Vec<Tool>> {
+        Ok(self.client.list_all_tools().await?)
+    }
+
+    /// The names of the tools from `tools/list`, sorted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn tool_names(&self) -> Result<Vec<String>> {
+        let mut names: Vec<String> = self
+            .tools()
+            .await?
+            .into_iter()
+            .map(|tool| tool.name.into_owned())
+            .collect();
+        names.sort_unstable();
+        Ok(names)
+    }
+
+    /// Call tool `name` with `arguments`, a JSON object, and return its
+    /// result, which may still have `is_error` set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `arguments` is not an object or the call fails
+    /// with a JSON-RPC error.
+    pub async fn call(&self, name: &str, arguments: Value) -> Result<CallToolResult> {
+        match self.exchange(name, arguments).await? {
+            Ok(result) => Ok(result),
+            Err(error) => bail!("{name} failed: {error:?}"),
+        }
+    }
+
+    /// Call tool `name` and return its structured content as `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Self::call`], and an error if the result is
+    /// an error result or has no structured content of that shape.
+    pub async fn call_as<T: DeserializeOwned>(&self, name: &str, arguments: Value) -> Result<T> {
+        let result = self.call(name, arguments).await?;
+        if result.is_error == Some(true) {
+            bail!("{name} returned an error result: {result:?}");
+        }
+        let content = result
+            .structured_content
+            .with_context(|| format!("{name}: no structured content"))?;
+        serde_json::from_value(content).with_context(|| format!("{name}: unexpected result"))
+    }
+
+    /// Call tool `name`, which should fail, and return the JSON-RPC error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the call succeeds or the session fails.
+    pub async fn call_error(&self, name: &str, arguments: Value) -> Result<ErrorData> {
+        match self.exchange(name, arguments).await? {
+            Ok(result) => bail!("{name} succeeded: {result:?}"),
+            Err(error) => Ok(error),
+        }
+    }
+
+    /// The first contents of the resource at `uri`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the read fails or has no contents.
+    pub async fn read(&self, uri: &str) -> Result<ResourceContents> {
+        let result = self
+            .client
+            .read_resource(ReadResourceRequestParams::new(uri))
+            .await?;
+        result
+            .contents
+            .into_iter()
+            .next()
+            .with_context(|| format!("{uri}: no contents"))
+    }
+
+    /// End the session and wait for the server to finish.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server failed.
+    pub async fn close(self) -> Result<()> {
+        self.client.cancel().await?;
+        self.server.await?
+    }
+
+    async fn exchange(
+        &self,
+        name: &str,
+        arguments: Value,
+    ) -> Result<Result<CallToolResult, ErrorData>> {
+        let Value::Object(arguments) = arguments else {
+            bail!("{name}: arguments must be a JSON object, not {arguments}");
+        };
+        let request = CallToolRequestParams::new(name.to_string()).with_arguments(arguments);
+        match self.client.call_tool(request).await {
+            Ok(result) => Ok(Ok(result)),
+            Err(ServiceError::McpError(error)) => Ok(Err(error)),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
@@ -0,0 +1,272 @@
+//! A small MCP client for testing the server end to end: it spawns the
+//! `rust-mcp` binary and speaks newline-delimited JSON-RPC to it over
+//! stdio, as an editor or agent would, so a test sees exactly what a real
+//! client gets.
+//!
+//! Only [`McpClient::spawn`] names the binary and its environment; copy
+//! this module next to another stdio MCP server's tests and change those
+//! lines to test it.
+
+use std::fs;
+use std::io::{BufRead as _, BufReader, Write as _};
+use std::path::{Path, PathBuf};
+use std::process::{Child, ChildStdin, Command, ExitStatus, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context as _, Result, bail};
+use serde_json::{Value, json};
+
+/// Protocol version the client asks for in the handshake.
+pub const PROTOCOL_VERSION: &str = "2025-06-18";
+
+/// How long a response may take before the test fails rather than hangs.
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A running server with a completed handshake. Dropping it kills the
+/// server.
+pub struct McpClient {
+    child: Child,
+    stdin: ChildStdin,
+    /// Lines the server wrote to stdout, read on a thread of their own so
+    /// a silent server cannot block the test past [`TIMEOUT`].
+    lines: Receiver<String>,
+    next_id: u64,
+    /// Notifications the server sent while the client waited for answers,
+    /// such as `notifications/progress`, oldest first.
+    pub notifications: Vec<Value>,
+    /// The server's answer to `initialize`: `protocolVersion`,
+    /// `capabilities`, `serverInfo`, and `instructions`.
+    pub server: Value,
+}
+
+impl McpClient {
+    /// Start the server with `config` as its config file and the other
+    /// directories next to it, then send `initialize` and
+    /// `notifications/initialized`.
+    pub fn spawn(config: &Path) -> Result<Self> {
+        let mut child = server(config)?
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .context("spawning rust-mcp")?;
+        let stdin = child.stdin.take().context("rust-mcp has no stdin")?;
+        let stdout = child.stdout.take().context("rust-mcp has no stdout")?;
+        let (sender, lines) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+        let mut client = Self {
+            child,
+            stdin,
+            lines,
+            next_id: 0,
+            notifications: Vec::new(),
+            server: Value::Null,
+        };
+        client.server = client.request(
+            "initialize",
+            &json!({
+                "protocolVersion": PROTOCOL_VERSION,
+                "capabilities": {},
+                "clientInfo": { "name": "rust-mcp-tests", "version": env!("CARGO_PKG_VERSION") },
+            }),
+        )?;
+        client.notify("notifications/initialized", &json!({}))?;
+        Ok(client)
+    }
+
+    /// Send a request and wait for its `result`; a JSON-RPC error fails.
+    pub fn request(&mut self, method: &str, params: &Value) -> Result<Value> {
+        match self.exchange(method, params)? {
+            Ok(result) => Ok(result),
+            Err(error) => bail!("{method} failed: {error}"),
+        }
+    }
+
+    /// Send a request that should fail and return its JSON-RPC `error`:
+    /// `code`, `message`, and `data`.
+    pub fn request_error(&mut self, method: &str, params: &Value) -> Result<Value> {
+        match self.exchange(method, params)? {
+            Ok(result) => bail!("{method} succeeded: {result}"),
+            Err(error) => Ok(error),
+        }
+    }
+
+    /// Send a request and wait for its `result` or `error`.
+    fn exchange(&mut self, method: &str, params: &Value) -> Result<Result<Value, Value>> {
+        let id = self.start(method, params)?;
+        self.answer(id)
+            .with_context(|| format!("no response to {method}"))
+    }
+
+    /// Send a request without waiting for its answer, and return its id for
+    /// [`Self::answer`].
+    pub fn start(&mut self, method: &str, params: &Value) -> Result<u64> {
+        self.next_id += 1;
+        let id = self.next_id;
+        self.send(&json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }))?;
+        Ok(id)
+    }
+
+    /// Wait for the `result` or `error` of request `id`.
+    pub fn answer(&mut self, id: u64) -> Result<Result<Value, Value>> {
+        let id = json!(id);
+        loop {
+            let line = self
+                .lines
+                .recv_timeout(TIMEOUT)
+                .context("no answer in time")?;
+            let message: Value =
+                serde_json::from_str(&line).with_context(|| format!("not JSON-RPC: {line}"))?;
+            // Requests and notifications from the server are not the answer.
+            if message.get("method").is_some() && message.get("id").is_none() {
+                self.notifications.push(message);
+                continue;
+            }
+            if message.get("method").is_some() || message.get("id") != Some(&id) {
+                continue;
+            }
+            if let Some(error) = message.get("error") {
+                return Ok(Err(error.clone()));
+            }
+            return message
+                .get("result")
+                .cloned()
+                .map(Ok)
+                .with_context(|| format!("response without a result: {line}"));
+        }
+    }
+
+    /// Wait for a notification the server sends unprompted, such as on a
+    /// signal, and return it.
+    pub fn wait_for(&mut self, method: &str) -> Result<Value> {
+        loop {
+            let line = self
+                .lines
+                .recv_timeout(TIMEOUT)
+                .with_context(|| format!("no {method}"))?;
+            let message: Value =
+                serde_json::from_str(&line).with_context(|| format!("not JSON-RPC: {line}"))?;
+            if message.get("method").is_none() || message.get("id").is_some() {
+                continue;
+            }
+            self.notifications.push(message.clone());
+            if message["method"] == method {
+                return Ok(message);
+            }
+        }
+    }
+
+    /// The server's process id, to signal it.
+    pub fn pid(&self) -> u32 {
+        self.child.id()
+    }
+
+    /// Wait for the server to exit, as it should once stdin closes or it
+    /// gets a shutdown signal.
+    pub fn wait_exit(&mut self) -> Result<ExitStatus> {
+        let deadline = Instant::now() + TIMEOUT;
+        loop {
+            if let Some(status) = self.child.try_wait()? {
+                return Ok(status);
+            }
+            if Instant::now() > deadline {
+                bail!("rust-mcp still running after {TIMEOUT:?}");
+            }
+            thread::sleep(Duration::from_millis(20));
+        }
+    }
+
+    /// Send a notification, which has no answer.
+    pub fn notify(&mut self, method: &str, params: &Value) -> Result<()> {
+        self.send(&json!({ "jsonrpc": "2.0", "method": method, "params": params }))
+    }
+
+    /// The tools from `tools/list`.
+    pub fn tools(&mut self) -> Result<Vec<Value>> {
+        let result = self.request("tools/list", &json!({}))?;
+        result
+            .get("tools")
+            .and_then(Value::as_array)
+            .cloned()
+            .with_context(|| format!("tools/list without tools: {result}"))
+    }
+
+    /// Call tool `name` with `arguments` and return its result.
+    pub fn call(&mut self, name: &str, arguments: &Value) -> Result<Value> {
+        self.request(
+            "tools/call",
+            &json!({ "name": name, "arguments": arguments }),
+        )
+    }
+
+    /// Call tool `name` with `arguments`, which should fail, and return the
+    /// JSON-RPC error.
+    pub fn call_error(&mut self, name: &str, arguments: &Value) -> Result<Value> {
+        self.request_error(
+            "tools/call",
+            &json!({ "name": name, "arguments": arguments }),
+        )
+    }
+
+    /// The first contents of the resource at `uri`, from `resources/read`.
+    pub fn read(&mut self, uri: &str) -> Result<Value> {
+        let result = self.request("resources/read", &json!({ "uri": uri }))?;
+        result
+            .pointer("/contents/0")
+            .cloned()
+            .with_context(|| format!("{uri}: no contents in {result}"))
+    }
+
+    fn send(&mut self, message: &Value) -> Result<()> {
+        writeln!(self.stdin, "{message}").context("writing to rust-mcp")?;
+        self.stdin.flush().context("writing to rust-mcp")
+    }
+}
+
+impl Drop for McpClient {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// A command starting the server with `config` as its config file and the
+/// other directories next to it.
+pub fn server(config: &Path) -> Result<Command> {
+    let dir = config.parent().context("config file without a directory")?;
+    let mut command = Command::new(env!("CARGO_BIN_EXE_rust-mcp"));
+    command
+        .arg("--config")
+        .arg(config)
+        .env("RUST_WORKSPACE_DATA_DIR", dir.join("data"))
+        .env("RUST_WORKSPACE_STATE_DIR", dir.join("state"))
+        .env("RUST_WORKSPACE_CACHE_DIR", dir.join("cache"));
+    Ok(command)
+}
+
+/// The text of a tool result's first content item.
+pub fn text(result: &Value) -> Result<&str> {
+    result
+        .pointer("/content/0/text")
+        .and_then(Value::as_str)
+        .with_context(|| format!("no text content in {result}"))
+}
+
+/// A config file holding `contents` in a fresh directory named after the
+/// test, so tests running in parallel do not share one.
+pub fn config_file(name: &str, contents: &str) -> Result<PathBuf> {
+    let dir = std::env::temp_dir().join(format!("rust-mcp-test-{}-{name}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir)?;
+    let file = dir.join("config.toml");
+    fs::write(&file, contents)?;
+    Ok(file)
+}
@@ -0,0 +1,297 @@
+//! `--transport http`: the handshake over streamable HTTP, the session
+//! limit, and bearer tokens with their tool scopes.
+
+use std::io::{BufRead as _, BufReader};
+use std::process::{Child, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context as _, Result, bail, ensure};
+use rust_mcp::client::{Connection, Server};
+use serde_json::{Map, Value, json};
+
+use crate::client::{PROTOCOL_VERSION, config_file, server};
+
+const AUTH: &str = r#"
+[mcp.auth]
+token = "admin-token"
+
+[mcp.auth.clients.reader]
+token = "reader-token"
+tools = ["get_profile"]
+"#;
+
+/// A server on a port of its own; dropping it kills the server.
+struct HttpServer {
+    child: Child,
+    url: String,
+    agent: ureq::Agent,
+}
+
+/// What the server answered to one POST.
+struct Reply {
+    status: u16,
+    session: Option<String>,
+    /// The JSON-RPC answer in the event stream, or the plain body.
+    text: String,
+}
+
+impl HttpServer {
+    /// Start the server with `config` on a free port and wait until it
+    /// listens.
+    fn start(name: &str, config: &str, max_sessions: u32) -> Result<Self> {
+        let mut child = server(&config_file(name, config)?)?
+            .args(["--transport", "http", "--bind", "127.0.0.1:0"])
+            .arg(format!("--max-sessions={max_sessions}"))
+            .env("RUST_LOG", "rust_mcp=info")
+            .stderr(Stdio::piped())
+            .spawn()
+            .context("spawning rust-mcp")?;
+        let stderr = child.stderr.take().context("rust-mcp has no stderr")?;
+        let url = BufReader::new(stderr)
+            .lines()
+            .map_while(Result::ok)
+            .find_map(|line| Some(line.split(" at ").nth(1)?.to_string()))
+            .context("rust-mcp did not say where it listens")?;
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .build()
+            .into();
+        Ok(Self { child, url, agent })
+    }
+
+    /// POST `message`, with `token` and in `session` when given.
+    fn post(&self, token: Option<&str>, session: Option<&str>, message: &Value) -> Result<Reply> {
+        let mut request = self
+            .agent
+            .post(&self.url)
+            .header("Accept", "application/json, text/event-stream")
+            .header("Content-Type", "application/json")
+            .header("MCP-Protocol-Version", PROTOCOL_VERSION);
+        if let Some(token) = token {
+            request = request.header("Authorization", format!("Bearer {token}"));
+        }
+        if let Some(session) = session {
+            request = request.header("Mcp-Session-Id", session);
+        }
+        let mut response = request.send(message.to_string())?;
+        let status = response.status().as_u16();
+        let session = response
+            .headers()
+            .get("mcp-session-id")
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        // An event stream stays open after the answer; stop at it.
+        let mut text = String::new();
+        let mut reader = BufReader::new(response.body_mut().as_reader());
+        let mut line = String::new();
+        while reader.read_line(&mut line)? > 0 {
+            if let Some(data) = line.strip_prefix("data: ")
+                && data.starts_with('{')
+            {
+                text = data.trim_end().to_string();
+                break;
+            }
+            text.push_str(&line);
+            line.clear();
+        }
+        Ok(Reply {
+            status,
+            session,
+            text,
+        })
+    }
+
+    /// Send `initialize` with `token`.
+    fn initialize(&self, token: Option<&str>) -> Result<Reply> {
+        self.post(
+            token,
+            None,
+            &json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "initialize",
+                "params": {
+                    "protocolVersion": PROTOCOL_VERSION,
+                    "capabilities": {},
+                    "clientInfo": { "name": "rust-mcp-tests", "version": "0" },
+                },
+            }),
+        )
+    }
+
+    /// Open a session with `token` and finish the handshake.
+    fn session(&self, token: &str) -> Result<String> {
+        let reply = self.initialize(Some(token))?;
+        ensure!(reply.status == 200, "{}: {}", reply.status, reply.text);
+        let session = reply.session.context("no session id")?;
+        let message = json!({ "jsonrpc": "2.0", "method": "notifications/initialized" });
+        self.post(Some(token), Some(&session), &message)?;
+        Ok(session)
+    }
+}
+
+impl Drop for HttpServer {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+fn initialize_over_http() -> Result<()> {
+    let server = HttpServer::start("http-init", "", 4)?;
+    let reply = server.initialize(None)?;
+    ensure!(reply.status == 200, "{}: {}", reply.status, reply.text);
+    ensure!(reply.text.contains("serverInfo"), "{}", reply.text);
+    Ok(())
+}
+
+#[test]
+fn clients_past_the_limit_are_refused() -> Result<()> {
+    let server = HttpServer::start("http-limit", "", 1)?;
+    let reply = server.initialize(None)?;
+    ensure!(reply.status == 200, "{}: {}", reply.status, reply.text);
+    let reply = server.initialize(None)?;
+    ensure!(reply.status == 503, "{}: {}", reply.status, reply.text);
+    Ok(())
+}
+
+#[test]
+fn requests_need_a_configured_token() -> Result<()> {
+    let server = HttpServer::start("http-token", AUTH, 4)?;
+    for token in [None, Some("guess"), Some("admin-token-but-longer")] {
+        let reply = server.initialize(token)?;
+        ensure!(reply.status == 401, "{token:?}: {}", reply.status);
+    }
+    for token in ["admin-token", "reader-token"] {
+        let reply = server.initialize(Some(token))?;
+        ensure!(reply.status == 200, "{token}: {}", reply.status);
+    }
+    Ok(())
+}
+
+#[test]
+fn scoped_clients_only_see_and_call_their_tools() -> Result<()> {
+    let server = HttpServer::start("http-scope", AUTH, 4)?;
+    let list = json!({ "jsonrpc": "2.0", "id": 2, "method": "tools/list" });
+    let call = |name: &str| {
+        json!({
+            "jsonrpc": "2.0",
+            "id": 3,
+            "method": "tools/call",
+            "params": { "name": name, "arguments": {} },
+        })
+    };
+
+    let reader = server.session("reader-token")?;
+    let listed: Value = serde_json::from_str(
+        &server
+            .post(Some("reader-token"), Some(&reader), &list)?
+            .text,
+    )?;
+    let names: Vec<&str> = listed["result"]["tools"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|tool| tool["name"].as_str())
+        .collect();
+    ensure!(names == ["get_profile"], "{listed}");
+    let allowed = server.post(Some("reader-token"), Some(&reader), &call("get_profile"))?;
+    ensure!(allowed.text.contains("\"result\""), "{}", allowed.text);
+    let refused = server.post(Some("reader-token"), Some(&reader), &call("config_show"))?;
+    ensure!(refused.text.contains("-32003"), "{}", refused.text);
+
+    let admin = server.session("admin-token")?;
+    let everything = server.post(Some("admin-token"), Some(&admin), &call("config_show"))?;
+    ensure!(
+        everything.text.contains("\"result\""),
+        "{}",
+        everything.text
+    );
+    Ok(())
+}
+
+#[test]
+fn scoped_clients_only_see_the_resources_of_their_tools() -> Result<()> {
+    let server = HttpServer::start("http-resources", AUTH, 4)?;
+    let request = |method: &str, params: Value| json!({ "jsonrpc": "2.0", "id": 4, "method": method, "params": params });
+
+    let reader = server.session("reader-token")?;
+    let listed = server.post(
+        Some("reader-token"),
+        Some(&reader),
+        &request("resources/list", json!({})),
+    )?;
+    let listed: Value = serde_json::from_str(&listed.text)?;
+    ensure!(listed["result"]["resources"] == json!([]), "{listed}");
+    for method in ["resources/read", "resources/subscribe"] {
+        let refused = server.post(
+            Some("reader-token"),
+            Some(&reader),
+            &request(method, json!({ "uri": "config://file" })),
+        )?;
+        ensure!(
+            refused.text.contains("-32003"),
+            "{method}: {}",
+            refused.text
+        );
+    }
+
+    let admin = server.session("admin-token")?;
+    let read = server.post(
+        Some("admin-token"),
+        Some(&admin),
+        &request("resources/read", json!({ "uri": "config://file" })),
+    )?;
+    ensure!(read.text.contains("\"result\""), "{}", read.text);
+    ensure!(!read.text.contains("reader-token"), "{}", read.text);
+    Ok(())
+}
+
+#[test]
+fn client_connections_speak_http() -> Result<()> {
+    let server = HttpServer::start("http-client", AUTH, 1)?;
+    let target = Server::Http {
+        url: server.url.clone(),
+        token: Some("reader-token".to_string()),
+    };
+    // One slot: closing must end the session for the second to get in.
+    for _ in 0..2 {
+        let connection = Connection::open(&target)?;
+        let names: Vec<String> = connection
+            .list_tools()?
+            .into_iter()
+            .map(|tool| tool.name.into_owned())
+            .collect();
+        ensure!(names == ["get_profile"], "{names:?}");
+        let result = connection.call_tool("get_profile", Map::new())?;
+        ensure!(result.is_error != Some(true), "{result:?}");
+        let Err(err) = connection.call_tool("echo", Map::new()) else {
+            bail!("echo is out of the reader's scope");
+        };
+        ensure!(err.to_string().contains("-32003"), "{err}");
+        connection.close()?;
+    }
+    Ok(())
+}
+
+#[test]
+fn serving_beyond_loopback_needs_a_token() -> Result<()> {
+    let mut child = server(&config_file("http-open", "")?)?
+        .args(["--transport", "http", "--bind", "0.0.0.0:0"])
+        .stderr(Stdio::null())
+        .spawn()?;
+    let deadline = Instant::now() + Duration::from_secs(10);
+    loop {
+        if let Some(status) = child.try_wait()? {
+            ensure!(!status.success(), "served without a token");
+            return Ok(());
+        }
+        if Instant::now() > deadline {
+            let _ = child.kill();
+            bail!("still serving on 0.0.0.0 without a token");
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+}
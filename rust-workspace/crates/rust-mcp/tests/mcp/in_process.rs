@@ -0,0 +1,156 @@
+//! The server through `rust_mcp::testing::TestClient`, in this process:
+//! typed results and errors, with the config built in code rather than
+//! read from a file.
+
+use anyhow::{Result, ensure};
+use rmcp::model::{ErrorCode, RawContent, ResourceContents};
+use serde_json::json;
+
+use rust_core::audit::AuditOutcome;
+use rust_core::{AppConfig, AppPaths, PathMode, RuntimeConfig};
+use rust_mcp::testing::TestClient;
+
+/// The code of a call the settings do not allow.
+const FORBIDDEN: ErrorCode = ErrorCode(-32_003);
+
+/// The code of a call past the session's rate limit.
+const RATE_LIMITED: ErrorCode = ErrorCode(-32_004);
+
+/// Paths under a fresh directory named after the test; no file is written.
+fn paths(name: &str) -> AppPaths {
+    let dir =
+        std::env::temp_dir().join(format!("rust-mcp-in-process-{}-{name}", std::process::id()));
+    AppPaths {
+        mode: PathMode::User,
+        config_file: dir.join("config.toml"),
+        data_dir: dir.join("data"),
+        state_dir: dir.join("state"),
+        cache_dir: dir.join("cache"),
+    }
+}
+
+fn config() -> AppConfig {
+    AppConfig {
+        profile: "in-process".to_string(),
+        runtime: RuntimeConfig {
+            parallelism: Some(2),
+            ..RuntimeConfig::default()
+        },
+        ..AppConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn tools_answer_with_typed_results() -> Result<()> {
+    let client = TestClient::start(paths("typed"), config()).await?;
+    let names = client.tool_names().await?;
+    ensure!(names.iter().any(|name| name == "run_task"), "{names:?}");
+
+    let result = client.call("echo", json!({ "message": "hi" })).await?;
+    let text = result.content.first().map(|content| &content.raw);
+    ensure!(
+        matches!(text, Some(RawContent::Text(text)) if text.text == "Echo: hi"),
+        "{result:?}"
+    );
+    let runtime: RuntimeConfig = client.call_as("get_runtime_config", json!({})).await?;
+    ensure!(runtime.parallelism == Some(2), "{runtime:?}");
+
+    let contents = client.read("paths://").await?;
+    let ResourceContents::TextResourceContents { text, .. } = contents else {
+        anyhow::bail!("paths:// is not text: {contents:?}");
+    };
+    ensure!(text.contains("config.toml"), "{text}");
+
+    client.close().await
+}
+
+#[tokio::test]
+async fn refusals_are_json_rpc_errors() -> Result<()> {
+    let mut config = config();
+    config.mcp.disabled_tools = vec!["echo".to_string()];
+    let client = TestClient::start(paths("errors"), config).await?;
+    ensure!(
+        !client.tool_names().await?.iter().any(|name| name == "echo"),
+        "echo is listed"
+    );
+
+    let error = client
+        .call_error("echo", json!({ "message": "hi" }))
+        .await?;
+    ensure!(error.code == FORBIDDEN, "{error:?}");
+    let error = client
+        .call_error("config_get", json!({ "key": "runtime.nope" }))
+        .await?;
+    ensure!(error.code == ErrorCode::INVALID_PARAMS, "{error:?}");
+    // Writes are off by default.
+    let error = client
+        .call_error("config_set", json!({ "key": "profile", "value": "x" }))
+        .await?;
+    ensure!(error.code == FORBIDDEN, "{error:?}");
+    ensure!(!paths("errors").config_file.exists(), "a file was written");
+
+    client.close().await
+}
+
+#[tokio::test]
+async fn calls_are_audited_with_secrets_redacted() -> Result<()> {
+    let paths = paths("audit");
+    let client = TestClient::start(paths.clone(), config()).await?;
+    client.call("echo", json!({ "message": "hi" })).await?;
+    client
+        .call_error(
+            "config_set",
+            json!({ "key": "mcp.auth.token", "value": "hunter2" }),
+        )
+        .await?;
+    client.close().await?;
+
+    let calls = rust_core::audit::list(&paths.state_dir)?;
+    let tools: Vec<&str> = calls.iter().map(|call| call.tool.as_str()).collect();
+    ensure!(tools == ["echo", "config_set"], "{tools:?}");
+    ensure!(
+        calls[0].outcome == AuditOutcome::Ok && calls[0].arguments == json!({ "message": "hi" }),
+        "{calls:?}"
+    );
+    ensure!(
+        matches!(calls[1].outcome, AuditOutcome::Error { code, .. } if code == FORBIDDEN.0),
+        "{calls:?}"
+    );
+    ensure!(calls[1].arguments["value"] == "<redacted>", "{calls:?}");
+    ensure!(calls.iter().all(|call| call.session.is_none()), "{calls:?}");
+    std::fs::remove_dir_all(paths.state_dir)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn sessions_over_their_rate_limit_are_refused() -> Result<()> {
+    let mut config = config();
+    config.mcp.rate_limit.calls_per_minute = 2;
+    config.mcp.rate_limit.tools = [("echo".to_string(), 0), ("config_get".to_string(), 1)].into();
+    let client = TestClient::start(paths("rate-limit"), config).await?;
+
+    client.call("get_profile", json!({})).await?;
+    client.call("get_runtime_config", json!({})).await?;
+    let error = client.call_error("get_profile", json!({})).await?;
+    ensure!(error.code == RATE_LIMITED, "{error:?}");
+    ensure!(error.message.contains("calls_per_minute"), "{error:?}");
+    let retry_after = error
+        .data
+        .as_ref()
+        .and_then(|data| data["retry_after"].as_u64());
+    ensure!(matches!(retry_after, Some(1..=60)), "{error:?}");
+
+    // Tools with a limit of their own are counted apart, and 0 lifts it.
+    client
+        .call("config_get", json!({ "key": "profile" }))
+        .await?;
+    let error = client
+        .call_error("config_get", json!({ "key": "profile" }))
+        .await?;
+    ensure!(error.code == RATE_LIMITED, "{error:?}");
+    for _ in 0..5 {
+        client.call("echo", json!({ "message": "hi" })).await?;
+    }
+
+    client.close().await
+}
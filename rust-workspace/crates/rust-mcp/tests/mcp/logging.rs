@@ -0,0 +1,84 @@
+//! Log records from tool calls, sent to the calling client as
+//! `notifications/message` up to `mcp.log_level` and the client's
+//! `logging/setLevel`.
+
+use anyhow::{Result, ensure};
+use serde_json::{Value, json};
+
+use crate::client::{McpClient, config_file};
+
+const TASKS: &str = "[tasks.hello]\ncommand = \"true\"\n";
+
+/// The messages of the `notifications/message` the client got so far, and
+/// forget them.
+fn messages(client: &mut McpClient) -> Vec<Value> {
+    client
+        .notifications
+        .drain(..)
+        .filter(|message| message["method"] == "notifications/message")
+        .map(|message| message["params"].clone())
+        .collect()
+}
+
+#[test]
+fn tool_call_records_reach_the_client_at_its_level() -> Result<()> {
+    let mut client = McpClient::spawn(&config_file("logging", TASKS)?)?;
+    ensure!(
+        client.server["capabilities"]["logging"].is_object(),
+        "{}",
+        client.server
+    );
+
+    client.call("run_task", &json!({ "task": "hello" }))?;
+    let records = messages(&mut client);
+    ensure!(
+        records.iter().any(|record| record["level"] == "info"
+            && record["logger"] == "rust_core::task"
+            && record["data"] == "task hello started"),
+        "{records:?}"
+    );
+
+    client.request("logging/setLevel", &json!({ "level": "warning" }))?;
+    client.call("run_task", &json!({ "task": "hello" }))?;
+    let records = messages(&mut client);
+    ensure!(records.is_empty(), "{records:?}");
+    Ok(())
+}
+
+#[test]
+fn task_output_reaches_the_client_line_by_line() -> Result<()> {
+    let tasks = "[tasks.chatty]\ncommand = \"echo one; echo two >&2\"\n";
+    let mut client = McpClient::spawn(&config_file("logging-output", tasks)?)?;
+    let result = client.call("run_task", &json!({ "task": "chatty" }))?;
+    ensure!(
+        result["structuredContent"]["tasks"][0]["status"] == "succeeded",
+        "{result}"
+    );
+    let lines: Vec<(&str, &str)> = client
+        .notifications
+        .iter()
+        .filter(|message| message["method"] == "notifications/message")
+        .filter_map(|message| {
+            let record = &message["params"];
+            Some((record["logger"].as_str()?, record["data"].as_str()?))
+        })
+        .filter(|(logger, _)| logger.starts_with("task."))
+        .collect();
+    ensure!(
+        lines.contains(&("task.chatty.stdout", "one"))
+            && lines.contains(&("task.chatty.stderr", "two")),
+        "{lines:?}"
+    );
+    Ok(())
+}
+
+#[test]
+fn the_config_caps_what_clients_get() -> Result<()> {
+    let config = format!("[mcp]\nlog_level = \"warn\"\n\n{TASKS}");
+    let mut client = McpClient::spawn(&config_file("logging-cap", &config)?)?;
+    client.request("logging/setLevel", &json!({ "level": "debug" }))?;
+    client.call("run_task", &json!({ "task": "hello" }))?;
+    let records = messages(&mut client);
+    ensure!(records.is_empty(), "{records:?}");
+    Ok(())
+}
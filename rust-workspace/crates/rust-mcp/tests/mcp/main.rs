@@ -0,0 +1,13 @@
+//! rust-mcp end to end: each test starts the server and talks to it over
+//! stdio through [`client::McpClient`], as an editor or agent would, except
+//! the [`in_process`] ones, which serve it in the test through
+//! `rust_mcp::testing`.
+
+mod client;
+mod http;
+mod in_process;
+mod logging;
+mod prompts;
+mod resources;
+mod shutdown;
+mod tools;
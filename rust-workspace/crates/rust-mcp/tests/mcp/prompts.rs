@@ -0,0 +1,76 @@
+//! The prompt list, and `diagnose-config` with what it embeds.
+
+use std::fs;
+use std::time::{Duration, SystemTime};
+
+use anyhow::{Result, ensure};
+use serde_json::json;
+
+use crate::client::{McpClient, config_file};
+
+#[test]
+fn diagnose_config_is_listed() -> Result<()> {
+    let mut client = McpClient::spawn(&config_file("prompts-list", "")?)?;
+    ensure!(
+        client.server["capabilities"]["prompts"].is_object(),
+        "{}",
+        client.server
+    );
+    let result = client.request("prompts/list", &json!({}))?;
+    let prompt = &result["prompts"][0];
+    ensure!(prompt["name"] == "diagnose-config", "{result}");
+    ensure!(prompt["arguments"][0]["name"] == "problem", "{result}");
+    ensure!(prompt["arguments"][0]["required"] != true, "{result}");
+    Ok(())
+}
+
+#[test]
+fn diagnose_config_embeds_the_config_and_recent_problems() -> Result<()> {
+    let file = config_file("prompts-diagnose", "")?;
+    let log = file.with_file_name("app.log");
+    let now = humantime::format_rfc3339_millis(SystemTime::now());
+    let old = humantime::format_rfc3339_millis(SystemTime::now() - Duration::from_secs(3 * 86_400));
+    fs::write(
+        &log,
+        format!(
+            "{old} ERROR rust_cli: stale failure\n\
+             {now} INFO  rust_cli: routine\n\
+             {now} ERROR rust_cli: timeout after 60s\n"
+        ),
+    )?;
+    fs::write(
+        &file,
+        format!(
+            "[logging]\nfile = {:?}\n\n[daemon.http]\ntoken = \"hunter2\"\n",
+            log.display().to_string()
+        ),
+    )?;
+    let mut client = McpClient::spawn(&file)?;
+    let result = client.request(
+        "prompts/get",
+        &json!({ "name": "diagnose-config", "arguments": { "problem": "runs time out" } }),
+    )?;
+    let messages = &result["messages"];
+    ensure!(
+        messages[0]["content"]["text"]
+            .as_str()
+            .is_some_and(|text| text.contains("runs time out")),
+        "{result}"
+    );
+
+    let embedded = &messages[1]["content"]["resource"]["resource"];
+    ensure!(embedded["uri"] == "config://current", "{result}");
+    let text = embedded["text"].as_str().unwrap_or_default();
+    ensure!(
+        text.contains("<redacted>") && !text.contains("hunter2"),
+        "{text}"
+    );
+
+    let problems = messages[2]["content"]["text"].as_str().unwrap_or_default();
+    ensure!(problems.contains("timeout after 60s"), "{problems}");
+    ensure!(
+        !problems.contains("stale failure") && !problems.contains("routine"),
+        "{problems}"
+    );
+    Ok(())
+}
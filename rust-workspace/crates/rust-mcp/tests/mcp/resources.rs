@@ -0,0 +1,162 @@
+//! The resource list, reading each resource, and subscribing to changes.
+
+use std::fs;
+
+use anyhow::{Result, ensure};
+use serde_json::{Value, json};
+
+use crate::client::{McpClient, config_file};
+
+const CONFIG: &str = "# a comment\nprofile = \"ci\"\n\n[daemon.http]\ntoken = \"hunter2\"\n";
+
+#[test]
+fn resources_are_listed_with_mime_types() -> Result<()> {
+    let mut client = McpClient::spawn(&config_file("resources-list", CONFIG)?)?;
+    ensure!(
+        client.server["capabilities"]["resources"].is_object(),
+        "{}",
+        client.server
+    );
+    let result = client.request("resources/list", &json!({}))?;
+    let listed: Vec<(&str, &str)> = result["resources"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|resource| Some((resource["uri"].as_str()?, resource["mimeType"].as_str()?)))
+        .collect();
+    ensure!(
+        listed
+            == [
+                ("config://current", "application/json"),
+                ("config://file", "application/toml"),
+                ("paths://", "application/json"),
+                ("schema://config", "application/json"),
+            ],
+        "{result}"
+    );
+    Ok(())
+}
+
+#[test]
+fn current_config_is_redacted_json() -> Result<()> {
+    let mut client = McpClient::spawn(&config_file("resources-current", CONFIG)?)?;
+    let contents = client.read("config://current")?;
+    ensure!(contents["mimeType"] == "application/json", "{contents}");
+    let config: Value = serde_json::from_str(contents["text"].as_str().unwrap_or_default())?;
+    ensure!(config["profile"] == "ci", "{config}");
+    ensure!(
+        config["daemon"]["http"]["token"] == "<redacted>",
+        "{config}"
+    );
+    Ok(())
+}
+
+#[test]
+fn config_file_is_the_toml_with_secrets_redacted() -> Result<()> {
+    let mut client = McpClient::spawn(&config_file("resources-file", CONFIG)?)?;
+    let contents = client.read("config://file")?;
+    ensure!(contents["mimeType"] == "application/toml", "{contents}");
+    ensure!(
+        contents["text"] == CONFIG.replace("\"hunter2\"", "\"<redacted>\""),
+        "{contents}"
+    );
+    Ok(())
+}
+
+#[test]
+fn paths_name_the_config_file() -> Result<()> {
+    let file = config_file("resources-paths", CONFIG)?;
+    let mut client = McpClient::spawn(&file)?;
+    let contents = client.read("paths://")?;
+    let paths: Value = serde_json::from_str(contents["text"].as_str().unwrap_or_default())?;
+    ensure!(paths["config"] == file.display().to_string(), "{paths}");
+    ensure!(
+        paths["state"].is_string() && paths["cache"].is_string(),
+        "{paths}"
+    );
+
+    let missing = client.read("config://nope");
+    ensure!(missing.is_err(), "unknown resource: {missing:?}");
+    Ok(())
+}
+
+#[test]
+fn config_schema_describes_the_settings() -> Result<()> {
+    let mut client = McpClient::spawn(&config_file("resources-schema", CONFIG)?)?;
+    let contents = client.read("schema://config")?;
+    ensure!(contents["mimeType"] == "application/json", "{contents}");
+    let schema: Value = serde_json::from_str(contents["text"].as_str().unwrap_or_default())?;
+    ensure!(schema["properties"]["profile"].is_object(), "{schema}");
+    Ok(())
+}
+
+/// The `notifications/resources/updated` the client got so far, by URI.
+fn updated(client: &McpClient) -> Vec<&str> {
+    client
+        .notifications
+        .iter()
+        .filter(|message| message["method"] == "notifications/resources/updated")
+        .filter_map(|message| message["params"]["uri"].as_str())
+        .collect()
+}
+
+#[test]
+fn subscribers_hear_of_config_changes() -> Result<()> {
+    let file = config_file("resources-subscribe", CONFIG)?;
+    let mut client = McpClient::spawn(&file)?;
+    ensure!(
+        client.server["capabilities"]["resources"]["subscribe"] == true,
+        "{}",
+        client.server
+    );
+    client.request("resources/subscribe", &json!({ "uri": "config://current" }))?;
+    let missing = client.request("resources/subscribe", &json!({ "uri": "config://nope" }));
+    ensure!(missing.is_err(), "unknown resource: {missing:?}");
+
+    // A reload that changes nothing is not news.
+    client.call("reload_config", &json!({}))?;
+    ensure!(updated(&client).is_empty(), "{:?}", client.notifications);
+
+    fs::write(&file, CONFIG.replace("\"ci\"", "\"nightly\""))?;
+    client.call("reload_config", &json!({}))?;
+    ensure!(
+        updated(&client) == ["config://current"],
+        "{:?}",
+        client.notifications
+    );
+
+    client.request(
+        "resources/unsubscribe",
+        &json!({ "uri": "config://current" }),
+    )?;
+    fs::write(&file, CONFIG)?;
+    client.call("reload_config", &json!({}))?;
+    ensure!(
+        updated(&client) == ["config://current"],
+        "{:?}",
+        client.notifications
+    );
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn sighup_reloads_the_config() -> Result<()> {
+    let file = config_file("resources-sighup", CONFIG)?;
+    let mut client = McpClient::spawn(&file)?;
+    client.request("resources/subscribe", &json!({ "uri": "config://current" }))?;
+    fs::write(&file, CONFIG.replace("\"ci\"", "\"nightly\""))?;
+    let status = std::process::Command::new("kill")
+        .args(["-HUP", &client.pid().to_string()])
+        .status()?;
+    ensure!(status.success(), "kill -HUP: {status}");
+    let notification = client.wait_for("notifications/resources/updated")?;
+    ensure!(
+        notification["params"]["uri"] == "config://current",
+        "{notification}"
+    );
+    let contents = client.read("config://current")?;
+    let config: Value = serde_json::from_str(contents["text"].as_str().unwrap_or_default())?;
+    ensure!(config["profile"] == "nightly", "{config}");
+    Ok(())
+}
@@ -0,0 +1,92 @@
+//! Shutting down on SIGTERM: calls in flight get `mcp.shutdown_grace`,
+//! new calls are refused meanwhile, and the server then exits cleanly. A
+//! server that cannot start exits with the code for why.
+
+#![cfg(unix)]
+
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{Result, ensure};
+use serde_json::json;
+
+use crate::client::{McpClient, config_file, server};
+
+const CONFIG: &str = r#"
+[mcp]
+shutdown_grace = 1
+
+[tasks.slow]
+command = "sleep 30"
+"#;
+
+fn signal(client: &McpClient, name: &str) -> Result<()> {
+    let status = Command::new("kill")
+        .args([format!("-{name}"), client.pid().to_string()])
+        .status()?;
+    ensure!(status.success(), "kill -{name}: {status}");
+    Ok(())
+}
+
+#[test]
+fn sigterm_drains_calls_then_exits() -> Result<()> {
+    let mut client = McpClient::spawn(&config_file("shutdown", CONFIG)?)?;
+    let running = client.start(
+        "tools/call",
+        &json!({ "name": "run_task", "arguments": { "task": "slow" } }),
+    )?;
+    // Let the task start.
+    thread::sleep(Duration::from_millis(300));
+    let signalled = Instant::now();
+    signal(&client, "TERM")?;
+    thread::sleep(Duration::from_millis(300));
+
+    let refused = client.call_error("echo", &json!({ "message": "late" }))?;
+    ensure!(refused["data"]["kind"] == "interrupted", "{refused}");
+
+    // Past the grace period the task is killed and reported.
+    let answer = client.answer(running)?;
+    ensure!(
+        signalled.elapsed() < Duration::from_secs(10),
+        "not cancelled"
+    );
+    ensure!(
+        answer
+            .as_ref()
+            .map_or(true, |result| result["isError"] == true),
+        "{answer:?}"
+    );
+    let status = client.wait_exit()?;
+    ensure!(status.success(), "{status}");
+    Ok(())
+}
+
+#[test]
+fn sigterm_without_calls_exits_at_once() -> Result<()> {
+    let mut client = McpClient::spawn(&config_file("shutdown-idle", CONFIG)?)?;
+    let signalled = Instant::now();
+    signal(&client, "INT")?;
+    let status = client.wait_exit()?;
+    ensure!(status.success(), "{status}");
+    ensure!(
+        signalled.elapsed() < Duration::from_secs(1),
+        "waited the grace period"
+    );
+    Ok(())
+}
+
+#[test]
+fn failing_to_start_exits_with_the_code_for_why() -> Result<()> {
+    let bad_config = server(&config_file("exit-config", "x = [")?)?
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .status()?;
+    ensure!(bad_config.code() == Some(2), "{bad_config}");
+    let usage = Command::new(env!("CARGO_BIN_EXE_rust-mcp"))
+        .arg("--no-such-flag")
+        .stderr(Stdio::null())
+        .status()?;
+    ensure!(usage.code() == Some(64), "{usage}");
+    Ok(())
+}
@@ -0,0 +1,439 @@
+//! The handshake, the tool list (one tool per shared `rust_core::ops`
+//! operation, and more), and every tool.
+
+use std::fs;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{Result, ensure};
+use serde_json::{Value, json};
+
+use crate::client::{McpClient, PROTOCOL_VERSION, config_file, text};
+
+const CONFIG: &str = "profile = \"ci\"\n\n[runtime]\nparallelism = 3\ntimeout = 42\n";
+
+/// JSON-RPC error codes: arguments the tool cannot use, and a call the
+/// settings do not allow.
+const INVALID_PARAMS: i64 = -32_602;
+const FORBIDDEN: i64 = -32_003;
+
+/// The tools the tests below call; a listed tool missing here fails
+/// [`every_tool_is_listed_and_tested`].
+const TESTED: [&str; 10] = [
+    "config_get",
+    "config_paths",
+    "config_set",
+    "config_show",
+    "echo",
+    "get_config_schema",
+    "get_profile",
+    "get_runtime_config",
+    "reload_config",
+    "run_task",
+];
+
+/// Tasks for `run_task`: `build` after `prepare`, both printing to stdout,
+/// which must not reach the protocol stream.
+const TASKS: &str = r#"
+[tasks.prepare]
+command = "echo preparing"
+
+[tasks.build]
+command = "echo building"
+depends_on = ["prepare"]
+
+[tasks.broken]
+command = "exit 3"
+
+[tasks.slow]
+command = "sleep 5"
+"#;
+
+#[test]
+fn handshake_reports_the_server_and_its_tools() -> Result<()> {
+    let client = McpClient::spawn(&config_file("handshake", CONFIG)?)?;
+    let server = &client.server;
+    ensure!(server["protocolVersion"] == PROTOCOL_VERSION, "{server}");
+    ensure!(server["serverInfo"]["name"].is_string(), "{server}");
+    ensure!(server["capabilities"]["tools"].is_object(), "{server}");
+    Ok(())
+}
+
+#[test]
+fn every_tool_is_listed_and_tested() -> Result<()> {
+    let mut client = McpClient::spawn(&config_file("list", CONFIG)?)?;
+    let tools = client.tools()?;
+    let mut names = Vec::new();
+    for tool in &tools {
+        ensure!(tool["description"].is_string(), "no description: {tool}");
+        ensure!(
+            tool["inputSchema"]["type"] == "object",
+            "bad schema: {tool}"
+        );
+        names.extend(tool["name"].as_str());
+    }
+    names.sort_unstable();
+    ensure!(names == TESTED, "listed {names:?}, tested {TESTED:?}");
+    for operation in rust_core::ops::REGISTRY {
+        ensure!(
+            names.contains(&operation.name),
+            "{} is not a tool",
+            operation.name
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn echo_returns_the_message() -> Result<()> {
+    let mut client = McpClient::spawn(&config_file("echo", CONFIG)?)?;
+    let result = client.call("echo", &json!({ "message": "hello" }))?;
+    ensure!(text(&result)? == "Echo: hello", "{result}");
+    ensure!(result["isError"] != true, "{result}");
+
+    let missing = client.call("echo", &json!({}));
+    ensure!(missing.is_err(), "echo without a message: {missing:?}");
+    Ok(())
+}
+
+#[test]
+fn get_profile_returns_the_configured_profile() -> Result<()> {
+    let mut client = McpClient::spawn(&config_file("profile", CONFIG)?)?;
+    let result = client.call("get_profile", &json!({}))?;
+    ensure!(text(&result)? == "ci", "{result}");
+    Ok(())
+}
+
+#[test]
+fn get_runtime_config_is_structured() -> Result<()> {
+    let mut client = McpClient::spawn(&config_file("runtime", CONFIG)?)?;
+    let result = client.call("get_runtime_config", &json!({}))?;
+    let runtime = &result["structuredContent"];
+    ensure!(runtime["parallelism"] == 3, "{result}");
+    ensure!(runtime["timeout"] == 42, "{result}");
+    // Clients that only read text get the same object.
+    let parsed: Value = serde_json::from_str(text(&result)?)?;
+    ensure!(&parsed == runtime, "{result}");
+    Ok(())
+}
+
+#[test]
+fn config_show_redacts_secrets() -> Result<()> {
+    let config = format!("{CONFIG}\n[daemon.http]\ntoken = \"hunter2\"\n");
+    let mut client = McpClient::spawn(&config_file("show", &config)?)?;
+    let result = client.call("config_show", &json!({}))?;
+    let shown = &result["structuredContent"];
+    ensure!(shown["runtime"]["timeout"] == 42, "{result}");
+    ensure!(shown["daemon"]["http"]["token"] == "<redacted>", "{result}");
+    Ok(())
+}
+
+#[test]
+fn config_paths_name_the_config_file() -> Result<()> {
+    let file = config_file("paths", CONFIG)?;
+    let mut client = McpClient::spawn(&file)?;
+    let result = client.call("config_paths", &json!({}))?;
+    let paths = &result["structuredContent"];
+    ensure!(paths["config"] == file.display().to_string(), "{result}");
+    ensure!(paths["state"].is_string(), "{result}");
+    Ok(())
+}
+
+#[test]
+fn get_config_schema_describes_the_settings() -> Result<()> {
+    let mut client = McpClient::spawn(&config_file("schema", CONFIG)?)?;
+    let result = client.call("get_config_schema", &json!({}))?;
+    let schema = &result["structuredContent"];
+    ensure!(
+        schema["$schema"] == "http://json-schema.org/draft-07/schema#",
+        "{result}"
+    );
+    ensure!(schema["properties"]["runtime"].is_object(), "{result}");
+    Ok(())
+}
+
+#[test]
+fn config_get_reads_settings_and_sections() -> Result<()> {
+    let mut client = McpClient::spawn(&config_file("get", CONFIG)?)?;
+    let result = client.call("config_get", &json!({ "key": "runtime.timeout" }))?;
+    ensure!(result["structuredContent"]["value"] == 42, "{result}");
+
+    let result = client.call("config_get", &json!({ "key": "runtime" }))?;
+    ensure!(
+        result["structuredContent"]["value"]["parallelism"] == 3,
+        "{result}"
+    );
+
+    let error = client.call_error("config_get", &json!({ "key": "runtime.nope" }))?;
+    ensure!(error["code"] == INVALID_PARAMS, "{error}");
+    ensure!(
+        error["message"] == "runtime.nope is not a setting",
+        "{error}"
+    );
+    Ok(())
+}
+
+#[test]
+fn config_set_is_off_by_default() -> Result<()> {
+    let file = config_file("set-off", CONFIG)?;
+    let mut client = McpClient::spawn(&file)?;
+    let error = client.call_error(
+        "config_set",
+        &json!({ "key": "runtime.timeout", "value": "90" }),
+    )?;
+    ensure!(error["code"] == FORBIDDEN, "{error}");
+    ensure!(
+        error["message"]
+            .as_str()
+            .is_some_and(|message| message.contains("mcp.allow_config_writes")),
+        "{error}"
+    );
+    ensure!(fs::read_to_string(&file)? == CONFIG, "file changed");
+    Ok(())
+}
+
+#[test]
+fn config_set_writes_the_file_when_allowed() -> Result<()> {
+    let config = format!("# keep me\n{CONFIG}\n[mcp]\nallow_config_writes = true\n");
+    let file = config_file("set", &config)?;
+    let mut client = McpClient::spawn(&file)?;
+    let result = client.call(
+        "config_set",
+        &json!({ "key": "runtime.timeout", "value": "90" }),
+    )?;
+    let changes = &result["structuredContent"]["changes"];
+    ensure!(changes[0]["key"] == "runtime.timeout", "{result}");
+    ensure!(
+        changes[0]["before"] == 42 && changes[0]["after"] == 90,
+        "{result}"
+    );
+    let written = fs::read_to_string(&file)?;
+    ensure!(
+        written.contains("# keep me") && written.contains("timeout = 90"),
+        "{written}"
+    );
+
+    // The server serves the new value from then on.
+    let result = client.call("config_get", &json!({ "key": "runtime.timeout" }))?;
+    ensure!(result["structuredContent"]["value"] == 90, "{result}");
+
+    for (key, value, code) in [
+        ("runtime.nope", "1", INVALID_PARAMS),
+        ("runtime.timeout", "soon", INVALID_PARAMS),
+        ("mcp.auth.token", "mine", FORBIDDEN),
+    ] {
+        let error = client.call_error("config_set", &json!({ "key": key, "value": value }))?;
+        ensure!(error["code"] == code, "{key} = {value}: {error}");
+    }
+    // The data says what kind of failure it was, as `rust-cli --json` does.
+    let error = client.call_error(
+        "config_set",
+        &json!({ "key": "runtime.timeout", "value": "soon" }),
+    )?;
+    ensure!(error["data"]["kind"] == "config", "{error}");
+    ensure!(error["data"]["error_code"].is_string(), "{error}");
+    ensure!(
+        fs::read_to_string(&file)? == written,
+        "a rejected value was written"
+    );
+    Ok(())
+}
+
+#[test]
+fn tools_can_be_turned_off() -> Result<()> {
+    let config = format!(
+        "{CONFIG}\n[mcp]\nallow_config_writes = true\ndisabled_tools = [\"config_set\", \"run_task\"]\n"
+    );
+    let mut client = McpClient::spawn(&config_file("disabled", &config)?)?;
+    let tools = client.tools()?;
+    let names: Vec<&str> = tools
+        .iter()
+        .filter_map(|tool| tool["name"].as_str())
+        .collect();
+    ensure!(
+        !names.contains(&"config_set") && !names.contains(&"run_task"),
+        "{names:?}"
+    );
+    ensure!(names.contains(&"config_get"), "{names:?}");
+    let error = client.call_error(
+        "config_set",
+        &json!({ "key": "runtime.timeout", "value": "90" }),
+    )?;
+    ensure!(error["code"] == FORBIDDEN, "{error}");
+
+    // The disabled list wins over the enabled one.
+    let config = format!(
+        "{CONFIG}\n[mcp]\nenabled_tools = [\"echo\", \"get_profile\"]\ndisabled_tools = [\"get_profile\"]\n"
+    );
+    let mut client = McpClient::spawn(&config_file("enabled", &config)?)?;
+    let tools = client.tools()?;
+    let names: Vec<&str> = tools
+        .iter()
+        .filter_map(|tool| tool["name"].as_str())
+        .collect();
+    ensure!(names == ["echo"], "{names:?}");
+    let error = client.call_error("get_profile", &json!({}))?;
+    ensure!(error["code"] == FORBIDDEN, "{error}");
+    Ok(())
+}
+
+#[test]
+fn reload_config_picks_up_edits() -> Result<()> {
+    let file = config_file("reload", CONFIG)?;
+    let mut client = McpClient::spawn(&file)?;
+    fs::write(&file, CONFIG.replace("timeout = 42", "timeout = 90"))?;
+    let result = client.call("reload_config", &json!({}))?;
+    ensure!(
+        result["structuredContent"]["changed"] == json!(["runtime.timeout"]),
+        "{result}"
+    );
+    let result = client.call("config_get", &json!({ "key": "runtime.timeout" }))?;
+    ensure!(result["structuredContent"]["value"] == 90, "{result}");
+
+    let result = client.call("reload_config", &json!({}))?;
+    ensure!(
+        result["structuredContent"]["changed"] == json!([]),
+        "{result}"
+    );
+
+    // A broken file is reported and the config in use stays.
+    fs::write(&file, "[runtime\n")?;
+    client.call_error("reload_config", &json!({}))?;
+    let result = client.call("config_get", &json!({ "key": "runtime.timeout" }))?;
+    ensure!(result["structuredContent"]["value"] == 90, "{result}");
+    Ok(())
+}
+
+#[test]
+fn run_task_runs_dependencies_first_and_reports_progress() -> Result<()> {
+    let file = config_file("run", &format!("{CONFIG}{TASKS}"))?;
+    let mut client = McpClient::spawn(&file)?;
+    let result = client.request(
+        "tools/call",
+        &json!({
+            "name": "run_task",
+            "arguments": { "task": "build", "profile": "nightly" },
+            "_meta": { "progressToken": "build-1" },
+        }),
+    )?;
+    ensure!(result["isError"] != true, "{result}");
+    let report = &result["structuredContent"];
+    ensure!(report["profile"] == "nightly", "{result}");
+    let tasks: Vec<(&str, &str)> = report["tasks"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|run| Some((run["name"].as_str()?, run["status"].as_str()?)))
+        .collect();
+    ensure!(
+        tasks == [("prepare", "succeeded"), ("build", "succeeded")],
+        "{result}"
+    );
+
+    let progress: Vec<&Value> = client
+        .notifications
+        .iter()
+        .filter(|message| message["method"] == "notifications/progress")
+        .map(|message| &message["params"])
+        .collect();
+    ensure!(progress.len() == 4, "{progress:?}");
+    ensure!(
+        progress
+            .iter()
+            .all(|step| step["progressToken"] == "build-1"),
+        "{progress:?}"
+    );
+    let last = progress[3];
+    ensure!(last["progress"] == 2.0 && last["total"] == 2.0, "{last}");
+    ensure!(last["message"] == "build finished", "{last}");
+
+    // Recorded for `history` and the TUI as a CLI run would be.
+    let dir = file.parent().map(|dir| dir.join("state"));
+    let recorded = dir.map(|dir| fs::read_to_string(dir.join("tasks.json")));
+    ensure!(
+        matches!(&recorded, Some(Ok(text)) if text.contains("\"prepare\"")),
+        "{recorded:?}"
+    );
+    Ok(())
+}
+
+#[test]
+fn run_task_reports_failed_tasks_in_its_result() -> Result<()> {
+    let mut client = McpClient::spawn(&config_file("run-fail", &format!("{CONFIG}{TASKS}"))?)?;
+    let result = client.request(
+        "tools/call",
+        &json!({
+            "name": "run_task",
+            "arguments": { "task": "broken" },
+            "_meta": { "progressToken": 7 },
+        }),
+    )?;
+    ensure!(result["isError"] == true, "{result}");
+    let last = client
+        .notifications
+        .iter()
+        .rfind(|message| message["method"] == "notifications/progress")
+        .map(|message| &message["params"]);
+    ensure!(
+        last.and_then(|step| step["message"].as_str())
+            .is_some_and(
+                |message| message.starts_with("broken failed") && message.contains("exit 3")
+            ),
+        "{last:?}"
+    );
+    let run = &result["structuredContent"]["tasks"][0];
+    ensure!(
+        run["status"] == "failed" && run["exit_code"] == 3,
+        "{result}"
+    );
+
+    let result = client.call("run_task", &json!({ "task": "slow", "timeout": 1 }))?;
+    ensure!(
+        result["structuredContent"]["tasks"][0]["status"] == "timed_out",
+        "{result}"
+    );
+
+    let error = client.call_error("run_task", &json!({ "task": "missing" }))?;
+    ensure!(error["code"] == INVALID_PARAMS, "{error}");
+    ensure!(
+        error["message"]
+            .as_str()
+            .is_some_and(|message| message.contains("missing")),
+        "{error}"
+    );
+    Ok(())
+}
+
+#[test]
+fn cancelling_run_task_kills_its_tasks() -> Result<()> {
+    let file = config_file("run-cancel", &format!("{CONFIG}{TASKS}"))?;
+    let mut client = McpClient::spawn(&file)?;
+    let running = client.start(
+        "tools/call",
+        &json!({ "name": "run_task", "arguments": { "task": "slow" } }),
+    )?;
+    // Let the task start.
+    thread::sleep(Duration::from_millis(300));
+    let cancelled = Instant::now();
+    client.notify(
+        "notifications/cancelled",
+        &json!({ "requestId": running, "reason": "changed my mind" }),
+    )?;
+    let answer = client.answer(running)?;
+    ensure!(
+        cancelled.elapsed() < Duration::from_secs(3),
+        "the task ran to completion"
+    );
+    let run = answer
+        .as_ref()
+        .map(|result| &result["structuredContent"]["tasks"][0]);
+    ensure!(
+        run.is_ok_and(|run| run["status"] == "cancelled"),
+        "{answer:?}"
+    );
+
+    let audit = fs::read_to_string(file.with_file_name("state").join("mcp-audit.jsonl"))?;
+    let last: Value = serde_json::from_str(audit.lines().last().unwrap_or_default())?;
+    ensure!(last["outcome"]["status"] == "cancelled", "{last}");
+    Ok(())
+}
@@ -1,10 +1,23 @@
-//! Reference TUI built on [`byteowlz_tui_kit`].
+//! Reference TUI built on [`byteowlz_tui_kit`]: a live dashboard over the
+//! rust-core config, tasks, and log file.
 //!
 //! Renders the "modern" byteowlz look: air over borders, weight-based hierarchy, one
 //! accent, a fuzzy command palette, and key progressions with an on-demand WhichKey hint.
 //! New byteowlz TUIs should start from this shape; see the `tui-design` skill.
+//!
+//! Three views share the screen: the effective config with each setting's default and
+//! environment override, the `[tasks]` table with the last outcome `rust-cli run`
+//! recorded for each task, and the tail of `logging.file`. All three follow changes on
+//! disk while the TUI runs. `--config`, `--system`, and `--user` select the same files
+//! as they do for rust-cli.
+
+mod sources;
 
-use std::time::Duration;
+use std::borrow::Cow;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use anyhow::Result;
 use byteowlz_tui_kit::action::{Action, ActionId, Key, KeyRouter, Route};
@@ -13,79 +26,66 @@ use byteowlz_tui_kit::palette::{CommandPalette, PaletteOutcome};
 use byteowlz_tui_kit::prelude::*;
 use byteowlz_tui_kit::terminal::TerminalGuard;
 use byteowlz_tui_kit::whichkey;
-use byteowlz_tui_kit::widgets::{bar, panel};
+use byteowlz_tui_kit::widgets::{bar, draw_empty_state, panel};
 use clap::Parser;
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::Modifier;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{List, ListItem, Padding, Paragraph};
+use ratatui::widgets::{List, ListItem, Padding, Paragraph, Wrap};
+use rust_core::logs::LogRecord;
+use rust_core::task::TaskStatus;
+use rust_core::{
+    APP_NAME, AppPaths, ErrorCode, Event, ExitCode, LogLevel, PathMode, RuntimeConfigExt as _,
+};
+
+use crate::sources::{Setting, Sources, TaskRow};
 
 /// The actions available in Normal mode. Defined as data — adding one never adds a mode.
 fn actions() -> Vec<Action> {
     vec![
         Action::new(ActionId::new("quit"), "Quit").key(Key::ctrl_char('c')),
-        Action::new(ActionId::new("item.open"), "Open in editor").key(Key::enter()),
-        Action::new(ActionId::new("item.delete"), "Delete").key(Key::char('d')),
-        Action::new(ActionId::new("item.add"), "Add new").key(Key::char('a')),
         Action::new(ActionId::new("palette.open"), "Command palette").key(Key::char(':')),
-        Action::new(ActionId::new("help"), "Help").key(Key::char('?')),
-        Action::new(ActionId::new("sort.date"), "Sort by date")
-            .keys(&[Key::char('s'), Key::char('d')]),
-        Action::new(ActionId::new("sort.importance"), "Sort by importance")
-            .keys(&[Key::char('s'), Key::char('i')]),
-        Action::new(ActionId::new("sort.category"), "Sort by category")
-            .keys(&[Key::char('s'), Key::char('c')]),
+        Action::new(ActionId::new("view.next"), "Next view").key(Key::tab()),
+        Action::new(ActionId::new("view.config"), "Go to config")
+            .keys(&[Key::char('g'), Key::char('c')]),
+        Action::new(ActionId::new("view.tasks"), "Go to tasks")
+            .keys(&[Key::char('g'), Key::char('t')]),
+        Action::new(ActionId::new("view.logs"), "Go to logs")
+            .keys(&[Key::char('g'), Key::char('l')]),
+        Action::new(ActionId::new("logs.follow"), "Follow the end of the log").key(Key::char('f')),
+        Action::new(ActionId::new("reload"), "Reload from disk").key(Key::char('r')),
         Action::new(ActionId::new("nav.down"), "Cursor down").key(Key::char('j')),
         Action::new(ActionId::new("nav.up"), "Cursor up").key(Key::char('k')),
     ]
 }
 
-/// One demo item, shaped like a memory entry.
-#[derive(Debug, Clone)]
-struct Item {
-    title: String,
-    meta: String,
-    body: String,
-}
-
-/// Seed items for the demo.
-const SEED: &[(&str, &str, &str)] = &[
-    (
-        "Port cleanup needs wait for exit",
-        "2d · debugging",
-        "Ports linger until the process fully exits; poll until the PID is gone.",
-    ),
-    (
-        "OpenCode PATCH renames session",
-        "3d · debugging",
-        "PATCH /session/{id} accepts {title} to rename a session.",
-    ),
-    (
-        "Voice mode uses eaRS for STT",
-        "5d · architecture",
-        "Voice mode uses eaRS for STT and kokorox for TTS over a WebSocket.",
-    ),
-    (
-        "sqlx offline mode for CI",
-        "1w · tooling",
-        "sqlx offline mode lets CI build without a live database.",
-    ),
-    (
-        "tokio task abort ordering",
-        "1w · patterns",
-        "Aborting a tokio task mid-await can drop guards before completion.",
-    ),
-];
-
-fn seed_items() -> Vec<Item> {
-    SEED.iter()
-        .map(|(title, meta, body)| Item {
-            title: (*title).to_string(),
-            meta: (*meta).to_string(),
-            body: (*body).to_string(),
-        })
-        .collect()
+/// The dashboard's views, in `Tab` order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum View {
+    Config,
+    Tasks,
+    Logs,
+}
+
+impl View {
+    const ALL: [Self; 3] = [Self::Config, Self::Tasks, Self::Logs];
+
+    const fn label(self) -> &'static str {
+        match self {
+            Self::Config => "config",
+            Self::Tasks => "tasks",
+            Self::Logs => "logs",
+        }
+    }
+
+    const fn next(self) -> Self {
+        match self {
+            Self::Config => Self::Tasks,
+            Self::Tasks => Self::Logs,
+            Self::Logs => Self::Config,
+        }
+    }
 }
 
 /// An in-progress key progression and its next-key options, for the WhichKey hint.
@@ -94,8 +94,12 @@ type PendingHint = (Vec<Key>, Vec<(Key, &'static str)>);
 /// The app: state plus the kit pieces it drives.
 struct App {
     theme: Theme,
-    items: Vec<Item>,
-    selection: Selection,
+    sources: Sources,
+    view: View,
+    settings: Selection,
+    tasks: Selection,
+    /// Lines scrolled up from the end of the log; 0 follows new lines.
+    log_scroll: usize,
     router: KeyRouter<'static>,
     status: String,
     palette: Option<CommandPalette>,
@@ -103,29 +107,64 @@ struct App {
 }
 
 impl App {
-    fn new() -> Self {
+    fn new(sources: Sources) -> Self {
         // NOTE: the router borrows the action table for its lifetime. We leak the boxed
         // table to a 'static reference so the borrow is valid for the whole session; the
-        // process is short-lived so this bounded leak is acceptable for a demo.
+        // process is short-lived so this bounded leak is acceptable.
         let actions: &'static [Action] = Box::leak(actions().into_boxed_slice());
-        let items = seed_items();
-        let mut selection = Selection::default();
-        selection.next(items.len());
         Self {
             theme: Theme::ansi_default(),
-            items,
-            selection,
+            status: format!("{} settings", sources.config.settings.len()),
+            sources,
+            view: View::Config,
+            settings: Selection::default(),
+            tasks: Selection::default(),
+            log_scroll: 0,
             router: KeyRouter::new(actions),
-            status: format!("{} items", SEED.len()),
             palette: None,
             pending_hint: None,
         }
     }
+
+    /// Rows in the current view.
+    fn rows(&self) -> usize {
+        match self.view {
+            View::Config => self.sources.config.settings.len(),
+            View::Tasks => self.sources.tasks.rows.len(),
+            View::Logs => self.sources.logs.lines.len(),
+        }
+    }
 }
 
 #[derive(Parser)]
-#[command(name = "rust-tui", version, about = "Reference byteowlz TUI")]
-struct Cli {}
+#[command(
+    name = "rust-tui",
+    version,
+    about = "Live dashboard over the rust-workspace config, tasks, and logs"
+)]
+struct Cli {
+    /// Override the config file path
+    #[arg(long, value_name = "PATH")]
+    config: Option<PathBuf>,
+    /// Use system-wide directories (/etc, /var/lib, /var/cache); the default as root
+    #[arg(long, conflicts_with = "user")]
+    system: bool,
+    /// Use per-user directories even when running as root
+    #[arg(long)]
+    user: bool,
+}
+
+impl Cli {
+    fn path_mode(&self) -> PathMode {
+        if self.system {
+            PathMode::System
+        } else if self.user {
+            PathMode::User
+        } else {
+            PathMode::detect()
+        }
+    }
+}
 
 /// The top-level flow signal.
 enum Flow {
@@ -133,14 +172,57 @@ enum Flow {
     Continue,
 }
 
-fn main() -> Result<()> {
-    let _ = Cli::parse();
+fn main() -> std::process::ExitCode {
+    let cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        Err(err) => {
+            let _ = err.print();
+            let code = if err.use_stderr() {
+                ExitCode::Usage
+            } else {
+                ExitCode::Success
+            };
+            return code.into();
+        }
+    };
+    match run(&cli) {
+        Ok(()) => ExitCode::Success.into(),
+        Err(err) => {
+            // The terminal guard has been dropped by now, so this prints to a
+            // restored terminal.
+            match ErrorCode::from_error(&err) {
+                Some(code) => eprintln!("Error[{code}]: {err:?}"),
+                None => eprintln!("Error: {err:?}"),
+            }
+            ExitCode::from_error(&err).into()
+        }
+    }
+}
+
+/// How long to wait for input before redrawing.
+const TICK: Duration = Duration::from_millis(120);
+
+fn run(cli: &Cli) -> Result<()> {
+    // Read-only: the TUI never creates the config file.
+    let paths = AppPaths::discover_in(cli.path_mode(), cli.config.as_deref())?;
+    let sources = Sources::load(paths)?;
+    let tick = sources.config.config.runtime.poll_interval(TICK);
+    let reloaded = Arc::new(AtomicBool::new(false));
+    let flag = Arc::clone(&reloaded);
+    sources.events.subscribe(move |event| {
+        if matches!(event, Event::ConfigReloaded { .. }) {
+            flag.store(true, Ordering::Relaxed);
+        }
+    });
     let mut guard = TerminalGuard::enter()?;
-    let mut app = App::new();
-    let tick = Duration::from_millis(120);
+    let mut app = App::new(sources);
     loop {
+        if reloaded.swap(false, Ordering::Relaxed) {
+            app.status = "config reloaded from disk".to_string();
+        }
         guard.draw(|frame| draw(frame, &mut app))?;
         let Some(event) = poll_event(tick)? else {
+            app.sources.refresh();
             continue;
         };
         if matches!(handle(event, &mut app), Flow::Quit) {
@@ -154,7 +236,10 @@ fn main() -> Result<()> {
 fn handle(event: AppEvent, app: &mut App) -> Flow {
     let key = match event {
         AppEvent::Key(key) => key,
-        AppEvent::Tick | AppEvent::Resize(_, _) => return Flow::Continue,
+        AppEvent::Tick | AppEvent::Resize(_, _) => {
+            app.sources.refresh();
+            return Flow::Continue;
+        }
     };
     if app.palette.is_some() {
         return handle_palette(key, app);
@@ -193,6 +278,11 @@ fn handle_normal(key: Key, app: &mut App) -> Flow {
         app.router.reset();
         return Flow::Continue;
     }
+    let key = match key {
+        k if k == Key::down() => Key::char('j'),
+        k if k == Key::up() => Key::char('k'),
+        k => k,
+    };
     match app.router.feed(key) {
         Route::Action(id) => {
             app.pending_hint = None;
@@ -209,32 +299,75 @@ fn handle_normal(key: Key, app: &mut App) -> Flow {
     Flow::Continue
 }
 
-/// Apply an action by id (navigation + the few that mutate state).
+/// Apply an action by id.
 fn apply_action(id: ActionId, app: &mut App) {
-    let max = app.items.len();
-    let moved = match id {
-        x if x == ActionId::new("nav.down") => {
-            app.selection.next(max);
-            true
+    let rows = app.rows();
+    let view = match id {
+        x if x == ActionId::new("view.next") => Some(app.view.next()),
+        x if x == ActionId::new("view.config") => Some(View::Config),
+        x if x == ActionId::new("view.tasks") => Some(View::Tasks),
+        x if x == ActionId::new("view.logs") => Some(View::Logs),
+        _ => None,
+    };
+    if let Some(view) = view {
+        app.view = view;
+        app.status = view_status(app);
+        return;
+    }
+    match id {
+        x if x == ActionId::new("nav.down") => match app.view {
+            View::Config => app.settings.next(rows),
+            View::Tasks => app.tasks.next(rows),
+            View::Logs => app.log_scroll = app.log_scroll.saturating_sub(1),
+        },
+        x if x == ActionId::new("nav.up") => match app.view {
+            View::Config => app.settings.previous(rows),
+            View::Tasks => app.tasks.previous(rows),
+            View::Logs => app.log_scroll = (app.log_scroll + 1).min(rows.saturating_sub(1)),
+        },
+        x if x == ActionId::new("logs.follow") => {
+            app.view = View::Logs;
+            app.log_scroll = 0;
+            app.status = "following the log".to_string();
         }
-        x if x == ActionId::new("nav.up") => {
-            app.selection.previous(max);
-            true
+        x if x == ActionId::new("reload") => {
+            app.status = if app.sources.refresh() {
+                "reloaded".to_string()
+            } else {
+                "nothing changed on disk".to_string()
+            };
         }
-        _ => false,
-    };
-    if !moved && id != ActionId::new("quit") {
-        app.status = format!("ran: {id}");
+        _ => {}
+    }
+}
+
+/// Status-line summary of the current view.
+fn view_status(app: &App) -> String {
+    match app.view {
+        View::Config => format!("{} settings", app.sources.config.settings.len()),
+        View::Tasks => format!("{} tasks", app.sources.tasks.rows.len()),
+        View::Logs => app.sources.logs.path.as_ref().map_or_else(
+            || "logging.file is not set".to_string(),
+            |path| {
+                format!(
+                    "{} lines of {}",
+                    app.sources.logs.lines.len(),
+                    path.display()
+                )
+            },
+        ),
     }
 }
 
 /// Render one frame.
 fn draw(frame: &mut Frame<'_>, app: &mut App) {
     let [header_area, main_area, status_area, hint_area] = layout(frame);
-    let [list_area, detail_area] = body(main_area);
     draw_header(frame, app, header_area);
-    draw_list(frame, app, list_area);
-    draw_detail(frame, app, detail_area);
+    match app.view {
+        View::Config => draw_config(frame, app, main_area),
+        View::Tasks => draw_tasks(frame, app, main_area),
+        View::Logs => draw_logs(frame, app, main_area),
+    }
     draw_status_row(frame, app, status_area);
     if let Some((prefix, options)) = &app.pending_hint {
         whichkey::draw_hint(frame, hint_area, app.theme, prefix, options);
@@ -271,95 +404,322 @@ fn body(area: Rect) -> [Rect; 2] {
     [chunks[0], chunks[2]]
 }
 
-/// Draw the header strip: app name + context, on a filled bar.
+/// Draw the header strip: app name, the views, and the profile, on a filled bar.
 fn draw_header(frame: &mut Frame<'_>, app: &App, area: Rect) {
     let theme = app.theme;
-    let line = Line::from(vec![
-        Span::styled(" memory ", theme.on_bar_bold(Token::Accent)),
-        Span::styled("  ·  ", theme.on_bar(Token::Muted)),
-        Span::styled("default store", theme.on_bar(Token::Muted)),
-    ]);
-    frame.render_widget(bar(line, theme), area);
+    let mut spans = vec![
+        Span::styled(format!(" {APP_NAME} "), theme.on_bar_bold(Token::Accent)),
+        Span::styled(" ", theme.on_bar(Token::Muted)),
+    ];
+    for view in View::ALL {
+        let style = if view == app.view {
+            theme.on_bar_bold(Token::Primary)
+        } else {
+            theme.on_bar(Token::Muted)
+        };
+        spans.push(Span::styled(format!(" {} ", view.label()), style));
+    }
+    let paths = &app.sources.paths;
+    spans.push(Span::styled(
+        format!(
+            "  ·  profile {}  ·  {}",
+            app.sources.config.config.profile,
+            paths.mode.name()
+        ),
+        theme.on_bar(Token::Muted),
+    ));
+    frame.render_widget(bar(Line::from(spans), theme), area);
 }
 
-/// Draw the status line: counts on the left, key hints on the right.
+/// Draw the status line: view summary on the left, key hints on the right.
 fn draw_status_row(frame: &mut Frame<'_>, app: &App, area: Rect) {
-    let left = if app.selection.has_selection() {
-        format!(
-            "{} · {} of {} selected",
-            app.status,
-            app.selection.selected().len(),
-            app.items.len()
-        )
-    } else {
-        format!("{} · {} items", app.status, app.items.len())
-    };
-    let hints: [(&str, &str); 6] = [
-        ("Ret", "open"),
-        ("d", "delete"),
-        ("s", "sort"),
+    let left = app.sources.config.error.as_ref().map_or_else(
+        || app.status.clone(),
+        |err| format!("config not reloaded: {err}"),
+    );
+    let hints: [(&str, &str); 5] = [
+        ("Tab", "view"),
+        ("g", "go to"),
+        ("r", "reload"),
         (":", "commands"),
-        ("?", "help"),
         ("q", "quit"),
     ];
     draw_status_bar(frame, area, app.theme, &left, &hints);
 }
 
-/// Draw the item list inside an active titled panel.
-fn draw_list(frame: &mut Frame<'_>, app: &App, area: Rect) {
+/// Draw the config view: settings on the left, the selected one on the right.
+fn draw_config(frame: &mut Frame<'_>, app: &App, area: Rect) {
     let theme = app.theme;
-    let items: Vec<ListItem<'_>> = app
-        .items
+    let [list_area, detail_area] = body(area);
+    let settings = &app.sources.config.settings;
+    let items: Vec<ListItem<'_>> = settings
         .iter()
         .enumerate()
-        .map(|(i, item)| list_row(i, item, app, theme))
+        .map(|(i, setting)| {
+            let marker = if setting.env.1.is_some() {
+                "● "
+            } else {
+                "  "
+            };
+            list_row(marker, &setting.key, i == app.settings.index(), theme)
+        })
         .collect();
-    let list = List::new(items).highlight_style(theme.focus().add_modifier(Modifier::BOLD));
-    let block = panel("items", theme, true);
-    let mut state = app.selection.state();
-    frame.render_stateful_widget(list.block(block), area, &mut state);
+    render_list(frame, list_area, items, &app.settings, "settings", theme);
+
+    let block = panel("setting", theme, false).padding(Padding::uniform(1));
+    let Some(setting) = settings.get(app.settings.index()) else {
+        draw_empty_state(frame, detail_area, theme, "No settings.");
+        return;
+    };
+    let lines = setting_detail(setting, theme);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(block),
+        detail_area,
+    );
+}
+
+/// Detail lines for one setting.
+fn setting_detail(setting: &Setting, theme: Theme) -> Vec<Line<'_>> {
+    let (env, env_value) = &setting.env;
+    let mut lines = vec![
+        Line::from(Span::styled(&setting.key, theme.fg_bold(Token::Primary))),
+        Line::from(""),
+        field_line("value", setting.value.clone(), theme),
+        field_line(
+            "default",
+            setting
+                .default
+                .clone()
+                .unwrap_or_else(|| "none".to_string()),
+            theme,
+        ),
+    ];
+    match env_value {
+        Some(value) => lines.push(Line::from(vec![
+            Span::styled(format!("{:<9}", "env"), theme.fg(Token::Muted)),
+            Span::styled(format!("{env}={value}"), theme.fg(Token::Warning)),
+            Span::styled("  (overrides the file)", theme.fg(Token::Muted)),
+        ])),
+        None => lines.push(field_line("env", env.clone(), theme)),
+    }
+    if let Some(description) = &setting.description {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            description,
+            theme.fg(Token::Muted),
+        )));
+    }
+    lines
 }
 
-/// Build one list row.
-fn list_row<'a>(index: usize, item: &'a Item, app: &App, theme: Theme) -> ListItem<'a> {
-    let marker = if app.selection.is_selected(index) {
-        "● "
+/// Draw the task view: tasks with their last outcome, and the selected one.
+fn draw_tasks(frame: &mut Frame<'_>, app: &App, area: Rect) {
+    let theme = app.theme;
+    let rows = &app.sources.tasks.rows;
+    if rows.is_empty() {
+        draw_empty_state(
+            frame,
+            area,
+            theme,
+            "No tasks — define them in the config's [tasks] table.",
+        );
+        return;
+    }
+    let [list_area, detail_area] = body(area);
+    let items: Vec<ListItem<'_>> = rows
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let (marker, token) = row
+                .last
+                .as_ref()
+                .map_or(("  ", Token::Muted), |last| status_marker(last.run.status));
+            ListItem::new(Line::from(vec![
+                Span::styled(marker, theme.fg(token)),
+                Span::styled(&row.name, row_style(i == app.tasks.index(), theme)),
+            ]))
+        })
+        .collect();
+    render_list(frame, list_area, items, &app.tasks, "tasks", theme);
+
+    let block = panel("task", theme, false).padding(Padding::uniform(1));
+    if let Some(row) = rows.get(app.tasks.index()) {
+        frame.render_widget(
+            Paragraph::new(task_detail(row, theme))
+                .wrap(Wrap { trim: false })
+                .block(block),
+            detail_area,
+        );
+    }
+}
+
+/// Detail lines for one task.
+fn task_detail(row: &TaskRow, theme: Theme) -> Vec<Line<'_>> {
+    let task = &row.task;
+    let runs = task.command.as_ref().map_or_else(
+        || {
+            task.builtin.as_ref().map_or_else(
+                || "nothing (groups its dependencies)".to_string(),
+                |builtin| format!("rust-cli {builtin}"),
+            )
+        },
+        Clone::clone,
+    );
+    let mut lines = vec![
+        Line::from(Span::styled(&row.name, theme.fg_bold(Token::Primary))),
+        Line::from(""),
+        field_line("runs", runs, theme),
+    ];
+    if !task.depends_on.is_empty() {
+        lines.push(field_line("after", task.depends_on.join(", "), theme));
+    }
+    if let Some(cwd) = &task.cwd {
+        lines.push(field_line("cwd", cwd.clone(), theme));
+    }
+    if let Some(timeout) = task.timeout {
+        lines.push(field_line("timeout", format!("{timeout}s"), theme));
+    }
+    lines.push(Line::from(""));
+    let Some(last) = &row.last else {
+        lines.push(Line::from(Span::styled(
+            format!("never run — `rust-cli run {}`", row.name),
+            theme.fg(Token::Muted),
+        )));
+        return lines;
+    };
+    let (_, token) = status_marker(last.run.status);
+    lines.push(Line::from(vec![
+        Span::styled(format!("{:<9}", "last run"), theme.fg(Token::Muted)),
+        Span::styled(last.run.status.to_string(), theme.fg_bold(token)),
+        Span::styled(
+            format!(
+                "  {}ms, {} ago",
+                last.run.duration_ms,
+                ago(last.finished_at)
+            ),
+            theme.fg(Token::Muted),
+        ),
+    ]));
+    if let Some(code) = last.run.exit_code {
+        lines.push(field_line("exit", code.to_string(), theme));
+    }
+    if let Some(dir) = &last.run.work_dir {
+        let dir = rust_core::pathfmt::display(dir).to_string();
+        lines.push(field_line("ran in", dir, theme));
+    }
+    if let Some(message) = &last.run.message {
+        lines.push(Line::from(Span::styled(message, theme.fg(token))));
+    }
+    lines
+}
+
+/// List marker and color for a task outcome.
+const fn status_marker(status: TaskStatus) -> (&'static str, Token) {
+    match status {
+        TaskStatus::Succeeded => ("✓ ", Token::Success),
+        TaskStatus::Failed | TaskStatus::TimedOut => ("✗ ", Token::Danger),
+        TaskStatus::Skipped | TaskStatus::Cancelled => ("- ", Token::Warning),
+    }
+}
+
+/// Draw the log view: the end of `logging.file`, newest at the bottom.
+fn draw_logs(frame: &mut Frame<'_>, app: &App, area: Rect) {
+    let theme = app.theme;
+    let logs = &app.sources.logs;
+    let Some(path) = &logs.path else {
+        draw_empty_state(
+            frame,
+            area,
+            theme,
+            "No log file — set logging.file in the config to follow rust-cli's log here.",
+        );
+        return;
+    };
+    let title = if app.log_scroll == 0 {
+        format!("{} (following)", path.display())
     } else {
-        "  "
+        format!("{} ({} lines up)", path.display(), app.log_scroll)
     };
-    let style = if index == app.selection.index() {
-        theme.focus()
+    let block = panel(&title, theme, true);
+    let height = usize::from(block.inner(area).height);
+    let end = logs.lines.len().saturating_sub(app.log_scroll);
+    let lines: Vec<Line<'_>> = logs
+        .lines
+        .iter()
+        .take(end)
+        .skip(end.saturating_sub(height))
+        .map(|line| log_line(line, theme))
+        .collect();
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// One log line, colored by its level. JSON records are shown the way text
+/// ones are written.
+fn log_line(line: &str, theme: Theme) -> Line<'_> {
+    let record = LogRecord::parse(line);
+    let token = match record.level {
+        Some(LogLevel::Error) => Token::Danger,
+        Some(LogLevel::Warn) => Token::Warning,
+        Some(LogLevel::Debug | LogLevel::Trace) => Token::Muted,
+        Some(LogLevel::Info) | None => Token::Primary,
+    };
+    let text = if line.starts_with('{') && !record.is_continuation() {
+        Cow::Owned(record.to_string())
     } else {
-        theme.fg(Token::Primary)
+        Cow::Borrowed(line)
     };
-    let line = Line::from(vec![
+    Line::from(Span::styled(text, theme.fg(token)))
+}
+
+/// Render a list of rows in a titled, active panel.
+fn render_list(
+    frame: &mut Frame<'_>,
+    area: Rect,
+    items: Vec<ListItem<'_>>,
+    selection: &Selection,
+    title: &str,
+    theme: Theme,
+) {
+    let list = List::new(items).highlight_style(theme.focus().add_modifier(Modifier::BOLD));
+    let mut state = selection.state();
+    frame.render_stateful_widget(list.block(panel(title, theme, true)), area, &mut state);
+}
+
+/// One list row: a marker and a label.
+fn list_row<'a>(marker: &'a str, label: &'a str, focused: bool, theme: Theme) -> ListItem<'a> {
+    ListItem::new(Line::from(vec![
         Span::styled(marker, theme.fg(Token::Accent)),
-        Span::styled(&item.title, style),
-    ]);
-    ListItem::new(line)
+        Span::styled(label, row_style(focused, theme)),
+    ]))
 }
 
-/// Draw the detail pane — progressive disclosure, inside a titled panel.
-fn draw_detail(frame: &mut Frame<'_>, app: &App, area: Rect) {
-    let theme = app.theme;
-    let idx = app.selection.index() % app.items.len();
-    let item = &app.items[idx];
-    let lines = vec![
-        Line::from(Span::styled(
-            format!("MEMORY · {}", item.meta),
-            theme.fg(Token::Muted),
-        )),
-        Line::from(""),
-        Line::from(Span::styled(&item.title, theme.fg_bold(Token::Primary))),
-        Line::from(Span::styled(&item.meta, theme.fg(Token::Muted))),
-        Line::from(""),
-        Line::from(Span::styled(&item.body, theme.fg(Token::Muted))),
-        Line::from(""),
-        Line::from(Span::styled(
-            "Ret open full editor",
-            theme.fg(Token::Accent),
-        )),
-    ];
-    let block = panel("detail", theme, false).padding(Padding::uniform(1));
-    frame.render_widget(Paragraph::new(lines).block(block), area);
+const fn row_style(focused: bool, theme: Theme) -> ratatui::style::Style {
+    if focused {
+        theme.focus()
+    } else {
+        theme.fg(Token::Primary)
+    }
+}
+
+/// A `name  value` detail line.
+fn field_line(name: &str, value: String, theme: Theme) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(format!("{name:<9}"), theme.fg(Token::Muted)),
+        Span::styled(value, theme.fg(Token::Primary)),
+    ])
+}
+
+/// How long ago `secs` (since the Unix epoch) was, roughly.
+fn ago(secs: u64) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    match now.saturating_sub(secs) {
+        s if s < 60 => format!("{s}s"),
+        s if s < 3600 => format!("{}m", s / 60),
+        s if s < 86_400 => format!("{}h", s / 3600),
+        s => format!("{}d", s / 86_400),
+    }
 }
@@ -0,0 +1,285 @@
+//! What the dashboard shows, read from the same files rust-cli uses.
+//!
+//! Each source is cheap to refresh on every tick: files are only re-read
+//! when their modification time changes, and the log tail only reads what
+//! was appended since the last look.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::fs::{self, File};
+use std::io::{Read as _, Seek as _, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use anyhow::{Context as _, Result};
+use rust_core::task::{self, LastRun};
+use rust_core::ui_spec::ui_spec;
+use rust_core::{
+    APP_NAME, AppConfig, AppConfigExt as _, AppPaths, ConfigEditor, Event, EventBus, TaskConfig,
+    generate_schema,
+};
+
+/// Lines of log kept in memory.
+const LOG_LINES: usize = 1000;
+
+/// One setting in the config view.
+#[derive(Debug, Clone)]
+pub struct Setting {
+    /// Dotted key, e.g. `runtime.timeout`.
+    pub key: String,
+    /// What the setting does.
+    pub description: Option<String>,
+    /// Value from the config file, or the default.
+    pub value: String,
+    /// Documented default.
+    pub default: Option<String>,
+    /// Environment variable that overrides the setting, and its value if set.
+    pub env: (String, Option<String>),
+}
+
+/// The effective config and its settings, one row per key.
+#[derive(Debug)]
+pub struct ConfigSource {
+    /// The loaded config, environment overrides included.
+    pub config: AppConfig,
+    /// Rows for the config view, in schema order.
+    pub settings: Vec<Setting>,
+    /// Why the last reload failed; the previous config stays shown.
+    pub error: Option<String>,
+    modified: Option<SystemTime>,
+}
+
+impl ConfigSource {
+    /// Load the config file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config cannot be loaded or the schema
+    /// cannot be described.
+    pub fn load(path: &Path) -> Result<Self> {
+        Ok(Self {
+            config: AppConfig::load_from_path(path)?,
+            settings: settings(path)?,
+            error: None,
+            modified: modified(path),
+        })
+    }
+
+    /// Reload if the file changed since the last load. Returns whether it
+    /// did.
+    pub fn refresh(&mut self, path: &Path) -> bool {
+        let now = modified(path);
+        if now == self.modified {
+            return false;
+        }
+        self.modified = now;
+        match Self::load(path) {
+            Ok(fresh) => *self = fresh,
+            Err(err) => self.error = Some(format!("{err:#}")),
+        }
+        true
+    }
+}
+
+/// Rows for every setting of the schema, valued from `config_file`.
+fn settings(config_file: &Path) -> Result<Vec<Setting>> {
+    let schema = serde_json::from_str(&generate_schema(APP_NAME)?)
+        .context("parsing generated JSON schema")?;
+    let spec = ui_spec(&schema, APP_NAME)?;
+    let editor = ConfigEditor::open(config_file)?;
+    Ok(spec
+        .groups
+        .into_iter()
+        .flat_map(|group| group.fields)
+        .map(|field| Setting {
+            value: editor
+                .get(&field.key)
+                .map_or_else(|| "unset".to_string(), ToString::to_string),
+            default: field.default.map(|value| value.to_string()),
+            env: (
+                field.env.clone(),
+                std::env::var(&field.env)
+                    .ok()
+                    .filter(|value| !value.is_empty()),
+            ),
+            description: field.description,
+            key: field.key,
+        })
+        .collect())
+}
+
+/// One task in the task view.
+#[derive(Debug, Clone)]
+pub struct TaskRow {
+    /// Task name.
+    pub name: String,
+    /// How it is defined.
+    pub task: TaskConfig,
+    /// Its last recorded outcome, from `rust-cli run`.
+    pub last: Option<LastRun>,
+}
+
+/// The `[tasks]` table and the last outcome of each task.
+#[derive(Debug, Default)]
+pub struct TaskSource {
+    /// Rows for the task view, by name.
+    pub rows: Vec<TaskRow>,
+    history: BTreeMap<String, LastRun>,
+    modified: Option<SystemTime>,
+}
+
+impl TaskSource {
+    /// Rebuild the rows from `tasks`, re-reading the history in
+    /// `state_dir` if it changed. Returns whether the history changed.
+    pub fn refresh(&mut self, tasks: &BTreeMap<String, TaskConfig>, state_dir: &Path) -> bool {
+        let now = modified(&state_dir.join(task::HISTORY_FILENAME));
+        let changed = now != self.modified;
+        if changed {
+            self.modified = now;
+            // A half-written or corrupt history shows as no history.
+            self.history = task::history(state_dir).unwrap_or_default();
+        }
+        self.rows = tasks
+            .iter()
+            .map(|(name, task)| TaskRow {
+                name: name.clone(),
+                task: task.clone(),
+                last: self.history.get(name).cloned(),
+            })
+            .collect();
+        changed
+    }
+}
+
+/// The end of the `logging.file`, followed as it grows.
+#[derive(Debug, Default)]
+pub struct LogTail {
+    /// The followed file, if `logging.file` is set.
+    pub path: Option<PathBuf>,
+    /// The last [`LOG_LINES`] lines.
+    pub lines: VecDeque<String>,
+    offset: u64,
+    partial: String,
+}
+
+impl LogTail {
+    /// Follow `path` from now on, starting over if it is a different file.
+    pub fn follow(&mut self, path: Option<PathBuf>) {
+        if path != self.path {
+            *self = Self {
+                path,
+                ..Self::default()
+            };
+        }
+    }
+
+    /// Read what was appended since the last call. A file that shrank was
+    /// truncated or replaced, so it is read again from the start. Returns
+    /// whether new lines arrived.
+    pub fn refresh(&mut self) -> bool {
+        let Some(path) = &self.path else {
+            return false;
+        };
+        let Ok(mut file) = File::open(path) else {
+            return false;
+        };
+        let len = file.metadata().map_or(0, |meta| meta.len());
+        if len < self.offset {
+            self.offset = 0;
+            self.partial.clear();
+            self.lines.clear();
+        }
+        if len == self.offset || file.seek(SeekFrom::Start(self.offset)).is_err() {
+            return false;
+        }
+        let mut bytes = Vec::new();
+        if file.read_to_end(&mut bytes).is_err() {
+            return false;
+        }
+        self.offset += bytes.len() as u64;
+        self.partial.push_str(&String::from_utf8_lossy(&bytes));
+        let Some(end) = self.partial.rfind('\n') else {
+            return false;
+        };
+        let complete: String = self.partial.drain(..=end).collect();
+        self.lines.extend(complete.lines().map(str::to_string));
+        while self.lines.len() > LOG_LINES {
+            self.lines.pop_front();
+        }
+        true
+    }
+}
+
+/// Everything the dashboard shows.
+#[derive(Debug)]
+pub struct Sources {
+    /// Resolved directories and config file, with the config's `[paths]`
+    /// overrides applied.
+    pub paths: AppPaths,
+    /// The paths as discovered, before the overrides.
+    discovered: AppPaths,
+    /// The config view.
+    pub config: ConfigSource,
+    /// The task view.
+    pub tasks: TaskSource,
+    /// The log view.
+    pub logs: LogTail,
+    /// Where a config reload is announced.
+    pub events: EventBus,
+}
+
+impl Sources {
+    /// Load everything for `paths`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config cannot be loaded or its `[paths]`
+    /// overrides cannot be expanded.
+    pub fn load(discovered: AppPaths) -> Result<Self> {
+        let config = ConfigSource::load(&discovered.config_file)?;
+        let mut sources = Self {
+            paths: discovered.clone().apply_overrides(&config.config)?,
+            discovered,
+            config,
+            tasks: TaskSource::default(),
+            logs: LogTail::default(),
+            events: EventBus::default(),
+        };
+        sources.refresh_dependents();
+        Ok(sources)
+    }
+
+    /// Pick up changes on disk. Returns whether anything changed.
+    pub fn refresh(&mut self) -> bool {
+        let mut changed = self.config.refresh(&self.paths.config_file);
+        if changed {
+            if self.config.error.is_none() {
+                match self.discovered.clone().apply_overrides(&self.config.config) {
+                    Ok(paths) => self.paths = paths,
+                    Err(err) => self.config.error = Some(format!("{err:#}")),
+                }
+                self.events.publish(&Event::ConfigReloaded {
+                    path: self.paths.config_file.clone(),
+                });
+            }
+            self.refresh_dependents();
+        } else {
+            changed |= self
+                .tasks
+                .refresh(&self.config.config.tasks, &self.paths.state_dir);
+        }
+        changed | self.logs.refresh()
+    }
+
+    /// Refresh the sources that depend on the config.
+    fn refresh_dependents(&mut self) {
+        self.tasks
+            .refresh(&self.config.config.tasks, &self.paths.state_dir);
+        self.logs
+            .follow(self.config.config.logging.file.as_ref().map(PathBuf::from));
+        self.logs.refresh();
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
@@ -0,0 +1,3076 @@
+
+using namespace System.Management.Automation
+using namespace System.Management.Automation.Language
+
+Register-ArgumentCompleter -Native -CommandName 'rust-cli' -ScriptBlock {
+    param($wordToComplete, $commandAst, $cursorPosition)
+
+    $commandElements = $commandAst.CommandElements
+    $command = @(
+        'rust-cli'
+        for ($i = 1; $i -lt $commandElements.Count; $i++) {
+            $element = $commandElements[$i]
+            if ($element -isnot [StringConstantExpressionAst] -or
+                $element.StringConstantType -ne [StringConstantType]::BareWord -or
+                $element.Value.StartsWith('-') -or
+                $element.Value -eq $wordToComplete) {
+                break
+        }
+        $element.Value
+    }) -join ';'
+
+    $completions = @(switch ($command) {
+        'rust-cli' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('run', 'run', [CompletionResultType]::ParameterValue, 'Execute the CLI''s primary behavior')
+            [CompletionResult]::new('init', 'init', [CompletionResultType]::ParameterValue, 'Create config directories and default files (overwrite with --force)')
+            [CompletionResult]::new('config', 'config', [CompletionResultType]::ParameterValue, 'Inspect and manage configuration')
+            [CompletionResult]::new('paths', 'paths', [CompletionResultType]::ParameterValue, 'Diagnose the config, data, state, and cache directories')
+            [CompletionResult]::new('healthcheck', 'healthcheck', [CompletionResultType]::ParameterValue, 'Probe config, state directory, and daemon; exit 0 if healthy, 1 if not')
+            [CompletionResult]::new('history', 'history', [CompletionResultType]::ParameterValue, 'List, show, and clear the recorded `run` invocations')
+            [CompletionResult]::new('logs', 'logs', [CompletionResultType]::ParameterValue, 'Show the log file (and its rotated copies), filtered by time and level')
+            [CompletionResult]::new('cache', 'cache', [CompletionResultType]::ParameterValue, 'Inspect and repair the content cache')
+            [CompletionResult]::new('clean', 'clean', [CompletionResultType]::ParameterValue, 'Remove the cache, log file, run history, leftovers, or state by category')
+            [CompletionResult]::new('backup', 'backup', [CompletionResultType]::ParameterValue, 'Back up, verify, and restore the config, state, and data directories')
+            [CompletionResult]::new('sync', 'sync', [CompletionResultType]::ParameterValue, 'Share portable settings between machines through a git remote')
+            [CompletionResult]::new('self-update', 'self-update', [CompletionResultType]::ParameterValue, 'Replace this binary with the latest release (checksum-verified; needs --yes)')
+            [CompletionResult]::new('release', 'release', [CompletionResultType]::ParameterValue, 'Maintainer tooling for cutting releases')
+            [CompletionResult]::new('dev', 'dev', [CompletionResultType]::ParameterValue, 'Maintainer tooling for working on this template')
+            [CompletionResult]::new('debug', 'debug', [CompletionResultType]::ParameterValue, 'Build and runtime facts for bug reports')
+            [CompletionResult]::new('generate', 'generate', [CompletionResultType]::ParameterValue, 'Write or verify generated artifacts (schema, example config, man page, completions, docs)')
+            [CompletionResult]::new('serve', 'serve', [CompletionResultType]::ParameterValue, 'Host a server in this process (`serve mcp`: the rust-mcp server, no second binary needed)')
+            [CompletionResult]::new('mcp', 'mcp', [CompletionResultType]::ParameterValue, 'Talk to an MCP server as a client: list tools, call one, read a resource')
+            [CompletionResult]::new('schema', 'schema', [CompletionResultType]::ParameterValue, 'Publish the config schema to the URL its $id points at')
+            [CompletionResult]::new('alias', 'alias', [CompletionResultType]::ParameterValue, 'Inspect the command aliases defined in [aliases]')
+            [CompletionResult]::new('plugin', 'plugin', [CompletionResultType]::ParameterValue, 'Inspect external subcommands: rust-cli-<name> executables on PATH run as `rust-cli <name>`')
+            [CompletionResult]::new('explain', 'explain', [CompletionResultType]::ParameterValue, 'Explain an error code (e.g. E0002): causes and how to fix it; lists all codes without one')
+            [CompletionResult]::new('version', 'version', [CompletionResultType]::ParameterValue, 'Print the version, commit, build date, target, rustc, and features')
+            [CompletionResult]::new('completions', 'completions', [CompletionResultType]::ParameterValue, 'Generate shell completions')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;run' {
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Override the profile to run under')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--when-idle', '--when-idle', [CompletionResultType]::ParameterName, 'Wait until the machine is idle by the [idle] thresholds before starting')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;init' {
+            [CompletionResult]::new('--schema', '--schema', [CompletionResultType]::ParameterName, 'Where the config''s #:schema directive points, for editor validation')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;config' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Output the effective configuration')
+            [CompletionResult]::new('import', 'import', [CompletionResultType]::ParameterValue, 'Set the settings listed in a file in the config file, keeping its comments')
+            [CompletionResult]::new('path', 'path', [CompletionResultType]::ParameterValue, 'Print the resolved config file path')
+            [CompletionResult]::new('paths', 'paths', [CompletionResultType]::ParameterValue, 'Print all resolved paths (config, data, state, cache)')
+            [CompletionResult]::new('schema', 'schema', [CompletionResultType]::ParameterValue, 'Print the JSON schema for the config file')
+            [CompletionResult]::new('docs', 'docs', [CompletionResultType]::ParameterValue, 'Print a Markdown reference of every setting: type, default, and description')
+            [CompletionResult]::new('reset', 'reset', [CompletionResultType]::ParameterValue, 'Regenerate the default configuration file')
+            [CompletionResult]::new('set-schema', 'set-schema', [CompletionResultType]::ParameterValue, 'Point the config''s #:schema directive at the published schema or a local copy')
+            [CompletionResult]::new('migrate', 'migrate', [CompletionResultType]::ParameterValue, 'Upgrade the config file to this build''s format version, keeping comments')
+            [CompletionResult]::new('export', 'export', [CompletionResultType]::ParameterValue, 'Render the effective configuration as environment variables for a deployment target')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;config;show' {
+            [CompletionResult]::new('--format', '--format', [CompletionResultType]::ParameterName, 'Text form: TOML, or flat `dotted.key = value` lines sorted by key for grepping and diffing')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--show-secrets', '--show-secrets', [CompletionResultType]::ParameterName, 'Print secret values as they are instead of masking them (keys naming a token, password, secret, key, ... or matching security.redact); the text form is then the loaded config''s debug dump rather than TOML')
+            [CompletionResult]::new('--redact', '--redact', [CompletionResultType]::ParameterName, 'Mask secret values, as is the default (kept for scripts that pass it)')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;config;import' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;config;path' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;config;paths' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;config;schema' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;config;docs' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;config;reset' {
+            [CompletionResult]::new('--schema', '--schema', [CompletionResultType]::ParameterName, 'Where the config''s #:schema directive points, for editor validation')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;config;set-schema' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;config;migrate' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;config;export' {
+            [CompletionResult]::new('--format', '--format', [CompletionResultType]::ParameterName, 'Output format; secret-bearing keys are emitted as references, not values')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;config;help' {
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Output the effective configuration')
+            [CompletionResult]::new('import', 'import', [CompletionResultType]::ParameterValue, 'Set the settings listed in a file in the config file, keeping its comments')
+            [CompletionResult]::new('path', 'path', [CompletionResultType]::ParameterValue, 'Print the resolved config file path')
+            [CompletionResult]::new('paths', 'paths', [CompletionResultType]::ParameterValue, 'Print all resolved paths (config, data, state, cache)')
+            [CompletionResult]::new('schema', 'schema', [CompletionResultType]::ParameterValue, 'Print the JSON schema for the config file')
+            [CompletionResult]::new('docs', 'docs', [CompletionResultType]::ParameterValue, 'Print a Markdown reference of every setting: type, default, and description')
+            [CompletionResult]::new('reset', 'reset', [CompletionResultType]::ParameterValue, 'Regenerate the default configuration file')
+            [CompletionResult]::new('set-schema', 'set-schema', [CompletionResultType]::ParameterValue, 'Point the config''s #:schema directive at the published schema or a local copy')
+            [CompletionResult]::new('migrate', 'migrate', [CompletionResultType]::ParameterValue, 'Upgrade the config file to this build''s format version, keeping comments')
+            [CompletionResult]::new('export', 'export', [CompletionResultType]::ParameterValue, 'Render the effective configuration as environment variables for a deployment target')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;config;help;show' {
+            break
+        }
+        'rust-cli;config;help;import' {
+            break
+        }
+        'rust-cli;config;help;path' {
+            break
+        }
+        'rust-cli;config;help;paths' {
+            break
+        }
+        'rust-cli;config;help;schema' {
+            break
+        }
+        'rust-cli;config;help;docs' {
+            break
+        }
+        'rust-cli;config;help;reset' {
+            break
+        }
+        'rust-cli;config;help;set-schema' {
+            break
+        }
+        'rust-cli;config;help;migrate' {
+            break
+        }
+        'rust-cli;config;help;export' {
+            break
+        }
+        'rust-cli;config;help;help' {
+            break
+        }
+        'rust-cli;paths' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('doctor', 'doctor', [CompletionResultType]::ParameterValue, 'Report existence, permissions, ownership, and disk usage of each directory, and any interrupted operation')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;paths;doctor' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--roll-forward', '--roll-forward', [CompletionResultType]::ParameterName, 'Finish an interrupted operation by installing the rest of its files')
+            [CompletionResult]::new('--revert', '--revert', [CompletionResultType]::ParameterName, 'Undo an interrupted operation by restoring the files it replaced')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;paths;help' {
+            [CompletionResult]::new('doctor', 'doctor', [CompletionResultType]::ParameterValue, 'Report existence, permissions, ownership, and disk usage of each directory, and any interrupted operation')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;paths;help;doctor' {
+            break
+        }
+        'rust-cli;paths;help;help' {
+            break
+        }
+        'rust-cli;healthcheck' {
+            [CompletionResult]::new('--probe', '--probe', [CompletionResultType]::ParameterName, 'Probes to run instead of `health.probes` (repeat or comma-separate)')
+            [CompletionResult]::new('--daemon', '--daemon', [CompletionResultType]::ParameterName, 'Daemon to probe instead of `health.daemon`: HOST:PORT or a Unix socket path')
+            [CompletionResult]::new('--timeout-ms', '--timeout-ms', [CompletionResultType]::ParameterName, 'Time limit for the whole check instead of `health.timeout_ms`')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;history' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List recorded runs, newest first')
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show one run with the outcome of each of its tasks')
+            [CompletionResult]::new('clear', 'clear', [CompletionResultType]::ParameterValue, 'Remove every recorded run')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;history;list' {
+            [CompletionResult]::new('--limit', '--limit', [CompletionResultType]::ParameterName, 'Show at most N runs')
+            [CompletionResult]::new('--task', '--task', [CompletionResultType]::ParameterName, 'Only show runs of this task')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;history;show' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;history;clear' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;history;help' {
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List recorded runs, newest first')
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show one run with the outcome of each of its tasks')
+            [CompletionResult]::new('clear', 'clear', [CompletionResultType]::ParameterValue, 'Remove every recorded run')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;history;help;list' {
+            break
+        }
+        'rust-cli;history;help;show' {
+            break
+        }
+        'rust-cli;history;help;clear' {
+            break
+        }
+        'rust-cli;history;help;help' {
+            break
+        }
+        'rust-cli;logs' {
+            [CompletionResult]::new('--since', '--since', [CompletionResultType]::ParameterName, 'Only records since WHEN: a duration ago (30m, 1h, 2d) or an RFC 3339 time')
+            [CompletionResult]::new('--level', '--level', [CompletionResultType]::ParameterName, 'Only records at LEVEL or more severe')
+            [CompletionResult]::new('-n', '-n', [CompletionResultType]::ParameterName, 'Show only the last N matching records (before following)')
+            [CompletionResult]::new('--lines', '--lines', [CompletionResultType]::ParameterName, 'Show only the last N matching records (before following)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('-f', '-f', [CompletionResultType]::ParameterName, 'Keep printing records as they are appended, until interrupted')
+            [CompletionResult]::new('--follow', '--follow', [CompletionResultType]::ParameterName, 'Keep printing records as they are appended, until interrupted')
+            [CompletionResult]::new('--audit', '--audit', [CompletionResultType]::ParameterName, 'Show the MCP server''s audit log of tool calls instead of logging.file')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;cache' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('stats', 'stats', [CompletionResultType]::ParameterValue, 'Show size, entry counts, and hit/miss counters per namespace')
+            [CompletionResult]::new('clear', 'clear', [CompletionResultType]::ParameterValue, 'Remove entries selected by namespace, age, and key pattern')
+            [CompletionResult]::new('verify', 'verify', [CompletionResultType]::ParameterValue, 'Check the cache index and every entry''s checksum')
+            [CompletionResult]::new('repair', 'repair', [CompletionResultType]::ParameterValue, 'Drop corrupt index records, damaged entries, and orphaned files')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;cache;stats' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;cache;clear' {
+            [CompletionResult]::new('--namespace', '--namespace', [CompletionResultType]::ParameterName, 'Only clear entries in this namespace (the key prefix before `/`)')
+            [CompletionResult]::new('--older-than', '--older-than', [CompletionResultType]::ParameterName, 'Only clear entries stored longer ago than AGE (e.g. 30m, 12h, 7d)')
+            [CompletionResult]::new('--pattern', '--pattern', [CompletionResultType]::ParameterName, 'Only clear keys matching GLOB (`*` and `?` wildcards)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;cache;verify' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;cache;repair' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;cache;help' {
+            [CompletionResult]::new('stats', 'stats', [CompletionResultType]::ParameterValue, 'Show size, entry counts, and hit/miss counters per namespace')
+            [CompletionResult]::new('clear', 'clear', [CompletionResultType]::ParameterValue, 'Remove entries selected by namespace, age, and key pattern')
+            [CompletionResult]::new('verify', 'verify', [CompletionResultType]::ParameterValue, 'Check the cache index and every entry''s checksum')
+            [CompletionResult]::new('repair', 'repair', [CompletionResultType]::ParameterValue, 'Drop corrupt index records, damaged entries, and orphaned files')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;cache;help;stats' {
+            break
+        }
+        'rust-cli;cache;help;clear' {
+            break
+        }
+        'rust-cli;cache;help;verify' {
+            break
+        }
+        'rust-cli;cache;help;repair' {
+            break
+        }
+        'rust-cli;cache;help;help' {
+            break
+        }
+        'rust-cli;clean' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--all', '--all', [CompletionResultType]::ParameterName, 'Remove every category below')
+            [CompletionResult]::new('--cache', '--cache', [CompletionResultType]::ParameterName, 'Cache entries, index, and counters')
+            [CompletionResult]::new('--logs', '--logs', [CompletionResultType]::ParameterName, 'The log file named by `logging.file`')
+            [CompletionResult]::new('--runs', '--runs', [CompletionResultType]::ParameterName, 'Run history and the last outcome of each task')
+            [CompletionResult]::new('--artifacts', '--artifacts', [CompletionResultType]::ParameterName, 'Temporary directories and partial files left by interrupted operations')
+            [CompletionResult]::new('--state', '--state', [CompletionResultType]::ParameterName, 'Everything else in the state directory, such as the journal')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;backup' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('create', 'create', [CompletionResultType]::ParameterValue, 'Archive the config and state (and optionally data) directories')
+            [CompletionResult]::new('restore', 'restore', [CompletionResultType]::ParameterValue, 'Verify an archive and restore its files into place')
+            [CompletionResult]::new('verify', 'verify', [CompletionResultType]::ParameterValue, 'Check every file in an archive against its manifest')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;backup;create' {
+            [CompletionResult]::new('-f', '-f', [CompletionResultType]::ParameterName, 'Archive to write (default: ./<app>-backup-<timestamp>.tar.zst)')
+            [CompletionResult]::new('--file', '--file', [CompletionResultType]::ParameterName, 'Archive to write (default: ./<app>-backup-<timestamp>.tar.zst)')
+            [CompletionResult]::new('--exclude', '--exclude', [CompletionResultType]::ParameterName, 'Leave out archive paths matching GLOB (e.g. ''state/*.log''); repeatable, added to `backup.exclude`')
+            [CompletionResult]::new('--level', '--level', [CompletionResultType]::ParameterName, 'zstd compression level (1-22; overrides `backup.compression_level`)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--include-data', '--include-data', [CompletionResultType]::ParameterName, 'Include the data directory')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;backup;restore' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;backup;verify' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;backup;help' {
+            [CompletionResult]::new('create', 'create', [CompletionResultType]::ParameterValue, 'Archive the config and state (and optionally data) directories')
+            [CompletionResult]::new('restore', 'restore', [CompletionResultType]::ParameterValue, 'Verify an archive and restore its files into place')
+            [CompletionResult]::new('verify', 'verify', [CompletionResultType]::ParameterValue, 'Check every file in an archive against its manifest')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;backup;help;create' {
+            break
+        }
+        'rust-cli;backup;help;restore' {
+            break
+        }
+        'rust-cli;backup;help;verify' {
+            break
+        }
+        'rust-cli;backup;help;help' {
+            break
+        }
+        'rust-cli;sync' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('init', 'init', [CompletionResultType]::ParameterValue, 'Set up the sync repository and point it at a git remote')
+            [CompletionResult]::new('push', 'push', [CompletionResultType]::ParameterValue, 'Send local setting changes to the remote')
+            [CompletionResult]::new('pull', 'pull', [CompletionResultType]::ParameterValue, 'Merge setting changes from the remote into the local config')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;sync;init' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;sync;push' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;sync;pull' {
+            [CompletionResult]::new('--prefer', '--prefer', [CompletionResultType]::ParameterName, 'Resolve settings changed on both sides by keeping this side''s value')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;sync;help' {
+            [CompletionResult]::new('init', 'init', [CompletionResultType]::ParameterValue, 'Set up the sync repository and point it at a git remote')
+            [CompletionResult]::new('push', 'push', [CompletionResultType]::ParameterValue, 'Send local setting changes to the remote')
+            [CompletionResult]::new('pull', 'pull', [CompletionResultType]::ParameterValue, 'Merge setting changes from the remote into the local config')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;sync;help;init' {
+            break
+        }
+        'rust-cli;sync;help;push' {
+            break
+        }
+        'rust-cli;sync;help;pull' {
+            break
+        }
+        'rust-cli;sync;help;help' {
+            break
+        }
+        'rust-cli;self-update' {
+            [CompletionResult]::new('--tag', '--tag', [CompletionResultType]::ParameterName, 'Install this release tag (e.g. v1.2.0) instead of the latest, even if it is not newer')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--check', '--check', [CompletionResultType]::ParameterName, 'Only report whether a newer release exists')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;release' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('stamp', 'stamp', [CompletionResultType]::ParameterValue, 'Set the version across the workspace and regenerate all artifacts')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;release;stamp' {
+            [CompletionResult]::new('--root', '--root', [CompletionResultType]::ParameterName, 'Workspace root (defaults to the nearest workspace above the current directory)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;release;help' {
+            [CompletionResult]::new('stamp', 'stamp', [CompletionResultType]::ParameterValue, 'Set the version across the workspace and regenerate all artifacts')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;release;help;stamp' {
+            break
+        }
+        'rust-cli;release;help;help' {
+            break
+        }
+        'rust-cli;dev' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('watch-generate', 'watch-generate', [CompletionResultType]::ParameterValue, 'Regenerate schema, example config, and docs whenever their sources change')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;dev;watch-generate' {
+            [CompletionResult]::new('--root', '--root', [CompletionResultType]::ParameterName, 'Workspace root (defaults to the nearest workspace above the current directory)')
+            [CompletionResult]::new('--interval', '--interval', [CompletionResultType]::ParameterName, 'How often to check the sources for changes (e.g. 500ms, 2s)')
+            [CompletionResult]::new('--cooldown', '--cooldown', [CompletionResultType]::ParameterName, 'Least time between two regenerations (e.g. 10s, 1m)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;dev;help' {
+            [CompletionResult]::new('watch-generate', 'watch-generate', [CompletionResultType]::ParameterValue, 'Regenerate schema, example config, and docs whenever their sources change')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;dev;help;watch-generate' {
+            break
+        }
+        'rust-cli;dev;help;help' {
+            break
+        }
+        'rust-cli;debug' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('features', 'features', [CompletionResultType]::ParameterValue, 'List optional subsystems: compiled into this build, enabled by the config')
+            [CompletionResult]::new('cli-spec', 'cli-spec', [CompletionResultType]::ParameterValue, 'Describe every command and argument as JSON for wrapper and docs generators')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;debug;features' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;debug;cli-spec' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;debug;help' {
+            [CompletionResult]::new('features', 'features', [CompletionResultType]::ParameterValue, 'List optional subsystems: compiled into this build, enabled by the config')
+            [CompletionResult]::new('cli-spec', 'cli-spec', [CompletionResultType]::ParameterValue, 'Describe every command and argument as JSON for wrapper and docs generators')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;debug;help;features' {
+            break
+        }
+        'rust-cli;debug;help;cli-spec' {
+            break
+        }
+        'rust-cli;debug;help;help' {
+            break
+        }
+        'rust-cli;generate' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('all', 'all', [CompletionResultType]::ParameterValue, 'Regenerate every artifact and rewrite the manifest')
+            [CompletionResult]::new('verify', 'verify', [CompletionResultType]::ParameterValue, 'Check that committed artifacts match what would be generated now')
+            [CompletionResult]::new('ui-spec', 'ui-spec', [CompletionResultType]::ParameterValue, 'Print the settings UI description derived from the config schema')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;generate;all' {
+            [CompletionResult]::new('--root', '--root', [CompletionResultType]::ParameterName, 'Artifact root (defaults to the nearest workspace above the current directory)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;generate;verify' {
+            [CompletionResult]::new('--root', '--root', [CompletionResultType]::ParameterName, 'Artifact root (defaults to the nearest workspace above the current directory)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;generate;ui-spec' {
+            [CompletionResult]::new('--format', '--format', [CompletionResultType]::ParameterName, 'Serialization of the description')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;generate;help' {
+            [CompletionResult]::new('all', 'all', [CompletionResultType]::ParameterValue, 'Regenerate every artifact and rewrite the manifest')
+            [CompletionResult]::new('verify', 'verify', [CompletionResultType]::ParameterValue, 'Check that committed artifacts match what would be generated now')
+            [CompletionResult]::new('ui-spec', 'ui-spec', [CompletionResultType]::ParameterValue, 'Print the settings UI description derived from the config schema')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;generate;help;all' {
+            break
+        }
+        'rust-cli;generate;help;verify' {
+            break
+        }
+        'rust-cli;generate;help;ui-spec' {
+            break
+        }
+        'rust-cli;generate;help;help' {
+            break
+        }
+        'rust-cli;serve' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('mcp', 'mcp', [CompletionResultType]::ParameterValue, 'Serve this CLI''s operations to agents over MCP, as the rust-mcp binary does')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;serve;mcp' {
+            [CompletionResult]::new('--transport', '--transport', [CompletionResultType]::ParameterName, 'How clients reach the server')
+            [CompletionResult]::new('--bind', '--bind', [CompletionResultType]::ParameterName, 'Address to listen on with `--transport http`')
+            [CompletionResult]::new('--max-sessions', '--max-sessions', [CompletionResultType]::ParameterName, 'Most clients connected at once with `--transport http`; more are refused with 503')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;serve;help' {
+            [CompletionResult]::new('mcp', 'mcp', [CompletionResultType]::ParameterValue, 'Serve this CLI''s operations to agents over MCP, as the rust-mcp binary does')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;serve;help;mcp' {
+            break
+        }
+        'rust-cli;serve;help;help' {
+            break
+        }
+        'rust-cli;mcp' {
+            [CompletionResult]::new('--url', '--url', [CompletionResultType]::ParameterName, 'Streamable HTTP endpoint of the server, e.g. `http://127.0.0.1:3001/mcp`')
+            [CompletionResult]::new('--token', '--token', [CompletionResultType]::ParameterName, 'Bearer token for --url')
+            [CompletionResult]::new('--stdio', '--stdio', [CompletionResultType]::ParameterName, 'Command line starting a stdio server, split like a shell would [default: this binary''s `serve mcp`]')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('list-tools', 'list-tools', [CompletionResultType]::ParameterValue, 'List the server''s tools with their descriptions')
+            [CompletionResult]::new('call', 'call', [CompletionResultType]::ParameterValue, 'Call a tool and print its result')
+            [CompletionResult]::new('read-resource', 'read-resource', [CompletionResultType]::ParameterValue, 'Read a resource and print its contents')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;mcp;list-tools' {
+            [CompletionResult]::new('--url', '--url', [CompletionResultType]::ParameterName, 'Streamable HTTP endpoint of the server, e.g. `http://127.0.0.1:3001/mcp`')
+            [CompletionResult]::new('--token', '--token', [CompletionResultType]::ParameterName, 'Bearer token for --url')
+            [CompletionResult]::new('--stdio', '--stdio', [CompletionResultType]::ParameterName, 'Command line starting a stdio server, split like a shell would [default: this binary''s `serve mcp`]')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;mcp;call' {
+            [CompletionResult]::new('--arg', '--arg', [CompletionResultType]::ParameterName, 'Argument to pass (repeatable); VALUE is JSON when it parses as JSON, else a string, e.g. `message=hi` or `tasks=''["build"]''`')
+            [CompletionResult]::new('--url', '--url', [CompletionResultType]::ParameterName, 'Streamable HTTP endpoint of the server, e.g. `http://127.0.0.1:3001/mcp`')
+            [CompletionResult]::new('--token', '--token', [CompletionResultType]::ParameterName, 'Bearer token for --url')
+            [CompletionResult]::new('--stdio', '--stdio', [CompletionResultType]::ParameterName, 'Command line starting a stdio server, split like a shell would [default: this binary''s `serve mcp`]')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;mcp;read-resource' {
+            [CompletionResult]::new('--url', '--url', [CompletionResultType]::ParameterName, 'Streamable HTTP endpoint of the server, e.g. `http://127.0.0.1:3001/mcp`')
+            [CompletionResult]::new('--token', '--token', [CompletionResultType]::ParameterName, 'Bearer token for --url')
+            [CompletionResult]::new('--stdio', '--stdio', [CompletionResultType]::ParameterName, 'Command line starting a stdio server, split like a shell would [default: this binary''s `serve mcp`]')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;mcp;help' {
+            [CompletionResult]::new('list-tools', 'list-tools', [CompletionResultType]::ParameterValue, 'List the server''s tools with their descriptions')
+            [CompletionResult]::new('call', 'call', [CompletionResultType]::ParameterValue, 'Call a tool and print its result')
+            [CompletionResult]::new('read-resource', 'read-resource', [CompletionResultType]::ParameterValue, 'Read a resource and print its contents')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;mcp;help;list-tools' {
+            break
+        }
+        'rust-cli;mcp;help;call' {
+            break
+        }
+        'rust-cli;mcp;help;read-resource' {
+            break
+        }
+        'rust-cli;mcp;help;help' {
+            break
+        }
+        'rust-cli;schema' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('publish', 'publish', [CompletionResultType]::ParameterValue, 'Publish the config schema to the schemas repository and check that its $id URL resolves')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;schema;publish' {
+            [CompletionResult]::new('--to', '--to', [CompletionResultType]::ParameterName, 'Git remote to commit the schema to, or an http(s) URL to PUT it under')
+            [CompletionResult]::new('--branch', '--branch', [CompletionResultType]::ParameterName, 'Branch to commit to (git destinations only)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--check', '--check', [CompletionResultType]::ParameterName, 'Only check that the $id URL serves the current schema')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;schema;help' {
+            [CompletionResult]::new('publish', 'publish', [CompletionResultType]::ParameterValue, 'Publish the config schema to the schemas repository and check that its $id URL resolves')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;schema;help;publish' {
+            break
+        }
+        'rust-cli;schema;help;help' {
+            break
+        }
+        'rust-cli;alias' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List the aliases defined in the config and what they expand to')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;alias;list' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;alias;help' {
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List the aliases defined in the config and what they expand to')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;alias;help;list' {
+            break
+        }
+        'rust-cli;alias;help;help' {
+            break
+        }
+        'rust-cli;plugin' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List the external subcommands (rust-cli-<name> executables) on PATH')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;plugin;list' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;plugin;help' {
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List the external subcommands (rust-cli-<name> executables) on PATH')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;plugin;help;list' {
+            break
+        }
+        'rust-cli;plugin;help;help' {
+            break
+        }
+        'rust-cli;explain' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;version' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;completions' {
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Override the config file path')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output format for command results')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'Control color output (auto, always, never)')
+            [CompletionResult]::new('--log-format', '--log-format', [CompletionResultType]::ParameterName, 'Log record format; overrides `logging.format`')
+            [CompletionResult]::new('--set', '--set', [CompletionResultType]::ParameterName, 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Maximum seconds a command (or each task of `run`) may take')
+            [CompletionResult]::new('--parallel', '--parallel', [CompletionResultType]::ParameterName, 'Override the degree of parallelism')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'Wait up to SECONDS for another instance to release the lock')
+            [CompletionResult]::new('--no-config', '--no-config', [CompletionResultType]::ParameterName, 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Reduce output to only errors')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Increase logging verbosity (stackable)')
+            [CompletionResult]::new('--debug', '--debug', [CompletionResultType]::ParameterName, 'Enable debug logging (equivalent to -vv)')
+            [CompletionResult]::new('--trace', '--trace', [CompletionResultType]::ParameterName, 'Enable trace logging (overrides other levels)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Alias for --output json')
+            [CompletionResult]::new('--yaml', '--yaml', [CompletionResultType]::ParameterName, 'Alias for --output yaml')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable ANSI colors in output')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Do not change anything on disk')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Assume "yes" for interactive prompts')
+            [CompletionResult]::new('--no-input', '--no-input', [CompletionResultType]::ParameterName, 'Never prompt for input; fail if confirmation would be required')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Print long output directly instead of through `$PAGER`/less')
+            [CompletionResult]::new('--no-progress', '--no-progress', [CompletionResultType]::ParameterName, 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)')
+            [CompletionResult]::new('--diagnostics', '--diagnostics', [CompletionResultType]::ParameterName, 'Emit additional diagnostics for troubleshooting')
+            [CompletionResult]::new('--stats', '--stats', [CompletionResultType]::ParameterName, 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran')
+            [CompletionResult]::new('--no-update-check', '--no-update-check', [CompletionResultType]::ParameterName, 'Do not look for a newer release in the background (see `[updates]`)')
+            [CompletionResult]::new('--no-lock', '--no-lock', [CompletionResultType]::ParameterName, 'Skip the single-instance lock (concurrent runs may corrupt state)')
+            [CompletionResult]::new('--system', '--system', [CompletionResultType]::ParameterName, 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root')
+            [CompletionResult]::new('--user', '--user', [CompletionResultType]::ParameterName, 'Use per-user directories even when running as root')
+            [CompletionResult]::new('--entrypoint', '--entrypoint', [CompletionResultType]::ParameterName, 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            break
+        }
+        'rust-cli;help' {
+            [CompletionResult]::new('run', 'run', [CompletionResultType]::ParameterValue, 'Execute the CLI''s primary behavior')
+            [CompletionResult]::new('init', 'init', [CompletionResultType]::ParameterValue, 'Create config directories and default files (overwrite with --force)')
+            [CompletionResult]::new('config', 'config', [CompletionResultType]::ParameterValue, 'Inspect and manage configuration')
+            [CompletionResult]::new('paths', 'paths', [CompletionResultType]::ParameterValue, 'Diagnose the config, data, state, and cache directories')
+            [CompletionResult]::new('healthcheck', 'healthcheck', [CompletionResultType]::ParameterValue, 'Probe config, state directory, and daemon; exit 0 if healthy, 1 if not')
+            [CompletionResult]::new('history', 'history', [CompletionResultType]::ParameterValue, 'List, show, and clear the recorded `run` invocations')
+            [CompletionResult]::new('logs', 'logs', [CompletionResultType]::ParameterValue, 'Show the log file (and its rotated copies), filtered by time and level')
+            [CompletionResult]::new('cache', 'cache', [CompletionResultType]::ParameterValue, 'Inspect and repair the content cache')
+            [CompletionResult]::new('clean', 'clean', [CompletionResultType]::ParameterValue, 'Remove the cache, log file, run history, leftovers, or state by category')
+            [CompletionResult]::new('backup', 'backup', [CompletionResultType]::ParameterValue, 'Back up, verify, and restore the config, state, and data directories')
+            [CompletionResult]::new('sync', 'sync', [CompletionResultType]::ParameterValue, 'Share portable settings between machines through a git remote')
+            [CompletionResult]::new('self-update', 'self-update', [CompletionResultType]::ParameterValue, 'Replace this binary with the latest release (checksum-verified; needs --yes)')
+            [CompletionResult]::new('release', 'release', [CompletionResultType]::ParameterValue, 'Maintainer tooling for cutting releases')
+            [CompletionResult]::new('dev', 'dev', [CompletionResultType]::ParameterValue, 'Maintainer tooling for working on this template')
+            [CompletionResult]::new('debug', 'debug', [CompletionResultType]::ParameterValue, 'Build and runtime facts for bug reports')
+            [CompletionResult]::new('generate', 'generate', [CompletionResultType]::ParameterValue, 'Write or verify generated artifacts (schema, example config, man page, completions, docs)')
+            [CompletionResult]::new('serve', 'serve', [CompletionResultType]::ParameterValue, 'Host a server in this process (`serve mcp`: the rust-mcp server, no second binary needed)')
+            [CompletionResult]::new('mcp', 'mcp', [CompletionResultType]::ParameterValue, 'Talk to an MCP server as a client: list tools, call one, read a resource')
+            [CompletionResult]::new('schema', 'schema', [CompletionResultType]::ParameterValue, 'Publish the config schema to the URL its $id points at')
+            [CompletionResult]::new('alias', 'alias', [CompletionResultType]::ParameterValue, 'Inspect the command aliases defined in [aliases]')
+            [CompletionResult]::new('plugin', 'plugin', [CompletionResultType]::ParameterValue, 'Inspect external subcommands: rust-cli-<name> executables on PATH run as `rust-cli <name>`')
+            [CompletionResult]::new('explain', 'explain', [CompletionResultType]::ParameterValue, 'Explain an error code (e.g. E0002): causes and how to fix it; lists all codes without one')
+            [CompletionResult]::new('version', 'version', [CompletionResultType]::ParameterValue, 'Print the version, commit, build date, target, rustc, and features')
+            [CompletionResult]::new('completions', 'completions', [CompletionResultType]::ParameterValue, 'Generate shell completions')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rust-cli;help;run' {
+            break
+        }
+        'rust-cli;help;init' {
+            break
+        }
+        'rust-cli;help;config' {
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Output the effective configuration')
+            [CompletionResult]::new('import', 'import', [CompletionResultType]::ParameterValue, 'Set the settings listed in a file in the config file, keeping its comments')
+            [CompletionResult]::new('path', 'path', [CompletionResultType]::ParameterValue, 'Print the resolved config file path')
+            [CompletionResult]::new('paths', 'paths', [CompletionResultType]::ParameterValue, 'Print all resolved paths (config, data, state, cache)')
+            [CompletionResult]::new('schema', 'schema', [CompletionResultType]::ParameterValue, 'Print the JSON schema for the config file')
+            [CompletionResult]::new('docs', 'docs', [CompletionResultType]::ParameterValue, 'Print a Markdown reference of every setting: type, default, and description')
+            [CompletionResult]::new('reset', 'reset', [CompletionResultType]::ParameterValue, 'Regenerate the default configuration file')
+            [CompletionResult]::new('set-schema', 'set-schema', [CompletionResultType]::ParameterValue, 'Point the config''s #:schema directive at the published schema or a local copy')
+            [CompletionResult]::new('migrate', 'migrate', [CompletionResultType]::ParameterValue, 'Upgrade the config file to this build''s format version, keeping comments')
+            [CompletionResult]::new('export', 'export', [CompletionResultType]::ParameterValue, 'Render the effective configuration as environment variables for a deployment target')
+            break
+        }
+        'rust-cli;help;config;show' {
+            break
+        }
+        'rust-cli;help;config;import' {
+            break
+        }
+        'rust-cli;help;config;path' {
+            break
+        }
+        'rust-cli;help;config;paths' {
+            break
+        }
+        'rust-cli;help;config;schema' {
+            break
+        }
+        'rust-cli;help;config;docs' {
+            break
+        }
+        'rust-cli;help;config;reset' {
+            break
+        }
+        'rust-cli;help;config;set-schema' {
+            break
+        }
+        'rust-cli;help;config;migrate' {
+            break
+        }
+        'rust-cli;help;config;export' {
+            break
+        }
+        'rust-cli;help;paths' {
+            [CompletionResult]::new('doctor', 'doctor', [CompletionResultType]::ParameterValue, 'Report existence, permissions, ownership, and disk usage of each directory, and any interrupted operation')
+            break
+        }
+        'rust-cli;help;paths;doctor' {
+            break
+        }
+        'rust-cli;help;healthcheck' {
+            break
+        }
+        'rust-cli;help;history' {
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List recorded runs, newest first')
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show one run with the outcome of each of its tasks')
+            [CompletionResult]::new('clear', 'clear', [CompletionResultType]::ParameterValue, 'Remove every recorded run')
+            break
+        }
+        'rust-cli;help;history;list' {
+            break
+        }
+        'rust-cli;help;history;show' {
+            break
+        }
+        'rust-cli;help;history;clear' {
+            break
+        }
+        'rust-cli;help;logs' {
+            break
+        }
+        'rust-cli;help;cache' {
+            [CompletionResult]::new('stats', 'stats', [CompletionResultType]::ParameterValue, 'Show size, entry counts, and hit/miss counters per namespace')
+            [CompletionResult]::new('clear', 'clear', [CompletionResultType]::ParameterValue, 'Remove entries selected by namespace, age, and key pattern')
+            [CompletionResult]::new('verify', 'verify', [CompletionResultType]::ParameterValue, 'Check the cache index and every entry''s checksum')
+            [CompletionResult]::new('repair', 'repair', [CompletionResultType]::ParameterValue, 'Drop corrupt index records, damaged entries, and orphaned files')
+            break
+        }
+        'rust-cli;help;cache;stats' {
+            break
+        }
+        'rust-cli;help;cache;clear' {
+            break
+        }
+        'rust-cli;help;cache;verify' {
+            break
+        }
+        'rust-cli;help;cache;repair' {
+            break
+        }
+        'rust-cli;help;clean' {
+            break
+        }
+        'rust-cli;help;backup' {
+            [CompletionResult]::new('create', 'create', [CompletionResultType]::ParameterValue, 'Archive the config and state (and optionally data) directories')
+            [CompletionResult]::new('restore', 'restore', [CompletionResultType]::ParameterValue, 'Verify an archive and restore its files into place')
+            [CompletionResult]::new('verify', 'verify', [CompletionResultType]::ParameterValue, 'Check every file in an archive against its manifest')
+            break
+        }
+        'rust-cli;help;backup;create' {
+            break
+        }
+        'rust-cli;help;backup;restore' {
+            break
+        }
+        'rust-cli;help;backup;verify' {
+            break
+        }
+        'rust-cli;help;sync' {
+            [CompletionResult]::new('init', 'init', [CompletionResultType]::ParameterValue, 'Set up the sync repository and point it at a git remote')
+            [CompletionResult]::new('push', 'push', [CompletionResultType]::ParameterValue, 'Send local setting changes to the remote')
+            [CompletionResult]::new('pull', 'pull', [CompletionResultType]::ParameterValue, 'Merge setting changes from the remote into the local config')
+            break
+        }
+        'rust-cli;help;sync;init' {
+            break
+        }
+        'rust-cli;help;sync;push' {
+            break
+        }
+        'rust-cli;help;sync;pull' {
+            break
+        }
+        'rust-cli;help;self-update' {
+            break
+        }
+        'rust-cli;help;release' {
+            [CompletionResult]::new('stamp', 'stamp', [CompletionResultType]::ParameterValue, 'Set the version across the workspace and regenerate all artifacts')
+            break
+        }
+        'rust-cli;help;release;stamp' {
+            break
+        }
+        'rust-cli;help;dev' {
+            [CompletionResult]::new('watch-generate', 'watch-generate', [CompletionResultType]::ParameterValue, 'Regenerate schema, example config, and docs whenever their sources change')
+            break
+        }
+        'rust-cli;help;dev;watch-generate' {
+            break
+        }
+        'rust-cli;help;debug' {
+            [CompletionResult]::new('features', 'features', [CompletionResultType]::ParameterValue, 'List optional subsystems: compiled into this build, enabled by the config')
+            [CompletionResult]::new('cli-spec', 'cli-spec', [CompletionResultType]::ParameterValue, 'Describe every command and argument as JSON for wrapper and docs generators')
+            break
+        }
+        'rust-cli;help;debug;features' {
+            break
+        }
+        'rust-cli;help;debug;cli-spec' {
+            break
+        }
+        'rust-cli;help;generate' {
+            [CompletionResult]::new('all', 'all', [CompletionResultType]::ParameterValue, 'Regenerate every artifact and rewrite the manifest')
+            [CompletionResult]::new('verify', 'verify', [CompletionResultType]::ParameterValue, 'Check that committed artifacts match what would be generated now')
+            [CompletionResult]::new('ui-spec', 'ui-spec', [CompletionResultType]::ParameterValue, 'Print the settings UI description derived from the config schema')
+            break
+        }
+        'rust-cli;help;generate;all' {
+            break
+        }
+        'rust-cli;help;generate;verify' {
+            break
+        }
+        'rust-cli;help;generate;ui-spec' {
+            break
+        }
+        'rust-cli;help;serve' {
+            [CompletionResult]::new('mcp', 'mcp', [CompletionResultType]::ParameterValue, 'Serve this CLI''s operations to agents over MCP, as the rust-mcp binary does')
+            break
+        }
+        'rust-cli;help;serve;mcp' {
+            break
+        }
+        'rust-cli;help;mcp' {
+            [CompletionResult]::new('list-tools', 'list-tools', [CompletionResultType]::ParameterValue, 'List the server''s tools with their descriptions')
+            [CompletionResult]::new('call', 'call', [CompletionResultType]::ParameterValue, 'Call a tool and print its result')
+            [CompletionResult]::new('read-resource', 'read-resource', [CompletionResultType]::ParameterValue, 'Read a resource and print its contents')
+            break
+        }
+        'rust-cli;help;mcp;list-tools' {
+            break
+        }
+        'rust-cli;help;mcp;call' {
+            break
+        }
+        'rust-cli;help;mcp;read-resource' {
+            break
+        }
+        'rust-cli;help;schema' {
+            [CompletionResult]::new('publish', 'publish', [CompletionResultType]::ParameterValue, 'Publish the config schema to the schemas repository and check that its $id URL resolves')
+            break
+        }
+        'rust-cli;help;schema;publish' {
+            break
+        }
+        'rust-cli;help;alias' {
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List the aliases defined in the config and what they expand to')
+            break
+        }
+        'rust-cli;help;alias;list' {
+            break
+        }
+        'rust-cli;help;plugin' {
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List the external subcommands (rust-cli-<name> executables) on PATH')
+            break
+        }
+        'rust-cli;help;plugin;list' {
+            break
+        }
+        'rust-cli;help;explain' {
+            break
+        }
+        'rust-cli;help;version' {
+            break
+        }
+        'rust-cli;help;completions' {
+            break
+        }
+        'rust-cli;help;help' {
+            break
+        }
+    })
+
+    $completions.Where{ $_.CompletionText -like "$wordToComplete*" } |
+        Sort-Object -Property ListItemText
+}
@@ -0,0 +1,2696 @@
+# Print an optspec for argparse to handle cmd's options that are independent of any subcommand.
+function __fish_rust_cli_global_optspecs
+	string join \n config= no-config q/quiet v/verbose debug trace output= json yaml no-color color= log-format= dry-run y/yes no-input set= timeout= parallel= no-pager no-progress diagnostics stats wait= no-update-check no-lock system user entrypoint h/help V/version
+end
+
+function __fish_rust_cli_needs_command
+	# Figure out if the current invocation already has a command.
+	set -l cmd (commandline -opc)
+	set -e cmd[1]
+	argparse -s (__fish_rust_cli_global_optspecs) -- $cmd 2>/dev/null
+	or return
+	if set -q argv[1]
+		# Also print the command, so this can be used to figure out what it is.
+		echo $argv[1]
+		return 1
+	end
+	return 0
+end
+
+function __fish_rust_cli_using_subcommand
+	set -l cmd (__fish_rust_cli_needs_command)
+	test -z "$cmd"
+	and return 1
+	contains -- $cmd[1] $argv
+end
+
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "run" -d 'Execute the CLI\'s primary behavior'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "init" -d 'Create config directories and default files (overwrite with --force)'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "config" -d 'Inspect and manage configuration'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "paths" -d 'Diagnose the config, data, state, and cache directories'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "healthcheck" -d 'Probe config, state directory, and daemon; exit 0 if healthy, 1 if not'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "history" -d 'List, show, and clear the recorded `run` invocations'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "logs" -d 'Show the log file (and its rotated copies), filtered by time and level'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "cache" -d 'Inspect and repair the content cache'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "clean" -d 'Remove the cache, log file, run history, leftovers, or state by category'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "backup" -d 'Back up, verify, and restore the config, state, and data directories'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "sync" -d 'Share portable settings between machines through a git remote'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "self-update" -d 'Replace this binary with the latest release (checksum-verified; needs --yes)'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "release" -d 'Maintainer tooling for cutting releases'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "dev" -d 'Maintainer tooling for working on this template'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "debug" -d 'Build and runtime facts for bug reports'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "generate" -d 'Write or verify generated artifacts (schema, example config, man page, completions, docs)'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "serve" -d 'Host a server in this process (`serve mcp`: the rust-mcp server, no second binary needed)'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "mcp" -d 'Talk to an MCP server as a client: list tools, call one, read a resource'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "schema" -d 'Publish the config schema to the URL its $id points at'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "alias" -d 'Inspect the command aliases defined in [aliases]'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "plugin" -d 'Inspect external subcommands: rust-cli-<name> executables on PATH run as `rust-cli <name>`'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "explain" -d 'Explain an error code (e.g. E0002): causes and how to fix it; lists all codes without one'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "version" -d 'Print the version, commit, build date, target, rustc, and features'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "completions" -d 'Generate shell completions'
+complete -c rust-cli -n "__fish_rust_cli_needs_command" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l profile -d 'Override the profile to run under' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l when-idle -d 'Wait until the machine is idle by the [idle] thresholds before starting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l schema -d 'Where the config\'s #:schema directive points, for editor validation' -r -f -a "remote\t'The published schema URL; editors need network access'
+local\t'config.schema.json written next to the config file, by relative path'
+file-url\t'config.schema.json written next to the config file, by file:// URL'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand init" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -f -a "show" -d 'Output the effective configuration'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -f -a "import" -d 'Set the settings listed in a file in the config file, keeping its comments'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -f -a "path" -d 'Print the resolved config file path'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -f -a "paths" -d 'Print all resolved paths (config, data, state, cache)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -f -a "schema" -d 'Print the JSON schema for the config file'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -f -a "docs" -d 'Print a Markdown reference of every setting: type, default, and description'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -f -a "reset" -d 'Regenerate the default configuration file'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -f -a "set-schema" -d 'Point the config\'s #:schema directive at the published schema or a local copy'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -f -a "migrate" -d 'Upgrade the config file to this build\'s format version, keeping comments'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -f -a "export" -d 'Render the effective configuration as environment variables for a deployment target'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and not __fish_seen_subcommand_from show import path paths schema docs reset set-schema migrate export help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l format -d 'Text form: TOML, or flat `dotted.key = value` lines sorted by key for grepping and diffing' -r -f -a "toml\t'A TOML document, like the config file'
+flat\t'`dotted.key = value` lines sorted by key, like `git config --list`'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l show-secrets -d 'Print secret values as they are instead of masking them (keys naming a token, password, secret, key, ... or matching security.redact); the text form is then the loaded config\'s debug dump rather than TOML'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l redact -d 'Mask secret values, as is the default (kept for scripts that pass it)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from show" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from import" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from path" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from paths" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from schema" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from docs" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l schema -d 'Where the config\'s #:schema directive points, for editor validation' -r -f -a "remote\t'The published schema URL; editors need network access'
+local\t'config.schema.json written next to the config file, by relative path'
+file-url\t'config.schema.json written next to the config file, by file:// URL'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from reset" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from set-schema" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from migrate" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l format -d 'Output format; secret-bearing keys are emitted as references, not values' -r -f -a "k8s-configmap\t'Kubernetes `ConfigMap` manifest (YAML), for `envFrom`'
+docker-env\t'Env file for `docker run --env-file`'
+systemd-env\t'Env file for systemd\'s `EnvironmentFile=`'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from export" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "show" -d 'Output the effective configuration'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "import" -d 'Set the settings listed in a file in the config file, keeping its comments'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "path" -d 'Print the resolved config file path'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "paths" -d 'Print all resolved paths (config, data, state, cache)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "schema" -d 'Print the JSON schema for the config file'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "docs" -d 'Print a Markdown reference of every setting: type, default, and description'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "reset" -d 'Regenerate the default configuration file'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "set-schema" -d 'Point the config\'s #:schema directive at the published schema or a local copy'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "migrate" -d 'Upgrade the config file to this build\'s format version, keeping comments'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "export" -d 'Render the effective configuration as environment variables for a deployment target'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -f -a "doctor" -d 'Report existence, permissions, ownership, and disk usage of each directory, and any interrupted operation'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and not __fish_seen_subcommand_from doctor help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l roll-forward -d 'Finish an interrupted operation by installing the rest of its files'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l revert -d 'Undo an interrupted operation by restoring the files it replaced'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from doctor" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from help" -f -a "doctor" -d 'Report existence, permissions, ownership, and disk usage of each directory, and any interrupted operation'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand paths; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l probe -d 'Probes to run instead of `health.probes` (repeat or comma-separate)' -r -f -a "config\t'The configuration loads and validates'
+state\t'A file can be created in the state directory'
+daemon\t'The daemon answers GET /health with 200'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l daemon -d 'Daemon to probe instead of `health.daemon`: HOST:PORT or a Unix socket path' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l timeout-ms -d 'Time limit for the whole check instead of `health.timeout_ms`' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand healthcheck" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -f -a "list" -d 'List recorded runs, newest first'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -f -a "show" -d 'Show one run with the outcome of each of its tasks'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -f -a "clear" -d 'Remove every recorded run'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and not __fish_seen_subcommand_from list show clear help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l limit -d 'Show at most N runs' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l task -d 'Only show runs of this task' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from list" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from show" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from clear" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from help" -f -a "list" -d 'List recorded runs, newest first'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from help" -f -a "show" -d 'Show one run with the outcome of each of its tasks'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from help" -f -a "clear" -d 'Remove every recorded run'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand history; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l since -d 'Only records since WHEN: a duration ago (30m, 1h, 2d) or an RFC 3339 time' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l level -d 'Only records at LEVEL or more severe' -r -f -a "error\t'Errors only'
+warn\t'Warnings and errors'
+info\t'Informational records and above'
+debug\t'Debug diagnostics and above'
+trace\t'Everything'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -s n -l lines -d 'Show only the last N matching records (before following)' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -s f -l follow -d 'Keep printing records as they are appended, until interrupted'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l audit -d 'Show the MCP server\'s audit log of tool calls instead of logging.file'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand logs" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -f -a "stats" -d 'Show size, entry counts, and hit/miss counters per namespace'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -f -a "clear" -d 'Remove entries selected by namespace, age, and key pattern'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -f -a "verify" -d 'Check the cache index and every entry\'s checksum'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -f -a "repair" -d 'Drop corrupt index records, damaged entries, and orphaned files'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and not __fish_seen_subcommand_from stats clear verify repair help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from stats" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l namespace -d 'Only clear entries in this namespace (the key prefix before `/`)' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l older-than -d 'Only clear entries stored longer ago than AGE (e.g. 30m, 12h, 7d)' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l pattern -d 'Only clear keys matching GLOB (`*` and `?` wildcards)' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from clear" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from verify" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from repair" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from help" -f -a "stats" -d 'Show size, entry counts, and hit/miss counters per namespace'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from help" -f -a "clear" -d 'Remove entries selected by namespace, age, and key pattern'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from help" -f -a "verify" -d 'Check the cache index and every entry\'s checksum'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from help" -f -a "repair" -d 'Drop corrupt index records, damaged entries, and orphaned files'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand cache; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l all -d 'Remove every category below'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l cache -d 'Cache entries, index, and counters'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l logs -d 'The log file named by `logging.file`'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l runs -d 'Run history and the last outcome of each task'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l artifacts -d 'Temporary directories and partial files left by interrupted operations'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l state -d 'Everything else in the state directory, such as the journal'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand clean" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -f -a "create" -d 'Archive the config and state (and optionally data) directories'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -f -a "restore" -d 'Verify an archive and restore its files into place'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -f -a "verify" -d 'Check every file in an archive against its manifest'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and not __fish_seen_subcommand_from create restore verify help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -s f -l file -d 'Archive to write (default: ./<app>-backup-<timestamp>.tar.zst)' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l exclude -d 'Leave out archive paths matching GLOB (e.g. \'state/*.log\'); repeatable, added to `backup.exclude`' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l level -d 'zstd compression level (1-22; overrides `backup.compression_level`)' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l include-data -d 'Include the data directory'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from create" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from restore" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from verify" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from help" -f -a "create" -d 'Archive the config and state (and optionally data) directories'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from help" -f -a "restore" -d 'Verify an archive and restore its files into place'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from help" -f -a "verify" -d 'Check every file in an archive against its manifest'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand backup; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -f -a "init" -d 'Set up the sync repository and point it at a git remote'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -f -a "push" -d 'Send local setting changes to the remote'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -f -a "pull" -d 'Merge setting changes from the remote into the local config'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and not __fish_seen_subcommand_from init push pull help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from init" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from push" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l prefer -d 'Resolve settings changed on both sides by keeping this side\'s value' -r -f -a "local\t'Keep the local value (the next push sends it)'
+remote\t'Take the remote value'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from pull" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from help" -f -a "init" -d 'Set up the sync repository and point it at a git remote'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from help" -f -a "push" -d 'Send local setting changes to the remote'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from help" -f -a "pull" -d 'Merge setting changes from the remote into the local config'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand sync; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l tag -d 'Install this release tag (e.g. v1.2.0) instead of the latest, even if it is not newer' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l check -d 'Only report whether a newer release exists'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand self-update" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -f -a "stamp" -d 'Set the version across the workspace and regenerate all artifacts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and not __fish_seen_subcommand_from stamp help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l root -d 'Workspace root (defaults to the nearest workspace above the current directory)' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from stamp" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from help" -f -a "stamp" -d 'Set the version across the workspace and regenerate all artifacts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand release; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -f -a "watch-generate" -d 'Regenerate schema, example config, and docs whenever their sources change'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and not __fish_seen_subcommand_from watch-generate help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l root -d 'Workspace root (defaults to the nearest workspace above the current directory)' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l interval -d 'How often to check the sources for changes (e.g. 500ms, 2s)' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l cooldown -d 'Least time between two regenerations (e.g. 10s, 1m)' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from watch-generate" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from help" -f -a "watch-generate" -d 'Regenerate schema, example config, and docs whenever their sources change'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand dev; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -f -a "features" -d 'List optional subsystems: compiled into this build, enabled by the config'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -f -a "cli-spec" -d 'Describe every command and argument as JSON for wrapper and docs generators'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and not __fish_seen_subcommand_from features cli-spec help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from features" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from cli-spec" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from help" -f -a "features" -d 'List optional subsystems: compiled into this build, enabled by the config'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from help" -f -a "cli-spec" -d 'Describe every command and argument as JSON for wrapper and docs generators'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand debug; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -f -a "all" -d 'Regenerate every artifact and rewrite the manifest'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -f -a "verify" -d 'Check that committed artifacts match what would be generated now'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -f -a "ui-spec" -d 'Print the settings UI description derived from the config schema'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and not __fish_seen_subcommand_from all verify ui-spec help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l root -d 'Artifact root (defaults to the nearest workspace above the current directory)' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from all" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l root -d 'Artifact root (defaults to the nearest workspace above the current directory)' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from verify" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l format -d 'Serialization of the description' -r -f -a "json\t'Pretty-printed JSON, as in `examples/config.ui.json`'
+yaml\t'YAML'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from ui-spec" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from help" -f -a "all" -d 'Regenerate every artifact and rewrite the manifest'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from help" -f -a "verify" -d 'Check that committed artifacts match what would be generated now'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from help" -f -a "ui-spec" -d 'Print the settings UI description derived from the config schema'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand generate; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -f -a "mcp" -d 'Serve this CLI\'s operations to agents over MCP, as the rust-mcp binary does'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and not __fish_seen_subcommand_from mcp help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l transport -d 'How clients reach the server' -r -f -a "stdio\t'Newline-delimited JSON-RPC on stdin and stdout, for a client that starts the server'
+http\t'Streamable HTTP at /mcp on --bind, streaming over server-sent events (also accepted as `sse`)'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l bind -d 'Address to listen on with `--transport http`' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l max-sessions -d 'Most clients connected at once with `--transport http`; more are refused with 503' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from mcp" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from help" -f -a "mcp" -d 'Serve this CLI\'s operations to agents over MCP, as the rust-mcp binary does'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand serve; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l url -d 'Streamable HTTP endpoint of the server, e.g. `http://127.0.0.1:3001/mcp`' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l token -d 'Bearer token for --url' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l stdio -d 'Command line starting a stdio server, split like a shell would [default: this binary\'s `serve mcp`]' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -f -a "list-tools" -d 'List the server\'s tools with their descriptions'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -f -a "call" -d 'Call a tool and print its result'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -f -a "read-resource" -d 'Read a resource and print its contents'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and not __fish_seen_subcommand_from list-tools call read-resource help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l url -d 'Streamable HTTP endpoint of the server, e.g. `http://127.0.0.1:3001/mcp`' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l token -d 'Bearer token for --url' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l stdio -d 'Command line starting a stdio server, split like a shell would [default: this binary\'s `serve mcp`]' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from list-tools" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l arg -d 'Argument to pass (repeatable); VALUE is JSON when it parses as JSON, else a string, e.g. `message=hi` or `tasks=\'["build"]\'`' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l url -d 'Streamable HTTP endpoint of the server, e.g. `http://127.0.0.1:3001/mcp`' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l token -d 'Bearer token for --url' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l stdio -d 'Command line starting a stdio server, split like a shell would [default: this binary\'s `serve mcp`]' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from call" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l url -d 'Streamable HTTP endpoint of the server, e.g. `http://127.0.0.1:3001/mcp`' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l token -d 'Bearer token for --url' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l stdio -d 'Command line starting a stdio server, split like a shell would [default: this binary\'s `serve mcp`]' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from read-resource" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from help" -f -a "list-tools" -d 'List the server\'s tools with their descriptions'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from help" -f -a "call" -d 'Call a tool and print its result'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from help" -f -a "read-resource" -d 'Read a resource and print its contents'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand mcp; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -f -a "publish" -d 'Publish the config schema to the schemas repository and check that its $id URL resolves'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and not __fish_seen_subcommand_from publish help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l to -d 'Git remote to commit the schema to, or an http(s) URL to PUT it under' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l branch -d 'Branch to commit to (git destinations only)' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l check -d 'Only check that the $id URL serves the current schema'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from publish" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from help" -f -a "publish" -d 'Publish the config schema to the schemas repository and check that its $id URL resolves'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand schema; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -f -a "list" -d 'List the aliases defined in the config and what they expand to'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and not __fish_seen_subcommand_from list help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from list" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from help" -f -a "list" -d 'List the aliases defined in the config and what they expand to'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand alias; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -f -a "list" -d 'List the external subcommands (rust-cli-<name> executables) on PATH'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and not __fish_seen_subcommand_from list help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from list" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from help" -f -a "list" -d 'List the external subcommands (rust-cli-<name> executables) on PATH'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand plugin; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand explain" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand version" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l config -d 'Override the config file path' -r -F
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l output -d 'Output format for command results' -r -f -a "text\t'Human-readable text'
+json\t'Pretty-printed JSON'
+yaml\t'YAML'
+table\t'Aligned columns'
+csv\t'Comma-separated values with a header row'
+ndjson\t'Newline-delimited JSON events: progress while the command runs, then the result'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l color -d 'Control color output (auto, always, never)' -r -f -a "auto\t'Detect terminal capabilities automatically'
+always\t'Always emit ANSI color codes'
+never\t'Never emit ANSI color codes'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l log-format -d 'Log record format; overrides `logging.format`' -r -f -a "text\t'Human-readable lines'
+json\t'One JSON object per line'"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l set -d 'Override a config setting for this run, over the file and environment (repeatable), e.g. runtime.timeout=120' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l timeout -d 'Maximum seconds a command (or each task of `run`) may take' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l parallel -d 'Override the degree of parallelism' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l wait -d 'Wait up to SECONDS for another instance to release the lock' -r
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l no-config -d 'Ignore the config file: built-in defaults, environment variables, and flags only; nothing is read or created'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -s q -l quiet -d 'Reduce output to only errors'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -s v -l verbose -d 'Increase logging verbosity (stackable)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l debug -d 'Enable debug logging (equivalent to -vv)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l trace -d 'Enable trace logging (overrides other levels)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l json -d 'Alias for --output json'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l yaml -d 'Alias for --output yaml'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l no-color -d 'Disable ANSI colors in output'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l dry-run -d 'Do not change anything on disk'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -s y -l yes -d 'Assume "yes" for interactive prompts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l no-input -d 'Never prompt for input; fail if confirmation would be required'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l no-pager -d 'Print long output directly instead of through `$PAGER`/less'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l no-progress -d 'Disable progress bars and spinners (also off with --quiet, structured --output, or when stderr is not a terminal)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l diagnostics -d 'Emit additional diagnostics for troubleshooting'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l stats -d 'Print wall time, CPU time, and peak memory after the command, and for each task `run` ran'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l no-update-check -d 'Do not look for a newer release in the background (see `[updates]`)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l no-lock -d 'Skip the single-instance lock (concurrent runs may corrupt state)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l system -d 'Use system-wide directories (/etc, /var/lib, /var/cache); the default as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l user -d 'Use per-user directories even when running as root'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -l entrypoint -d 'Container mode: config from environment variables only, JSON logs on stdout, no eager directory creation, graceful shutdown on SIGTERM'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand completions" -s V -l version -d 'Print version'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "run" -d 'Execute the CLI\'s primary behavior'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "init" -d 'Create config directories and default files (overwrite with --force)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "config" -d 'Inspect and manage configuration'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "paths" -d 'Diagnose the config, data, state, and cache directories'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "healthcheck" -d 'Probe config, state directory, and daemon; exit 0 if healthy, 1 if not'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "history" -d 'List, show, and clear the recorded `run` invocations'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "logs" -d 'Show the log file (and its rotated copies), filtered by time and level'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "cache" -d 'Inspect and repair the content cache'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "clean" -d 'Remove the cache, log file, run history, leftovers, or state by category'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "backup" -d 'Back up, verify, and restore the config, state, and data directories'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "sync" -d 'Share portable settings between machines through a git remote'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "self-update" -d 'Replace this binary with the latest release (checksum-verified; needs --yes)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "release" -d 'Maintainer tooling for cutting releases'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "dev" -d 'Maintainer tooling for working on this template'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "debug" -d 'Build and runtime facts for bug reports'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "generate" -d 'Write or verify generated artifacts (schema, example config, man page, completions, docs)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "serve" -d 'Host a server in this process (`serve mcp`: the rust-mcp server, no second binary needed)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "mcp" -d 'Talk to an MCP server as a client: list tools, call one, read a resource'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "schema" -d 'Publish the config schema to the URL its $id points at'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "alias" -d 'Inspect the command aliases defined in [aliases]'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "plugin" -d 'Inspect external subcommands: rust-cli-<name> executables on PATH run as `rust-cli <name>`'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "explain" -d 'Explain an error code (e.g. E0002): causes and how to fix it; lists all codes without one'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "version" -d 'Print the version, commit, build date, target, rustc, and features'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "completions" -d 'Generate shell completions'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and not __fish_seen_subcommand_from run init config paths healthcheck history logs cache clean backup sync self-update release dev debug generate serve mcp schema alias plugin explain version completions help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "show" -d 'Output the effective configuration'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "import" -d 'Set the settings listed in a file in the config file, keeping its comments'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "path" -d 'Print the resolved config file path'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "paths" -d 'Print all resolved paths (config, data, state, cache)'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "schema" -d 'Print the JSON schema for the config file'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "docs" -d 'Print a Markdown reference of every setting: type, default, and description'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "reset" -d 'Regenerate the default configuration file'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "set-schema" -d 'Point the config\'s #:schema directive at the published schema or a local copy'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "migrate" -d 'Upgrade the config file to this build\'s format version, keeping comments'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "export" -d 'Render the effective configuration as environment variables for a deployment target'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from paths" -f -a "doctor" -d 'Report existence, permissions, ownership, and disk usage of each directory, and any interrupted operation'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from history" -f -a "list" -d 'List recorded runs, newest first'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from history" -f -a "show" -d 'Show one run with the outcome of each of its tasks'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from history" -f -a "clear" -d 'Remove every recorded run'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from cache" -f -a "stats" -d 'Show size, entry counts, and hit/miss counters per namespace'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from cache" -f -a "clear" -d 'Remove entries selected by namespace, age, and key pattern'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from cache" -f -a "verify" -d 'Check the cache index and every entry\'s checksum'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from cache" -f -a "repair" -d 'Drop corrupt index records, damaged entries, and orphaned files'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from backup" -f -a "create" -d 'Archive the config and state (and optionally data) directories'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from backup" -f -a "restore" -d 'Verify an archive and restore its files into place'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from backup" -f -a "verify" -d 'Check every file in an archive against its manifest'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from sync" -f -a "init" -d 'Set up the sync repository and point it at a git remote'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from sync" -f -a "push" -d 'Send local setting changes to the remote'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from sync" -f -a "pull" -d 'Merge setting changes from the remote into the local config'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from release" -f -a "stamp" -d 'Set the version across the workspace and regenerate all artifacts'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from dev" -f -a "watch-generate" -d 'Regenerate schema, example config, and docs whenever their sources change'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from debug" -f -a "features" -d 'List optional subsystems: compiled into this build, enabled by the config'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from debug" -f -a "cli-spec" -d 'Describe every command and argument as JSON for wrapper and docs generators'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from generate" -f -a "all" -d 'Regenerate every artifact and rewrite the manifest'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from generate" -f -a "verify" -d 'Check that committed artifacts match what would be generated now'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from generate" -f -a "ui-spec" -d 'Print the settings UI description derived from the config schema'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from serve" -f -a "mcp" -d 'Serve this CLI\'s operations to agents over MCP, as the rust-mcp binary does'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from mcp" -f -a "list-tools" -d 'List the server\'s tools with their descriptions'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from mcp" -f -a "call" -d 'Call a tool and print its result'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from mcp" -f -a "read-resource" -d 'Read a resource and print its contents'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from schema" -f -a "publish" -d 'Publish the config schema to the schemas repository and check that its $id URL resolves'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from alias" -f -a "list" -d 'List the aliases defined in the config and what they expand to'
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand help; and __fish_seen_subcommand_from plugin" -f -a "list" -d 'List the external subcommands (rust-cli-<name> executables) on PATH'
+
+# Values known only at run time come from `RUST_WORKSPACE_COMPLETE=<KIND> rust-cli`.
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -f -a "(env RUST_WORKSPACE_COMPLETE=tasks rust-cli)"
+complete -c rust-cli -n "__fish_rust_cli_using_subcommand run" -l profile -x -a "(env RUST_WORKSPACE_COMPLETE=profiles rust-cli)"
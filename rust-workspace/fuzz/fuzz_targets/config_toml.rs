@@ -0,0 +1,11 @@
+//! Config file parsing: any text either loads into a valid `AppConfig` or
+//! fails with an error, never a panic.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_core::{AppConfig, AppConfigExt as _};
+
+fuzz_target!(|text: &str| {
+    let _ = AppConfig::from_str_and_env(text, Default::default());
+});
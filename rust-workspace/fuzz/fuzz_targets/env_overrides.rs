@@ -0,0 +1,17 @@
+//! `<PREFIX>__*` environment overrides over the default config. Each input
+//! line is `KEY=VALUE`, with the key taken after the prefix, e.g.
+//! `RUNTIME__TIMEOUT=30`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_core::{AppConfig, AppConfigExt as _, env_prefix};
+
+fuzz_target!(|text: &str| {
+    let vars = text
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (format!("{}__{key}", env_prefix()), value.to_string()))
+        .collect();
+    let _ = AppConfig::from_str_and_env("", vars);
+});
@@ -0,0 +1,10 @@
+//! `~` and `$VAR` expansion of configured paths.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_core::paths::expand_str_path;
+
+fuzz_target!(|text: &str| {
+    let _ = expand_str_path(text);
+});
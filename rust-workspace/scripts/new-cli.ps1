@@ -123,6 +123,7 @@ try {
 
     Write-Host "Created workspace project at $destination" -ForegroundColor Green
     Write-Host "Crates renamed to: $Name-core, $Name-cli, $Name-tui, $Name-mcp, $Name-api" -ForegroundColor Green
+    Write-Host "Run 'just generate' in the new workspace to refresh generated artifacts." -ForegroundColor Green
     exit 0
 }
 catch {
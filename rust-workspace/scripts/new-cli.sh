@@ -160,3 +160,4 @@ PY
 
 echo "Created workspace project at $DEST"
 echo "Crates renamed to: ${NAME}-core, ${NAME}-cli, ${NAME}-tui, ${NAME}-mcp, ${NAME}-api"
+echo "Run 'just generate' in the new workspace to refresh generated artifacts."